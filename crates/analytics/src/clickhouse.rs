@@ -19,6 +19,7 @@ use super::{
     refunds::{
         distribution::RefundDistributionRow, filters::RefundFilterRow, metrics::RefundMetricRow,
     },
+    reports::rows::{DisputeReportRow, PaymentReportRow, RefundReportRow},
     sdk_events::{filters::SdkEventFilter, metrics::SdkEventMetricRow},
     types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, QueryExecutionError},
 };
@@ -266,6 +267,36 @@ impl TryInto<PaymentFilterRow> for serde_json::Value {
     }
 }
 
+impl TryInto<PaymentReportRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PaymentReportRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PaymentReportRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<RefundReportRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<RefundReportRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse RefundReportRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<DisputeReportRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<DisputeReportRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse DisputeReportRow in clickhouse results",
+        ))
+    }
+}
+
 impl TryInto<PaymentIntentMetricRow> for serde_json::Value {
     type Error = Report<ParsingError>;
 
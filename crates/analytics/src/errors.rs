@@ -14,6 +14,10 @@ pub enum AnalyticsError {
     AccessForbiddenError,
     #[error("Failed to fetch currency exchange rate")]
     ForexFetchFailed,
+    #[error("Report not found")]
+    ReportNotFound,
+    #[error("Report download link is invalid or has expired")]
+    InvalidReportDownloadLink,
 }
 
 impl ErrorSwitch<ApiErrorResponse> for AnalyticsError {
@@ -40,6 +44,15 @@ impl ErrorSwitch<ApiErrorResponse> for AnalyticsError {
                 "Failed to fetch currency exchange rate",
                 None,
             )),
+            Self::ReportNotFound => {
+                ApiErrorResponse::NotFound(ApiError::new("IR", 0, "Report not found", None))
+            }
+            Self::InvalidReportDownloadLink => ApiErrorResponse::Unauthorized(ApiError::new(
+                "IR",
+                0,
+                "Report download link is invalid or has expired",
+                None,
+            )),
         }
     }
 }
@@ -16,6 +16,7 @@ pub mod payment_intents;
 pub mod payments;
 mod query;
 pub mod refunds;
+pub mod reports;
 pub mod sdk_events;
 pub mod search;
 mod sqlx;
@@ -65,7 +66,7 @@ use router_env::{
     tracing::{self, instrument},
     types::FlowMetric,
 };
-use storage_impl::config::Database;
+use storage_impl::{config::Database, errors::ApplicationError};
 use strum::Display;
 
 use self::{
@@ -1128,6 +1129,72 @@ pub struct ReportConfig {
     pub dispute_function: String,
     pub authentication_function: String,
     pub region: String,
+    #[serde(default)]
+    pub engine: ReportEngine,
+    #[serde(default)]
+    pub native: NativeReportConfig,
+}
+
+/// Where reports are generated
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportEngine {
+    /// Hand off report generation to the configured AWS Lambda functions
+    #[default]
+    Lambda,
+    /// Generate reports within the application, using the analytics provider and file storage
+    Native,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct NativeReportConfig {
+    /// Prefix of the file storage key under which reports are stored
+    pub file_key_prefix: String,
+    /// Duration (in hours) of the time window queried at a time while generating a report
+    pub query_window_in_hours: u16,
+    /// Time (in seconds) for which the download link of a report is valid, report statuses are
+    /// retained for the same duration
+    pub download_link_expiry_in_secs: u32,
+}
+
+impl Default for NativeReportConfig {
+    fn default() -> Self {
+        Self {
+            file_key_prefix: "reports".to_string(),
+            query_window_in_hours: 24,
+            // 7 days
+            download_link_expiry_in_secs: 604800,
+        }
+    }
+}
+
+impl ReportConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        if self.engine != ReportEngine::Native {
+            return Ok(());
+        }
+
+        when(self.native.file_key_prefix.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Report file key prefix must not be empty".into(),
+            ))
+        })?;
+
+        when(self.native.query_window_in_hours == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Report query window must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.native.download_link_expiry_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Report download link expiry must be greater than 0".into(),
+            ))
+        })
+    }
 }
 
 /// Analytics Flow routes Enums
@@ -1156,6 +1223,8 @@ pub enum AnalyticsFlow {
     GenerateDisputeReport,
     GenerateRefundReport,
     GenerateAuthenticationReport,
+    GetReportStatus,
+    DownloadReport,
     GetApiEventMetrics,
     GetApiEventFilters,
     GetConnectorEvents,
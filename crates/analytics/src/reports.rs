@@ -0,0 +1,42 @@
+mod core;
+pub mod rows;
+pub mod writer;
+
+use api_models::analytics::ReportFormat;
+
+pub use self::core::generate_report;
+use crate::errors::AnalyticsResult;
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportType {
+    Payment,
+    Refund,
+    Dispute,
+}
+
+/// Destination to which the content of a report is written in parts as it is generated
+#[async_trait::async_trait]
+pub trait ReportSink: Send {
+    /// Minimum size of every part written to the sink except the last one
+    fn min_part_size(&self) -> usize;
+
+    async fn write_part(&mut self, part: Vec<u8>) -> AnalyticsResult<()>;
+}
+
+/// Summary of a generated report, whose content has been written to a [`ReportSink`]
+#[derive(Debug)]
+pub struct GeneratedReport {
+    pub format: ReportFormat,
+    pub record_count: u64,
+}
+
+pub fn get_file_extension(format: ReportFormat) -> &'static str {
+    match format {
+        ReportFormat::Csv => "csv",
+        ReportFormat::Jsonl => "jsonl",
+    }
+}
@@ -0,0 +1,146 @@
+use api_models::analytics::{ReportFormat, TimeRange};
+use common_utils::errors::ReportSwitchExt;
+use router_env::{
+    logger,
+    tracing::{self, instrument},
+};
+use time::Duration;
+
+use super::{
+    rows::{load_report_rows, DisputeReportRow, PaymentReportRow, RefundReportRow, ReportRow},
+    writer::ReportWriter,
+    GeneratedReport, ReportSink, ReportType,
+};
+use crate::{
+    enums::AuthInfo, errors::AnalyticsResult, types::LoadRow, AnalyticsProvider, ClickhouseClient,
+    SqlxClient,
+};
+
+/// Generates a report by querying the records in the requested time range one window at a time
+/// and encoding them as they are loaded. The encoded content is written to the sink in parts, so
+/// that the whole report is never held in memory.
+#[instrument(skip_all, fields(%report_type))]
+pub async fn generate_report(
+    pool: &AnalyticsProvider,
+    report_type: ReportType,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    format: ReportFormat,
+    query_window: Duration,
+    sink: &mut dyn ReportSink,
+) -> AnalyticsResult<GeneratedReport> {
+    let mut writer = ReportWriter::new(format);
+    let mut has_written_parts = false;
+
+    for window in split_time_range(time_range, query_window) {
+        match report_type {
+            ReportType::Payment => {
+                let rows = load_rows::<PaymentReportRow>(pool, auth, &window).await?;
+                writer.write_records(&rows)?;
+            }
+            ReportType::Refund => {
+                let rows = load_rows::<RefundReportRow>(pool, auth, &window).await?;
+                writer.write_records(&rows)?;
+            }
+            ReportType::Dispute => {
+                let rows = load_rows::<DisputeReportRow>(pool, auth, &window).await?;
+                writer.write_records(&rows)?;
+            }
+        }
+
+        if let Some(part) = writer.take_part(sink.min_part_size()) {
+            sink.write_part(part).await?;
+            has_written_parts = true;
+        }
+    }
+
+    // The sink receives at least one part, even if the report is empty
+    let (last_part, record_count) = writer.finish();
+    if !last_part.is_empty() || !has_written_parts {
+        sink.write_part(last_part).await?;
+    }
+
+    logger::debug!(record_count, "Generated report");
+    Ok(GeneratedReport {
+        format,
+        record_count,
+    })
+}
+
+async fn load_rows<R>(
+    pool: &AnalyticsProvider,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+) -> AnalyticsResult<Vec<R>>
+where
+    R: ReportRow,
+    SqlxClient: LoadRow<R>,
+    ClickhouseClient: LoadRow<R>,
+{
+    match pool {
+        AnalyticsProvider::Sqlx(pool) | AnalyticsProvider::CombinedSqlx(pool, _) => {
+            load_report_rows(auth, time_range, pool).await
+        }
+        AnalyticsProvider::Clickhouse(pool) | AnalyticsProvider::CombinedCkh(_, pool) => {
+            load_report_rows(auth, time_range, pool).await
+        }
+    }
+    .switch()
+}
+
+/// Splits the time range into consecutive, non-overlapping windows of at most `window` length.
+/// An open ended time range is closed at the current time.
+fn split_time_range(time_range: &TimeRange, window: Duration) -> Vec<TimeRange> {
+    let end_time = time_range
+        .end_time
+        .unwrap_or_else(common_utils::date_time::now);
+
+    let mut windows = Vec::new();
+    let mut start_time = time_range.start_time;
+    while start_time <= end_time {
+        let next_start_time = start_time.saturating_add(window);
+        windows.push(TimeRange {
+            start_time,
+            // Both ends of a time range filter are inclusive
+            end_time: Some(end_time.min(next_start_time - Duration::microseconds(1))),
+        });
+        start_time = next_start_time;
+    }
+    windows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_time_range() {
+        let start_time = common_utils::date_time::now();
+        let end_time = start_time + Duration::hours(5);
+        let time_range = TimeRange {
+            start_time,
+            end_time: Some(end_time),
+        };
+        let windows = split_time_range(&time_range, Duration::hours(2));
+
+        let bounds: Vec<_> = windows
+            .iter()
+            .map(|window| (window.start_time, window.end_time))
+            .collect();
+        let one_microsecond = Duration::microseconds(1);
+        assert_eq!(
+            bounds,
+            vec![
+                (
+                    start_time,
+                    Some(start_time + Duration::hours(2) - one_microsecond)
+                ),
+                (
+                    start_time + Duration::hours(2),
+                    Some(start_time + Duration::hours(4) - one_microsecond)
+                ),
+                (start_time + Duration::hours(4), Some(end_time)),
+            ]
+        );
+    }
+}
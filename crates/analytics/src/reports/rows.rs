@@ -0,0 +1,218 @@
+use api_models::analytics::{refunds::RefundType, TimeRange};
+use common_utils::errors::ReportSwitchExt;
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, FilterTypes, Order, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{
+        AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, FiltersError, FiltersResult,
+        LoadRow, TableEngine,
+    },
+};
+
+/// A single record of a report, along with the table it is read from
+pub trait ReportRow: serde::Serialize {
+    const COLLECTION: AnalyticsCollection;
+
+    /// Columns selected for the report, in the order in which they appear in the report
+    const COLUMNS: &'static [&'static str];
+
+    /// Columns uniquely identifying a record, used to drop stale versions of a record from
+    /// collapsing tables
+    const PRIMARY_KEY: &'static [&'static str];
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct PaymentReportRow {
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub status: DBEnumWrapper<storage_enums::AttemptStatus>,
+    pub amount: Option<i64>,
+    pub currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    pub connector: Option<String>,
+    pub payment_method: Option<String>,
+    pub payment_method_type: Option<String>,
+    pub authentication_type: Option<DBEnumWrapper<storage_enums::AuthenticationType>>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ReportRow for PaymentReportRow {
+    const COLLECTION: AnalyticsCollection = AnalyticsCollection::Payment;
+    const COLUMNS: &'static [&'static str] = &[
+        "payment_id",
+        "attempt_id",
+        "merchant_id",
+        "profile_id",
+        "status",
+        "amount",
+        "currency",
+        "connector",
+        "payment_method",
+        "payment_method_type",
+        "authentication_type",
+        "error_code",
+        "error_message",
+        "created_at",
+        "modified_at",
+    ];
+    const PRIMARY_KEY: &'static [&'static str] = &["payment_id", "attempt_id"];
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct RefundReportRow {
+    pub refund_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub refund_status: DBEnumWrapper<storage_enums::RefundStatus>,
+    pub refund_type: Option<DBEnumWrapper<RefundType>>,
+    pub total_amount: Option<i64>,
+    pub refund_amount: Option<i64>,
+    pub currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    pub connector: Option<String>,
+    pub connector_refund_id: Option<String>,
+    pub refund_reason: Option<String>,
+    pub refund_error_code: Option<String>,
+    pub refund_error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ReportRow for RefundReportRow {
+    const COLLECTION: AnalyticsCollection = AnalyticsCollection::Refund;
+    const COLUMNS: &'static [&'static str] = &[
+        "refund_id",
+        "payment_id",
+        "attempt_id",
+        "merchant_id",
+        "profile_id",
+        "refund_status",
+        "refund_type",
+        "total_amount",
+        "refund_amount",
+        "currency",
+        "connector",
+        "connector_refund_id",
+        "refund_reason",
+        "refund_error_code",
+        "refund_error_message",
+        "created_at",
+        "modified_at",
+    ];
+    const PRIMARY_KEY: &'static [&'static str] = &["refund_id"];
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct DisputeReportRow {
+    pub dispute_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub dispute_stage: DBEnumWrapper<storage_enums::DisputeStage>,
+    pub dispute_status: DBEnumWrapper<storage_enums::DisputeStatus>,
+    pub dispute_amount: Option<i64>,
+    pub currency: Option<String>,
+    pub connector: Option<String>,
+    pub connector_dispute_id: Option<String>,
+    pub connector_reason: Option<String>,
+    pub connector_reason_code: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ReportRow for DisputeReportRow {
+    const COLLECTION: AnalyticsCollection = AnalyticsCollection::Dispute;
+    const COLUMNS: &'static [&'static str] = &[
+        "dispute_id",
+        "payment_id",
+        "attempt_id",
+        "merchant_id",
+        "profile_id",
+        "dispute_stage",
+        "dispute_status",
+        "dispute_amount",
+        "currency",
+        "connector",
+        "connector_dispute_id",
+        "connector_reason",
+        "connector_reason_code",
+        "challenge_required_by",
+        "created_at",
+        "modified_at",
+    ];
+    const PRIMARY_KEY: &'static [&'static str] = &["dispute_id"];
+}
+
+pub async fn load_report_rows<T, R>(
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    pool: &T,
+) -> FiltersResult<Vec<R>>
+where
+    T: AnalyticsDataSource + LoadRow<R>,
+    R: ReportRow,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(R::COLLECTION);
+
+    for column in R::COLUMNS {
+        query_builder.add_select_column(*column).switch()?;
+    }
+
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    auth.set_filter_clause(&mut query_builder).switch()?;
+
+    query_builder
+        .add_order_by_clause("created_at", Order::Ascending)
+        .attach_printable("Error adding order by clause")
+        .switch()?;
+
+    // Updates to a record in a collapsing table insert a cancelling row along with the new
+    // version of the record, so only the latest version with a positive sign is retained until
+    // the rows are collapsed.
+    if let TableEngine::CollapsingMergeTree { sign } = T::get_table_engine(R::COLLECTION) {
+        query_builder
+            .add_custom_filter_clause(sign, "1", FilterTypes::EqualBool)
+            .attach_printable("Error adding sign filter")
+            .switch()?;
+        query_builder
+            .add_order_by_clause("modified_at", Order::Descending)
+            .attach_printable("Error adding order by clause")
+            .switch()?;
+        query_builder
+            .set_limit_by(1, R::PRIMARY_KEY)
+            .attach_printable("Error adding limit by clause")
+            .switch()?;
+    }
+
+    query_builder
+        .execute_query::<R, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
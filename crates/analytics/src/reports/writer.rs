@@ -0,0 +1,158 @@
+use api_models::analytics::ReportFormat;
+use error_stack::ResultExt;
+
+use crate::errors::{AnalyticsError, AnalyticsResult};
+
+/// Encodes report records into the requested format as they are loaded, so that the records of
+/// a time window can be dropped before the next window is queried. The encoded content is taken
+/// out in parts, so that only the part that has not been uploaded yet is held in memory.
+pub struct ReportWriter {
+    format: ReportFormat,
+    buffer: Vec<u8>,
+    record_count: u64,
+}
+
+impl ReportWriter {
+    pub fn new(format: ReportFormat) -> Self {
+        Self {
+            format,
+            buffer: Vec::new(),
+            record_count: 0,
+        }
+    }
+
+    pub fn write_records<R>(&mut self, records: &[R]) -> AnalyticsResult<()>
+    where
+        R: serde::Serialize,
+    {
+        match self.format {
+            ReportFormat::Csv => {
+                let mut writer = csv::WriterBuilder::new()
+                    .has_headers(self.record_count == 0)
+                    .from_writer(&mut self.buffer);
+                for record in records {
+                    writer
+                        .serialize(record)
+                        .change_context(AnalyticsError::UnknownError)
+                        .attach_printable("Failed to encode report record as CSV")?;
+                }
+                writer
+                    .flush()
+                    .change_context(AnalyticsError::UnknownError)
+                    .attach_printable("Failed to flush CSV report records")?;
+            }
+            ReportFormat::Jsonl => {
+                for record in records {
+                    serde_json::to_writer(&mut self.buffer, record)
+                        .change_context(AnalyticsError::UnknownError)
+                        .attach_printable("Failed to encode report record as JSON")?;
+                    self.buffer.push(b'\n');
+                }
+            }
+        }
+
+        // Safety: Assuming we won't deal with more than `u64::MAX` records in a report
+        #[allow(clippy::as_conversions)]
+        let record_count = records.len() as u64;
+        self.record_count += record_count;
+        Ok(())
+    }
+
+    /// Takes out the encoded content that has not been taken out yet, if it is at least
+    /// `min_part_size` bytes long.
+    pub fn take_part(&mut self, min_part_size: usize) -> Option<Vec<u8>> {
+        (self.buffer.len() >= min_part_size).then(|| std::mem::take(&mut self.buffer))
+    }
+
+    /// Returns the remaining encoded content along with the number of records written.
+    pub fn finish(self) -> (Vec<u8>, u64) {
+        (self.buffer, self.record_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    #[derive(serde::Serialize)]
+    struct Record {
+        id: &'static str,
+        amount: i64,
+    }
+
+    fn write_windows(writer: &mut ReportWriter, min_part_size: usize) -> Vec<u8> {
+        let mut content = Vec::new();
+        for window in [
+            vec![
+                Record {
+                    id: "pay_1",
+                    amount: 100,
+                },
+                Record {
+                    id: "pay_2",
+                    amount: 200,
+                },
+            ],
+            vec![],
+            vec![Record {
+                id: "pay_3",
+                amount: 300,
+            }],
+        ] {
+            writer.write_records(&window).expect("records are encoded");
+            if let Some(part) = writer.take_part(min_part_size) {
+                content.extend(part);
+            }
+        }
+        content
+    }
+
+    #[test]
+    fn test_csv_header_is_written_once() {
+        let mut writer = ReportWriter::new(ReportFormat::Csv);
+        let mut content = write_windows(&mut writer, 0);
+        let (remaining, record_count) = writer.finish();
+        content.extend(remaining);
+
+        assert_eq!(record_count, 3);
+        assert_eq!(
+            String::from_utf8(content).expect("content is UTF-8"),
+            "id,amount\npay_1,100\npay_2,200\npay_3,300\n"
+        );
+    }
+
+    #[test]
+    fn test_jsonl_records_are_written_per_line() {
+        let mut writer = ReportWriter::new(ReportFormat::Jsonl);
+        let mut content = write_windows(&mut writer, 0);
+        let (remaining, record_count) = writer.finish();
+        content.extend(remaining);
+
+        assert_eq!(record_count, 3);
+        assert_eq!(
+            String::from_utf8(content).expect("content is UTF-8"),
+            "{\"id\":\"pay_1\",\"amount\":100}\n{\"id\":\"pay_2\",\"amount\":200}\n{\"id\":\"pay_3\",\"amount\":300}\n"
+        );
+    }
+
+    #[test]
+    fn test_parts_are_taken_once_large_enough() {
+        let mut writer = ReportWriter::new(ReportFormat::Csv);
+        writer
+            .write_records(&[Record {
+                id: "pay_1",
+                amount: 100,
+            }])
+            .expect("records are encoded");
+        assert!(writer.take_part(1024).is_none());
+
+        let part = writer.take_part(10).expect("part is large enough");
+        assert_eq!(part, b"id,amount\npay_1,100\n");
+        assert!(writer.take_part(0).is_some_and(|part| part.is_empty()));
+
+        let (remaining, record_count) = writer.finish();
+        assert!(remaining.is_empty());
+        assert_eq!(record_count, 1);
+    }
+}
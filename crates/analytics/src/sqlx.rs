@@ -638,6 +638,75 @@ impl<'a> FromRow<'a, PgRow> for super::payments::distribution::PaymentDistributi
     }
 }
 
+impl<'a> FromRow<'a, PgRow> for super::reports::rows::PaymentReportRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        Ok(Self {
+            payment_id: row.try_get("payment_id")?,
+            attempt_id: row.try_get("attempt_id")?,
+            merchant_id: row.try_get("merchant_id")?,
+            profile_id: row.try_get("profile_id")?,
+            status: row.try_get("status")?,
+            amount: row.try_get("amount")?,
+            currency: row.try_get("currency")?,
+            connector: row.try_get("connector")?,
+            payment_method: row.try_get("payment_method")?,
+            payment_method_type: row.try_get("payment_method_type")?,
+            authentication_type: row.try_get("authentication_type")?,
+            error_code: row.try_get("error_code")?,
+            error_message: row.try_get("error_message")?,
+            created_at: row.try_get("created_at")?,
+            modified_at: row.try_get("modified_at")?,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::reports::rows::RefundReportRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        Ok(Self {
+            refund_id: row.try_get("refund_id")?,
+            payment_id: row.try_get("payment_id")?,
+            attempt_id: row.try_get("attempt_id")?,
+            merchant_id: row.try_get("merchant_id")?,
+            profile_id: row.try_get("profile_id")?,
+            refund_status: row.try_get("refund_status")?,
+            refund_type: row.try_get("refund_type")?,
+            total_amount: row.try_get("total_amount")?,
+            refund_amount: row.try_get("refund_amount")?,
+            currency: row.try_get("currency")?,
+            connector: row.try_get("connector")?,
+            connector_refund_id: row.try_get("connector_refund_id")?,
+            refund_reason: row.try_get("refund_reason")?,
+            refund_error_code: row.try_get("refund_error_code")?,
+            refund_error_message: row.try_get("refund_error_message")?,
+            created_at: row.try_get("created_at")?,
+            modified_at: row.try_get("modified_at")?,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::reports::rows::DisputeReportRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        Ok(Self {
+            dispute_id: row.try_get("dispute_id")?,
+            payment_id: row.try_get("payment_id")?,
+            attempt_id: row.try_get("attempt_id")?,
+            merchant_id: row.try_get("merchant_id")?,
+            profile_id: row.try_get("profile_id")?,
+            dispute_stage: row.try_get("dispute_stage")?,
+            dispute_status: row.try_get("dispute_status")?,
+            dispute_amount: row.try_get("dispute_amount")?,
+            currency: row.try_get("currency")?,
+            connector: row.try_get("connector")?,
+            connector_dispute_id: row.try_get("connector_dispute_id")?,
+            connector_reason: row.try_get("connector_reason")?,
+            connector_reason_code: row.try_get("connector_reason_code")?,
+            challenge_required_by: row.try_get("challenge_required_by")?,
+            created_at: row.try_get("created_at")?,
+            modified_at: row.try_get("modified_at")?,
+        })
+    }
+}
+
 impl<'a> FromRow<'a, PgRow> for super::payments::filters::PaymentFilterRow {
     fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
         let currency: Option<DBEnumWrapper<Currency>> =
@@ -688,6 +688,10 @@ pub struct WebhookDetails {
     /// If this property is true, a webhook message is posted whenever a payment fails
     #[schema(example = true)]
     pub payment_failed_enabled: Option<bool>,
+
+    /// The format in which outgoing webhooks are sent. Webhooks are sent as Stripe `Event` objects
+    /// signed in the `Stripe-Signature` header when set to `stripe`
+    pub webhook_format: Option<api_enums::OutgoingWebhookFormat>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -720,6 +724,57 @@ pub struct MerchantConnectorId {
     pub id: id_type::MerchantConnectorAccountId,
 }
 
+/// The state of the circuit breaker of a merchant connector account
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorCircuitBreakerState {
+    /// Payments are routed to the connector
+    #[default]
+    Closed,
+    /// The connector calls have been failing, payments are not routed to the connector
+    Open,
+    /// Payments are routed to the connector again, a failed connector call opens the breaker
+    /// and enough successful connector calls close it
+    HalfOpen,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ConnectorCircuitBreakerResponse {
+    /// The identifier of the merchant connector account
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// Name of the connector
+    #[schema(value_type = Connector, example = "stripe")]
+    pub connector_name: String,
+
+    pub state: ConnectorCircuitBreakerState,
+
+    /// Number of failed connector calls counted in the current failure window
+    pub failure_count: u32,
+
+    /// Number of successful connector calls since the breaker became half open
+    pub half_open_success_count: u32,
+
+    /// The time at which the breaker was last opened
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub opened_at: Option<time::PrimitiveDateTime>,
+}
+
 #[cfg(feature = "v2")]
 /// Create a new Merchant Connector for the merchant account. The connector could be a payment processor / facilitator / acquirer or specialized services like Fraud / Accounting etc."
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -2005,6 +2060,9 @@ pub struct ProfileCreate {
 
     /// Indicates if pre network tokenization is enabled or not
     pub is_pre_network_tokenization_enabled: Option<bool>,
+
+    /// Policy applied to the open disputes of the profile as their evidence submission deadline approaches
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
 }
 
 #[nutype::nutype(
@@ -2309,6 +2367,9 @@ pub struct ProfileResponse {
     /// Indicates if pre network tokenization is enabled or not
     #[schema(default = false, example = false)]
     pub is_pre_network_tokenization_enabled: bool,
+
+    /// Policy applied to the open disputes of the profile as their evidence submission deadline approaches
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
 }
 
 #[cfg(feature = "v2")]
@@ -2613,6 +2674,9 @@ pub struct ProfileUpdate {
     /// Indicates if pre network tokenization is enabled or not
     #[schema(default = false, example = false)]
     pub is_pre_network_tokenization_enabled: Option<bool>,
+
+    /// Policy applied to the open disputes of the profile as their evidence submission deadline approaches
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
 }
 
 #[cfg(feature = "v2")]
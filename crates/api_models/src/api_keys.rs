@@ -1,3 +1,4 @@
+use common_types::api_keys::ApiKeyScope;
 use common_utils::custom_serde;
 use masking::StrongSecret;
 use serde::{Deserialize, Serialize};
@@ -23,6 +24,20 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permissions granted to the API Key. If not provided, the API Key has access to all the
+    /// resources of the merchant account.
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The IP addresses or CIDR ranges that the API Key can be used from. If not provided, the
+    /// API Key can be used from any IP address.
+    #[schema(example = json!(["10.0.0.0/16", "192.168.1.10"]))]
+    pub allowed_ips: Option<Vec<String>>,
+
+    /// The business profile that the API Key is restricted to. If not provided, the API Key can
+    /// be used with any business profile of the merchant account.
+    #[schema(value_type = Option<String>, max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +75,18 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permissions granted to the API Key, the API Key has access to all the resources of
+    /// the merchant account if not present.
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The IP addresses or CIDR ranges that the API Key can be used from.
+    #[schema(example = json!(["10.0.0.0/16", "192.168.1.10"]))]
+    pub allowed_ips: Option<Vec<String>>,
+
+    /// The business profile that the API Key is restricted to.
+    #[schema(value_type = Option<String>, max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +129,18 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permissions granted to the API Key, the API Key has access to all the resources of
+    /// the merchant account if not present.
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The IP addresses or CIDR ranges that the API Key can be used from.
+    #[schema(example = json!(["10.0.0.0/16", "192.168.1.10"]))]
+    pub allowed_ips: Option<Vec<String>>,
+
+    /// The business profile that the API Key is restricted to.
+    #[schema(value_type = Option<String>, max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -130,6 +169,24 @@ pub struct UpdateApiKeyRequest {
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
 
+    /// The permissions granted to the API Key. Providing an empty list removes the restriction,
+    /// giving the API Key access to all the resources of the merchant account.
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The IP addresses or CIDR ranges that the API Key can be used from. Providing an empty list
+    /// removes the restriction, allowing the API Key to be used from any IP address.
+    #[schema(example = json!(["10.0.0.0/16", "192.168.1.10"]))]
+    pub allowed_ips: Option<Vec<String>>,
+
+    /// The business profile to restrict the API Key to.
+    #[schema(value_type = Option<String>, max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// Removes the profile restriction of the API Key, allowing it to be used with any business
+    /// profile of the merchant account. Cannot be provided along with `profile_id`.
+    #[schema(example = false)]
+    pub remove_profile_restriction: Option<bool>,
+
     #[serde(skip_deserializing)]
     #[schema(value_type = String)]
     pub key_id: common_utils::id_type::ApiKeyId,
@@ -42,6 +42,18 @@ impl events::ApiEventMetric for DecisionManager {
 
 pub type DecisionManagerResponse = DecisionManagerRecord;
 
+/// Findings of the analysis of the rules of a decision manager config, the config is not saved
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DecisionManagerAnalysisResponse {
+    pub findings: Vec<euclid::dssa::types::RuleFinding>,
+}
+
+impl events::ApiEventMetric for DecisionManagerAnalysisResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
+
 #[cfg(feature = "v2")]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct DecisionManagerRequest {
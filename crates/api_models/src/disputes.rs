@@ -144,6 +144,8 @@ pub struct DisputeListGetConstraints {
     /// The time range for which objects are needed. TimeRange has two fields start_time and end_time from which objects can be filtered as per required scenarios (created_at, time less than, greater than etc).
     #[serde(flatten)]
     pub time_range: Option<TimeRange>,
+    /// Only return disputes whose evidence submission deadline falls within the given number of hours from now
+    pub expiring_within_hours: Option<u32>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, ToSchema)]
@@ -224,6 +226,28 @@ pub struct DeleteEvidenceRequest {
     pub evidence_type: EvidenceType,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeEvidencePackageRequest {
+    /// Id of the dispute
+    pub dispute_id: String,
+    /// Upload the summary of the evidence as a text file and attach it to the dispute as an uncategorized file.
+    /// A summary attached earlier is replaced, while the request fails if another uncategorized file is attached.
+    #[serde(default)]
+    #[schema(default = false, example = false)]
+    pub attach_summary: bool,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DisputeEvidencePackageResponse {
+    /// Evidence pre-filled from the payment, customer and fulfillment data of the dispute. It has to be reviewed before it is submitted
+    pub evidence: SubmitEvidenceRequest,
+    /// Summary of the payment, authentication, customer and fulfillment data of the dispute
+    pub summary: String,
+    /// File Id of the summary, if it was attached to the dispute
+    pub summary_file_id: Option<String>,
+}
+
 #[derive(Clone, Debug, serde::Serialize)]
 pub struct DisputesAggregateResponse {
     /// Different status of disputes with their count
@@ -74,6 +74,7 @@ impl_api_event_type!(
         CreateFileResponse,
         MerchantConnectorResponse,
         MerchantConnectorId,
+        ConnectorCircuitBreakerResponse,
         MandateResponse,
         MandateRevokedResponse,
         RetrievePaymentLinkRequest,
@@ -128,6 +129,8 @@ impl_api_event_type!(
         GetApiEventMetricRequest,
         SdkEventsRequest,
         ReportRequest,
+        ReportStatusResponse,
+        ReportDownloadRequest,
         ConnectorEventsRequest,
         OutgoingWebhookLogsRequest,
         GetGlobalSearchRequest,
@@ -1,8 +1,9 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use super::{
-    DeleteEvidenceRequest, DisputeResponse, DisputeResponsePaymentsRetrieve,
-    DisputesAggregateResponse, SubmitEvidenceRequest,
+    DeleteEvidenceRequest, DisputeEvidencePackageRequest, DisputeEvidencePackageResponse,
+    DisputeResponse, DisputeResponsePaymentsRetrieve, DisputesAggregateResponse,
+    SubmitEvidenceRequest,
 };
 
 impl ApiEventMetric for SubmitEvidenceRequest {
@@ -33,6 +34,20 @@ impl ApiEventMetric for DeleteEvidenceRequest {
         })
     }
 }
+impl ApiEventMetric for DisputeEvidencePackageRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Dispute {
+            dispute_id: self.dispute_id.clone(),
+        })
+    }
+}
+impl ApiEventMetric for DisputeEvidencePackageResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Dispute {
+            dispute_id: self.evidence.dispute_id.clone(),
+        })
+    }
+}
 
 impl ApiEventMetric for DisputesAggregateResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
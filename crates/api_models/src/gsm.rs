@@ -31,6 +31,9 @@ pub struct GsmCreateRequest {
     pub error_category: Option<ErrorCategory>,
     /// indicates if retry with pan is possible
     pub clear_pan_possible: bool,
+    /// delay in seconds after which a requeued payment is retried, applicable when the decision is `requeue`
+    #[schema(minimum = 0)]
+    pub requeue_backoff_in_seconds: Option<i32>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -97,6 +100,11 @@ pub struct GsmUpdateRequest {
     pub error_category: Option<ErrorCategory>,
     /// indicates if retry with pan is possible
     pub clear_pan_possible: Option<bool>,
+    /// delay in seconds after which a requeued payment is retried, applicable when the decision is `requeue`. The delay is left unchanged when not provided
+    #[schema(minimum = 0)]
+    pub requeue_backoff_in_seconds: Option<i32>,
+    /// removes the requeue delay of the rule so that the default delay is used, cannot be set along with `requeue_backoff_in_seconds`
+    pub clear_requeue_backoff: Option<bool>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -154,4 +162,7 @@ pub struct GsmResponse {
     pub error_category: Option<ErrorCategory>,
     /// indicates if retry with pan is possible
     pub clear_pan_possible: bool,
+    /// delay in seconds after which a requeued payment is retried, applicable when the decision is `requeue`
+    #[schema(minimum = 0)]
+    pub requeue_backoff_in_seconds: Option<i32>,
 }
@@ -883,6 +883,205 @@ pub struct PayoutLinkStatusDetails {
     pub test_mode: bool,
 }
 
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchCreateRequest {
+    /// The business profile to create the payouts of the batch under. If not provided, the default profile of the merchant is used
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// A description of the batch
+    #[schema(max_length = 255, example = "Seller payouts for June")]
+    pub description: Option<String>,
+
+    /// Whether the payouts of the batch are fulfilled right after they are created
+    #[schema(default = true, example = true)]
+    pub auto_fulfill: Option<bool>,
+
+    /// The payouts of the batch, one for every row. `payout_id` may be provided to create the payout of a row with a known identifier
+    #[schema(value_type = Vec<PayoutsCreateRequest>)]
+    pub payouts: Vec<PayoutCreateRequest>,
+}
+
+/// A row of a CSV file of a batch of payouts
+#[derive(Debug, Deserialize)]
+pub struct PayoutBatchUploadRecord {
+    pub payout_id: Option<String>,
+    pub amount: Option<i64>,
+    pub currency: Option<api_enums::Currency>,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub email: Option<Email>,
+    pub name: Option<Secret<String>>,
+    pub payout_type: Option<api_enums::PayoutType>,
+    pub payout_method_id: Option<String>,
+    pub payout_token: Option<String>,
+    /// JSON of the payout method data, as accepted in the Payout Create request
+    pub payout_method_data: Option<String>,
+    pub description: Option<String>,
+}
+
+impl TryFrom<PayoutBatchUploadRecord> for PayoutCreateRequest {
+    type Error = String;
+
+    fn try_from(record: PayoutBatchUploadRecord) -> Result<Self, Self::Error> {
+        let payout_method_data = record
+            .payout_method_data
+            .filter(|payout_method_data| !payout_method_data.trim().is_empty())
+            .map(|payout_method_data| serde_json::from_str(&payout_method_data))
+            .transpose()
+            .map_err(|error| format!("invalid payout_method_data: {error}"))?;
+
+        Ok(Self {
+            payout_id: record.payout_id,
+            amount: record
+                .amount
+                .map(|amount| payments::Amount::from(common_utils::types::MinorUnit::new(amount))),
+            currency: record.currency,
+            customer_id: record.customer_id,
+            email: record.email,
+            name: record.name,
+            payout_type: record.payout_type,
+            payout_method_id: record.payout_method_id,
+            payout_token: record.payout_token,
+            payout_method_data,
+            description: record.description,
+            ..Default::default()
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+pub struct PayoutBatchRetrieveRequest {
+    /// The identifier of the batch
+    pub batch_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchListConstraints {
+    /// The maximum number of batches to return
+    #[schema(default = 10, maximum = 100)]
+    #[serde(default = "default_payouts_list_limit")]
+    pub limit: u32,
+
+    /// The number of batches to skip
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PayoutBatchResponse {
+    /// The identifier of the batch
+    #[schema(example = "payout_batch_mbabizu24mvu3mela5njyhpit4")]
+    pub batch_id: String,
+
+    /// The identifier of the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The business profile the payouts of the batch are created under
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The status of the batch
+    #[schema(value_type = PayoutBatchStatus, example = "scheduled")]
+    pub status: api_enums::PayoutBatchStatus,
+
+    /// A description of the batch
+    pub description: Option<String>,
+
+    /// Whether the payouts of the batch are fulfilled right after they are created
+    pub auto_fulfill: bool,
+
+    /// The number of rows of the batch
+    pub total_count: i32,
+
+    /// The number of rows whose payout was created and did not fail
+    pub succeeded_count: i32,
+
+    /// The number of rows which are invalid, or whose payout could not be created or failed
+    pub failed_count: i32,
+
+    /// The amounts of the rows of the batch, for every currency
+    pub totals: Vec<PayoutBatchCurrencyTotal>,
+
+    /// The outcome of every row of the batch. Not included when listing batches
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<PayoutBatchItemResponse>>,
+
+    /// Time when the batch was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// Time when the batch was last updated
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified: PrimitiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PayoutBatchCurrencyTotal {
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The sum of the amounts of all the rows in this currency
+    #[schema(value_type = i64, example = 10000)]
+    pub total_amount: common_utils::types::MinorUnit,
+
+    /// The sum of the amounts of the succeeded rows in this currency
+    #[schema(value_type = i64, example = 8000)]
+    pub succeeded_amount: common_utils::types::MinorUnit,
+
+    /// The sum of the amounts of the failed rows in this currency
+    #[schema(value_type = i64, example = 2000)]
+    pub failed_amount: common_utils::types::MinorUnit,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PayoutBatchItemResponse {
+    /// Position of the row in the batch, starting from 1
+    #[schema(example = 1)]
+    pub row_number: u32,
+
+    /// The identifier of the payout of the row
+    pub payout_id: Option<String>,
+
+    #[schema(value_type = Option<String>, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+
+    #[schema(value_type = Option<i64>, example = 1000)]
+    pub amount: Option<common_utils::types::MinorUnit>,
+
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The status of the row
+    #[schema(value_type = PayoutBatchItemStatus, example = "pending")]
+    pub status: api_enums::PayoutBatchItemStatus,
+
+    /// The status of the payout of the row, once it is created
+    #[schema(value_type = Option<PayoutStatus>, example = "success")]
+    pub payout_status: Option<api_enums::PayoutStatus>,
+
+    /// The reasons the row is invalid
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub validation_errors: Vec<String>,
+
+    /// The error code of the creation or of the payout of the row
+    pub error_code: Option<String>,
+
+    /// The error message of the creation or of the payout of the row
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct PayoutBatchListResponse {
+    /// The number of batches included in the list
+    pub size: usize,
+    /// The list of batches, without the outcome of their rows
+    pub data: Vec<PayoutBatchResponse>,
+}
+
 impl From<Bank> for payout_method_utils::BankAdditionalData {
     fn from(bank_data: Bank) -> Self {
         match bank_data {
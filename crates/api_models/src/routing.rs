@@ -16,7 +16,7 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::{
-    enums::{RoutableConnectors, TransactionType},
+    enums::{Currency, PaymentMethod, RoutableConnectors, TransactionType},
     open_router,
 };
 
@@ -139,12 +139,19 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::BillingCountry,
         DirKeyKind::BusinessCountry,
         DirKeyKind::BusinessLabel,
+        DirKeyKind::CustomerEmail,
         DirKeyKind::MetaData,
         DirKeyKind::RewardType,
         DirKeyKind::VoucherType,
         DirKeyKind::CardRedirectType,
         DirKeyKind::BankTransferType,
         DirKeyKind::RealTimePaymentType,
+        DirKeyKind::PaymentTimeOfDay,
+        DirKeyKind::PaymentDayOfWeek,
+        DirKeyKind::PaymentDate,
+        DirKeyKind::CustomerAttemptCount,
+        DirKeyKind::CardFailureCount,
+        DirKeyKind::CustomerTransactedAmount,
     ];
 }
 
@@ -175,6 +182,80 @@ impl EuclidAnalysable for ConnectorSelection {
     }
 }
 
+impl ast::printer::EuclidPrintable for ConnectorSelection {
+    fn print_output(&self) -> Result<String, ast::printer::PrintError> {
+        let choices = match self {
+            Self::Priority(list) => list
+                .iter()
+                .map(|choice| ast::OutputChoice {
+                    name: choice.to_string(),
+                    split: None,
+                })
+                .collect::<Vec<_>>(),
+            Self::VolumeSplit(splits) => splits
+                .iter()
+                .map(|split| ast::OutputChoice {
+                    name: split.connector.to_string(),
+                    split: Some(split.split),
+                })
+                .collect(),
+        };
+        ast::printer::output_choices(&choices)
+    }
+}
+
+#[cfg(feature = "ast_parser")]
+impl ast::parser::EuclidParsable for ConnectorSelection {
+    fn parse_output(input: &str) -> ast::parser::ParseResult<&str, Self> {
+        fn connector_choice(
+            name: &str,
+        ) -> Result<RoutableConnectorChoice, ast::parser::EuclidError> {
+            let invalid_connector = || ast::parser::EuclidError::InvalidConnector(name.to_string());
+            let (connector, merchant_connector_id) = match name.split_once(':') {
+                Some((connector, mca_id)) => (
+                    connector,
+                    Some(
+                        common_utils::id_type::MerchantConnectorAccountId::wrap(mca_id.to_string())
+                            .map_err(|_| invalid_connector())?,
+                    ),
+                ),
+                None => (name, None),
+            };
+
+            Ok(RoutableConnectorChoice {
+                choice_kind: RoutableChoiceKind::FullStruct,
+                connector: connector.parse().map_err(|_| invalid_connector())?,
+                merchant_connector_id,
+            })
+        }
+
+        ast::parser::map_output_choices(input, |choices| {
+            if choices.iter().all(|choice| choice.split.is_none()) {
+                choices
+                    .iter()
+                    .map(|choice| connector_choice(&choice.name))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(Self::Priority)
+            } else {
+                choices
+                    .iter()
+                    .map(|choice| {
+                        // Volume splits have to be given for either all or none of the connectors
+                        let split = choice.split.ok_or_else(|| {
+                            ast::parser::EuclidError::InvalidPercentage(choice.name.clone())
+                        })?;
+                        Ok(ConnectorVolumeSplit {
+                            connector: connector_choice(&choice.name)?,
+                            split,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(Self::VolumeSplit)
+            }
+        })
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq)]
 pub struct ConnectorVolumeSplit {
     pub connector: RoutableConnectorChoice,
@@ -530,6 +611,180 @@ pub struct RoutingLinkWrapper {
     pub algorithm_id: RoutingAlgorithmId,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingSimulationRequest {
+    /// The window of past payments to replay the routing algorithm against
+    pub time_range: common_utils::types::TimeRange,
+    /// The maximum number of payments to replay, the most recent payments in the window are
+    /// replayed first
+    #[schema(default = 1000, maximum = 10000)]
+    pub limit: Option<u32>,
+}
+
+/// Report of replaying a routing algorithm against past payments, without calling any connector
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingSimulationResponse {
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub time_range: common_utils::types::TimeRange,
+    /// The number of past payments the routing algorithm was replayed against
+    pub total_payments: u64,
+    /// The number of payments which would have been routed to a different connector
+    pub rerouted_payments: u64,
+    /// The number of payments for which none of the connectors would have been eligible
+    pub unroutable_payments: u64,
+    /// The traffic of each connector, across all the payments
+    pub connectors: Vec<ConnectorTrafficShift>,
+    /// The traffic of each connector, per payment method
+    pub payment_methods: Vec<PaymentMethodTrafficShift>,
+    /// The traffic of each connector, per currency
+    pub currencies: Vec<CurrencyTrafficShift>,
+    /// The percentage of the payments which succeeded
+    pub historical_success_rate: Option<f64>,
+    /// The estimated percentage of the payments which would have succeeded with the routing
+    /// algorithm, from the success rate of the connectors in analytics for the payment method and
+    /// currency of each payment
+    pub estimated_success_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorTrafficShift {
+    pub connector: String,
+    /// The number of payments processed by the connector
+    pub historical_payments: u64,
+    /// The number of payments the routing algorithm would have routed to the connector
+    pub simulated_payments: u64,
+    /// The percentage of the payments processed by the connector in the time range which
+    /// succeeded, from analytics
+    pub historical_success_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentMethodTrafficShift {
+    #[schema(value_type = Option<PaymentMethod>)]
+    pub payment_method: Option<PaymentMethod>,
+    pub connectors: Vec<ConnectorTrafficShift>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct CurrencyTrafficShift {
+    #[schema(value_type = Currency)]
+    pub currency: Currency,
+    pub connectors: Vec<ConnectorTrafficShift>,
+}
+
+/// Advanced routing algorithm written in the routing DSL
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingDslExportResponse {
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub name: String,
+    /// The rules of the routing algorithm in the routing DSL, which can be edited and parsed back
+    /// into the same routing algorithm
+    #[schema(example = "default: [\"stripe\"]\n")]
+    pub dsl: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingActivateRequest {
+    /// The reason for activating the routing algorithm, recorded in the activation history
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingActivationWrapper {
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub reason: Option<String>,
+}
+
+/// An activation or deactivation of a routing algorithm of a profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingActivationRecord {
+    pub id: String,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub transaction_type: TransactionType,
+    /// The routing algorithm which was active before, if any
+    #[schema(value_type = Option<String>)]
+    pub previous_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    /// The routing algorithm which was activated, absent if the active routing algorithm was
+    /// deactivated
+    #[schema(value_type = Option<String>)]
+    pub algorithm_id: Option<common_utils::id_type::RoutingId>,
+    /// The user or API key which made the change
+    pub activated_by: Option<String>,
+    pub reason: Option<String>,
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingActivationHistoryQuery {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub limit: Option<u16>,
+    pub offset: Option<u32>,
+}
+
+/// Activations of the routing algorithms of a profile, latest first
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingActivationHistoryResponse {
+    pub activations: Vec<RoutingActivationRecord>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRollbackRequest {
+    /// The profile whose latest routing algorithm activation is rolled back
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The reason for the rollback, recorded in the activation history
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingAlgorithmDiffQuery {
+    /// The routing algorithm compared against
+    pub base_algorithm_id: common_utils::id_type::RoutingId,
+    pub algorithm_id: common_utils::id_type::RoutingId,
+}
+
+/// Structural differences between two routing algorithms
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingAlgorithmDiffResponse {
+    #[schema(value_type = String)]
+    pub base_algorithm_id: common_utils::id_type::RoutingId,
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    /// The changes made to the base routing algorithm, empty if the algorithms are identical
+    pub changes: Vec<RoutingAlgorithmChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingAlgorithmChange {
+    /// The changed part of the routing algorithm, such as `defaultSelection`,
+    /// `rules.<rule name>` or `rules.<rule name>.statements`
+    #[schema(example = "rules.card_rule.connectorSelection")]
+    pub path: String,
+    pub change_type: RoutingAlgorithmChangeType,
+    /// The value in the base routing algorithm, absent for additions
+    #[schema(value_type = Option<Object>)]
+    pub base_value: Option<serde_json::Value>,
+    /// The value in the compared routing algorithm, absent for removals
+    #[schema(value_type = Option<Object>)]
+    pub value: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingAlgorithmChangeType {
+    Added,
+    Removed,
+    Modified,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct DynamicAlgorithmWithTimestamp<T> {
     pub algorithm_id: Option<T>,
@@ -23,7 +23,7 @@ pub enum SurchargeOutput {
     Rate(Percentage<SURCHARGE_PERCENTAGE_PRECISION_LENGTH>),
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SurchargeDecisionConfigs {
     pub surcharge_details: Option<SurchargeDetailsOutput>,
 }
@@ -42,6 +42,8 @@ impl EuclidDirFilter for SurchargeDecisionConfigs {
         DirKeyKind::BankDebitType,
         DirKeyKind::CryptoType,
         DirKeyKind::RealTimePaymentType,
+        DirKeyKind::CustomerAttemptCount,
+        DirKeyKind::CustomerTransactedAmount,
     ];
 }
 
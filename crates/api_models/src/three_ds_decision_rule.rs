@@ -0,0 +1,35 @@
+use common_types::three_ds_decision_rule_engine::{ThreeDSDecision, ThreeDSDecisionRule};
+use common_utils::events;
+use euclid::{
+    backend::{inputs::BackendInput, ProgramTrace},
+    frontend::ast::Program,
+};
+
+/// Request to evaluate a 3DS decision rule program against a sample input, without making a
+/// payment. Either the program or the id of a stored program has to be provided
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ThreeDsDecisionRuleExecuteRequest {
+    pub program: Option<Program<ThreeDSDecisionRule>>,
+    pub routing_id: Option<common_utils::id_type::RoutingId>,
+    pub parameters: BackendInput,
+}
+
+impl events::ApiEventMetric for ThreeDsDecisionRuleExecuteRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ThreeDsDecisionRuleExecuteResponse {
+    pub decision: ThreeDSDecision,
+    /// Name of the rule which matched, the default selection of the program is used when empty
+    pub rule_name: Option<String>,
+    pub trace: ProgramTrace,
+}
+
+impl events::ApiEventMetric for ThreeDsDecisionRuleExecuteResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+
+use common_enums::EventType;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The request body for creating a webhook endpoint.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointCreateRequest {
+    /// The URL to which webhooks are delivered.
+    #[schema(value_type = String, max_length = 255, example = "https://www.example.com/webhooks")]
+    pub url: url::Url,
+
+    /// The secret used to sign webhooks delivered to this endpoint. If not provided, the
+    /// `payment_response_hash_key` of the business profile is used.
+    #[schema(value_type = Option<String>, max_length = 255)]
+    pub secret: Option<Secret<String>>,
+
+    /// A description of the webhook endpoint.
+    #[schema(max_length = 255, example = "Refunds consumer")]
+    pub description: Option<String>,
+
+    /// The event types the endpoint is subscribed to. The endpoint receives all events if the
+    /// list is not provided or empty.
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["refund_succeeded", "refund_failed"]))]
+    pub enabled_events: Option<HashSet<EventType>>,
+
+    /// Indicates whether webhooks are delivered to the endpoint.
+    #[schema(default = true, example = true)]
+    pub is_enabled: Option<bool>,
+}
+
+/// The request body for updating a webhook endpoint.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointUpdateRequest {
+    /// The URL to which webhooks are delivered.
+    #[schema(value_type = Option<String>, max_length = 255, example = "https://www.example.com/webhooks")]
+    pub url: Option<url::Url>,
+
+    /// The secret used to sign webhooks delivered to this endpoint. An empty string removes the
+    /// secret, and the `payment_response_hash_key` of the business profile is used instead.
+    #[schema(value_type = Option<String>, max_length = 255)]
+    pub secret: Option<Secret<String>>,
+
+    /// A description of the webhook endpoint. An empty string removes the description.
+    #[schema(max_length = 255, example = "Refunds consumer")]
+    pub description: Option<String>,
+
+    /// The event types the endpoint is subscribed to. An empty list subscribes the endpoint to
+    /// all events.
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["refund_succeeded", "refund_failed"]))]
+    pub enabled_events: Option<HashSet<EventType>>,
+
+    /// Indicates whether webhooks are delivered to the endpoint.
+    #[schema(example = true)]
+    pub is_enabled: Option<bool>,
+}
+
+/// The response body for a webhook endpoint.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointResponse {
+    /// The identifier for the webhook endpoint.
+    #[schema(max_length = 64, example = "whe_Sk7Zyt1dIHUUXW9p4vPG")]
+    pub endpoint_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The URL to which webhooks are delivered.
+    #[schema(max_length = 255, example = "https://www.example.com/webhooks")]
+    pub url: String,
+
+    /// Indicates whether a secret specific to this endpoint is used to sign webhooks.
+    pub has_secret: bool,
+
+    /// A description of the webhook endpoint.
+    #[schema(max_length = 255, example = "Refunds consumer")]
+    pub description: Option<String>,
+
+    /// The event types the endpoint is subscribed to. An empty list indicates that the endpoint
+    /// is subscribed to all events.
+    pub enabled_events: Vec<EventType>,
+
+    /// Indicates whether webhooks are delivered to the endpoint.
+    pub is_enabled: bool,
+
+    /// Time at which the webhook endpoint was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time at which the webhook endpoint was last modified.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+/// The response body for deleting a webhook endpoint.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointDeleteResponse {
+    /// The identifier for the webhook endpoint.
+    #[schema(max_length = 64, example = "whe_Sk7Zyt1dIHUUXW9p4vPG")]
+    pub endpoint_id: String,
+
+    /// Indicates whether the webhook endpoint was deleted.
+    pub deleted: bool,
+}
+
+/// The request body for starting the rotation of the `payment_response_hash_key` of a business
+/// profile.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookSecretRotationStartRequest {
+    /// The new key used to sign outgoing webhooks. A key is generated if not provided.
+    #[schema(value_type = Option<String>, min_length = 32, max_length = 255)]
+    pub new_payment_response_hash_key: Option<Secret<String>>,
+
+    /// The duration for which outgoing webhooks are signed with both the previous and the new
+    /// key. Defaults to 86400 seconds (24 hours), and can be at most 604800 seconds (7 days).
+    #[schema(minimum = 1, maximum = 604800, example = 86400)]
+    pub rotation_window_in_seconds: Option<u32>,
+}
+
+/// The status of the rotation of the `payment_response_hash_key` of a business profile.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookSecretRotationStatus {
+    /// No rotation has been started for the profile.
+    NotStarted,
+    /// Outgoing webhooks are signed with both the previous and the new key.
+    InProgress,
+    /// The rotation window has elapsed, outgoing webhooks are signed with the new key only.
+    Expired,
+    /// The rotation was completed, outgoing webhooks are signed with the new key only.
+    Completed,
+}
+
+/// The response body for the rotation of the `payment_response_hash_key` of a business profile.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookSecretRotationResponse {
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The status of the rotation.
+    pub status: WebhookSecretRotationStatus,
+
+    /// The new key used to sign outgoing webhooks. Only returned when a rotation is started.
+    #[schema(value_type = Option<String>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_response_hash_key: Option<Secret<String>>,
+
+    /// Time at which the rotation was started.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub started_at: Option<PrimitiveDateTime>,
+
+    /// Time until which outgoing webhooks are signed with the previous key as well.
+    #[schema(example = "2022-09-11T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+
+    /// Time at which the rotation was completed.
+    #[schema(example = "2022-09-10T18:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEndpointCreateRequest {}
+
+impl common_utils::events::ApiEventMetric for WebhookEndpointUpdateRequest {}
+
+impl common_utils::events::ApiEventMetric for WebhookEndpointResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEndpointDeleteResponse {}
+
+impl common_utils::events::ApiEventMetric for WebhookSecretRotationStartRequest {}
+
+impl common_utils::events::ApiEventMetric for WebhookSecretRotationResponse {}
@@ -1437,6 +1437,7 @@ impl EventClass {
                 EventType::DisputeChallenged,
                 EventType::DisputeWon,
                 EventType::DisputeLost,
+                EventType::DisputeDeadlineApproaching,
             ]),
             Self::Mandates => HashSet::from([EventType::MandateActive, EventType::MandateRevoked]),
             #[cfg(feature = "payouts")]
@@ -1489,6 +1490,8 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    /// The evidence submission deadline of an open dispute is approaching
+    DisputeDeadlineApproaching,
     MandateActive,
     MandateRevoked,
     #[cfg(feature = "payouts")]
@@ -1528,6 +1531,32 @@ pub enum WebhookDeliveryAttempt {
     ManualRetry,
 }
 
+/// The format in which outgoing webhooks are sent to the merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OutgoingWebhookFormat {
+    /// Hyperswitch webhook payloads, signed in the `X-Webhook-Signature-512` and
+    /// `X-Webhook-Signature-V2` headers
+    #[default]
+    Hyperswitch,
+    /// Stripe `Event` objects, signed in the `Stripe-Signature` header, which can be verified with
+    /// the webhook verification utilities of the Stripe client libraries
+    Stripe,
+}
+
 // TODO: This decision about using KV mode or not,
 // should be taken at a top level rather than pushing it down to individual functions via an enum.
 #[derive(
@@ -6855,6 +6884,67 @@ pub enum PayoutStatus {
     RequiresVendorAccountCreation,
 }
 
+/// The status of a batch of payouts
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchStatus {
+    /// Some of the rows of the batch are invalid, none of the payouts were created
+    ValidationFailed,
+    /// The payouts of the batch are yet to be created
+    #[default]
+    Scheduled,
+    /// The payouts of the batch are being created
+    Processing,
+    /// All the payouts of the batch were created
+    Succeeded,
+    /// Some of the payouts of the batch could not be created, or failed
+    PartiallySucceeded,
+    /// None of the payouts of the batch could be created
+    Failed,
+}
+
+/// The status of a single row of a batch of payouts
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchItemStatus {
+    /// The payout of the row is yet to be created
+    #[default]
+    Pending,
+    /// The payout of the row was created
+    Succeeded,
+    /// The row is invalid, or its payout could not be created or failed
+    Failed,
+}
+
 /// The payout_type of the payout request is a mandatory field for confirming the payouts. It should be specified in the Create request. If not provided, it must be updated in the Payout Update request before it can be confirmed.
 #[derive(
     Clone,
@@ -7316,6 +7406,7 @@ pub enum TransactionStatus {
     strum::Display,
     strum::EnumString,
     strum::EnumIter,
+    ToSchema,
 )]
 #[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
@@ -7358,7 +7449,9 @@ pub enum ParentGroup {
     Internal,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Serialize)]
+#[derive(
+    Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize, ToSchema,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum Resource {
     Payment,
@@ -8277,13 +8370,16 @@ pub enum ProcessTrackerStatus {
     Debug,
     PartialEq,
     Eq,
+    Hash,
     strum::EnumString,
+    strum::EnumIter,
     strum::Display,
 )]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 pub enum ProcessTrackerRunner {
     PaymentsSyncWorkflow,
+    PaymentsRequeueWorkflow,
     RefundWorkflowRouter,
     DeleteTokenizeDataWorkflow,
     ApiKeyExpiryWorkflow,
@@ -8291,6 +8387,9 @@ pub enum ProcessTrackerRunner {
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
     PassiveRecoveryWorkflow,
+    IncomingWebhookQueueWorkflow,
+    PayoutBatchWorkflow,
+    DisputeDeadlineWorkflow,
 }
 
 #[derive(Debug)]
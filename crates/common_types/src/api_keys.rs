@@ -0,0 +1,30 @@
+//! API Key related types
+
+use common_enums::{PermissionGroup, Resource};
+use common_utils::impl_to_sql_from_sql_json;
+use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// A permission granted to a restricted API Key. The API Key can access the resources of the
+/// permission group, and the permission group also determines whether the resources can only be
+/// read or can also be modified.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ApiKeyScope {
+    /// The permission group granted to the API Key
+    #[schema(value_type = PermissionGroup, example = "operations_view")]
+    pub permission_group: PermissionGroup,
+
+    /// The resources of the permission group that the API Key is restricted to. All the resources
+    /// of the permission group are accessible if not provided.
+    #[schema(value_type = Option<Vec<Resource>>, example = json!(["payment"]))]
+    pub resources: Option<Vec<Resource>>,
+}
+
+/// The list of permissions granted to a restricted API Key
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression)]
+#[diesel(sql_type = Jsonb)]
+#[serde(transparent)]
+pub struct ApiKeyScopes(pub Vec<ApiKeyScope>);
+impl_to_sql_from_sql_json!(ApiKeyScopes);
@@ -0,0 +1,53 @@
+//! Dispute related types
+
+use common_enums::enums;
+use common_utils::{impl_to_sql_from_sql_json, types::MinorUnit};
+use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+/// Policy applied to the open disputes of a business profile as their evidence submission deadline approaches
+pub struct DisputePolicy {
+    /// Open disputes for an amount below the threshold configured for their currency are accepted automatically, instead of sending deadline reminders for them
+    pub auto_accept_thresholds: Option<Vec<DisputeAutoAcceptThreshold>>,
+
+    /// Number of hours before the evidence submission deadline at which reminders are sent for open disputes. Overrides the application default when provided
+    #[schema(value_type = Option<Vec<u16>>, example = json!([72, 24]))]
+    pub reminder_offsets_in_hours: Option<Vec<u16>>,
+}
+impl_to_sql_from_sql_json!(DisputePolicy);
+
+impl DisputePolicy {
+    /// Checks whether a dispute of the given amount and currency must be accepted automatically
+    pub fn is_eligible_for_auto_accept(
+        &self,
+        amount: MinorUnit,
+        currency: enums::Currency,
+    ) -> bool {
+        self.auto_accept_thresholds
+            .as_ref()
+            .is_some_and(|thresholds| {
+                thresholds
+                    .iter()
+                    .any(|threshold| threshold.currency == currency && amount < threshold.amount)
+            })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Amount threshold below which open disputes in a currency are accepted automatically
+pub struct DisputeAutoAcceptThreshold {
+    /// The currency of the disputes the threshold applies to
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+
+    /// Disputes for an amount below this value, in the lowest denomination of the currency, are accepted automatically
+    #[schema(value_type = i64, example = 1000)]
+    pub amount: MinorUnit,
+}
@@ -2,8 +2,10 @@
 
 #![warn(missing_docs, missing_debug_implementations)]
 
+pub mod api_keys;
 pub mod consts;
 pub mod customers;
+pub mod disputes;
 pub mod domain;
 pub mod payment_methods;
 pub mod payments;
@@ -60,5 +60,8 @@ impl EuclidDirFilter for ThreeDSDecisionRule {
         DirKeyKind::CustomerDeviceDisplaySize,
         DirKeyKind::AcquirerCountry,
         DirKeyKind::AcquirerFraudRate,
+        DirKeyKind::CustomerAttemptCount,
+        DirKeyKind::CardFailureCount,
+        DirKeyKind::CustomerTransactedAmount,
     ];
 }
@@ -14,6 +14,9 @@ pub enum ApiEventsType {
     Payout {
         payout_id: String,
     },
+    PayoutBatch {
+        batch_id: Option<String>,
+    },
     #[cfg(feature = "v1")]
     Payment {
         payment_id: id_type::PaymentId,
@@ -158,6 +161,12 @@ impl<Q: ApiEventMetric, E> ApiEventMetric for Result<Q, E> {
     }
 }
 
+impl<T: ApiEventMetric> ApiEventMetric for Option<T> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        self.as_ref().and_then(ApiEventMetric::get_api_event_type)
+    }
+}
+
 // TODO: Ideally all these types should be replaced by newtype responses
 impl<T> ApiEventMetric for Vec<T> {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
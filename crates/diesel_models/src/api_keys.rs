@@ -18,6 +18,9 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub scopes: Option<common_types::api_keys::ApiKeyScopes>,
+    pub allowed_ips: Option<Vec<String>>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
 }
 
 #[derive(Debug, Insertable)]
@@ -32,6 +35,9 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub scopes: Option<common_types::api_keys::ApiKeyScopes>,
+    pub allowed_ips: Option<Vec<String>>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
 }
 
 #[derive(Debug)]
@@ -41,6 +47,9 @@ pub enum ApiKeyUpdate {
         description: Option<String>,
         expires_at: Option<Option<PrimitiveDateTime>>,
         last_used: Option<PrimitiveDateTime>,
+        scopes: Option<Option<common_types::api_keys::ApiKeyScopes>>,
+        allowed_ips: Option<Option<Vec<String>>>,
+        profile_id: Option<Option<common_utils::id_type::ProfileId>>,
     },
     LastUsedUpdate {
         last_used: PrimitiveDateTime,
@@ -54,6 +63,9 @@ pub(crate) struct ApiKeyUpdateInternal {
     pub description: Option<String>,
     pub expires_at: Option<Option<PrimitiveDateTime>>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub scopes: Option<Option<common_types::api_keys::ApiKeyScopes>>,
+    pub allowed_ips: Option<Option<Vec<String>>>,
+    pub profile_id: Option<Option<common_utils::id_type::ProfileId>>,
 }
 
 impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
@@ -64,17 +76,26 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
                 description,
                 expires_at,
                 last_used,
+                scopes,
+                allowed_ips,
+                profile_id,
             } => Self {
                 name,
                 description,
                 expires_at,
                 last_used,
+                scopes,
+                allowed_ips,
+                profile_id,
             },
             ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
                 last_used: Some(last_used),
                 name: None,
                 description: None,
                 expires_at: None,
+                scopes: None,
+                allowed_ips: None,
+                profile_id: None,
             },
         }
     }
@@ -74,6 +74,8 @@ pub struct Profile {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: Option<bool>,
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
+    pub webhook_secret_rotation: Option<WebhookSecretRotation>,
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -129,6 +131,7 @@ pub struct ProfileNew {
     pub id: Option<common_utils::id_type::ProfileId>,
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: Option<bool>,
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -183,6 +186,8 @@ pub struct ProfileUpdateInternal {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: Option<bool>,
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
+    pub webhook_secret_rotation: Option<WebhookSecretRotation>,
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -234,6 +239,8 @@ impl ProfileUpdateInternal {
             is_iframe_redirection_enabled,
             is_pre_network_tokenization_enabled,
             three_ds_decision_rule_algorithm,
+            webhook_secret_rotation,
+            dispute_policy,
         } = self;
         Profile {
             profile_id: source.profile_id,
@@ -316,6 +323,8 @@ impl ProfileUpdateInternal {
                 .or(source.is_pre_network_tokenization_enabled),
             three_ds_decision_rule_algorithm: three_ds_decision_rule_algorithm
                 .or(source.three_ds_decision_rule_algorithm),
+            webhook_secret_rotation: webhook_secret_rotation.or(source.webhook_secret_rotation),
+            dispute_policy: dispute_policy.or(source.dispute_policy),
         }
     }
 }
@@ -730,10 +739,25 @@ pub struct WebhookDetails {
     pub payment_created_enabled: Option<bool>,
     pub payment_succeeded_enabled: Option<bool>,
     pub payment_failed_enabled: Option<bool>,
+    pub webhook_format: Option<common_enums::OutgoingWebhookFormat>,
 }
 
 common_utils::impl_to_sql_from_sql_json!(WebhookDetails);
 
+/// Tracks the rotation of the `payment_response_hash_key` of a profile. While the rotation is
+/// active, outgoing webhooks are signed with both the current and the previous key. The previous
+/// key is encrypted with the key of the merchant.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct WebhookSecretRotation {
+    pub previous_payment_response_hash_key: Option<Encryption>,
+    pub started_at: time::PrimitiveDateTime,
+    pub expires_at: time::PrimitiveDateTime,
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+common_utils::impl_to_sql_from_sql_json!(WebhookSecretRotation);
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
 #[diesel(sql_type = diesel::sql_types::Jsonb)]
 pub struct BusinessPaymentLinkConfig {
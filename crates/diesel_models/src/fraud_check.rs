@@ -27,6 +27,7 @@ pub struct FraudCheck {
     pub modified_at: PrimitiveDateTime,
     pub last_step: FraudCheckLastStep,
     pub payment_capture_method: Option<storage_enums::CaptureMethod>, // In postFrm, we are updating capture method from automatic to manual. To store the merchant actual capture method, we are storing the actual capture method in payment_capture_method. It will be useful while approving the FRM decision.
+    pub fulfillment_details: Option<serde_json::Value>,
 }
 
 #[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -49,6 +50,7 @@ pub struct FraudCheckNew {
     pub modified_at: PrimitiveDateTime,
     pub last_step: FraudCheckLastStep,
     pub payment_capture_method: Option<storage_enums::CaptureMethod>,
+    pub fulfillment_details: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -68,6 +70,11 @@ pub enum FraudCheckUpdate {
         status: FraudCheckStatus,
         error_message: Option<Option<String>>,
     },
+    FulfillmentUpdate {
+        fulfillment_details: Option<serde_json::Value>,
+        modified_at: PrimitiveDateTime,
+        last_step: FraudCheckLastStep,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -81,6 +88,8 @@ pub struct FraudCheckUpdateInternal {
     metadata: Option<serde_json::Value>,
     last_step: FraudCheckLastStep,
     payment_capture_method: Option<storage_enums::CaptureMethod>,
+    fulfillment_details: Option<serde_json::Value>,
+    modified_at: Option<PrimitiveDateTime>,
 }
 
 impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
@@ -113,6 +122,16 @@ impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
                 frm_error: error_message,
                 ..Default::default()
             },
+            FraudCheckUpdate::FulfillmentUpdate {
+                fulfillment_details,
+                modified_at,
+                last_step,
+            } => Self {
+                fulfillment_details,
+                modified_at: Some(modified_at),
+                last_step,
+                ..Default::default()
+            },
         }
     }
 }
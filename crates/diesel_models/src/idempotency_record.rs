@@ -0,0 +1,41 @@
+use common_utils::{encryption::Encryption, id_type};
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::schema::idempotency_record;
+
+/// Response of a request made with an `Idempotency-Key`, replayed for retries of the request
+/// until the record expires
+#[derive(
+    Clone, Debug, Identifiable, Queryable, Selectable, serde::Serialize, serde::Deserialize,
+)]
+#[diesel(table_name = idempotency_record, primary_key(merchant_id, idempotency_key), check_for_backend(diesel::pg::Pg))]
+pub struct IdempotencyRecord {
+    pub merchant_id: id_type::MerchantId,
+    pub idempotency_key: String,
+    /// Hash of the flow, path and payload of the request
+    pub request_fingerprint: String,
+    /// Encrypted body and headers of the response, absent if the response had no body
+    pub response: Option<Encryption>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+impl IdempotencyRecord {
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= common_utils::date_time::now()
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = idempotency_record)]
+pub struct IdempotencyRecordNew {
+    pub merchant_id: id_type::MerchantId,
+    pub idempotency_key: String,
+    pub request_fingerprint: String,
+    pub response: Option<Encryption>,
+    pub created_at: PrimitiveDateTime,
+    pub expires_at: PrimitiveDateTime,
+}
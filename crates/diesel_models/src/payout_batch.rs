@@ -0,0 +1,130 @@
+use common_utils::{encryption::Encryption, id_type, types::MinorUnit};
+use diesel::{
+    deserialize::FromSqlRow, expression::AsExpression, sql_types::Jsonb, AsChangeset, Identifiable,
+    Insertable, Queryable, Selectable,
+};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payout_batch};
+
+/// Batch of payouts, whose payouts are created asynchronously by the scheduler
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = payout_batch, primary_key(batch_id), check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBatch {
+    pub batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub description: Option<String>,
+    pub auto_fulfill: bool,
+    pub total_count: i32,
+    pub succeeded_count: i32,
+    pub failed_count: i32,
+    /// Payout create requests of the rows, encrypted with the key of the merchant. Absent when
+    /// the batch failed validation, since the payouts are never created, and removed once the
+    /// payouts of all the rows were created
+    pub payout_requests: Option<Encryption>,
+    pub items: PayoutBatchItems,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+/// Outcome of a row of a batch, the details of the payout method of the row are not stored here
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PayoutBatchItem {
+    /// Position of the row in the batch, starting from 1
+    pub row_number: u32,
+    pub payout_id: Option<String>,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub amount: Option<MinorUnit>,
+    pub currency: Option<storage_enums::Currency>,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub validation_errors: Vec<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Jsonb)]
+pub struct PayoutBatchItems(pub Vec<PayoutBatchItem>);
+
+common_utils::impl_to_sql_from_sql_json!(PayoutBatchItems);
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchNew {
+    pub batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub description: Option<String>,
+    pub auto_fulfill: bool,
+    pub total_count: i32,
+    pub succeeded_count: i32,
+    pub failed_count: i32,
+    pub payout_requests: Option<Encryption>,
+    pub items: PayoutBatchItems,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchUpdateInternal {
+    pub status: Option<storage_enums::PayoutBatchStatus>,
+    pub succeeded_count: Option<i32>,
+    pub failed_count: Option<i32>,
+    pub items: Option<PayoutBatchItems>,
+    pub payout_requests: Option<Option<Encryption>>,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug)]
+pub enum PayoutBatchUpdate {
+    StatusUpdate {
+        status: storage_enums::PayoutBatchStatus,
+    },
+    ItemsUpdate {
+        status: storage_enums::PayoutBatchStatus,
+        succeeded_count: i32,
+        failed_count: i32,
+        items: PayoutBatchItems,
+    },
+}
+
+impl From<PayoutBatchUpdate> for PayoutBatchUpdateInternal {
+    fn from(value: PayoutBatchUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match value {
+            PayoutBatchUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                succeeded_count: None,
+                failed_count: None,
+                items: None,
+                payout_requests: None,
+                last_modified_at,
+            },
+            PayoutBatchUpdate::ItemsUpdate {
+                status,
+                succeeded_count,
+                failed_count,
+                items,
+            } => Self {
+                status: Some(status),
+                succeeded_count: Some(succeeded_count),
+                failed_count: Some(failed_count),
+                items: Some(items),
+                // The payout requests are not needed once no row is pending
+                payout_requests: (!matches!(
+                    status,
+                    storage_enums::PayoutBatchStatus::Scheduled
+                        | storage_enums::PayoutBatchStatus::Processing
+                ))
+                .then_some(None),
+                last_modified_at,
+            },
+        }
+    }
+}
@@ -5,7 +5,11 @@ use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::{enums as storage_enums, errors, schema::process_tracker, StorageResult};
+use crate::{
+    enums as storage_enums, errors,
+    schema::{process_tracker, process_tracker_archive},
+    StorageResult,
+};
 
 #[derive(
     Clone,
@@ -106,6 +110,49 @@ impl ProcessTrackerNew {
     }
 }
 
+/// A process tracker task moved out of the `process_tracker` table by the scheduler cleaner.
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = process_tracker_archive)]
+pub struct ProcessTrackerArchiveNew {
+    pub id: String,
+    pub name: Option<String>,
+    pub tag: Vec<String>,
+    pub runner: Option<String>,
+    pub retry_count: i32,
+    pub schedule_time: Option<PrimitiveDateTime>,
+    pub rule: String,
+    pub tracking_data: serde_json::Value,
+    pub business_status: String,
+    pub status: storage_enums::ProcessTrackerStatus,
+    pub event: Vec<String>,
+    pub created_at: PrimitiveDateTime,
+    pub updated_at: PrimitiveDateTime,
+    pub version: ApiVersion,
+    pub archived_at: PrimitiveDateTime,
+}
+
+impl ProcessTrackerArchiveNew {
+    pub fn new(process: ProcessTracker, archived_at: PrimitiveDateTime) -> Self {
+        Self {
+            id: process.id,
+            name: process.name,
+            tag: process.tag,
+            runner: process.runner,
+            retry_count: process.retry_count,
+            schedule_time: process.schedule_time,
+            rule: process.rule,
+            tracking_data: process.tracking_data,
+            business_status: process.business_status,
+            status: process.status,
+            event: process.event,
+            created_at: process.created_at,
+            updated_at: process.updated_at,
+            version: process.version,
+            archived_at,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum ProcessTrackerUpdate {
     Update {
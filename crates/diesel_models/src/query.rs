@@ -21,6 +21,7 @@ pub mod fraud_check;
 pub mod generic_link;
 pub mod generics;
 pub mod gsm;
+pub mod idempotency_record;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -32,6 +33,7 @@ pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
@@ -39,6 +41,7 @@ pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod routing_algorithm_activation;
 #[cfg(feature = "tokenization_v2")]
 pub mod tokenization;
 pub mod unified_translations;
@@ -47,3 +50,4 @@ pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
 mod utils;
+pub mod webhook_endpoint;
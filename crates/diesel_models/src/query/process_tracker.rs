@@ -1,14 +1,20 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
-use router_env::{instrument, tracing};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    QueryDsl, Table,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
 use time::PrimitiveDateTime;
 
-use super::generics;
+use super::generics::{self, db_metrics};
 use crate::{
     enums, errors,
     process_tracker::{
-        ProcessTracker, ProcessTrackerNew, ProcessTrackerUpdate, ProcessTrackerUpdateInternal,
+        ProcessTracker, ProcessTrackerArchiveNew, ProcessTrackerNew, ProcessTrackerUpdate,
+        ProcessTrackerUpdateInternal,
     },
-    schema::process_tracker::dsl,
+    schema::{process_tracker::dsl, process_tracker_archive},
     PgPooledConn, StorageResult,
 };
 
@@ -138,4 +144,64 @@ impl ProcessTracker {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_processes_by_runner_status_updated_before(
+        conn: &PgPooledConn,
+        runner: &str,
+        status: enums::ProcessTrackerStatus,
+        updated_before: PrimitiveDateTime,
+        limit: i64,
+        version: enums::ApiVersion,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::runner
+                .eq(runner.to_owned())
+                .and(dsl::status.eq(status))
+                .and(dsl::updated_at.lt(updated_before))
+                .and(dsl::version.eq(version)),
+            Some(limit),
+            None,
+            Some(dsl::updated_at.asc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_processes_by_ids(
+        conn: &PgPooledConn,
+        ids: Vec<String>,
+    ) -> StorageResult<usize> {
+        let query = diesel::delete(<Self as HasTable>::table().filter(dsl::id.eq_any(ids)));
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Delete,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while deleting processes")
+    }
+}
+
+impl ProcessTrackerArchiveNew {
+    /// Inserts the archived processes, ignoring the ones that have already been archived, so that
+    /// archival can be safely retried.
+    #[instrument(skip(conn))]
+    pub async fn insert_batch(conn: &PgPooledConn, batch: Vec<Self>) -> StorageResult<usize> {
+        let query = diesel::insert_into(process_tracker_archive::table)
+            .values(batch)
+            .on_conflict_do_nothing();
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<process_tracker_archive::table, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while archiving processes")
+    }
 }
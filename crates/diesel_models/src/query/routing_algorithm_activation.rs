@@ -0,0 +1,37 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums,
+    routing_algorithm_activation::{RoutingAlgorithmActivation, RoutingAlgorithmActivationNew},
+    schema::routing_algorithm_activation::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl RoutingAlgorithmActivationNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RoutingAlgorithmActivation> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RoutingAlgorithmActivation {
+    /// Activations of the profile for the transaction type, latest first
+    pub async fn list_by_profile_id_transaction_type(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        transaction_type: &enums::TransactionType,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::transaction_type.eq(transaction_type.to_owned())),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+}
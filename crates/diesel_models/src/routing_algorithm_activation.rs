@@ -0,0 +1,37 @@
+use common_utils::id_type;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{enums, schema::routing_algorithm_activation};
+
+/// Activation of a routing algorithm for a profile and transaction type. Activations are never
+/// updated, they make up the history of the algorithms active for the profile.
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = routing_algorithm_activation, primary_key(activation_id), check_for_backend(diesel::pg::Pg))]
+pub struct RoutingAlgorithmActivation {
+    pub activation_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub transaction_type: enums::TransactionType,
+    pub previous_algorithm_id: Option<id_type::RoutingId>,
+    /// Algorithm active after the activation, absent if the active algorithm was deactivated
+    pub algorithm_id: Option<id_type::RoutingId>,
+    /// User or API key that activated the algorithm
+    pub activated_by: Option<String>,
+    pub reason: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = routing_algorithm_activation)]
+pub struct RoutingAlgorithmActivationNew {
+    pub activation_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub transaction_type: enums::TransactionType,
+    pub previous_algorithm_id: Option<id_type::RoutingId>,
+    pub algorithm_id: Option<id_type::RoutingId>,
+    pub activated_by: Option<String>,
+    pub reason: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
@@ -54,6 +54,10 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        scopes -> Nullable<Jsonb>,
+        allowed_ips -> Nullable<Array<Nullable<Text>>>,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
     }
 }
 
@@ -228,6 +232,8 @@ diesel::table! {
         is_iframe_redirection_enabled -> Nullable<Bool>,
         is_pre_network_tokenization_enabled -> Nullable<Bool>,
         three_ds_decision_rule_algorithm -> Nullable<Jsonb>,
+        webhook_secret_rotation -> Nullable<Jsonb>,
+        dispute_policy -> Nullable<Jsonb>,
     }
 }
 
@@ -471,7 +477,7 @@ diesel::table! {
         #[max_length = 64]
         business_profile_id -> Nullable<Varchar>,
         primary_object_created_at -> Nullable<Timestamp>,
-        #[max_length = 64]
+        #[max_length = 255]
         idempotent_event_id -> Nullable<Varchar>,
         #[max_length = 64]
         initial_attempt_id -> Nullable<Varchar>,
@@ -480,6 +486,8 @@ diesel::table! {
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
         metadata -> Nullable<Jsonb>,
         is_overall_delivery_successful -> Nullable<Bool>,
+        #[max_length = 64]
+        webhook_endpoint_id -> Nullable<Varchar>,
     }
 }
 
@@ -542,6 +550,7 @@ diesel::table! {
         #[max_length = 64]
         last_step -> Varchar,
         payment_capture_method -> Nullable<CaptureMethod>,
+        fulfillment_details -> Nullable<Jsonb>,
     }
 }
 
@@ -576,6 +585,7 @@ diesel::table! {
         #[max_length = 64]
         error_category -> Nullable<Varchar>,
         clear_pan_possible -> Bool,
+        requeue_backoff_in_seconds -> Nullable<Int4>,
     }
 }
 
@@ -601,6 +611,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    idempotency_record (merchant_id, idempotency_key) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 255]
+        idempotency_key -> Varchar,
+        #[max_length = 64]
+        request_fingerprint -> Varchar,
+        response -> Nullable<Bytea>,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1177,6 +1204,32 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch (batch_id) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        auto_fulfill -> Bool,
+        total_count -> Int4,
+        succeeded_count -> Int4,
+        failed_count -> Int4,
+        payout_requests -> Nullable<Bytea>,
+        items -> Jsonb,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1248,6 +1301,34 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    process_tracker_archive (id) {
+        #[max_length = 127]
+        id -> Varchar,
+        #[max_length = 64]
+        name -> Nullable<Varchar>,
+        tag -> Array<Nullable<Text>>,
+        #[max_length = 64]
+        runner -> Nullable<Varchar>,
+        retry_count -> Int4,
+        schedule_time -> Nullable<Timestamp>,
+        #[max_length = 255]
+        rule -> Varchar,
+        tracking_data -> Json,
+        #[max_length = 255]
+        business_status -> Varchar,
+        status -> ProcessTrackerStatus,
+        event -> Array<Nullable<Text>>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        version -> ApiVersion,
+        archived_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1416,6 +1497,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    routing_algorithm_activation (activation_id) {
+        #[max_length = 64]
+        activation_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        transaction_type -> TransactionType,
+        #[max_length = 64]
+        previous_algorithm_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        algorithm_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        activated_by -> Nullable<Varchar>,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1561,6 +1666,28 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_endpoint (endpoint_id) {
+        #[max_length = 64]
+        endpoint_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        url -> Text,
+        secret -> Nullable<Bytea>,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        enabled_events -> Array<Nullable<EventType>>,
+        is_enabled -> Bool,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
@@ -1582,6 +1709,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     fraud_check,
     gateway_status_map,
     generic_link,
+    idempotency_record,
     incremental_authorization,
     locker_mock_up,
     mandate,
@@ -1594,17 +1722,21 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_link,
     payment_methods,
     payout_attempt,
+    payout_batch,
     payouts,
     process_tracker,
+    process_tracker_archive,
     refund,
     relay,
     reverse_lookup,
     roles,
     routing_algorithm,
+    routing_algorithm_activation,
     themes,
     unified_translations,
     user_authentication_methods,
     user_key_store,
     user_roles,
     users,
+    webhook_endpoint,
 );
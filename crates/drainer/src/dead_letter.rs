@@ -0,0 +1,423 @@
+use std::{collections::HashMap, sync::Arc};
+
+use actix_web::{web, HttpRequest, Scope};
+use common_utils::{errors::CustomResult, id_type};
+use diesel_models::errors::DatabaseError;
+use error_stack::{report, Report, ResultExt};
+use masking::{PeekInterface, StrongSecret};
+use router_env::{instrument, logger, tracing};
+use serde::{de::value::MapDeserializer, Deserialize, Serialize};
+
+use crate::{
+    errors::{self, DeadLetterError},
+    kv, metrics,
+    query::ExecuteQuery,
+    services::{self, log_and_return_error_response, Store},
+    stream::StreamEntries,
+    utils::deserialize_i64,
+    Settings, StreamData,
+};
+
+const ADMIN_API_KEY_HEADER: &str = "api-key";
+const DEFAULT_LIST_COUNT: u64 = 100;
+const MAX_LIST_COUNT: u64 = 1000;
+
+pub struct DeadLetter;
+
+impl DeadLetter {
+    pub fn server(conf: Settings, stores: HashMap<id_type::TenantId, Arc<Store>>) -> Scope {
+        web::scope("dead_letter")
+            .app_data(web::Data::new(conf))
+            .app_data(web::Data::new(stores))
+            .service(web::resource("/{tenant_id}/{shard}").route(web::get().to(list_entries)))
+            .service(
+                web::resource("/{tenant_id}/{shard}/{entry_id}")
+                    .route(web::get().to(retrieve_entry))
+                    .route(web::delete().to(discard_entry)),
+            )
+            .service(
+                web::resource("/{tenant_id}/{shard}/{entry_id}/retry")
+                    .route(web::post().to(retry_entry)),
+            )
+    }
+}
+
+/// Fields of a dead letter stream entry, which are the fields of the original drainer stream entry
+/// along with the details of the failure.
+#[derive(Debug, Deserialize)]
+struct DeadLetterFields {
+    request_id: String,
+    global_id: String,
+    typed_sql: String,
+    #[serde(deserialize_with = "deserialize_i64")]
+    pushed_at: i64,
+    source_stream: String,
+    source_entry_id: String,
+    error: String,
+    #[serde(deserialize_with = "deserialize_i64")]
+    attempts: i64,
+    #[serde(deserialize_with = "deserialize_i64")]
+    dead_lettered_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeadLetterEntry {
+    pub id: String,
+    pub source_stream: String,
+    pub source_entry_id: String,
+    pub request_id: String,
+    pub global_id: String,
+    pub operation: Option<String>,
+    pub table: Option<String>,
+    pub error: String,
+    pub attempts: i64,
+    pub pushed_at: i64,
+    pub dead_lettered_at: i64,
+    /// The query which failed, included only when a single entry is retrieved
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub typed_sql: Option<serde_json::Value>,
+}
+
+impl DeadLetterEntry {
+    fn from_stream_entry(
+        id: String,
+        fields: HashMap<String, String>,
+        include_query: bool,
+    ) -> CustomResult<Self, DeadLetterError> {
+        let iter = MapDeserializer::<
+            '_,
+            std::collections::hash_map::IntoIter<String, String>,
+            serde_json::error::Error,
+        >::new(fields.into_iter());
+        let fields = DeadLetterFields::deserialize(iter)
+            .change_context(DeadLetterError::EntryParsingFailed)?;
+
+        let operation = serde_json::from_str::<kv::DBOperation>(&fields.typed_sql)
+            .inspect_err(|error| logger::warn!(?error, "Failed to parse dead lettered query"))
+            .ok();
+
+        Ok(Self {
+            id,
+            source_stream: fields.source_stream,
+            source_entry_id: fields.source_entry_id,
+            request_id: fields.request_id,
+            global_id: fields.global_id,
+            operation: operation
+                .as_ref()
+                .map(|operation| operation.operation().to_owned()),
+            table: operation
+                .as_ref()
+                .map(|operation| operation.table().to_owned()),
+            error: fields.error,
+            attempts: fields.attempts,
+            pushed_at: fields.pushed_at,
+            dead_lettered_at: fields.dead_lettered_at,
+            typed_sql: include_query
+                .then(|| serde_json::from_str(&fields.typed_sql).ok())
+                .flatten(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeadLetterListResponse {
+    pub stream: String,
+    pub count: usize,
+    pub entries: Vec<DeadLetterEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeadLetterListConstraints {
+    /// Entry ID to start listing from, inclusive
+    pub start: Option<String>,
+    pub count: Option<u64>,
+}
+
+impl Store {
+    /// Moves a drainer stream entry whose query could not be executed to the dead letter stream of
+    /// the same shard, along with the error and the number of attempts made.
+    #[instrument(skip_all)]
+    pub(crate) async fn push_to_dead_letter_stream(
+        &self,
+        stream_index: u8,
+        source_stream: &str,
+        source_entry_id: &str,
+        entry: HashMap<String, String>,
+        error: &Report<DatabaseError>,
+        attempts: u8,
+    ) -> errors::DrainerResult<()> {
+        let dead_letter_stream = self.get_dead_letter_stream_name(stream_index);
+
+        let fields = entry
+            .into_iter()
+            .chain([
+                ("source_stream".to_string(), source_stream.to_string()),
+                ("source_entry_id".to_string(), source_entry_id.to_string()),
+                ("error".to_string(), format!("{error:?}")),
+                ("attempts".to_string(), attempts.to_string()),
+                (
+                    "dead_lettered_at".to_string(),
+                    common_utils::date_time::now_unix_timestamp().to_string(),
+                ),
+            ])
+            .collect::<Vec<_>>();
+
+        self.redis_conn
+            .stream_append_entry(
+                &dead_letter_stream.as_str().into(),
+                &redis_interface::RedisEntryId::AutoGeneratedID,
+                fields,
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        metrics::ENTRIES_DEAD_LETTERED.add(
+            1,
+            router_env::metric_attributes!(("stream", source_stream.to_owned())),
+        );
+
+        Ok(())
+    }
+
+    async fn read_dead_letter_entries(
+        &self,
+        stream_index: u8,
+        start: &str,
+        end: &str,
+        count: Option<u64>,
+    ) -> CustomResult<StreamEntries, DeadLetterError> {
+        let dead_letter_stream = self.get_dead_letter_stream_name(stream_index);
+
+        self.redis_conn
+            .stream_read_range(&dead_letter_stream.as_str().into(), start, end, count)
+            .await
+            .change_context(DeadLetterError::RedisError)
+    }
+
+    async fn find_dead_letter_entry(
+        &self,
+        stream_index: u8,
+        entry_id: &str,
+    ) -> CustomResult<HashMap<String, String>, DeadLetterError> {
+        self.read_dead_letter_entries(stream_index, entry_id, entry_id, Some(1))
+            .await?
+            .into_iter()
+            .find(|(id, _)| id == entry_id)
+            .map(|(_, fields)| fields)
+            .ok_or_else(|| {
+                report!(DeadLetterError::EntryNotFound {
+                    entry_id: entry_id.to_owned(),
+                })
+            })
+    }
+
+    async fn delete_dead_letter_entry(
+        &self,
+        stream_index: u8,
+        entry_id: &str,
+    ) -> CustomResult<(), DeadLetterError> {
+        let dead_letter_stream = self.get_dead_letter_stream_name(stream_index);
+
+        self.redis_conn
+            .stream_delete_entries(&dead_letter_stream.as_str().into(), entry_id)
+            .await
+            .change_context(DeadLetterError::RedisError)
+            .map(|_| ())
+    }
+}
+
+fn authenticate(req: &HttpRequest, conf: &Settings) -> CustomResult<(), DeadLetterError> {
+    let admin_api_key = conf.secrets.get_inner().admin_api_key.peek();
+    let api_key = req
+        .headers()
+        .get(ADMIN_API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    match api_key {
+        // The admin endpoints are disabled if an admin API key has not been configured. The keys
+        // are compared in constant time.
+        Some(api_key)
+            if !admin_api_key.is_empty()
+                && StrongSecret::<String>::new(api_key.to_owned())
+                    == StrongSecret::new(admin_api_key.to_owned()) =>
+        {
+            Ok(())
+        }
+        _ => Err(report!(DeadLetterError::Unauthorized)),
+    }
+}
+
+fn get_store<'a>(
+    conf: &Settings,
+    stores: &'a HashMap<id_type::TenantId, Arc<Store>>,
+    tenant_id: &id_type::TenantId,
+    shard: u8,
+) -> CustomResult<&'a Arc<Store>, DeadLetterError> {
+    let num_partitions = conf.drainer.num_partitions;
+    if shard >= num_partitions {
+        return Err(report!(DeadLetterError::InvalidShard {
+            shard,
+            num_partitions
+        }));
+    }
+
+    stores.get(tenant_id).ok_or_else(|| {
+        report!(DeadLetterError::TenantNotFound {
+            tenant_id: tenant_id.get_string_repr().to_owned(),
+        })
+    })
+}
+
+fn to_json_response<T: Serialize>(response: &T) -> actix_web::HttpResponse {
+    services::http_response_json(
+        serde_json::to_string(response)
+            .map_err(|err| {
+                logger::error!(serialization_error=?err);
+            })
+            .unwrap_or_default(),
+    )
+}
+
+#[instrument(skip_all)]
+pub async fn list_entries(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<(id_type::TenantId, u8)>,
+    query: web::Query<DeadLetterListConstraints>,
+) -> impl actix_web::Responder {
+    let (tenant_id, shard) = path.into_inner();
+    let constraints = query.into_inner();
+
+    let result = async {
+        authenticate(&req, &conf)?;
+        let store = get_store(&conf, &stores, &tenant_id, shard)?;
+
+        let start = constraints.start.as_deref().unwrap_or("-");
+        let count = constraints
+            .count
+            .unwrap_or(DEFAULT_LIST_COUNT)
+            .min(MAX_LIST_COUNT);
+
+        let entries = store
+            .read_dead_letter_entries(shard, start, "+", Some(count))
+            .await?
+            .into_iter()
+            .map(|(id, fields)| DeadLetterEntry::from_stream_entry(id, fields, false))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok::<_, Report<DeadLetterError>>(DeadLetterListResponse {
+            stream: store.get_dead_letter_stream_name(shard),
+            count: entries.len(),
+            entries,
+        })
+    }
+    .await;
+
+    match result {
+        Ok(response) => to_json_response(&response),
+        Err(error) => log_and_return_error_response(error),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_entry(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<(id_type::TenantId, u8, String)>,
+) -> impl actix_web::Responder {
+    let (tenant_id, shard, entry_id) = path.into_inner();
+
+    let result = async {
+        authenticate(&req, &conf)?;
+        let store = get_store(&conf, &stores, &tenant_id, shard)?;
+
+        let fields = store.find_dead_letter_entry(shard, &entry_id).await?;
+        DeadLetterEntry::from_stream_entry(entry_id.clone(), fields, true)
+    }
+    .await;
+
+    match result {
+        Ok(response) => to_json_response(&response),
+        Err(error) => log_and_return_error_response(error),
+    }
+}
+
+/// Executes the query of a dead lettered entry again, and removes the entry from the dead letter
+/// stream if it succeeds. The entry is retained if the query fails.
+#[instrument(skip_all)]
+pub async fn retry_entry(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<(id_type::TenantId, u8, String)>,
+) -> impl actix_web::Responder {
+    let (tenant_id, shard, entry_id) = path.into_inner();
+
+    let result = async {
+        authenticate(&req, &conf)?;
+        let store = get_store(&conf, &stores, &tenant_id, shard)?;
+
+        let fields = store.find_dead_letter_entry(shard, &entry_id).await?;
+        let entry = DeadLetterEntry::from_stream_entry(entry_id.clone(), fields.clone(), false)?;
+        let data =
+            StreamData::from_hashmap(fields).change_context(DeadLetterError::EntryParsingFailed)?;
+
+        data.typed_sql
+            .execute_query(store, data.pushed_at)
+            .await
+            .map_err(|error| {
+                let message = error.current_context().to_string();
+                error.change_context(DeadLetterError::QueryExecutionFailed { message })
+            })?;
+
+        store.delete_dead_letter_entry(shard, &entry_id).await?;
+        metrics::DEAD_LETTER_ENTRIES_RETRIED.add(
+            1,
+            router_env::metric_attributes!(("stream", entry.source_stream.clone())),
+        );
+        logger::info!(%entry_id, "Dead letter entry was retried successfully");
+
+        Ok::<_, Report<DeadLetterError>>(entry)
+    }
+    .await;
+
+    match result {
+        Ok(response) => to_json_response(&response),
+        Err(error) => log_and_return_error_response(error),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn discard_entry(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<(id_type::TenantId, u8, String)>,
+) -> impl actix_web::Responder {
+    let (tenant_id, shard, entry_id) = path.into_inner();
+
+    let result = async {
+        authenticate(&req, &conf)?;
+        let store = get_store(&conf, &stores, &tenant_id, shard)?;
+
+        let fields = store.find_dead_letter_entry(shard, &entry_id).await?;
+        let entry = DeadLetterEntry::from_stream_entry(entry_id.clone(), fields, false)?;
+
+        store.delete_dead_letter_entry(shard, &entry_id).await?;
+        metrics::DEAD_LETTER_ENTRIES_DISCARDED.add(
+            1,
+            router_env::metric_attributes!(("stream", entry.source_stream.clone())),
+        );
+        logger::info!(%entry_id, "Dead letter entry was discarded");
+
+        Ok::<_, Report<DeadLetterError>>(entry)
+    }
+    .await;
+
+    match result {
+        Ok(response) => to_json_response(&response),
+        Err(error) => log_and_return_error_response(error),
+    }
+}
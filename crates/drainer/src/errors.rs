@@ -27,6 +27,24 @@ pub enum HealthCheckError {
     RedisError { message: String },
 }
 
+#[derive(Debug, Error, Clone, serde::Serialize)]
+pub enum DeadLetterError {
+    #[error("Invalid or missing admin API key")]
+    Unauthorized,
+    #[error("Tenant `{tenant_id}` is not configured")]
+    TenantNotFound { tenant_id: String },
+    #[error("Shard {shard} does not exist, the drainer has {num_partitions} partitions")]
+    InvalidShard { shard: u8, num_partitions: u8 },
+    #[error("Dead letter entry `{entry_id}` was not found")]
+    EntryNotFound { entry_id: String },
+    #[error("Failed to parse the dead letter entry")]
+    EntryParsingFailed,
+    #[error("Failed to execute the query for the dead letter entry: {message}")]
+    QueryExecutionFailed { message: String },
+    #[error("Error while accessing the dead letter stream in Redis")]
+    RedisError,
+}
+
 impl From<std::io::Error> for DrainerError {
     fn from(err: std::io::Error) -> Self {
         Self::IoError(err)
@@ -56,3 +74,17 @@ impl actix_web::ResponseError for HealthCheckError {
         }
     }
 }
+
+impl actix_web::ResponseError for DeadLetterError {
+    fn status_code(&self) -> reqwest::StatusCode {
+        use reqwest::StatusCode;
+
+        match self {
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::TenantNotFound { .. } | Self::EntryNotFound { .. } => StatusCode::NOT_FOUND,
+            Self::InvalidShard { .. } => StatusCode::BAD_REQUEST,
+            Self::QueryExecutionFailed { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::EntryParsingFailed | Self::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
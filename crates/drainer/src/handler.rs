@@ -3,7 +3,10 @@ use std::{
     sync::{atomic, Arc},
 };
 
-use common_utils::id_type;
+use common_utils::{errors::CustomResult, id_type};
+use diesel::result::DatabaseErrorKind;
+use diesel_models::errors::DatabaseError;
+use error_stack::Report;
 use router_env::tracing::Instrument;
 use tokio::{
     sync::{mpsc, oneshot},
@@ -184,6 +187,7 @@ async fn drainer_handler(
     let drainer_result = Box::pin(drainer(
         store.clone(),
         max_read_count,
+        stream_index,
         stream_name.as_str(),
         jobs_picked,
     ))
@@ -204,6 +208,7 @@ async fn drainer_handler(
 async fn drainer(
     store: Arc<Store>,
     max_read_count: u64,
+    stream_index: u8,
     stream_name: &str,
     jobs_picked: Arc<atomic::AtomicU8>,
 ) -> errors::DrainerResult<()> {
@@ -245,7 +250,7 @@ async fn drainer(
     let mut last_processed_id = String::new();
 
     for (entry_id, entry) in entries.clone() {
-        let data = match StreamData::from_hashmap(entry) {
+        let data = match StreamData::from_hashmap(entry.clone()) {
             Ok(data) => data,
             Err(err) => {
                 logger::error!(operation = "deserialization", err=?err);
@@ -259,22 +264,52 @@ async fn drainer(
             }
         };
 
-        tracing::Span::current().record("request_id", data.request_id);
-        tracing::Span::current().record("global_id", data.global_id);
+        tracing::Span::current().record("request_id", &data.request_id);
+        tracing::Span::current().record("global_id", &data.global_id);
         tracing::Span::current().record("session_id", &session_id);
 
-        match data.typed_sql.execute_query(&store, data.pushed_at).await {
+        match execute_query_with_retries(&store, data, &entry).await {
             Ok(_) => {
                 last_processed_id = entry_id;
             }
-            Err(err) => match err.current_context() {
-                // In case of Uniqueviolation we can't really do anything to fix it so just clear
-                // it from the stream
-                diesel_models::errors::DatabaseError::UniqueViolation => {
+            Err((err, attempts)) => match get_failed_entry_action(&err) {
+                FailedEntryAction::Skip => {
                     last_processed_id = entry_id;
                 }
-                // break from the loop in case of an error in query
-                _ => break,
+                FailedEntryAction::Halt => {
+                    logger::error!(
+                        ?err,
+                        %entry_id,
+                        attempts,
+                        "Query failed with a transient error, entry will be retried in a later session"
+                    );
+                    break;
+                }
+                FailedEntryAction::DeadLetter => match store
+                    .push_to_dead_letter_stream(
+                        stream_index,
+                        stream_name,
+                        &entry_id,
+                        entry,
+                        &err,
+                        attempts,
+                    )
+                    .await
+                {
+                    Ok(_) => {
+                        logger::error!(%entry_id, attempts, "Moved entry to the dead letter stream");
+                        last_processed_id = entry_id;
+                    }
+                    // break from the loop in case the entry could not be dead lettered
+                    Err(error) => {
+                        logger::error!(
+                            ?error,
+                            %entry_id,
+                            "Failed to move entry to the dead letter stream"
+                        );
+                        break;
+                    }
+                },
             },
         }
     }
@@ -297,3 +332,172 @@ async fn drainer(
 
     Ok(())
 }
+
+/// Executes the query of a stream entry, retrying it up to the configured number of attempts.
+/// Returns the error of the last attempt along with the number of attempts made if all of them fail.
+async fn execute_query_with_retries(
+    store: &Arc<Store>,
+    data: StreamData,
+    entry: &HashMap<String, String>,
+) -> Result<(), (error_stack::Report<DatabaseError>, u8)> {
+    let max_attempts = store.config.max_query_attempts;
+    let retry_interval = Duration::from_millis(store.config.query_retry_interval.into());
+
+    let mut attempts = 1;
+    let mut result: CustomResult<(), DatabaseError> =
+        data.typed_sql.execute_query(store, data.pushed_at).await;
+
+    loop {
+        match result {
+            Ok(()) => return Ok(()),
+            Err(err)
+                if attempts >= max_attempts
+                    || matches!(err.current_context(), DatabaseError::UniqueViolation) =>
+            {
+                return Err((err, attempts));
+            }
+            Err(err) => {
+                logger::warn!(?err, attempts, "Retrying query execution");
+                metrics::QUERY_EXECUTION_RETRIES.add(1, &[]);
+                time::sleep(retry_interval).await;
+
+                // The query is consumed on execution, hence it is parsed again from the entry
+                let data = match StreamData::from_hashmap(entry.clone()) {
+                    Ok(data) => data,
+                    Err(parse_err) => {
+                        logger::error!(operation = "deserialization", err=?parse_err);
+                        return Err((err, attempts));
+                    }
+                };
+
+                attempts += 1;
+                result = data.typed_sql.execute_query(store, data.pushed_at).await;
+            }
+        }
+    }
+}
+
+/// The action taken for a stream entry whose query could not be executed.
+#[derive(Debug, PartialEq)]
+enum FailedEntryAction {
+    /// In case of a unique violation we can't really do anything to fix it, so the entry is
+    /// just cleared from the stream.
+    Skip,
+    /// The entry is moved to the dead letter stream so that it can be replayed later, without
+    /// blocking the rest of the stream.
+    DeadLetter,
+    /// The entry and the ones after it are left in the stream and drained in a later session, so
+    /// that the order of the entries is preserved while the database is unavailable.
+    Halt,
+}
+
+fn get_failed_entry_action(error: &Report<DatabaseError>) -> FailedEntryAction {
+    match error.current_context() {
+        DatabaseError::UniqueViolation => FailedEntryAction::Skip,
+        _ if is_deterministic_query_error(error) => FailedEntryAction::DeadLetter,
+        _ => FailedEntryAction::Halt,
+    }
+}
+
+/// Returns `true` if the query failed for a reason that executing it again cannot fix, such as a
+/// constraint violation or a value that cannot be serialized. Connection errors and other
+/// transient errors, including transaction serialization conflicts, return `false`.
+fn is_deterministic_query_error(error: &Report<DatabaseError>) -> bool {
+    match error.current_context() {
+        DatabaseError::UniqueViolation
+        | DatabaseError::NotFound
+        | DatabaseError::NoFieldsToUpdate
+        | DatabaseError::QueryGenerationFailed => true,
+        DatabaseError::DatabaseConnectionError => false,
+        DatabaseError::Others => match error.downcast_ref::<diesel::result::Error>() {
+            Some(diesel::result::Error::DatabaseError(kind, _)) => !matches!(
+                kind,
+                DatabaseErrorKind::ClosedConnection
+                    | DatabaseErrorKind::UnableToSendCommand
+                    | DatabaseErrorKind::SerializationFailure
+                    | DatabaseErrorKind::ReadOnlyTransaction
+            ),
+            Some(
+                diesel::result::Error::NotFound
+                | diesel::result::Error::QueryBuilderError(_)
+                | diesel::result::Error::SerializationError(_)
+                | diesel::result::Error::DeserializationError(_),
+            ) => true,
+            _ => false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use error_stack::report;
+
+    use super::*;
+
+    fn database_error(kind: DatabaseErrorKind) -> Report<DatabaseError> {
+        report!(diesel::result::Error::DatabaseError(
+            kind,
+            Box::new(String::from("database error")),
+        ))
+        .change_context(DatabaseError::Others)
+    }
+
+    #[test]
+    fn test_constraint_violations_are_dead_lettered() {
+        for kind in [
+            DatabaseErrorKind::ForeignKeyViolation,
+            DatabaseErrorKind::NotNullViolation,
+            DatabaseErrorKind::CheckViolation,
+        ] {
+            assert_eq!(
+                get_failed_entry_action(&database_error(kind)),
+                FailedEntryAction::DeadLetter
+            );
+        }
+
+        let serialization_error = report!(diesel::result::Error::SerializationError(
+            "invalid value".into()
+        ))
+        .change_context(DatabaseError::Others);
+        assert_eq!(
+            get_failed_entry_action(&serialization_error),
+            FailedEntryAction::DeadLetter
+        );
+        assert_eq!(
+            get_failed_entry_action(&report!(DatabaseError::NotFound)),
+            FailedEntryAction::DeadLetter
+        );
+    }
+
+    #[test]
+    fn test_connection_errors_halt_the_stream() {
+        for kind in [
+            DatabaseErrorKind::ClosedConnection,
+            DatabaseErrorKind::UnableToSendCommand,
+            DatabaseErrorKind::SerializationFailure,
+        ] {
+            assert_eq!(
+                get_failed_entry_action(&database_error(kind)),
+                FailedEntryAction::Halt
+            );
+        }
+
+        assert_eq!(
+            get_failed_entry_action(&report!(DatabaseError::DatabaseConnectionError)),
+            FailedEntryAction::Halt
+        );
+        // Errors that cannot be classified are retried rather than dead lettered
+        assert_eq!(
+            get_failed_entry_action(&report!(DatabaseError::Others)),
+            FailedEntryAction::Halt
+        );
+    }
+
+    #[test]
+    fn test_unique_violations_are_skipped() {
+        assert_eq!(
+            get_failed_entry_action(&report!(DatabaseError::UniqueViolation)),
+            FailedEntryAction::Skip
+        );
+    }
+}
@@ -2,8 +2,9 @@ use std::sync::Arc;
 
 use common_utils::errors::CustomResult;
 use diesel_models::errors::DatabaseError;
+use error_stack::ResultExt;
 
-use crate::{kv, logger, metrics, pg_connection, services::Store};
+use crate::{kv, logger, metrics, services::Store};
 
 #[async_trait::async_trait]
 pub trait ExecuteQuery {
@@ -21,7 +22,13 @@ impl ExecuteQuery for kv::DBOperation {
         store: &Arc<Store>,
         pushed_at: i64,
     ) -> CustomResult<(), DatabaseError> {
-        let conn = pg_connection(&store.master_pool).await;
+        // Failing to obtain a connection is reported as a connection error, so that the entry is
+        // retried instead of being dead lettered
+        let conn = store
+            .master_pool
+            .get()
+            .await
+            .change_context(DatabaseError::DatabaseConnectionError)?;
         let operation = self.operation();
         let table = self.table();
 
@@ -22,6 +22,9 @@ pub struct Store {
 pub struct StoreConfig {
     pub drainer_stream_name: String,
     pub drainer_num_partitions: u8,
+    pub dead_letter_stream_name: String,
+    pub max_query_attempts: u8,
+    pub query_retry_interval: u32,
 }
 
 impl Store {
@@ -45,6 +48,9 @@ impl Store {
             config: StoreConfig {
                 drainer_stream_name: config.drainer.stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
+                dead_letter_stream_name: config.drainer.dead_letter_stream_name.clone(),
+                max_query_attempts: config.drainer.max_query_attempts,
+                query_retry_interval: config.drainer.query_retry_interval,
             },
             request_id: None,
         }
@@ -60,7 +66,7 @@ where
         "message": error.to_string()
     })
     .to_string();
-    HttpResponse::InternalServerError()
+    HttpResponse::build(error.current_context().status_code())
         .content_type(mime::APPLICATION_JSON)
         .body(body)
 }
@@ -68,6 +68,7 @@ impl AppState {
 pub struct Settings<S: SecretState> {
     pub server: Server,
     pub master_database: SecretStateContainer<Database, S>,
+    pub secrets: SecretStateContainer<Secrets, S>,
     pub redis: redis::RedisSettings,
     pub log: Log,
     pub drainer: DrainerSettings,
@@ -76,6 +77,13 @@ pub struct Settings<S: SecretState> {
     pub multitenancy: Multitenancy,
 }
 
+#[derive(Debug, Default, Deserialize, Clone)]
+#[serde(default)]
+pub struct Secrets {
+    /// API key required by the dead letter admin endpoints, which are disabled if it is not set
+    pub admin_api_key: Secret<String>,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct Database {
@@ -114,6 +122,9 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    pub dead_letter_stream_name: String,
+    pub max_query_attempts: u8,
+    pub query_retry_interval: u32, // in milliseconds
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -224,6 +235,9 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 100,      // in milliseconds
+            dead_letter_stream_name: "DRAINER_DEAD_LETTER_STREAM".into(),
+            max_query_attempts: 3,
+            query_retry_interval: 500, // in milliseconds
         }
     }
 }
@@ -270,10 +284,30 @@ impl Database {
 
 impl DrainerSettings {
     fn validate(&self) -> Result<(), errors::DrainerError> {
-        common_utils::fp_utils::when(self.stream_name.is_default_or_empty(), || {
+        use common_utils::fp_utils::when;
+
+        when(self.stream_name.is_default_or_empty(), || {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
+        })?;
+
+        when(self.dead_letter_stream_name.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer dead letter stream name must not be empty".into(),
+            ))
+        })?;
+
+        when(self.dead_letter_stream_name == self.stream_name, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer dead letter stream name must be different from the stream name".into(),
+            ))
+        })?;
+
+        when(self.max_query_attempts == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer max query attempts must be greater than zero".into(),
+            ))
         })
     }
 }
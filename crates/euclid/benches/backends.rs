@@ -47,6 +47,8 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
             billing_country: Some(enums::Country::France),
             business_label: None,
             setup_future_usage: None,
+            customer_email: None,
+            created_at: None,
         },
         payment_method: inputs::PaymentMethodInput {
             payment_method: Some(enums::PaymentMethod::PayLater),
@@ -58,6 +60,10 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
             mandate_type: None,
             payment_type: None,
         },
+        velocity: inputs::VelocityInput::default(),
+        issuer_data: None,
+        customer_device_data: None,
+        acquirer_data: None,
     };
 
     let (_, program) = parser::program(code1).expect("Parser");
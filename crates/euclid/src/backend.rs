@@ -8,7 +8,10 @@ pub use interpreter::InterpreterBackend;
 #[cfg(feature = "valued_jit")]
 pub use vir_interpreter::VirInterpreterBackend;
 
-use crate::frontend::ast;
+use crate::{
+    frontend::{ast, vir},
+    types::EuclidValue,
+};
 
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct BackendOutput<O> {
@@ -16,6 +19,40 @@ pub struct BackendOutput<O> {
     pub connector_selection: O,
 }
 
+/// Outcome of a single comparison of a rule against the input
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ComparisonTrace {
+    pub key: String,
+    pub logic: vir::ValuedComparisonLogic,
+    pub values: Vec<EuclidValue>,
+    pub matched: bool,
+}
+
+/// Outcome of an if statement of a rule. Like execution, the comparisons stop at the first one
+/// which did not match, and the nested statements are only evaluated when all the comparisons
+/// matched, stopping at the first nested statement which matched
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct StatementTrace {
+    pub matched: bool,
+    pub comparisons: Vec<ComparisonTrace>,
+    pub nested: Option<Vec<StatementTrace>>,
+}
+
+/// Outcome of a rule, with its statements up to the first one which matched
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RuleTrace {
+    pub rule_name: String,
+    pub matched: bool,
+    pub statements: Vec<StatementTrace>,
+}
+
+/// The rules evaluated for an input, in order. Evaluation stops at the first rule which matched,
+/// the default selection of the program is used when none of them matched
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ProgramTrace {
+    pub rules: Vec<RuleTrace>,
+}
+
 pub trait EuclidBackend<O>: Sized {
     type Error: serde::Serialize;
 
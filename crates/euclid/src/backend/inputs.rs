@@ -1,7 +1,12 @@
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 
-use crate::enums;
+use crate::{
+    enums,
+    frontend::dir::enums::{
+        CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType, PaymentDayOfWeek,
+    },
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MandateData {
@@ -28,6 +33,81 @@ pub struct PaymentInput {
     pub billing_country: Option<enums::Country>,
     pub business_label: Option<String>,
     pub setup_future_usage: Option<enums::SetupFutureUsage>,
+    #[serde(default)]
+    pub customer_email: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_at: Option<time::PrimitiveDateTime>,
+}
+
+/// The creation time of a payment, as seen in the timezone of a program
+#[derive(Debug, Clone)]
+pub struct PaymentTime {
+    /// Time of day as `HHMM`
+    pub time_of_day: common_utils::types::MinorUnit,
+    pub day_of_week: PaymentDayOfWeek,
+    /// Date as `YYYYMMDD`
+    pub date: common_utils::types::MinorUnit,
+}
+
+impl PaymentInput {
+    /// Returns the creation time of the payment in the given timezone, the creation time is
+    /// taken to be in UTC
+    pub fn get_payment_time(&self, timezone: Option<chrono_tz::Tz>) -> Option<PaymentTime> {
+        use chrono::{Datelike, Timelike};
+
+        let created_at = self.created_at?.assume_utc();
+        // The offset of the timezone is looked up at the creation time, which accounts for
+        // daylight saving time
+        let created_at =
+            chrono::DateTime::from_timestamp(created_at.unix_timestamp(), created_at.nanosecond())?
+                .with_timezone(&timezone.unwrap_or(chrono_tz::UTC));
+
+        Some(PaymentTime {
+            time_of_day: common_utils::types::MinorUnit::new(
+                i64::from(created_at.hour()) * 100 + i64::from(created_at.minute()),
+            ),
+            day_of_week: created_at.weekday().into(),
+            date: common_utils::types::MinorUnit::new(
+                i64::from(created_at.year()) * 10000
+                    + i64::from(created_at.month()) * 100
+                    + i64::from(created_at.day()),
+            ),
+        })
+    }
+}
+
+/// Velocity of the customer and the card of a payment, derived from the payments made before it
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VelocityInput {
+    /// Number of payment attempts made by the customer in the recent minutes
+    pub customer_attempt_count: Option<i64>,
+    /// Number of failed payment attempts made with the card today
+    pub card_failure_count: Option<i64>,
+    /// Amount transacted by the customer in the last 24 hours, in the currency of the payment
+    pub customer_transacted_amount: Option<common_utils::types::MinorUnit>,
+}
+
+/// Details of the bank which issued the card of a payment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssuerDataInput {
+    pub name: Option<String>,
+    pub country: Option<enums::Country>,
+}
+
+/// Details of the device the customer is making the payment from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerDeviceDataInput {
+    pub platform: Option<CustomerDevicePlatform>,
+    pub device_type: Option<CustomerDeviceType>,
+    pub display_size: Option<CustomerDeviceDisplaySize>,
+}
+
+/// Details of the bank acquiring the payment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcquirerDataInput {
+    pub country: Option<enums::Country>,
+    /// Fraud rate of the acquirer, in the same unit as the `acquirer_fraud_rate` of the rules
+    pub fraud_rate: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,4 +116,12 @@ pub struct BackendInput {
     pub payment: PaymentInput,
     pub payment_method: PaymentMethodInput,
     pub mandate: MandateData,
+    #[serde(default)]
+    pub velocity: VelocityInput,
+    #[serde(default)]
+    pub issuer_data: Option<IssuerDataInput>,
+    #[serde(default)]
+    pub customer_device_data: Option<CustomerDeviceDataInput>,
+    #[serde(default)]
+    pub acquirer_data: Option<AcquirerDataInput>,
 }
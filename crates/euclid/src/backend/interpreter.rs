@@ -5,6 +5,7 @@ use common_utils::types::MinorUnit;
 use crate::{
     backend::{self, inputs, EuclidBackend},
     frontend::ast,
+    types::StrValueRefinement,
 };
 
 pub struct InterpreterBackend<O> {
@@ -26,8 +27,12 @@ where
                 ast::ComparisonType::LessThan => num < other,
                 ast::ComparisonType::LessThanEqual => num <= other,
                 ast::ComparisonType::GreaterThanEqual => num >= other,
-                ast::ComparisonType::Equal => num == other,
+                ast::ComparisonType::Equal | ast::ComparisonType::InList => num == other,
                 ast::ComparisonType::NotEqual => num != other,
+                ast::ComparisonType::StartsWith
+                | ast::ComparisonType::EndsWith
+                | ast::ComparisonType::Contains
+                | ast::ComparisonType::MatchesRegex => false,
             };
 
             if res {
@@ -38,6 +43,11 @@ where
         Ok(false)
     }
 
+    fn eval_str_pattern(comparison_type: &ast::ComparisonType, value: &str, pattern: &str) -> bool {
+        Option::<StrValueRefinement>::from(comparison_type.clone())
+            .is_some_and(|refinement| refinement.is_match(pattern, value, None))
+    }
+
     fn eval_comparison(
         comparison: &ast::Comparison,
         ctx: &types::Context,
@@ -55,7 +65,9 @@ where
             match (val, &comparison.comparison, &comparison.value) {
                 (EnumVariant(e1), Equal, EnumVariant(e2)) => Ok(e1 == e2),
                 (EnumVariant(e1), NotEqual, EnumVariant(e2)) => Ok(e1 != e2),
-                (EnumVariant(e), Equal, EnumVariantArray(evec)) => Ok(evec.iter().any(|v| e == v)),
+                (EnumVariant(e), Equal | InList, EnumVariantArray(evec)) => {
+                    Ok(evec.iter().any(|v| e == v))
+                }
                 (EnumVariant(e), NotEqual, EnumVariantArray(evec)) => {
                     Ok(evec.iter().all(|v| e != v))
                 }
@@ -65,11 +77,29 @@ where
                 (Number(n1), GreaterThanEqual, Number(n2)) => Ok(n1 >= n2),
                 (Number(n1), LessThan, Number(n2)) => Ok(n1 < n2),
                 (Number(n1), GreaterThan, Number(n2)) => Ok(n1 > n2),
-                (Number(n), Equal, NumberArray(nvec)) => Ok(nvec.iter().any(|v| v == n)),
+                (Number(n), Equal | InList, NumberArray(nvec)) => Ok(nvec.iter().any(|v| v == n)),
                 (Number(n), NotEqual, NumberArray(nvec)) => Ok(nvec.iter().all(|v| v != n)),
                 (Number(n), Equal, NumberComparisonArray(ncvec)) => {
                     Self::eval_number_comparison_array(*n, ncvec)
                 }
+                (StrValue(s1), Equal, StrValue(s2)) => Ok(s1 == s2),
+                (StrValue(s1), NotEqual, StrValue(s2)) => Ok(s1 != s2),
+                (StrValue(s), Equal | InList, StrValueArray(svec)) => {
+                    Ok(svec.iter().any(|v| s == v))
+                }
+                (StrValue(s), NotEqual, StrValueArray(svec)) => Ok(svec.iter().all(|v| s != v)),
+                (StrValue(s), comparison_type, StrValue(pattern))
+                    if comparison_type.is_string_pattern() =>
+                {
+                    Ok(Self::eval_str_pattern(comparison_type, s, pattern))
+                }
+                (StrValue(s), comparison_type, StrValueArray(patterns))
+                    if comparison_type.is_string_pattern() =>
+                {
+                    Ok(patterns
+                        .iter()
+                        .any(|pattern| Self::eval_str_pattern(comparison_type, s, pattern)))
+                }
                 _ => Err(types::InterpreterError {
                     error_type: types::InterpreterErrorType::InvalidComparison,
                     metadata: comparison.metadata.clone(),
@@ -176,7 +206,7 @@ where
     }
 
     fn execute(&self, input: inputs::BackendInput) -> Result<super::BackendOutput<O>, Self::Error> {
-        let ctx: types::Context = input.into();
+        let ctx = types::Context::from_input(input, self.program.timezone);
         Self::eval_program(&self.program, &ctx)
     }
 }
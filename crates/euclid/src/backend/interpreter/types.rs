@@ -1,5 +1,6 @@
 use std::{collections::HashMap, fmt, ops::Deref, string::ToString};
 
+use common_utils::types::MinorUnit;
 use serde::Serialize;
 
 use crate::{backend::inputs, frontend::ast::ValueType, types::EuclidKey};
@@ -35,8 +36,9 @@ impl Deref for Context {
     }
 }
 
-impl From<inputs::BackendInput> for Context {
-    fn from(input: inputs::BackendInput) -> Self {
+impl Context {
+    pub fn from_input(input: inputs::BackendInput, timezone: Option<chrono_tz::Tz>) -> Self {
+        let payment_time = input.payment.get_payment_time(timezone);
         let ctx = HashMap::<String, Option<ValueType>>::from_iter([
             (
                 EuclidKey::PaymentMethod.to_string(),
@@ -74,6 +76,111 @@ impl From<inputs::BackendInput> for Context {
                 EuclidKey::PaymentCurrency.to_string(),
                 Some(ValueType::EnumVariant(input.payment.currency.to_string())),
             ),
+            (
+                EuclidKey::PaymentTimeOfDay.to_string(),
+                payment_time
+                    .as_ref()
+                    .map(|payment_time| ValueType::Number(payment_time.time_of_day)),
+            ),
+            (
+                EuclidKey::PaymentDayOfWeek.to_string(),
+                payment_time.as_ref().map(|payment_time| {
+                    ValueType::EnumVariant(payment_time.day_of_week.to_string())
+                }),
+            ),
+            (
+                EuclidKey::PaymentDate.to_string(),
+                payment_time.map(|payment_time| ValueType::Number(payment_time.date)),
+            ),
+            (
+                EuclidKey::CustomerAttemptCount.to_string(),
+                input
+                    .velocity
+                    .customer_attempt_count
+                    .map(|count| ValueType::Number(MinorUnit::new(count))),
+            ),
+            (
+                EuclidKey::CardFailureCount.to_string(),
+                input
+                    .velocity
+                    .card_failure_count
+                    .map(|count| ValueType::Number(MinorUnit::new(count))),
+            ),
+            (
+                EuclidKey::CustomerTransactedAmount.to_string(),
+                input
+                    .velocity
+                    .customer_transacted_amount
+                    .map(ValueType::Number),
+            ),
+            (
+                EuclidKey::IssuerName.to_string(),
+                input
+                    .issuer_data
+                    .as_ref()
+                    .and_then(|issuer| issuer.name.clone())
+                    .map(ValueType::StrValue),
+            ),
+            (
+                EuclidKey::IssuerCountry.to_string(),
+                input
+                    .issuer_data
+                    .as_ref()
+                    .and_then(|issuer| issuer.country)
+                    .map(|country| ValueType::EnumVariant(country.to_string())),
+            ),
+            (
+                EuclidKey::CustomerDevicePlatform.to_string(),
+                input
+                    .customer_device_data
+                    .as_ref()
+                    .and_then(|device| device.platform.as_ref())
+                    .map(|platform| ValueType::EnumVariant(platform.to_string())),
+            ),
+            (
+                EuclidKey::CustomerDeviceType.to_string(),
+                input
+                    .customer_device_data
+                    .as_ref()
+                    .and_then(|device| device.device_type.as_ref())
+                    .map(|device_type| ValueType::EnumVariant(device_type.to_string())),
+            ),
+            (
+                EuclidKey::CustomerDeviceDisplaySize.to_string(),
+                input
+                    .customer_device_data
+                    .as_ref()
+                    .and_then(|device| device.display_size.as_ref())
+                    .map(|display_size| ValueType::EnumVariant(display_size.to_string())),
+            ),
+            (
+                EuclidKey::AcquirerCountry.to_string(),
+                input
+                    .acquirer_data
+                    .as_ref()
+                    .and_then(|acquirer| acquirer.country)
+                    .map(|country| ValueType::EnumVariant(country.to_string())),
+            ),
+            (
+                EuclidKey::AcquirerFraudRate.to_string(),
+                input
+                    .acquirer_data
+                    .as_ref()
+                    .and_then(|acquirer| acquirer.fraud_rate)
+                    .map(|fraud_rate| ValueType::Number(MinorUnit::new(fraud_rate))),
+            ),
+            (
+                EuclidKey::CardBin.to_string(),
+                input.payment.card_bin.map(ValueType::StrValue),
+            ),
+            (
+                EuclidKey::BusinessLabel.to_string(),
+                input.payment.business_label.map(ValueType::StrValue),
+            ),
+            (
+                EuclidKey::CustomerEmail.to_string(),
+                input.payment.customer_email.map(ValueType::StrValue),
+            ),
         ]);
 
         Self(ctx)
@@ -2,6 +2,7 @@ pub mod types;
 
 use std::fmt::Debug;
 
+use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -11,19 +12,87 @@ use crate::{
         dir::{self, EuclidDirFilter},
         vir,
     },
+    types::{self as euclid_types, EuclidValue},
 };
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct VirInterpreterBackend<O> {
     program: vir::ValuedProgram<O>,
+    /// The `matches_regex` patterns of the program, compiled once when the backend is created.
+    /// Patterns missing here are compiled when the program is executed.
+    #[serde(skip)]
+    compiled_regexes: FxHashMap<String, regex::Regex>,
 }
 
 impl<O> VirInterpreterBackend<O>
 where
     O: Clone,
 {
+    fn collect_regexes(
+        stmts: &[vir::ValuedIfStatement],
+        compiled_regexes: &mut FxHashMap<String, regex::Regex>,
+    ) {
+        for stmt in stmts {
+            let patterns = stmt
+                .condition
+                .iter()
+                .flat_map(|comp| comp.values.iter())
+                .filter_map(|value| match value {
+                    EuclidValue::Metadata(metadata_value) => {
+                        Some((&metadata_value.value, &metadata_value.refinement))
+                    }
+                    _ => value
+                        .get_str_value()
+                        .map(|str_value| (&str_value.value, &str_value.refinement)),
+                })
+                .filter(|(_, refinement)| {
+                    matches!(
+                        refinement,
+                        Some(euclid_types::StrValueRefinement::MatchesRegex)
+                    )
+                });
+
+            for (pattern, _) in patterns {
+                if !compiled_regexes.contains_key(pattern) {
+                    if let Ok(regex) = euclid_types::compile_anchored_regex(pattern) {
+                        compiled_regexes.insert(pattern.clone(), regex);
+                    }
+                }
+            }
+
+            if let Some(nested) = &stmt.nested {
+                Self::collect_regexes(nested, compiled_regexes);
+            }
+        }
+    }
+
+    fn statements_use_key(
+        stmts: &[vir::ValuedIfStatement],
+        predicate: &impl Fn(&euclid_types::EuclidKey) -> bool,
+    ) -> bool {
+        stmts.iter().any(|stmt| {
+            stmt.condition
+                .iter()
+                .flat_map(|comp| comp.values.iter())
+                .any(|value| predicate(&value.get_key()))
+                || stmt
+                    .nested
+                    .as_ref()
+                    .is_some_and(|nested| Self::statements_use_key(nested, predicate))
+        })
+    }
+
+    /// Whether any of the rules of the program compare a key matching the predicate, so that
+    /// inputs which are expensive to compute are only computed for the programs using them
+    pub fn uses_key(&self, predicate: impl Fn(&euclid_types::EuclidKey) -> bool) -> bool {
+        self.program
+            .rules
+            .iter()
+            .any(|rule| Self::statements_use_key(&rule.statements, &predicate))
+    }
+
     #[inline]
-    fn eval_comparison(comp: &vir::ValuedComparison, ctx: &types::Context) -> bool {
+    fn eval_comparison(comp: &vir::ValuedComparison, ctx: &types::Context<'_>) -> bool {
         match &comp.logic {
             vir::ValuedComparisonLogic::PositiveDisjunction => {
                 comp.values.iter().any(|v| ctx.check_presence(v))
@@ -35,11 +104,11 @@ where
     }
 
     #[inline]
-    fn eval_condition(cond: &vir::ValuedIfCondition, ctx: &types::Context) -> bool {
+    fn eval_condition(cond: &vir::ValuedIfCondition, ctx: &types::Context<'_>) -> bool {
         cond.iter().all(|comp| Self::eval_comparison(comp, ctx))
     }
 
-    fn eval_statement(stmt: &vir::ValuedIfStatement, ctx: &types::Context) -> bool {
+    fn eval_statement(stmt: &vir::ValuedIfStatement, ctx: &types::Context<'_>) -> bool {
         if Self::eval_condition(&stmt.condition, ctx) {
             {
                 stmt.nested.as_ref().map_or(true, |nested_stmts| {
@@ -51,7 +120,7 @@ where
         }
     }
 
-    fn eval_rule(rule: &vir::ValuedRule<O>, ctx: &types::Context) -> bool {
+    fn eval_rule(rule: &vir::ValuedRule<O>, ctx: &types::Context<'_>) -> bool {
         rule.statements
             .iter()
             .any(|stmt| Self::eval_statement(stmt, ctx))
@@ -59,7 +128,7 @@ where
 
     fn eval_program(
         program: &vir::ValuedProgram<O>,
-        ctx: &types::Context,
+        ctx: &types::Context<'_>,
     ) -> backend::BackendOutput<O> {
         program
             .rules
@@ -76,6 +145,127 @@ where
                 },
             )
     }
+
+    fn trace_comparison(
+        comp: &vir::ValuedComparison,
+        ctx: &types::Context<'_>,
+    ) -> backend::ComparisonTrace {
+        backend::ComparisonTrace {
+            key: comp
+                .values
+                .first()
+                .map(|value| value.get_key().to_string())
+                .unwrap_or_default(),
+            logic: comp.logic.clone(),
+            values: comp.values.clone(),
+            matched: Self::eval_comparison(comp, ctx),
+        }
+    }
+
+    fn trace_statement(
+        stmt: &vir::ValuedIfStatement,
+        ctx: &types::Context<'_>,
+    ) -> backend::StatementTrace {
+        let mut comparisons = Vec::new();
+        let mut condition_matched = true;
+
+        for comp in &stmt.condition {
+            let comparison = Self::trace_comparison(comp, ctx);
+            condition_matched = comparison.matched;
+            comparisons.push(comparison);
+
+            if !condition_matched {
+                break;
+            }
+        }
+
+        let nested = stmt
+            .nested
+            .as_ref()
+            .filter(|_| condition_matched)
+            .map(|nested_stmts| Self::trace_statements(nested_stmts, ctx));
+        let matched = condition_matched
+            && nested
+                .as_ref()
+                .map_or(true, |nested_stmts| nested_stmts.iter().any(|s| s.matched));
+
+        backend::StatementTrace {
+            matched,
+            comparisons,
+            nested,
+        }
+    }
+
+    fn trace_statements(
+        stmts: &[vir::ValuedIfStatement],
+        ctx: &types::Context<'_>,
+    ) -> Vec<backend::StatementTrace> {
+        let mut statements = Vec::new();
+
+        for stmt in stmts {
+            let statement = Self::trace_statement(stmt, ctx);
+            let matched = statement.matched;
+            statements.push(statement);
+
+            if matched {
+                break;
+            }
+        }
+
+        statements
+    }
+
+    fn trace_rule(rule: &vir::ValuedRule<O>, ctx: &types::Context<'_>) -> backend::RuleTrace {
+        let statements = Self::trace_statements(&rule.statements, ctx);
+
+        backend::RuleTrace {
+            rule_name: rule.name.clone(),
+            matched: statements.iter().any(|stmt| stmt.matched),
+            statements,
+        }
+    }
+
+    fn trace_program(
+        program: &vir::ValuedProgram<O>,
+        ctx: &types::Context<'_>,
+    ) -> (backend::BackendOutput<O>, backend::ProgramTrace) {
+        let mut rules = Vec::new();
+
+        for rule in &program.rules {
+            let rule_trace = Self::trace_rule(rule, ctx);
+            let matched = rule_trace.matched;
+            rules.push(rule_trace);
+
+            if matched {
+                return (
+                    backend::BackendOutput {
+                        connector_selection: rule.connector_selection.clone(),
+                        rule_name: Some(rule.name.clone()),
+                    },
+                    backend::ProgramTrace { rules },
+                );
+            }
+        }
+
+        (
+            backend::BackendOutput {
+                connector_selection: program.default_selection.clone(),
+                rule_name: None,
+            },
+            backend::ProgramTrace { rules },
+        )
+    }
+
+    /// Executes the program against the input like [`EuclidBackend::execute`], also returning a
+    /// trace of the rules and comparisons which were evaluated to arrive at the output
+    pub fn execute_with_trace(
+        &self,
+        input: inputs::BackendInput,
+    ) -> Result<(backend::BackendOutput<O>, backend::ProgramTrace), types::VirInterpreterError>
+    {
+        let ctx = types::Context::from_input(input, self.program.timezone, &self.compiled_regexes);
+        Ok(Self::trace_program(&self.program, &ctx))
+    }
 }
 
 impl<O> EuclidBackend<O> for VirInterpreterBackend<O>
@@ -91,8 +281,14 @@ where
         let vir_program = dir::lowering::lower_program(dir_program)
             .map_err(types::VirInterpreterError::LoweringError)?;
 
+        let mut compiled_regexes = FxHashMap::default();
+        for rule in &vir_program.rules {
+            Self::collect_regexes(&rule.statements, &mut compiled_regexes);
+        }
+
         Ok(Self {
             program: vir_program,
+            compiled_regexes,
         })
     }
 
@@ -100,7 +296,7 @@ where
         &self,
         input: inputs::BackendInput,
     ) -> Result<backend::BackendOutput<O>, Self::Error> {
-        let ctx = types::Context::from_input(input);
+        let ctx = types::Context::from_input(input, self.program.timezone, &self.compiled_regexes);
         Ok(Self::eval_program(&self.program, &ctx))
     }
 }
@@ -142,6 +338,8 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                customer_email: None,
+                created_at: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -153,6 +351,10 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: None,
+            customer_device_data: None,
+            acquirer_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -182,6 +384,8 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                customer_email: None,
+                created_at: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -193,6 +397,10 @@ mod test {
                 mandate_type: None,
                 payment_type: Some(enums::PaymentType::SetupMandate),
             },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: None,
+            customer_device_data: None,
+            acquirer_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -223,6 +431,8 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                customer_email: None,
+                created_at: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -234,6 +444,10 @@ mod test {
                 mandate_type: None,
                 payment_type: Some(enums::PaymentType::PptMandate),
             },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: None,
+            customer_device_data: None,
+            acquirer_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -264,6 +478,8 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                customer_email: None,
+                created_at: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -275,6 +491,10 @@ mod test {
                 mandate_type: Some(enums::MandateType::SingleUse),
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: None,
+            customer_device_data: None,
+            acquirer_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -305,6 +525,8 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                customer_email: None,
+                created_at: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -316,6 +538,10 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: None,
+            customer_device_data: None,
+            acquirer_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -346,6 +572,8 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                customer_email: None,
+                created_at: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -357,6 +585,10 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: None,
+            customer_device_data: None,
+            acquirer_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -387,6 +619,8 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                customer_email: None,
+                created_at: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -398,6 +632,10 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: None,
+            customer_device_data: None,
+            acquirer_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -428,6 +666,8 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                customer_email: None,
+                created_at: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -439,6 +679,10 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: None,
+            customer_device_data: None,
+            acquirer_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -469,6 +713,8 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: Some(enums::SetupFutureUsage::OffSession),
+                customer_email: None,
+                created_at: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -480,6 +726,10 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: None,
+            customer_device_data: None,
+            acquirer_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -512,6 +762,8 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                customer_email: None,
+                created_at: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -523,6 +775,10 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: None,
+            customer_device_data: None,
+            acquirer_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -553,6 +809,8 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                customer_email: None,
+                created_at: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -564,6 +822,10 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: None,
+            customer_device_data: None,
+            acquirer_data: None,
         };
         let mut inp_equal = inp_greater.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -603,6 +865,8 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                customer_email: None,
+                created_at: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -614,6 +878,10 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: None,
+            customer_device_data: None,
+            acquirer_data: None,
         };
         let mut inp_equal = inp_lower.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -629,4 +897,434 @@ mod test {
             "rule_1"
         );
     }
+
+    #[test]
+    fn test_payment_time_keys() {
+        let program_str = r#"
+        timezone: "Asia/Kolkata"
+        default: ["stripe", "adyen"]
+
+        weekend: ["stripe"]
+        {
+           payment_day_of_week = (saturday, sunday)
+        }
+
+        night: ["adyen"]
+        {
+           payment_time_of_day = (>= 22:00, < 06:00)
+        }
+
+        maintenance_window: ["adyen"]
+        {
+           payment_date >= 2026-11-01 & payment_date <= 2026-11-05
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+                customer_email: None,
+                created_at: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(enums::PaymentMethodType::Affirm),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: None,
+            customer_device_data: None,
+            acquirer_data: None,
+        };
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+
+        let rule_name_at = |created_at: time::PrimitiveDateTime| {
+            let mut inp = inp.clone();
+            inp.payment.created_at = Some(created_at);
+            backend.execute(inp).expect("Execution").rule_name
+        };
+
+        // Friday 20:00 UTC is Saturday 01:30 in the timezone of the program
+        assert_eq!(
+            rule_name_at(time::macros::datetime!(2026-10-16 20:00)).as_deref(),
+            Some("weekend")
+        );
+        assert_eq!(
+            rule_name_at(time::macros::datetime!(2026-10-15 18:00)).as_deref(),
+            Some("night")
+        );
+        assert_eq!(
+            rule_name_at(time::macros::datetime!(2026-11-03 06:30)).as_deref(),
+            Some("maintenance_window")
+        );
+        assert_eq!(
+            rule_name_at(time::macros::datetime!(2026-11-10 06:30)),
+            None
+        );
+        assert_eq!(
+            backend.execute(inp.clone()).expect("Execution").rule_name,
+            None
+        );
+    }
+
+    #[test]
+    fn test_payment_time_keys_daylight_saving() {
+        let program_str = r#"
+        timezone: "Europe/London"
+        default: ["stripe", "adyen"]
+
+        evening: ["adyen"]
+        {
+           payment_time_of_day >= 22:00
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+                customer_email: None,
+                created_at: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(enums::PaymentMethodType::Affirm),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: None,
+            customer_device_data: None,
+            acquirer_data: None,
+        };
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+
+        let rule_name_at = |created_at: time::PrimitiveDateTime| {
+            let mut inp = inp.clone();
+            inp.payment.created_at = Some(created_at);
+            backend.execute(inp).expect("Execution").rule_name
+        };
+
+        // 21:30 UTC is 22:30 in London during summer time and 21:30 in winter
+        assert_eq!(
+            rule_name_at(time::macros::datetime!(2026-07-01 21:30)).as_deref(),
+            Some("evening")
+        );
+        assert_eq!(
+            rule_name_at(time::macros::datetime!(2026-12-01 21:30)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_string_pattern_operators() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        invoices: ["stripe"]
+        {
+           "merchant_order_ref" starts_with ("INV-", "BILL-") {
+               "merchant_order_ref" ends_with "-EU"
+           }
+        }
+
+        bin_range: ["adyen"]
+        {
+           card_bin starts_with "4000"
+        }
+
+        listed_bins: ["stripe"]
+        {
+           card_bin in_list ("424242", "555555")
+        }
+
+        order_pattern: ["adyen"]
+        {
+           "merchant_order_ref" matches_regex "ORD-[0-9]{4}"
+        }
+
+        corporate_customers: ["stripe"]
+        {
+           customer_email ends_with ("@example.com", "@example.org")
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+                customer_email: None,
+                created_at: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: Some(enums::PaymentMethodType::Credit),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: None,
+            customer_device_data: None,
+            acquirer_data: None,
+        };
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+
+        let rule_name_for = |card_bin: Option<&str>, order_ref: Option<&str>| {
+            let mut inp = inp.clone();
+            inp.payment.card_bin = card_bin.map(ToString::to_string);
+            inp.metadata = order_ref.map(|order_ref| {
+                FxHashMap::from_iter([("merchant_order_ref".to_string(), order_ref.to_string())])
+            });
+            backend.execute(inp).expect("Execution").rule_name
+        };
+
+        assert_eq!(
+            rule_name_for(None, Some("BILL-1024-EU")).as_deref(),
+            Some("invoices")
+        );
+        assert_eq!(
+            rule_name_for(Some("400012"), Some("BILL-1024-US")).as_deref(),
+            Some("bin_range")
+        );
+        assert_eq!(
+            rule_name_for(Some("555555"), None).as_deref(),
+            Some("listed_bins")
+        );
+        assert_eq!(
+            rule_name_for(None, Some("ORD-1024")).as_deref(),
+            Some("order_pattern")
+        );
+        // The regex has to match the entire value
+        assert_eq!(rule_name_for(Some("555556"), Some("ORD-10245")), None);
+
+        let mut inp_email = inp.clone();
+        inp_email.payment.customer_email = Some("john@example.org".to_string());
+        assert_eq!(
+            backend
+                .execute(inp_email)
+                .expect("Execution")
+                .rule_name
+                .as_deref(),
+            Some("corporate_customers")
+        );
+    }
+
+    #[test]
+    fn test_velocity_keys() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        repeated_card_failures: ["adyen"]
+        {
+           card_failure_count >= 3
+        }
+
+        high_spender: ["stripe"]
+        {
+           customer_attempt_count < 5 & customer_transacted_amount > 100000
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+                customer_email: None,
+                created_at: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: Some(enums::PaymentMethodType::Credit),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: None,
+            customer_device_data: None,
+            acquirer_data: None,
+        };
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        assert!(backend.uses_key(euclid_types::EuclidKey::is_velocity_key));
+
+        let rule_name_for = |velocity: inputs::VelocityInput| {
+            let mut inp = inp.clone();
+            inp.velocity = velocity;
+            backend.execute(inp).expect("Execution").rule_name
+        };
+
+        assert_eq!(
+            rule_name_for(inputs::VelocityInput {
+                card_failure_count: Some(3),
+                ..Default::default()
+            })
+            .as_deref(),
+            Some("repeated_card_failures")
+        );
+        assert_eq!(
+            rule_name_for(inputs::VelocityInput {
+                customer_attempt_count: Some(2),
+                card_failure_count: Some(1),
+                customer_transacted_amount: Some(MinorUnit::new(250000)),
+            })
+            .as_deref(),
+            Some("high_spender")
+        );
+        // Velocity which could not be computed does not match any comparison
+        assert_eq!(rule_name_for(inputs::VelocityInput::default()), None);
+    }
+
+    #[test]
+    fn test_execution_trace() {
+        let program_str = r#"
+        default: ["stripe"]
+
+        domestic_web: ["adyen"]
+        {
+           customer_device_platform = web & issuer_country = India
+        }
+
+        risky_acquirer: ["checkout"]
+        {
+           acquirer_fraud_rate > 5
+           {
+              amount < 1000
+              issuer_name = "hdfc"
+           }
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(5000),
+                card_bin: None,
+                currency: enums::Currency::INR,
+                authentication_type: None,
+                capture_method: None,
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+                customer_email: None,
+                created_at: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: Some(enums::PaymentMethodType::Credit),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            velocity: inputs::VelocityInput::default(),
+            issuer_data: Some(inputs::IssuerDataInput {
+                name: Some("hdfc".to_string()),
+                country: Some(enums::Country::India),
+            }),
+            customer_device_data: Some(inputs::CustomerDeviceDataInput {
+                platform: Some(dir::enums::CustomerDevicePlatform::Android),
+                device_type: None,
+                display_size: None,
+            }),
+            acquirer_data: Some(inputs::AcquirerDataInput {
+                country: None,
+                fraud_rate: Some(8),
+            }),
+        };
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+
+        let (output, trace) = backend.execute_with_trace(inp.clone()).expect("Execution");
+        let expected = backend.execute(inp).expect("Execution");
+        assert_eq!(output.rule_name, expected.rule_name);
+        assert_eq!(output.rule_name.as_deref(), Some("risky_acquirer"));
+
+        let matched_rules = trace
+            .rules
+            .iter()
+            .map(|rule| (rule.rule_name.as_str(), rule.matched))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            matched_rules,
+            vec![("domestic_web", false), ("risky_acquirer", true)]
+        );
+
+        // The device platform did not match, so the issuer country was not evaluated
+        let domestic_web_comparisons = trace
+            .rules
+            .first()
+            .and_then(|rule| rule.statements.first())
+            .map(|stmt| {
+                stmt.comparisons
+                    .iter()
+                    .map(|comp| (comp.key.as_str(), comp.matched))
+                    .collect::<Vec<_>>()
+            })
+            .expect("Statement");
+        assert_eq!(
+            domestic_web_comparisons,
+            vec![("customer_device_platform", false)]
+        );
+
+        // Only the second of the nested statements matched
+        let nested_matches = trace
+            .rules
+            .get(1)
+            .and_then(|rule| rule.statements.first())
+            .and_then(|stmt| stmt.nested.as_ref())
+            .map(|nested| nested.iter().map(|stmt| stmt.matched).collect::<Vec<_>>())
+            .expect("Nested statements");
+        assert_eq!(nested_matches, vec![false, true]);
+    }
 }
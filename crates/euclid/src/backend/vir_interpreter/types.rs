@@ -1,3 +1,4 @@
+use common_utils::types::MinorUnit;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
@@ -12,18 +13,42 @@ pub enum VirInterpreterError {
     LoweringError(dssa::types::AnalysisError),
 }
 
-pub struct Context {
+pub struct Context<'a> {
     atomic_values: FxHashSet<EuclidValue>,
     numeric_values: FxHashMap<EuclidKey, EuclidValue>,
+    str_values: FxHashMap<EuclidKey, String>,
+    metadata_values: FxHashMap<String, String>,
+    compiled_regexes: &'a FxHashMap<String, regex::Regex>,
 }
 
-impl Context {
+impl<'a> Context<'a> {
     pub fn check_presence(&self, value: &EuclidValue) -> bool {
         let key = value.get_key();
 
         match key.key_type() {
-            types::DataType::MetadataValue => self.atomic_values.contains(value),
-            types::DataType::StrValue => self.atomic_values.contains(value),
+            types::DataType::MetadataValue => match value {
+                EuclidValue::Metadata(program_value) if program_value.refinement.is_some() => self
+                    .metadata_values
+                    .get(&program_value.key)
+                    .is_some_and(|ctx_value| {
+                        program_value.is_satisfied_by(
+                            ctx_value,
+                            self.compiled_regexes.get(&program_value.value),
+                        )
+                    }),
+                _ => self.atomic_values.contains(value),
+            },
+            types::DataType::StrValue => match value.get_str_value() {
+                Some(program_value) if program_value.refinement.is_some() => {
+                    self.str_values.get(&key).is_some_and(|ctx_value| {
+                        program_value.is_satisfied_by(
+                            ctx_value,
+                            self.compiled_regexes.get(&program_value.value),
+                        )
+                    })
+                }
+                _ => self.atomic_values.contains(value),
+            },
             types::DataType::EnumVariant => self.atomic_values.contains(value),
             types::DataType::Number => {
                 let ctx_num_value = self
@@ -50,11 +75,20 @@ impl Context {
         }
     }
 
-    pub fn from_input(input: BackendInput) -> Self {
+    pub fn from_input(
+        input: BackendInput,
+        timezone: Option<chrono_tz::Tz>,
+        compiled_regexes: &'a FxHashMap<String, regex::Regex>,
+    ) -> Self {
+        let payment_time = input.payment.get_payment_time(timezone);
         let payment = input.payment;
         let payment_method = input.payment_method;
         let meta_data = input.metadata;
         let payment_mandate = input.mandate;
+        let velocity = input.velocity;
+        let issuer_data = input.issuer_data;
+        let customer_device_data = input.customer_device_data;
+        let acquirer_data = input.acquirer_data;
 
         let mut enum_values: FxHashSet<EuclidValue> =
             FxHashSet::from_iter([EuclidValue::PaymentCurrency(payment.currency)]);
@@ -67,10 +101,13 @@ impl Context {
             enum_values.insert(EuclidValue::PaymentMethodType(pmt));
         }
 
-        if let Some(met) = meta_data {
-            for (key, value) in met.into_iter() {
-                enum_values.insert(EuclidValue::Metadata(MetadataValue { key, value }));
-            }
+        let metadata_values = meta_data.unwrap_or_default();
+        for (key, value) in metadata_values.iter() {
+            enum_values.insert(EuclidValue::Metadata(MetadataValue {
+                key: key.clone(),
+                value: value.clone(),
+                refinement: None,
+            }));
         }
 
         if let Some(card_network) = payment_method.card_network {
@@ -92,12 +129,26 @@ impl Context {
         if let Some(country) = payment.billing_country {
             enum_values.insert(EuclidValue::BillingCountry(country));
         }
+        let mut str_values: FxHashMap<EuclidKey, String> = FxHashMap::default();
         if let Some(card_bin) = payment.card_bin {
-            enum_values.insert(EuclidValue::CardBin(StrValue { value: card_bin }));
+            str_values.insert(EuclidKey::CardBin, card_bin.clone());
+            enum_values.insert(EuclidValue::CardBin(StrValue {
+                value: card_bin,
+                refinement: None,
+            }));
         }
         if let Some(business_label) = payment.business_label {
+            str_values.insert(EuclidKey::BusinessLabel, business_label.clone());
             enum_values.insert(EuclidValue::BusinessLabel(StrValue {
                 value: business_label,
+                refinement: None,
+            }));
+        }
+        if let Some(customer_email) = payment.customer_email {
+            str_values.insert(EuclidKey::CustomerEmail, customer_email.clone());
+            enum_values.insert(EuclidValue::CustomerEmail(StrValue {
+                value: customer_email,
+                refinement: None,
             }));
         }
         if let Some(setup_future_usage) = payment.setup_future_usage {
@@ -113,7 +164,31 @@ impl Context {
             enum_values.insert(EuclidValue::MandateAcceptanceType(mandate_acceptance_type));
         }
 
-        let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
+        if let Some(issuer_data) = issuer_data {
+            if let Some(name) = issuer_data.name {
+                str_values.insert(EuclidKey::IssuerName, name.clone());
+                enum_values.insert(EuclidValue::IssuerName(StrValue {
+                    value: name,
+                    refinement: None,
+                }));
+            }
+            if let Some(country) = issuer_data.country {
+                enum_values.insert(EuclidValue::IssuerCountry(country));
+            }
+        }
+        if let Some(device_data) = customer_device_data {
+            if let Some(platform) = device_data.platform {
+                enum_values.insert(EuclidValue::CustomerDevicePlatform(platform));
+            }
+            if let Some(device_type) = device_data.device_type {
+                enum_values.insert(EuclidValue::CustomerDeviceType(device_type));
+            }
+            if let Some(display_size) = device_data.display_size {
+                enum_values.insert(EuclidValue::CustomerDeviceDisplaySize(display_size));
+            }
+        }
+
+        let mut numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
                 number: payment.amount,
@@ -121,9 +196,73 @@ impl Context {
             }),
         )]);
 
+        if let Some(payment_time) = payment_time {
+            enum_values.insert(EuclidValue::PaymentDayOfWeek(payment_time.day_of_week));
+            numeric_values.insert(
+                EuclidKey::PaymentTimeOfDay,
+                EuclidValue::PaymentTimeOfDay(types::NumValue {
+                    number: payment_time.time_of_day,
+                    refinement: None,
+                }),
+            );
+            numeric_values.insert(
+                EuclidKey::PaymentDate,
+                EuclidValue::PaymentDate(types::NumValue {
+                    number: payment_time.date,
+                    refinement: None,
+                }),
+            );
+        }
+
+        if let Some(count) = velocity.customer_attempt_count {
+            numeric_values.insert(
+                EuclidKey::CustomerAttemptCount,
+                EuclidValue::CustomerAttemptCount(types::NumValue {
+                    number: MinorUnit::new(count),
+                    refinement: None,
+                }),
+            );
+        }
+        if let Some(count) = velocity.card_failure_count {
+            numeric_values.insert(
+                EuclidKey::CardFailureCount,
+                EuclidValue::CardFailureCount(types::NumValue {
+                    number: MinorUnit::new(count),
+                    refinement: None,
+                }),
+            );
+        }
+        if let Some(amount) = velocity.customer_transacted_amount {
+            numeric_values.insert(
+                EuclidKey::CustomerTransactedAmount,
+                EuclidValue::CustomerTransactedAmount(types::NumValue {
+                    number: amount,
+                    refinement: None,
+                }),
+            );
+        }
+
+        if let Some(acquirer_data) = acquirer_data {
+            if let Some(country) = acquirer_data.country {
+                enum_values.insert(EuclidValue::AcquirerCountry(country));
+            }
+            if let Some(fraud_rate) = acquirer_data.fraud_rate {
+                numeric_values.insert(
+                    EuclidKey::AcquirerFraudRate,
+                    EuclidValue::AcquirerFraudRate(types::NumValue {
+                        number: MinorUnit::new(fraud_rate),
+                        refinement: None,
+                    }),
+                );
+            }
+        }
+
         Self {
             atomic_values: enum_values,
             numeric_values,
+            str_values,
+            metadata_values,
+            compiled_regexes,
         }
     }
 }
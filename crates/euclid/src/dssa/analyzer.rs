@@ -18,7 +18,7 @@ use crate::{
         dir::{self, EuclidDirFilter},
         vir,
     },
-    types::{DataType, Metadata},
+    types::{DataType, Metadata, NumValueRefinement},
 };
 
 /// Analyses conflicting assertions on the same key in a conjunctive context.
@@ -101,6 +101,71 @@ pub fn analyze_exhaustive_negations(
     Ok(())
 }
 
+/// Analyses number assertions on the same key in a conjunctive context that cannot be satisfied
+/// by any single number.
+///
+/// For example,
+/// ```notrust
+/// payment_time_of_day >= 22:00 && ... && payment_time_of_day < 06:00
+/// ```notrust
+/// This is a time window that no payment can fall in, windows spanning midnight need to be
+/// written as a disjunction like `payment_time_of_day = (>= 22:00, < 06:00)` instead.
+pub fn analyze_conflicting_number_ranges(
+    keywise_number_assertions: &FxHashMap<dir::DirKey, Vec<&dir::DirValue>>,
+    assertion_metadata: &FxHashMap<&dir::DirValue, &Metadata>,
+) -> Result<(), types::AnalysisError> {
+    for (key, values) in keywise_number_assertions {
+        let mut lower_bound = i64::MIN;
+        let mut upper_bound = i64::MAX;
+        let mut exact_value: Option<i64> = None;
+        let mut has_conflict = false;
+
+        for num_value in values.iter().filter_map(|val| val.get_num_value()) {
+            let number = num_value.number.get_amount_as_i64();
+            match num_value.refinement {
+                None => {
+                    has_conflict |= exact_value.is_some_and(|exact| exact != number);
+                    exact_value = Some(number);
+                }
+                Some(NumValueRefinement::GreaterThan) => {
+                    lower_bound = lower_bound.max(number.saturating_add(1))
+                }
+                Some(NumValueRefinement::GreaterThanEqual) => lower_bound = lower_bound.max(number),
+                Some(NumValueRefinement::LessThan) => {
+                    upper_bound = upper_bound.min(number.saturating_sub(1))
+                }
+                Some(NumValueRefinement::LessThanEqual) => upper_bound = upper_bound.min(number),
+                Some(NumValueRefinement::NotEqual) => {}
+            }
+        }
+
+        has_conflict |= lower_bound > upper_bound
+            || exact_value.is_some_and(|exact| exact < lower_bound || exact > upper_bound);
+
+        if has_conflict {
+            let err_type = types::AnalysisErrorType::ConflictingAssertions {
+                key: key.clone(),
+                values: values
+                    .iter()
+                    .map(|val| types::ValueData {
+                        value: (*val).clone(),
+                        metadata: assertion_metadata
+                            .get(val)
+                            .map(|meta| (*meta).clone())
+                            .unwrap_or_default(),
+                    })
+                    .collect(),
+            };
+
+            Err(types::AnalysisError {
+                error_type: err_type,
+                metadata: Default::default(),
+            })?;
+        }
+    }
+    Ok(())
+}
+
 fn analyze_negated_assertions(
     keywise_assertions: &FxHashMap<dir::DirKey, FxHashSet<&dir::DirValue>>,
     assertion_metadata: &FxHashMap<&dir::DirValue, &Metadata>,
@@ -151,6 +216,8 @@ fn perform_condition_analyses(
         FxHashMap::default();
     let mut keywise_negations: FxHashMap<dir::DirKey, FxHashSet<&dir::DirValue>> =
         FxHashMap::default();
+    let mut keywise_number_assertions: FxHashMap<dir::DirKey, Vec<&dir::DirValue>> =
+        FxHashMap::default();
 
     for ctx_val in context {
         let key = if let Some(k) = ctx_val.value.get_key() {
@@ -163,6 +230,14 @@ fn perform_condition_analyses(
             continue;
         }
 
+        if key.kind.is_payment_time_key() && matches!(key.kind.get_type(), DataType::Number) {
+            if let types::CtxValueKind::Assertion(val) = ctx_val.value {
+                keywise_number_assertions.entry(key).or_default().push(val);
+                assertion_metadata.insert(val, ctx_val.metadata);
+            }
+            continue;
+        }
+
         if !matches!(key.kind.get_type(), DataType::EnumVariant) {
             continue;
         }
@@ -194,6 +269,7 @@ fn perform_condition_analyses(
     }
 
     analyze_conflicting_assertions(&keywise_assertions, &assertion_metadata)?;
+    analyze_conflicting_number_ranges(&keywise_number_assertions, &assertion_metadata)?;
     analyze_exhaustive_negations(&keywise_negations, &keywise_negation_metadata)?;
     analyze_negated_assertions(
         &keywise_assertions,
@@ -205,7 +281,7 @@ fn perform_condition_analyses(
     Ok(())
 }
 
-fn perform_context_analyses(
+pub fn perform_context_analyses(
     context: &types::ConjunctiveContext<'_>,
     knowledge_graph: &ConstraintGraph<dir::DirValue>,
 ) -> Result<(), types::AnalysisError> {
@@ -295,6 +371,137 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_conflicting_time_window_detection() {
+        let program_str = r#"
+            default: ["stripe", "adyen"]
+
+            batch_window: ["adyen"]
+            {
+                payment_day_of_week = saturday {
+                    payment_time_of_day >= 22:00 & payment_time_of_day < 06:00
+                }
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let analysis_result = analyze(program, None);
+
+        if let Err(types::AnalysisError {
+            error_type: types::AnalysisErrorType::ConflictingAssertions { key, values },
+            ..
+        }) = analysis_result
+        {
+            assert!(
+                matches!(key.kind, dir::DirKeyKind::PaymentTimeOfDay),
+                "Key should be payment_time_of_day"
+            );
+            assert_eq!(values.len(), 2, "There should be 2 conflicting conditions");
+        } else {
+            panic!("Did not receive conflicting assertions error");
+        }
+
+        let program_str = r#"
+            default: ["stripe", "adyen"]
+
+            batch_window: ["adyen"]
+            {
+                payment_time_of_day = (>= 22:00, < 06:00) & payment_date >= 2026-11-01
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        assert!(analyze(program, None).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_time_literals_rejected() {
+        let parses_completely = |program_str: &str| {
+            ast::parser::program::<DummyOutput>(program_str)
+                .is_ok_and(|(rest, _)| rest.trim().is_empty())
+        };
+        let with_condition = |timezone: &str, condition: &str| {
+            format!(
+                r#"
+                timezone: "{timezone}"
+                default: ["stripe", "adyen"]
+
+                rule_1: ["adyen"]
+                {{
+                    {condition}
+                }}
+            "#
+            )
+        };
+
+        assert!(parses_completely(&with_condition(
+            "America/New_York",
+            "payment_time_of_day = (>= 23:59, < 00:00) & payment_date = 2028-02-29"
+        )));
+
+        for (timezone, condition) in [
+            ("UTC", "payment_time_of_day >= 25:00"),
+            ("UTC", "payment_time_of_day >= 22:99"),
+            ("UTC", "payment_date = 2026-13-01"),
+            ("UTC", "payment_date = 2026-02-30"),
+            ("UTC", "amount = 06:30"),
+            ("UTC", "amount >= 2026-11-01"),
+            ("+05:30", "payment_time_of_day >= 22:00"),
+            ("Mars/Olympus_Mons", "payment_time_of_day >= 22:00"),
+        ] {
+            assert!(
+                !parses_completely(&with_condition(timezone, condition)),
+                "Expected '{condition}' with timezone '{timezone}' to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_regex_pattern_validation() {
+        let analyze_condition = |condition: &str| {
+            let program_str = format!(
+                r#"
+                default: ["stripe", "adyen"]
+
+                rule_1: ["adyen"]
+                {{
+                    {condition}
+                }}
+            "#
+            );
+            let (_, program) = ast::parser::program::<DummyOutput>(&program_str).expect("Program");
+            analyze(program, None)
+        };
+
+        assert!(analyze_condition(r#""order_ref" matches_regex "ORD-[0-9]{4}""#).is_ok());
+        assert!(analyze_condition(r#"card_bin starts_with "4000""#).is_ok());
+
+        for condition in [
+            r#""order_ref" matches_regex "ORD-[0-9""#,
+            r#""order_ref" matches_regex "(a{1000}){1000}""#,
+            r#"card_bin starts_with "4000000""#,
+        ] {
+            assert!(
+                matches!(
+                    analyze_condition(condition),
+                    Err(types::AnalysisError {
+                        error_type: types::AnalysisErrorType::InvalidValue { .. },
+                        ..
+                    })
+                ),
+                "Expected an invalid value error for {condition}"
+            );
+        }
+
+        assert!(matches!(
+            analyze_condition(r#"amount starts_with 100"#),
+            Err(types::AnalysisError {
+                error_type: types::AnalysisErrorType::InvalidComparison { .. },
+                ..
+            })
+        ));
+    }
+
     #[test]
     fn test_exhaustive_negation_detection() {
         let program_str = r#"
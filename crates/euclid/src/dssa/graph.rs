@@ -58,6 +58,7 @@ impl cgraph::NodeViz for dir::DirValue {
             Self::CaptureMethod(cm) => cm.to_string(),
             Self::BusinessCountry(bc) => bc.to_string(),
             Self::BillingCountry(bc) => bc.to_string(),
+            Self::CustomerEmail(email) => email.value.clone(),
             Self::Connector(conn) => conn.connector.to_string(),
             Self::MetaData(mv) => format!("[{} = {}]", mv.key, mv.value),
             Self::MandateAcceptanceType(mat) => mat.to_string(),
@@ -82,6 +83,12 @@ impl cgraph::NodeViz for dir::DirValue {
             }
             Self::AcquirerCountry(acquirer_country) => acquirer_country.to_string(),
             Self::AcquirerFraudRate(acquirer_fraud_rate) => acquirer_fraud_rate.number.to_string(),
+            Self::PaymentTimeOfDay(time_of_day) => time_of_day.number.to_string(),
+            Self::PaymentDayOfWeek(day_of_week) => day_of_week.to_string(),
+            Self::PaymentDate(date) => date.number.to_string(),
+            Self::CustomerAttemptCount(count) => count.number.to_string(),
+            Self::CardFailureCount(count) => count.number.to_string(),
+            Self::CustomerTransactedAmount(amount) => amount.number.to_string(),
         }
     }
 }
@@ -0,0 +1,706 @@
+//! Rule level analysis of programs in the Euclid Rule DSL
+//!
+//! Unlike [`analyze`](crate::dssa::analyzer::analyze), which rejects a program on the first
+//! problem found, this reports the findings for each rule of the program. The rules of a program
+//! are evaluated in order and the first rule that matches is selected, so a rule is never selected
+//! if its conditions can never be satisfied or if earlier rules match whenever it matches. The
+//! payments matched by two rules are also reported when the rules have different outputs and
+//! overlapping amount ranges, along with the payment method and currency combinations for which
+//! some payments (of some amounts, for instance) are matched by no rule and fall back to the
+//! default selection.
+use rustc_hash::{FxHashMap, FxHashSet};
+use strum::IntoEnumIterator;
+
+use crate::{
+    dssa::{analyzer, state_machine, truth, types},
+    enums,
+    frontend::{
+        ast,
+        dir::{self, EuclidDirFilter},
+    },
+    types::{DataType, NumValueRefinement},
+};
+
+/// Values a number key can take in a context, both bounds included
+#[derive(Debug, Clone, PartialEq)]
+struct NumberRange {
+    lower: i64,
+    upper: i64,
+    excluded: FxHashSet<i64>,
+}
+
+impl Default for NumberRange {
+    fn default() -> Self {
+        Self {
+            lower: i64::MIN,
+            upper: i64::MAX,
+            excluded: FxHashSet::default(),
+        }
+    }
+}
+
+impl NumberRange {
+    fn restrict(&mut self, refinement: Option<&NumValueRefinement>, number: i64) {
+        match refinement {
+            None => {
+                self.lower = self.lower.max(number);
+                self.upper = self.upper.min(number);
+            }
+            Some(NumValueRefinement::GreaterThan) => {
+                self.lower = self.lower.max(number.saturating_add(1))
+            }
+            Some(NumValueRefinement::GreaterThanEqual) => self.lower = self.lower.max(number),
+            Some(NumValueRefinement::LessThan) => {
+                self.upper = self.upper.min(number.saturating_sub(1))
+            }
+            Some(NumValueRefinement::LessThanEqual) => self.upper = self.upper.min(number),
+            Some(NumValueRefinement::NotEqual) => {
+                self.excluded.insert(number);
+            }
+        }
+    }
+
+    /// Restricts the range to the numbers which do not satisfy the refinement
+    fn exclude(&mut self, refinement: Option<&NumValueRefinement>, number: i64) {
+        match refinement {
+            None => {
+                self.excluded.insert(number);
+            }
+            Some(NumValueRefinement::GreaterThan) => {
+                self.restrict(Some(&NumValueRefinement::LessThanEqual), number)
+            }
+            Some(NumValueRefinement::GreaterThanEqual) => {
+                self.restrict(Some(&NumValueRefinement::LessThan), number)
+            }
+            Some(NumValueRefinement::LessThan) => {
+                self.restrict(Some(&NumValueRefinement::GreaterThanEqual), number)
+            }
+            Some(NumValueRefinement::LessThanEqual) => {
+                self.restrict(Some(&NumValueRefinement::GreaterThan), number)
+            }
+            Some(NumValueRefinement::NotEqual) => self.restrict(None, number),
+        }
+    }
+
+    fn intersect(&self, other: &Self) -> Self {
+        Self {
+            lower: self.lower.max(other.lower),
+            upper: self.upper.min(other.upper),
+            excluded: &self.excluded | &other.excluded,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.lower > self.upper || (self.lower == self.upper && self.excluded.contains(&self.lower))
+    }
+
+    /// Ranges which together hold the numbers outside of the range
+    fn complement(&self) -> Vec<Self> {
+        let mut ranges = Vec::new();
+        if self.lower > i64::MIN {
+            ranges.push(Self {
+                upper: self.lower.saturating_sub(1),
+                ..Self::default()
+            });
+        }
+        if self.upper < i64::MAX {
+            ranges.push(Self {
+                lower: self.upper.saturating_add(1),
+                ..Self::default()
+            });
+        }
+        ranges.extend(
+            self.excluded
+                .iter()
+                .filter(|number| **number >= self.lower && **number <= self.upper)
+                .map(|number| Self {
+                    lower: *number,
+                    upper: *number,
+                    excluded: FxHashSet::default(),
+                }),
+        );
+        ranges
+    }
+
+    fn is_subset(&self, other: &Self) -> bool {
+        self.is_empty()
+            || (self.lower >= other.lower
+                && self.upper <= other.upper
+                && other.excluded.iter().all(|number| {
+                    *number < self.lower || *number > self.upper || self.excluded.contains(number)
+                }))
+    }
+}
+
+/// Values of the keys of an enum type which have a known set of values
+fn get_enum_value_set(key: &dir::DirKey) -> Option<FxHashSet<dir::DirValue>> {
+    matches!(key.kind.get_type(), DataType::EnumVariant)
+        .then(|| key.kind.get_value_set())
+        .flatten()
+        .map(FxHashSet::from_iter)
+}
+
+fn is_pattern(value: &dir::DirValue) -> bool {
+    match value {
+        dir::DirValue::MetaData(metadata) => metadata.refinement.is_some(),
+        _ => value
+            .get_str_val()
+            .is_some_and(|str_value| str_value.refinement.is_some()),
+    }
+}
+
+/// Constraints on the payments matching a conjunctive context
+#[derive(Debug, Clone, Default)]
+struct ContextConstraints {
+    /// Values that the enum keys with a known set of values can take
+    enum_values: FxHashMap<dir::DirKey, FxHashSet<dir::DirValue>>,
+    number_ranges: FxHashMap<dir::DirKey, NumberRange>,
+    /// Values asserted and negated for the other keys, which are only compared for equality
+    assertions: FxHashSet<dir::DirValue>,
+    negations: FxHashSet<dir::DirValue>,
+}
+
+impl ContextConstraints {
+    fn from_context(context: &types::ConjunctiveContext<'_>) -> Self {
+        let mut constraints = Self::default();
+        for ctx_val in context {
+            match ctx_val.value {
+                types::CtxValueKind::Assertion(value) => constraints.add_value(value, true),
+                types::CtxValueKind::Negation(values) => values
+                    .iter()
+                    .for_each(|value| constraints.add_value(value, false)),
+            }
+        }
+        constraints
+    }
+
+    fn add_value(&mut self, value: &dir::DirValue, is_asserted: bool) {
+        let key = value.get_key();
+
+        if let Some(num_value) = value.get_num_value() {
+            let range = self.number_ranges.entry(key).or_default();
+            let number = num_value.number.get_amount_as_i64();
+            if is_asserted {
+                range.restrict(num_value.refinement.as_ref(), number);
+            } else {
+                range.exclude(num_value.refinement.as_ref(), number);
+            }
+        } else if let Some(value_set) = get_enum_value_set(&key) {
+            let values = self.enum_values.entry(key).or_insert(value_set);
+            if is_asserted {
+                values.retain(|allowed| allowed == value);
+            } else {
+                values.remove(value);
+            }
+        } else if is_asserted {
+            self.assertions.insert(value.clone());
+        } else {
+            self.negations.insert(value.clone());
+        }
+    }
+
+    fn is_satisfiable(&self) -> bool {
+        // A key has a single value, so it cannot be equal to two different values. It can match
+        // several patterns though.
+        let mut asserted_keys = FxHashSet::default();
+        self.enum_values.values().all(|values| !values.is_empty())
+            && self.number_ranges.values().all(|range| !range.is_empty())
+            && self.assertions.is_disjoint(&self.negations)
+            && self
+                .assertions
+                .iter()
+                .filter(|value| !is_pattern(value))
+                .all(|value| asserted_keys.insert(value.get_key()))
+    }
+
+    fn intersect(&self, other: &Self) -> Self {
+        let mut constraints = self.clone();
+        for (key, values) in &other.enum_values {
+            constraints
+                .enum_values
+                .entry(key.clone())
+                .and_modify(|own| own.retain(|value| values.contains(value)))
+                .or_insert_with(|| values.clone());
+        }
+        for (key, range) in &other.number_ranges {
+            constraints
+                .number_ranges
+                .entry(key.clone())
+                .and_modify(|own| *own = own.intersect(range))
+                .or_insert_with(|| range.clone());
+        }
+        constraints
+            .assertions
+            .extend(other.assertions.iter().cloned());
+        constraints
+            .negations
+            .extend(other.negations.iter().cloned());
+        constraints
+    }
+
+    /// Whether every payment matching these constraints also matches the other constraints
+    fn implies(&self, other: &Self) -> bool {
+        other.enum_values.iter().all(|(key, values)| {
+            self.enum_values.get(key).map_or_else(
+                || get_enum_value_set(key).is_some_and(|all_values| all_values.is_subset(values)),
+                |own| own.is_subset(values),
+            )
+        }) && other.number_ranges.iter().all(|(key, range)| {
+            self.number_ranges.get(key).map_or_else(
+                || NumberRange::default().is_subset(range),
+                |own| own.is_subset(range),
+            )
+        }) && other.assertions.is_subset(&self.assertions)
+            && other.negations.is_subset(&self.negations)
+    }
+
+    /// Whether a payment with the value can match these constraints, the other keys aside
+    fn allows(&self, value: &dir::DirValue) -> bool {
+        self.enum_values
+            .get(&value.get_key())
+            .map_or(true, |values| values.contains(value))
+    }
+
+    /// Constraints which together match the payments matching these constraints but not the other
+    /// constraints
+    fn subtract(&self, other: &Self) -> Vec<Self> {
+        if !self.intersect(other).is_satisfiable() {
+            return vec![self.clone()];
+        }
+
+        // Each remainder matches the other constraints on the keys before the key it differs on,
+        // so that the remainders do not overlap
+        let mut remainders = Vec::new();
+        let mut matching = self.clone();
+        for (key, values) in &other.enum_values {
+            let own = matching
+                .enum_values
+                .get(key)
+                .cloned()
+                .or_else(|| get_enum_value_set(key))
+                .unwrap_or_default();
+            let mut remainder = matching.clone();
+            remainder.enum_values.insert(key.clone(), &own - values);
+            remainders.push(remainder);
+            matching.enum_values.insert(key.clone(), &own & values);
+        }
+        for (key, range) in &other.number_ranges {
+            let own = matching.number_ranges.get(key).cloned().unwrap_or_default();
+            for complement in range.complement() {
+                let mut remainder = matching.clone();
+                remainder
+                    .number_ranges
+                    .insert(key.clone(), own.intersect(&complement));
+                remainders.push(remainder);
+            }
+            matching
+                .number_ranges
+                .insert(key.clone(), own.intersect(range));
+        }
+        for value in &other.assertions {
+            let mut remainder = matching.clone();
+            remainder.negations.insert(value.clone());
+            remainders.push(remainder);
+            matching.assertions.insert(value.clone());
+        }
+        for value in &other.negations {
+            let mut remainder = matching.clone();
+            remainder.assertions.insert(value.clone());
+            remainders.push(remainder);
+            matching.negations.insert(value.clone());
+        }
+
+        remainders
+            .into_iter()
+            .filter(Self::is_satisfiable)
+            .collect()
+    }
+}
+
+/// Whether every payment matching the constraints matches one of the contexts
+fn is_covered(constraints: ContextConstraints, contexts: &[&ContextConstraints]) -> bool {
+    let mut uncovered = vec![constraints];
+    for context in contexts {
+        uncovered = uncovered
+            .iter()
+            .flat_map(|constraints| constraints.subtract(context))
+            .collect();
+        if uncovered.is_empty() {
+            return true;
+        }
+    }
+    false
+}
+
+struct AnalyzedRule<'a, O> {
+    rule: &'a dir::DirRule<O>,
+    /// Constraints of the contexts of the rule which can be satisfied
+    contexts: Vec<ContextConstraints>,
+}
+
+/// Reports the unreachable, shadowed and overlapping rules of the program, and the payment method
+/// and currency combinations which the rules do not fully match.
+pub fn analyze_rules<O: EuclidDirFilter + PartialEq>(
+    program: ast::Program<O>,
+) -> Result<Vec<types::RuleFinding>, types::AnalysisError> {
+    let dir_program = ast::lowering::lower_program(program)?;
+
+    let mut findings = Vec::new();
+    let mut analyzed_rules: Vec<AnalyzedRule<'_, O>> = Vec::with_capacity(dir_program.rules.len());
+
+    for rule in &dir_program.rules {
+        let (contexts, unreachable_reason) = get_satisfiable_contexts(rule)?;
+
+        let finding_types = if contexts.is_empty() {
+            vec![types::RuleFindingType::UnreachableRule {
+                reason: unreachable_reason.unwrap_or_else(|| "The rule has no conditions".into()),
+            }]
+        } else if let Some(shadowed_by) = get_shadowing_rules(&contexts, &analyzed_rules) {
+            vec![types::RuleFindingType::ShadowedRule { shadowed_by }]
+        } else {
+            get_overlapping_amount_ranges(rule, &contexts, &analyzed_rules)
+        };
+
+        findings.extend(
+            finding_types
+                .into_iter()
+                .map(|finding_type| types::RuleFinding {
+                    rule_name: Some(rule.name.clone()),
+                    finding_type,
+                }),
+        );
+        analyzed_rules.push(AnalyzedRule { rule, contexts });
+    }
+
+    findings.extend(
+        get_uncovered_combinations(&analyzed_rules)
+            .into_iter()
+            .map(|finding_type| types::RuleFinding {
+                rule_name: None,
+                finding_type,
+            }),
+    );
+
+    Ok(findings)
+}
+
+/// Constraints of the contexts of the rule which can be satisfied, along with the reason the
+/// first context which cannot be satisfied was rejected.
+fn get_satisfiable_contexts<O>(
+    rule: &dir::DirRule<O>,
+) -> Result<(Vec<ContextConstraints>, Option<String>), types::AnalysisError> {
+    let mut contexts = Vec::new();
+    let mut unreachable_reason = None;
+    if rule.statements.is_empty() {
+        return Ok((contexts, unreachable_reason));
+    }
+
+    let mut ctx_manager = state_machine::RuleContextManager::new(rule, &[]);
+    while let Some(ctx) = ctx_manager.advance().map_err(|err| types::AnalysisError {
+        metadata: Default::default(),
+        error_type: types::AnalysisErrorType::StateMachine(err),
+    })? {
+        let constraints = ContextConstraints::from_context(ctx);
+        match analyzer::perform_context_analyses(ctx, &truth::ANALYSIS_GRAPH) {
+            Ok(()) if constraints.is_satisfiable() => contexts.push(constraints),
+            Ok(()) => {
+                unreachable_reason.get_or_insert_with(|| {
+                    "The conditions of the rule can never be satisfied together".to_string()
+                });
+            }
+            Err(error) => {
+                unreachable_reason.get_or_insert_with(|| error.to_string());
+            }
+        }
+    }
+
+    Ok((contexts, unreachable_reason))
+}
+
+/// Earlier rules which together match every payment the rule matches, if any
+fn get_shadowing_rules<O>(
+    contexts: &[ContextConstraints],
+    earlier_rules: &[AnalyzedRule<'_, O>],
+) -> Option<Vec<String>> {
+    let mut shadowed_by = Vec::new();
+    for context in contexts {
+        let shadowing_rule = earlier_rules.iter().find(|earlier_rule| {
+            earlier_rule
+                .contexts
+                .iter()
+                .any(|earlier_context| context.implies(earlier_context))
+        })?;
+
+        if !shadowed_by.contains(&shadowing_rule.rule.name) {
+            shadowed_by.push(shadowing_rule.rule.name.clone());
+        }
+    }
+    Some(shadowed_by)
+}
+
+fn get_overlapping_amount_ranges<O: PartialEq>(
+    rule: &dir::DirRule<O>,
+    contexts: &[ContextConstraints],
+    earlier_rules: &[AnalyzedRule<'_, O>],
+) -> Vec<types::RuleFindingType> {
+    let amount_key = dir::DirKey::new(dir::DirKeyKind::PaymentAmount, None);
+
+    earlier_rules
+        .iter()
+        .filter(|earlier_rule| earlier_rule.rule.connector_selection != rule.connector_selection)
+        .filter_map(|earlier_rule| {
+            let overlap = contexts
+                .iter()
+                .filter(|context| context.number_ranges.contains_key(&amount_key))
+                .find_map(|context| {
+                    earlier_rule
+                        .contexts
+                        .iter()
+                        .filter(|earlier_context| {
+                            earlier_context.number_ranges.contains_key(&amount_key)
+                        })
+                        .map(|earlier_context| context.intersect(earlier_context))
+                        .find(|overlap| overlap.is_satisfiable())
+                })?;
+            let range = overlap.number_ranges.get(&amount_key)?;
+
+            Some(types::RuleFindingType::OverlappingAmountRange {
+                overlapping_rule: earlier_rule.rule.name.clone(),
+                min_amount: (range.lower != i64::MIN).then_some(range.lower),
+                max_amount: (range.upper != i64::MAX).then_some(range.upper),
+            })
+        })
+        .collect()
+}
+
+fn get_uncovered_combinations<O>(rules: &[AnalyzedRule<'_, O>]) -> Vec<types::RuleFindingType> {
+    let contexts = rules
+        .iter()
+        .flat_map(|analyzed_rule| analyzed_rule.contexts.iter())
+        .collect::<Vec<_>>();
+
+    // A combination is covered if the rules match all of its payments, whatever the values of the
+    // other keys (amount, card network, metadata...) are
+    enums::PaymentMethod::iter()
+        .filter_map(|payment_method| {
+            let payment_method_value = dir::DirValue::PaymentMethod(payment_method);
+            let payment_method_contexts = contexts
+                .iter()
+                .copied()
+                .filter(|context| context.allows(&payment_method_value))
+                .collect::<Vec<_>>();
+
+            let currencies = enums::Currency::iter()
+                .filter(|currency| {
+                    let currency_value = dir::DirValue::PaymentCurrency(*currency);
+                    let mut combination = ContextConstraints::default();
+                    combination.add_value(&payment_method_value, true);
+                    combination.add_value(&currency_value, true);
+                    let combination_contexts = payment_method_contexts
+                        .iter()
+                        .copied()
+                        .filter(|context| context.allows(&currency_value))
+                        .collect::<Vec<_>>();
+                    !is_covered(combination, &combination_contexts)
+                })
+                .collect::<Vec<_>>();
+
+            if currencies.is_empty() {
+                None
+            } else if currencies.len() == enums::Currency::iter().count() {
+                Some(types::RuleFindingType::UncoveredCombination {
+                    payment_method,
+                    currencies: None,
+                })
+            } else {
+                Some(types::RuleFindingType::UncoveredCombination {
+                    payment_method,
+                    currencies: Some(currencies),
+                })
+            }
+        })
+        .collect()
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+    use crate::types::DummyOutput;
+
+    fn get_findings(program_str: &str) -> Vec<types::RuleFinding> {
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        analyze_rules(program).expect("Analysis")
+    }
+
+    fn get_finding_types(
+        findings: &[types::RuleFinding],
+        rule_name: Option<&str>,
+    ) -> Vec<types::RuleFindingType> {
+        findings
+            .iter()
+            .filter(|finding| finding.rule_name.as_deref() == rule_name)
+            .map(|finding| finding.finding_type.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_unreachable_and_shadowed_rules() {
+        let findings = get_findings(
+            r#"
+            default: ["stripe"]
+
+            cards: ["adyen"]
+            {
+                payment_method = card
+            }
+
+            conflicting: ["checkout"]
+            {
+                payment_method = wallet & amount > 500 & amount < 100
+            }
+
+            usd_cards: ["checkout"]
+            {
+                payment_method = card & currency = USD
+            }
+
+            usd_wallets: ["checkout"]
+            {
+                payment_method = wallet & currency = USD
+            }
+        "#,
+        );
+
+        assert!(matches!(
+            get_finding_types(&findings, Some("conflicting")).as_slice(),
+            [types::RuleFindingType::UnreachableRule { .. }]
+        ));
+        assert_eq!(
+            get_finding_types(&findings, Some("usd_cards")),
+            vec![types::RuleFindingType::ShadowedRule {
+                shadowed_by: vec!["cards".to_string()]
+            }]
+        );
+        assert!(get_finding_types(&findings, Some("usd_wallets")).is_empty());
+    }
+
+    #[test]
+    fn test_overlapping_amount_ranges() {
+        let findings = get_findings(
+            r#"
+            default: ["stripe"]
+
+            small: ["adyen"]
+            {
+                payment_method = card & amount <= 1000
+            }
+
+            large: ["checkout"]
+            {
+                payment_method = card & amount >= 500
+            }
+
+            medium: ["adyen"]
+            {
+                payment_method = card & amount >= 300
+            }
+        "#,
+        );
+
+        assert_eq!(
+            get_finding_types(&findings, Some("large")),
+            vec![types::RuleFindingType::OverlappingAmountRange {
+                overlapping_rule: "small".to_string(),
+                min_amount: Some(500),
+                max_amount: Some(1000),
+            }]
+        );
+        // Overlaps with the rules having the same output are not reported
+        assert_eq!(
+            get_finding_types(&findings, Some("medium")),
+            vec![types::RuleFindingType::OverlappingAmountRange {
+                overlapping_rule: "large".to_string(),
+                min_amount: Some(500),
+                max_amount: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_uncovered_combinations() {
+        let findings = get_findings(
+            r#"
+            default: ["stripe"]
+
+            cards: ["adyen"]
+            {
+                payment_method = card & currency /= USD
+            }
+
+            other_payment_methods: ["checkout"]
+            {
+                payment_method /= card
+            }
+        "#,
+        );
+
+        assert_eq!(
+            get_finding_types(&findings, None),
+            vec![types::RuleFindingType::UncoveredCombination {
+                payment_method: enums::PaymentMethod::Card,
+                currencies: Some(vec![enums::Currency::USD]),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_uncovered_combinations_with_amount_conditions() {
+        let findings = get_findings(
+            r#"
+            default: ["stripe"]
+
+            large_cards: ["adyen"]
+            {
+                payment_method = card & amount >= 100
+            }
+
+            small_usd_cards: ["checkout"]
+            {
+                payment_method = card & amount < 100 & currency = USD
+            }
+
+            wallets: ["checkout"]
+            {
+                payment_method = wallet & amount > 0
+            }
+
+            other_payment_methods: ["checkout"]
+            {
+                payment_method /= card & payment_method /= wallet
+            }
+        "#,
+        );
+
+        // Card payments below 100 are only matched in USD, and wallet payments are never matched
+        // for amounts of 0 or less
+        assert_eq!(
+            get_finding_types(&findings, None),
+            vec![
+                types::RuleFindingType::UncoveredCombination {
+                    payment_method: enums::PaymentMethod::Card,
+                    currencies: Some(
+                        enums::Currency::iter()
+                            .filter(|currency| *currency != enums::Currency::USD)
+                            .collect()
+                    ),
+                },
+                types::RuleFindingType::UncoveredCombination {
+                    payment_method: enums::PaymentMethod::Wallet,
+                    currencies: None,
+                },
+            ]
+        );
+    }
+}
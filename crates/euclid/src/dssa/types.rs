@@ -4,6 +4,7 @@ use serde::Serialize;
 
 use crate::{
     dssa::{self, graph},
+    enums,
     frontend::{ast, dir},
     types::{DataType, EuclidValue, Metadata},
 };
@@ -154,6 +155,39 @@ pub enum AnalysisErrorType {
     NotSupported,
 }
 
+/// A finding of the rule level analysis of a program, see [`crate::dssa::report`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RuleFinding {
+    /// Name of the rule the finding concerns, absent for the findings concerning the rules as a
+    /// whole
+    pub rule_name: Option<String>,
+    #[serde(flatten)]
+    pub finding_type: RuleFindingType,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", content = "info", rename_all = "snake_case")]
+pub enum RuleFindingType {
+    /// The conditions of the rule can never be satisfied
+    UnreachableRule { reason: String },
+    /// Earlier rules are selected for every payment the rule matches
+    ShadowedRule { shadowed_by: Vec<String> },
+    /// The amount range of the rule overlaps with that of an earlier rule having a different
+    /// output, the earlier rule being selected for the amounts in the overlap. The bounds are
+    /// inclusive, an absent bound meaning that the overlap is unbounded.
+    OverlappingAmountRange {
+        overlapping_rule: String,
+        min_amount: Option<i64>,
+        max_amount: Option<i64>,
+    },
+    /// No rule can be selected for some of the payments made with the payment method in the
+    /// currencies, in any currency if the currencies are absent
+    UncoveredCombination {
+        payment_method: enums::PaymentMethod,
+        currencies: Option<Vec<enums::Currency>>,
+    },
+}
+
 #[derive(Debug, Clone)]
 pub enum ValueType {
     EnumVariants(Vec<EuclidValue>),
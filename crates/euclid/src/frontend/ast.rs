@@ -1,6 +1,7 @@
 pub mod lowering;
 #[cfg(feature = "ast_parser")]
 pub mod parser;
+pub mod printer;
 
 use common_enums::RoutableConnectors;
 use common_utils::types::MinorUnit;
@@ -43,6 +44,12 @@ pub enum ValueType {
     /// conditions like "500 < amount < 1000"
     /// eg: payment.amount = (> 500, < 1000)
     NumberComparisonArray(Vec<NumberComparison>),
+    /// Similar to NumberArray but for arbitrary strings
+    /// eg: card_bin in_list ("424242", "400000")
+    StrValueArray(Vec<String>),
+    /// Similar to NumberArray but for metadata variants
+    /// eg: "merchant_order_ref" in_list ("ORD", "INV")
+    MetadataVariantArray(Vec<MetadataValue>),
 }
 
 impl ValueType {
@@ -55,8 +62,34 @@ impl ValueType {
             Self::NumberComparisonArray(_) => DataType::Number,
             Self::NumberArray(_) => DataType::Number,
             Self::EnumVariantArray(_) => DataType::EnumVariant,
+            Self::StrValueArray(_) => DataType::StrValue,
+            Self::MetadataVariantArray(_) => DataType::MetadataValue,
         }
     }
+
+    pub fn is_array(&self) -> bool {
+        matches!(
+            self,
+            Self::NumberArray(_)
+                | Self::EnumVariantArray(_)
+                | Self::NumberComparisonArray(_)
+                | Self::StrValueArray(_)
+                | Self::MetadataVariantArray(_)
+        )
+    }
+}
+
+/// A connector in the output of a rule as written in the DSL, with an optional volume split
+/// percentage
+///
+/// ```text
+/// ["stripe", "adyen"]
+/// [70% "stripe", 30% "adyen"]
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutputChoice {
+    pub name: String,
+    pub split: Option<u8>,
 }
 
 /// Represents a number comparison for "NumberComparisonArrayValue"
@@ -77,10 +110,31 @@ pub enum ComparisonType {
     LessThanEqual,
     GreaterThan,
     GreaterThanEqual,
+    /// Matches if the value is one of the values in the given array
+    InList,
+    /// Matches string and metadata values starting with the given prefix
+    StartsWith,
+    /// Matches string and metadata values ending with the given suffix
+    EndsWith,
+    /// Matches string and metadata values containing the given substring
+    Contains,
+    /// Matches string and metadata values against the given regex, which has to match the
+    /// entire value
+    MatchesRegex,
+}
+
+impl ComparisonType {
+    /// Whether the comparison matches string and metadata values against a pattern
+    pub fn is_string_pattern(&self) -> bool {
+        matches!(
+            self,
+            Self::StartsWith | Self::EndsWith | Self::Contains | Self::MatchesRegex
+        )
+    }
 }
 
 /// Represents a single comparison condition.
-#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Comparison {
     /// The left hand side which will always be a domain input identifier like "payment.method.cardtype"
@@ -113,7 +167,7 @@ pub type IfCondition = Vec<Comparison>;
 ///     }
 /// }
 /// ```
-#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IfStatement {
     #[schema(value_type=Vec<Comparison>)]
@@ -135,7 +189,7 @@ pub struct IfStatement {
 ///     }
 /// }
 /// ```
-#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 #[aliases(RuleConnectorSelection = Rule<ConnectorSelection>)]
 pub struct Rule<O> {
@@ -147,7 +201,7 @@ pub struct Rule<O> {
 
 /// The program, having a default connector selection and
 /// a bunch of rules. Also can hold arbitrary metadata.
-#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 #[aliases(ProgramConnectorSelection = Program<ConnectorSelection>)]
 pub struct Program<O> {
@@ -156,6 +210,15 @@ pub struct Program<O> {
     pub rules: Vec<Rule<O>>,
     #[schema(value_type=HashMap<String, serde_json::Value>)]
     pub metadata: Metadata,
+    /// The IANA timezone in which the payment time keys (`payment_time_of_day`,
+    /// `payment_day_of_week` and `payment_date`) are evaluated, defaults to UTC
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::types::timezone::option"
+    )]
+    #[schema(value_type = Option<String>, example = "Asia/Kolkata")]
+    pub timezone: Option<chrono_tz::Tz>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
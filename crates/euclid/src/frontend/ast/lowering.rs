@@ -5,6 +5,8 @@
 
 use std::str::FromStr;
 
+use common_utils::types::MinorUnit;
+
 use crate::{
     dssa::types::{AnalysisError, AnalysisErrorType},
     enums::CollectVariants,
@@ -67,19 +69,23 @@ macro_rules! lower_enum {
 /// payment_amount = 17052001
 /// ```notrust
 /// This is for the cases in which there are numerical values involved and they are lowered
-/// accordingly on basis of the supplied key, currently payment_amount is the only key having this
-/// use case
+/// accordingly on basis of the supplied key, an optional validation closure can be supplied for
+/// keys which only accept certain numbers
 macro_rules! lower_number {
-    ($key:ident, $value:ident, $comp:ident) => {
+    ($key:ident, $value:ident, $comp:ident $(, $validation_closure:expr)?) => {
         match $value {
-            ast::ValueType::Number(num) => Ok(vec![dir::DirValue::$key(types::NumValue {
-                number: num,
-                refinement: $comp.into(),
-            })]),
+            ast::ValueType::Number(num) => {
+                $($validation_closure(num)?;)?
+                Ok(vec![dir::DirValue::$key(types::NumValue {
+                    number: num,
+                    refinement: $comp.into(),
+                })])
+            }
 
             ast::ValueType::NumberArray(na) => na
                 .into_iter()
                 .map(|num| {
+                    $($validation_closure(num)?;)?
                     Ok(dir::DirValue::$key(types::NumValue {
                         number: num,
                         refinement: $comp.clone().into(),
@@ -90,6 +96,7 @@ macro_rules! lower_number {
             ast::ValueType::NumberComparisonArray(nca) => nca
                 .into_iter()
                 .map(|nc| {
+                    $($validation_closure(nc.number)?;)?
                     Ok(dir::DirValue::$key(types::NumValue {
                         number: nc.number,
                         refinement: nc.comparison_type.into(),
@@ -114,14 +121,42 @@ macro_rules! lower_number {
 /// ```notrust
 ///
 /// This serves for the purpose were we have the DirKey as Card_bin and value as an arbitrary string
-/// So particularly it lowers an arbitrary value to a predefined key.
+/// So particularly it lowers an arbitrary value to a predefined key. Pattern comparisons like
+/// `starts_with` are lowered as refinements of the value.
 macro_rules! lower_str {
-    ($key:ident, $value:ident $(, $validation_closure:expr)?) => {
+    ($key:ident, $value:ident, $comp:ident $(, $validation_closure:expr)?) => {
         match $value {
             ast::ValueType::StrValue(st) => {
-                $($validation_closure(&st)?;)?
-                Ok(vec![dir::DirValue::$key(types::StrValue { value: st })])
+                let str_value = types::StrValue {
+                    value: st,
+                    refinement: $comp.into(),
+                };
+                validate_regex_pattern(
+                    dir::DirKeyKind::$key,
+                    &str_value.value,
+                    str_value.refinement.as_ref(),
+                )?;
+                $($validation_closure(&str_value)?;)?
+                Ok(vec![dir::DirValue::$key(str_value)])
             }
+
+            ast::ValueType::StrValueArray(sva) => sva
+                .into_iter()
+                .map(|st| {
+                    let str_value = types::StrValue {
+                        value: st,
+                        refinement: $comp.clone().into(),
+                    };
+                    validate_regex_pattern(
+                        dir::DirKeyKind::$key,
+                        &str_value.value,
+                        str_value.refinement.as_ref(),
+                    )?;
+                    $($validation_closure(&str_value)?;)?
+                    Ok(dir::DirValue::$key(str_value))
+                })
+                .collect(),
+
             _ => Err(AnalysisErrorType::InvalidType {
                 key: dir::DirKeyKind::$key.to_string(),
                 expected: DataType::StrValue,
@@ -132,14 +167,39 @@ macro_rules! lower_str {
 }
 
 macro_rules! lower_metadata {
-    ($key:ident, $value:ident) => {
+    ($key:ident, $value:ident, $comp:ident) => {
         match $value {
             ast::ValueType::MetadataVariant(md) => {
-                Ok(vec![dir::DirValue::$key(types::MetadataValue {
+                let metadata_value = types::MetadataValue {
                     key: md.key,
                     value: md.value,
-                })])
+                    refinement: $comp.into(),
+                };
+                validate_regex_pattern(
+                    dir::DirKeyKind::$key,
+                    &metadata_value.value,
+                    metadata_value.refinement.as_ref(),
+                )?;
+                Ok(vec![dir::DirValue::$key(metadata_value)])
             }
+
+            ast::ValueType::MetadataVariantArray(mva) => mva
+                .into_iter()
+                .map(|md| {
+                    let metadata_value = types::MetadataValue {
+                        key: md.key,
+                        value: md.value,
+                        refinement: $comp.clone().into(),
+                    };
+                    validate_regex_pattern(
+                        dir::DirKeyKind::$key,
+                        &metadata_value.value,
+                        metadata_value.refinement.as_ref(),
+                    )?;
+                    Ok(dir::DirValue::$key(metadata_value))
+                })
+                .collect(),
+
             _ => Err(AnalysisErrorType::InvalidType {
                 key: dir::DirKeyKind::$key.to_string(),
                 expected: DataType::MetadataValue,
@@ -148,6 +208,38 @@ macro_rules! lower_metadata {
         }
     };
 }
+
+/// Checks that `matches_regex` patterns are not too long and compile within the size limits, so
+/// that a rule cannot make the evaluation of the program expensive
+fn validate_regex_pattern(
+    key: dir::DirKeyKind,
+    pattern: &str,
+    refinement: Option<&types::StrValueRefinement>,
+) -> Result<(), AnalysisErrorType> {
+    if !matches!(refinement, Some(types::StrValueRefinement::MatchesRegex)) {
+        return Ok(());
+    }
+
+    let message = if pattern.len() > types::MAX_REGEX_PATTERN_LENGTH {
+        format!(
+            "Regex patterns can be at most {} characters long",
+            types::MAX_REGEX_PATTERN_LENGTH
+        )
+    } else {
+        match types::compile_anchored_regex(pattern) {
+            Ok(_) => return Ok(()),
+            Err(regex::Error::CompiledTooBig(_)) => "Regex pattern is too complex".to_string(),
+            Err(error) => format!("Invalid regex pattern: {error}"),
+        }
+    };
+
+    Err(AnalysisErrorType::InvalidValue {
+        key,
+        value: pattern.to_string(),
+        message: Some(message),
+    })
+}
+
 /// lowers the comparison operators for different subtle value types present
 /// by throwing required errors for comparisons that can't be performed for a certain value type
 /// for example
@@ -215,6 +307,39 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
             })?;
         }
 
+        (
+            ast::ComparisonType::LessThan
+            | ast::ComparisonType::GreaterThan
+            | ast::ComparisonType::GreaterThanEqual
+            | ast::ComparisonType::LessThanEqual,
+            ast::ValueType::StrValueArray(_) | ast::ValueType::MetadataVariantArray(_),
+        ) => {
+            Err(AnalysisErrorType::InvalidComparison {
+                operator: comp.comparison.clone(),
+                value_type: comp.value.get_type(),
+            })?;
+        }
+
+        (ast::ComparisonType::InList, value) if !value.is_array() => {
+            Err(AnalysisErrorType::InvalidComparison {
+                operator: comp.comparison.clone(),
+                value_type: value.get_type(),
+            })?;
+        }
+
+        (comparison, value)
+            if comparison.is_string_pattern()
+                && !matches!(
+                    value.get_type(),
+                    DataType::StrValue | DataType::MetadataValue
+                ) =>
+        {
+            Err(AnalysisErrorType::InvalidComparison {
+                operator: comp.comparison.clone(),
+                value_type: value.get_type(),
+            })?;
+        }
+
         _ => {}
     }
 
@@ -249,32 +374,95 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
         dir::DirKeyKind::MobilePaymentType => lower_enum!(MobilePaymentType, value),
         dir::DirKeyKind::RealTimePaymentType => lower_enum!(RealTimePaymentType, value),
         dir::DirKeyKind::CardBin => {
-            let validation_closure = |st: &String| -> Result<(), AnalysisErrorType> {
-                if st.len() == 6 && st.chars().all(|x| x.is_ascii_digit()) {
+            let validation_closure = |st: &types::StrValue| -> Result<(), AnalysisErrorType> {
+                let is_numeric = st.value.chars().all(|x| x.is_ascii_digit());
+                let (is_valid, message) = match st.refinement {
+                    None => (st.value.len() == 6 && is_numeric, "Expected 6 digits"),
+                    Some(types::StrValueRefinement::MatchesRegex) => (true, ""),
+                    // Prefixes of a bin are used to match ranges of bins
+                    Some(
+                        types::StrValueRefinement::StartsWith
+                        | types::StrValueRefinement::EndsWith
+                        | types::StrValueRefinement::Contains,
+                    ) => (
+                        (1..=6).contains(&st.value.len()) && is_numeric,
+                        "Expected 1 to 6 digits",
+                    ),
+                };
+                if is_valid {
                     Ok(())
                 } else {
                     Err(AnalysisErrorType::InvalidValue {
                         key: dir::DirKeyKind::CardBin,
-                        value: st.clone(),
-                        message: Some("Expected 6 digits".to_string()),
+                        value: st.value.clone(),
+                        message: Some(message.to_string()),
                     })
                 }
             };
-            lower_str!(CardBin, value, validation_closure)
+            lower_str!(CardBin, value, comparison, validation_closure)
         }
-        dir::DirKeyKind::BusinessLabel => lower_str!(BusinessLabel, value),
-        dir::DirKeyKind::MetaData => lower_metadata!(MetaData, value),
+        dir::DirKeyKind::BusinessLabel => lower_str!(BusinessLabel, value, comparison),
+        dir::DirKeyKind::CustomerEmail => lower_str!(CustomerEmail, value, comparison),
+        dir::DirKeyKind::MetaData => lower_metadata!(MetaData, value, comparison),
         dir::DirKeyKind::PaymentAmount => lower_number!(PaymentAmount, value, comparison),
         dir::DirKeyKind::Connector => Err(AnalysisErrorType::InvalidKey(
             dir::DirKeyKind::Connector.to_string(),
         )),
-        dir::DirKeyKind::IssuerName => lower_str!(IssuerName, value),
+        dir::DirKeyKind::IssuerName => lower_str!(IssuerName, value, comparison),
         dir::DirKeyKind::IssuerCountry => lower_enum!(IssuerCountry, value),
         dir::DirKeyKind::CustomerDevicePlatform => lower_enum!(CustomerDevicePlatform, value),
         dir::DirKeyKind::CustomerDeviceType => lower_enum!(CustomerDeviceType, value),
         dir::DirKeyKind::CustomerDeviceDisplaySize => lower_enum!(CustomerDeviceDisplaySize, value),
         dir::DirKeyKind::AcquirerCountry => lower_enum!(AcquirerCountry, value),
         dir::DirKeyKind::AcquirerFraudRate => lower_number!(AcquirerFraudRate, value, comparison),
+        dir::DirKeyKind::PaymentTimeOfDay => {
+            let validation_closure = |num: MinorUnit| -> Result<(), AnalysisErrorType> {
+                let time = num.get_amount_as_i64();
+                if (0..2400).contains(&time) && time % 100 < 60 {
+                    Ok(())
+                } else {
+                    Err(AnalysisErrorType::InvalidValue {
+                        key: dir::DirKeyKind::PaymentTimeOfDay,
+                        value: time.to_string(),
+                        message: Some("Expected a time of day between 00:00 and 23:59".to_string()),
+                    })
+                }
+            };
+            lower_number!(PaymentTimeOfDay, value, comparison, validation_closure)
+        }
+        dir::DirKeyKind::PaymentDayOfWeek => lower_enum!(PaymentDayOfWeek, value),
+        dir::DirKeyKind::PaymentDate => {
+            let validation_closure = |num: MinorUnit| -> Result<(), AnalysisErrorType> {
+                let date = num.get_amount_as_i64();
+                let is_valid_date = i32::try_from(date / 10000)
+                    .ok()
+                    .zip(u8::try_from(date / 100 % 100).ok())
+                    .zip(u8::try_from(date % 100).ok())
+                    .and_then(|((year, month), day)| {
+                        time::Month::try_from(month)
+                            .and_then(|month| time::Date::from_calendar_date(year, month, day))
+                            .ok()
+                    })
+                    .is_some();
+                if is_valid_date {
+                    Ok(())
+                } else {
+                    Err(AnalysisErrorType::InvalidValue {
+                        key: dir::DirKeyKind::PaymentDate,
+                        value: date.to_string(),
+                        message: Some("Expected a valid date as YYYYMMDD".to_string()),
+                    })
+                }
+            };
+            lower_number!(PaymentDate, value, comparison, validation_closure)
+        }
+        dir::DirKeyKind::CustomerAttemptCount => {
+            lower_number!(CustomerAttemptCount, value, comparison)
+        }
+        dir::DirKeyKind::CardFailureCount => lower_number!(CardFailureCount, value, comparison),
+        dir::DirKeyKind::CustomerTransactedAmount => {
+            lower_number!(CustomerTransactedAmount, value, comparison)
+        }
     }
 }
 
@@ -291,6 +479,11 @@ fn lower_comparison<O: EuclidDirFilter>(
         ast::ComparisonType::LessThanEqual => dir::DirComparisonLogic::PositiveDisjunction,
         ast::ComparisonType::GreaterThanEqual => dir::DirComparisonLogic::PositiveDisjunction,
         ast::ComparisonType::GreaterThan => dir::DirComparisonLogic::PositiveDisjunction,
+        ast::ComparisonType::InList
+        | ast::ComparisonType::StartsWith
+        | ast::ComparisonType::EndsWith
+        | ast::ComparisonType::Contains
+        | ast::ComparisonType::MatchesRegex => dir::DirComparisonLogic::PositiveDisjunction,
     };
     let values = lower_comparison_inner::<O>(comp).map_err(|etype| AnalysisError {
         error_type: etype,
@@ -352,5 +545,6 @@ pub fn lower_program<O: EuclidDirFilter>(
             .map(lower_rule)
             .collect::<Result<_, _>>()?,
         metadata: program.metadata,
+        timezone: program.timezone,
     })
 }
@@ -3,7 +3,10 @@ use nom::{
     branch, bytes::complete, character::complete as pchar, combinator, error, multi, sequence,
 };
 
-use crate::{frontend::ast, types::DummyOutput};
+use crate::{
+    frontend::ast,
+    types::{self, DummyOutput},
+};
 pub type ParseResult<T, U> = nom::IResult<T, U, error::VerboseError<T>>;
 
 pub enum EuclidError {
@@ -71,6 +74,79 @@ pub fn num_i64(input: &str) -> ParseResult<&str, i64> {
     )(input)
 }
 
+/// Parses a time of day like `06:30` into the number `630`
+pub fn time_of_day_i64(input: &str) -> ParseResult<&str, i64> {
+    error::context(
+        "time_of_day",
+        combinator::map_res(
+            sequence::separated_pair(
+                complete::take_while_m_n(2, 2, |c: char| c.is_ascii_digit()),
+                complete::tag(":"),
+                complete::take_while_m_n(2, 2, |c: char| c.is_ascii_digit()),
+            ),
+            |(hour, minute): (&str, &str)| {
+                let invalid_time = || EuclidError::InvalidNumber(format!("{hour}:{minute}"));
+                let hour = hour.parse::<i64>().map_err(|_| invalid_time())?;
+                let minute = minute.parse::<i64>().map_err(|_| invalid_time())?;
+                if hour < 24 && minute < 60 {
+                    Ok(hour * 100 + minute)
+                } else {
+                    Err(invalid_time())
+                }
+            },
+        ),
+    )(input)
+}
+
+/// Parses a date like `2026-11-01` into the number `20261101`
+pub fn date_i64(input: &str) -> ParseResult<&str, i64> {
+    error::context(
+        "date",
+        combinator::map_res(
+            sequence::tuple((
+                complete::take_while_m_n(4, 4, |c: char| c.is_ascii_digit()),
+                sequence::preceded(
+                    complete::tag("-"),
+                    complete::take_while_m_n(2, 2, |c: char| c.is_ascii_digit()),
+                ),
+                sequence::preceded(
+                    complete::tag("-"),
+                    complete::take_while_m_n(2, 2, |c: char| c.is_ascii_digit()),
+                ),
+            )),
+            |(year, month, day): (&str, &str, &str)| {
+                let invalid_date = || EuclidError::InvalidNumber(format!("{year}-{month}-{day}"));
+                let date = year
+                    .parse::<i32>()
+                    .ok()
+                    .zip(month.parse::<u8>().ok())
+                    .zip(day.parse::<u8>().ok())
+                    .and_then(|((year, month), day)| {
+                        time::Month::try_from(month)
+                            .and_then(|month| time::Date::from_calendar_date(year, month, day))
+                            .ok()
+                    })
+                    .ok_or_else(invalid_date)?;
+                Ok(i64::from(date.year()) * 10000
+                    + i64::from(u8::from(date.month())) * 100
+                    + i64::from(date.day()))
+            },
+        ),
+    )(input)
+}
+
+type NumberParser = for<'a> fn(&'a str) -> ParseResult<&'a str, i64>;
+
+/// Returns the parser of the numbers of a key, the payment time keys are written as times of day
+/// and dates instead of plain numbers
+fn number_parser_for_key(key: &str) -> NumberParser {
+    match key {
+        "payment_time_of_day" => time_of_day_i64,
+        "payment_date" => date_i64,
+        _ => num_i64,
+    }
+}
+
 pub fn string_str(input: &str) -> ParseResult<&str, String> {
     error::context(
         "String",
@@ -114,10 +190,16 @@ pub fn percentage(input: &str) -> ParseResult<&str, u8> {
 }
 
 pub fn number_value(input: &str) -> ParseResult<&str, ast::ValueType> {
+    number_value_with(num_i64)(input)
+}
+
+fn number_value_with<'a>(
+    number: NumberParser,
+) -> impl FnMut(&'a str) -> ParseResult<&'a str, ast::ValueType> {
     error::context(
         "number_value",
-        combinator::map(num_i64, |n| ast::ValueType::Number(MinorUnit::new(n))),
-    )(input)
+        combinator::map(number, |n| ast::ValueType::Number(MinorUnit::new(n))),
+    )
 }
 
 pub fn str_value(input: &str) -> ParseResult<&str, ast::ValueType> {
@@ -144,9 +226,15 @@ pub fn enum_variant_value(input: &str) -> ParseResult<&str, ast::ValueType> {
 }
 
 pub fn number_array_value(input: &str) -> ParseResult<&str, ast::ValueType> {
-    fn num_minor_unit(input: &str) -> ParseResult<&str, MinorUnit> {
-        combinator::map(num_i64, MinorUnit::new)(input)
-    }
+    number_array_value_with(num_i64)(input)
+}
+
+fn number_array_value_with<'a>(
+    number: NumberParser,
+) -> impl FnMut(&'a str) -> ParseResult<&'a str, ast::ValueType> {
+    let num_minor_unit = move |input: &'a str| -> ParseResult<&'a str, MinorUnit> {
+        combinator::map(number, MinorUnit::new)(input)
+    };
     let many_with_comma = multi::many0(sequence::preceded(
         skip_ws(complete::tag(",")),
         skip_ws(num_minor_unit),
@@ -168,7 +256,7 @@ pub fn number_array_value(input: &str) -> ParseResult<&str, ast::ValueType> {
                 ast::ValueType::NumberArray(rest)
             },
         ),
-    )(input)
+    )
 }
 
 pub fn enum_variant_array_value(input: &str) -> ParseResult<&str, ast::ValueType> {
@@ -196,7 +284,45 @@ pub fn enum_variant_array_value(input: &str) -> ParseResult<&str, ast::ValueType
     )(input)
 }
 
+pub fn str_array_value(input: &str) -> ParseResult<&str, Vec<String>> {
+    let many_with_comma = multi::many0(sequence::preceded(
+        skip_ws(complete::tag(",")),
+        skip_ws(string_str),
+    ));
+
+    let full_sequence = sequence::pair(skip_ws(string_str), many_with_comma);
+
+    error::context(
+        "str_array_value",
+        combinator::map(
+            sequence::delimited(
+                skip_ws(complete::tag("(")),
+                full_sequence,
+                skip_ws(complete::tag(")")),
+            ),
+            |tup: (String, Vec<String>)| {
+                let mut rest = tup.1;
+                rest.insert(0, tup.0);
+                rest
+            },
+        ),
+    )(input)
+}
+
+pub fn str_value_array_value(input: &str) -> ParseResult<&str, ast::ValueType> {
+    error::context(
+        "str_value_array_value",
+        combinator::map(str_array_value, ast::ValueType::StrValueArray),
+    )(input)
+}
+
 pub fn number_comparison(input: &str) -> ParseResult<&str, ast::NumberComparison> {
+    number_comparison_with(num_i64)(input)
+}
+
+fn number_comparison_with<'a>(
+    number: NumberParser,
+) -> impl FnMut(&'a str) -> ParseResult<&'a str, ast::NumberComparison> {
     let operator = combinator::map_res(
         branch::alt((
             complete::tag(">="),
@@ -216,22 +342,28 @@ pub fn number_comparison(input: &str) -> ParseResult<&str, ast::NumberComparison
     error::context(
         "number_comparison",
         combinator::map(
-            sequence::pair(operator, num_i64),
+            sequence::pair(operator, skip_ws(number)),
             |tup: (ast::ComparisonType, i64)| ast::NumberComparison {
                 comparison_type: tup.0,
                 number: MinorUnit::new(tup.1),
             },
         ),
-    )(input)
+    )
 }
 
 pub fn number_comparison_array_value(input: &str) -> ParseResult<&str, ast::ValueType> {
+    number_comparison_array_value_with(num_i64)(input)
+}
+
+fn number_comparison_array_value_with<'a>(
+    number: NumberParser,
+) -> impl FnMut(&'a str) -> ParseResult<&'a str, ast::ValueType> {
     let many_with_comma = multi::many0(sequence::preceded(
         skip_ws(complete::tag(",")),
-        skip_ws(number_comparison),
+        skip_ws(number_comparison_with(number)),
     ));
 
-    let full_sequence = sequence::pair(skip_ws(number_comparison), many_with_comma);
+    let full_sequence = sequence::pair(skip_ws(number_comparison_with(number)), many_with_comma);
 
     error::context(
         "number_comparison_array_value",
@@ -247,21 +379,28 @@ pub fn number_comparison_array_value(input: &str) -> ParseResult<&str, ast::Valu
                 ast::ValueType::NumberComparisonArray(rest)
             },
         ),
-    )(input)
+    )
 }
 
 pub fn value_type(input: &str) -> ParseResult<&str, ast::ValueType> {
+    value_type_with(num_i64)(input)
+}
+
+fn value_type_with<'a>(
+    number: NumberParser,
+) -> impl FnMut(&'a str) -> ParseResult<&'a str, ast::ValueType> {
     error::context(
         "value_type",
         branch::alt((
-            number_value,
+            number_value_with(number),
             enum_variant_value,
             enum_variant_array_value,
-            number_array_value,
-            number_comparison_array_value,
+            number_array_value_with(number),
+            number_comparison_array_value_with(number),
             str_value,
+            str_value_array_value,
         )),
-    )(input)
+    )
 }
 
 pub fn comparison_type(input: &str) -> ParseResult<&str, ast::ComparisonType> {
@@ -275,6 +414,11 @@ pub fn comparison_type(input: &str) -> ParseResult<&str, ast::ComparisonType> {
                 complete::tag("="),
                 complete::tag(">"),
                 complete::tag("<"),
+                complete::tag("in_list"),
+                complete::tag("starts_with"),
+                complete::tag("ends_with"),
+                complete::tag("contains"),
+                complete::tag("matches_regex"),
             )),
             |s: &str| match s {
                 "/=" => Ok(ast::ComparisonType::NotEqual),
@@ -283,50 +427,100 @@ pub fn comparison_type(input: &str) -> ParseResult<&str, ast::ComparisonType> {
                 "=" => Ok(ast::ComparisonType::Equal),
                 ">" => Ok(ast::ComparisonType::GreaterThan),
                 "<" => Ok(ast::ComparisonType::LessThan),
+                "in_list" => Ok(ast::ComparisonType::InList),
+                "starts_with" => Ok(ast::ComparisonType::StartsWith),
+                "ends_with" => Ok(ast::ComparisonType::EndsWith),
+                "contains" => Ok(ast::ComparisonType::Contains),
+                "matches_regex" => Ok(ast::ComparisonType::MatchesRegex),
                 _ => Err(EuclidError::InvalidOperator(s.to_string())),
             },
         ),
     )(input)
 }
 
-pub fn comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
+/// Parses the metadata of a program or of a comparison, which is written as a JSON object after
+/// `@` like `@{"created_by": "dashboard"}`
+pub fn metadata(input: &str) -> ParseResult<&str, types::Metadata> {
+    fn json_object(input: &str) -> ParseResult<&str, types::Metadata> {
+        let mut objects = serde_json::Deserializer::from_str(input).into_iter::<types::Metadata>();
+        match (objects.next(), input.get(objects.byte_offset()..)) {
+            (Some(Ok(metadata)), Some(rest)) => Ok((rest, metadata)),
+            _ => Err(nom::Err::Error(error::VerboseError {
+                errors: vec![(input, error::VerboseErrorKind::Context("json_object"))],
+            })),
+        }
+    }
+
     error::context(
-        "condition",
-        combinator::map(
-            sequence::tuple((
-                skip_ws(complete::take_while1(|c: char| {
-                    c.is_ascii_alphabetic() || c == '.' || c == '_'
-                })),
-                skip_ws(comparison_type),
-                skip_ws(value_type),
-            )),
-            |tup: (&str, ast::ComparisonType, ast::ValueType)| ast::Comparison {
-                lhs: tup.0.to_string(),
-                comparison: tup.1,
-                value: tup.2,
-                metadata: std::collections::HashMap::new(),
-            },
-        ),
+        "metadata",
+        sequence::preceded(skip_ws(complete::tag("@")), skip_ws(json_object)),
     )(input)
 }
 
+pub fn comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
+    // The numbers in the value are parsed according to the key
+    fn key_comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
+        let (input, (lhs, comparison)) = sequence::pair(
+            skip_ws(complete::take_while1(|c: char| {
+                c.is_ascii_alphabetic() || c == '.' || c == '_'
+            })),
+            skip_ws(comparison_type),
+        )(input)?;
+        let (input, value) = skip_ws(value_type_with(number_parser_for_key(lhs)))(input)?;
+        let (input, metadata) = combinator::opt(metadata)(input)?;
+
+        Ok((
+            input,
+            ast::Comparison {
+                lhs: lhs.to_string(),
+                comparison,
+                value,
+                metadata: metadata.unwrap_or_default(),
+            },
+        ))
+    }
+
+    error::context("condition", key_comparison)(input)
+}
+
 pub fn arbitrary_comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
     error::context(
         "condition",
-        combinator::map(
+        combinator::map_opt(
             sequence::tuple((
                 skip_ws(string_str),
                 skip_ws(comparison_type),
-                skip_ws(string_str),
+                skip_ws(branch::alt((str_value, str_value_array_value))),
+                combinator::opt(metadata),
             )),
-            |tup: (String, ast::ComparisonType, String)| ast::Comparison {
-                lhs: "metadata".to_string(),
-                comparison: tup.1,
-                value: ast::ValueType::MetadataVariant(ast::MetadataValue {
-                    key: tup.0,
-                    value: tup.2,
-                }),
-                metadata: std::collections::HashMap::new(),
+            |tup: (
+                String,
+                ast::ComparisonType,
+                ast::ValueType,
+                Option<types::Metadata>,
+            )| {
+                let key = tup.0;
+                let value = match tup.2 {
+                    ast::ValueType::StrValue(value) => {
+                        ast::ValueType::MetadataVariant(ast::MetadataValue { key, value })
+                    }
+                    ast::ValueType::StrValueArray(values) => ast::ValueType::MetadataVariantArray(
+                        values
+                            .into_iter()
+                            .map(|value| ast::MetadataValue {
+                                key: key.clone(),
+                                value,
+                            })
+                            .collect(),
+                    ),
+                    _ => None?,
+                };
+                Some(ast::Comparison {
+                    lhs: "metadata".to_string(),
+                    comparison: tup.1,
+                    value,
+                    metadata: tup.3.unwrap_or_default(),
+                })
             },
         ),
     )(input)
@@ -416,6 +610,46 @@ pub fn rule<O: EuclidParsable>(input: &str) -> ParseResult<&str, ast::Rule<O>> {
     )(input)
 }
 
+/// Parses the connectors of an output like `["stripe", "adyen"]`, which can also have volume
+/// splits like `[70% "stripe", 30% "adyen"]`
+pub fn output_choices(input: &str) -> ParseResult<&str, Vec<ast::OutputChoice>> {
+    fn output_choice(input: &str) -> ParseResult<&str, ast::OutputChoice> {
+        combinator::map(
+            sequence::pair(combinator::opt(skip_ws(percentage)), skip_ws(string_str)),
+            |(split, name)| ast::OutputChoice { name, split },
+        )(input)
+    }
+
+    let many_with_comma = multi::many0(sequence::preceded(
+        skip_ws(complete::tag(",")),
+        output_choice,
+    ));
+
+    error::context(
+        "output_choices",
+        combinator::map(
+            sequence::delimited(
+                skip_ws(complete::tag("[")),
+                sequence::pair(output_choice, many_with_comma),
+                skip_ws(complete::tag("]")),
+            ),
+            |tup: (ast::OutputChoice, Vec<ast::OutputChoice>)| {
+                let mut rest = tup.1;
+                rest.insert(0, tup.0);
+                rest
+            },
+        ),
+    )(input)
+}
+
+/// Parses the connectors of an output, and converts them to the output of a rule
+pub fn map_output_choices<O>(
+    input: &str,
+    convert: impl FnMut(Vec<ast::OutputChoice>) -> Result<O, EuclidError>,
+) -> ParseResult<&str, O> {
+    combinator::map_res(output_choices, convert)(input)
+}
+
 pub fn output<O: EuclidParsable>(input: &str) -> ParseResult<&str, O> {
     O::parse_output(input)
 }
@@ -430,15 +664,41 @@ pub fn default_output<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&
     )(input)
 }
 
+pub fn timezone(input: &str) -> ParseResult<&str, chrono_tz::Tz> {
+    error::context(
+        "timezone",
+        sequence::preceded(
+            sequence::pair(
+                skip_ws(complete::tag("timezone")),
+                skip_ws(pchar::char(':')),
+            ),
+            skip_ws(combinator::map_res(string_str, |timezone: String| {
+                types::timezone::parse(&timezone)
+            })),
+        ),
+    )(input)
+}
+
 pub fn program<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&str, ast::Program<O>> {
     error::context(
         "program",
         combinator::map(
-            sequence::pair(default_output, multi::many1(skip_ws(rule::<O>))),
-            |tup: (O, Vec<ast::Rule<O>>)| ast::Program {
-                default_selection: tup.0,
-                rules: tup.1,
-                metadata: std::collections::HashMap::new(),
+            sequence::tuple((
+                combinator::opt(metadata),
+                combinator::opt(timezone),
+                default_output,
+                multi::many1(skip_ws(rule::<O>)),
+            )),
+            |tup: (
+                Option<types::Metadata>,
+                Option<chrono_tz::Tz>,
+                O,
+                Vec<ast::Rule<O>>,
+            )| ast::Program {
+                default_selection: tup.2,
+                rules: tup.3,
+                metadata: tup.0.unwrap_or_default(),
+                timezone: tup.1,
             },
         ),
     )(input)
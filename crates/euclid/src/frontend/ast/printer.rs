@@ -0,0 +1,595 @@
+//! Printing of the ast back to the DSL
+//!
+//! The printed DSL is the canonical form of the program, and it is accepted by the parser, which
+//! gives back the same program. The metadata of the program and of its comparisons is printed as a
+//! JSON object after `@`, with its keys sorted.
+
+use std::{collections::BTreeMap, fmt::Write, str::FromStr};
+
+use common_utils::types::MinorUnit;
+
+use crate::{
+    frontend::{ast, dir},
+    types::{self, DummyOutput},
+};
+
+const INDENT: &str = "    ";
+
+#[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
+#[serde(tag = "type", content = "info", rename_all = "snake_case")]
+pub enum PrintError {
+    #[error("Strings in the DSL have to be non empty and cannot contain '\"', got '{0}'")]
+    InvalidString(String),
+    #[error("'{0}' is not a valid identifier")]
+    InvalidIdentifier(String),
+    #[error("Negative number {0} cannot be represented in the DSL")]
+    NegativeNumber(i64),
+    #[error("Arrays in the DSL have to be non empty")]
+    EmptyArray,
+    #[error("If conditions in the DSL have to have at least one comparison")]
+    EmptyCondition,
+    #[error("Rule '{0}' has to have at least one if statement")]
+    EmptyRule(String),
+    #[error("Programs in the DSL have to have at least one rule")]
+    EmptyProgram,
+    #[error("Comparison '{0:?}' cannot be used in a number comparison array")]
+    InvalidNumberComparison(ast::ComparisonType),
+    #[error("Metadata values can only be compared on the 'metadata' key, got '{0}'")]
+    InvalidMetadataComparison(String),
+    #[error("Metadata values can only be compared in the first comparison of a condition")]
+    MisplacedMetadataComparison,
+    #[error("Volume split of {0}% cannot be represented in the DSL, which has splits up to 99%")]
+    InvalidVolumeSplit(u8),
+    #[error("Metadata cannot be represented as JSON: {0}")]
+    InvalidMetadata(String),
+}
+
+pub trait EuclidPrintable {
+    fn print_output(&self) -> Result<String, PrintError>;
+}
+
+impl EuclidPrintable for DummyOutput {
+    fn print_output(&self) -> Result<String, PrintError> {
+        let choices = self
+            .outputs
+            .iter()
+            .map(|output| ast::OutputChoice {
+                name: output.clone(),
+                split: None,
+            })
+            .collect::<Vec<_>>();
+        output_choices(&choices)
+    }
+}
+
+fn is_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+pub fn string(value: &str) -> Result<String, PrintError> {
+    if value.is_empty() || value.contains('"') {
+        return Err(PrintError::InvalidString(value.to_string()));
+    }
+
+    Ok(format!("\"{value}\""))
+}
+
+pub fn identifier(value: &str) -> Result<String, PrintError> {
+    if !is_identifier(value) {
+        return Err(PrintError::InvalidIdentifier(value.to_string()));
+    }
+
+    Ok(value.to_string())
+}
+
+/// Prints a number, numbers of the payment time keys are printed as times of day like `06:30` and
+/// dates like `2026-11-01`
+pub fn number(key: Option<&dir::DirKeyKind>, num: MinorUnit) -> Result<String, PrintError> {
+    let num = num.get_amount_as_i64();
+    if num < 0 {
+        return Err(PrintError::NegativeNumber(num));
+    }
+
+    Ok(match key {
+        Some(dir::DirKeyKind::PaymentTimeOfDay) if num < 2400 && num % 100 < 60 => {
+            format!("{:02}:{:02}", num / 100, num % 100)
+        }
+        Some(dir::DirKeyKind::PaymentDate) if (10_000_000..100_000_000).contains(&num) => {
+            format!("{:04}-{:02}-{:02}", num / 10000, num / 100 % 100, num % 100)
+        }
+        _ => num.to_string(),
+    })
+}
+
+pub fn comparison_type(comparison: &ast::ComparisonType) -> &'static str {
+    match comparison {
+        ast::ComparisonType::Equal => "=",
+        ast::ComparisonType::NotEqual => "/=",
+        ast::ComparisonType::LessThan => "<",
+        ast::ComparisonType::LessThanEqual => "<=",
+        ast::ComparisonType::GreaterThan => ">",
+        ast::ComparisonType::GreaterThanEqual => ">=",
+        ast::ComparisonType::InList => "in_list",
+        ast::ComparisonType::StartsWith => "starts_with",
+        ast::ComparisonType::EndsWith => "ends_with",
+        ast::ComparisonType::Contains => "contains",
+        ast::ComparisonType::MatchesRegex => "matches_regex",
+    }
+}
+
+fn array<T>(
+    values: &[T],
+    print_value: impl Fn(&T) -> Result<String, PrintError>,
+) -> Result<String, PrintError> {
+    if values.is_empty() {
+        return Err(PrintError::EmptyArray);
+    }
+
+    let values = values
+        .iter()
+        .map(print_value)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(format!("({})", values.join(", ")))
+}
+
+pub fn number_comparison(
+    key: Option<&dir::DirKeyKind>,
+    comparison: &ast::NumberComparison,
+) -> Result<String, PrintError> {
+    match &comparison.comparison_type {
+        ast::ComparisonType::LessThan
+        | ast::ComparisonType::LessThanEqual
+        | ast::ComparisonType::GreaterThan
+        | ast::ComparisonType::GreaterThanEqual => Ok(format!(
+            "{} {}",
+            comparison_type(&comparison.comparison_type),
+            number(key, comparison.number)?
+        )),
+        other => Err(PrintError::InvalidNumberComparison(other.clone())),
+    }
+}
+
+/// Prints the value of a comparison on the given key, metadata values are printed by
+/// [`comparison`] as they are written along with their metadata key
+pub fn value_type(lhs: &str, value: &ast::ValueType) -> Result<String, PrintError> {
+    let key = dir::DirKeyKind::from_str(lhs).ok();
+    let key = key.as_ref();
+
+    match value {
+        ast::ValueType::Number(num) => number(key, *num),
+        ast::ValueType::EnumVariant(variant) => identifier(variant),
+        ast::ValueType::StrValue(value) => string(value),
+        ast::ValueType::NumberArray(nums) => array(nums, |num| number(key, *num)),
+        ast::ValueType::EnumVariantArray(variants) => {
+            array(variants, |variant| identifier(variant))
+        }
+        ast::ValueType::NumberComparisonArray(comparisons) => {
+            array(comparisons, |comparison| number_comparison(key, comparison))
+        }
+        ast::ValueType::StrValueArray(values) => array(values, |value| string(value)),
+        ast::ValueType::MetadataVariant(_) | ast::ValueType::MetadataVariantArray(_) => {
+            Err(PrintError::InvalidMetadataComparison(lhs.to_string()))
+        }
+    }
+}
+
+/// Prints non empty metadata as a JSON object after `@`, the keys are sorted so that the same
+/// metadata is always printed the same way
+pub fn metadata(metadata: &types::Metadata) -> Result<Option<String>, PrintError> {
+    if metadata.is_empty() {
+        return Ok(None);
+    }
+
+    let metadata = metadata.iter().collect::<BTreeMap<_, _>>();
+    serde_json::to_string(&metadata)
+        .map(|json| Some(format!("@{json}")))
+        .map_err(|error| PrintError::InvalidMetadata(error.to_string()))
+}
+
+fn is_metadata_comparison(comparison: &ast::Comparison) -> bool {
+    matches!(
+        comparison.value,
+        ast::ValueType::MetadataVariant(_) | ast::ValueType::MetadataVariantArray(_)
+    )
+}
+
+pub fn comparison(comparison: &ast::Comparison) -> Result<String, PrintError> {
+    let operator = comparison_type(&comparison.comparison);
+    let is_metadata_comparison = comparison.lhs == dir::DirKeyKind::MetaData.to_string();
+
+    let (lhs, value) = match &comparison.value {
+        ast::ValueType::MetadataVariant(_) | ast::ValueType::MetadataVariantArray(_)
+            if !is_metadata_comparison =>
+        {
+            return Err(PrintError::InvalidMetadataComparison(
+                comparison.lhs.clone(),
+            ));
+        }
+        ast::ValueType::MetadataVariant(metadata_value) => {
+            (string(&metadata_value.key)?, string(&metadata_value.value)?)
+        }
+        ast::ValueType::MetadataVariantArray(metadata_values) => {
+            let key = metadata_values
+                .first()
+                .map(|metadata_value| metadata_value.key.as_str())
+                .ok_or(PrintError::EmptyArray)?;
+            // All the values of an array are parsed with the same metadata key
+            if metadata_values
+                .iter()
+                .any(|metadata_value| metadata_value.key != key)
+            {
+                return Err(PrintError::InvalidMetadataComparison(key.to_string()));
+            }
+            (
+                string(key)?,
+                array(metadata_values, |metadata_value| {
+                    string(&metadata_value.value)
+                })?,
+            )
+        }
+        value => {
+            if comparison.lhs.is_empty()
+                || !comparison
+                    .lhs
+                    .chars()
+                    .all(|c| c.is_ascii_alphabetic() || c == '.' || c == '_')
+            {
+                return Err(PrintError::InvalidIdentifier(comparison.lhs.clone()));
+            }
+            (comparison.lhs.clone(), value_type(&comparison.lhs, value)?)
+        }
+    };
+
+    Ok(match metadata(&comparison.metadata)? {
+        Some(metadata) => format!("{lhs} {operator} {value} {metadata}"),
+        None => format!("{lhs} {operator} {value}"),
+    })
+}
+
+fn write_if_statement(
+    out: &mut String,
+    stmt: &ast::IfStatement,
+    depth: usize,
+) -> Result<(), PrintError> {
+    if stmt.condition.is_empty() {
+        return Err(PrintError::EmptyCondition);
+    }
+    // The parser only accepts a metadata comparison as the first comparison of a condition
+    if stmt.condition.iter().skip(1).any(is_metadata_comparison) {
+        return Err(PrintError::MisplacedMetadataComparison);
+    }
+
+    let condition = stmt
+        .condition
+        .iter()
+        .map(comparison)
+        .collect::<Result<Vec<_>, _>>()?;
+    let indent = INDENT.repeat(depth);
+    out.push_str(&indent);
+    out.push_str(&condition.join(" & "));
+
+    match &stmt.nested {
+        None => out.push('\n'),
+        Some(nested) if nested.is_empty() => out.push_str(" {}\n"),
+        Some(nested) => {
+            out.push_str(" {\n");
+            for nested_stmt in nested {
+                write_if_statement(out, nested_stmt, depth + 1)?;
+            }
+            out.push_str(&indent);
+            out.push_str("}\n");
+        }
+    }
+
+    Ok(())
+}
+
+pub fn if_statement(stmt: &ast::IfStatement) -> Result<String, PrintError> {
+    let mut out = String::new();
+    write_if_statement(&mut out, stmt, 0)?;
+    Ok(out)
+}
+
+pub fn output_choices(choices: &[ast::OutputChoice]) -> Result<String, PrintError> {
+    if choices.is_empty() {
+        return Err(PrintError::EmptyArray);
+    }
+
+    let choices = choices
+        .iter()
+        .map(|choice| {
+            let name = string(&choice.name)?;
+            match choice.split {
+                None => Ok(name),
+                Some(split) if split < 100 => Ok(format!("{split}% {name}")),
+                Some(split) => Err(PrintError::InvalidVolumeSplit(split)),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(format!("[{}]", choices.join(", ")))
+}
+
+pub fn rule<O: EuclidPrintable>(rule: &ast::Rule<O>) -> Result<String, PrintError> {
+    let name = identifier(&rule.name)?;
+    if rule.statements.is_empty() {
+        return Err(PrintError::EmptyRule(name));
+    }
+
+    let mut out = format!("{name}: {}\n{{\n", rule.connector_selection.print_output()?);
+    for stmt in &rule.statements {
+        write_if_statement(&mut out, stmt, 1)?;
+    }
+    out.push_str("}\n");
+    Ok(out)
+}
+
+/// Prints the program as DSL text, which parses back into the same program
+///
+/// ```text
+/// @{"created_by":"dashboard"}
+/// timezone: "Asia/Kolkata"
+/// default: ["stripe", "adyen"]
+///
+/// rule_1: ["adyen"]
+/// {
+///     payment_method = card @{"source":"card_rules"} {
+///         card_network = (visa, mastercard)
+///     }
+/// }
+/// ```
+pub fn program<O: EuclidPrintable>(program: &ast::Program<O>) -> Result<String, PrintError> {
+    if program.rules.is_empty() {
+        return Err(PrintError::EmptyProgram);
+    }
+
+    let mut out = String::new();
+
+    if let Some(metadata) = metadata(&program.metadata)? {
+        writeln!(out, "{metadata}").ok();
+    }
+
+    if let Some(timezone) = program.timezone {
+        writeln!(out, "timezone: {}", string(timezone.name())?).ok();
+    }
+
+    writeln!(
+        out,
+        "default: {}",
+        program.default_selection.print_output()?
+    )
+    .ok();
+
+    for rule_value in &program.rules {
+        out.push('\n');
+        out.push_str(&rule(rule_value)?);
+    }
+
+    Ok(out)
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod test {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::frontend::ast::parser;
+
+    fn assert_round_trip(program: &ast::Program<DummyOutput>) {
+        let dsl = super::program(program).expect("Print program");
+        let (rest, parsed) = parser::program::<DummyOutput>(&dsl).expect("Parse program");
+
+        assert!(rest.trim().is_empty(), "Unparsed DSL: {rest}");
+        assert_eq!(&parsed, program, "DSL: {dsl}");
+        assert_eq!(super::program(&parsed).expect("Print program"), dsl);
+    }
+
+    #[test]
+    fn test_program_round_trip() {
+        let program_str = r#"
+        @{"created_by": "dashboard", "version": 2}
+        timezone: "Asia/Kolkata"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["stripe"]
+        {
+            payment_method = card @{"source": {"rule": "cards"}} & amount > 500 {
+                card_network = (visa, mastercard) & card_bin starts_with ("4000", "5100")
+                amount = (>= 1000, < 5000) {}
+            }
+            "order_ref" in_list ("ORD", "INV") & payment_method /= (wallet, pay_later)
+        }
+
+        night_window: ["adyen"]
+        {
+            payment_time_of_day = (>= 22:00, < 06:30) & payment_date >= 2026-11-01
+            "order_ref" matches_regex "ORD-[0-9]{4}" @{"owner": "ops"} & card_bin in_list ("424242")
+        }
+        "#;
+
+        let (_, program) = parser::program::<DummyOutput>(program_str).expect("Program");
+        assert_eq!(program.rules.len(), 2);
+        assert_eq!(program.metadata.get("version"), Some(&serde_json::json!(2)));
+        assert_round_trip(&program);
+
+        let dsl = super::program(&program).expect("Print program");
+        assert!(dsl.starts_with("@{\"created_by\":\"dashboard\",\"version\":2}\n"));
+        assert!(
+            dsl.contains("payment_method = card @{\"source\":{\"rule\":\"cards\"}} & amount > 500")
+        );
+        assert!(dsl.contains("payment_time_of_day = (>= 22:00, < 06:30)"));
+    }
+
+    #[test]
+    fn test_metadata_round_trip() {
+        let metadata = std::collections::HashMap::from([
+            (
+                "b".to_string(),
+                serde_json::json!({"nested": [1, "two", null]}),
+            ),
+            (
+                "a".to_string(),
+                serde_json::json!("quoted \"value\" with {braces} & more"),
+            ),
+        ]);
+        let program = ast::Program {
+            default_selection: DummyOutput {
+                outputs: vec!["stripe".to_string()],
+            },
+            rules: vec![ast::Rule {
+                name: "rule_1".to_string(),
+                connector_selection: DummyOutput {
+                    outputs: vec!["adyen".to_string()],
+                },
+                statements: vec![ast::IfStatement {
+                    condition: vec![
+                        ast::Comparison {
+                            lhs: "metadata".to_string(),
+                            comparison: ast::ComparisonType::Equal,
+                            value: ast::ValueType::MetadataVariant(ast::MetadataValue {
+                                key: "order_ref".to_string(),
+                                value: "ORD".to_string(),
+                            }),
+                            metadata: metadata.clone(),
+                        },
+                        ast::Comparison {
+                            lhs: "amount".to_string(),
+                            comparison: ast::ComparisonType::GreaterThan,
+                            value: ast::ValueType::Number(MinorUnit::new(500)),
+                            metadata: metadata.clone(),
+                        },
+                    ],
+                    nested: Some(Vec::new()),
+                }],
+            }],
+            metadata,
+            timezone: None,
+        };
+
+        assert_round_trip(&program);
+    }
+
+    #[test]
+    fn test_unrepresentable_programs() {
+        let rule = |name: &str, statements: Vec<ast::IfStatement>| ast::Rule {
+            name: name.to_string(),
+            connector_selection: DummyOutput {
+                outputs: vec!["adyen".to_string()],
+            },
+            statements,
+        };
+        let statement = ast::IfStatement {
+            condition: vec![ast::Comparison {
+                lhs: "amount".to_string(),
+                comparison: ast::ComparisonType::GreaterThan,
+                value: ast::ValueType::Number(MinorUnit::new(500)),
+                metadata: std::collections::HashMap::new(),
+            }],
+            nested: None,
+        };
+        let program = |rules: Vec<ast::Rule<DummyOutput>>| ast::Program {
+            default_selection: DummyOutput {
+                outputs: vec!["stripe".to_string()],
+            },
+            rules,
+            metadata: std::collections::HashMap::new(),
+            timezone: None,
+        };
+
+        assert!(matches!(
+            super::program(&program(Vec::new())),
+            Err(PrintError::EmptyProgram)
+        ));
+        assert!(matches!(
+            super::program(&program(vec![rule("rule_1", Vec::new())])),
+            Err(PrintError::EmptyRule(_))
+        ));
+        assert!(matches!(
+            super::program(&program(vec![rule(
+                "night window",
+                vec![statement.clone()]
+            )])),
+            Err(PrintError::InvalidIdentifier(_))
+        ));
+
+        let mut misplaced = statement;
+        misplaced.condition.push(ast::Comparison {
+            lhs: "metadata".to_string(),
+            comparison: ast::ComparisonType::Equal,
+            value: ast::ValueType::MetadataVariant(ast::MetadataValue {
+                key: "order_ref".to_string(),
+                value: "ORD".to_string(),
+            }),
+            metadata: std::collections::HashMap::new(),
+        });
+        assert!(matches!(
+            super::program(&program(vec![rule("rule_1", vec![misplaced])])),
+            Err(PrintError::MisplacedMetadataComparison)
+        ));
+    }
+
+    #[test]
+    fn test_output_choices_round_trip() {
+        let choices = vec![
+            ast::OutputChoice {
+                name: "stripe".to_string(),
+                split: Some(70),
+            },
+            ast::OutputChoice {
+                name: "adyen:mca_123".to_string(),
+                split: Some(30),
+            },
+        ];
+
+        let dsl = output_choices(&choices).expect("Print output choices");
+        assert_eq!(dsl, r#"[70% "stripe", 30% "adyen:mca_123"]"#);
+
+        let (rest, parsed) = parser::output_choices(&dsl).expect("Parse output choices");
+        assert!(rest.is_empty());
+        assert_eq!(parsed, choices);
+
+        let full_split = ast::OutputChoice {
+            name: "stripe".to_string(),
+            split: Some(100),
+        };
+        assert!(matches!(
+            output_choices(&[full_split]),
+            Err(PrintError::InvalidVolumeSplit(100))
+        ));
+    }
+
+    #[test]
+    fn test_unrepresentable_values() {
+        let comparison = |lhs: &str, value: ast::ValueType| ast::Comparison {
+            lhs: lhs.to_string(),
+            comparison: ast::ComparisonType::Equal,
+            value,
+            metadata: std::collections::HashMap::new(),
+        };
+
+        assert!(matches!(
+            super::comparison(&comparison(
+                "business_label",
+                ast::ValueType::StrValue("say \"hi\"".to_string())
+            )),
+            Err(PrintError::InvalidString(_))
+        ));
+        assert!(matches!(
+            super::comparison(&comparison(
+                "amount",
+                ast::ValueType::Number(MinorUnit::new(-1))
+            )),
+            Err(PrintError::NegativeNumber(-1))
+        ));
+        assert!(matches!(
+            super::comparison(&comparison(
+                "payment_method",
+                ast::ValueType::EnumVariantArray(Vec::new())
+            )),
+            Err(PrintError::EmptyArray)
+        ));
+    }
+}
@@ -34,6 +34,7 @@ macro_rules! dirval {
     ($key:ident s= $str:literal) => {{
         $crate::frontend::dir::DirValue::$key($crate::types::StrValue {
             value: $str.to_string(),
+            refinement: None,
         })
     }};
 
@@ -41,6 +42,7 @@ macro_rules! dirval {
         $crate::frontend::dir::DirValue::MetaData($crate::types::MetadataValue {
             key: $key.to_string(),
             value: $str.to_string(),
+            refinement: None,
         })
     }};
 }
@@ -241,6 +243,13 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "billing_country")]
     BillingCountry,
+    #[strum(
+        serialize = "customer_email",
+        detailed_message = "Email address of the customer",
+        props(Category = "Customer")
+    )]
+    #[serde(rename = "customer_email")]
+    CustomerEmail,
     #[serde(skip_deserializing, rename = "connector")]
     Connector,
     #[strum(
@@ -334,6 +343,48 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "acquirer_fraud_rate")]
     AcquirerFraudRate,
+    #[strum(
+        serialize = "payment_time_of_day",
+        detailed_message = "Time of day at which the payment was created, as HH:MM",
+        props(Category = "Payment Time")
+    )]
+    #[serde(rename = "payment_time_of_day")]
+    PaymentTimeOfDay,
+    #[strum(
+        serialize = "payment_day_of_week",
+        detailed_message = "Day of the week on which the payment was created",
+        props(Category = "Payment Time")
+    )]
+    #[serde(rename = "payment_day_of_week")]
+    PaymentDayOfWeek,
+    #[strum(
+        serialize = "payment_date",
+        detailed_message = "Date on which the payment was created, as YYYY-MM-DD",
+        props(Category = "Payment Time")
+    )]
+    #[serde(rename = "payment_date")]
+    PaymentDate,
+    #[strum(
+        serialize = "customer_attempt_count",
+        detailed_message = "Number of payment attempts made by the customer in the recent minutes",
+        props(Category = "Velocity")
+    )]
+    #[serde(rename = "customer_attempt_count")]
+    CustomerAttemptCount,
+    #[strum(
+        serialize = "card_failure_count",
+        detailed_message = "Number of failed payment attempts made with the card today",
+        props(Category = "Velocity")
+    )]
+    #[serde(rename = "card_failure_count")]
+    CardFailureCount,
+    #[strum(
+        serialize = "customer_transacted_amount",
+        detailed_message = "Amount transacted by the customer in the last 24 hours, in the currency of the payment",
+        props(Category = "Velocity")
+    )]
+    #[serde(rename = "customer_transacted_amount")]
+    CustomerTransactedAmount,
 }
 
 pub trait EuclidDirFilter: Sized
@@ -351,6 +402,14 @@ where
 }
 
 impl DirKeyKind {
+    /// Keys evaluated against the creation time of the payment
+    pub fn is_payment_time_key(&self) -> bool {
+        matches!(
+            self,
+            Self::PaymentTimeOfDay | Self::PaymentDayOfWeek | Self::PaymentDate
+        )
+    }
+
     pub fn get_type(&self) -> types::DataType {
         match self {
             Self::PaymentMethod => types::DataType::EnumVariant,
@@ -376,6 +435,7 @@ impl DirKeyKind {
             Self::CaptureMethod => types::DataType::EnumVariant,
             Self::BusinessCountry => types::DataType::EnumVariant,
             Self::BillingCountry => types::DataType::EnumVariant,
+            Self::CustomerEmail => types::DataType::StrValue,
             Self::Connector => types::DataType::EnumVariant,
             Self::BankDebitType => types::DataType::EnumVariant,
             Self::BusinessLabel => types::DataType::StrValue,
@@ -391,6 +451,12 @@ impl DirKeyKind {
             Self::CustomerDeviceDisplaySize => types::DataType::EnumVariant,
             Self::AcquirerCountry => types::DataType::EnumVariant,
             Self::AcquirerFraudRate => types::DataType::Number,
+            Self::PaymentTimeOfDay => types::DataType::Number,
+            Self::PaymentDayOfWeek => types::DataType::EnumVariant,
+            Self::PaymentDate => types::DataType::Number,
+            Self::CustomerAttemptCount => types::DataType::Number,
+            Self::CardFailureCount => types::DataType::Number,
+            Self::CustomerTransactedAmount => types::DataType::Number,
         }
     }
     pub fn get_value_set(&self) -> Option<Vec<DirValue>> {
@@ -503,6 +569,7 @@ impl DirKeyKind {
                     .collect(),
             ),
             Self::BusinessLabel => None,
+            Self::CustomerEmail => None,
             Self::SetupFutureUsage => Some(
                 enums::SetupFutureUsage::iter()
                     .map(DirValue::SetupFutureUsage)
@@ -555,6 +622,16 @@ impl DirKeyKind {
                     .collect(),
             ),
             Self::AcquirerFraudRate => None,
+            Self::PaymentTimeOfDay => None,
+            Self::PaymentDayOfWeek => Some(
+                enums::PaymentDayOfWeek::iter()
+                    .map(DirValue::PaymentDayOfWeek)
+                    .collect(),
+            ),
+            Self::PaymentDate => None,
+            Self::CustomerAttemptCount => None,
+            Self::CardFailureCount => None,
+            Self::CustomerTransactedAmount => None,
         }
     }
 }
@@ -612,6 +689,8 @@ pub enum DirValue {
     BusinessCountry(enums::Country),
     #[serde(rename = "billing_country")]
     BillingCountry(enums::Country),
+    #[serde(rename = "customer_email")]
+    CustomerEmail(types::StrValue),
     #[serde(skip_deserializing, rename = "connector")]
     Connector(Box<ast::ConnectorChoice>),
     #[serde(rename = "business_label")]
@@ -640,6 +719,18 @@ pub enum DirValue {
     AcquirerCountry(enums::Country),
     #[serde(rename = "acquirer_fraud_rate")]
     AcquirerFraudRate(types::NumValue),
+    #[serde(rename = "payment_time_of_day")]
+    PaymentTimeOfDay(types::NumValue),
+    #[serde(rename = "payment_day_of_week")]
+    PaymentDayOfWeek(enums::PaymentDayOfWeek),
+    #[serde(rename = "payment_date")]
+    PaymentDate(types::NumValue),
+    #[serde(rename = "customer_attempt_count")]
+    CustomerAttemptCount(types::NumValue),
+    #[serde(rename = "card_failure_count")]
+    CardFailureCount(types::NumValue),
+    #[serde(rename = "customer_transacted_amount")]
+    CustomerTransactedAmount(types::NumValue),
 }
 
 impl DirValue {
@@ -650,6 +741,7 @@ impl DirValue {
             Self::RewardType(_) => (DirKeyKind::RewardType, None),
             Self::BusinessCountry(_) => (DirKeyKind::BusinessCountry, None),
             Self::BillingCountry(_) => (DirKeyKind::BillingCountry, None),
+            Self::CustomerEmail(_) => (DirKeyKind::CustomerEmail, None),
             Self::BankTransferType(_) => (DirKeyKind::BankTransferType, None),
             Self::UpiType(_) => (DirKeyKind::UpiType, None),
             Self::CardType(_) => (DirKeyKind::CardType, None),
@@ -683,6 +775,12 @@ impl DirValue {
             Self::CustomerDeviceDisplaySize(_) => (DirKeyKind::CustomerDeviceDisplaySize, None),
             Self::AcquirerCountry(_) => (DirKeyKind::AcquirerCountry, None),
             Self::AcquirerFraudRate(_) => (DirKeyKind::AcquirerFraudRate, None),
+            Self::PaymentTimeOfDay(_) => (DirKeyKind::PaymentTimeOfDay, None),
+            Self::PaymentDayOfWeek(_) => (DirKeyKind::PaymentDayOfWeek, None),
+            Self::PaymentDate(_) => (DirKeyKind::PaymentDate, None),
+            Self::CustomerAttemptCount(_) => (DirKeyKind::CustomerAttemptCount, None),
+            Self::CardFailureCount(_) => (DirKeyKind::CardFailureCount, None),
+            Self::CustomerTransactedAmount(_) => (DirKeyKind::CustomerTransactedAmount, None),
         };
 
         DirKey::new(kind, data)
@@ -705,6 +803,7 @@ impl DirValue {
             Self::PaymentCurrency(_) => None,
             Self::BusinessCountry(_) => None,
             Self::BillingCountry(_) => None,
+            Self::CustomerEmail(_) => None,
             Self::Connector(_) => None,
             Self::BankTransferType(_) => None,
             Self::UpiType(_) => None,
@@ -727,6 +826,12 @@ impl DirValue {
             Self::CustomerDeviceDisplaySize(_) => None,
             Self::AcquirerCountry(_) => None,
             Self::AcquirerFraudRate(_) => None,
+            Self::PaymentTimeOfDay(_) => None,
+            Self::PaymentDayOfWeek(_) => None,
+            Self::PaymentDate(_) => None,
+            Self::CustomerAttemptCount(_) => None,
+            Self::CardFailureCount(_) => None,
+            Self::CustomerTransactedAmount(_) => None,
         }
     }
 
@@ -734,6 +839,7 @@ impl DirValue {
         match self {
             Self::CardBin(val) => Some(val.clone()),
             Self::IssuerName(val) => Some(val.clone()),
+            Self::CustomerEmail(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -742,6 +848,11 @@ impl DirValue {
         match self {
             Self::PaymentAmount(val) => Some(val.clone()),
             Self::AcquirerFraudRate(val) => Some(val.clone()),
+            Self::PaymentTimeOfDay(val) => Some(val.clone()),
+            Self::PaymentDate(val) => Some(val.clone()),
+            Self::CustomerAttemptCount(val) => Some(val.clone()),
+            Self::CardFailureCount(val) => Some(val.clone()),
+            Self::CustomerTransactedAmount(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -764,6 +875,7 @@ impl DirValue {
             (Self::PaymentCurrency(pc1), Self::PaymentCurrency(pc2)) => pc1 == pc2,
             (Self::BusinessCountry(c1), Self::BusinessCountry(c2)) => c1 == c2,
             (Self::BillingCountry(c1), Self::BillingCountry(c2)) => c1 == c2,
+            (Self::CustomerEmail(e1), Self::CustomerEmail(e2)) => e1 == e2,
             (Self::PaymentType(pt1), Self::PaymentType(pt2)) => pt1 == pt2,
             (Self::MandateType(mt1), Self::MandateType(mt2)) => mt1 == mt2,
             (Self::MandateAcceptanceType(mat1), Self::MandateAcceptanceType(mat2)) => mat1 == mat2,
@@ -782,6 +894,12 @@ impl DirValue {
             (Self::CustomerDeviceDisplaySize(s1), Self::CustomerDeviceDisplaySize(s2)) => s1 == s2,
             (Self::AcquirerCountry(c1), Self::AcquirerCountry(c2)) => c1 == c2,
             (Self::AcquirerFraudRate(r1), Self::AcquirerFraudRate(r2)) => r1 == r2,
+            (Self::PaymentTimeOfDay(t1), Self::PaymentTimeOfDay(t2)) => t1 == t2,
+            (Self::PaymentDayOfWeek(d1), Self::PaymentDayOfWeek(d2)) => d1 == d2,
+            (Self::PaymentDate(d1), Self::PaymentDate(d2)) => d1 == d2,
+            (Self::CustomerAttemptCount(c1), Self::CustomerAttemptCount(c2)) => c1 == c2,
+            (Self::CardFailureCount(c1), Self::CardFailureCount(c2)) => c1 == c2,
+            (Self::CustomerTransactedAmount(a1), Self::CustomerTransactedAmount(a2)) => a1 == a2,
             _ => false,
         }
     }
@@ -915,6 +1033,7 @@ pub struct DirProgram<O> {
     pub default_selection: O,
     pub rules: Vec<DirRule<O>>,
     pub metadata: types::Metadata,
+    pub timezone: Option<chrono_tz::Tz>,
 }
 
 #[cfg(test)]
@@ -965,7 +1084,14 @@ mod test {
             dirval!(AuthenticationType = ThreeDs),
             dirval!(CaptureMethod = Manual),
             dirval!(BillingCountry = UnitedStatesOfAmerica),
+            dirval!(CustomerEmail s= "john@example.com"),
             dirval!(BusinessCountry = France),
+            dirval!(PaymentTimeOfDay = 600),
+            dirval!(PaymentDayOfWeek = Saturday),
+            dirval!(PaymentDate = 20261101),
+            dirval!(CustomerAttemptCount = 3),
+            dirval!(CardFailureCount = 2),
+            dirval!(CustomerTransactedAmount = 50000),
         ];
 
         for val in values {
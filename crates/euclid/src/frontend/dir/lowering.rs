@@ -250,6 +250,7 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::PaymentCurrency(pc) => EuclidValue::PaymentCurrency(pc),
         dir::DirValue::BusinessCountry(buc) => EuclidValue::BusinessCountry(buc),
         dir::DirValue::BillingCountry(bic) => EuclidValue::BillingCountry(bic),
+        dir::DirValue::CustomerEmail(email) => EuclidValue::CustomerEmail(email),
         dir::DirValue::MandateAcceptanceType(mat) => EuclidValue::MandateAcceptanceType(mat),
         dir::DirValue::MandateType(mt) => EuclidValue::MandateType(mt),
         dir::DirValue::PaymentType(pt) => EuclidValue::PaymentType(pt),
@@ -275,6 +276,16 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         }
         dir::DirValue::AcquirerCountry(country) => EuclidValue::AcquirerCountry(country),
         dir::DirValue::AcquirerFraudRate(num_value) => EuclidValue::AcquirerFraudRate(num_value),
+        dir::DirValue::PaymentTimeOfDay(num_value) => EuclidValue::PaymentTimeOfDay(num_value),
+        dir::DirValue::PaymentDayOfWeek(day_of_week) => EuclidValue::PaymentDayOfWeek(day_of_week),
+        dir::DirValue::PaymentDate(num_value) => EuclidValue::PaymentDate(num_value),
+        dir::DirValue::CustomerAttemptCount(num_value) => {
+            EuclidValue::CustomerAttemptCount(num_value)
+        }
+        dir::DirValue::CardFailureCount(num_value) => EuclidValue::CardFailureCount(num_value),
+        dir::DirValue::CustomerTransactedAmount(num_value) => {
+            EuclidValue::CustomerTransactedAmount(num_value)
+        }
     })
 }
 
@@ -346,5 +357,6 @@ pub fn lower_program<O>(
                 metadata: Default::default(),
             })?,
         metadata: dir_program.metadata,
+        timezone: dir_program.timezone,
     })
 }
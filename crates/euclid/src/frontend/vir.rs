@@ -36,4 +36,6 @@ pub struct ValuedProgram<O> {
     pub default_selection: O,
     pub rules: Vec<ValuedRule<O>>,
     pub metadata: Metadata,
+    #[serde(default, with = "crate::types::timezone::option")]
+    pub timezone: Option<chrono_tz::Tz>,
 }
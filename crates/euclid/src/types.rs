@@ -11,7 +11,10 @@ use crate::{
     frontend::{
         ast,
         dir::{
-            enums::{CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType},
+            enums::{
+                CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType,
+                PaymentDayOfWeek,
+            },
             DirKeyKind, DirValue, EuclidDirFilter,
         },
     },
@@ -59,6 +62,8 @@ pub enum EuclidKey {
     BusinessCountry,
     #[strum(serialize = "billing_country")]
     BillingCountry,
+    #[strum(serialize = "customer_email")]
+    CustomerEmail,
     #[strum(serialize = "business_label")]
     BusinessLabel,
     #[strum(serialize = "setup_future_usage")]
@@ -77,6 +82,18 @@ pub enum EuclidKey {
     CustomerDeviceDisplaySize,
     #[strum(serialize = "customer_device_platform")]
     CustomerDevicePlatform,
+    #[strum(serialize = "payment_time_of_day")]
+    PaymentTimeOfDay,
+    #[strum(serialize = "payment_day_of_week")]
+    PaymentDayOfWeek,
+    #[strum(serialize = "payment_date")]
+    PaymentDate,
+    #[strum(serialize = "customer_attempt_count")]
+    CustomerAttemptCount,
+    #[strum(serialize = "card_failure_count")]
+    CardFailureCount,
+    #[strum(serialize = "customer_transacted_amount")]
+    CustomerTransactedAmount,
 }
 
 impl EuclidDirFilter for DummyOutput {
@@ -88,6 +105,7 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::CaptureMethod,
         DirKeyKind::AuthenticationType,
         DirKeyKind::CardBin,
+        DirKeyKind::CustomerEmail,
         DirKeyKind::PayLaterType,
         DirKeyKind::PaymentAmount,
         DirKeyKind::MetaData,
@@ -95,6 +113,17 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::MandateType,
         DirKeyKind::PaymentType,
         DirKeyKind::SetupFutureUsage,
+        DirKeyKind::PaymentTimeOfDay,
+        DirKeyKind::PaymentDayOfWeek,
+        DirKeyKind::PaymentDate,
+        DirKeyKind::CustomerAttemptCount,
+        DirKeyKind::CardFailureCount,
+        DirKeyKind::CustomerTransactedAmount,
+        DirKeyKind::IssuerName,
+        DirKeyKind::IssuerCountry,
+        DirKeyKind::CustomerDevicePlatform,
+        DirKeyKind::AcquirerCountry,
+        DirKeyKind::AcquirerFraudRate,
     ];
 }
 impl EuclidAnalysable for DummyOutput {
@@ -108,6 +137,7 @@ impl EuclidAnalysable for DummyOutput {
                     DirValue::MetaData(MetadataValue {
                         key: metadata_key.clone(),
                         value: metadata_value.clone(),
+                        refinement: None,
                     }),
                     std::collections::HashMap::from_iter([(
                         "DUMMY_OUTPUT".to_string(),
@@ -122,7 +152,7 @@ impl EuclidAnalysable for DummyOutput {
             .collect()
     }
 }
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DummyOutput {
     pub outputs: Vec<String>,
 }
@@ -137,6 +167,37 @@ pub enum DataType {
     StrValue,
 }
 
+impl DataType {
+    /// The comparisons that can be performed on values of the data type
+    pub fn get_comparison_types(&self) -> Vec<ast::ComparisonType> {
+        match self {
+            Self::Number => vec![
+                ast::ComparisonType::Equal,
+                ast::ComparisonType::NotEqual,
+                ast::ComparisonType::LessThan,
+                ast::ComparisonType::LessThanEqual,
+                ast::ComparisonType::GreaterThan,
+                ast::ComparisonType::GreaterThanEqual,
+                ast::ComparisonType::InList,
+            ],
+            Self::EnumVariant => vec![
+                ast::ComparisonType::Equal,
+                ast::ComparisonType::NotEqual,
+                ast::ComparisonType::InList,
+            ],
+            Self::MetadataValue | Self::StrValue => vec![
+                ast::ComparisonType::Equal,
+                ast::ComparisonType::NotEqual,
+                ast::ComparisonType::InList,
+                ast::ComparisonType::StartsWith,
+                ast::ComparisonType::EndsWith,
+                ast::ComparisonType::Contains,
+                ast::ComparisonType::MatchesRegex,
+            ],
+        }
+    }
+}
+
 impl EuclidKey {
     pub fn key_type(&self) -> DataType {
         match self {
@@ -151,6 +212,7 @@ impl EuclidKey {
             Self::PaymentCurrency => DataType::EnumVariant,
             Self::BusinessCountry => DataType::EnumVariant,
             Self::BillingCountry => DataType::EnumVariant,
+            Self::CustomerEmail => DataType::StrValue,
             Self::MandateType => DataType::EnumVariant,
             Self::MandateAcceptanceType => DataType::EnumVariant,
             Self::PaymentType => DataType::EnumVariant,
@@ -163,8 +225,23 @@ impl EuclidKey {
             Self::CustomerDeviceType => DataType::EnumVariant,
             Self::CustomerDeviceDisplaySize => DataType::EnumVariant,
             Self::CustomerDevicePlatform => DataType::EnumVariant,
+            Self::PaymentTimeOfDay => DataType::Number,
+            Self::PaymentDayOfWeek => DataType::EnumVariant,
+            Self::PaymentDate => DataType::Number,
+            Self::CustomerAttemptCount => DataType::Number,
+            Self::CardFailureCount => DataType::Number,
+            Self::CustomerTransactedAmount => DataType::Number,
         }
     }
+
+    /// Whether the value of the key is derived from the payments made before the payment, rather
+    /// than from the payment itself
+    pub fn is_velocity_key(&self) -> bool {
+        matches!(
+            self,
+            Self::CustomerAttemptCount | Self::CardFailureCount | Self::CustomerTransactedAmount
+        )
+    }
 }
 
 enums::collect_variants!(EuclidKey);
@@ -188,6 +265,11 @@ impl From<ast::ComparisonType> for Option<NumValueRefinement> {
             ast::ComparisonType::LessThan => Some(NumValueRefinement::LessThan),
             ast::ComparisonType::LessThanEqual => Some(NumValueRefinement::LessThanEqual),
             ast::ComparisonType::GreaterThanEqual => Some(NumValueRefinement::GreaterThanEqual),
+            ast::ComparisonType::InList
+            | ast::ComparisonType::StartsWith
+            | ast::ComparisonType::EndsWith
+            | ast::ComparisonType::Contains
+            | ast::ComparisonType::MatchesRegex => None,
         }
     }
 }
@@ -204,15 +286,122 @@ impl From<NumValueRefinement> for ast::ComparisonType {
     }
 }
 
+/// The maximum length of a `matches_regex` pattern
+pub const MAX_REGEX_PATTERN_LENGTH: usize = 256;
+
+/// The maximum size of a compiled `matches_regex` pattern, in bytes
+const REGEX_SIZE_LIMIT: usize = 1 << 16;
+
+/// Compiles a `matches_regex` pattern, which has to match the entire value
+pub fn compile_anchored_regex(pattern: &str) -> Result<regex::Regex, regex::Error> {
+    let build = |pattern: &str| {
+        regex::RegexBuilder::new(pattern)
+            .size_limit(REGEX_SIZE_LIMIT)
+            .dfa_size_limit(REGEX_SIZE_LIMIT)
+            .build()
+    };
+
+    // The pattern is compiled on its own first, so that an unbalanced pattern like `a)|(b` cannot
+    // escape the anchors it is wrapped in
+    build(pattern)?;
+    build(&format!("^(?:{pattern})$"))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StrValueRefinement {
+    StartsWith,
+    EndsWith,
+    Contains,
+    MatchesRegex,
+}
+
+impl StrValueRefinement {
+    /// Checks whether the value matches the pattern. Regex patterns are compiled on the fly if a
+    /// compiled regex is not provided.
+    pub fn is_match(
+        &self,
+        pattern: &str,
+        value: &str,
+        compiled_regex: Option<&regex::Regex>,
+    ) -> bool {
+        match self {
+            Self::StartsWith => value.starts_with(pattern),
+            Self::EndsWith => value.ends_with(pattern),
+            Self::Contains => value.contains(pattern),
+            Self::MatchesRegex => compiled_regex.map_or_else(
+                || compile_anchored_regex(pattern).is_ok_and(|regex| regex.is_match(value)),
+                |regex| regex.is_match(value),
+            ),
+        }
+    }
+}
+
+impl From<ast::ComparisonType> for Option<StrValueRefinement> {
+    fn from(comp_type: ast::ComparisonType) -> Self {
+        match comp_type {
+            ast::ComparisonType::StartsWith => Some(StrValueRefinement::StartsWith),
+            ast::ComparisonType::EndsWith => Some(StrValueRefinement::EndsWith),
+            ast::ComparisonType::Contains => Some(StrValueRefinement::Contains),
+            ast::ComparisonType::MatchesRegex => Some(StrValueRefinement::MatchesRegex),
+            ast::ComparisonType::Equal
+            | ast::ComparisonType::NotEqual
+            | ast::ComparisonType::InList
+            | ast::ComparisonType::LessThan
+            | ast::ComparisonType::LessThanEqual
+            | ast::ComparisonType::GreaterThan
+            | ast::ComparisonType::GreaterThanEqual => None,
+        }
+    }
+}
+
+impl From<StrValueRefinement> for ast::ComparisonType {
+    fn from(value: StrValueRefinement) -> Self {
+        match value {
+            StrValueRefinement::StartsWith => Self::StartsWith,
+            StrValueRefinement::EndsWith => Self::EndsWith,
+            StrValueRefinement::Contains => Self::Contains,
+            StrValueRefinement::MatchesRegex => Self::MatchesRegex,
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct StrValue {
     pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refinement: Option<StrValueRefinement>,
+}
+
+impl StrValue {
+    /// Checks whether the given value satisfies this value, taking the refinement into account
+    pub fn is_satisfied_by(&self, value: &str, compiled_regex: Option<&regex::Regex>) -> bool {
+        self.refinement
+            .as_ref()
+            .map_or(self.value == value, |refinement| {
+                refinement.is_match(&self.value, value, compiled_regex)
+            })
+    }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct MetadataValue {
     pub key: String,
     pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refinement: Option<StrValueRefinement>,
+}
+
+impl MetadataValue {
+    /// Checks whether the given value of the metadata key satisfies this value, taking the
+    /// refinement into account
+    pub fn is_satisfied_by(&self, value: &str, compiled_regex: Option<&regex::Regex>) -> bool {
+        self.refinement
+            .as_ref()
+            .map_or(self.value == value, |refinement| {
+                refinement.is_match(&self.value, value, compiled_regex)
+            })
+    }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
@@ -276,6 +465,7 @@ pub enum EuclidValue {
     PaymentCurrency(enums::Currency),
     BusinessCountry(enums::Country),
     BillingCountry(enums::Country),
+    CustomerEmail(StrValue),
     BusinessLabel(StrValue),
     SetupFutureUsage(enums::SetupFutureUsage),
     IssuerName(StrValue),
@@ -285,12 +475,33 @@ pub enum EuclidValue {
     CustomerDeviceType(CustomerDeviceType),
     CustomerDeviceDisplaySize(CustomerDeviceDisplaySize),
     CustomerDevicePlatform(CustomerDevicePlatform),
+    PaymentTimeOfDay(NumValue),
+    PaymentDayOfWeek(PaymentDayOfWeek),
+    PaymentDate(NumValue),
+    CustomerAttemptCount(NumValue),
+    CardFailureCount(NumValue),
+    CustomerTransactedAmount(NumValue),
 }
 
 impl EuclidValue {
     pub fn get_num_value(&self) -> Option<NumValue> {
         match self {
             Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentTimeOfDay(val) => Some(val.clone()),
+            Self::PaymentDate(val) => Some(val.clone()),
+            Self::CustomerAttemptCount(val) => Some(val.clone()),
+            Self::CardFailureCount(val) => Some(val.clone()),
+            Self::CustomerTransactedAmount(val) => Some(val.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_str_value(&self) -> Option<&StrValue> {
+        match self {
+            Self::CardBin(val)
+            | Self::BusinessLabel(val)
+            | Self::IssuerName(val)
+            | Self::CustomerEmail(val) => Some(val),
             _ => None,
         }
     }
@@ -311,6 +522,7 @@ impl EuclidValue {
             Self::PaymentCurrency(_) => EuclidKey::PaymentCurrency,
             Self::BusinessCountry(_) => EuclidKey::BusinessCountry,
             Self::BillingCountry(_) => EuclidKey::BillingCountry,
+            Self::CustomerEmail(_) => EuclidKey::CustomerEmail,
             Self::BusinessLabel(_) => EuclidKey::BusinessLabel,
             Self::SetupFutureUsage(_) => EuclidKey::SetupFutureUsage,
             Self::IssuerName(_) => EuclidKey::IssuerName,
@@ -320,6 +532,47 @@ impl EuclidValue {
             Self::CustomerDeviceType(_) => EuclidKey::CustomerDeviceType,
             Self::CustomerDeviceDisplaySize(_) => EuclidKey::CustomerDeviceDisplaySize,
             Self::CustomerDevicePlatform(_) => EuclidKey::CustomerDevicePlatform,
+            Self::PaymentTimeOfDay(_) => EuclidKey::PaymentTimeOfDay,
+            Self::PaymentDayOfWeek(_) => EuclidKey::PaymentDayOfWeek,
+            Self::PaymentDate(_) => EuclidKey::PaymentDate,
+            Self::CustomerAttemptCount(_) => EuclidKey::CustomerAttemptCount,
+            Self::CardFailureCount(_) => EuclidKey::CardFailureCount,
+            Self::CustomerTransactedAmount(_) => EuclidKey::CustomerTransactedAmount,
+        }
+    }
+}
+
+/// Serialization of timezones as their IANA names, like `Asia/Kolkata`
+pub mod timezone {
+    use std::str::FromStr;
+
+    use chrono_tz::Tz;
+
+    pub fn parse(timezone: &str) -> Result<Tz, String> {
+        Tz::from_str(timezone).map_err(|error| error.to_string())
+    }
+
+    pub mod option {
+        use chrono_tz::Tz;
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        pub fn serialize<S>(timezone: &Option<Tz>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            timezone
+                .map(|timezone| timezone.name())
+                .serialize(serializer)
+        }
+
+        pub fn deserialize<'a, D>(deserializer: D) -> Result<Option<Tz>, D::Error>
+        where
+            D: Deserializer<'a>,
+        {
+            Option::<String>::deserialize(deserializer)?
+                .map(|timezone| super::parse(&timezone))
+                .transpose()
+                .map_err(serde::de::Error::custom)
         }
     }
 }
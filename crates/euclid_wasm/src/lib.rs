@@ -215,6 +215,13 @@ pub fn run_program(program: JsValue, input: JsValue) -> JsResult {
     Ok(serde_wasm_bindgen::to_value(&res)?)
 }
 
+#[wasm_bindgen(js_name = printProgram)]
+pub fn print_program(program: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(program)?;
+    let dsl = ast::printer::program(&program).err_to_js()?;
+    Ok(serde_wasm_bindgen::to_value(&dsl)?)
+}
+
 #[wasm_bindgen(js_name = getAllConnectors)]
 pub fn get_all_connectors() -> JsResult {
     Ok(serde_wasm_bindgen::to_value(RoutableConnectors::VARIANTS)?)
@@ -237,6 +244,13 @@ pub fn get_key_type(key: &str) -> Result<String, String> {
     Ok(key_str)
 }
 
+#[wasm_bindgen(js_name = getKeyComparisons)]
+pub fn get_key_comparisons(key: &str) -> JsResult {
+    let key = dir::DirKeyKind::from_str(key).map_err(|_| "Invalid key received".to_string())?;
+    let comparisons = key.get_type().get_comparison_types();
+    Ok(serde_wasm_bindgen::to_value(&comparisons)?)
+}
+
 #[wasm_bindgen(js_name = getThreeDsKeys)]
 pub fn get_three_ds_keys() -> JsResult {
     let keys = <common_types::payments::ConditionalConfigs as EuclidDirFilter>::ALLOWED;
@@ -295,6 +309,7 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::AcquirerCountry => dir_enums::Country::VARIANTS,
         dir::DirKeyKind::CustomerDeviceType => dir_enums::CustomerDeviceType::VARIANTS,
         dir::DirKeyKind::CustomerDevicePlatform => dir_enums::CustomerDevicePlatform::VARIANTS,
+        dir::DirKeyKind::PaymentDayOfWeek => dir_enums::PaymentDayOfWeek::VARIANTS,
         dir::DirKeyKind::CustomerDeviceDisplaySize => {
             dir_enums::CustomerDeviceDisplaySize::VARIANTS
         }
@@ -303,9 +318,17 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         | dir::DirKeyKind::Connector
         | dir::DirKeyKind::CardBin
         | dir::DirKeyKind::BusinessLabel
+        | dir::DirKeyKind::CustomerEmail
         | dir::DirKeyKind::MetaData
         | dir::DirKeyKind::IssuerName
-        | dir::DirKeyKind::AcquirerFraudRate => Err("Key does not have variants".to_string())?,
+        | dir::DirKeyKind::AcquirerFraudRate
+        | dir::DirKeyKind::PaymentTimeOfDay
+        | dir::DirKeyKind::PaymentDate
+        | dir::DirKeyKind::CustomerAttemptCount
+        | dir::DirKeyKind::CardFailureCount
+        | dir::DirKeyKind::CustomerTransactedAmount => {
+            Err("Key does not have variants".to_string())?
+        }
     };
 
     Ok(serde_wasm_bindgen::to_value(variants)?)
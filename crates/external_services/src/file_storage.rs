@@ -64,6 +64,30 @@ pub trait FileStorageInterface: dyn_clone::DynClone + Sync + Send {
 
     /// Retrieves a file from the selected storage scheme.
     async fn retrieve_file(&self, file_key: &str) -> CustomResult<Vec<u8>, FileStorageError>;
+
+    /// Starts the upload of a file whose content is uploaded in parts, so that the whole file
+    /// does not have to be held in memory.
+    async fn start_multipart_upload(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Box<dyn MultipartUpload>, FileStorageError>;
+}
+
+/// Minimum size of every part of a multipart upload except the last one.
+pub const MULTIPART_UPLOAD_MIN_PART_SIZE: usize = 5 * 1024 * 1024;
+
+/// Trait for uploading the content of a file in parts
+#[async_trait::async_trait]
+pub trait MultipartUpload: Send + Sync {
+    /// Appends a part to the file. Every part except the last one must be at least
+    /// [`MULTIPART_UPLOAD_MIN_PART_SIZE`] bytes long.
+    async fn upload_part(&mut self, part: Vec<u8>) -> CustomResult<(), FileStorageError>;
+
+    /// Completes the upload, after which the file can be retrieved.
+    async fn complete(self: Box<Self>) -> CustomResult<(), FileStorageError>;
+
+    /// Aborts the upload, discarding the parts uploaded so far.
+    async fn abort(self: Box<Self>) -> CustomResult<(), FileStorageError>;
 }
 
 dyn_clone::clone_trait_object!(FileStorageInterface);
@@ -1,8 +1,12 @@
 use aws_config::meta::region::RegionProviderChain;
 use aws_sdk_s3::{
     operation::{
-        delete_object::DeleteObjectError, get_object::GetObjectError, put_object::PutObjectError,
+        abort_multipart_upload::AbortMultipartUploadError,
+        complete_multipart_upload::CompleteMultipartUploadError,
+        create_multipart_upload::CreateMultipartUploadError, delete_object::DeleteObjectError,
+        get_object::GetObjectError, put_object::PutObjectError, upload_part::UploadPartError,
     },
+    types::{CompletedMultipartUpload, CompletedPart},
     Client,
 };
 use aws_sdk_sts::config::Region;
@@ -10,7 +14,7 @@ use common_utils::{errors::CustomResult, ext_traits::ConfigExt};
 use error_stack::ResultExt;
 
 use super::InvalidFileStorageConfig;
-use crate::file_storage::{FileStorageError, FileStorageInterface};
+use crate::file_storage::{FileStorageError, FileStorageInterface, MultipartUpload};
 
 /// Configuration for AWS S3 file storage.
 #[derive(Debug, serde::Deserialize, Clone, Default)]
@@ -104,6 +108,129 @@ impl AwsFileStorageClient {
             .map_err(AwsS3StorageError::UnknownError)?
             .to_vec())
     }
+
+    /// Starts a multipart upload to AWS S3.
+    async fn start_multipart_upload(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<AwsS3MultipartUpload, AwsS3StorageError> {
+        let upload_id = self
+            .inner_client
+            .create_multipart_upload()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .send()
+            .await
+            .map_err(AwsS3StorageError::CreateMultipartUploadFailure)?
+            .upload_id
+            .ok_or(AwsS3StorageError::MissingUploadId)?;
+
+        Ok(AwsS3MultipartUpload {
+            client: self.clone(),
+            file_key: file_key.to_owned(),
+            upload_id,
+            completed_parts: Vec::new(),
+        })
+    }
+}
+
+/// Multipart upload to AWS S3.
+#[derive(Debug)]
+struct AwsS3MultipartUpload {
+    client: AwsFileStorageClient,
+    file_key: String,
+    upload_id: String,
+    completed_parts: Vec<CompletedPart>,
+}
+
+impl AwsS3MultipartUpload {
+    /// Uploads a part of the file to AWS S3.
+    async fn upload_part(&mut self, part: Vec<u8>) -> CustomResult<(), AwsS3StorageError> {
+        let part_number = i32::try_from(self.completed_parts.len())
+            .ok()
+            .and_then(|count| count.checked_add(1))
+            .ok_or(AwsS3StorageError::TooManyParts)?;
+        let e_tag = self
+            .client
+            .inner_client
+            .upload_part()
+            .bucket(&self.client.bucket_name)
+            .key(&self.file_key)
+            .upload_id(&self.upload_id)
+            .part_number(part_number)
+            .body(part.into())
+            .send()
+            .await
+            .map_err(AwsS3StorageError::UploadPartFailure)?
+            .e_tag;
+
+        self.completed_parts.push(
+            CompletedPart::builder()
+                .set_e_tag(e_tag)
+                .part_number(part_number)
+                .build(),
+        );
+        Ok(())
+    }
+
+    /// Completes the multipart upload to AWS S3.
+    async fn complete(self) -> CustomResult<(), AwsS3StorageError> {
+        self.client
+            .inner_client
+            .complete_multipart_upload()
+            .bucket(&self.client.bucket_name)
+            .key(&self.file_key)
+            .upload_id(&self.upload_id)
+            .multipart_upload(
+                CompletedMultipartUpload::builder()
+                    .set_parts(Some(self.completed_parts))
+                    .build(),
+            )
+            .send()
+            .await
+            .map_err(AwsS3StorageError::CompleteMultipartUploadFailure)?;
+        Ok(())
+    }
+
+    /// Aborts the multipart upload to AWS S3, discarding the uploaded parts.
+    async fn abort(self) -> CustomResult<(), AwsS3StorageError> {
+        self.client
+            .inner_client
+            .abort_multipart_upload()
+            .bucket(&self.client.bucket_name)
+            .key(&self.file_key)
+            .upload_id(&self.upload_id)
+            .send()
+            .await
+            .map_err(AwsS3StorageError::AbortMultipartUploadFailure)?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl MultipartUpload for AwsS3MultipartUpload {
+    /// Uploads a part of the file to AWS S3.
+    async fn upload_part(&mut self, part: Vec<u8>) -> CustomResult<(), FileStorageError> {
+        self.upload_part(part)
+            .await
+            .change_context(FileStorageError::UploadFailed)
+    }
+
+    /// Completes the multipart upload to AWS S3.
+    async fn complete(self: Box<Self>) -> CustomResult<(), FileStorageError> {
+        (*self)
+            .complete()
+            .await
+            .change_context(FileStorageError::UploadFailed)
+    }
+
+    /// Aborts the multipart upload to AWS S3.
+    async fn abort(self: Box<Self>) -> CustomResult<(), FileStorageError> {
+        (*self)
+            .abort()
+            .await
+            .change_context(FileStorageError::DeleteFailed)
+    }
 }
 
 #[async_trait::async_trait]
@@ -135,6 +262,18 @@ impl FileStorageInterface for AwsFileStorageClient {
             .await
             .change_context(FileStorageError::RetrieveFailed)?)
     }
+
+    /// Starts a multipart upload to AWS S3.
+    async fn start_multipart_upload(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Box<dyn MultipartUpload>, FileStorageError> {
+        Ok(Box::new(
+            self.start_multipart_upload(file_key)
+                .await
+                .change_context(FileStorageError::UploadFailed)?,
+        ))
+    }
 }
 
 /// Enum representing errors that can occur during AWS S3 file storage operations.
@@ -152,6 +291,30 @@ enum AwsS3StorageError {
     #[error("File delete from S3 failed: {0:?}")]
     DeleteFailure(aws_sdk_s3::error::SdkError<DeleteObjectError>),
 
+    /// Error indicating that starting a multipart upload to S3 failed.
+    #[error("Multipart upload creation in S3 failed: {0:?}")]
+    CreateMultipartUploadFailure(aws_sdk_s3::error::SdkError<CreateMultipartUploadError>),
+
+    /// Error indicating that S3 did not return the identifier of a multipart upload.
+    #[error("Multipart upload ID missing in S3 response")]
+    MissingUploadId,
+
+    /// Error indicating that the maximum number of parts of a multipart upload was exceeded.
+    #[error("Too many parts in multipart upload")]
+    TooManyParts,
+
+    /// Error indicating that uploading a part to S3 failed.
+    #[error("Part upload to S3 failed: {0:?}")]
+    UploadPartFailure(aws_sdk_s3::error::SdkError<UploadPartError>),
+
+    /// Error indicating that completing a multipart upload to S3 failed.
+    #[error("Multipart upload completion in S3 failed: {0:?}")]
+    CompleteMultipartUploadFailure(aws_sdk_s3::error::SdkError<CompleteMultipartUploadError>),
+
+    /// Error indicating that aborting a multipart upload to S3 failed.
+    #[error("Multipart upload abort in S3 failed: {0:?}")]
+    AbortMultipartUploadFailure(aws_sdk_s3::error::SdkError<AbortMultipartUploadError>),
+
     /// Unknown error occurred.
     #[error("Unknown error occurred: {0:?}")]
     UnknownError(aws_sdk_s3::primitives::ByteStreamError),
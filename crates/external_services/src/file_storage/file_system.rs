@@ -3,13 +3,13 @@
 use std::{
     fs::{remove_file, File},
     io::{Read, Write},
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
 use common_utils::errors::CustomResult;
 use error_stack::ResultExt;
 
-use crate::file_storage::{FileStorageError, FileStorageInterface};
+use crate::file_storage::{FileStorageError, FileStorageInterface, MultipartUpload};
 
 /// Constructs the file path for a given file key within the file system.
 /// The file path is generated based on the workspace path and the provided file key.
@@ -22,6 +22,20 @@ fn get_file_path(file_key: impl AsRef<str>) -> PathBuf {
     file_path
 }
 
+/// Creates the file at the specified path, along with any missing parent directories.
+fn create_file(file_path: &Path) -> CustomResult<File, FileSystemStorageError> {
+    // Ignore the file name and create directories in the `file_path` if not exists
+    std::fs::create_dir_all(
+        file_path
+            .parent()
+            .ok_or(FileSystemStorageError::CreateDirFailed)
+            .attach_printable("Failed to obtain parent directory")?,
+    )
+    .change_context(FileSystemStorageError::CreateDirFailed)?;
+
+    File::create(file_path).change_context(FileSystemStorageError::CreateFailure)
+}
+
 /// Represents a file system for storing and managing files locally.
 #[derive(Debug, Clone)]
 pub(super) struct FileSystem;
@@ -33,19 +47,7 @@ impl FileSystem {
         file_key: &str,
         file: Vec<u8>,
     ) -> CustomResult<(), FileSystemStorageError> {
-        let file_path = get_file_path(file_key);
-
-        // Ignore the file name and create directories in the `file_path` if not exists
-        std::fs::create_dir_all(
-            file_path
-                .parent()
-                .ok_or(FileSystemStorageError::CreateDirFailed)
-                .attach_printable("Failed to obtain parent directory")?,
-        )
-        .change_context(FileSystemStorageError::CreateDirFailed)?;
-
-        let mut file_handler =
-            File::create(file_path).change_context(FileSystemStorageError::CreateFailure)?;
+        let mut file_handler = create_file(&get_file_path(file_key))?;
         file_handler
             .write_all(&file)
             .change_context(FileSystemStorageError::WriteFailure)?;
@@ -71,6 +73,40 @@ impl FileSystem {
     }
 }
 
+/// Multipart upload to the local file system, the parts are appended to the file as they are
+/// uploaded.
+#[derive(Debug)]
+struct FileSystemMultipartUpload {
+    file_path: PathBuf,
+    file_handler: File,
+}
+
+#[async_trait::async_trait]
+impl MultipartUpload for FileSystemMultipartUpload {
+    /// Appends the part to the file.
+    async fn upload_part(&mut self, part: Vec<u8>) -> CustomResult<(), FileStorageError> {
+        self.file_handler
+            .write_all(&part)
+            .change_context(FileSystemStorageError::WriteFailure)
+            .change_context(FileStorageError::UploadFailed)
+    }
+
+    /// Flushes the content of the file.
+    async fn complete(mut self: Box<Self>) -> CustomResult<(), FileStorageError> {
+        self.file_handler
+            .flush()
+            .change_context(FileSystemStorageError::WriteFailure)
+            .change_context(FileStorageError::UploadFailed)
+    }
+
+    /// Deletes the partially written file.
+    async fn abort(self: Box<Self>) -> CustomResult<(), FileStorageError> {
+        remove_file(&self.file_path)
+            .change_context(FileSystemStorageError::DeleteFailure)
+            .change_context(FileStorageError::DeleteFailed)
+    }
+}
+
 #[async_trait::async_trait]
 impl FileStorageInterface for FileSystem {
     /// Saves the provided file data to the file system under the specified file key.
@@ -100,6 +136,21 @@ impl FileStorageInterface for FileSystem {
             .await
             .change_context(FileStorageError::RetrieveFailed)?)
     }
+
+    /// Creates the file associated with the specified file key, to which the uploaded parts are
+    /// appended.
+    async fn start_multipart_upload(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Box<dyn MultipartUpload>, FileStorageError> {
+        let file_path = get_file_path(file_key);
+        let file_handler =
+            create_file(&file_path).change_context(FileStorageError::UploadFailed)?;
+        Ok(Box::new(FileSystemMultipartUpload {
+            file_path,
+            file_handler,
+        }))
+    }
 }
 
 /// Represents an error that can occur during local file system storage operations.
@@ -1,19 +1,23 @@
 /// Module for Contract based routing
 pub mod contract_routing_client;
 
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
 
 use common_utils::errors::CustomResult;
+use redis_interface::{errors::RedisError, RedisConnectionPool};
 use router_env::logger;
 use serde;
 /// Elimination Routing Client Interface Implementation
 pub mod elimination_based_client;
+/// Embedded Success Rate and Elimination Routing Implementation
+pub mod embedded_client;
 /// Success Routing Client Interface Implementation
 pub mod success_rate_client;
 
 pub use contract_routing_client::ContractScoreCalculatorClient;
-pub use elimination_based_client::EliminationAnalyserClient;
-pub use success_rate_client::SuccessRateCalculatorClient;
+pub use elimination_based_client::{EliminationAnalyserClient, EliminationBasedRouting};
+pub use embedded_client::EmbeddedDynamicRoutingClient;
+pub use success_rate_client::{SuccessBasedDynamicRouting, SuccessRateCalculatorClient};
 
 use super::Client;
 /// Result type for Dynamic Routing
@@ -47,11 +51,11 @@ pub enum DynamicRoutingError {
 #[derive(Debug, Clone)]
 pub struct RoutingStrategy {
     /// success rate service for Dynamic Routing
-    pub success_rate_client: Option<SuccessRateCalculatorClient<Client>>,
+    pub success_rate_client: Option<Box<dyn SuccessBasedDynamicRouting>>,
     /// contract based routing service for Dynamic Routing
     pub contract_based_client: Option<ContractScoreCalculatorClient<Client>>,
     /// elimination service for Dynamic Routing
-    pub elimination_based_client: Option<EliminationAnalyserClient<Client>>,
+    pub elimination_based_client: Option<Box<dyn EliminationBasedRouting>>,
 }
 
 /// Contains the Dynamic Routing Client Config
@@ -67,39 +71,70 @@ pub enum DynamicRoutingClientConfig {
         /// Service name
         service: String,
     },
+    /// If the success rate and elimination routing are computed within the application,
+    /// contract based routing is unavailable in this mode
+    Embedded {
+        /// The mode of the client, `embedded`
+        mode: DynamicRoutingMode,
+    },
     #[default]
     /// If the dynamic routing client config has been disabled
     Disabled,
 }
 
+/// The modes of the Dynamic Routing Client which do not need a server
+#[derive(Debug, Clone, Copy, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DynamicRoutingMode {
+    /// Success rate and elimination routing backed by redis
+    Embedded,
+}
+
 impl DynamicRoutingClientConfig {
     /// establish connection with the server
     pub async fn get_dynamic_routing_connection(
         self,
         client: Client,
+        redis_conn: error_stack::Result<Arc<RedisConnectionPool>, RedisError>,
     ) -> Result<RoutingStrategy, Box<dyn std::error::Error>> {
-        let (success_rate_client, contract_based_client, elimination_based_client) = match self {
+        let routing_strategy = match self {
             Self::Enabled { host, port, .. } => {
                 let uri = format!("http://{}:{}", host, port).parse::<tonic::transport::Uri>()?;
                 logger::info!("Connection established with dynamic routing gRPC Server");
-                (
-                    Some(SuccessRateCalculatorClient::with_origin(
+                RoutingStrategy {
+                    success_rate_client: Some(Box::new(SuccessRateCalculatorClient::with_origin(
                         client.clone(),
                         uri.clone(),
-                    )),
-                    Some(ContractScoreCalculatorClient::with_origin(
+                    ))),
+                    contract_based_client: Some(ContractScoreCalculatorClient::with_origin(
                         client.clone(),
                         uri.clone(),
                     )),
-                    Some(EliminationAnalyserClient::with_origin(client, uri)),
-                )
+                    elimination_based_client: Some(Box::new(
+                        EliminationAnalyserClient::with_origin(client, uri),
+                    )),
+                }
+            }
+            Self::Embedded {
+                mode: DynamicRoutingMode::Embedded,
+            } => {
+                let redis_conn = redis_conn.map_err(|error| {
+                    format!("The embedded dynamic routing engine requires redis: {error:?}")
+                })?;
+                let embedded_client = EmbeddedDynamicRoutingClient::new(redis_conn);
+                logger::info!("Using the embedded dynamic routing engine");
+                RoutingStrategy {
+                    success_rate_client: Some(Box::new(embedded_client.clone())),
+                    contract_based_client: None,
+                    elimination_based_client: Some(Box::new(embedded_client)),
+                }
             }
-            Self::Disabled => (None, None, None),
+            Self::Disabled => RoutingStrategy {
+                success_rate_client: None,
+                contract_based_client: None,
+                elimination_based_client: None,
+            },
         };
-        Ok(RoutingStrategy {
-            success_rate_client,
-            contract_based_client,
-            elimination_based_client,
-        })
+        Ok(routing_strategy)
     }
 }
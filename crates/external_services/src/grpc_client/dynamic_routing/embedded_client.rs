@@ -0,0 +1,980 @@
+use std::sync::Arc;
+
+use api_models::routing::{
+    EliminationAnalyserConfig as EliminationConfig, EliminationRoutingConfig,
+    RoutableConnectorChoice, RoutableConnectorChoiceWithBucketName,
+    RoutableConnectorChoiceWithStatus, SuccessBasedRoutingConfig, SuccessBasedRoutingConfigBody,
+    SuccessRateSpecificityLevel,
+};
+use common_utils::{ext_traits::OptionExt, transformers::ForeignTryFrom};
+use error_stack::{report, ResultExt};
+use redis_interface::{RedisConnectionPool, RedisKey};
+use router_env::{instrument, logger, tracing};
+
+use super::{
+    elimination_based_client::{
+        elimination_rate::{
+            invalidate_bucket_response, update_elimination_bucket_response, BucketInformation,
+            EliminationInformation, LabelWithStatus as EliminationLabelWithStatus,
+        },
+        EliminationBasedRouting, EliminationBucketConfig, EliminationResponse,
+        InvalidateBucketResponse, UpdateEliminationBucketResponse,
+    },
+    success_rate_client::{
+        success_rate::{
+            invalidate_windows_response, update_success_rate_window_response, LabelWithScore,
+        },
+        CalGlobalSuccessRateConfig, CalGlobalSuccessRateResponse, CalSuccessRateConfig,
+        CalSuccessRateResponse, InvalidateWindowsResponse, SuccessBasedDynamicRouting,
+        UpdateSuccessRateWindowConfig, UpdateSuccessRateWindowResponse,
+    },
+    DynamicRoutingError, DynamicRoutingResult,
+};
+use crate::grpc_client::GrpcHeaders;
+
+/// Prefix of the redis keys holding the success rate windows of an entity
+const SUCCESS_RATE_PREFIX: &str = "DYNAMIC_ROUTING_SR";
+
+/// Prefix of the redis keys holding the elimination buckets of an entity
+const ELIMINATION_PREFIX: &str = "DYNAMIC_ROUTING_ELIMINATION";
+
+/// Entity id under which the success rate windows and elimination buckets of the connectors across
+/// all the entities of a tenant are kept. They are shared by the entities, so they are kept with
+/// the default configs instead of the config of the entity which updates them.
+const GLOBAL_ENTITY_ID: &str = "global";
+
+/// Number of updates after which the current block of a window is closed, when the config does
+/// not have a current block threshold
+const DEFAULT_MAX_TOTAL_COUNT: u64 = 5;
+
+/// Time after which the windows and buckets of an entity which has not been updated are removed
+const DYNAMIC_ROUTING_KEY_TTL_IN_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Records the status of the labels in their windows in `KEYS[1]`.
+///
+/// `ARGV` holds the maximum number of aggregates (closed blocks) of a window, the duration (in
+/// seconds, `0` if unbounded) and the maximum number of updates (`0` if unbounded) of a block and
+/// the ttl of the key, followed by the name and status (`1` or `0`) of each window. The current
+/// block of a window is closed when either of its thresholds is reached, and the aggregates which
+/// fall out of the window are removed.
+const UPDATE_SUCCESS_RATE_WINDOW_SCRIPT: &str = r#"
+local now = tonumber(redis.call("TIME")[1])
+local max_aggregates = tonumber(ARGV[1])
+local block_duration = tonumber(ARGV[2])
+local max_total_count = tonumber(ARGV[3])
+local ttl = tonumber(ARGV[4])
+
+for i = 5, #ARGV, 2 do
+    local window = ARGV[i]
+    local status = tonumber(ARGV[i + 1])
+    local state = redis.call("HMGET", KEYS[1], window .. "|seq", window .. "|started_at")
+    local seq = tonumber(state[1])
+    local started_at = tonumber(state[2])
+    if seq == nil or started_at == nil then
+        seq = 0
+        redis.call("HSET", KEYS[1], window .. "|seq", seq, window .. "|started_at", now)
+    else
+        local total = tonumber(redis.call("HGET", KEYS[1], window .. "|" .. seq .. "|total")) or 0
+        if (max_total_count > 0 and total >= max_total_count)
+            or (block_duration > 0 and now - started_at >= block_duration) then
+            local expired = seq - max_aggregates
+            seq = seq + 1
+            redis.call("HSET", KEYS[1], window .. "|seq", seq, window .. "|started_at", now)
+            redis.call("HDEL", KEYS[1], window .. "|" .. expired .. "|total", window .. "|" .. expired .. "|success")
+        end
+    end
+    redis.call("HINCRBY", KEYS[1], window .. "|" .. seq .. "|total", 1)
+    redis.call("HINCRBY", KEYS[1], window .. "|" .. seq .. "|success", status)
+end
+redis.call("EXPIRE", KEYS[1], ttl)
+return 1
+"#;
+
+/// Sums up the aggregates (closed blocks) of the windows in `KEYS[1]`.
+///
+/// `ARGV` holds the maximum number of aggregates of a window followed by the name of each window.
+/// Returns the number of aggregates, the total count and the success count of each window.
+const FETCH_SUCCESS_RATE_WINDOW_SCRIPT: &str = r#"
+local max_aggregates = tonumber(ARGV[1])
+
+local result = {}
+for i = 2, #ARGV do
+    local window = ARGV[i]
+    local seq = tonumber(redis.call("HGET", KEYS[1], window .. "|seq"))
+    local aggregates, total, success = 0, 0, 0
+    if seq ~= nil then
+        for block = math.max(0, seq - max_aggregates), seq - 1 do
+            local counts = redis.call("HMGET", KEYS[1], window .. "|" .. block .. "|total", window .. "|" .. block .. "|success")
+            if counts[1] then
+                aggregates = aggregates + 1
+                total = total + tonumber(counts[1])
+                success = success + (tonumber(counts[2]) or 0)
+            end
+        end
+    end
+    table.insert(result, aggregates)
+    table.insert(result, total)
+    table.insert(result, success)
+end
+return result
+"#;
+
+/// Adds a failure to each of the buckets in `KEYS[1]`, after leaking them.
+///
+/// `ARGV` holds the size and the leak interval (in seconds) of the buckets and the ttl of the key,
+/// followed by the name of each bucket. A bucket leaks a failure every leak interval and does not
+/// hold more failures than its size.
+const UPDATE_ELIMINATION_BUCKET_SCRIPT: &str = r#"
+local now = tonumber(redis.call("TIME")[1])
+local bucket_size = tonumber(ARGV[1])
+local leak_interval = tonumber(ARGV[2])
+local ttl = tonumber(ARGV[3])
+
+for i = 4, #ARGV do
+    local bucket = ARGV[i]
+    local state = redis.call("HMGET", KEYS[1], bucket .. "|level", bucket .. "|leaked_at")
+    local level = tonumber(state[1]) or 0
+    local leaked_at = tonumber(state[2]) or now
+    if leak_interval > 0 then
+        local leaked = math.floor((now - leaked_at) / leak_interval)
+        level = math.max(0, level - leaked)
+        leaked_at = leaked_at + leaked * leak_interval
+    end
+    if level == 0 then
+        leaked_at = now
+    end
+    level = math.min(level + 1, bucket_size)
+    redis.call("HSET", KEYS[1], bucket .. "|level", level, bucket .. "|leaked_at", leaked_at)
+end
+redis.call("EXPIRE", KEYS[1], ttl)
+return 1
+"#;
+
+/// Finds the full buckets of the windows in `KEYS[1]`.
+///
+/// `ARGV` holds the size and the leak interval (in seconds) of the buckets followed by the name of
+/// each window. Returns the (sorted) names of the buckets of each window which are full after
+/// being leaked.
+const FETCH_ELIMINATED_BUCKETS_SCRIPT: &str = r#"
+local now = tonumber(redis.call("TIME")[1])
+local bucket_size = tonumber(ARGV[1])
+local leak_interval = tonumber(ARGV[2])
+local suffix = "|level"
+
+local fields = redis.call("HGETALL", KEYS[1])
+local state = {}
+for i = 1, #fields, 2 do
+    state[fields[i]] = fields[i + 1]
+end
+
+local result = {}
+for i = 3, #ARGV do
+    local prefix = ARGV[i] .. "|"
+    local eliminated = {}
+    for field, value in pairs(state) do
+        if string.sub(field, 1, #prefix) == prefix and string.sub(field, -#suffix) == suffix then
+            local bucket = string.sub(field, 1, -#suffix - 1)
+            local level = tonumber(value) or 0
+            local leaked_at = tonumber(state[bucket .. "|leaked_at"]) or now
+            if leak_interval > 0 then
+                level = level - math.floor((now - leaked_at) / leak_interval)
+            end
+            if bucket_size > 0 and level >= bucket_size then
+                table.insert(eliminated, string.sub(bucket, #prefix + 1))
+            end
+        end
+    end
+    table.sort(eliminated)
+    table.insert(result, eliminated)
+end
+return result
+"#;
+
+/// Success rate and elimination based routing computed within the application, with the windows
+/// and buckets of the entities kept in redis instead of the dynamic routing service.
+///
+/// Contract based routing is not supported by this client.
+#[derive(Clone)]
+pub struct EmbeddedDynamicRoutingClient {
+    redis_conn: Arc<RedisConnectionPool>,
+}
+
+impl std::fmt::Debug for EmbeddedDynamicRoutingClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EmbeddedDynamicRoutingClient")
+            .field("redis_conn", &"Redis conn doesn't implement debug")
+            .finish()
+    }
+}
+
+/// Aggregated counts of a success rate window
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SuccessRateWindow {
+    aggregates: i64,
+    total: i64,
+    success: i64,
+}
+
+impl SuccessRateWindow {
+    /// The success rate (in percent) of the window, or the default success rate if the window does
+    /// not have enough aggregates yet
+    fn get_score(&self, min_aggregates_size: u32, default_success_rate: f64) -> f64 {
+        match (u32::try_from(self.success), u32::try_from(self.total)) {
+            (Ok(success), Ok(total))
+                if self.aggregates >= i64::from(min_aggregates_size) && total > 0 =>
+            {
+                f64::from(success) * 100.0 / f64::from(total)
+            }
+            _ => default_success_rate,
+        }
+    }
+}
+
+impl EmbeddedDynamicRoutingClient {
+    /// Creates a client which keeps its windows and buckets in the given redis connection
+    pub fn new(redis_conn: Arc<RedisConnectionPool>) -> Self {
+        Self { redis_conn }
+    }
+
+    fn get_key(prefix: &str, tenant_id: &str, id: &str) -> String {
+        format!("{prefix}_{tenant_id}_{id}")
+    }
+
+    fn get_window(params: &str, label: &str) -> String {
+        format!("{params}|{label}")
+    }
+
+    /// The success based routing config of the entity, or the default one if it has not been
+    /// configured
+    fn get_success_based_routing_config(
+        success_based_config: SuccessBasedRoutingConfig,
+    ) -> DynamicRoutingResult<SuccessBasedRoutingConfigBody> {
+        success_based_config
+            .config
+            .or_else(|| SuccessBasedRoutingConfig::default().config)
+            .get_required_value("config")
+            .change_context(DynamicRoutingError::MissingRequiredField {
+                field: "config".to_string(),
+            })
+    }
+
+    /// The success based routing config of the global windows
+    fn get_global_success_based_routing_config(
+    ) -> DynamicRoutingResult<SuccessBasedRoutingConfigBody> {
+        Self::get_success_based_routing_config(SuccessBasedRoutingConfig::default())
+    }
+
+    /// The elimination config of the entity, or the default one if it has not been configured
+    fn get_elimination_bucket_config(
+        config: Option<EliminationConfig>,
+    ) -> DynamicRoutingResult<EliminationBucketConfig> {
+        config
+            .or_else(|| EliminationRoutingConfig::default().elimination_analyser_config)
+            .get_required_value("elimination_analyser_config")
+            .change_context(DynamicRoutingError::MissingRequiredField {
+                field: "elimination_analyser_config".to_string(),
+            })
+            .and_then(ForeignTryFrom::foreign_try_from)
+    }
+
+    /// The elimination config of the global buckets
+    fn get_global_elimination_bucket_config() -> DynamicRoutingResult<EliminationBucketConfig> {
+        Self::get_elimination_bucket_config(None)
+    }
+
+    async fn fetch_success_rate_windows(
+        &self,
+        key: &str,
+        max_aggregates_size: u32,
+        windows: Vec<String>,
+    ) -> DynamicRoutingResult<Vec<SuccessRateWindow>> {
+        let window_count = windows.len();
+        let values = std::iter::once(max_aggregates_size.to_string())
+            .chain(windows)
+            .collect::<Vec<_>>();
+
+        let result = self
+            .redis_conn
+            .evaluate_redis_script::<_, Vec<i64>>(
+                FETCH_SUCCESS_RATE_WINDOW_SCRIPT,
+                vec![RedisKey::from(key).tenant_aware_key(&self.redis_conn)],
+                values,
+            )
+            .await
+            .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                "Failed to fetch the success rate windows".to_string(),
+            ))?;
+
+        let windows = result
+            .chunks_exact(3)
+            .filter_map(|counts| match counts {
+                &[aggregates, total, success] => Some(SuccessRateWindow {
+                    aggregates,
+                    total,
+                    success,
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if windows.len() != window_count {
+            return Err(report!(
+                DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                    "Unexpected number of success rate windows".to_string(),
+                )
+            ))
+            .attach_printable_lazy(|| {
+                format!("Unexpected success rate script result: {result:?}")
+            });
+        }
+
+        Ok(windows)
+    }
+
+    async fn update_success_rate_windows(
+        &self,
+        key: &str,
+        config: &UpdateSuccessRateWindowConfig,
+        windows_with_status: Vec<(String, bool)>,
+    ) -> DynamicRoutingResult<()> {
+        let (block_duration_in_secs, max_total_count) = config
+            .current_block_threshold
+            .as_ref()
+            .map(|threshold| {
+                (
+                    threshold
+                        .duration_in_mins
+                        .unwrap_or_default()
+                        .saturating_mul(60),
+                    threshold.max_total_count,
+                )
+            })
+            .unwrap_or((0, DEFAULT_MAX_TOTAL_COUNT));
+
+        let values = [
+            config.max_aggregates_size.to_string(),
+            block_duration_in_secs.to_string(),
+            max_total_count.to_string(),
+            DYNAMIC_ROUTING_KEY_TTL_IN_SECS.to_string(),
+        ]
+        .into_iter()
+        .chain(
+            windows_with_status
+                .into_iter()
+                .flat_map(|(window, status)| [window, u8::from(status).to_string()]),
+        )
+        .collect::<Vec<_>>();
+
+        self.redis_conn
+            .evaluate_redis_script::<_, i64>(
+                UPDATE_SUCCESS_RATE_WINDOW_SCRIPT,
+                vec![RedisKey::from(key).tenant_aware_key(&self.redis_conn)],
+                values,
+            )
+            .await
+            .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                "Failed to update the success rate windows".to_string(),
+            ))?;
+
+        Ok(())
+    }
+
+    async fn fetch_eliminated_buckets(
+        &self,
+        key: &str,
+        config: &EliminationBucketConfig,
+        windows: Vec<String>,
+    ) -> DynamicRoutingResult<Vec<Vec<String>>> {
+        let window_count = windows.len();
+        let values = [
+            config.bucket_size.to_string(),
+            config.bucket_leak_interval_in_secs.to_string(),
+        ]
+        .into_iter()
+        .chain(windows)
+        .collect::<Vec<_>>();
+
+        let eliminated_buckets = self
+            .redis_conn
+            .evaluate_redis_script::<_, Vec<Vec<String>>>(
+                FETCH_ELIMINATED_BUCKETS_SCRIPT,
+                vec![RedisKey::from(key).tenant_aware_key(&self.redis_conn)],
+                values,
+            )
+            .await
+            .change_context(DynamicRoutingError::EliminationRateRoutingFailure(
+                "Failed to fetch the elimination buckets".to_string(),
+            ))?;
+
+        if eliminated_buckets.len() != window_count {
+            return Err(report!(DynamicRoutingError::EliminationRateRoutingFailure(
+                "Unexpected number of elimination buckets".to_string(),
+            )))
+            .attach_printable_lazy(|| {
+                format!("Unexpected elimination script result: {eliminated_buckets:?}")
+            });
+        }
+
+        Ok(eliminated_buckets)
+    }
+
+    async fn update_elimination_buckets(
+        &self,
+        key: &str,
+        config: &EliminationBucketConfig,
+        buckets: Vec<String>,
+    ) -> DynamicRoutingResult<()> {
+        let values = [
+            config.bucket_size.to_string(),
+            config.bucket_leak_interval_in_secs.to_string(),
+            DYNAMIC_ROUTING_KEY_TTL_IN_SECS.to_string(),
+        ]
+        .into_iter()
+        .chain(buckets)
+        .collect::<Vec<_>>();
+
+        self.redis_conn
+            .evaluate_redis_script::<_, i64>(
+                UPDATE_ELIMINATION_BUCKET_SCRIPT,
+                vec![RedisKey::from(key).tenant_aware_key(&self.redis_conn)],
+                values,
+            )
+            .await
+            .change_context(DynamicRoutingError::EliminationRateRoutingFailure(
+                "Failed to update the elimination bucket".to_string(),
+            ))?;
+
+        Ok(())
+    }
+}
+
+/// Orders the labels by their score, from the highest to the lowest. Labels with the same score
+/// keep their order.
+fn sort_labels_by_score(mut labels_with_score: Vec<LabelWithScore>) -> Vec<LabelWithScore> {
+    labels_with_score.sort_by(|a, b| b.score.total_cmp(&a.score));
+    labels_with_score
+}
+
+#[async_trait::async_trait]
+impl SuccessBasedDynamicRouting for EmbeddedDynamicRoutingClient {
+    #[instrument(skip_all)]
+    async fn calculate_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        params: String,
+        label_input: Vec<RoutableConnectorChoice>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<CalSuccessRateResponse> {
+        let config = Self::get_success_based_routing_config(success_rate_based_config)?;
+
+        // Global success rates are tracked per connector, across all the entities of the tenant
+        let (key, windows, config) = match config.specificity_level {
+            SuccessRateSpecificityLevel::Merchant => (
+                Self::get_key(SUCCESS_RATE_PREFIX, &headers.tenant_id, &id),
+                label_input
+                    .iter()
+                    .map(|label| Self::get_window(&params, &label.to_string()))
+                    .collect::<Vec<_>>(),
+                config,
+            ),
+            SuccessRateSpecificityLevel::Global => (
+                Self::get_key(SUCCESS_RATE_PREFIX, &headers.tenant_id, GLOBAL_ENTITY_ID),
+                label_input
+                    .iter()
+                    .map(|label| Self::get_window(&params, &label.connector.to_string()))
+                    .collect::<Vec<_>>(),
+                Self::get_global_success_based_routing_config()?,
+            ),
+        };
+        let window_config = UpdateSuccessRateWindowConfig::foreign_try_from(config.clone())?;
+        let score_config = CalSuccessRateConfig::foreign_try_from(config)?;
+
+        let windows = self
+            .fetch_success_rate_windows(&key, window_config.max_aggregates_size, windows)
+            .await?;
+
+        let labels_with_score = label_input
+            .into_iter()
+            .zip(windows)
+            .map(|(label, window)| LabelWithScore {
+                score: window.get_score(
+                    score_config.min_aggregates_size,
+                    score_config.default_success_rate,
+                ),
+                label: label.to_string(),
+            })
+            .collect();
+
+        let response = CalSuccessRateResponse {
+            labels_with_score: sort_labels_by_score(labels_with_score),
+        };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn update_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        params: String,
+        label_input: Vec<RoutableConnectorChoiceWithStatus>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<UpdateSuccessRateWindowResponse> {
+        let config = Self::get_success_based_routing_config(success_rate_based_config)?;
+        let window_config = UpdateSuccessRateWindowConfig::foreign_try_from(config)?;
+        let global_window_config = UpdateSuccessRateWindowConfig::foreign_try_from(
+            Self::get_global_success_based_routing_config()?,
+        )?;
+
+        let windows_with_status = label_input
+            .iter()
+            .map(|conn_choice| {
+                (
+                    Self::get_window(&params, &conn_choice.routable_connector_choice.to_string()),
+                    conn_choice.status,
+                )
+            })
+            .collect();
+
+        let global_windows_with_status = label_input
+            .iter()
+            .map(|conn_choice| {
+                (
+                    Self::get_window(
+                        &params,
+                        &conn_choice.routable_connector_choice.connector.to_string(),
+                    ),
+                    conn_choice.status,
+                )
+            })
+            .collect();
+
+        self.update_success_rate_windows(
+            &Self::get_key(SUCCESS_RATE_PREFIX, &headers.tenant_id, &id),
+            &window_config,
+            windows_with_status,
+        )
+        .await?;
+
+        self.update_success_rate_windows(
+            &Self::get_key(SUCCESS_RATE_PREFIX, &headers.tenant_id, GLOBAL_ENTITY_ID),
+            &global_window_config,
+            global_windows_with_status,
+        )
+        .await?;
+
+        let response = UpdateSuccessRateWindowResponse {
+            status: update_success_rate_window_response::UpdationStatus::WindowUpdationSucceeded
+                .into(),
+        };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn invalidate_success_rate_routing_keys(
+        &self,
+        id: String,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<InvalidateWindowsResponse> {
+        self.redis_conn
+            .delete_key(&Self::get_key(SUCCESS_RATE_PREFIX, &headers.tenant_id, &id).into())
+            .await
+            .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                "Failed to invalidate the success rate routing keys".to_string(),
+            ))?;
+
+        let response = InvalidateWindowsResponse {
+            status: invalidate_windows_response::InvalidationStatus::WindowInvalidationSucceeded
+                .into(),
+        };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn calculate_entity_and_global_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        params: String,
+        label_input: Vec<RoutableConnectorChoice>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<CalGlobalSuccessRateResponse> {
+        let config = Self::get_success_based_routing_config(success_rate_based_config)?;
+        let window_config = UpdateSuccessRateWindowConfig::foreign_try_from(config.clone())?;
+        let score_config = CalGlobalSuccessRateConfig::foreign_try_from(config)?;
+        let global_config = Self::get_global_success_based_routing_config()?;
+        let global_window_config =
+            UpdateSuccessRateWindowConfig::foreign_try_from(global_config.clone())?;
+        let global_score_config = CalSuccessRateConfig::foreign_try_from(global_config)?;
+
+        let labels = label_input
+            .iter()
+            .map(|conn_choice| conn_choice.to_string())
+            .collect::<Vec<_>>();
+
+        let global_labels = label_input
+            .iter()
+            .map(|conn_choice| conn_choice.connector.to_string())
+            .collect::<Vec<_>>();
+
+        let entity_windows = self
+            .fetch_success_rate_windows(
+                &Self::get_key(SUCCESS_RATE_PREFIX, &headers.tenant_id, &id),
+                window_config.max_aggregates_size,
+                labels
+                    .iter()
+                    .map(|label| Self::get_window(&params, label))
+                    .collect(),
+            )
+            .await?;
+
+        let global_windows = self
+            .fetch_success_rate_windows(
+                &Self::get_key(SUCCESS_RATE_PREFIX, &headers.tenant_id, GLOBAL_ENTITY_ID),
+                global_window_config.max_aggregates_size,
+                global_labels
+                    .iter()
+                    .map(|label| Self::get_window(&params, label))
+                    .collect(),
+            )
+            .await?;
+
+        let score_labels = |labels: Vec<String>,
+                            windows: Vec<SuccessRateWindow>,
+                            min_aggregates_size: u32,
+                            default_success_rate: f64| {
+            sort_labels_by_score(
+                labels
+                    .into_iter()
+                    .zip(windows)
+                    .map(|(label, window)| LabelWithScore {
+                        score: window.get_score(min_aggregates_size, default_success_rate),
+                        label,
+                    })
+                    .collect(),
+            )
+        };
+
+        let response = CalGlobalSuccessRateResponse {
+            entity_scores_with_labels: score_labels(
+                labels,
+                entity_windows,
+                score_config.entity_min_aggregates_size,
+                score_config.entity_default_success_rate,
+            ),
+            global_scores_with_labels: score_labels(
+                global_labels,
+                global_windows,
+                global_score_config.min_aggregates_size,
+                global_score_config.default_success_rate,
+            ),
+        };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+}
+
+#[async_trait::async_trait]
+impl EliminationBasedRouting for EmbeddedDynamicRoutingClient {
+    #[instrument(skip_all)]
+    async fn perform_elimination_routing(
+        &self,
+        id: String,
+        params: String,
+        label_input: Vec<RoutableConnectorChoice>,
+        configs: Option<EliminationConfig>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<EliminationResponse> {
+        let config = Self::get_elimination_bucket_config(configs)?;
+        let global_config = Self::get_global_elimination_bucket_config()?;
+
+        let (labels, global_windows): (Vec<_>, Vec<_>) = label_input
+            .into_iter()
+            .map(|conn_choice| {
+                (
+                    conn_choice.to_string(),
+                    Self::get_window(&params, &conn_choice.connector.to_string()),
+                )
+            })
+            .unzip();
+
+        let entity_buckets = self
+            .fetch_eliminated_buckets(
+                &Self::get_key(ELIMINATION_PREFIX, &headers.tenant_id, &id),
+                &config,
+                labels
+                    .iter()
+                    .map(|label| Self::get_window(&params, label))
+                    .collect(),
+            )
+            .await?;
+
+        let global_buckets = self
+            .fetch_eliminated_buckets(
+                &Self::get_key(ELIMINATION_PREFIX, &headers.tenant_id, GLOBAL_ENTITY_ID),
+                &global_config,
+                global_windows,
+            )
+            .await?;
+
+        let labels_with_status = labels
+            .into_iter()
+            .zip(entity_buckets.into_iter().zip(global_buckets))
+            .map(
+                |(label, (bucket_name, global_bucket_name))| EliminationLabelWithStatus {
+                    label,
+                    elimination_information: Some(EliminationInformation {
+                        entity: Some(BucketInformation {
+                            is_eliminated: !bucket_name.is_empty(),
+                            bucket_name,
+                        }),
+                        global: Some(BucketInformation {
+                            is_eliminated: !global_bucket_name.is_empty(),
+                            bucket_name: global_bucket_name,
+                        }),
+                    }),
+                },
+            )
+            .collect();
+
+        let response = EliminationResponse { labels_with_status };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn update_elimination_bucket_config(
+        &self,
+        id: String,
+        params: String,
+        report: Vec<RoutableConnectorChoiceWithBucketName>,
+        configs: Option<EliminationConfig>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<UpdateEliminationBucketResponse> {
+        let config = Self::get_elimination_bucket_config(configs)?;
+        let global_config = Self::get_global_elimination_bucket_config()?;
+
+        let (buckets, global_buckets): (Vec<_>, Vec<_>) = report
+            .into_iter()
+            .map(|conn_choice_with_bucket| {
+                let choice = &conn_choice_with_bucket.routable_connector_choice;
+                let bucket_name = &conn_choice_with_bucket.bucket_name;
+                (
+                    format!(
+                        "{}|{bucket_name}",
+                        Self::get_window(&params, &choice.to_string())
+                    ),
+                    format!(
+                        "{}|{bucket_name}",
+                        Self::get_window(&params, &choice.connector.to_string())
+                    ),
+                )
+            })
+            .unzip();
+
+        self.update_elimination_buckets(
+            &Self::get_key(ELIMINATION_PREFIX, &headers.tenant_id, &id),
+            &config,
+            buckets,
+        )
+        .await?;
+
+        self.update_elimination_buckets(
+            &Self::get_key(ELIMINATION_PREFIX, &headers.tenant_id, GLOBAL_ENTITY_ID),
+            &global_config,
+            global_buckets,
+        )
+        .await?;
+
+        let response = UpdateEliminationBucketResponse {
+            status: update_elimination_bucket_response::UpdationStatus::BucketUpdationSucceeded
+                .into(),
+        };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn invalidate_elimination_bucket(
+        &self,
+        id: String,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<InvalidateBucketResponse> {
+        self.redis_conn
+            .delete_key(&Self::get_key(ELIMINATION_PREFIX, &headers.tenant_id, &id).into())
+            .await
+            .change_context(DynamicRoutingError::EliminationRateRoutingFailure(
+                "Failed to invalidate the elimination bucket".to_string(),
+            ))?;
+
+        let response = InvalidateBucketResponse {
+            status: invalidate_bucket_response::InvalidationStatus::BucketInvalidationSucceeded
+                .into(),
+        };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::{super::success_rate_client::success_rate::CurrentBlockThreshold, *};
+
+    async fn get_client(key: &str) -> EmbeddedDynamicRoutingClient {
+        let redis_conn = RedisConnectionPool::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("failed to create redis connection pool");
+        redis_conn
+            .delete_key(&key.into())
+            .await
+            .expect("failed to delete the test key");
+        EmbeddedDynamicRoutingClient::new(Arc::new(redis_conn))
+    }
+
+    #[test]
+    fn test_success_rate_window_score() {
+        let window = SuccessRateWindow {
+            aggregates: 5,
+            total: 40,
+            success: 30,
+        };
+        assert_eq!(window.get_score(5, 100.0), 75.0);
+        assert_eq!(window.get_score(6, 100.0), 100.0);
+
+        let empty_window = SuccessRateWindow {
+            aggregates: 0,
+            total: 0,
+            success: 0,
+        };
+        assert_eq!(empty_window.get_score(0, 90.0), 90.0);
+    }
+
+    #[test]
+    fn test_sort_labels_by_score() {
+        let label_with_score = |label: &str, score| LabelWithScore {
+            score,
+            label: label.to_string(),
+        };
+        let labels = sort_labels_by_score(vec![
+            label_with_score("stripe:mca_1", 50.0),
+            label_with_score("adyen:mca_2", 80.0),
+            label_with_score("checkout:mca_3", 50.0),
+        ]);
+
+        assert_eq!(
+            labels
+                .iter()
+                .map(|label| label.label.as_str())
+                .collect::<Vec<_>>(),
+            vec!["adyen:mca_2", "stripe:mca_1", "checkout:mca_3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_success_rate_window_scripts() {
+        let key = "TEST_DYNAMIC_ROUTING_SR";
+        let client = get_client(key).await;
+        let config = UpdateSuccessRateWindowConfig {
+            max_aggregates_size: 2,
+            current_block_threshold: Some(CurrentBlockThreshold {
+                duration_in_mins: None,
+                max_total_count: 2,
+            }),
+        };
+
+        // Blocks of two updates: [success, failure], [success, success], [failure, success] and
+        // the current block [success]
+        for status in [true, false, true, true, false, true, true] {
+            client
+                .update_success_rate_windows(
+                    key,
+                    &config,
+                    vec![("card|stripe".to_string(), status)],
+                )
+                .await
+                .expect("failed to update the success rate window");
+        }
+
+        let windows = client
+            .fetch_success_rate_windows(
+                key,
+                config.max_aggregates_size,
+                vec!["card|stripe".to_string(), "card|adyen".to_string()],
+            )
+            .await
+            .expect("failed to fetch the success rate windows");
+
+        // Only the last two closed blocks are in the window
+        assert_eq!(
+            windows,
+            vec![
+                SuccessRateWindow {
+                    aggregates: 2,
+                    total: 4,
+                    success: 3,
+                },
+                SuccessRateWindow {
+                    aggregates: 0,
+                    total: 0,
+                    success: 0,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_elimination_bucket_scripts() {
+        let key = "TEST_DYNAMIC_ROUTING_ELIMINATION";
+        let client = get_client(key).await;
+        let config = EliminationBucketConfig {
+            bucket_size: 2,
+            bucket_leak_interval_in_secs: 0,
+        };
+
+        for bucket in [
+            "card|stripe|bucket_1",
+            "card|stripe|bucket_1",
+            "card|stripe|bucket_2",
+            "card|adyen|bucket_1",
+        ] {
+            client
+                .update_elimination_buckets(key, &config, vec![bucket.to_string()])
+                .await
+                .expect("failed to update the elimination bucket");
+        }
+
+        let eliminated_buckets = client
+            .fetch_eliminated_buckets(
+                key,
+                &config,
+                vec!["card|stripe".to_string(), "card|adyen".to_string()],
+            )
+            .await
+            .expect("failed to fetch the elimination buckets");
+
+        assert_eq!(
+            eliminated_buckets,
+            vec![vec!["bucket_1".to_string()], Vec::<String>::new()]
+        );
+    }
+}
@@ -18,6 +18,9 @@ pub enum ApplicationResponse<R> {
     FileData((Vec<u8>, mime::Mime)),
     JsonWithHeaders((R, Vec<(String, masking::Maskable<String>)>)),
     GenericLinkForm(Box<GenericLinks>),
+    /// JSON body and headers of the response to a previous request made with the same
+    /// idempotency key
+    IdempotentReplay((String, Vec<(String, masking::Maskable<String>)>)),
 }
 
 impl<R> ApplicationResponse<R> {
@@ -34,6 +37,7 @@ impl<R> ApplicationResponse<R> {
             | Self::PaymentLinkForm(_)
             | Self::FileData(_)
             | Self::GenericLinkForm(_)
+            | Self::IdempotentReplay(_)
             | Self::StatusOk => Err(common_utils::errors::ValidationError::InvalidValue {
                 message: "expected either Json or JsonWithHeaders Response".to_string(),
             }
@@ -22,6 +22,71 @@ use masking::{ExposeInterface, PeekInterface, Secret};
 
 use crate::type_encryption::{crypto_operation, AsyncLift, CryptoOperation};
 
+/// Tracks the rotation of the `payment_response_hash_key` of a profile. While the rotation is
+/// active, outgoing webhooks are signed with both the current and the previous key.
+#[cfg(feature = "v1")]
+#[derive(Clone, Debug)]
+pub struct WebhookSecretRotation {
+    pub previous_payment_response_hash_key: OptionalEncryptableName,
+    pub started_at: time::PrimitiveDateTime,
+    pub expires_at: time::PrimitiveDateTime,
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+#[cfg(feature = "v1")]
+impl WebhookSecretRotation {
+    /// Returns the previous key if the rotation has neither been completed nor expired yet
+    pub fn get_active_previous_key(&self) -> Option<&Secret<String>> {
+        let is_active = self.completed_at.is_none() && date_time::now() < self.expires_at;
+
+        is_active
+            .then_some(self.previous_payment_response_hash_key.as_ref())
+            .flatten()
+            .map(|key| key.get_inner())
+    }
+
+    async fn decrypt(
+        state: &keymanager::KeyManagerState,
+        rotation: diesel_models::business_profile::WebhookSecretRotation,
+        key: &Secret<Vec<u8>>,
+        key_manager_identifier: keymanager::Identifier,
+    ) -> CustomResult<Self, common_utils::errors::CryptoError> {
+        Ok(Self {
+            previous_payment_response_hash_key: rotation
+                .previous_payment_response_hash_key
+                .async_lift(|inner| async {
+                    crypto_operation(
+                        state,
+                        type_name!(diesel_models::business_profile::Profile),
+                        CryptoOperation::DecryptOptional(inner),
+                        key_manager_identifier.clone(),
+                        key.peek(),
+                    )
+                    .await
+                    .and_then(|val| val.try_into_optionaloperation())
+                })
+                .await?,
+            started_at: rotation.started_at,
+            expires_at: rotation.expires_at,
+            completed_at: rotation.completed_at,
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl From<WebhookSecretRotation> for diesel_models::business_profile::WebhookSecretRotation {
+    fn from(rotation: WebhookSecretRotation) -> Self {
+        Self {
+            previous_payment_response_hash_key: rotation
+                .previous_payment_response_hash_key
+                .map(Encryption::from),
+            started_at: rotation.started_at,
+            expires_at: rotation.expires_at,
+            completed_at: rotation.completed_at,
+        }
+    }
+}
+
 #[cfg(feature = "v1")]
 #[derive(Clone, Debug)]
 pub struct Profile {
@@ -76,6 +141,8 @@ pub struct Profile {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: bool,
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
+    pub webhook_secret_rotation: Option<WebhookSecretRotation>,
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -129,6 +196,7 @@ pub struct ProfileSetter {
     pub merchant_business_country: Option<api_enums::CountryAlpha2>,
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: bool,
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -188,6 +256,8 @@ impl From<ProfileSetter> for Profile {
             is_iframe_redirection_enabled: value.is_iframe_redirection_enabled,
             is_pre_network_tokenization_enabled: value.is_pre_network_tokenization_enabled,
             three_ds_decision_rule_algorithm: None, // three_ds_decision_rule_algorithm is not yet created during profile creation
+            webhook_secret_rotation: None,
+            dispute_policy: value.dispute_policy,
         }
     }
 }
@@ -248,6 +318,7 @@ pub struct ProfileGeneralUpdate {
     pub merchant_business_country: Option<api_enums::CountryAlpha2>,
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: Option<bool>,
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -274,6 +345,10 @@ pub enum ProfileUpdate {
     CardTestingSecretKeyUpdate {
         card_testing_secret_key: OptionalEncryptableName,
     },
+    WebhookSecretRotationUpdate {
+        payment_response_hash_key: Option<String>,
+        webhook_secret_rotation: WebhookSecretRotation,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -324,6 +399,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     merchant_business_country,
                     is_iframe_redirection_enabled,
                     is_pre_network_tokenization_enabled,
+                    dispute_policy,
                 } = *update;
 
                 Self {
@@ -373,6 +449,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_iframe_redirection_enabled,
                     is_pre_network_tokenization_enabled,
                     three_ds_decision_rule_algorithm: None,
+                    webhook_secret_rotation: None,
+                    dispute_policy,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -425,6 +503,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm,
+                webhook_secret_rotation: None,
+                dispute_policy: None,
             },
             ProfileUpdate::DynamicRoutingAlgorithmUpdate {
                 dynamic_routing_algorithm,
@@ -474,6 +554,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm: None,
+                webhook_secret_rotation: None,
+                dispute_policy: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -523,6 +605,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm: None,
+                webhook_secret_rotation: None,
+                dispute_policy: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -572,6 +656,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm: None,
+                webhook_secret_rotation: None,
+                dispute_policy: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -621,6 +707,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm: None,
+                webhook_secret_rotation: None,
+                dispute_policy: None,
             },
             ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -670,6 +758,60 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm: None,
+                webhook_secret_rotation: None,
+                dispute_policy: None,
+            },
+            ProfileUpdate::WebhookSecretRotationUpdate {
+                payment_response_hash_key,
+                webhook_secret_rotation,
+            } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key,
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: None,
+                metadata: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                dynamic_routing_algorithm: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                always_request_extended_authorization: None,
+                is_click_to_pay_enabled: None,
+                authentication_product_ids: None,
+                card_testing_guard_config: None,
+                card_testing_secret_key: None,
+                is_clear_pan_retries_enabled: None,
+                force_3ds_challenge: None,
+                is_debit_routing_enabled: None,
+                merchant_business_country: None,
+                is_iframe_redirection_enabled: None,
+                is_pre_network_tokenization_enabled: None,
+                three_ds_decision_rule_algorithm: None,
+                webhook_secret_rotation: Some(webhook_secret_rotation.into()),
+                dispute_policy: None,
             },
         }
     }
@@ -739,6 +881,8 @@ impl super::behaviour::Conversion for Profile {
             is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
             is_pre_network_tokenization_enabled: Some(self.is_pre_network_tokenization_enabled),
             three_ds_decision_rule_algorithm: self.three_ds_decision_rule_algorithm,
+            webhook_secret_rotation: self.webhook_secret_rotation.map(Into::into),
+            dispute_policy: self.dispute_policy,
         })
     }
 
@@ -834,6 +978,19 @@ impl super::behaviour::Conversion for Profile {
                     .is_pre_network_tokenization_enabled
                     .unwrap_or(false),
                 three_ds_decision_rule_algorithm: item.three_ds_decision_rule_algorithm,
+                webhook_secret_rotation: match item.webhook_secret_rotation {
+                    Some(rotation) => Some(
+                        WebhookSecretRotation::decrypt(
+                            state,
+                            rotation,
+                            key,
+                            key_manager_identifier.clone(),
+                        )
+                        .await?,
+                    ),
+                    None => None,
+                },
+                dispute_policy: item.dispute_policy,
             })
         }
         .await
@@ -897,6 +1054,7 @@ impl super::behaviour::Conversion for Profile {
             merchant_business_country: self.merchant_business_country,
             is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
             is_pre_network_tokenization_enabled: Some(self.is_pre_network_tokenization_enabled),
+            dispute_policy: self.dispute_policy,
         })
     }
 }
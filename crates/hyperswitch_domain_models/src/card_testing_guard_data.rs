@@ -1,3 +1,4 @@
+use masking::Secret;
 use serde::{self, Deserialize, Serialize};
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -9,4 +10,5 @@ pub struct CardTestingGuardData {
     pub is_customer_id_blocking_enabled: bool,
     pub customer_id_blocking_cache_key: String,
     pub card_testing_guard_expiry: i32,
+    pub card_fingerprint: Secret<String>,
 }
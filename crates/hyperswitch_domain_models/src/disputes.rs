@@ -13,6 +13,7 @@ pub struct DisputeListConstraints {
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub currency: Option<Vec<common_enums::Currency>>,
     pub time_range: Option<common_utils::types::TimeRange>,
+    pub challenge_required_by_range: Option<common_utils::types::TimeRange>,
 }
 
 impl
@@ -41,6 +42,7 @@ impl
             merchant_connector_id,
             currency,
             time_range,
+            expiring_within_hours,
         } = value;
         let profile_id_from_request_body = profile_id;
         // Match both the profile ID from the request body and the list of authenticated profile IDs coming from auth layer
@@ -71,6 +73,15 @@ impl
             }
         };
 
+        // Disputes expiring soon are the ones whose deadline lies between now and the given number of hours from now
+        let challenge_required_by_range = expiring_within_hours.map(|hours| {
+            let now = common_utils::date_time::now();
+            common_utils::types::TimeRange {
+                start_time: now,
+                end_time: Some(now.saturating_add(time::Duration::hours(i64::from(hours)))),
+            }
+        });
+
         Ok(Self {
             dispute_id,
             payment_id,
@@ -84,6 +95,7 @@ impl
             merchant_connector_id,
             currency,
             time_range,
+            challenge_required_by_range,
         })
     }
 }
@@ -285,6 +285,12 @@ pub enum ApiErrorResponse {
     InvalidPlatformOperation,
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_45", message = "External vault failed during processing with connector")]
     ExternalVaultFailed,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_46", message = "Too many requests, retry after {retry_after} seconds")]
+    TooManyRequests { retry_after: u64 },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_47", message = "Idempotency key `{idempotency_key}` was used with a different request")]
+    IdempotencyKeyReused { idempotency_key: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_48", message = "Payment method `{payment_method_id}` is already attached to another customer")]
+    PaymentMethodAlreadyAttached { payment_method_id: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_02", message = "Bad request received in webhook")]
@@ -643,6 +649,15 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::ExternalVaultFailed => {
                 AER::BadRequest(ApiError::new("IR", 45, "External Vault failed while processing with connector.", None))
             },
+            Self::TooManyRequests { retry_after } => {
+                AER::TooManyRequests(ApiError::new("IR", 46, format!("Too many requests, retry after {retry_after} seconds"), None))
+            }
+            Self::IdempotencyKeyReused { idempotency_key } => {
+                AER::Unprocessable(ApiError::new("IR", 47, format!("Idempotency key `{idempotency_key}` was already used with a different request, use a new key for a different request"), None))
+            }
+            Self::PaymentMethodAlreadyAttached { payment_method_id } => {
+                AER::BadRequest(ApiError::new("IR", 48, format!("Payment method `{payment_method_id}` is already attached to another customer"), None))
+            }
 
             Self::WebhookAuthenticationFailed => {
                 AER::Unauthorized(ApiError::new("WE", 1, "Webhook authentication failed", None))
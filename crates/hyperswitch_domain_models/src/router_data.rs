@@ -105,6 +105,9 @@ pub struct RouterData<Flow, Request, Response> {
 
     /// Contains whole connector response
     pub whole_connector_response: Option<String>,
+
+    /// Merchant connector account through which the request is made to the connector
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
 }
 
 // Different patterns of authentication.
@@ -163,3 +163,45 @@ impl ApiEventMetric for FrmFulfillmentRequest {
         Some(ApiEventsType::FraudCheck)
     }
 }
+
+/// Shipment details of a fulfillment which are stored with the fraud check of the payment, the
+/// destinations of the shipments are not stored
+#[derive(Eq, PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct FulfillmentDetails {
+    pub order_id: String,
+    pub shipment_ids: Vec<String>,
+    pub products: Vec<Product>,
+    pub tracking_company: Option<String>,
+    pub tracking_numbers: Option<Vec<String>>,
+    pub tracking_urls: Option<Vec<String>>,
+    pub carrier: Option<String>,
+    pub fulfillment_method: Option<String>,
+    pub shipment_status: Option<String>,
+    pub shipped_at: Option<String>,
+}
+
+impl From<&FrmFulfillmentRequest> for FulfillmentDetails {
+    fn from(fulfillment_request: &FrmFulfillmentRequest) -> Self {
+        Self {
+            order_id: fulfillment_request.order_id.clone(),
+            shipment_ids: fulfillment_request
+                .fulfillments
+                .iter()
+                .map(|fulfillment| fulfillment.shipment_id.clone())
+                .collect(),
+            products: fulfillment_request
+                .fulfillments
+                .iter()
+                .filter_map(|fulfillment| fulfillment.products.clone())
+                .flatten()
+                .collect(),
+            tracking_company: fulfillment_request.tracking_company.clone(),
+            tracking_numbers: fulfillment_request.tracking_numbers.clone(),
+            tracking_urls: fulfillment_request.tracking_urls.clone(),
+            carrier: fulfillment_request.carrier.clone(),
+            fulfillment_method: fulfillment_request.fulfillment_method.clone(),
+            shipment_status: fulfillment_request.shipment_status.clone(),
+            shipped_at: fulfillment_request.shipped_at.clone(),
+        }
+    }
+}
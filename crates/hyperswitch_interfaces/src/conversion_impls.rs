@@ -85,6 +85,7 @@ fn get_default_router_data<F, Req, Resp>(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: None,
     }
 }
 
@@ -38,6 +38,7 @@ impl IntoContext for BackendInput {
         if let Some(business_label) = self.payment.business_label {
             ctx.push(dir::DirValue::BusinessLabel(StrValue {
                 value: business_label,
+                refinement: None,
             }));
         }
         if let Some(billing_country) = self.payment.billing_country {
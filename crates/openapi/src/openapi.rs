@@ -66,6 +66,7 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "payment link", description = "Create payment link"),
         (name = "Routing", description = "Create and manage routing configurations"),
         (name = "Event", description = "Manage events"),
+        (name = "Webhook Endpoint", description = "Create and manage webhook endpoints"),
     ),
     // The paths will be displayed in the same order as they are registered here
     paths(
@@ -113,6 +114,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_connector_account::connector_list,
         routes::merchant_connector_account::connector_update,
         routes::merchant_connector_account::connector_delete,
+        routes::merchant_connector_account::connector_circuit_breaker_retrieve,
+        routes::merchant_connector_account::connector_circuit_breaker_reset,
 
         //Routes for gsm
         routes::gsm::create_gsm_rule,
@@ -157,8 +160,13 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::routing_create_config,
         routes::routing::routing_link_config,
         routes::routing::routing_retrieve_config,
+        routes::routing::routing_simulate_config,
+        routes::routing::routing_export_config_dsl,
         routes::routing::list_routing_configs,
         routes::routing::routing_unlink_config,
+        routes::routing::routing_retrieve_activation_history,
+        routes::routing::routing_diff_config,
+        routes::routing::routing_rollback_config,
         routes::routing::routing_update_default_config,
         routes::routing::routing_retrieve_default_config,
         routes::routing::routing_retrieve_linked_config,
@@ -186,6 +194,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payouts::payouts_confirm,
         routes::payouts::payouts_list_filters,
         routes::payouts::payouts_list_by_filter,
+        routes::payouts::payouts_batch_create,
+        routes::payouts::payouts_batch_retrieve,
+        routes::payouts::payouts_batch_list,
 
         // Routes for api keys
         routes::api_keys::api_key_create,
@@ -200,6 +211,16 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
 
+        // Routes for webhook endpoints
+        routes::webhook_endpoints::create_webhook_endpoint,
+        routes::webhook_endpoints::list_webhook_endpoints,
+        routes::webhook_endpoints::retrieve_webhook_endpoint,
+        routes::webhook_endpoints::update_webhook_endpoint,
+        routes::webhook_endpoints::delete_webhook_endpoint,
+        routes::webhook_endpoints::start_webhook_secret_rotation,
+        routes::webhook_endpoints::retrieve_webhook_secret_rotation,
+        routes::webhook_endpoints::complete_webhook_secret_rotation,
+
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
     ),
@@ -233,6 +254,8 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::refunds::StripeSplitRefundRequest,
         common_types::payments::ConnectorChargeResponseData,
         common_types::payments::StripeChargeResponseData,
+        common_types::disputes::DisputePolicy,
+        common_types::disputes::DisputeAutoAcceptThreshold,
         api_models::refunds::RefundRequest,
         api_models::refunds::RefundType,
         api_models::refunds::RefundResponse,
@@ -246,6 +269,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantAccountDeleteResponse,
         api_models::admin::MerchantConnectorDeleteResponse,
         api_models::admin::MerchantConnectorResponse,
+        api_models::admin::ConnectorCircuitBreakerResponse,
+        api_models::admin::ConnectorCircuitBreakerState,
         api_models::admin::MerchantConnectorListResponse,
         api_models::admin::AuthenticationConnectorDetails,
         api_models::admin::ExtendedCardInfoConfig,
@@ -608,10 +633,17 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutLinkResponse,
         api_models::payouts::Bank,
         api_models::payouts::PayoutCreatePayoutLinkConfig,
+        api_models::payouts::PayoutBatchCreateRequest,
+        api_models::payouts::PayoutBatchResponse,
+        api_models::payouts::PayoutBatchCurrencyTotal,
+        api_models::payouts::PayoutBatchItemResponse,
+        api_models::payouts::PayoutBatchListResponse,
         api_models::enums::PayoutEntityType,
         api_models::enums::PayoutSendPriority,
         api_models::enums::PayoutStatus,
         api_models::enums::PayoutType,
+        api_models::enums::PayoutBatchStatus,
+        api_models::enums::PayoutBatchItemStatus,
         api_models::enums::TransactionType,
         api_models::payments::FrmMessage,
         api_models::webhooks::OutgoingWebhook,
@@ -632,6 +664,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
         api_models::api_keys::UpdateApiKeyRequest,
+        common_types::api_keys::ApiKeyScope,
+        api_models::enums::PermissionGroup,
+        api_models::enums::Resource,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
@@ -662,6 +697,19 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::StraightThroughAlgorithm,
         api_models::routing::ConnectorVolumeSplit,
         api_models::routing::ConnectorSelection,
+        api_models::routing::RoutingSimulationRequest,
+        api_models::routing::RoutingSimulationResponse,
+        api_models::routing::RoutingDslExportResponse,
+        api_models::routing::RoutingActivateRequest,
+        api_models::routing::RoutingActivationRecord,
+        api_models::routing::RoutingActivationHistoryResponse,
+        api_models::routing::RoutingRollbackRequest,
+        api_models::routing::RoutingAlgorithmDiffResponse,
+        api_models::routing::RoutingAlgorithmChange,
+        api_models::routing::RoutingAlgorithmChangeType,
+        api_models::routing::ConnectorTrafficShift,
+        api_models::routing::PaymentMethodTrafficShift,
+        api_models::routing::CurrencyTrafficShift,
         api_models::routing::SuccessRateSpecificityLevel,
         api_models::routing::ToggleDynamicRoutingQuery,
         api_models::routing::ToggleDynamicRoutingPath,
@@ -689,7 +737,15 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
         api_models::webhook_events::TotalEventsResponse,
+        api_models::webhook_endpoints::WebhookEndpointCreateRequest,
+        api_models::webhook_endpoints::WebhookEndpointUpdateRequest,
+        api_models::webhook_endpoints::WebhookEndpointResponse,
+        api_models::webhook_endpoints::WebhookEndpointDeleteResponse,
+        api_models::webhook_endpoints::WebhookSecretRotationStartRequest,
+        api_models::webhook_endpoints::WebhookSecretRotationStatus,
+        api_models::webhook_endpoints::WebhookSecretRotationResponse,
         api_models::enums::WebhookDeliveryAttempt,
+        api_models::enums::OutgoingWebhookFormat,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
         api_models::payments::CustomerDetailsResponse,
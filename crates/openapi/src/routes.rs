@@ -21,4 +21,5 @@ pub mod relay;
 pub mod revenue_recovery;
 pub mod routing;
 pub mod tokenization;
+pub mod webhook_endpoints;
 pub mod webhook_events;
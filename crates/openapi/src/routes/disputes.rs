@@ -32,6 +32,7 @@ pub async fn retrieve_dispute() {}
         ("received_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the dispute received time"),
         ("received_time.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the dispute received time"),
         ("received_time.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the dispute received time"),
+        ("expiring_within_hours" = Option<u32>, Query, description = "Only return disputes whose evidence submission deadline falls within the given number of hours from now"),
     ),
     responses(
         (status = 200, description = "The dispute list was retrieved successfully", body = Vec<DisputeResponse>),
@@ -59,6 +60,7 @@ pub async fn retrieve_disputes_list() {}
         ("received_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the dispute received time"),
         ("received_time.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the dispute received time"),
         ("received_time.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the dispute received time"),
+        ("expiring_within_hours" = Option<u32>, Query, description = "Only return disputes whose evidence submission deadline falls within the given number of hours from now"),
     ),
     responses(
         (status = 200, description = "The dispute list was retrieved successfully", body = Vec<DisputeResponse>),
@@ -307,6 +307,50 @@ pub async fn connector_update() {}
 )]
 pub async fn connector_delete() {}
 
+/// Merchant Connector - Circuit Breaker Retrieve
+///
+/// Retrieve the state of the circuit breaker of a Merchant Connector. Payments are not routed to the connector while its breaker is open.
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/connectors/{connector_id}/circuit_breaker",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Circuit breaker retrieved successfully", body = ConnectorCircuitBreakerResponse),
+        (status = 404, description = "Merchant Connector does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Retrieve the circuit breaker of a Merchant Connector",
+    security(("api_key" = []))
+)]
+pub async fn connector_circuit_breaker_retrieve() {}
+
+/// Merchant Connector - Circuit Breaker Reset
+///
+/// Close the circuit breaker of a Merchant Connector, clearing the recorded connector call outcomes. Payments are routed to the connector again.
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/connectors/{connector_id}/circuit_breaker/reset",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Circuit breaker reset successfully", body = ConnectorCircuitBreakerResponse),
+        (status = 404, description = "Merchant Connector does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Reset the circuit breaker of a Merchant Connector",
+    security(("api_key" = []))
+)]
+pub async fn connector_circuit_breaker_reset() {}
+
 /// Merchant Connector - Delete
 ///
 /// Delete or Detach a Merchant Connector from Merchant Account
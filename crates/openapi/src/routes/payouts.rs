@@ -190,3 +190,54 @@ pub async fn payouts_list_by_filter_profile() {}
     security(("api_key" = []))
 )]
 pub async fn payouts_confirm() {}
+
+/// Payouts - Batch Create
+///
+/// Validates every payout of the batch and schedules their creation. If any of the payouts is invalid, none of them are created and the batch is returned with the errors of every row.
+#[utoipa::path(
+    post,
+    path = "/payouts/batch",
+    request_body=PayoutBatchCreateRequest,
+    responses(
+        (status = 200, description = "Payout batch created", body = PayoutBatchResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payouts_batch_create() {}
+
+/// Payouts - Batch Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/batch/{batch_id}",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch retrieved", body = PayoutBatchResponse),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payouts_batch_retrieve() {}
+
+/// Payouts - Batch List
+#[utoipa::path(
+    get,
+    path = "/payouts/batch/list",
+    params(
+        ("limit" = Option<u32>, Query, description = "The maximum number of batches to return"),
+        ("offset" = Option<u32>, Query, description = "The number of batches to skip")
+    ),
+    responses(
+        (status = 200, description = "Payout batches listed", body = PayoutBatchListResponse)
+    ),
+    tag = "Payouts",
+    operation_id = "List Payout Batches",
+    security(("api_key" = []))
+)]
+pub async fn payouts_batch_list() {}
@@ -45,13 +45,14 @@ pub async fn routing_create_config() {}
 #[cfg(feature = "v1")]
 /// Routing - Activate config
 ///
-/// Activate a routing config
+/// Activate a routing config. The activation is recorded in the activation history of the profile
 #[utoipa::path(
     post,
     path = "/routing/{routing_algorithm_id}/activate",
     params(
         ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
     ),
+    request_body = RoutingActivateRequest,
     responses(
         (status = 200, description = "Routing config activated", body = RoutingDictionaryRecord),
         (status = 500, description = "Internal server error"),
@@ -86,6 +87,57 @@ pub async fn routing_link_config() {}
 )]
 pub async fn routing_retrieve_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Simulate
+///
+/// Replay a routing algorithm against the past payments of its profile, without calling any
+/// connector, to see how the traffic of each connector would shift if the algorithm were
+/// activated
+#[utoipa::path(
+    post,
+    path = "/routing/{routing_algorithm_id}/simulate",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    request_body = RoutingSimulationRequest,
+    responses(
+        (status = 200, description = "Routing config simulated", body = RoutingSimulationResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Simulate a routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_simulate_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Export as DSL
+///
+/// Export an advanced routing algorithm as text in the routing DSL, which can be edited and parsed
+/// back into the same routing algorithm
+#[utoipa::path(
+    get,
+    path = "/routing/{routing_algorithm_id}/dsl",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    responses(
+        (status = 200, description = "Routing config exported as DSL", body = RoutingDslExportResponse),
+        (status = 400, description = "Routing config is not an advanced routing algorithm"),
+        (status = 412, description = "Routing config cannot be written in the DSL"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Export a routing config as DSL",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_export_config_dsl() {}
+
 #[cfg(feature = "v2")]
 /// Routing - Retrieve
 ///
@@ -152,6 +204,76 @@ pub async fn list_routing_configs() {}
 )]
 pub async fn routing_unlink_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Activation history
+///
+/// List the activations and deactivations of the routing configs of a profile, latest first
+#[utoipa::path(
+    get,
+    path = "/routing/history",
+    params(
+        ("profile_id" = String, Query, description = "The unique identifier for a profile"),
+        ("limit" = Option<u16>, Query, description = "The number of records to be returned, at most 100"),
+        ("offset" = Option<u32>, Query, description = "The number of records to skip"),
+    ),
+    responses(
+        (status = 200, description = "Successfully fetched the activation history", body = RoutingActivationHistoryResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve the routing activation history",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_retrieve_activation_history() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Diff
+///
+/// Compare two routing configs rule by rule
+#[utoipa::path(
+    get,
+    path = "/routing/diff",
+    params(
+        ("base_algorithm_id" = String, Query, description = "The unique identifier for the config compared against"),
+        ("algorithm_id" = String, Query, description = "The unique identifier for the compared config"),
+    ),
+    responses(
+        (status = 200, description = "Successfully compared the routing configs", body = RoutingAlgorithmDiffResponse),
+        (status = 400, description = "Routing config cannot be compared"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Compare routing configs",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_diff_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Rollback
+///
+/// Roll back the latest activation or deactivation of a routing config of a profile, by
+/// re-activating the routing config which was active before it
+#[utoipa::path(
+    post,
+    path = "/routing/rollback",
+    request_body = RoutingRollbackRequest,
+    responses(
+        (status = 200, description = "Successfully rolled back the routing config", body = RoutingActivationRecord),
+        (status = 412, description = "No activation to roll back, or the active routing config was changed outside of the activation history"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Roll back a routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_rollback_config() {}
+
 #[cfg(feature = "v1")]
 /// Routing - Update Default Config
 ///
@@ -0,0 +1,192 @@
+/// Webhook Endpoint - Create
+///
+/// Register a webhook endpoint for a Profile. The endpoint receives the events it is subscribed
+/// to, in addition to the webhook URL configured in the Profile.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = WebhookEndpointCreateRequest,
+        examples(
+            (
+                "Create a webhook endpoint subscribed to refund events" = (
+                    value = json!({
+                        "url": "https://www.example.com/webhooks/refunds",
+                        "enabled_events": ["refund_succeeded", "refund_failed"]
+                    })
+                )
+            )
+        )
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoint created", body = WebhookEndpointResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Create a Webhook Endpoint",
+    security(("api_key" = []))
+)]
+pub async fn create_webhook_endpoint() {}
+
+/// Webhook Endpoint - List
+///
+/// List the webhook endpoints registered for a Profile.
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoints retrieved", body = Vec<WebhookEndpointResponse>),
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "List Webhook Endpoints",
+    security(("api_key" = []))
+)]
+pub async fn list_webhook_endpoints() {}
+
+/// Webhook Endpoint - Retrieve
+///
+/// Retrieve a webhook endpoint registered for a Profile.
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoint retrieved", body = WebhookEndpointResponse),
+        (status = 404, description = "Webhook endpoint not found")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Retrieve a Webhook Endpoint",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_webhook_endpoint() {}
+
+/// Webhook Endpoint - Update
+///
+/// Update a webhook endpoint registered for a Profile.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    request_body(
+        content = WebhookEndpointUpdateRequest,
+        examples(
+            (
+                "Disable a webhook endpoint" = (
+                    value = json!({
+                        "is_enabled": false
+                    })
+                )
+            )
+        )
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoint updated", body = WebhookEndpointResponse),
+        (status = 404, description = "Webhook endpoint not found")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Update a Webhook Endpoint",
+    security(("api_key" = []))
+)]
+pub async fn update_webhook_endpoint() {}
+
+/// Webhook Endpoint - Delete
+///
+/// Delete a webhook endpoint registered for a Profile.
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoint deleted", body = WebhookEndpointDeleteResponse),
+        (status = 404, description = "Webhook endpoint not found")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Delete a Webhook Endpoint",
+    security(("api_key" = []))
+)]
+pub async fn delete_webhook_endpoint() {}
+
+/// Webhook Secret Rotation - Start
+///
+/// Start the rotation of the key used to sign outgoing webhooks of a Profile. Until the rotation
+/// window elapses or the rotation is completed, outgoing webhooks carry signatures computed with
+/// both the previous and the new key.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_secret_rotation",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body = WebhookSecretRotationStartRequest,
+    responses(
+        (status = 200, description = "Webhook secret rotation started", body = WebhookSecretRotationResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 412, description = "A webhook secret rotation is already in progress")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Start a Webhook Secret Rotation",
+    security(("api_key" = []))
+)]
+pub async fn start_webhook_secret_rotation() {}
+
+/// Webhook Secret Rotation - Retrieve
+///
+/// Retrieve the status of the rotation of the key used to sign outgoing webhooks of a Profile.
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_secret_rotation",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Webhook secret rotation retrieved", body = WebhookSecretRotationResponse),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Retrieve a Webhook Secret Rotation",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_webhook_secret_rotation() {}
+
+/// Webhook Secret Rotation - Complete
+///
+/// Complete the rotation of the key used to sign outgoing webhooks of a Profile. The previous key
+/// is discarded, and outgoing webhooks are signed with the new key only.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_secret_rotation/complete",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Webhook secret rotation completed", body = WebhookSecretRotationResponse),
+        (status = 412, description = "No webhook secret rotation is pending completion")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Complete a Webhook Secret Rotation",
+    security(("api_key" = []))
+)]
+pub async fn complete_webhook_secret_rotation() {}
@@ -18,6 +18,7 @@ use fred::{
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
         MultipleValues, RedisMap, RedisValue, ScanType, Scanner, SetOptions, XCap, XReadResponse,
+        XReadValue,
     },
 };
 use futures::StreamExt;
@@ -722,6 +723,20 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::GetLengthFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_read_range(
+        &self,
+        stream: &RedisKey,
+        start: &str,
+        end: &str,
+        count: Option<u64>,
+    ) -> CustomResult<Vec<XReadValue<String, String, String>>, errors::RedisError> {
+        self.pool
+            .xrange_values(stream.tenant_aware_key(self), start, end, count)
+            .await
+            .change_context(errors::RedisError::StreamReadFailed)
+    }
+
     pub fn get_keys_with_prefix<K>(&self, keys: K) -> MultipleKeys
     where
         K: Into<MultipleKeys> + Debug + Send + Sync,
@@ -1,5 +1,7 @@
 pub use analytics::*;
 
+pub mod report_generation;
+
 pub mod routes {
     use std::{
         collections::{HashMap, HashSet},
@@ -10,8 +12,8 @@ pub mod routes {
     use analytics::{
         api_event::api_events_core, connector_events::connector_events_core, enums::AuthInfo,
         errors::AnalyticsError, lambda_utils::invoke_lambda, opensearch::OpenSearchError,
-        outgoing_webhook_event::outgoing_webhook_events_core, sdk_events::sdk_events_core,
-        AnalyticsFlow,
+        outgoing_webhook_event::outgoing_webhook_events_core, reports::ReportType,
+        sdk_events::sdk_events_core, AnalyticsFlow,
     };
     use api_models::analytics::{
         api_event::QueryType,
@@ -23,13 +25,15 @@ pub mod routes {
         GetAuthEventMetricRequest, GetDisputeMetricRequest, GetFrmFilterRequest,
         GetFrmMetricRequest, GetPaymentFiltersRequest, GetPaymentIntentFiltersRequest,
         GetPaymentIntentMetricRequest, GetPaymentMetricRequest, GetRefundFilterRequest,
-        GetRefundMetricRequest, GetSdkEventFiltersRequest, GetSdkEventMetricRequest, ReportRequest,
+        GetRefundMetricRequest, GetSdkEventFiltersRequest, GetSdkEventMetricRequest,
+        ReportDownloadRequest, ReportRequest,
     };
     use common_enums::EntityType;
     use common_utils::types::TimeRange;
     use error_stack::{report, ResultExt};
     use futures::{stream::FuturesUnordered, StreamExt};
 
+    use super::report_generation;
     use crate::{
         analytics_validator::request_validator,
         consts::opensearch::SEARCH_INDEXES,
@@ -94,6 +98,13 @@ pub mod routes {
                             web::resource("report/authentications")
                                 .route(web::post().to(generate_merchant_authentication_report)),
                         )
+                        .service(
+                            web::resource("report/status/{report_id}")
+                                .route(web::get().to(get_report_status)),
+                        )
+                        .service(
+                            web::resource("report/download").route(web::get().to(download_report)),
+                        )
                         .service(
                             web::resource("metrics/sdk_events")
                                 .route(web::post().to(get_sdk_event_metrics)),
@@ -1427,7 +1438,7 @@ pub mod routes {
 
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::MerchantLevel {
@@ -1437,15 +1448,8 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                report_generation::generate_report(state, user_id, ReportType::Refund, report_req)
+                    .await
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantReportRead,
@@ -1477,7 +1481,7 @@ pub mod routes {
                     .get_secret();
 
                 let org_id = auth.merchant_account.get_org_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: None,
                     auth: AuthInfo::OrgLevel {
@@ -1486,15 +1490,8 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                report_generation::generate_report(state, user_id, ReportType::Refund, report_req)
+                    .await
             },
             &auth::JWTAuth {
                 permission: Permission::OrganizationReportRead,
@@ -1531,7 +1528,7 @@ pub mod routes {
                     .profile_id
                     .ok_or(report!(UserErrors::JwtProfileIdMissing))
                     .change_context(AnalyticsError::AccessForbiddenError)?;
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::ProfileLevel {
@@ -1542,15 +1539,8 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                report_generation::generate_report(state, user_id, ReportType::Refund, report_req)
+                    .await
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileReportRead,
@@ -1582,7 +1572,7 @@ pub mod routes {
 
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::MerchantLevel {
@@ -1592,15 +1582,8 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                report_generation::generate_report(state, user_id, ReportType::Dispute, report_req)
+                    .await
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantReportRead,
@@ -1631,7 +1614,7 @@ pub mod routes {
                     .get_secret();
 
                 let org_id = auth.merchant_account.get_org_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: None,
                     auth: AuthInfo::OrgLevel {
@@ -1640,15 +1623,8 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                report_generation::generate_report(state, user_id, ReportType::Dispute, report_req)
+                    .await
             },
             &auth::JWTAuth {
                 permission: Permission::OrganizationReportRead,
@@ -1685,7 +1661,7 @@ pub mod routes {
                     .profile_id
                     .ok_or(report!(UserErrors::JwtProfileIdMissing))
                     .change_context(AnalyticsError::AccessForbiddenError)?;
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::ProfileLevel {
@@ -1696,15 +1672,8 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                report_generation::generate_report(state, user_id, ReportType::Dispute, report_req)
+                    .await
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileReportRead,
@@ -1737,7 +1706,7 @@ pub mod routes {
 
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::MerchantLevel {
@@ -1747,15 +1716,8 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                report_generation::generate_report(state, user_id, ReportType::Payment, report_req)
+                    .await
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantReportRead,
@@ -1787,7 +1749,7 @@ pub mod routes {
                     .get_secret();
 
                 let org_id = auth.merchant_account.get_org_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: None,
                     auth: AuthInfo::OrgLevel {
@@ -1796,15 +1758,8 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                report_generation::generate_report(state, user_id, ReportType::Payment, report_req)
+                    .await
             },
             &auth::JWTAuth {
                 permission: Permission::OrganizationReportRead,
@@ -1840,7 +1795,7 @@ pub mod routes {
                     .profile_id
                     .ok_or(report!(UserErrors::JwtProfileIdMissing))
                     .change_context(AnalyticsError::AccessForbiddenError)?;
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::ProfileLevel {
@@ -1851,15 +1806,8 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                report_generation::generate_report(state, user_id, ReportType::Payment, report_req)
+                    .await
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileReportRead,
@@ -2024,6 +1972,51 @@ pub mod routes {
         .await
     }
 
+    #[cfg(feature = "v1")]
+    pub async fn get_report_status(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetReportStatus;
+        let report_id = path.into_inner();
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            report_id,
+            |state, (_, user_id): auth::AuthenticationDataWithUserId, report_id, _| async move {
+                report_generation::retrieve_report_status(state, &user_id, &report_id).await
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// The signed token in the download link authorizes the download, since the link is
+    /// opened from the report email and not from the dashboard.
+    #[cfg(feature = "v1")]
+    pub async fn download_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        query: web::Query<ReportDownloadRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DownloadReport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            query.into_inner(),
+            |state, _: (), payload, _| report_generation::download_report(state, payload),
+            &auth::NoAuth,
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     /// # Panics
     ///
     /// Panics if `json_payload` array does not contain one `GetApiEventMetricRequest` element.
@@ -0,0 +1,363 @@
+use analytics::{
+    errors::AnalyticsError,
+    lambda_utils::invoke_lambda,
+    reports::{self, ReportType},
+    ReportEngine,
+};
+use api_models::analytics::{
+    GenerateReportRequest, ReportDownloadRequest, ReportFormat, ReportStatus, ReportStatusResponse,
+};
+use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
+use external_services::file_storage::{MultipartUpload, MULTIPART_UPLOAD_MIN_PART_SIZE};
+use router_env::{
+    instrument, logger,
+    tracing::{self, Instrument},
+};
+
+use crate::{
+    consts,
+    routes::SessionState,
+    services::{authentication as auth, ApplicationResponse},
+};
+#[cfg(feature = "email")]
+use crate::{
+    services::{email::types as email_types, jwt},
+    types::domain::UserEmail,
+    utils::user as user_utils,
+};
+
+const REPORT_STATUS_KEY_PREFIX: &str = "report_status";
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct ReportDownloadClaims {
+    report_id: String,
+    file_key: String,
+    format: ReportFormat,
+    exp: u64,
+}
+
+/// Generates a report using the configured report engine.
+///
+/// Reports generated by the native engine are generated in the background, and the status of the
+/// report is returned so that it can be polled until the download link is emailed.
+pub async fn generate_report(
+    state: SessionState,
+    user_id: String,
+    report_type: ReportType,
+    request: GenerateReportRequest,
+) -> CustomResult<ApplicationResponse<Option<ReportStatusResponse>>, AnalyticsError> {
+    let config = &state.conf.report_download_config;
+    match config.engine {
+        ReportEngine::Lambda => {
+            let lambda_function = match report_type {
+                ReportType::Payment => &config.payment_function,
+                ReportType::Refund => &config.refund_function,
+                ReportType::Dispute => &config.dispute_function,
+            };
+            let json_bytes =
+                serde_json::to_vec(&request).map_err(|_| AnalyticsError::UnknownError)?;
+            invoke_lambda(lambda_function, &config.region, &json_bytes).await?;
+            Ok(ApplicationResponse::Json(None))
+        }
+        ReportEngine::Native => {
+            let now = common_utils::date_time::now();
+            let status = ReportStatusResponse {
+                report_id: common_utils::generate_id(consts::ID_LENGTH, "report"),
+                status: ReportStatus::Pending,
+                format: request.request.format,
+                time_range: request.request.time_range,
+                record_count: None,
+                error_message: None,
+                created_at: now,
+                modified_at: now,
+            };
+            store_report_status(&state, &user_id, &status).await?;
+
+            let response = status.clone();
+            // Using a tokio spawn here and not arbiter because the report generation is not tied
+            // to the lifetime of the request
+            tokio::spawn(
+                async move {
+                    run_report_generation(state, user_id, report_type, request, status).await;
+                }
+                .in_current_span(),
+            );
+
+            Ok(ApplicationResponse::Json(Some(response)))
+        }
+    }
+}
+
+/// Retrieves the status of a report generated by the native report engine, only the user who
+/// requested the report can retrieve its status.
+pub async fn retrieve_report_status(
+    state: SessionState,
+    user_id: &str,
+    report_id: &str,
+) -> CustomResult<ApplicationResponse<ReportStatusResponse>, AnalyticsError> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .get_and_deserialize_key::<ReportStatusResponse>(
+            &get_report_status_key(user_id, report_id).into(),
+            "ReportStatusResponse",
+        )
+        .await
+        .map_err(|error| match error.current_context() {
+            redis_interface::errors::RedisError::NotFound => {
+                error.change_context(AnalyticsError::ReportNotFound)
+            }
+            _ => error.change_context(AnalyticsError::UnknownError),
+        })
+        .map(ApplicationResponse::Json)
+}
+
+/// Serves the report file referenced by a signed download link.
+pub async fn download_report(
+    state: SessionState,
+    request: ReportDownloadRequest,
+) -> CustomResult<ApplicationResponse<()>, AnalyticsError> {
+    let claims = auth::decode_jwt::<ReportDownloadClaims>(&request.token, &state)
+        .await
+        .change_context(AnalyticsError::InvalidReportDownloadLink)?;
+
+    let content = state
+        .file_storage_client
+        .retrieve_file(&claims.file_key)
+        .await
+        .change_context(AnalyticsError::ReportNotFound)
+        .attach_printable_lazy(|| format!("Failed to retrieve report {}", claims.report_id))?;
+
+    let content_type = match claims.format {
+        ReportFormat::Csv => mime::TEXT_CSV,
+        ReportFormat::Jsonl => "application/x-ndjson"
+            .parse::<mime::Mime>()
+            .change_context(AnalyticsError::UnknownError)?,
+    };
+
+    Ok(ApplicationResponse::FileData((content, content_type)))
+}
+
+#[instrument(skip_all, fields(report_id = %status.report_id, %report_type))]
+async fn run_report_generation(
+    state: SessionState,
+    user_id: String,
+    report_type: ReportType,
+    request: GenerateReportRequest,
+    mut status: ReportStatusResponse,
+) {
+    status.status = ReportStatus::Processing;
+    status.modified_at = common_utils::date_time::now();
+    store_report_status(&state, &user_id, &status)
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to update report status"))
+        .ok();
+
+    match generate_and_deliver_report(&state, report_type, &request, &status.report_id).await {
+        Ok(record_count) => {
+            status.status = ReportStatus::Completed;
+            status.record_count = Some(record_count);
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to generate report");
+            status.status = ReportStatus::Failed;
+            status.error_message = Some(error.current_context().to_string());
+        }
+    }
+
+    status.modified_at = common_utils::date_time::now();
+    store_report_status(&state, &user_id, &status)
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to update report status"))
+        .ok();
+}
+
+async fn generate_and_deliver_report(
+    state: &SessionState,
+    report_type: ReportType,
+    request: &GenerateReportRequest,
+    report_id: &str,
+) -> CustomResult<u64, AnalyticsError> {
+    let config = &state.conf.report_download_config.native;
+
+    let file_key = format!(
+        "{}/{report_type}/{report_id}.{}",
+        config.file_key_prefix,
+        reports::get_file_extension(request.request.format)
+    );
+    let mut upload = ReportUpload(
+        state
+            .file_storage_client
+            .start_multipart_upload(&file_key)
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to start report upload")?,
+    );
+
+    let report = match reports::generate_report(
+        &state.pool,
+        report_type,
+        &request.auth,
+        &request.request.time_range,
+        request.request.format,
+        time::Duration::hours(config.query_window_in_hours.into()),
+        &mut upload,
+    )
+    .await
+    {
+        Ok(report) => report,
+        Err(error) => {
+            upload
+                .0
+                .abort()
+                .await
+                .inspect_err(|error| logger::error!(?error, "Failed to abort report upload"))
+                .ok();
+            return Err(error);
+        }
+    };
+
+    upload
+        .0
+        .complete()
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to complete report upload")?;
+
+    #[cfg(feature = "email")]
+    send_report_email(
+        state,
+        report_type,
+        request,
+        ReportDownloadClaims {
+            report_id: report_id.to_owned(),
+            file_key,
+            format: report.format,
+            exp: jwt::generate_exp(std::time::Duration::from_secs(
+                config.download_link_expiry_in_secs.into(),
+            ))
+            .change_context(AnalyticsError::UnknownError)?
+            .as_secs(),
+        },
+    )
+    .await?;
+
+    Ok(report.record_count)
+}
+
+/// Uploads the parts of a report to the file storage as the report is generated.
+struct ReportUpload(Box<dyn MultipartUpload>);
+
+#[async_trait::async_trait]
+impl reports::ReportSink for ReportUpload {
+    fn min_part_size(&self) -> usize {
+        MULTIPART_UPLOAD_MIN_PART_SIZE
+    }
+
+    async fn write_part(&mut self, part: Vec<u8>) -> CustomResult<(), AnalyticsError> {
+        self.0
+            .upload_part(part)
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to upload report part")
+    }
+}
+
+/// Emails the signed download link of the report to the user who requested the report, along
+/// with any additional recipients in the request.
+#[cfg(feature = "email")]
+async fn send_report_email(
+    state: &SessionState,
+    report_type: ReportType,
+    request: &GenerateReportRequest,
+    claims: ReportDownloadClaims,
+) -> CustomResult<(), AnalyticsError> {
+    let token = jwt::generate_jwt(&claims, &state.conf)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to sign report download link")?;
+    let link = format!(
+        "{}/analytics/v1/report/download?token={token}",
+        state.base_url
+    );
+
+    let mut recipients = vec![request.email.clone()];
+    for email in request.request.emails.iter().flatten() {
+        if !recipients.contains(email) {
+            recipients.push(email.clone());
+        }
+    }
+
+    let mut failed = false;
+    for recipient in recipients {
+        let email_contents = email_types::ReportDownload {
+            recipient_email: UserEmail::new(recipient)
+                .change_context(AnalyticsError::UnknownError)?,
+            subject: consts::EMAIL_SUBJECT_REPORT_DOWNLOAD,
+            report_name: report_type.to_string(),
+            link: link.clone(),
+            expires_in: state
+                .conf
+                .report_download_config
+                .native
+                .download_link_expiry_in_secs
+                / 3600,
+        };
+
+        state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                user_utils::get_base_url(state),
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+            .inspect_err(|error| {
+                failed = true;
+                logger::error!(?error, "Failed to send report email");
+            })
+            .ok();
+    }
+
+    common_utils::fp_utils::when(failed, || {
+        Err(error_stack::report!(AnalyticsError::UnknownError))
+            .attach_printable("Failed to send report email to one or more recipients")
+    })
+}
+
+async fn store_report_status(
+    state: &SessionState,
+    user_id: &str,
+    status: &ReportStatusResponse,
+) -> CustomResult<(), AnalyticsError> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_report_status_key(user_id, &status.report_id).into(),
+            status,
+            state
+                .conf
+                .report_download_config
+                .native
+                .download_link_expiry_in_secs
+                .into(),
+        )
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to store report status")
+}
+
+fn get_report_status_key(user_id: &str, report_id: &str) -> String {
+    format!("{REPORT_STATUS_KEY_PREFIX}_{user_id}_{report_id}")
+}
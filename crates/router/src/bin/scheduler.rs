@@ -278,6 +278,9 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PaymentsSyncWorkflow => {
                     Ok(Box::new(workflows::payment_sync::PaymentsSyncWorkflow))
                 }
+                storage::ProcessTrackerRunner::PaymentsRequeueWorkflow => Ok(Box::new(
+                    workflows::payment_requeue::PaymentsRequeueWorkflow,
+                )),
                 storage::ProcessTrackerRunner::RefundWorkflowRouter => {
                     Ok(Box::new(workflows::refund_router::RefundWorkflowRouter))
                 }
@@ -324,6 +327,25 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PassiveRecoveryWorkflow => {
                     Ok(Box::new(workflows::revenue_recovery::ExecutePcrWorkflow))
                 }
+                storage::ProcessTrackerRunner::IncomingWebhookQueueWorkflow => Ok(Box::new(
+                    workflows::incoming_webhook_queue::IncomingWebhookQueueWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PayoutBatchWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(workflows::payout_batch::PayoutBatchWorkflow))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payout batch workflow when payouts feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::DisputeDeadlineWorkflow => Ok(Box::new(
+                    workflows::dispute_deadline::DisputeDeadlineWorkflow,
+                )),
             }
         };
 
@@ -2,10 +2,13 @@ use actix_web::{web, Scope};
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use super::customers::*;
+#[cfg(feature = "olap")]
+use super::webhook_endpoints::*;
+use super::{charges::*, payment_methods::*};
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use super::{payment_intents::*, setup_intents::*};
 use super::{refunds::*, webhooks::*};
-use crate::routes::{self, mandates, webhooks};
+use crate::routes::{self, webhooks};
 
 pub struct PaymentIntents;
 
@@ -119,12 +122,49 @@ impl Webhooks {
     }
 }
 
-pub struct Mandates;
+pub struct PaymentMethods;
 
-impl Mandates {
+impl PaymentMethods {
     pub fn server(config: routes::AppState) -> Scope {
         web::scope("/payment_methods")
             .app_data(web::Data::new(config))
-            .service(web::resource("/{id}/detach").route(web::post().to(mandates::revoke_mandate)))
+            .service(web::resource("/{id}").route(web::get().to(payment_method_retrieve)))
+            .service(web::resource("/{id}/attach").route(web::post().to(payment_method_attach)))
+            .service(web::resource("/{id}/detach").route(web::post().to(payment_method_detach)))
+    }
+}
+
+pub struct Charges;
+
+impl Charges {
+    pub fn server(config: routes::AppState) -> Scope {
+        let mut route = web::scope("/charges").app_data(web::Data::new(config));
+        #[cfg(feature = "olap")]
+        {
+            route = route.service(web::resource("").route(web::get().to(charge_list)))
+        }
+        route = route.service(web::resource("/{charge_id}").route(web::get().to(charge_retrieve)));
+        route
+    }
+}
+
+pub struct WebhookEndpoints;
+
+#[cfg(feature = "olap")]
+impl WebhookEndpoints {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/webhook_endpoints")
+            .app_data(web::Data::new(config))
+            .service(
+                web::resource("")
+                    .route(web::post().to(webhook_endpoint_create))
+                    .route(web::get().to(webhook_endpoint_list)),
+            )
+            .service(
+                web::resource("/{webhook_endpoint_id}")
+                    .route(web::get().to(webhook_endpoint_retrieve))
+                    .route(web::post().to(webhook_endpoint_update))
+                    .route(web::delete().to(webhook_endpoint_delete)),
+            )
     }
 }
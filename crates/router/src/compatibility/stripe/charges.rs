@@ -0,0 +1,207 @@
+pub mod types;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::payments as payment_types;
+#[cfg(feature = "olap")]
+use error_stack::ResultExt;
+use router_env::{instrument, tracing, Flow};
+
+#[cfg(feature = "olap")]
+use crate::compatibility::stripe::payment_intents::types::StripePaymentListConstraints;
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{
+        api_locking::{self, GetLockingInput},
+        payments,
+    },
+    routes,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::{api as api_types, domain},
+};
+#[cfg(feature = "olap")]
+use crate::{
+    core::errors::{ApiErrorResponse, RouterResponse, RouterResult},
+    types::transformers::ForeignInto,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRetrieve))]
+pub async fn charge_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> HttpResponse {
+    let payload = payment_types::PaymentsRetrieveRequest {
+        resource_id: api_types::PaymentIdType::PaymentIntentId(path.into_inner()),
+        merchant_id: None,
+        force_sync: false,
+        connector: None,
+        param: None,
+        merchant_connector_details: None,
+        client_secret: None,
+        expand_attempts: None,
+        expand_captures: None,
+        all_keys_required: None,
+    };
+
+    let flow = Flow::PaymentsRetrieve;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeChargeResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payments::payments_core::<
+                api_types::PSync,
+                api_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api_types::PSync>,
+            >(
+                state,
+                req_state,
+                merchant_context,
+                None,
+                payments::PaymentStatus,
+                payload,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Permission::MerchantPaymentRead,
+        }),
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsList))]
+#[cfg(feature = "olap")]
+pub async fn charge_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    payload: web::Query<StripePaymentListConstraints>,
+) -> HttpResponse {
+    let payload = match payment_types::PaymentListConstraints::try_from(payload.into_inner()) {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+    let flow = Flow::PaymentsList;
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeChargeListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            list_charges(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Permission::MerchantPaymentRead,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payments are listed without their refunds, the refunds of every listed payment are loaded to
+/// report the refunded amount of its charge. Whether more charges follow the page is found by
+/// listing a single payment past the end of the page.
+#[cfg(feature = "olap")]
+async fn list_charges(
+    state: routes::SessionState,
+    merchant_context: domain::MerchantContext,
+    constraints: payment_types::PaymentListConstraints,
+) -> RouterResponse<types::StripeChargeList> {
+    let payments = list_payment_responses(&state, &merchant_context, constraints.clone()).await?;
+
+    let is_page_full = u32::try_from(payments.len()).is_ok_and(|size| size >= constraints.limit);
+    let has_more = if is_page_full {
+        let next_page_constraints = if constraints.ending_before.is_some() {
+            payment_types::PaymentListConstraints {
+                ending_before: payments.first().map(|payment| payment.payment_id.clone()),
+                limit: 1,
+                ..constraints
+            }
+        } else {
+            payment_types::PaymentListConstraints {
+                starting_after: payments.last().map(|payment| payment.payment_id.clone()),
+                limit: 1,
+                ..constraints
+            }
+        };
+        !list_payment_responses(&state, &merchant_context, next_page_constraints)
+            .await?
+            .is_empty()
+    } else {
+        false
+    };
+
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let data = futures::future::try_join_all(payments.into_iter().map(|payment| async move {
+        let refunds = db
+            .find_refund_by_payment_id_merchant_id(&payment.payment_id, merchant_id, storage_scheme)
+            .await
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the refunds of the payment")?;
+
+        Ok::<_, error_stack::Report<ApiErrorResponse>>(payment_types::PaymentsResponse {
+            refunds: (!refunds.is_empty())
+                .then(|| refunds.into_iter().map(ForeignInto::foreign_into).collect()),
+            ..payment
+        })
+    }))
+    .await?;
+
+    Ok(api::ApplicationResponse::Json(types::StripeChargeList {
+        data,
+        has_more,
+    }))
+}
+
+#[cfg(feature = "olap")]
+async fn list_payment_responses(
+    state: &routes::SessionState,
+    merchant_context: &domain::MerchantContext,
+    constraints: payment_types::PaymentListConstraints,
+) -> RouterResult<Vec<payment_types::PaymentsResponse>> {
+    payments::list_payments(state.clone(), merchant_context.clone(), None, constraints)
+        .await?
+        .get_json_body()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Unexpected response when listing payments")
+        .map(|payment_list| payment_list.data)
+}
@@ -0,0 +1,323 @@
+use api_models::payments;
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+    types::MinorUnit,
+};
+use serde::Serialize;
+
+use crate::types::api::{enums as api_enums, refunds};
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeChargeStatus {
+    Succeeded,
+    Pending,
+    Failed,
+}
+
+impl From<api_enums::IntentStatus> for StripeChargeStatus {
+    fn from(item: api_enums::IntentStatus) -> Self {
+        match item {
+            // stripe marks authorized charges as succeeded, with `captured` set to false
+            api_enums::IntentStatus::Succeeded
+            | api_enums::IntentStatus::PartiallyCaptured
+            | api_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | api_enums::IntentStatus::RequiresCapture => Self::Succeeded,
+            api_enums::IntentStatus::Failed | api_enums::IntentStatus::Cancelled => Self::Failed,
+            api_enums::IntentStatus::Processing
+            | api_enums::IntentStatus::RequiresCustomerAction
+            | api_enums::IntentStatus::RequiresMerchantAction
+            | api_enums::IntentStatus::RequiresPaymentMethod
+            | api_enums::IntentStatus::RequiresConfirmation => Self::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeChargeResponse {
+    pub id: id_type::PaymentId,
+    pub object: &'static str,
+    pub amount: i64,
+    pub amount_captured: i64,
+    pub amount_refunded: i64,
+    pub currency: String,
+    pub customer: Option<id_type::CustomerId>,
+    pub description: Option<String>,
+    pub paid: bool,
+    pub captured: bool,
+    pub refunded: bool,
+    pub status: StripeChargeStatus,
+    pub payment_intent: id_type::PaymentId,
+    pub payment_method: Option<String>,
+    pub created: Option<i64>,
+    pub metadata: Option<serde_json::Value>,
+    pub failure_code: Option<String>,
+    pub failure_message: Option<String>,
+    pub livemode: bool,
+}
+
+impl From<payments::PaymentsResponse> for StripeChargeResponse {
+    fn from(resp: payments::PaymentsResponse) -> Self {
+        let status = StripeChargeStatus::from(resp.status);
+        let amount_captured = resp.amount_received.unwrap_or(MinorUnit::new(0));
+        let amount_refunded = resp
+            .refunds
+            .iter()
+            .flatten()
+            .filter(|refund| refund.status == refunds::RefundStatus::Succeeded)
+            .fold(MinorUnit::new(0), |total, refund| total + refund.amount);
+
+        Self {
+            object: "charge",
+            // a payment has a single charge in the stripe representation, which shares the
+            // identifier of the payment
+            id: resp.payment_id.clone(),
+            amount: resp.amount.get_amount_as_i64(),
+            amount_captured: amount_captured.get_amount_as_i64(),
+            amount_refunded: amount_refunded.get_amount_as_i64(),
+            currency: resp.currency.to_lowercase(),
+            customer: resp.customer_id,
+            description: resp.description,
+            paid: status == StripeChargeStatus::Succeeded,
+            captured: amount_captured > MinorUnit::new(0),
+            refunded: amount_refunded > MinorUnit::new(0) && amount_refunded >= amount_captured,
+            status,
+            payment_intent: resp.payment_id,
+            payment_method: resp.payment_method_id,
+            created: resp.created.map(|t| t.assume_utc().unix_timestamp()),
+            metadata: resp.metadata,
+            failure_code: resp.error_code,
+            failure_message: resp.error_message,
+            livemode: false,
+        }
+    }
+}
+
+#[derive(Default, Eq, PartialEq, Serialize)]
+pub struct StripeChargeListResponse {
+    pub object: String,
+    pub url: String,
+    pub has_more: bool,
+    pub data: Vec<StripeChargeResponse>,
+}
+
+/// A page of payments along with their refunds, which is listed as charges
+#[derive(Debug, Serialize)]
+pub struct StripeChargeList {
+    pub data: Vec<payments::PaymentsResponse>,
+    pub has_more: bool,
+}
+
+impl ApiEventMetric for StripeChargeList {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl From<StripeChargeList> for StripeChargeListResponse {
+    fn from(it: StripeChargeList) -> Self {
+        Self {
+            object: "list".to_string(),
+            url: "/v1/charges".to_string(),
+            has_more: it.has_more,
+            data: it.data.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn payment_id() -> id_type::PaymentId {
+        id_type::PaymentId::try_from(Cow::from("pay_1")).expect("Invalid payment id")
+    }
+
+    fn refund(amount: i64, status: refunds::RefundStatus) -> refunds::RefundResponse {
+        refunds::RefundResponse {
+            refund_id: "ref_1".to_string(),
+            payment_id: payment_id(),
+            amount: MinorUnit::new(amount),
+            currency: "USD".to_string(),
+            status,
+            reason: None,
+            metadata: None,
+            error_message: None,
+            error_code: None,
+            unified_code: None,
+            unified_message: None,
+            created_at: None,
+            updated_at: None,
+            connector: "stripe".to_string(),
+            profile_id: None,
+            merchant_connector_id: None,
+            split_refunds: None,
+            issuer_error_code: None,
+            issuer_error_message: None,
+        }
+    }
+
+    fn payment(
+        status: api_enums::IntentStatus,
+        amount_received: Option<i64>,
+        refunds: Option<Vec<refunds::RefundResponse>>,
+    ) -> payments::PaymentsResponse {
+        payments::PaymentsResponse {
+            payment_id: payment_id(),
+            merchant_id: id_type::MerchantId::try_from(Cow::from("merchant_1"))
+                .expect("Invalid merchant id"),
+            status,
+            amount: MinorUnit::new(1000),
+            net_amount: MinorUnit::new(1000),
+            shipping_cost: None,
+            amount_capturable: MinorUnit::new(0),
+            amount_received: amount_received.map(MinorUnit::new),
+            connector: Some("stripe".to_string()),
+            client_secret: None,
+            created: None,
+            currency: "USD".to_string(),
+            customer_id: None,
+            customer: None,
+            description: None,
+            refunds,
+            disputes: None,
+            attempts: None,
+            captures: None,
+            mandate_id: None,
+            mandate_data: None,
+            setup_future_usage: None,
+            off_session: None,
+            capture_on: None,
+            capture_method: None,
+            payment_method: None,
+            payment_method_data: None,
+            payment_token: None,
+            shipping: None,
+            billing: None,
+            order_details: None,
+            email: None,
+            name: None,
+            phone: None,
+            return_url: None,
+            authentication_type: None,
+            statement_descriptor_name: None,
+            statement_descriptor_suffix: None,
+            next_action: None,
+            cancellation_reason: None,
+            error_code: None,
+            error_message: None,
+            unified_code: None,
+            unified_message: None,
+            payment_experience: None,
+            payment_method_type: None,
+            connector_label: None,
+            business_country: None,
+            business_label: None,
+            business_sub_label: None,
+            allowed_payment_method_types: None,
+            ephemeral_key: None,
+            manual_retry_allowed: None,
+            connector_transaction_id: None,
+            frm_message: None,
+            metadata: None,
+            connector_metadata: None,
+            feature_metadata: None,
+            reference_id: None,
+            payment_link: None,
+            profile_id: None,
+            surcharge_details: None,
+            attempt_count: 1,
+            merchant_decision: None,
+            merchant_connector_id: None,
+            incremental_authorization_allowed: None,
+            authorization_count: None,
+            incremental_authorizations: None,
+            external_authentication_details: None,
+            external_3ds_authentication_attempted: None,
+            expires_on: None,
+            fingerprint: None,
+            browser_info: None,
+            payment_method_id: None,
+            payment_method_status: None,
+            updated: None,
+            split_payments: None,
+            frm_metadata: None,
+            extended_authorization_applied: None,
+            capture_before: None,
+            merchant_order_reference_id: None,
+            order_tax_amount: None,
+            connector_mandate_id: None,
+            card_discovery: None,
+            force_3ds_challenge: None,
+            force_3ds_challenge_trigger: None,
+            issuer_error_code: None,
+            issuer_error_message: None,
+            is_iframe_redirection_enabled: None,
+            whole_connector_response: None,
+        }
+    }
+
+    #[test]
+    fn test_authorized_charge() {
+        let charge = StripeChargeResponse::from(payment(
+            api_enums::IntentStatus::RequiresCapture,
+            None,
+            None,
+        ));
+
+        assert_eq!(charge.status, StripeChargeStatus::Succeeded);
+        assert!(charge.paid);
+        assert!(!charge.captured);
+        assert_eq!(charge.amount_captured, 0);
+        assert_eq!(charge.currency, "usd");
+    }
+
+    #[test]
+    fn test_partially_refunded_charge() {
+        let charge = StripeChargeResponse::from(payment(
+            api_enums::IntentStatus::Succeeded,
+            Some(1000),
+            Some(vec![
+                refund(300, refunds::RefundStatus::Succeeded),
+                refund(200, refunds::RefundStatus::Failed),
+                refund(100, refunds::RefundStatus::Pending),
+            ]),
+        ));
+
+        assert!(charge.captured);
+        assert_eq!(charge.amount_captured, 1000);
+        // Only succeeded refunds count towards the refunded amount
+        assert_eq!(charge.amount_refunded, 300);
+        assert!(!charge.refunded);
+    }
+
+    #[test]
+    fn test_fully_refunded_charge() {
+        let charge = StripeChargeResponse::from(payment(
+            api_enums::IntentStatus::Succeeded,
+            Some(1000),
+            Some(vec![
+                refund(600, refunds::RefundStatus::Succeeded),
+                refund(400, refunds::RefundStatus::Succeeded),
+            ]),
+        ));
+
+        assert_eq!(charge.amount_refunded, 1000);
+        assert!(charge.refunded);
+    }
+
+    #[test]
+    fn test_failed_charge() {
+        let charge =
+            StripeChargeResponse::from(payment(api_enums::IntentStatus::Failed, None, None));
+
+        assert_eq!(charge.status, StripeChargeStatus::Failed);
+        assert!(!charge.paid);
+        assert!(!charge.refunded);
+    }
+}
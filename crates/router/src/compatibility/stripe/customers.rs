@@ -13,7 +13,7 @@ use crate::{
     compatibility::{stripe::errors, wrap},
     core::{api_locking, customers, payment_methods::cards},
     routes,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::{
         api::{customers as customer_types, payment_methods},
         domain,
@@ -66,6 +66,7 @@ pub async fn customer_create(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -110,6 +111,7 @@ pub async fn customer_retrieve(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerRead,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -168,6 +170,7 @@ pub async fn customer_update(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -212,6 +215,7 @@ pub async fn customer_delete(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -263,6 +267,7 @@ pub async fn list_customer_payment_method_api(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerRead,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -150,6 +150,9 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "token_already_used", message = "duplicate payment method")]
     DuplicatePaymentMethod,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "payment_method_unexpected_state", message = "The payment method you provided has already been attached to a customer.")]
+    PaymentMethodAlreadyAttached { payment_method_id: String },
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "" , message = "deserialization failed: {error_message}")]
     SerdeQsError {
         error_message: String,
@@ -285,6 +288,10 @@ pub enum StripeErrorCode {
     PlatformBadRequest,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "Platform Unauthorized Request")]
     PlatformUnauthorizedRequest,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests hit the API too quickly. Retry after {retry_after} seconds.")]
+    RateLimit { retry_after: u64 },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_key_reused", message = "Keys for idempotent requests can only be used with the same parameters they were first used with. Try using a key other than '{idempotency_key}' if you meant to execute a different request.")]
+    IdempotencyKeyReused { idempotency_key: String },
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -393,7 +400,6 @@ pub enum StripeErrorCode {
         PostalCodeInvalid,
         ProcessingError,
         ProductInactive,
-        RateLimit,
         ReferToCustomer,
         RefundDisputedPayment,
         ResourceAlreadyExists,
@@ -688,6 +694,15 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             }
             errors::ApiErrorResponse::PlatformAccountAuthNotSupported => Self::PlatformBadRequest,
             errors::ApiErrorResponse::InvalidPlatformOperation => Self::PlatformUnauthorizedRequest,
+            errors::ApiErrorResponse::TooManyRequests { retry_after } => {
+                Self::RateLimit { retry_after }
+            }
+            errors::ApiErrorResponse::IdempotencyKeyReused { idempotency_key } => {
+                Self::IdempotencyKeyReused { idempotency_key }
+            }
+            errors::ApiErrorResponse::PaymentMethodAlreadyAttached { payment_method_id } => {
+                Self::PaymentMethodAlreadyAttached { payment_method_id }
+            }
         }
     }
 }
@@ -727,6 +742,7 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::DuplicateMerchantAccount
             | Self::DuplicateMerchantConnectorAccount { .. }
             | Self::DuplicatePaymentMethod
+            | Self::PaymentMethodAlreadyAttached { .. }
             | Self::PaymentFailed
             | Self::VerificationFailed { .. }
             | Self::DisputeFailed { .. }
@@ -762,6 +778,7 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::PaymentMethodDeleteFailed
             | Self::ExtendedCardInfoNotFound
             | Self::PlatformBadRequest
+            | Self::IdempotencyKeyReused { .. }
             | Self::LinkConfigurationError { .. } => StatusCode::BAD_REQUEST,
             Self::RefundFailed
             | Self::PayoutFailed
@@ -782,6 +799,7 @@ impl actix_web::ResponseError for StripeErrorCode {
                 StatusCode::from_u16(*code).unwrap_or(StatusCode::OK)
             }
             Self::LockTimeout => StatusCode::LOCKED,
+            Self::RateLimit { .. } => StatusCode::TOO_MANY_REQUESTS,
         }
     }
 
@@ -12,7 +12,7 @@ use crate::{
     compatibility::{stripe::errors, wrap},
     core::payments,
     routes::{self},
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
 };
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use crate::{
@@ -101,6 +101,7 @@ pub async fn payment_intents_create(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantPaymentWrite,
         }),
         locking_action,
     ))
@@ -131,6 +132,7 @@ pub async fn payment_intents_retrieve(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: false,
+        permission: Permission::MerchantPaymentRead,
     };
 
     let (auth_type, auth_flow) =
@@ -212,6 +214,7 @@ pub async fn payment_intents_retrieve_with_gateway_creds(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: false,
+        permission: Permission::MerchantPaymentRead,
     };
 
     let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers(), api_auth) {
@@ -300,6 +303,7 @@ pub async fn payment_intents_update(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: false,
+        permission: Permission::MerchantPaymentWrite,
     };
 
     let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers(), api_auth) {
@@ -391,6 +395,7 @@ pub async fn payment_intents_confirm(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: false,
+        permission: Permission::MerchantPaymentWrite,
     };
 
     let (auth_type, auth_flow) =
@@ -516,6 +521,7 @@ pub async fn payment_intents_capture(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantPaymentWrite,
         }),
         locking_action,
     ))
@@ -551,6 +557,7 @@ pub async fn payment_intents_cancel(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: false,
+        permission: Permission::MerchantPaymentWrite,
     };
 
     let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers(), api_auth) {
@@ -639,6 +646,7 @@ pub async fn payment_intent_list(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantPaymentRead,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -0,0 +1,287 @@
+pub mod types;
+use ::payment_methods::controller::PaymentMethodsController;
+use actix_web::{web, HttpRequest, HttpResponse};
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{
+        api_locking,
+        errors::{ApiErrorResponse, RouterResponse},
+        payment_methods::cards,
+    },
+    routes::{self, mandates},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::{
+        api::payment_methods::{self as payment_method_types, PaymentMethodId},
+        domain,
+    },
+};
+
+/// Prefix of the mandate IDs, mandates used to be detached through the payment method detach
+/// endpoint
+const MANDATE_ID_PREFIX: &str = "man_";
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsRetrieve))]
+pub async fn payment_method_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+
+    let flow = Flow::PaymentMethodsRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, pm, _| async move {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            cards::PmCards {
+                state: &state,
+                merchant_context: &merchant_context,
+            }
+            .retrieve_payment_method(pm)
+            .await
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Permission::MerchantCustomerRead,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsUpdate))]
+pub async fn payment_method_attach(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripePaymentMethodAttachRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+
+    let pm = PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+    let customer_id = payload.customer;
+
+    let flow = Flow::PaymentMethodsUpdate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodAttachResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        pm,
+        |state, auth: auth::AuthenticationData, pm, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            attach_payment_method(state, merchant_context, pm, customer_id.clone())
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsDelete))]
+pub async fn payment_method_detach(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payment_method_id = path.into_inner();
+
+    // Integrations which detach mandates through this endpoint are still supported
+    if payment_method_id.starts_with(MANDATE_ID_PREFIX) {
+        return Box::pin(mandates::revoke_mandate(
+            state,
+            req,
+            web::Path::from(payment_method_id),
+        ))
+        .await;
+    }
+
+    let pm = PaymentMethodId { payment_method_id };
+
+    let flow = Flow::PaymentMethodsDelete;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodDetachResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        pm,
+        |state, auth: auth::AuthenticationData, pm, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            detach_payment_method(state, merchant_context, pm)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment methods are always saved against the customer they were created for, attaching a
+/// payment method only succeeds for the customer it already belongs to.
+async fn attach_payment_method(
+    state: routes::SessionState,
+    merchant_context: domain::MerchantContext,
+    pm: PaymentMethodId,
+    customer_id: id_type::CustomerId,
+) -> RouterResponse<payment_method_types::PaymentMethodResponse> {
+    let payment_method_id = pm.payment_method_id.clone();
+    let payment_method = cards::PmCards {
+        state: &state,
+        merchant_context: &merchant_context,
+    }
+    .retrieve_payment_method(pm)
+    .await?
+    .get_json_body()
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Unexpected response when retrieving the payment method")?;
+
+    validate_payment_method_customer(
+        payment_method_id,
+        payment_method.customer_id.as_ref(),
+        &customer_id,
+    )?;
+
+    Ok(api::ApplicationResponse::Json(payment_method))
+}
+
+/// Like Stripe, a payment method attached to a customer cannot be attached to another customer
+fn validate_payment_method_customer(
+    payment_method_id: String,
+    payment_method_customer_id: Option<&id_type::CustomerId>,
+    customer_id: &id_type::CustomerId,
+) -> Result<(), ApiErrorResponse> {
+    if payment_method_customer_id != Some(customer_id) {
+        return Err(ApiErrorResponse::PaymentMethodAlreadyAttached { payment_method_id });
+    }
+    Ok(())
+}
+
+/// A payment method cannot exist without a customer, detaching a payment method deletes it. The
+/// payment method is returned as it was before it was deleted, without the customer.
+async fn detach_payment_method(
+    state: routes::SessionState,
+    merchant_context: domain::MerchantContext,
+    pm: PaymentMethodId,
+) -> RouterResponse<payment_method_types::PaymentMethodResponse> {
+    let pm_cards = cards::PmCards {
+        state: &state,
+        merchant_context: &merchant_context,
+    };
+
+    let payment_method = pm_cards
+        .retrieve_payment_method(pm.clone())
+        .await?
+        .get_json_body()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Unexpected response when retrieving the payment method")?;
+
+    pm_cards.delete_payment_method(pm).await?;
+
+    Ok(api::ApplicationResponse::Json(
+        payment_method_types::PaymentMethodResponse {
+            customer_id: None,
+            ..payment_method
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn customer_id(customer_id: &'static str) -> id_type::CustomerId {
+        id_type::CustomerId::try_from(Cow::from(customer_id)).expect("Invalid customer id")
+    }
+
+    #[test]
+    fn test_attach_to_the_same_customer() {
+        assert!(validate_payment_method_customer(
+            "pm_1".to_string(),
+            Some(&customer_id("cus_1")),
+            &customer_id("cus_1"),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_attach_to_a_different_customer() {
+        assert!(matches!(
+            validate_payment_method_customer(
+                "pm_1".to_string(),
+                Some(&customer_id("cus_1")),
+                &customer_id("cus_2"),
+            ),
+            Err(ApiErrorResponse::PaymentMethodAlreadyAttached { payment_method_id })
+                if payment_method_id == "pm_1"
+        ));
+        assert!(matches!(
+            validate_payment_method_customer("pm_1".to_string(), None, &customer_id("cus_2")),
+            Err(ApiErrorResponse::PaymentMethodAlreadyAttached { .. })
+        ));
+    }
+}
@@ -0,0 +1,54 @@
+use common_utils::{date_time, id_type, pii};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::customers::types::CardDetails, logger,
+    types::api::payment_methods as api_types,
+};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct StripePaymentMethodAttachRequest {
+    pub customer: id_type::CustomerId,
+}
+
+#[derive(Serialize, PartialEq, Eq)]
+pub struct StripePaymentMethodResponse {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub payment_method_type: Option<String>,
+    pub card: Option<CardDetails>,
+    pub customer: Option<id_type::CustomerId>,
+    pub created: u64,
+    pub livemode: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+pub type StripePaymentMethodAttachResponse = StripePaymentMethodResponse;
+pub type StripePaymentMethodDetachResponse = StripePaymentMethodResponse;
+
+impl From<api_types::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(item: api_types::PaymentMethodResponse) -> Self {
+        let created = item.created.unwrap_or_else(date_time::now);
+        Self {
+            id: item.payment_method_id,
+            object: "payment_method",
+            payment_method_type: item
+                .payment_method
+                .map(|payment_method| payment_method.to_string()),
+            card: item.card.map(From::from),
+            customer: item.customer_id,
+            created: u64::try_from(created.assume_utc().unix_timestamp()).unwrap_or_else(|error| {
+                logger::error!(
+                    %error,
+                    "incorrect value for `payment_method.created` provided {}", created
+                );
+                // Current timestamp converted to Unix timestamp should have a positive value
+                // for many years to come
+                u64::try_from(date_time::now().assume_utc().unix_timestamp()).unwrap_or_default()
+            }),
+            livemode: false,
+            metadata: item.metadata,
+        }
+    }
+}
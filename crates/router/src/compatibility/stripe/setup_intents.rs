@@ -16,7 +16,7 @@ use crate::{
     },
     core::{api_locking, payments},
     routes,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::{api as api_types, domain},
 };
 
@@ -85,6 +85,7 @@ pub async fn setup_intents_create(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantPaymentWrite,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -115,6 +116,7 @@ pub async fn setup_intents_retrieve(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: false,
+        permission: Permission::MerchantPaymentRead,
     };
 
     let (auth_type, auth_flow) =
@@ -198,6 +200,7 @@ pub async fn setup_intents_update(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: false,
+        permission: Permission::MerchantPaymentWrite,
     };
 
     let (auth_type, auth_flow) =
@@ -282,6 +285,7 @@ pub async fn setup_intents_confirm(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: false,
+        permission: Permission::MerchantPaymentWrite,
     };
 
     let (auth_type, auth_flow) =
@@ -0,0 +1,290 @@
+pub mod types;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{
+        api_locking,
+        errors::{ApiErrorResponse, RouterResult},
+        webhooks::webhook_endpoints,
+    },
+    routes,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::api::webhook_endpoints as webhook_endpoint_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointCreate))]
+pub async fn webhook_endpoint_create(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripeWebhookEndpointCreateRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+
+    let create_request =
+        match webhook_endpoint_types::WebhookEndpointCreateRequest::try_from(payload) {
+            Ok(request) => request,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
+    let flow = Flow::WebhookEndpointCreate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeWebhookEndpointResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        create_request,
+        |state, auth: auth::AuthenticationData, request, _| async move {
+            let profile_id = get_profile_id(&auth)?;
+            let secret = request.secret.clone();
+            let webhook_endpoint = webhook_endpoints::create_webhook_endpoint(
+                state,
+                auth.key_store,
+                profile_id,
+                request,
+            )
+            .await?
+            .get_json_body()
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response when creating the webhook endpoint")?;
+
+            Ok(api::ApplicationResponse::Json(
+                types::StripeCreatedWebhookEndpoint {
+                    webhook_endpoint,
+                    secret,
+                },
+            ))
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Permission::ProfileWebhookEventWrite,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointList))]
+pub async fn webhook_endpoint_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeWebhookEndpointListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| async move {
+            let profile_id = get_profile_id(&auth)?;
+            webhook_endpoints::list_webhook_endpoints(state, auth.key_store, profile_id).await
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Permission::ProfileWebhookEventRead,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointRetrieve))]
+pub async fn webhook_endpoint_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let endpoint_id = path.into_inner();
+
+    let flow = Flow::WebhookEndpointRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeWebhookEndpointResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        endpoint_id,
+        |state, auth: auth::AuthenticationData, endpoint_id, _| async move {
+            let profile_id = get_profile_id(&auth)?;
+            webhook_endpoints::retrieve_webhook_endpoint(
+                state,
+                auth.key_store,
+                profile_id,
+                endpoint_id,
+            )
+            .await
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Permission::ProfileWebhookEventRead,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointUpdate))]
+pub async fn webhook_endpoint_update(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripeWebhookEndpointUpdateRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+
+    let update_request =
+        match webhook_endpoint_types::WebhookEndpointUpdateRequest::try_from(payload) {
+            Ok(request) => request,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+    let endpoint_id = path.into_inner();
+
+    let flow = Flow::WebhookEndpointUpdate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeWebhookEndpointResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        update_request,
+        |state, auth: auth::AuthenticationData, request, _| {
+            let endpoint_id = endpoint_id.clone();
+            async move {
+                let profile_id = get_profile_id(&auth)?;
+                webhook_endpoints::update_webhook_endpoint(
+                    state,
+                    auth.key_store,
+                    profile_id,
+                    endpoint_id,
+                    request,
+                )
+                .await
+            }
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Permission::ProfileWebhookEventWrite,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointDelete))]
+pub async fn webhook_endpoint_delete(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let endpoint_id = path.into_inner();
+
+    let flow = Flow::WebhookEndpointDelete;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeWebhookEndpointDeleteResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        endpoint_id,
+        |state, auth: auth::AuthenticationData, endpoint_id, _| async move {
+            let profile_id = get_profile_id(&auth)?;
+            webhook_endpoints::delete_webhook_endpoint(
+                state,
+                auth.key_store,
+                profile_id,
+                endpoint_id,
+            )
+            .await
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Permission::ProfileWebhookEventWrite,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Stripe has no notion of business profiles, the webhook endpoints of the profile the API key is
+/// restricted to are managed, or those of the default profile of the merchant otherwise.
+fn get_profile_id(auth: &auth::AuthenticationData) -> RouterResult<id_type::ProfileId> {
+    auth.profile_id
+        .clone()
+        .or_else(|| auth.merchant_account.default_profile.clone())
+        .ok_or_else(|| {
+            report!(ApiErrorResponse::PreconditionFailed {
+                message: "Webhook endpoints can only be managed for merchant accounts with a \
+                          default business profile, or with an API key restricted to a business \
+                          profile"
+                    .to_string(),
+            })
+        })
+}
@@ -0,0 +1,345 @@
+use std::collections::{BTreeSet, HashSet};
+
+use api_models::enums::{EventClass, EventType};
+use common_utils::{
+    date_time,
+    events::{ApiEventMetric, ApiEventsType},
+};
+use masking::ExposeInterface;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::webhooks::get_stripe_event_type, core::errors, logger,
+    types::api::webhook_endpoints,
+};
+
+/// Stripe's wildcard for subscribing a webhook endpoint to all events
+const ALL_EVENTS: &str = "*";
+
+/// Prefix of the secrets generated for webhook endpoints created without a secret
+const WEBHOOK_ENDPOINT_SECRET_PREFIX: &str = "whsec";
+const WEBHOOK_ENDPOINT_SECRET_LENGTH: usize = 32;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripeWebhookEndpointCreateRequest {
+    pub url: url::Url,
+    pub enabled_events: Vec<String>,
+    pub description: Option<String>,
+    pub secret: Option<masking::Secret<String>>,
+    pub metadata: Option<serde_json::Value>, // not used
+    pub api_version: Option<String>,         // not used
+    pub connect: Option<bool>,               // not used
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripeWebhookEndpointUpdateRequest {
+    pub url: Option<url::Url>,
+    pub enabled_events: Option<Vec<String>>,
+    pub description: Option<String>,
+    pub secret: Option<masking::Secret<String>>,
+    pub disabled: Option<bool>,
+    pub metadata: Option<serde_json::Value>, // not used
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeWebhookEndpointStatus {
+    Enabled,
+    Disabled,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeWebhookEndpointResponse {
+    pub id: String,
+    pub object: &'static str,
+    pub url: String,
+    pub description: Option<String>,
+    pub enabled_events: Vec<&'static str>,
+    pub status: StripeWebhookEndpointStatus,
+    pub created: u64,
+    pub livemode: bool,
+    /// The signing secret of the endpoint, which is only returned when the endpoint is created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+}
+
+/// A created webhook endpoint along with its signing secret. The secret is not logged with the
+/// API events.
+#[derive(Debug, Serialize)]
+pub struct StripeCreatedWebhookEndpoint {
+    #[serde(flatten)]
+    pub webhook_endpoint: webhook_endpoints::WebhookEndpointResponse,
+    #[serde(skip)]
+    pub secret: Option<masking::Secret<String>>,
+}
+
+impl ApiEventMetric for StripeCreatedWebhookEndpoint {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        self.webhook_endpoint.get_api_event_type()
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeWebhookEndpointListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripeWebhookEndpointResponse>,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeWebhookEndpointDeleteResponse {
+    pub id: String,
+    pub object: &'static str,
+    pub deleted: bool,
+}
+
+impl TryFrom<StripeWebhookEndpointCreateRequest>
+    for webhook_endpoints::WebhookEndpointCreateRequest
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripeWebhookEndpointCreateRequest) -> Result<Self, Self::Error> {
+        // Stripe generates a signing secret for every endpoint
+        let secret = item.secret.unwrap_or_else(|| {
+            masking::Secret::new(format!(
+                "{WEBHOOK_ENDPOINT_SECRET_PREFIX}_{}",
+                common_utils::crypto::generate_cryptographically_secure_random_string(
+                    WEBHOOK_ENDPOINT_SECRET_LENGTH
+                )
+            ))
+        });
+
+        Ok(Self {
+            url: item.url,
+            secret: Some(secret),
+            description: item.description,
+            enabled_events: Some(get_event_types(&item.enabled_events)?),
+            is_enabled: Some(true),
+        })
+    }
+}
+
+impl TryFrom<StripeWebhookEndpointUpdateRequest>
+    for webhook_endpoints::WebhookEndpointUpdateRequest
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripeWebhookEndpointUpdateRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            url: item.url,
+            secret: item.secret,
+            description: item.description,
+            enabled_events: item
+                .enabled_events
+                .as_deref()
+                .map(get_event_types)
+                .transpose()?,
+            is_enabled: item.disabled.map(|disabled| !disabled),
+        })
+    }
+}
+
+impl From<webhook_endpoints::WebhookEndpointResponse> for StripeWebhookEndpointResponse {
+    fn from(item: webhook_endpoints::WebhookEndpointResponse) -> Self {
+        let enabled_events = if item.enabled_events.is_empty() {
+            vec![ALL_EVENTS]
+        } else {
+            item.enabled_events
+                .into_iter()
+                .map(get_stripe_event_type)
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect()
+        };
+
+        Self {
+            id: item.endpoint_id,
+            object: "webhook_endpoint",
+            url: item.url,
+            description: item.description,
+            enabled_events,
+            status: if item.is_enabled {
+                StripeWebhookEndpointStatus::Enabled
+            } else {
+                StripeWebhookEndpointStatus::Disabled
+            },
+            created: u64::try_from(item.created_at.assume_utc().unix_timestamp()).unwrap_or_else(
+                |error| {
+                    logger::error!(
+                        %error,
+                        "incorrect value for `webhook_endpoint.created_at` provided {}",
+                        item.created_at
+                    );
+                    // Current timestamp converted to Unix timestamp should have a positive value
+                    // for many years to come
+                    u64::try_from(date_time::now().assume_utc().unix_timestamp())
+                        .unwrap_or_default()
+                },
+            ),
+            livemode: false,
+            secret: None,
+        }
+    }
+}
+
+impl From<StripeCreatedWebhookEndpoint> for StripeWebhookEndpointResponse {
+    fn from(item: StripeCreatedWebhookEndpoint) -> Self {
+        Self {
+            secret: item.secret.map(ExposeInterface::expose),
+            ..Self::from(item.webhook_endpoint)
+        }
+    }
+}
+
+impl From<Vec<webhook_endpoints::WebhookEndpointResponse>> for StripeWebhookEndpointListResponse {
+    fn from(item: Vec<webhook_endpoints::WebhookEndpointResponse>) -> Self {
+        Self {
+            object: "list",
+            url: "/v1/webhook_endpoints",
+            has_more: false,
+            data: item.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<webhook_endpoints::WebhookEndpointDeleteResponse>
+    for StripeWebhookEndpointDeleteResponse
+{
+    fn from(item: webhook_endpoints::WebhookEndpointDeleteResponse) -> Self {
+        Self {
+            id: item.endpoint_id,
+            object: "webhook_endpoint",
+            deleted: item.deleted,
+        }
+    }
+}
+
+/// Converts the stripe event types to the event types the endpoint is subscribed to. A stripe
+/// event type can stand for more than one event type, and `*` subscribes the endpoint to all
+/// events, which is an empty set of event types.
+fn get_event_types(
+    enabled_events: &[String],
+) -> Result<HashSet<EventType>, errors::ApiErrorResponse> {
+    if enabled_events
+        .iter()
+        .any(|stripe_event_type| stripe_event_type == ALL_EVENTS)
+    {
+        return Ok(HashSet::new());
+    }
+
+    let mut event_classes = vec![
+        EventClass::Payments,
+        EventClass::Refunds,
+        EventClass::Disputes,
+        EventClass::Mandates,
+    ];
+    #[cfg(feature = "payouts")]
+    event_classes.push(EventClass::Payouts);
+    let supported_event_types = event_classes
+        .into_iter()
+        .flat_map(EventClass::event_types)
+        .collect::<Vec<_>>();
+
+    enabled_events
+        .iter()
+        .try_fold(HashSet::new(), |mut event_types, stripe_event_type| {
+            let matching_event_types = supported_event_types
+                .iter()
+                .filter(|event_type| get_stripe_event_type(**event_type) == stripe_event_type)
+                .copied()
+                .collect::<Vec<_>>();
+            if matching_event_types.is_empty() {
+                return Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Invalid event type `{stripe_event_type}` in `enabled_events`"
+                    ),
+                });
+            }
+            event_types.extend(matching_event_types);
+            Ok(event_types)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use masking::PeekInterface;
+
+    use super::*;
+
+    fn stripe_event_types(stripe_event_types: &[&str]) -> Vec<String> {
+        stripe_event_types
+            .iter()
+            .map(|stripe_event_type| stripe_event_type.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_get_event_types() {
+        assert_eq!(
+            get_event_types(&stripe_event_types(&["refund.failed", "mandate.revoked"])).ok(),
+            Some(HashSet::from([
+                EventType::RefundFailed,
+                EventType::MandateRevoked
+            ]))
+        );
+        // A stripe event type stands for all the event types which map to it
+        assert_eq!(
+            get_event_types(&stripe_event_types(&["payment_intent.succeeded"])).ok(),
+            Some(HashSet::from([
+                EventType::PaymentSucceeded,
+                EventType::PaymentCaptured
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_get_event_types_for_all_events() {
+        assert_eq!(
+            get_event_types(&stripe_event_types(&["refund.failed", ALL_EVENTS])).ok(),
+            Some(HashSet::new())
+        );
+    }
+
+    #[test]
+    fn test_get_event_types_with_invalid_event_type() {
+        assert!(matches!(
+            get_event_types(&stripe_event_types(&["refund.failed", "charge.refunded"])),
+            Err(errors::ApiErrorResponse::InvalidRequestData { message })
+                if message.contains("charge.refunded")
+        ));
+    }
+
+    #[test]
+    fn test_webhook_endpoint_secret() {
+        let request = StripeWebhookEndpointCreateRequest {
+            url: url::Url::parse("https://www.example.com/webhooks").expect("Invalid URL"),
+            enabled_events: stripe_event_types(&[ALL_EVENTS]),
+            description: None,
+            secret: None,
+            metadata: None,
+            api_version: None,
+            connect: None,
+        };
+
+        let generated_secret =
+            webhook_endpoints::WebhookEndpointCreateRequest::try_from(request.clone())
+                .expect("Invalid webhook endpoint request")
+                .secret
+                .expect("Missing webhook endpoint secret");
+        assert!(generated_secret
+            .peek()
+            .starts_with(&format!("{WEBHOOK_ENDPOINT_SECRET_PREFIX}_")));
+
+        let provided_secret = webhook_endpoints::WebhookEndpointCreateRequest::try_from(
+            StripeWebhookEndpointCreateRequest {
+                secret: Some(masking::Secret::new("whsec_provided".to_string())),
+                ..request
+            },
+        )
+        .expect("Invalid webhook endpoint request")
+        .secret
+        .expect("Missing webhook endpoint secret");
+        assert_eq!(provided_secret.peek(), "whsec_provided");
+    }
+}
@@ -23,18 +23,53 @@ use crate::{
     services::request::Maskable,
 };
 
+/// Outgoing webhooks of merchant accounts with Stripe as their compatible connector hold the object
+/// under `data`, tagged with its type. Profiles with the `stripe` webhook format receive Stripe
+/// `Event` objects instead, see [`StripeEventOutgoingWebhook`].
 #[derive(Serialize, Debug)]
-pub struct StripeOutgoingWebhook {
+pub struct StripeOutgoingWebhook<Data = StripeWebhookObject> {
     id: String,
     #[serde(rename = "type")]
     stype: &'static str,
     object: &'static str,
-    data: StripeWebhookObject,
+    data: Data,
     created: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    livemode: Option<bool>,
     // api_version: "2019-11-05", // not used
 }
 
-impl OutgoingWebhookType for StripeOutgoingWebhook {
+/// Stripe `Event` objects, which hold the object the event is about under `data.object`
+pub type StripeEventOutgoingWebhook = StripeOutgoingWebhook<StripeEventData>;
+
+/// The `data` of a Stripe event, which holds the object the event is about
+#[derive(Serialize, Debug)]
+pub struct StripeEventData {
+    #[serde(serialize_with = "serialize_event_object")]
+    object: StripeWebhookObject,
+}
+
+/// Serializes the object of a Stripe event as is, without the type tag of the webhooks sent to
+/// merchant accounts with Stripe as their compatible connector
+fn serialize_event_object<S: serde::Serializer>(
+    object: &StripeWebhookObject,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match object {
+        StripeWebhookObject::PaymentIntent(payment_intent) => payment_intent.serialize(serializer),
+        StripeWebhookObject::Refund(refund) => refund.serialize(serializer),
+        StripeWebhookObject::Dispute(dispute) => dispute.serialize(serializer),
+        StripeWebhookObject::Mandate(mandate) => mandate.serialize(serializer),
+        #[cfg(feature = "payouts")]
+        StripeWebhookObject::Payout(payout) => payout.serialize(serializer),
+    }
+}
+
+impl<Data> OutgoingWebhookType for StripeOutgoingWebhook<Data>
+where
+    Data: Serialize + Sync + Send + std::fmt::Debug + 'static,
+    Self: From<api::OutgoingWebhook>,
+{
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
@@ -262,7 +297,7 @@ impl From<DisputeStatus> for StripeDisputeStatus {
     }
 }
 
-fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static str {
+pub(super) fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static str {
     match event_type {
         api_models::enums::EventType::PaymentSucceeded => "payment_intent.succeeded",
         api_models::enums::EventType::PaymentFailed => "payment_intent.payment_failed",
@@ -280,6 +315,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeChallenged => "dispute.challenged",
         api_models::enums::EventType::DisputeWon => "dispute.won",
         api_models::enums::EventType::DisputeLost => "dispute.lost",
+        api_models::enums::EventType::DisputeDeadlineApproaching => "dispute.deadline_approaching",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
 
@@ -299,12 +335,16 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
     }
 }
 
-impl From<api::OutgoingWebhook> for StripeOutgoingWebhook {
-    fn from(value: api::OutgoingWebhook) -> Self {
+impl<Data> StripeOutgoingWebhook<Data> {
+    fn new(
+        value: api::OutgoingWebhook,
+        get_data: impl FnOnce(api::OutgoingWebhookContent) -> Data,
+        livemode: Option<bool>,
+    ) -> Self {
         Self {
             id: value.event_id,
             stype: get_stripe_event_type(value.event_type),
-            data: StripeWebhookObject::from(value.content),
+            data: get_data(value.content),
             object: "event",
             // put this conversion it into a function
             created: u64::try_from(value.timestamp.assume_utc().unix_timestamp()).unwrap_or_else(
@@ -319,10 +359,32 @@ impl From<api::OutgoingWebhook> for StripeOutgoingWebhook {
                         .unwrap_or_default()
                 },
             ),
+            livemode,
         }
     }
 }
 
+impl From<api::OutgoingWebhook> for StripeOutgoingWebhook {
+    fn from(value: api::OutgoingWebhook) -> Self {
+        Self::new(value, StripeWebhookObject::from, None)
+    }
+}
+
+impl From<api::OutgoingWebhook> for StripeEventOutgoingWebhook {
+    fn from(value: api::OutgoingWebhook) -> Self {
+        // Only the events of production deployments are live, the API keys of other environments
+        // are test keys
+        let livemode = matches!(router_env::env::which(), router_env::env::Env::Production);
+        Self::new(
+            value,
+            |content| StripeEventData {
+                object: StripeWebhookObject::from(content),
+            },
+            Some(livemode),
+        )
+    }
+}
+
 impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
     fn from(value: api::OutgoingWebhookContent) -> Self {
         match value {
@@ -341,3 +403,100 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::borrow::Cow;
+
+    use common_utils::types::MinorUnit;
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn refund_webhook() -> api::OutgoingWebhook {
+        let payment_id = common_utils::id_type::PaymentId::try_from(Cow::from("pay_1"))
+            .expect("Invalid payment id");
+        api::OutgoingWebhook {
+            merchant_id: common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1"))
+                .expect("Invalid merchant id"),
+            event_id: "evt_1".to_string(),
+            event_type: api_models::enums::EventType::RefundSucceeded,
+            content: api::OutgoingWebhookContent::RefundDetails(Box::new(
+                api_models::refunds::RefundResponse {
+                    refund_id: "ref_1".to_string(),
+                    payment_id,
+                    amount: MinorUnit::new(500),
+                    currency: "USD".to_string(),
+                    status: api_models::refunds::RefundStatus::Succeeded,
+                    reason: None,
+                    metadata: None,
+                    error_message: None,
+                    error_code: None,
+                    unified_code: None,
+                    unified_message: None,
+                    created_at: None,
+                    updated_at: None,
+                    connector: "stripe".to_string(),
+                    profile_id: None,
+                    merchant_connector_id: None,
+                    split_refunds: None,
+                    issuer_error_code: None,
+                    issuer_error_message: None,
+                },
+            )),
+            timestamp: datetime!(2024-06-01 00:00),
+        }
+    }
+
+    #[test]
+    fn test_stripe_event_shape() {
+        let event = serde_json::to_value(StripeEventOutgoingWebhook::from(refund_webhook()))
+            .expect("Failed to serialize the event");
+
+        assert_eq!(event.pointer("/id"), Some(&serde_json::json!("evt_1")));
+        assert_eq!(event.pointer("/object"), Some(&serde_json::json!("event")));
+        assert_eq!(
+            event.pointer("/type"),
+            Some(&serde_json::json!("refund.succeeded"))
+        );
+        assert_eq!(
+            event.pointer("/created"),
+            Some(&serde_json::json!(1717200000))
+        );
+        assert!(event
+            .pointer("/livemode")
+            .is_some_and(|livemode| livemode.is_boolean()));
+        // The object of the event is held as is under `data.object`
+        assert_eq!(
+            event.pointer("/data/object"),
+            Some(&serde_json::json!({
+                "id": "ref_1",
+                "amount": 500,
+                "currency": "usd",
+                "payment_intent": "pay_1",
+                "status": "succeeded",
+                "created": null,
+                "metadata": {},
+            }))
+        );
+    }
+
+    #[test]
+    fn test_compatible_connector_webhook_shape() {
+        let webhook = serde_json::to_value(StripeOutgoingWebhook::from(refund_webhook()))
+            .expect("Failed to serialize the webhook");
+
+        // The object is tagged with its type, and the webhook does not tell the mode
+        assert_eq!(
+            webhook.pointer("/data/type"),
+            Some(&serde_json::json!("refund"))
+        );
+        assert_eq!(
+            webhook.pointer("/data/object/id"),
+            Some(&serde_json::json!("ref_1"))
+        );
+        assert!(webhook.pointer("/livemode").is_none());
+    }
+}
@@ -6,10 +6,11 @@ use router_env::{instrument, tracing, Tag};
 use serde::Serialize;
 
 use crate::{
-    core::{api_locking, errors},
+    core::{api_locking, errors, idempotency},
     events::api_logs::ApiEventMetric,
     routes::{
         app::{AppStateInfo, ReqState},
+        lock_utils::GetApiIdentifier,
         AppState, SessionState,
     },
     services::{self, api, authentication as auth, logger},
@@ -17,7 +18,7 @@ use crate::{
 
 #[instrument(skip(request, payload, state, func, api_authentication))]
 pub async fn compatibility_api_wrap<'a, 'b, U, T, Q, F, Fut, S, E, E2>(
-    flow: impl router_env::types::FlowMetric,
+    flow: impl router_env::types::FlowMetric + GetApiIdentifier,
     state: Arc<AppState>,
     request: &'a HttpRequest,
     payload: T,
@@ -53,6 +54,7 @@ where
         func,
         api_authentication,
         lock_action,
+        idempotency::IdempotencyAction::NotApplicable,
     )
     .await
     .map(|response| {
@@ -106,6 +108,9 @@ where
             }
         }
         Ok(api::ApplicationResponse::StatusOk) => api::http_response_ok(),
+        Ok(api::ApplicationResponse::IdempotentReplay((response, headers))) => {
+            api::http_response_json_with_headers(response, headers, None)
+        }
         Ok(api::ApplicationResponse::TextPlain(text)) => api::http_response_plaintext(text),
         Ok(api::ApplicationResponse::FileData((file_data, content_type))) => {
             api::http_response_file_data(file_data, content_type)
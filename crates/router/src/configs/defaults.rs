@@ -101,6 +101,63 @@ impl Default for super::settings::EphemeralConfig {
     }
 }
 
+impl Default for super::settings::IncomingWebhookQueueSettings {
+    fn default() -> Self {
+        Self {
+            retry_delay_in_secs: 5,
+            max_retries: 10,
+        }
+    }
+}
+
+impl Default for super::settings::IdempotencySettings {
+    fn default() -> Self {
+        Self {
+            ttl_in_secs: 86400, // 24 * 60 * 60 seconds
+        }
+    }
+}
+
+impl Default for super::settings::VelocitySettings {
+    fn default() -> Self {
+        Self {
+            customer_attempt_window_in_mins: 60,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorCircuitBreakerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            failure_threshold: 10,
+            failure_window_in_secs: 60,
+            open_duration_in_secs: 60,
+            half_open_success_threshold: 3,
+            half_open_max_calls: 3,
+        }
+    }
+}
+
+impl Default for super::settings::DisputeDeadlineSettings {
+    fn default() -> Self {
+        Self {
+            reminder_offsets_in_hours: vec![72, 24],
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl Default for super::settings::PayoutBatchSettings {
+    fn default() -> Self {
+        Self {
+            max_rows: 1000,
+            chunk_size: 100,
+            concurrency: 10,
+        }
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::DrainerSettings {
     fn default() -> Self {
@@ -143,6 +200,9 @@ impl Default for super::settings::ApiKeys {
             // hashes of API keys
             hash_key: String::new().into(),
 
+            // Number of trusted proxies appending to the `X-Forwarded-For` header
+            trusted_proxy_count: 1,
+
             // Specifies the number of days before API key expiry when email reminders should be sent
             #[cfg(feature = "email")]
             expiry_reminder_days: vec![7, 3, 1],
@@ -111,6 +111,8 @@ impl SecretsHandler for settings::ApiKeys {
             .get_secret(api_keys.hash_key.clone())
             .await?;
 
+        let trusted_proxy_count = api_keys.trusted_proxy_count;
+
         #[cfg(feature = "email")]
         let expiry_reminder_days = api_keys.expiry_reminder_days.clone();
 
@@ -134,6 +136,7 @@ impl SecretsHandler for settings::ApiKeys {
 
         Ok(value.transition_state(|_| Self {
             hash_key,
+            trusted_proxy_count,
             #[cfg(feature = "email")]
             expiry_reminder_days,
 
@@ -501,6 +504,11 @@ pub(crate) async fn fetch_raw_secrets(
         multiple_api_version_supported_connectors: conf.multiple_api_version_supported_connectors,
         applepay_merchant_configs,
         lock_settings: conf.lock_settings,
+        rate_limit: conf.rate_limit,
+        idempotency: conf.idempotency,
+        velocity: conf.velocity,
+        connector_circuit_breaker: conf.connector_circuit_breaker,
+        dispute_deadline: conf.dispute_deadline,
         temp_locker_enable_config: conf.temp_locker_enable_config,
         generic_link: conf.generic_link,
         payment_link: conf.payment_link,
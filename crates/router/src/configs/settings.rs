@@ -50,7 +50,7 @@ use crate::{
     core::errors::{ApplicationError, ApplicationResult},
     env::{self, Env},
     events::EventsConfig,
-    routes::app,
+    routes::{app, lock_utils},
     AppState,
 };
 pub const REQUIRED_FIELDS_CONFIG_FILE: &str = "payment_required_fields_v2.toml";
@@ -123,6 +123,11 @@ pub struct Settings<S: SecretState> {
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: SecretStateContainer<ApplepayMerchantConfigs, S>,
     pub lock_settings: LockSettings,
+    pub rate_limit: RateLimitSettings,
+    pub idempotency: IdempotencySettings,
+    pub velocity: VelocitySettings,
+    pub connector_circuit_breaker: ConnectorCircuitBreakerSettings,
+    pub dispute_deadline: DisputeDeadlineSettings,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub generic_link: GenericLink,
     pub payment_link: PaymentLink,
@@ -811,6 +816,18 @@ pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
     pub redis_lock_expiry_seconds: u32,
+    pub incoming_queue: IncomingWebhookQueueSettings,
+}
+
+/// Settings for the incoming webhooks queued because the resource they refer to is locked by
+/// another incoming webhook
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct IncomingWebhookQueueSettings {
+    /// Delay before a queued webhook is processed, and base delay of the backoff between its
+    /// retries
+    pub retry_delay_in_secs: u32,
+    pub max_retries: u32,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -827,6 +844,11 @@ pub struct ApiKeys {
     /// hashes of API keys
     pub hash_key: Secret<String>,
 
+    /// Number of trusted proxies in front of the application that append the address of their
+    /// peer to the `X-Forwarded-For` header, used for determining the client IP address when
+    /// enforcing the IP allowlists of API keys
+    pub trusted_proxy_count: usize,
+
     // Specifies the number of days before API key expiry when email reminders should be sent
     #[cfg(feature = "email")]
     pub expiry_reminder_days: Vec<u8>,
@@ -1014,11 +1036,22 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
         self.lock_settings.validate()?;
+        self.rate_limit.validate()?;
+        self.idempotency.validate()?;
+        self.velocity.validate()?;
+        self.connector_circuit_breaker.validate()?;
+        self.dispute_deadline.validate()?;
         self.events.validate()?;
 
+        #[cfg(feature = "payouts")]
+        self.payouts.batch.validate()?;
+
         #[cfg(feature = "olap")]
         self.opensearch.validate()?;
 
+        #[cfg(feature = "olap")]
+        self.report_download_config.validate()?;
+
         self.encryption_management
             .validate()
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into()))?;
@@ -1079,6 +1112,21 @@ pub struct Payouts {
     pub payout_eligibility: bool,
     #[serde(default)]
     pub required_fields: PayoutRequiredFields,
+    #[serde(default)]
+    pub batch: PayoutBatchSettings,
+}
+
+/// Settings for the batches of payouts
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PayoutBatchSettings {
+    /// Maximum number of rows accepted in a single batch
+    pub max_rows: u32,
+    /// Number of rows of a batch processed by a single run of the scheduler task
+    pub chunk_size: u32,
+    /// Number of payouts of a chunk created concurrently
+    pub concurrency: u32,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -1110,6 +1158,83 @@ impl<'de> Deserialize<'de> for LockSettings {
     }
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    pub enabled: bool,
+    pub algorithm: RateLimitAlgorithm,
+    /// Limit applied to all the requests of a merchant
+    pub merchant: Option<RateLimit>,
+    /// Limit applied to the requests authenticated with an API key, tracked separately for each
+    /// API key of a merchant
+    pub api_key: Option<RateLimit>,
+    /// Limits applied to the requests of a merchant to a group of routes
+    pub route_groups: HashMap<lock_utils::ApiIdentifier, RateLimit>,
+    /// Limits overriding the `merchant` limit for specific merchants
+    pub merchant_overrides: HashMap<id_type::MerchantId, RateLimit>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub struct RateLimit {
+    /// Number of requests allowed in a window
+    pub requests: u32,
+    pub window_in_secs: u32,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitAlgorithm {
+    /// Requests consume tokens from a bucket refilled at a constant rate, allowing bursts of
+    /// requests up to the size of the bucket
+    #[default]
+    TokenBucket,
+    /// Requests are counted over a window of time ending at the current request
+    SlidingWindow,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct IdempotencySettings {
+    /// Time for which the responses of requests made with an `Idempotency-Key` are replayed
+    pub ttl_in_secs: u32,
+}
+
+/// Settings for the velocity keys of routing and decision manager programs
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct VelocitySettings {
+    /// Window over which the payment attempts of a customer are counted
+    pub customer_attempt_window_in_mins: u32,
+}
+
+/// Settings for the circuit breakers of the merchant connector accounts
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorCircuitBreakerSettings {
+    /// Whether the outcomes of the connector calls are recorded and the connectors with an open
+    /// breaker are removed from routing
+    pub enabled: bool,
+    /// Number of failed connector calls within the failure window which opens the breaker
+    pub failure_threshold: u32,
+    /// Window over which the failed connector calls are counted
+    pub failure_window_in_secs: u32,
+    /// Time after which an open breaker lets the connector calls through again (half open)
+    pub open_duration_in_secs: u32,
+    /// Number of successful connector calls which closes a half open breaker
+    pub half_open_success_threshold: u32,
+    /// Number of connector calls a half open breaker lets through within an open duration
+    pub half_open_max_calls: u32,
+}
+
+/// Settings for the reminders sent as the evidence submission deadline of an open dispute approaches
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DisputeDeadlineSettings {
+    /// Number of hours before the deadline at which reminders are sent, unless overridden by the
+    /// dispute policy of the business profile
+    pub reminder_offsets_in_hours: Vec<u16>,
+}
+
 #[cfg(feature = "olap")]
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct ConnectorOnboarding {
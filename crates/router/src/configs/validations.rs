@@ -142,6 +142,12 @@ impl super::settings::ApiKeys {
             ))
         })?;
 
+        when(self.trusted_proxy_count == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "API key trusted proxy count must be greater than zero".into(),
+            ))
+        })?;
+
         #[cfg(feature = "email")]
         when(self.expiry_reminder_days.is_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
@@ -181,6 +187,132 @@ impl super::settings::LockSettings {
     }
 }
 
+impl super::settings::RateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.merchant
+            .iter()
+            .chain(self.api_key.iter())
+            .chain(self.route_groups.values())
+            .chain(self.merchant_overrides.values())
+            .try_for_each(|rate_limit| rate_limit.validate())
+    }
+}
+
+impl super::settings::RateLimit {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.requests.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rate limit requests must not be empty or 0".into(),
+            ))
+        })?;
+
+        when(self.window_in_secs.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rate limit window_in_secs must not be empty or 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::IdempotencySettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.ttl_in_secs.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "idempotency ttl_in_secs must not be empty or 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::VelocitySettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.customer_attempt_window_in_mins.is_default_or_empty()
+                || self.customer_attempt_window_in_mins > 24 * 60,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "velocity customer_attempt_window_in_mins must be between 1 and 1440".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::ConnectorCircuitBreakerSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.enabled
+                && [
+                    self.failure_threshold,
+                    self.failure_window_in_secs,
+                    self.open_duration_in_secs,
+                    self.half_open_success_threshold,
+                    self.half_open_max_calls,
+                ]
+                .contains(&0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector_circuit_breaker thresholds, window and duration must not be 0"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.enabled && self.half_open_max_calls < self.half_open_success_threshold,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector_circuit_breaker half_open_max_calls must not be less than \
+                     half_open_success_threshold"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl super::settings::PayoutBatchSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            [self.max_rows, self.chunk_size, self.concurrency].contains(&0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "payouts.batch max_rows, chunk_size and concurrency must not be 0".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::DisputeDeadlineSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.reminder_offsets_in_hours.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "dispute_deadline reminder offsets must not be empty".into(),
+            ))
+        })?;
+
+        when(self.reminder_offsets_in_hours.contains(&0), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "dispute_deadline reminder offsets must not be 0".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::WebhooksSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -31,6 +31,9 @@ pub const REQUEST_TIMEOUT_PAYMENT_NOT_FOUND: &str = "Timed out ,payment not foun
 pub const REQUEST_TIMEOUT_ERROR_MESSAGE_FROM_PSYNC: &str =
     "This Payment has been moved to failed as there is no response from the connector";
 
+/// Default delay (in seconds) before a payment requeued by a GSM rule is retried
+pub const DEFAULT_GSM_REQUEUE_BACKOFF_IN_SECONDS: i32 = 5 * 60;
+
 ///Payment intent fulfillment default timeout (in seconds)
 pub const DEFAULT_FULFILLMENT_TIME: i64 = 15 * 60;
 
@@ -79,6 +82,8 @@ pub const EMAIL_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24; // 1 day
 pub const EMAIL_TOKEN_BLACKLIST_PREFIX: &str = "BET_";
 
 pub const EMAIL_SUBJECT_API_KEY_EXPIRY: &str = "API Key Expiry Notice";
+pub const EMAIL_SUBJECT_REPORT_DOWNLOAD: &str = "Your Report is Ready";
+pub const EMAIL_SUBJECT_DISPUTE_DEADLINE: &str = "Dispute Evidence Deadline Approaching";
 pub const EMAIL_SUBJECT_DASHBOARD_FEATURE_REQUEST: &str = "Dashboard Pro Feature Request by";
 pub const EMAIL_SUBJECT_APPROVAL_RECON_REQUEST: &str =
     "Approval of Recon Request - Access Granted to Recon Dashboard";
@@ -91,6 +96,12 @@ pub const GUEST_USER_CARD_BLOCKING_CACHE_KEY_PREFIX: &str = "GUEST_USER_CARD_BLO
 
 pub const CUSTOMER_ID_BLOCKING_PREFIX: &str = "CUSTOMER_ID_BLOCKING";
 
+pub const CUSTOMER_ATTEMPT_VELOCITY_PREFIX: &str = "CUSTOMER_ATTEMPT_VELOCITY";
+
+pub const CUSTOMER_AMOUNT_VELOCITY_PREFIX: &str = "CUSTOMER_AMOUNT_VELOCITY";
+
+pub const CARD_FAILURE_VELOCITY_PREFIX: &str = "CARD_FAILURE_VELOCITY";
+
 #[cfg(feature = "olap")]
 pub const VERIFY_CONNECTOR_ID_PREFIX: &str = "conn_verify";
 #[cfg(feature = "olap")]
@@ -115,6 +126,8 @@ pub const LOCKER_HEALTH_CALL_PATH: &str = "/health";
 
 pub const AUTHENTICATION_ID_PREFIX: &str = "authn";
 
+pub const ROUTING_ACTIVATION_ID_PREFIX: &str = "routing_act";
+
 // URL for checking the outgoing call
 pub const OUTGOING_CALL_URL: &str = "https://api.stripe.com/healthcheck";
 
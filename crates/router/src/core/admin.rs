@@ -22,6 +22,8 @@ use hyperswitch_domain_models::merchant_connector_account::{
 use masking::{ExposeInterface, PeekInterface, Secret};
 use pm_auth::{connector::plaid::transformers::PlaidAuthType, types as pm_auth_types};
 use regex::Regex;
+#[cfg(feature = "v1")]
+use router_env::tracing::Instrument;
 use uuid::Uuid;
 
 #[cfg(any(feature = "v1", feature = "v2"))]
@@ -3984,6 +3986,7 @@ impl ProfileCreateBridge for api::ProfileCreate {
             is_pre_network_tokenization_enabled: self
                 .is_pre_network_tokenization_enabled
                 .unwrap_or_default(),
+            dispute_policy: self.dispute_policy,
         }))
     }
 
@@ -4297,6 +4300,13 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry)?;
         }
 
+        validate_outgoing_webhook_format(
+            self.webhook_details.as_ref(),
+            self.payment_response_hash_key
+                .as_ref()
+                .or(business_profile.payment_response_hash_key.as_ref()),
+        )?;
+
         let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
 
         if let Some(ref routing_algorithm) = self.routing_algorithm {
@@ -4434,6 +4444,7 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 merchant_business_country: self.merchant_business_country,
                 is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
                 is_pre_network_tokenization_enabled: self.is_pre_network_tokenization_enabled,
+                dispute_policy: self.dispute_policy,
             },
         )))
     }
@@ -4452,6 +4463,13 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        validate_outgoing_webhook_format(
+            self.webhook_details.as_ref(),
+            self.payment_response_hash_key
+                .as_ref()
+                .or(business_profile.payment_response_hash_key.as_ref()),
+        )?;
+
         let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
 
         let payment_link_config = self
@@ -4578,6 +4596,28 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
     }
 }
 
+/// Webhooks sent as Stripe events are always signed, which requires the profile to have a
+/// `payment_response_hash_key`
+#[cfg(feature = "olap")]
+fn validate_outgoing_webhook_format(
+    webhook_details: Option<&admin_types::WebhookDetails>,
+    payment_response_hash_key: Option<&String>,
+) -> RouterResult<()> {
+    let is_stripe_format = webhook_details
+        .and_then(|webhook_details| webhook_details.webhook_format)
+        == Some(api_enums::OutgoingWebhookFormat::Stripe);
+    let has_signing_key = payment_response_hash_key
+        .is_some_and(|payment_response_hash_key| !payment_response_hash_key.is_empty());
+
+    fp_utils::when(is_stripe_format && !has_signing_key, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`payment_response_hash_key` is required to send webhooks in the `stripe` \
+                      format"
+                .to_string(),
+        }))
+    })
+}
+
 #[cfg(feature = "olap")]
 pub async fn update_profile(
     state: SessionState,
@@ -4595,6 +4635,8 @@ pub async fn update_profile(
             id: profile_id.get_string_repr().to_owned(),
         })?;
 
+    #[cfg(feature = "v1")]
+    let is_dispute_policy_updated = request.dispute_policy.is_some();
     let profile_update = request
         .get_update_profile_object(&state, &key_store, &business_profile)
         .await?;
@@ -4611,6 +4653,31 @@ pub async fn update_profile(
             id: profile_id.get_string_repr().to_owned(),
         })?;
 
+    // The open disputes of the profile follow the updated policy, the tasks are added in the
+    // background since the profile may have many open disputes
+    #[cfg(feature = "v1")]
+    if is_dispute_policy_updated {
+        let state = state.clone();
+        let business_profile = updated_business_profile.clone();
+        tokio::spawn(
+            async move {
+                crate::core::disputes::deadline::add_dispute_deadline_tasks_for_open_disputes(
+                    &state,
+                    &business_profile,
+                )
+                .await
+                .inspect_err(|error| {
+                    crate::logger::error!(
+                        ?error,
+                        "Failed to add the deadline tasks of open disputes"
+                    );
+                })
+                .ok();
+            }
+            .in_current_span(),
+        );
+    }
+
     Ok(service_api::ApplicationResponse::Json(
         api_models::admin::ProfileResponse::foreign_try_from(updated_business_profile)
             .change_context(errors::ApiErrorResponse::InternalServerError)
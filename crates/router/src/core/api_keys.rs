@@ -1,4 +1,5 @@
-use common_utils::date_time;
+use common_types::api_keys::{ApiKeyScope, ApiKeyScopes};
+use common_utils::{date_time, fp_utils};
 #[cfg(feature = "email")]
 use diesel_models::{api_keys::ApiKey, enums as storage_enums};
 use error_stack::{report, ResultExt};
@@ -8,10 +9,15 @@ use router_env::{instrument, tracing};
 use crate::{
     configs::settings,
     consts,
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
     db::domain,
     routes::{metrics, SessionState},
-    services::{authentication, ApplicationResponse},
+    services::{
+        authentication, authorization::permission_groups::PermissionGroupExt, ApplicationResponse,
+    },
     types::{api, storage, transformers::ForeignInto},
 };
 
@@ -119,6 +125,16 @@ pub async fn create_api_key(
 
     let merchant_id = key_store.merchant_id.clone();
 
+    validate_api_key_restrictions(api_key.scopes.as_deref(), api_key.allowed_ips.as_deref())?;
+    core_utils::validate_and_get_business_profile(
+        store,
+        &(&state).into(),
+        &key_store,
+        api_key.profile_id.as_ref(),
+        &merchant_id,
+    )
+    .await?;
+
     let hash_key = api_key_config.get_hash_key()?;
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
@@ -131,6 +147,9 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        scopes: api_key.scopes.map(ApiKeyScopes),
+        allowed_ips: api_key.allowed_ips,
+        profile_id: api_key.profile_id,
     };
 
     let api_key = store
@@ -271,11 +290,43 @@ pub async fn retrieve_api_key(
 pub async fn update_api_key(
     state: SessionState,
     api_key: api::UpdateApiKeyRequest,
+    key_store: domain::MerchantKeyStore,
 ) -> RouterResponse<api::RetrieveApiKeyResponse> {
     let merchant_id = api_key.merchant_id.clone();
     let key_id = api_key.key_id.clone();
     let store = state.store.as_ref();
 
+    // An empty list removes the corresponding restriction, so only non-empty lists are validated
+    validate_api_key_restrictions(
+        api_key
+            .scopes
+            .as_deref()
+            .filter(|scopes| !scopes.is_empty()),
+        api_key
+            .allowed_ips
+            .as_deref()
+            .filter(|allowed_ips| !allowed_ips.is_empty()),
+    )?;
+
+    fp_utils::when(
+        api_key.profile_id.is_some() && api_key.remove_profile_restriction == Some(true),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "profile_id cannot be provided when removing the profile restriction"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    core_utils::validate_and_get_business_profile(
+        store,
+        &(&state).into(),
+        &key_store,
+        api_key.profile_id.as_ref(),
+        &merchant_id,
+    )
+    .await?;
+
     let api_key = store
         .update_api_key(
             merchant_id.to_owned(),
@@ -358,6 +409,69 @@ pub async fn update_api_key(
     Ok(ApplicationResponse::Json(api_key.foreign_into()))
 }
 
+/// Parses an entry of the IP allowlist of an API Key, which is either a CIDR range or a single IP
+/// address.
+pub fn parse_allowed_ip(allowed_ip: &str) -> Option<ipnet::IpNet> {
+    allowed_ip.parse::<ipnet::IpNet>().ok().or_else(|| {
+        allowed_ip
+            .parse::<std::net::IpAddr>()
+            .ok()
+            .map(ipnet::IpNet::from)
+    })
+}
+
+fn validate_api_key_restrictions(
+    scopes: Option<&[ApiKeyScope]>,
+    allowed_ips: Option<&[String]>,
+) -> RouterResult<()> {
+    if let Some(scopes) = scopes {
+        fp_utils::when(scopes.is_empty(), || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "scopes must contain at least one scope".to_string(),
+            })
+        })?;
+
+        for scope in scopes {
+            let group_resources = scope.permission_group.resources();
+            let is_valid_scope = scope.resources.as_ref().map_or(true, |resources| {
+                !resources.is_empty()
+                    && resources
+                        .iter()
+                        .all(|resource| group_resources.contains(resource))
+            });
+            fp_utils::when(!is_valid_scope, || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "resources of the scope must be a non-empty subset of the resources of \
+                         the {} permission group",
+                        scope.permission_group
+                    ),
+                })
+            })?;
+        }
+    }
+
+    if let Some(allowed_ips) = allowed_ips {
+        fp_utils::when(allowed_ips.is_empty(), || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "allowed_ips must contain at least one IP address or CIDR range"
+                    .to_string(),
+            })
+        })?;
+
+        if let Some(invalid_ip) = allowed_ips
+            .iter()
+            .find(|allowed_ip| parse_allowed_ip(allowed_ip).is_none())
+        {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("{invalid_ip} is not a valid IP address or CIDR range"),
+            }));
+        }
+    }
+
+    Ok(())
+}
+
 // Update api_key_expiry task in the process_tracker table.
 // Construct Update variant of ProcessTrackerUpdate with new tracking_data.
 // A task is not scheduled if the time for the first email is in the past.
@@ -585,4 +699,71 @@ mod tests {
         let new_hashed_api_key = plaintext_api_key.keyed_hash(hash_key.peek());
         assert_eq!(hashed_api_key, new_hashed_api_key)
     }
+
+    #[test]
+    fn test_unrestricted_api_key() {
+        assert!(validate_api_key_restrictions(None, None).is_ok());
+    }
+
+    #[test]
+    fn test_api_key_scope_validation() {
+        let scope = |permission_group, resources| ApiKeyScope {
+            permission_group,
+            resources,
+        };
+
+        assert!(validate_api_key_restrictions(
+            Some(&[scope(common_enums::PermissionGroup::OperationsView, None)]),
+            None
+        )
+        .is_ok());
+        assert!(validate_api_key_restrictions(
+            Some(&[scope(
+                common_enums::PermissionGroup::OperationsManage,
+                Some(vec![
+                    common_enums::Resource::Payment,
+                    common_enums::Resource::Refund
+                ])
+            )]),
+            None
+        )
+        .is_ok());
+
+        // A key restricted to no scope at all would not be usable
+        assert!(validate_api_key_restrictions(Some(&[]), None).is_err());
+        assert!(validate_api_key_restrictions(
+            Some(&[scope(
+                common_enums::PermissionGroup::OperationsView,
+                Some(Vec::new())
+            )]),
+            None
+        )
+        .is_err());
+        assert!(validate_api_key_restrictions(
+            Some(&[scope(
+                common_enums::PermissionGroup::OperationsView,
+                Some(vec![common_enums::Resource::Connector])
+            )]),
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_unknown_api_key_scope() {
+        assert!(serde_json::from_value::<ApiKeyScope>(serde_json::json!({
+            "permission_group": "payments_superuser"
+        }))
+        .is_err());
+        assert!(serde_json::from_value::<ApiKeyScope>(serde_json::json!({
+            "permission_group": "operations_view",
+            "resources": ["unknown_resource"]
+        }))
+        .is_err());
+        assert!(serde_json::from_value::<ApiKeyScope>(serde_json::json!({
+            "permission_group": "operations_view",
+            "permissions": ["payment_write"]
+        }))
+        .is_err());
+    }
 }
@@ -22,8 +22,9 @@ pub enum LockStatus {
 pub enum LockAction {
     // Sleep until the lock is acquired
     Hold { input: LockingInput },
-    // Queue it but return response as 2xx, could be used for webhooks
-    QueueWithOk,
+    // Try to acquire the lock once, the caller queues the request and returns response as 2xx if
+    // the lock is busy, used for incoming webhooks
+    QueueWithOk { input: LockingInput },
     // Return Error
     Drop,
     // Locking Not applicable
@@ -50,12 +51,16 @@ impl LockingInput {
 }
 
 impl LockAction {
+    /// Acquires the lock for the action.
+    ///
+    /// Only [`Self::QueueWithOk`] results in [`LockStatus::Busy`], when the lock is held by
+    /// another caller. The other actions either acquire the lock or fail.
     #[instrument(skip_all)]
     pub async fn perform_locking_action<A>(
         self,
         state: &A,
         merchant_id: common_utils::id_type::MerchantId,
-    ) -> RouterResult<()>
+    ) -> RouterResult<LockStatus>
     where
         A: SessionStateInfo,
     {
@@ -90,7 +95,7 @@ impl LockAction {
                             logger::info!("Lock acquired for locking input {:?}", input);
                             tracing::Span::current()
                                 .record("redis_lock_acquired", redis_locking_key);
-                            return Ok(());
+                            return Ok(LockStatus::Acquired);
                         }
                         Ok(redis::SetnxReply::KeyNotSet) => {
                             logger::info!(
@@ -111,7 +116,40 @@ impl LockAction {
 
                 Err(report!(errors::ApiErrorResponse::ResourceBusy))
             }
-            Self::QueueWithOk | Self::Drop | Self::NotApplicable => Ok(()),
+            Self::QueueWithOk { input } => {
+                let redis_conn = state
+                    .store()
+                    .get_redis_conn()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+                let redis_locking_key = input.get_redis_locking_key(merchant_id);
+                let redis_lock_expiry_seconds =
+                    state.conf().lock_settings.redis_lock_expiry_seconds;
+                let redis_lock_result = redis_conn
+                    .set_key_if_not_exists_with_expiry(
+                        &redis_locking_key.as_str().into(),
+                        state.get_request_id(),
+                        Some(i64::from(redis_lock_expiry_seconds)),
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+                match redis_lock_result {
+                    redis::SetnxReply::KeySet => {
+                        logger::info!("Lock acquired for locking input {:?}", input);
+                        tracing::Span::current().record("redis_lock_acquired", redis_locking_key);
+                        Ok(LockStatus::Acquired)
+                    }
+                    redis::SetnxReply::KeyNotSet => {
+                        logger::info!(
+                            "Lock busy by other request, queueing request for locking input {:?}",
+                            input
+                        );
+                        Ok(LockStatus::Busy)
+                    }
+                }
+            }
+            Self::Drop | Self::NotApplicable => Ok(LockStatus::Acquired),
         }
     }
 
@@ -125,7 +163,7 @@ impl LockAction {
         A: SessionStateInfo,
     {
         match self {
-            Self::Hold { input } => {
+            Self::Hold { input } | Self::QueueWithOk { input } => {
                 let redis_conn = state
                     .store()
                     .get_redis_conn()
@@ -168,7 +206,7 @@ impl LockAction {
                     }
                 }
             }
-            Self::QueueWithOk | Self::Drop | Self::NotApplicable => Ok(()),
+            Self::Drop | Self::NotApplicable => Ok(()),
         }
     }
 }
@@ -207,6 +207,7 @@ pub fn construct_router_data<F: Clone, Req, Res>(
         authentication_id: None,
         psd2_sca_exemption_type,
         whole_connector_response: None,
+        merchant_connector_id: None,
     })
 }
 
@@ -1,3 +1,4 @@
+use common_utils::crypto::Encryptable;
 use error_stack::ResultExt;
 use hyperswitch_domain_models::{
     card_testing_guard_data::CardTestingGuardData, router_request_types::BrowserInformation,
@@ -102,6 +103,7 @@ pub async fn validate_card_testing_guard_checks(
                     .is_customer_id_blocking_enabled,
                 customer_id_blocking_cache_key,
                 card_testing_guard_expiry,
+                card_fingerprint: fingerprint,
             }))
         }
         None => Ok(None),
@@ -116,29 +118,20 @@ pub async fn generate_fingerprint(
 
     match card_testing_secret_key {
         Some(card_testing_secret_key) => {
-            let card_number_fingerprint = payment_method_data
-                .as_ref()
-                .and_then(|pm_data| match pm_data {
-                    api_models::payments::PaymentMethodData::Card(card) => {
-                        crypto::HmacSha512::sign_message(
-                            &crypto::HmacSha512,
-                            card_testing_secret_key.get_inner().peek().as_bytes(),
-                            card.card_number.clone().get_card_no().as_bytes(),
-                        )
-                        .attach_printable("error in pm fingerprint creation")
-                        .map_or_else(
-                            |err| {
-                                logger::error!(error=?err);
-                                None
-                            },
-                            Some,
-                        )
-                    }
-                    _ => None,
-                })
-                .map(hex::encode);
+            let card_number_fingerprint =
+                payment_method_data
+                    .as_ref()
+                    .and_then(|pm_data| match pm_data {
+                        api_models::payments::PaymentMethodData::Card(card) => {
+                            generate_card_number_fingerprint(
+                                card_testing_secret_key,
+                                &card.card_number,
+                            )
+                        }
+                        _ => None,
+                    });
 
-            card_number_fingerprint.map(Secret::new).ok_or_else(|| {
+            card_number_fingerprint.ok_or_else(|| {
                 error_stack::report!(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable("Error while masking fingerprint")
             })
@@ -148,6 +141,28 @@ pub async fn generate_fingerprint(
     }
 }
 
+/// Fingerprint of a card number with the card testing secret key of a profile
+pub fn generate_card_number_fingerprint(
+    card_testing_secret_key: &Encryptable<Secret<String>>,
+    card_number: &cards::CardNumber,
+) -> Option<Secret<String>> {
+    crypto::HmacSha512::sign_message(
+        &crypto::HmacSha512,
+        card_testing_secret_key.get_inner().peek().as_bytes(),
+        card_number.clone().get_card_no().as_bytes(),
+    )
+    .attach_printable("error in pm fingerprint creation")
+    .map_or_else(
+        |err| {
+            logger::error!(error=?err);
+            None
+        },
+        Some,
+    )
+    .map(hex::encode)
+    .map(Secret::new)
+}
+
 pub async fn increment_blocked_count_in_cache(
     state: &SessionState,
     card_testing_guard_data: Option<CardTestingGuardData>,
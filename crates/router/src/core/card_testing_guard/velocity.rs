@@ -0,0 +1,321 @@
+//! Velocity of the customers and cards of a profile, counted from the outcomes of the payments
+//! checked by the card testing guard, and made available to routing and decision manager
+//! programs through the velocity keys.
+//!
+//! Each counter is a Redis hash with a field per time bucket, the buckets outside the window of
+//! the counter are ignored when the counter is read and removed when the key expires.
+
+use std::collections::HashMap;
+
+use common_enums::enums;
+use common_utils::{date_time, id_type, types::MinorUnit};
+use error_stack::ResultExt;
+use euclid::backend::inputs::VelocityInput;
+use hyperswitch_domain_models::card_testing_guard_data::CardTestingGuardData;
+use masking::{PeekInterface, Secret};
+use redis_interface::RedisConnectionPool;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+};
+
+const SECONDS_IN_MINUTE: i64 = 60;
+const SECONDS_IN_HOUR: i64 = 60 * 60;
+const SECONDS_IN_DAY: i64 = 24 * 60 * 60;
+
+/// Number of hours over which the amount transacted by a customer is summed up
+const CUSTOMER_TRANSACTED_AMOUNT_WINDOW_IN_HOURS: i64 = 24;
+
+struct VelocityCounter {
+    key: String,
+    bucket_size_in_secs: i64,
+    window_size_in_secs: i64,
+}
+
+impl VelocityCounter {
+    fn customer_attempts(
+        state: &SessionState,
+        profile_id: &id_type::ProfileId,
+        customer_id: &id_type::CustomerId,
+    ) -> Self {
+        Self {
+            key: format!(
+                "{}_{}_{}",
+                consts::CUSTOMER_ATTEMPT_VELOCITY_PREFIX,
+                profile_id.get_string_repr(),
+                customer_id.get_string_repr(),
+            ),
+            bucket_size_in_secs: SECONDS_IN_MINUTE,
+            window_size_in_secs: i64::from(state.conf.velocity.customer_attempt_window_in_mins)
+                * SECONDS_IN_MINUTE,
+        }
+    }
+
+    fn customer_transacted_amount(
+        profile_id: &id_type::ProfileId,
+        customer_id: &id_type::CustomerId,
+        currency: enums::Currency,
+    ) -> Self {
+        Self {
+            key: format!(
+                "{}_{}_{}_{currency}",
+                consts::CUSTOMER_AMOUNT_VELOCITY_PREFIX,
+                profile_id.get_string_repr(),
+                customer_id.get_string_repr(),
+            ),
+            bucket_size_in_secs: SECONDS_IN_HOUR,
+            window_size_in_secs: CUSTOMER_TRANSACTED_AMOUNT_WINDOW_IN_HOURS * SECONDS_IN_HOUR,
+        }
+    }
+
+    /// Failures of a card are counted for the current day (in UTC)
+    fn card_failures(profile_id: &id_type::ProfileId, card_fingerprint: &Secret<String>) -> Self {
+        Self {
+            key: format!(
+                "{}_{}_{}",
+                consts::CARD_FAILURE_VELOCITY_PREFIX,
+                profile_id.get_string_repr(),
+                card_fingerprint.peek(),
+            ),
+            bucket_size_in_secs: SECONDS_IN_DAY,
+            window_size_in_secs: SECONDS_IN_DAY,
+        }
+    }
+
+    /// Buckets are aligned to the Unix epoch, so that day buckets start at midnight (in UTC)
+    fn get_bucket(&self, timestamp: i64) -> i64 {
+        timestamp / self.bucket_size_in_secs
+    }
+
+    fn current_bucket(&self) -> i64 {
+        self.get_bucket(date_time::now().assume_utc().unix_timestamp())
+    }
+
+    /// A window which is not a multiple of the bucket size covers the oldest bucket partially,
+    /// and the whole of that bucket is counted
+    fn window_size_in_buckets(&self) -> i64 {
+        self.window_size_in_secs / self.bucket_size_in_secs
+            + i64::from(self.window_size_in_secs % self.bucket_size_in_secs != 0)
+    }
+
+    /// Sums up the buckets within the window ending with the current bucket
+    fn sum_buckets(&self, buckets: HashMap<String, i64>, current_bucket: i64) -> i64 {
+        let oldest_bucket = current_bucket - self.window_size_in_buckets() + 1;
+        buckets
+            .into_iter()
+            .filter(|(bucket, _)| {
+                bucket
+                    .parse::<i64>()
+                    .is_ok_and(|bucket| (oldest_bucket..=current_bucket).contains(&bucket))
+            })
+            .map(|(_, value)| value)
+            .sum()
+    }
+
+    async fn increment(&self, redis_conn: &RedisConnectionPool, value: i64) -> RouterResult<()> {
+        let key = self.key.as_str().into();
+        redis_conn
+            .increment_fields_in_hash(&key, &[(self.current_bucket(), value)])
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to increment velocity counter")?;
+        redis_conn
+            .set_expiry(
+                &key,
+                self.bucket_size_in_secs * self.window_size_in_buckets(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to set expiry of velocity counter")
+    }
+
+    async fn get_total(&self, redis_conn: &RedisConnectionPool) -> RouterResult<i64> {
+        let buckets = redis_conn
+            .get_hash_fields::<HashMap<String, i64>>(&self.key.as_str().into())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get velocity counter")?;
+
+        Ok(self.sum_buckets(buckets, self.current_bucket()))
+    }
+}
+
+/// Velocity of the customer and the card of a payment.
+///
+/// The velocity is best effort, counters which cannot be read are left out of the input and
+/// evaluate like a missing value in the rules of the program.
+#[instrument(skip_all)]
+pub async fn get_velocity_input(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    customer_id: Option<&id_type::CustomerId>,
+    card_fingerprint: Option<&Secret<String>>,
+    currency: enums::Currency,
+) -> VelocityInput {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection for velocity input");
+            return VelocityInput::default();
+        }
+    };
+    let redis_conn = &*redis_conn;
+
+    let read = |counter: VelocityCounter| async move {
+        counter
+            .get_total(redis_conn)
+            .await
+            .inspect_err(|error| logger::error!(?error, "Failed to read velocity counter"))
+            .ok()
+    };
+
+    let (customer_attempt_count, customer_transacted_amount) = match customer_id {
+        Some(customer_id) => (
+            read(VelocityCounter::customer_attempts(
+                state,
+                profile_id,
+                customer_id,
+            ))
+            .await,
+            read(VelocityCounter::customer_transacted_amount(
+                profile_id,
+                customer_id,
+                currency,
+            ))
+            .await
+            .map(MinorUnit::new),
+        ),
+        None => (None, None),
+    };
+    let card_failure_count = match card_fingerprint {
+        Some(card_fingerprint) => {
+            read(VelocityCounter::card_failures(profile_id, card_fingerprint)).await
+        }
+        None => None,
+    };
+
+    VelocityInput {
+        customer_attempt_count,
+        card_failure_count,
+        customer_transacted_amount,
+    }
+}
+
+/// Counts the outcome of a payment attempt checked by the card testing guard in the velocity of
+/// its customer and card.
+///
+/// Like the card testing guard, only the outcome of the payment confirmation is counted, the
+/// amount of a payment completed later (after an authentication, for instance) is not added to
+/// the amount transacted by the customer.
+#[instrument(skip_all)]
+pub async fn record_payment_attempt_velocity(
+    state: &SessionState,
+    card_testing_guard_data: &CardTestingGuardData,
+    profile_id: &id_type::ProfileId,
+    customer_id: Option<&id_type::CustomerId>,
+    currency: enums::Currency,
+    amount: MinorUnit,
+    status: enums::AttemptStatus,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    if let Some(customer_id) = customer_id {
+        VelocityCounter::customer_attempts(state, profile_id, customer_id)
+            .increment(&redis_conn, 1)
+            .await?;
+
+        if matches!(
+            status,
+            enums::AttemptStatus::Charged
+                | enums::AttemptStatus::Authorized
+                | enums::AttemptStatus::PartialCharged
+                | enums::AttemptStatus::PartialChargedAndChargeable
+        ) {
+            VelocityCounter::customer_transacted_amount(profile_id, customer_id, currency)
+                .increment(&redis_conn, amount.get_amount_as_i64())
+                .await?;
+        }
+    }
+
+    if status == enums::AttemptStatus::Failure {
+        VelocityCounter::card_failures(profile_id, &card_testing_guard_data.card_fingerprint)
+            .increment(&redis_conn, 1)
+            .await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counter(bucket_size_in_secs: i64, window_size_in_secs: i64) -> VelocityCounter {
+        VelocityCounter {
+            key: "velocity".to_string(),
+            bucket_size_in_secs,
+            window_size_in_secs,
+        }
+    }
+
+    fn buckets(buckets: &[(i64, i64)]) -> HashMap<String, i64> {
+        buckets
+            .iter()
+            .map(|(bucket, value)| (bucket.to_string(), *value))
+            .collect()
+    }
+
+    #[test]
+    fn test_bucket_alignment() {
+        let minute_counter = counter(SECONDS_IN_MINUTE, 5 * SECONDS_IN_MINUTE);
+        assert_eq!(minute_counter.get_bucket(0), 0);
+        assert_eq!(minute_counter.get_bucket(59), 0);
+        assert_eq!(minute_counter.get_bucket(60), 1);
+
+        // 2024-01-01T23:59:59Z and 2024-01-02T00:00:00Z fall in consecutive day buckets
+        let day_counter = counter(SECONDS_IN_DAY, SECONDS_IN_DAY);
+        assert_eq!(
+            day_counter.get_bucket(1_704_153_599) + 1,
+            day_counter.get_bucket(1_704_153_600)
+        );
+        assert_eq!(
+            day_counter.get_bucket(1_704_153_600),
+            day_counter.get_bucket(1_704_239_999)
+        );
+    }
+
+    #[test]
+    fn test_expired_buckets_are_not_counted() {
+        let counter = counter(SECONDS_IN_MINUTE, 5 * SECONDS_IN_MINUTE);
+        assert_eq!(counter.window_size_in_buckets(), 5);
+
+        // Buckets 96 to 100 are within the window, bucket 95 has expired
+        let total = counter.sum_buckets(buckets(&[(95, 10), (96, 1), (98, 2), (100, 3)]), 100);
+        assert_eq!(total, 6);
+
+        // Buckets which are not numbers are ignored
+        let total = counter.sum_buckets(
+            HashMap::from([("bucket".to_string(), 10), ("100".to_string(), 1)]),
+            100,
+        );
+        assert_eq!(total, 1);
+    }
+
+    #[test]
+    fn test_window_not_a_multiple_of_bucket_size() {
+        // A window of 90 minutes covers the current hour and half of the hour before, which is
+        // counted as a whole
+        let counter = counter(SECONDS_IN_HOUR, 90 * SECONDS_IN_MINUTE);
+        assert_eq!(counter.window_size_in_buckets(), 2);
+
+        let total = counter.sum_buckets(buckets(&[(7, 100), (8, 10), (9, 1), (10, 1000)]), 9);
+        assert_eq!(total, 11);
+    }
+}
@@ -1,13 +1,20 @@
 #[cfg(feature = "v2")]
 use api_models::conditional_configs::DecisionManagerRequest;
 use api_models::conditional_configs::{
-    DecisionManager, DecisionManagerRecord, DecisionManagerResponse,
+    DecisionManager, DecisionManagerAnalysisResponse, DecisionManagerRecord,
+    DecisionManagerResponse,
 };
 use common_utils::ext_traits::StringExt;
 #[cfg(feature = "v2")]
 use common_utils::types::keymanager::KeyManagerState;
+#[cfg(feature = "v1")]
+use error_stack::report;
 use error_stack::ResultExt;
+#[cfg(feature = "v1")]
+use euclid::frontend::ast::Program;
 
+#[cfg(feature = "v1")]
+use crate::core::errors::RouterResult;
 use crate::{
     core::errors::{self, RouterResponse},
     routes::SessionState,
@@ -80,32 +87,7 @@ pub async fn upsert_conditional_config(
     use super::routing::helpers::update_merchant_active_algorithm_ref;
 
     let db = state.store.as_ref();
-    let (name, prog) = match request {
-        DecisionManager::DecisionManagerv0(ccr) => {
-            let name = ccr.name;
-
-            let prog = ccr
-                .algorithm
-                .get_required_value("algorithm")
-                .change_context(errors::ApiErrorResponse::MissingRequiredField {
-                    field_name: "algorithm",
-                })
-                .attach_printable("Algorithm for config not given")?;
-            (name, prog)
-        }
-        DecisionManager::DecisionManagerv1(dmr) => {
-            let name = dmr.name;
-
-            let prog = dmr
-                .program
-                .get_required_value("program")
-                .change_context(errors::ApiErrorResponse::MissingRequiredField {
-                    field_name: "program",
-                })
-                .attach_printable("Program for config not given")?;
-            (name, prog)
-        }
-    };
+    let (name, prog) = get_decision_manager_program(request)?;
     let timestamp = common_utils::date_time::now_unix_timestamp();
     let mut algo_id: api_models::routing::RoutingAlgorithmRef = merchant_context
         .get_merchant_account()
@@ -219,6 +201,57 @@ pub async fn upsert_conditional_config(
     }
 }
 
+#[cfg(feature = "v1")]
+fn get_decision_manager_program(
+    request: DecisionManager,
+) -> RouterResult<(
+    Option<String>,
+    Program<common_types::payments::ConditionalConfigs>,
+)> {
+    use common_utils::ext_traits::OptionExt;
+
+    match request {
+        DecisionManager::DecisionManagerv0(ccr) => {
+            let prog = ccr
+                .algorithm
+                .get_required_value("algorithm")
+                .change_context(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "algorithm",
+                })
+                .attach_printable("Algorithm for config not given")?;
+            Ok((ccr.name, prog))
+        }
+        DecisionManager::DecisionManagerv1(dmr) => {
+            let prog = dmr
+                .program
+                .get_required_value("program")
+                .change_context(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "program",
+                })
+                .attach_printable("Program for config not given")?;
+            Ok((dmr.name, prog))
+        }
+    }
+}
+
+/// Reports the unreachable, shadowed and overlapping rules of the config without saving it
+#[cfg(feature = "v1")]
+pub async fn analyze_conditional_config(
+    request: DecisionManager,
+) -> RouterResponse<DecisionManagerAnalysisResponse> {
+    let (_, program) = get_decision_manager_program(request)?;
+
+    let findings = euclid::dssa::report::analyze_rules(program).map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.to_string(),
+        })
+    })?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        DecisionManagerAnalysisResponse { findings },
+    ))
+}
+
 #[cfg(feature = "v2")]
 pub async fn delete_conditional_config(
     _state: SessionState,
@@ -0,0 +1,859 @@
+//! Circuit breakers of the merchant connector accounts.
+//!
+//! The outcomes of the calls made to a connector are recorded against the merchant connector
+//! account through which the calls are made. The breaker opens once the failed calls within the
+//! failure window reach the threshold, and the connector is then skipped by routing. After the
+//! open duration the breaker becomes half open, letting a bounded number of calls through (per
+//! open duration): enough successful calls close it, while a failed call opens it again.
+
+#[cfg(feature = "v1")]
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use api_models::{
+    admin::{ConnectorCircuitBreakerResponse, ConnectorCircuitBreakerState},
+    routing::RoutableConnectorChoice,
+};
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use redis_interface::{RedisConnectionPool, RedisKey};
+use router_env::{instrument, logger, tracing, tracing::Instrument};
+
+use super::{
+    errors::{self, CustomResult, RouterResult},
+    metrics,
+};
+#[cfg(feature = "v1")]
+use super::{
+    errors::{RouterResponse, StorageErrorExt},
+    utils as core_utils,
+};
+use crate::{configs::settings::ConnectorCircuitBreakerSettings, routes::SessionState, types};
+#[cfg(feature = "v1")]
+use crate::{services, types::domain};
+
+pub const CIRCUIT_BREAKER_PREFIX: &str = "CIRCUIT_BREAKER";
+
+#[cfg(feature = "v1")]
+const STATE_FIELD: &str = "state";
+#[cfg(feature = "v1")]
+const FAILURES_FIELD: &str = "failures";
+#[cfg(feature = "v1")]
+const OPENED_AT_FIELD: &str = "opened_at";
+#[cfg(feature = "v1")]
+const HALF_OPEN_SUCCESSES_FIELD: &str = "half_open_successes";
+
+/// Records the outcome of a connector call in the breaker in `KEYS[1]`.
+///
+/// `ARGV` holds the outcome (`1` for a successful call), the failure threshold, the failure window
+/// and the open duration (in seconds), the number of successful calls which closes a half open
+/// breaker and the expiry of the breaker (in seconds). Returns the state of the breaker before and
+/// after recording the outcome.
+const RECORD_OUTCOME_SCRIPT: &str = r#"
+local now = tonumber(redis.call("TIME")[1])
+local success = ARGV[1] == "1"
+local failure_threshold = tonumber(ARGV[2])
+local failure_window = tonumber(ARGV[3])
+local open_duration = tonumber(ARGV[4])
+local half_open_success_threshold = tonumber(ARGV[5])
+local ttl = tonumber(ARGV[6])
+
+local breaker = redis.call("HMGET", KEYS[1], "state", "failures", "window_started_at", "opened_at", "half_open_successes")
+local half_open_calls = redis.call("HMGET", KEYS[1], "half_open_calls", "half_open_calls_started_at")
+local previous_state = breaker[1] or "closed"
+local state = previous_state
+local failures = tonumber(breaker[2]) or 0
+local window_started_at = tonumber(breaker[3]) or now
+local opened_at = tonumber(breaker[4]) or 0
+local half_open_successes = tonumber(breaker[5]) or 0
+
+if state == "open" and now - opened_at >= open_duration then
+    state = "half_open"
+    half_open_successes = 0
+end
+
+if state == "closed" then
+    if now - window_started_at >= failure_window then
+        failures = 0
+        window_started_at = now
+    end
+    if not success then
+        failures = failures + 1
+        if failures >= failure_threshold then
+            state = "open"
+            opened_at = now
+        end
+    end
+elseif state == "half_open" then
+    if success then
+        half_open_successes = half_open_successes + 1
+        if half_open_successes >= half_open_success_threshold then
+            state = "closed"
+            failures = 0
+            window_started_at = now
+            half_open_successes = 0
+        end
+    else
+        state = "open"
+        opened_at = now
+        half_open_successes = 0
+    end
+end
+
+-- The calls let through by a half open breaker are counted afresh once it opens or closes again
+if state ~= "half_open" then
+    half_open_calls = { 0, 0 }
+end
+
+redis.call("HSET", KEYS[1],
+    "state", state,
+    "failures", failures,
+    "window_started_at", window_started_at,
+    "opened_at", opened_at,
+    "half_open_successes", half_open_successes,
+    "half_open_calls", half_open_calls[1] or 0,
+    "half_open_calls_started_at", half_open_calls[2] or 0)
+redis.call("EXPIRE", KEYS[1], ttl)
+return { previous_state, state }
+"#;
+
+/// Decides whether a call can be made through the breaker in `KEYS[1]`.
+///
+/// `ARGV` holds the open duration (in seconds) and the maximum number of calls let through by a
+/// half open breaker within an open duration. Calls are always let through a closed breaker and
+/// never through an open one. Returns `1` if the call is let through.
+const ADMIT_CALL_SCRIPT: &str = r#"
+local now = tonumber(redis.call("TIME")[1])
+local open_duration = tonumber(ARGV[1])
+local half_open_max_calls = tonumber(ARGV[2])
+
+local breaker = redis.call("HMGET", KEYS[1], "state", "opened_at", "half_open_calls", "half_open_calls_started_at")
+local state = breaker[1] or "closed"
+local opened_at = tonumber(breaker[2]) or 0
+
+if state == "closed" then
+    return 1
+end
+if state == "open" and now - opened_at < open_duration then
+    return 0
+end
+
+-- Half open, the count is reset every open duration so that the breaker does not stay half open
+-- if the outcomes of the calls it let through are never recorded
+local calls = tonumber(breaker[3]) or 0
+local calls_started_at = tonumber(breaker[4]) or 0
+if now - calls_started_at >= open_duration then
+    calls = 0
+    calls_started_at = now
+end
+if calls >= half_open_max_calls then
+    return 0
+end
+redis.call("HSET", KEYS[1], "half_open_calls", calls + 1, "half_open_calls_started_at", calls_started_at)
+return 1
+"#;
+
+/// Outcome of a call made to a connector, as seen by the circuit breaker
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConnectorCallOutcome {
+    Success,
+    Failure,
+}
+
+impl ConnectorCallOutcome {
+    /// Timeouts, connection failures and 5xx responses are failures of the connector. Errors in
+    /// building the request or in reading the response are not attributed to the connector.
+    pub fn from_response(
+        response: &CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+    ) -> Option<Self> {
+        match response {
+            Ok(Ok(_)) => Some(Self::Success),
+            Ok(Err(response)) if (500..=599).contains(&response.status_code) => Some(Self::Failure),
+            Ok(Err(_)) => Some(Self::Success),
+            Err(error) => {
+                let error = error.current_context();
+                (error.is_upstream_timeout()
+                    || error.is_connection_closed_before_message_could_complete()
+                    || matches!(error, errors::ApiClientError::RequestNotSent(_)))
+                .then_some(Self::Failure)
+            }
+        }
+    }
+}
+
+/// State of the breaker of a merchant connector account
+#[cfg(feature = "v1")]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct CircuitBreaker {
+    state: ConnectorCircuitBreakerState,
+    failure_count: u32,
+    half_open_success_count: u32,
+    opened_at: Option<i64>,
+}
+
+#[cfg(feature = "v1")]
+impl CircuitBreaker {
+    /// Builds the breaker from the fields of its hash. An open breaker is reported as half open
+    /// once the open duration has elapsed, as the transition only happens on the next recorded
+    /// outcome.
+    fn from_fields(fields: &HashMap<String, String>, now: i64, open_duration_in_secs: u32) -> Self {
+        let get_count = |field: &str| {
+            fields
+                .get(field)
+                .and_then(|value| value.parse::<u32>().ok())
+                .unwrap_or_default()
+        };
+        let opened_at = fields
+            .get(OPENED_AT_FIELD)
+            .and_then(|value| value.parse::<i64>().ok())
+            .filter(|opened_at| *opened_at > 0);
+        let state = fields
+            .get(STATE_FIELD)
+            .and_then(|value| ConnectorCircuitBreakerState::from_str(value).ok())
+            .unwrap_or_default();
+
+        match state {
+            ConnectorCircuitBreakerState::Open
+                if opened_at.is_some_and(|opened_at| {
+                    now - opened_at >= i64::from(open_duration_in_secs)
+                }) =>
+            {
+                Self {
+                    state: ConnectorCircuitBreakerState::HalfOpen,
+                    failure_count: get_count(FAILURES_FIELD),
+                    half_open_success_count: 0,
+                    opened_at,
+                }
+            }
+            _ => Self {
+                state,
+                failure_count: get_count(FAILURES_FIELD),
+                half_open_success_count: get_count(HALF_OPEN_SUCCESSES_FIELD),
+                opened_at,
+            },
+        }
+    }
+}
+
+fn get_circuit_breaker_key(merchant_connector_id: &id_type::MerchantConnectorAccountId) -> String {
+    format!(
+        "{}_{}",
+        CIRCUIT_BREAKER_PREFIX,
+        merchant_connector_id.get_string_repr()
+    )
+}
+
+/// Records the outcome of a connector call in the breaker of the merchant connector account
+/// through which the call was made.
+///
+/// The outcome is recorded in the background, so the connector call neither waits on redis nor
+/// fails if the outcome cannot be recorded.
+pub fn record_connector_call_outcome(
+    state: &SessionState,
+    connector: &str,
+    merchant_connector_id: Option<&id_type::MerchantConnectorAccountId>,
+    response: &CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+) {
+    if !state.conf.connector_circuit_breaker.enabled {
+        return;
+    }
+
+    let Some((merchant_connector_id, outcome)) =
+        merchant_connector_id.zip(ConnectorCallOutcome::from_response(response))
+    else {
+        return;
+    };
+
+    let state = state.clone();
+    let connector = connector.to_string();
+    let merchant_connector_id = merchant_connector_id.clone();
+    tokio::spawn(
+        async move {
+            record_outcome_in_circuit_breaker(&state, &connector, &merchant_connector_id, outcome)
+                .await
+        }
+        .in_current_span(),
+    );
+}
+
+#[instrument(skip_all)]
+async fn record_outcome_in_circuit_breaker(
+    state: &SessionState,
+    connector: &str,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    outcome: ConnectorCallOutcome,
+) {
+    let config = &state.conf.connector_circuit_breaker;
+    match update_circuit_breaker(state, config, merchant_connector_id, outcome).await {
+        Ok((previous_state, current_state)) if previous_state != current_state => {
+            logger::info!(
+                connector,
+                ?merchant_connector_id,
+                %previous_state,
+                %current_state,
+                "Connector circuit breaker state changed"
+            );
+            metrics::CONNECTOR_CIRCUIT_BREAKER_STATE_TRANSITION.add(
+                1,
+                router_env::metric_attributes!(
+                    ("connector", connector.to_string()),
+                    ("state", current_state.to_string()),
+                ),
+            );
+        }
+        Ok(_) => {}
+        Err(error) => {
+            logger::error!(?error, "Failed to record the connector call outcome");
+        }
+    }
+}
+
+async fn update_circuit_breaker(
+    state: &SessionState,
+    config: &ConnectorCircuitBreakerSettings,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    outcome: ConnectorCallOutcome,
+) -> RouterResult<(ConnectorCircuitBreakerState, ConnectorCircuitBreakerState)> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    evaluate_record_outcome_script(
+        &redis_conn,
+        &get_circuit_breaker_key(merchant_connector_id).into(),
+        config,
+        outcome,
+    )
+    .await
+}
+
+async fn evaluate_record_outcome_script(
+    redis_conn: &RedisConnectionPool,
+    key: &RedisKey,
+    config: &ConnectorCircuitBreakerSettings,
+    outcome: ConnectorCallOutcome,
+) -> RouterResult<(ConnectorCircuitBreakerState, ConnectorCircuitBreakerState)> {
+    let values = vec![
+        match outcome {
+            ConnectorCallOutcome::Success => "1",
+            ConnectorCallOutcome::Failure => "0",
+        }
+        .to_string(),
+        config.failure_threshold.to_string(),
+        config.failure_window_in_secs.to_string(),
+        config.open_duration_in_secs.to_string(),
+        config.half_open_success_threshold.to_string(),
+        (u64::from(config.failure_window_in_secs) + u64::from(config.open_duration_in_secs))
+            .to_string(),
+    ];
+
+    let result = redis_conn
+        .evaluate_redis_script::<_, Vec<String>>(
+            RECORD_OUTCOME_SCRIPT,
+            vec![key.tenant_aware_key(redis_conn)],
+            values,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to evaluate circuit breaker script")?;
+
+    let [previous_state, current_state] = result.as_slice() else {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable_lazy(
+            || format!("Unexpected circuit breaker script result: {result:?}"),
+        );
+    };
+
+    let parse_state = |value: &str| {
+        ConnectorCircuitBreakerState::from_str(value)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Unexpected circuit breaker state: {value}"))
+    };
+
+    Ok((parse_state(previous_state)?, parse_state(current_state)?))
+}
+
+/// Whether a call can be made through the breaker of the merchant connector account, counting the
+/// call against the calls a half open breaker lets through
+async fn admit_circuit_breaker_call(
+    state: &SessionState,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<bool> {
+    let config = &state.conf.connector_circuit_breaker;
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    evaluate_admit_call_script(
+        &redis_conn,
+        &get_circuit_breaker_key(merchant_connector_id).into(),
+        config,
+    )
+    .await
+}
+
+async fn evaluate_admit_call_script(
+    redis_conn: &RedisConnectionPool,
+    key: &RedisKey,
+    config: &ConnectorCircuitBreakerSettings,
+) -> RouterResult<bool> {
+    let values = vec![
+        config.open_duration_in_secs.to_string(),
+        config.half_open_max_calls.to_string(),
+    ];
+
+    redis_conn
+        .evaluate_redis_script::<_, i64>(
+            ADMIT_CALL_SCRIPT,
+            vec![key.tenant_aware_key(redis_conn)],
+            values,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to evaluate circuit breaker script")
+        .map(|admitted| admitted == 1)
+}
+
+#[cfg(feature = "v1")]
+async fn get_circuit_breaker(
+    state: &SessionState,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<CircuitBreaker> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let fields = redis_conn
+        .get_hash_fields::<HashMap<String, String>>(
+            &get_circuit_breaker_key(merchant_connector_id).into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the connector circuit breaker")?;
+
+    Ok(CircuitBreaker::from_fields(
+        &fields,
+        common_utils::date_time::now_unix_timestamp(),
+        state.conf.connector_circuit_breaker.open_duration_in_secs,
+    ))
+}
+
+/// Removes the connectors whose breaker is open from the connectors chosen by routing, as well as
+/// the connectors whose breaker is half open and has already let through as many calls as it
+/// allows. A half open breaker counts each connector it keeps as a call let through.
+///
+/// Breakers which could not be read are considered closed. If the breakers of all the connectors
+/// are open, the connectors are returned as is, so that the payment is still attempted.
+#[instrument(skip_all)]
+pub async fn filter_connectors_with_open_circuit_breaker(
+    state: &SessionState,
+    connectors: Vec<RoutableConnectorChoice>,
+) -> Vec<RoutableConnectorChoice> {
+    if !state.conf.connector_circuit_breaker.enabled {
+        return connectors;
+    }
+
+    let is_open = futures::future::join_all(connectors.iter().map(|choice| async {
+        match choice.merchant_connector_id.as_ref() {
+            Some(merchant_connector_id) => admit_circuit_breaker_call(state, merchant_connector_id)
+                .await
+                .inspect_err(|error| {
+                    logger::error!(?error, "Failed to get the connector circuit breaker")
+                })
+                .is_ok_and(|admitted| !admitted),
+            None => false,
+        }
+    }))
+    .await;
+
+    if is_open.iter().all(|is_open| *is_open) {
+        if !connectors.is_empty() {
+            logger::warn!("Circuit breakers of all the routable connectors are open");
+        }
+        return connectors;
+    }
+
+    connectors
+        .into_iter()
+        .zip(is_open)
+        .filter_map(|(choice, is_open)| {
+            if is_open {
+                logger::info!(
+                    connector = %choice.connector,
+                    merchant_connector_id = ?choice.merchant_connector_id,
+                    "Skipping connector with open circuit breaker"
+                );
+                metrics::CONNECTOR_CIRCUIT_BREAKER_ROUTING_EXCLUSION.add(
+                    1,
+                    router_env::metric_attributes!(("connector", choice.connector.to_string())),
+                );
+            }
+            (!is_open).then_some(choice)
+        })
+        .collect()
+}
+
+#[cfg(feature = "v1")]
+fn get_circuit_breaker_response(
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+    connector_name: String,
+    breaker: CircuitBreaker,
+) -> ConnectorCircuitBreakerResponse {
+    ConnectorCircuitBreakerResponse {
+        merchant_connector_id,
+        connector_name,
+        state: breaker.state,
+        failure_count: breaker.failure_count,
+        half_open_success_count: breaker.half_open_success_count,
+        opened_at: breaker
+            .opened_at
+            .and_then(|opened_at| time::OffsetDateTime::from_unix_timestamp(opened_at).ok())
+            .map(|opened_at| time::PrimitiveDateTime::new(opened_at.date(), opened_at.time())),
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn find_merchant_connector_account(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    let store = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mca = store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            merchant_id,
+            merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &mca)?;
+
+    Ok(mca)
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_circuit_breaker(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<ConnectorCircuitBreakerResponse> {
+    let mca =
+        find_merchant_connector_account(&state, &merchant_id, profile_id, &merchant_connector_id)
+            .await?;
+    let breaker = get_circuit_breaker(&state, &merchant_connector_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_circuit_breaker_response(merchant_connector_id, mca.connector_name, breaker),
+    ))
+}
+
+/// Closes the breaker of the merchant connector account, clearing the recorded outcomes
+#[cfg(feature = "v1")]
+pub async fn reset_circuit_breaker(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<ConnectorCircuitBreakerResponse> {
+    let mca =
+        find_merchant_connector_account(&state, &merchant_id, profile_id, &merchant_connector_id)
+            .await?;
+    let breaker = get_circuit_breaker(&state, &merchant_connector_id).await?;
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    redis_conn
+        .delete_key(&get_circuit_breaker_key(&merchant_connector_id).into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reset the connector circuit breaker")?;
+
+    if breaker.state != ConnectorCircuitBreakerState::Closed {
+        logger::info!(
+            ?merchant_connector_id,
+            previous_state = %breaker.state,
+            "Connector circuit breaker reset"
+        );
+        metrics::CONNECTOR_CIRCUIT_BREAKER_STATE_TRANSITION.add(
+            1,
+            router_env::metric_attributes!(
+                ("connector", mca.connector_name.clone()),
+                ("state", ConnectorCircuitBreakerState::Closed.to_string()),
+            ),
+        );
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        get_circuit_breaker_response(
+            merchant_connector_id,
+            mca.connector_name,
+            CircuitBreaker::default(),
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[cfg(feature = "v1")]
+    fn fields(values: &[(&str, &str)]) -> HashMap<String, String> {
+        values
+            .iter()
+            .map(|(field, value)| (field.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_missing_breaker_is_closed() {
+        let breaker = CircuitBreaker::from_fields(&HashMap::new(), 1_000, 60);
+        assert_eq!(breaker, CircuitBreaker::default());
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_open_breaker_becomes_half_open_after_open_duration() {
+        let fields = fields(&[
+            (STATE_FIELD, "open"),
+            (FAILURES_FIELD, "10"),
+            (OPENED_AT_FIELD, "1000"),
+        ]);
+
+        let breaker = CircuitBreaker::from_fields(&fields, 1_030, 60);
+        assert_eq!(breaker.state, ConnectorCircuitBreakerState::Open);
+
+        let breaker = CircuitBreaker::from_fields(&fields, 1_060, 60);
+        assert_eq!(breaker.state, ConnectorCircuitBreakerState::HalfOpen);
+        assert_eq!(breaker.opened_at, Some(1_000));
+    }
+
+    #[test]
+    fn test_connector_call_outcome() {
+        let response = |status_code| types::Response {
+            headers: None,
+            response: bytes::Bytes::new(),
+            status_code,
+        };
+
+        assert_eq!(
+            ConnectorCallOutcome::from_response(&Ok(Ok(response(200)))),
+            Some(ConnectorCallOutcome::Success)
+        );
+        assert_eq!(
+            ConnectorCallOutcome::from_response(&Ok(Err(response(400)))),
+            Some(ConnectorCallOutcome::Success)
+        );
+        assert_eq!(
+            ConnectorCallOutcome::from_response(&Ok(Err(response(503)))),
+            Some(ConnectorCallOutcome::Failure)
+        );
+        assert_eq!(
+            ConnectorCallOutcome::from_response(&Err(report!(
+                errors::ApiClientError::RequestTimeoutReceived
+            ))),
+            Some(ConnectorCallOutcome::Failure)
+        );
+        assert_eq!(
+            ConnectorCallOutcome::from_response(&Err(report!(
+                errors::ApiClientError::ResponseDecodingFailed
+            ))),
+            None
+        );
+    }
+
+    async fn get_redis_conn(key: &str) -> RedisConnectionPool {
+        let redis_conn = RedisConnectionPool::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        redis_conn.delete_key(&key.into()).await.unwrap();
+        redis_conn
+    }
+
+    fn get_config() -> ConnectorCircuitBreakerSettings {
+        ConnectorCircuitBreakerSettings {
+            enabled: true,
+            failure_threshold: 3,
+            failure_window_in_secs: 60,
+            open_duration_in_secs: 60,
+            half_open_success_threshold: 2,
+            half_open_max_calls: 2,
+        }
+    }
+
+    /// Opens the breaker long enough ago for the open duration to have elapsed
+    async fn open_breaker_after_open_duration(redis_conn: &RedisConnectionPool, key: &str) {
+        redis_conn
+            .set_hash_fields(
+                &key.into(),
+                vec![("state", "open"), ("failures", "3"), ("opened_at", "1")],
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn record_outcome(
+        redis_conn: &RedisConnectionPool,
+        key: &str,
+        outcome: ConnectorCallOutcome,
+    ) -> (ConnectorCircuitBreakerState, ConnectorCircuitBreakerState) {
+        evaluate_record_outcome_script(redis_conn, &key.into(), &get_config(), outcome)
+            .await
+            .unwrap()
+    }
+
+    async fn admit_call(redis_conn: &RedisConnectionPool, key: &str) -> bool {
+        evaluate_admit_call_script(redis_conn, &key.into(), &get_config())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_breaker_opens_at_failure_threshold() {
+        let key = "TEST_CIRCUIT_BREAKER_THRESHOLD";
+        let redis_conn = get_redis_conn(key).await;
+
+        for outcome in [
+            ConnectorCallOutcome::Failure,
+            ConnectorCallOutcome::Success,
+            ConnectorCallOutcome::Failure,
+        ] {
+            assert_eq!(
+                record_outcome(&redis_conn, key, outcome).await,
+                (
+                    ConnectorCircuitBreakerState::Closed,
+                    ConnectorCircuitBreakerState::Closed
+                )
+            );
+            assert!(admit_call(&redis_conn, key).await);
+        }
+
+        assert_eq!(
+            record_outcome(&redis_conn, key, ConnectorCallOutcome::Failure).await,
+            (
+                ConnectorCircuitBreakerState::Closed,
+                ConnectorCircuitBreakerState::Open
+            )
+        );
+        assert!(!admit_call(&redis_conn, key).await);
+
+        // The breaker stays open until the open duration has elapsed
+        assert_eq!(
+            record_outcome(&redis_conn, key, ConnectorCallOutcome::Success).await,
+            (
+                ConnectorCircuitBreakerState::Open,
+                ConnectorCircuitBreakerState::Open
+            )
+        );
+        assert!(!admit_call(&redis_conn, key).await);
+    }
+
+    #[tokio::test]
+    async fn test_half_open_breaker_lets_a_bounded_number_of_calls_through() {
+        let key = "TEST_CIRCUIT_BREAKER_HALF_OPEN_CALLS";
+        let redis_conn = get_redis_conn(key).await;
+        open_breaker_after_open_duration(&redis_conn, key).await;
+
+        assert!(admit_call(&redis_conn, key).await);
+        assert!(admit_call(&redis_conn, key).await);
+        assert!(!admit_call(&redis_conn, key).await);
+
+        // The calls let through are still counted once the breaker is half open
+        assert_eq!(
+            record_outcome(&redis_conn, key, ConnectorCallOutcome::Success).await,
+            (
+                ConnectorCircuitBreakerState::Open,
+                ConnectorCircuitBreakerState::HalfOpen
+            )
+        );
+        assert!(!admit_call(&redis_conn, key).await);
+    }
+
+    #[tokio::test]
+    async fn test_half_open_breaker_closes_after_successful_calls() {
+        let key = "TEST_CIRCUIT_BREAKER_HALF_OPEN_SUCCESS";
+        let redis_conn = get_redis_conn(key).await;
+        open_breaker_after_open_duration(&redis_conn, key).await;
+
+        assert!(admit_call(&redis_conn, key).await);
+        assert!(admit_call(&redis_conn, key).await);
+        assert_eq!(
+            record_outcome(&redis_conn, key, ConnectorCallOutcome::Success).await,
+            (
+                ConnectorCircuitBreakerState::Open,
+                ConnectorCircuitBreakerState::HalfOpen
+            )
+        );
+        assert_eq!(
+            record_outcome(&redis_conn, key, ConnectorCallOutcome::Success).await,
+            (
+                ConnectorCircuitBreakerState::HalfOpen,
+                ConnectorCircuitBreakerState::Closed
+            )
+        );
+
+        // A closed breaker lets every call through and counts the failures afresh
+        for _ in 0..3 {
+            assert!(admit_call(&redis_conn, key).await);
+        }
+        assert_eq!(
+            record_outcome(&redis_conn, key, ConnectorCallOutcome::Failure).await,
+            (
+                ConnectorCircuitBreakerState::Closed,
+                ConnectorCircuitBreakerState::Closed
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_half_open_breaker_opens_again_after_a_failed_call() {
+        let key = "TEST_CIRCUIT_BREAKER_HALF_OPEN_FAILURE";
+        let redis_conn = get_redis_conn(key).await;
+        open_breaker_after_open_duration(&redis_conn, key).await;
+
+        assert!(admit_call(&redis_conn, key).await);
+        assert_eq!(
+            record_outcome(&redis_conn, key, ConnectorCallOutcome::Success).await,
+            (
+                ConnectorCircuitBreakerState::Open,
+                ConnectorCircuitBreakerState::HalfOpen
+            )
+        );
+        assert_eq!(
+            record_outcome(&redis_conn, key, ConnectorCallOutcome::Failure).await,
+            (
+                ConnectorCircuitBreakerState::HalfOpen,
+                ConnectorCircuitBreakerState::Open
+            )
+        );
+
+        // The open duration starts again from the failed call
+        assert!(!admit_call(&redis_conn, key).await);
+    }
+}
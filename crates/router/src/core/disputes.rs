@@ -7,6 +7,10 @@ use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
 use router_env::{instrument, tracing};
 use strum::IntoEnumIterator;
+#[cfg(feature = "v1")]
+pub mod deadline;
+#[cfg(feature = "v1")]
+pub mod evidence;
 pub mod transformers;
 
 use super::{
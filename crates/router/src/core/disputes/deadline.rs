@@ -0,0 +1,697 @@
+use std::str::FromStr;
+
+#[cfg(feature = "email")]
+use common_utils::ext_traits::ValueExt;
+use common_utils::{date_time, id_type, types::MinorUnit};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use super::accept_dispute;
+#[cfg(feature = "email")]
+use crate::services::email::types as email_types;
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        metrics, webhooks,
+    },
+    routes::{metrics as route_metrics, SessionState},
+    types::{
+        api::{self, disputes},
+        domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const DISPUTE_DEADLINE_TASK: &str = "DISPUTE_DEADLINE";
+const DISPUTE_DEADLINE_TAG: &str = "DISPUTE";
+#[cfg(feature = "email")]
+const DISPUTE_DEADLINE_EMAIL_PREFIX: &str = "DISPUTE_DEADLINE_EMAIL";
+/// Number of open disputes of a profile listed at once, when adding their deadline tasks
+const OPEN_DISPUTES_PAGE_SIZE: u32 = 100;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct DisputeDeadlineTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub dispute_id: String,
+}
+
+/// Adds the process tracker task which watches the evidence submission deadline of an open
+/// dispute. The task is scheduled for the first reminder, or immediately if the dispute has to be
+/// accepted as per the dispute policy of the profile.
+#[instrument(skip_all)]
+pub async fn add_dispute_deadline_task(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    dispute: &storage::Dispute,
+) -> RouterResult<()> {
+    let now = date_time::now();
+    let Some(challenge_required_by) = dispute
+        .challenge_required_by
+        .filter(|challenge_required_by| *challenge_required_by > now)
+    else {
+        return Ok(());
+    };
+    if dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened {
+        return Ok(());
+    }
+
+    let schedule_time =
+        if is_eligible_for_auto_accept(business_profile.dispute_policy.as_ref(), dispute) {
+            now
+        } else {
+            get_first_reminder_time(
+                &get_reminder_offsets(state, business_profile),
+                challenge_required_by,
+                now,
+            )
+        };
+
+    let runner = storage::ProcessTrackerRunner::DisputeDeadlineWorkflow;
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        DISPUTE_DEADLINE_TASK,
+        &dispute.dispute_id,
+        &dispute.merchant_id,
+    );
+    let tracking_data = DisputeDeadlineTrackingData {
+        merchant_id: dispute.merchant_id.clone(),
+        profile_id: business_profile.get_id().clone(),
+        dispute_id: dispute.dispute_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id.clone(),
+        DISPUTE_DEADLINE_TASK,
+        runner,
+        [DISPUTE_DEADLINE_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct dispute deadline process tracker task")?;
+
+    let attributes = router_env::metric_attributes!(("flow", "DisputeDeadline"));
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            route_metrics::TASKS_ADDED_COUNT.add(1, attributes);
+            Ok(())
+        }
+        // The task reads the latest deadline of the dispute every time it runs, it only has to be
+        // brought forward when the dispute has to be looked at earlier than it is scheduled for
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            logger::debug!(
+                dispute_id = %dispute.dispute_id,
+                "Dispute deadline task already exists"
+            );
+            reschedule_dispute_deadline_task(state, &process_tracker_id, schedule_time).await
+        }
+        Err(error) => {
+            route_metrics::TASK_ADDITION_FAILURES_COUNT.add(1, attributes);
+            Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to add dispute deadline process tracker task")
+        }
+    }
+}
+
+async fn reschedule_dispute_deadline_task(
+    state: &SessionState,
+    process_tracker_id: &str,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let Some(process) = state
+        .store
+        .find_process_by_id(process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find dispute deadline process tracker task")?
+    else {
+        return Ok(());
+    };
+
+    // A task which finished earlier is started again, since the dispute is open again
+    let is_rescheduled = match process.status {
+        storage_enums::ProcessTrackerStatus::Finish => true,
+        storage_enums::ProcessTrackerStatus::ProcessStarted => false,
+        _ => process.schedule_time.map_or(true, |process_schedule_time| {
+            process_schedule_time > schedule_time
+        }),
+    };
+    if is_rescheduled {
+        state
+            .store
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule dispute deadline process tracker task")?;
+    }
+
+    Ok(())
+}
+
+/// Adds the deadline tasks of the open disputes of the profile, which are otherwise only added
+/// when a dispute webhook is received. Used when the dispute policy of the profile is updated, so
+/// that the disputes opened earlier follow the updated policy.
+#[instrument(skip_all)]
+pub async fn add_dispute_deadline_tasks_for_open_disputes(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+) -> RouterResult<()> {
+    let page_size = usize::try_from(OPEN_DISPUTES_PAGE_SIZE).unwrap_or(usize::MAX);
+    let mut offset = 0;
+
+    loop {
+        let disputes = state
+            .store
+            .find_disputes_by_constraints(
+                &business_profile.merchant_id,
+                &hyperswitch_domain_models::disputes::DisputeListConstraints {
+                    dispute_id: None,
+                    payment_id: None,
+                    limit: Some(OPEN_DISPUTES_PAGE_SIZE),
+                    offset: Some(offset),
+                    profile_id: Some(vec![business_profile.get_id().clone()]),
+                    dispute_status: Some(vec![storage_enums::DisputeStatus::DisputeOpened]),
+                    dispute_stage: None,
+                    reason: None,
+                    connector: None,
+                    merchant_connector_id: None,
+                    currency: None,
+                    time_range: None,
+                    challenge_required_by_range: Some(common_utils::types::TimeRange {
+                        start_time: date_time::now(),
+                        end_time: None,
+                    }),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the open disputes of the profile")?;
+
+        for dispute in &disputes {
+            add_dispute_deadline_task(state, business_profile, dispute)
+                .await
+                .inspect_err(|error| {
+                    logger::error!(
+                        ?error,
+                        dispute_id = %dispute.dispute_id,
+                        "Failed to add dispute deadline task"
+                    );
+                })
+                .ok();
+        }
+
+        if disputes.len() < page_size {
+            return Ok(());
+        }
+        offset = offset.saturating_add(OPEN_DISPUTES_PAGE_SIZE);
+    }
+}
+
+/// Applies the dispute policy of the profile to the dispute, or sends the reminder due for its
+/// evidence submission deadline. Returns the time at which the dispute has to be looked at again,
+/// if any.
+///
+/// When the dispute cannot be accepted as per the dispute policy, the reminders are sent instead,
+/// and accepting the dispute is attempted again the next time the dispute is looked at.
+#[instrument(skip_all)]
+pub async fn process_dispute_deadline(
+    state: &SessionState,
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    tracking_data: &DisputeDeadlineTrackingData,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let dispute = state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(
+            &tracking_data.merchant_id,
+            &tracking_data.dispute_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: tracking_data.dispute_id.clone(),
+        })?;
+
+    let now = date_time::now();
+    // Disputes which were challenged, accepted or closed in the meantime are no longer tracked
+    let Some(challenge_required_by) =
+        dispute
+            .challenge_required_by
+            .filter(|challenge_required_by| {
+                dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened
+                    && *challenge_required_by > now
+            })
+    else {
+        return Ok(None);
+    };
+
+    if dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+        && is_eligible_for_auto_accept(business_profile.dispute_policy.as_ref(), &dispute)
+    {
+        match Box::pin(accept_dispute(
+            state.clone(),
+            merchant_context.clone(),
+            Some(tracking_data.profile_id.clone()),
+            disputes::DisputeId {
+                dispute_id: dispute.dispute_id.clone(),
+            },
+        ))
+        .await
+        {
+            Ok(_) => {
+                metrics::DISPUTE_AUTO_ACCEPT_METRIC.add(1, &[]);
+                return Ok(None);
+            }
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    dispute_id = %dispute.dispute_id,
+                    "Failed to accept dispute as per the dispute policy, sending reminders instead"
+                );
+            }
+        }
+    }
+
+    let reminder_offsets = get_reminder_offsets(state, &business_profile);
+    let time_remaining = challenge_required_by - now;
+
+    if let Some(offset) = get_due_reminder_offset(&reminder_offsets, time_remaining) {
+        send_dispute_deadline_reminder(
+            state,
+            merchant_context,
+            business_profile,
+            &dispute,
+            offset,
+            time_remaining,
+        )
+        .await?;
+    }
+
+    Ok(get_next_reminder_time(
+        &reminder_offsets,
+        challenge_required_by,
+        time_remaining,
+    ))
+}
+
+async fn send_dispute_deadline_reminder(
+    state: &SessionState,
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    dispute: &storage::Dispute,
+    offset: u16,
+    time_remaining: time::Duration,
+) -> RouterResult<()> {
+    let event_type = storage_enums::EventType::DisputeDeadlineApproaching;
+    // A reminder is raised for every offset, the offset keeps the events of the dispute apart
+    let idempotent_event_id = format!(
+        "{}_{offset}h",
+        webhooks::utils::get_idempotent_event_id(
+            &dispute.dispute_id,
+            event_type,
+            storage_enums::WebhookDeliveryAttempt::InitialAttempt,
+        )
+    );
+    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(dispute.clone());
+
+    #[cfg(feature = "email")]
+    let merchant_account = merchant_context.get_merchant_account().clone();
+
+    Box::pin(
+        webhooks::create_event_and_trigger_outgoing_webhook_with_idempotent_event_id(
+            state.clone(),
+            merchant_context,
+            business_profile,
+            event_type,
+            storage_enums::EventClass::Disputes,
+            dispute.dispute_id.clone(),
+            storage_enums::EventObjectType::DisputeDetails,
+            api::OutgoingWebhookContent::DisputeDetails(Box::new(dispute_response)),
+            Some(dispute.created_at),
+            idempotent_event_id,
+        ),
+    )
+    .await?;
+    metrics::DISPUTE_DEADLINE_REMINDER_METRIC.add(1, &[]);
+
+    // The webhook has been delivered at this point, a failure to send the email must not raise
+    // the reminder again
+    #[cfg(feature = "email")]
+    send_dispute_deadline_email(state, &merchant_account, dispute, offset, time_remaining)
+        .await
+        .inspect_err(|error| {
+            logger::error!(?error, "Failed to send dispute deadline reminder email");
+        })
+        .ok();
+
+    #[cfg(not(feature = "email"))]
+    logger::debug!(
+        hours_remaining = time_remaining.whole_hours(),
+        "Email feature is disabled, dispute deadline reminder email not sent"
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "email")]
+async fn send_dispute_deadline_email(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    dispute: &storage::Dispute,
+    offset: u16,
+    time_remaining: time::Duration,
+) -> RouterResult<()> {
+    let Some(email_id) = merchant_account
+        .merchant_details
+        .clone()
+        .map(|merchant_details| {
+            merchant_details.parse_value::<api::MerchantDetails>("MerchantDetails")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse merchant details")?
+        .and_then(|merchant_details| merchant_details.primary_email)
+    else {
+        logger::debug!("Merchant has no primary email, dispute deadline reminder email not sent");
+        return Ok(());
+    };
+
+    let challenge_required_by = dispute
+        .challenge_required_by
+        .map(|challenge_required_by| {
+            challenge_required_by
+                .assume_utc()
+                .format(&time::format_description::well_known::Rfc3339)
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to format dispute evidence submission deadline")?
+        .unwrap_or_default();
+
+    let email_contents = email_types::DisputeDeadlineReminder {
+        recipient_email: domain::UserEmail::from_pii_email(email_id)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert recipient's email to UserEmail")?,
+        subject: crate::consts::EMAIL_SUBJECT_DISPUTE_DEADLINE,
+        dispute_id: dispute.dispute_id.clone(),
+        payment_id: dispute.payment_id.get_string_repr().to_owned(),
+        connector: dispute.connector.clone(),
+        amount: dispute.amount.clone(),
+        currency: dispute.currency.clone(),
+        challenge_required_by,
+        hours_remaining: time_remaining.whole_hours(),
+    };
+
+    // The task runs again when it fails after sending the email, the email of a reminder is
+    // only sent once
+    if !mark_dispute_deadline_email_as_sent(state, dispute, offset).await {
+        logger::debug!("Dispute deadline reminder email already sent");
+        return Ok(());
+    }
+
+    state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            crate::utils::user::get_base_url(state),
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send dispute deadline reminder email")?;
+
+    Ok(())
+}
+
+/// Returns whether the email of the reminder can be sent, the email is sent anyway when it cannot
+/// be known whether it was sent already
+#[cfg(feature = "email")]
+async fn mark_dispute_deadline_email_as_sent(
+    state: &SessionState,
+    dispute: &storage::Dispute,
+    offset: u16,
+) -> bool {
+    let key = format!(
+        "{DISPUTE_DEADLINE_EMAIL_PREFIX}_{}_{}_{offset}h",
+        dispute.merchant_id.get_string_repr(),
+        dispute.dispute_id
+    );
+    // The reminder of an offset is not due again once the offset has passed
+    let expiry = time::Duration::hours(i64::from(offset)).whole_seconds();
+
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .set_key_if_not_exists_with_expiry(&key.as_str().into(), "true", Some(expiry))
+                .await
+        }
+        Err(error) => Err(error),
+    };
+
+    match result {
+        Ok(redis_interface::SetnxReply::KeySet) => true,
+        Ok(redis_interface::SetnxReply::KeyNotSet) => false,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to mark dispute deadline reminder email as sent"
+            );
+            true
+        }
+    }
+}
+
+/// Time at which the first reminder is due, or the current time if it is already due
+fn get_first_reminder_time(
+    reminder_offsets: &[u16],
+    challenge_required_by: PrimitiveDateTime,
+    now: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    reminder_offsets
+        .iter()
+        .max()
+        .map(|offset| {
+            challenge_required_by.saturating_sub(time::Duration::hours(i64::from(*offset)))
+        })
+        .map_or(now, |first_reminder_time| first_reminder_time.max(now))
+}
+
+/// The reminder of the offset closest to the deadline which has already been reached is due,
+/// reminders of the earlier offsets which were missed are not sent anymore
+fn get_due_reminder_offset(
+    reminder_offsets: &[u16],
+    time_remaining: time::Duration,
+) -> Option<u16> {
+    reminder_offsets
+        .iter()
+        .filter(|offset| time::Duration::hours(i64::from(**offset)) >= time_remaining)
+        .min()
+        .copied()
+}
+
+fn get_next_reminder_time(
+    reminder_offsets: &[u16],
+    challenge_required_by: PrimitiveDateTime,
+    time_remaining: time::Duration,
+) -> Option<PrimitiveDateTime> {
+    reminder_offsets
+        .iter()
+        .filter(|offset| time::Duration::hours(i64::from(**offset)) < time_remaining)
+        .max()
+        .map(|offset| {
+            challenge_required_by.saturating_sub(time::Duration::hours(i64::from(*offset)))
+        })
+}
+
+/// Offsets (in hours) before the deadline at which reminders are sent for the disputes of the
+/// profile, the dispute policy of the profile takes precedence over the application default
+fn get_reminder_offsets(state: &SessionState, business_profile: &domain::Profile) -> Vec<u16> {
+    business_profile
+        .dispute_policy
+        .as_ref()
+        .and_then(|dispute_policy| dispute_policy.reminder_offsets_in_hours.clone())
+        .filter(|reminder_offsets| !reminder_offsets.is_empty())
+        .unwrap_or_else(|| {
+            state
+                .conf
+                .dispute_deadline
+                .reminder_offsets_in_hours
+                .clone()
+        })
+}
+
+fn is_eligible_for_auto_accept(
+    dispute_policy: Option<&common_types::disputes::DisputePolicy>,
+    dispute: &storage::Dispute,
+) -> bool {
+    let currency = dispute
+        .dispute_currency
+        .or_else(|| storage_enums::Currency::from_str(&dispute.currency).ok());
+
+    dispute_policy
+        .zip(currency)
+        .is_some_and(|(dispute_policy, currency)| {
+            dispute_policy
+                .is_eligible_for_auto_accept(MinorUnit::new(dispute.dispute_amount), currency)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::borrow::Cow;
+
+    use common_types::disputes::{DisputeAutoAcceptThreshold, DisputePolicy};
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn dispute(amount: i64, currency: &str) -> storage::Dispute {
+        let now = date_time::now();
+        storage::Dispute {
+            dispute_id: "dp_1".to_string(),
+            amount: amount.to_string(),
+            currency: currency.to_string(),
+            dispute_stage: storage_enums::DisputeStage::Dispute,
+            dispute_status: storage_enums::DisputeStatus::DisputeOpened,
+            payment_id: id_type::PaymentId::try_from(Cow::from("pay_1"))
+                .expect("Invalid payment id"),
+            attempt_id: "pay_1_1".to_string(),
+            merchant_id: id_type::MerchantId::try_from(Cow::from("merchant_1"))
+                .expect("Invalid merchant id"),
+            connector_status: "needs_response".to_string(),
+            connector_dispute_id: "dispute_1".to_string(),
+            connector_reason: None,
+            connector_reason_code: None,
+            challenge_required_by: None,
+            connector_created_at: None,
+            connector_updated_at: None,
+            created_at: now,
+            modified_at: now,
+            connector: "stripe".to_string(),
+            evidence: masking::Secret::new(serde_json::Value::Null),
+            profile_id: None,
+            merchant_connector_id: None,
+            dispute_amount: amount,
+            organization_id: id_type::OrganizationId::try_from(Cow::from("org_1"))
+                .expect("Invalid organization id"),
+            dispute_currency: None,
+        }
+    }
+
+    fn dispute_policy(thresholds: &[(storage_enums::Currency, i64)]) -> DisputePolicy {
+        DisputePolicy {
+            auto_accept_thresholds: Some(
+                thresholds
+                    .iter()
+                    .map(|(currency, amount)| DisputeAutoAcceptThreshold {
+                        currency: *currency,
+                        amount: MinorUnit::new(*amount),
+                    })
+                    .collect(),
+            ),
+            reminder_offsets_in_hours: None,
+        }
+    }
+
+    #[test]
+    fn test_is_eligible_for_auto_accept() {
+        let policy = dispute_policy(&[(storage_enums::Currency::USD, 1000)]);
+
+        assert!(is_eligible_for_auto_accept(
+            Some(&policy),
+            &dispute(999, "USD")
+        ));
+        // The threshold is exclusive
+        assert!(!is_eligible_for_auto_accept(
+            Some(&policy),
+            &dispute(1000, "USD")
+        ));
+        assert!(!is_eligible_for_auto_accept(
+            Some(&policy),
+            &dispute(10, "EUR")
+        ));
+        assert!(!is_eligible_for_auto_accept(None, &dispute(10, "USD")));
+        // Disputes whose currency cannot be known are never accepted
+        assert!(!is_eligible_for_auto_accept(
+            Some(&policy),
+            &dispute(10, "not a currency")
+        ));
+        // The currency of the dispute takes precedence over the currency string of the connector
+        assert!(is_eligible_for_auto_accept(
+            Some(&policy),
+            &storage::Dispute {
+                dispute_currency: Some(storage_enums::Currency::USD),
+                ..dispute(10, "usd")
+            }
+        ));
+    }
+
+    #[test]
+    fn test_first_reminder_time() {
+        let challenge_required_by = datetime!(2025-07-10 12:00);
+
+        assert_eq!(
+            get_first_reminder_time(&[24, 72], challenge_required_by, datetime!(2025-07-01 0:00)),
+            datetime!(2025-07-07 12:00)
+        );
+        // A reminder which is already due is sent right away
+        assert_eq!(
+            get_first_reminder_time(&[24, 72], challenge_required_by, datetime!(2025-07-09 0:00)),
+            datetime!(2025-07-09 0:00)
+        );
+        assert_eq!(
+            get_first_reminder_time(&[], challenge_required_by, datetime!(2025-07-01 0:00)),
+            datetime!(2025-07-01 0:00)
+        );
+    }
+
+    #[test]
+    fn test_due_reminder_offset() {
+        let offsets = [72, 24, 6];
+
+        assert_eq!(
+            get_due_reminder_offset(&offsets, time::Duration::hours(100)),
+            None
+        );
+        assert_eq!(
+            get_due_reminder_offset(&offsets, time::Duration::hours(72)),
+            Some(72)
+        );
+        // Only the reminder closest to the deadline is sent when several were missed
+        assert_eq!(
+            get_due_reminder_offset(&offsets, time::Duration::hours(5)),
+            Some(6)
+        );
+        assert_eq!(
+            get_due_reminder_offset(&offsets, time::Duration::minutes(24 * 60 - 1)),
+            Some(24)
+        );
+    }
+
+    #[test]
+    fn test_next_reminder_time() {
+        let offsets = [72, 24, 6];
+        let challenge_required_by = datetime!(2025-07-10 12:00);
+
+        assert_eq!(
+            get_next_reminder_time(&offsets, challenge_required_by, time::Duration::hours(72)),
+            Some(datetime!(2025-07-09 12:00))
+        );
+        assert_eq!(
+            get_next_reminder_time(&offsets, challenge_required_by, time::Duration::hours(7)),
+            Some(datetime!(2025-07-10 6:00))
+        );
+        assert_eq!(
+            get_next_reminder_time(&offsets, challenge_required_by, time::Duration::hours(6)),
+            None
+        );
+    }
+}
@@ -0,0 +1,985 @@
+use api_models::{disputes as dispute_models, payments as payment_models};
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    payments::{payment_attempt::PaymentAttempt, PaymentIntent},
+    router_request_types::fraud_check::FulfillmentDetails,
+};
+use masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        files,
+        payments::helpers as payment_helpers,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const SUMMARY_FILE_NAME: &str = "evidence_summary.txt";
+
+/// Data held for the payment of a dispute, which is used to pre-fill its evidence
+struct EvidenceSources {
+    payment_intent: PaymentIntent,
+    payment_attempts: Vec<PaymentAttempt>,
+    disputed_attempt: PaymentAttempt,
+    customer: Option<domain::Customer>,
+    billing_address: Option<payment_models::Address>,
+    shipping_address: Option<payment_models::Address>,
+    authentication: Option<storage::Authentication>,
+    fraud_check: Option<storage::FraudCheck>,
+}
+
+impl EvidenceSources {
+    fn get_card_info(&self) -> Option<payment_models::AdditionalCardInfo> {
+        self.disputed_attempt
+            .payment_method_data
+            .clone()
+            .and_then(|payment_method_data| {
+                payment_method_data
+                    .parse_value::<payment_models::AdditionalPaymentData>("AdditionalPaymentData")
+                    .inspect_err(|error| {
+                        logger::warn!(?error, "Failed to parse additional payment data");
+                    })
+                    .ok()
+            })
+            .and_then(|additional_payment_data| match additional_payment_data {
+                payment_models::AdditionalPaymentData::Card(card_info) => Some(*card_info),
+                _ => None,
+            })
+    }
+
+    fn get_customer_ip(&self) -> Option<std::net::IpAddr> {
+        self.disputed_attempt
+            .browser_info
+            .clone()
+            .and_then(|browser_info| {
+                browser_info
+                    .parse_value::<common_utils::types::BrowserInformation>("BrowserInformation")
+                    .inspect_err(|error| {
+                        logger::warn!(?error, "Failed to parse browser information");
+                    })
+                    .ok()
+            })
+            .and_then(|browser_info| browser_info.ip_address)
+    }
+
+    fn get_fulfillment_details(&self) -> Option<FulfillmentDetails> {
+        self.fraud_check
+            .as_ref()
+            .and_then(|fraud_check| fraud_check.fulfillment_details.clone())
+            .and_then(|fulfillment_details| {
+                fulfillment_details
+                    .parse_value::<FulfillmentDetails>("FulfillmentDetails")
+                    .inspect_err(|error| {
+                        logger::warn!(?error, "Failed to parse fulfillment details");
+                    })
+                    .ok()
+            })
+    }
+
+    fn get_customer_name(&self) -> Option<String> {
+        self.customer
+            .as_ref()
+            .and_then(|customer| customer.name.clone())
+            .map(|name| name.into_inner().expose())
+            .or_else(|| {
+                self.billing_address
+                    .as_ref()
+                    .and_then(|address| address.address.as_ref())
+                    .and_then(|address| address.get_optional_full_name())
+                    .map(ExposeInterface::expose)
+            })
+    }
+
+    fn get_customer_email(&self) -> Option<String> {
+        self.customer
+            .as_ref()
+            .and_then(|customer| customer.email.clone())
+            .map(|email| common_utils::pii::Email::from(email).peek().clone())
+            .or_else(|| {
+                self.billing_address
+                    .as_ref()
+                    .and_then(|address| address.email.as_ref())
+                    .map(|email| email.peek().clone())
+            })
+    }
+
+    fn get_product_description(&self) -> Option<String> {
+        let order_details = self
+            .payment_intent
+            .order_details
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|order_details| {
+                order_details
+                    .expose()
+                    .parse_value::<payment_models::OrderDetailsWithAmount>("OrderDetailsWithAmount")
+                    .ok()
+            })
+            .map(|order_details| {
+                format!(
+                    "{} x {}",
+                    order_details.quantity, order_details.product_name
+                )
+            })
+            .collect::<Vec<_>>();
+
+        self.payment_intent
+            .description
+            .clone()
+            .or((!order_details.is_empty()).then(|| order_details.join(", ")))
+    }
+}
+
+/// Pre-fills the evidence of an open dispute from the payment, authentication, customer and
+/// fulfillment data held for it, along with a summary of that data. The evidence is returned for
+/// the merchant to review and submit, it is not submitted to the connector.
+#[instrument(skip_all)]
+pub async fn build_evidence_package(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: dispute_models::DisputeEvidencePackageRequest,
+) -> RouterResponse<dispute_models::DisputeEvidencePackageResponse> {
+    let dispute = state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(
+            merchant_context.get_merchant_account().get_id(),
+            &req.dispute_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: req.dispute_id.clone(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &dispute)?;
+    common_utils::fp_utils::when(
+        !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+            && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened),
+        || {
+            Err(errors::ApiErrorResponse::DisputeStatusValidationFailed {
+                reason: format!(
+                "Evidence package cannot be built because the dispute is in {} stage and has {} status",
+                dispute.dispute_stage, dispute.dispute_status
+            ),
+            })
+        },
+    )?;
+
+    let sources = get_evidence_sources(&state, &merchant_context, &dispute).await?;
+    let summary = render_evidence_summary(&dispute, &sources);
+
+    // Evidence files which are already attached to the dispute are retained
+    let dispute_evidence: api::DisputeEvidence = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+
+    let summary_file_id = if req.attach_summary {
+        let previous_summary_file_id = get_replaceable_evidence_summary(&dispute_evidence)?;
+        let summary_file_id = attach_evidence_summary(
+            &state,
+            merchant_context.clone(),
+            &dispute,
+            &dispute_evidence,
+            &summary,
+        )
+        .await?;
+        // The summary attached by an earlier call is replaced by the new one
+        if let Some(previous_summary_file_id) = previous_summary_file_id {
+            delete_previous_evidence_summary(&state, merchant_context, previous_summary_file_id)
+                .await;
+        }
+        Some(summary_file_id)
+    } else {
+        None
+    };
+
+    let fulfillment_details = sources.get_fulfillment_details();
+
+    let evidence = dispute_models::SubmitEvidenceRequest {
+        dispute_id: dispute.dispute_id.clone(),
+        billing_address: sources
+            .billing_address
+            .as_ref()
+            .and_then(|address| address.address.as_ref())
+            .map(format_address),
+        customer_email_address: sources.get_customer_email(),
+        customer_name: sources.get_customer_name(),
+        customer_purchase_ip: sources.get_customer_ip().map(|ip| ip.to_string()),
+        product_description: sources.get_product_description(),
+        shipping_address: sources
+            .shipping_address
+            .as_ref()
+            .and_then(|address| address.address.as_ref())
+            .map(format_address),
+        shipping_carrier: fulfillment_details
+            .as_ref()
+            .and_then(|fulfillment_details| {
+                fulfillment_details
+                    .carrier
+                    .clone()
+                    .or_else(|| fulfillment_details.tracking_company.clone())
+            }),
+        shipping_date: fulfillment_details
+            .as_ref()
+            .and_then(|fulfillment_details| fulfillment_details.shipped_at.clone()),
+        shipping_tracking_number: fulfillment_details
+            .as_ref()
+            .and_then(|fulfillment_details| fulfillment_details.tracking_numbers.as_ref())
+            .filter(|tracking_numbers| !tracking_numbers.is_empty())
+            .map(|tracking_numbers| tracking_numbers.join(", ")),
+        cancellation_policy: dispute_evidence.cancellation_policy,
+        customer_communication: dispute_evidence.customer_communication,
+        customer_signature: dispute_evidence.customer_signature,
+        receipt: dispute_evidence.receipt,
+        refund_policy: dispute_evidence.refund_policy,
+        service_documentation: dispute_evidence.service_documentation,
+        shipping_documentation: dispute_evidence.shipping_documentation,
+        invoice_showing_distinct_transactions: dispute_evidence
+            .invoice_showing_distinct_transactions,
+        recurring_transaction_agreement: dispute_evidence.recurring_transaction_agreement,
+        uncategorized_file: summary_file_id
+            .clone()
+            .or(dispute_evidence.uncategorized_file),
+        uncategorized_text: Some(summary.clone()),
+        ..Default::default()
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        dispute_models::DisputeEvidencePackageResponse {
+            evidence,
+            summary,
+            summary_file_id,
+        },
+    ))
+}
+
+async fn get_evidence_sources(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    dispute: &storage::Dispute,
+) -> RouterResult<EvidenceSources> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key_store = merchant_context.get_merchant_key_store();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &dispute.payment_id,
+            merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let mut payment_attempts = db
+        .find_attempts_by_merchant_id_payment_id(merchant_id, &dispute.payment_id, storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    payment_attempts.sort_by_key(|payment_attempt| payment_attempt.created_at);
+    let disputed_attempt = payment_attempts
+        .iter()
+        .find(|payment_attempt| payment_attempt.attempt_id == dispute.attempt_id)
+        .cloned()
+        .ok_or(errors::ApiErrorResponse::PaymentNotFound)
+        .attach_printable("Disputed payment attempt not found")?;
+
+    let customer = match payment_intent.customer_id.as_ref() {
+        Some(customer_id) => db
+            .find_customer_optional_by_customer_id_merchant_id(
+                key_manager_state,
+                customer_id,
+                merchant_id,
+                key_store,
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch customer of the disputed payment")?,
+        None => None,
+    };
+
+    let billing_address = payment_helpers::get_address_by_id(
+        state,
+        payment_intent
+            .billing_address_id
+            .clone()
+            .or(disputed_attempt.payment_method_billing_address_id.clone()),
+        key_store,
+        &payment_intent.payment_id,
+        merchant_id,
+        storage_scheme,
+    )
+    .await?
+    .map(payment_models::Address::foreign_from);
+    let shipping_address = payment_helpers::get_address_by_id(
+        state,
+        payment_intent.shipping_address_id.clone(),
+        key_store,
+        &payment_intent.payment_id,
+        merchant_id,
+        storage_scheme,
+    )
+    .await?
+    .map(payment_models::Address::foreign_from);
+
+    // Authentication and fraud check records complement the evidence, the evidence can still be
+    // built without them
+    let authentication = match disputed_attempt.authentication_id.clone() {
+        Some(authentication_id) => db
+            .find_authentication_by_merchant_id_authentication_id(merchant_id, authentication_id)
+            .await
+            .inspect_err(|error| {
+                logger::warn!(
+                    ?error,
+                    "Failed to fetch authentication of the disputed payment"
+                );
+            })
+            .ok(),
+        None => None,
+    };
+    let fraud_check = db
+        .find_fraud_check_by_payment_id_if_present(dispute.payment_id.clone(), merchant_id.clone())
+        .await
+        .inspect_err(|error| {
+            logger::warn!(
+                ?error,
+                "Failed to fetch fraud check of the disputed payment"
+            );
+        })
+        .ok()
+        .flatten();
+
+    Ok(EvidenceSources {
+        payment_intent,
+        payment_attempts,
+        disputed_attempt,
+        customer,
+        billing_address,
+        shipping_address,
+        authentication,
+        fraud_check,
+    })
+}
+
+/// The evidence summary attached by an earlier evidence package, which is replaced by the new
+/// summary. The summary is only attached if the uncategorized file of the dispute is free, or
+/// holds such a summary, so that a file attached by the merchant is never replaced.
+fn get_replaceable_evidence_summary(
+    dispute_evidence: &api::DisputeEvidence,
+) -> RouterResult<Option<String>> {
+    match &dispute_evidence.uncategorized_file {
+        None => Ok(None),
+        Some(file_id) if dispute_evidence.evidence_summary_file.as_ref() == Some(file_id) => {
+            Ok(Some(file_id.clone()))
+        }
+        Some(_) => Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The evidence summary cannot be attached as an uncategorized file is already \
+                      attached to the dispute"
+                .to_string(),
+        }
+        .into()),
+    }
+}
+
+/// Uploads the summary as a text file and attaches it to the dispute as the uncategorized file,
+/// marking it as the evidence summary. Returns the id of the file.
+async fn attach_evidence_summary(
+    state: &SessionState,
+    merchant_context: domain::MerchantContext,
+    dispute: &storage::Dispute,
+    dispute_evidence: &api::DisputeEvidence,
+    summary: &str,
+) -> RouterResult<String> {
+    let file = summary.as_bytes().to_vec();
+    let file_size = i32::try_from(file.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Evidence summary is too large to be uploaded")?;
+    let create_file_request = api::CreateFileRequest {
+        file,
+        file_name: Some(SUMMARY_FILE_NAME.to_string()),
+        file_size,
+        file_type: mime::TEXT_PLAIN,
+        purpose: api::FilePurpose::DisputeEvidence,
+        dispute_id: Some(dispute.dispute_id.clone()),
+    };
+
+    let file_id = match Box::pin(files::files_create_core(
+        state.clone(),
+        merchant_context,
+        create_file_request,
+    ))
+    .await?
+    {
+        services::ApplicationResponse::Json(create_file_response) => create_file_response.file_id,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response received from files create core")?,
+    };
+
+    let updated_dispute_evidence = api::DisputeEvidence {
+        uncategorized_file: Some(file_id.clone()),
+        evidence_summary_file: Some(file_id.clone()),
+        ..dispute_evidence.clone()
+    };
+    let update_dispute = storage::DisputeUpdate::EvidenceUpdate {
+        evidence: updated_dispute_evidence
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error while encoding dispute evidence")?
+            .into(),
+    };
+    state
+        .store
+        .update_dispute(dispute.clone(), update_dispute)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute.dispute_id.clone(),
+        })
+        .attach_printable_lazy(|| {
+            format!(
+                "Unable to update dispute with dispute_id: {}",
+                dispute.dispute_id
+            )
+        })?;
+
+    Ok(file_id)
+}
+
+/// Deletes the evidence summary attached by an earlier evidence package. The new summary is
+/// already attached to the dispute, so a failure to delete the previous one is only logged.
+async fn delete_previous_evidence_summary(
+    state: &SessionState,
+    merchant_context: domain::MerchantContext,
+    file_id: String,
+) {
+    Box::pin(files::files_delete_core(
+        state.clone(),
+        merchant_context,
+        api::FileId { file_id },
+    ))
+    .await
+    .inspect_err(|error| {
+        logger::warn!(?error, "Failed to delete the previous evidence summary");
+    })
+    .ok();
+}
+
+fn render_evidence_summary(dispute: &storage::Dispute, sources: &EvidenceSources) -> String {
+    let mut lines = vec![
+        format!("Dispute {}", dispute.dispute_id),
+        format!("Connector: {}", dispute.connector),
+        format!("Amount: {} {}", dispute.amount, dispute.currency),
+        format!(
+            "Stage: {}, Status: {}",
+            dispute.dispute_stage, dispute.dispute_status
+        ),
+    ];
+    lines.extend(
+        dispute
+            .connector_reason
+            .as_ref()
+            .map(|reason| format!("Reason: {reason}")),
+    );
+    lines.extend(
+        dispute
+            .challenge_required_by
+            .map(|challenge_required_by| format!("Evidence due by: {challenge_required_by}")),
+    );
+
+    let payment_intent = &sources.payment_intent;
+    lines.push(String::new());
+    lines.push(format!(
+        "Payment {}",
+        payment_intent.payment_id.get_string_repr()
+    ));
+    lines.push(format!("Created at: {}", payment_intent.created_at));
+    lines.push(format!(
+        "Amount: {} {}",
+        payment_intent.amount.get_amount_as_i64(),
+        payment_intent
+            .currency
+            .map(|currency| currency.to_string())
+            .unwrap_or_default()
+    ));
+    lines.push(format!("Status: {}", payment_intent.status));
+    lines.extend(
+        sources
+            .get_product_description()
+            .map(|product_description| format!("Description: {product_description}")),
+    );
+
+    lines.push(String::new());
+    lines.push("Payment timeline".to_string());
+    lines.extend(sources.payment_attempts.iter().map(|payment_attempt| {
+        let mut line = format!(
+            "{}: attempt {} {} through {}, amount {}",
+            payment_attempt.created_at,
+            payment_attempt.attempt_id,
+            payment_attempt.status,
+            payment_attempt
+                .connector
+                .as_deref()
+                .unwrap_or("unknown connector"),
+            payment_attempt
+                .net_amount
+                .get_total_amount()
+                .get_amount_as_i64(),
+        );
+        if let Some(payment_method_type) = payment_attempt.payment_method_type {
+            line.push_str(&format!(", payment method type {payment_method_type}"));
+        }
+        if let Some(error_message) = payment_attempt.error_message.as_ref() {
+            line.push_str(&format!(", error: {error_message}"));
+        }
+        if payment_attempt.attempt_id == sources.disputed_attempt.attempt_id {
+            line.push_str(" (disputed)");
+        }
+        line
+    }));
+
+    lines.push(String::new());
+    lines.push("Authentication".to_string());
+    lines.extend(
+        sources
+            .disputed_attempt
+            .authentication_type
+            .map(|authentication_type| format!("Authentication type: {authentication_type}")),
+    );
+    if let Some(authentication) = sources.authentication.as_ref() {
+        lines.push(format!(
+            "3DS authentication through {}: {}",
+            authentication.authentication_connector, authentication.authentication_status
+        ));
+        lines.extend(
+            authentication
+                .trans_status
+                .as_ref()
+                .map(|trans_status| format!("Transaction status: {trans_status}")),
+        );
+        lines.extend(authentication.eci.as_ref().map(|eci| format!("ECI: {eci}")));
+        lines.extend(
+            authentication
+                .message_version
+                .as_ref()
+                .map(|message_version| format!("3DS version: {message_version}")),
+        );
+        lines.extend(
+            authentication
+                .ds_trans_id
+                .as_ref()
+                .map(|ds_trans_id| format!("Directory server transaction id: {ds_trans_id}")),
+        );
+    }
+    if let Some(card_info) = sources.get_card_info() {
+        lines.extend(
+            card_info
+                .last4
+                .map(|last4| format!("Card ending in: {last4}")),
+        );
+        lines.extend(
+            card_info
+                .card_network
+                .map(|card_network| format!("Card network: {card_network}")),
+        );
+        lines.extend(
+            card_info
+                .payment_checks
+                .map(|payment_checks| format!("AVS and CVV checks: {payment_checks}")),
+        );
+        lines.extend(
+            card_info
+                .authentication_data
+                .map(|authentication_data| format!("Authentication data: {authentication_data}")),
+        );
+    }
+
+    lines.push(String::new());
+    lines.push("Customer".to_string());
+    lines.extend(
+        sources
+            .get_customer_name()
+            .map(|customer_name| format!("Name: {customer_name}")),
+    );
+    lines.extend(
+        sources
+            .get_customer_email()
+            .map(|customer_email| format!("Email: {customer_email}")),
+    );
+    lines.extend(
+        sources
+            .get_customer_ip()
+            .map(|customer_ip| format!("Purchase IP address: {customer_ip}")),
+    );
+    lines.extend(
+        sources
+            .billing_address
+            .as_ref()
+            .and_then(|address| address.address.as_ref())
+            .map(|address| format!("Billing address: {}", format_address(address))),
+    );
+    lines.extend(
+        sources
+            .shipping_address
+            .as_ref()
+            .and_then(|address| address.address.as_ref())
+            .map(|address| format!("Shipping address: {}", format_address(address))),
+    );
+
+    if let Some(fraud_check) = sources.fraud_check.as_ref() {
+        lines.push(String::new());
+        lines.push("Fraud check".to_string());
+        lines.push(format!(
+            "{} check through {}: {}",
+            fraud_check.frm_transaction_type, fraud_check.frm_name, fraud_check.frm_status
+        ));
+        lines.extend(
+            fraud_check
+                .frm_score
+                .map(|frm_score| format!("Score: {frm_score}")),
+        );
+    }
+
+    if let Some(fulfillment_details) = sources.get_fulfillment_details() {
+        lines.push(String::new());
+        lines.push(format!(
+            "Fulfillment of order {}",
+            fulfillment_details.order_id
+        ));
+        lines.extend(
+            fulfillment_details
+                .carrier
+                .or(fulfillment_details.tracking_company)
+                .map(|carrier| format!("Carrier: {carrier}")),
+        );
+        lines.extend(
+            fulfillment_details
+                .tracking_numbers
+                .filter(|tracking_numbers| !tracking_numbers.is_empty())
+                .map(|tracking_numbers| {
+                    format!("Tracking numbers: {}", tracking_numbers.join(", "))
+                }),
+        );
+        lines.extend(
+            fulfillment_details
+                .shipped_at
+                .map(|shipped_at| format!("Shipped at: {shipped_at}")),
+        );
+        lines.extend(
+            fulfillment_details
+                .shipment_status
+                .map(|shipment_status| format!("Shipment status: {shipment_status}")),
+        );
+        lines.extend(fulfillment_details.products.iter().map(|product| {
+            format!(
+                "Product: {} x {} ({})",
+                product.item_quantity, product.item_name, product.item_id
+            )
+        }));
+    }
+
+    lines.join("\n")
+}
+
+fn format_address(address: &payment_models::AddressDetails) -> String {
+    [
+        address.line1.as_ref().map(|line1| line1.peek().clone()),
+        address.line2.as_ref().map(|line2| line2.peek().clone()),
+        address.line3.as_ref().map(|line3| line3.peek().clone()),
+        address.city.clone(),
+        address.state.as_ref().map(|state| state.peek().clone()),
+        address.zip.as_ref().map(|zip| zip.peek().clone()),
+        address.country.map(|country| country.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::borrow::Cow;
+
+    use common_utils::{id_type, types::MinorUnit};
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn dispute() -> storage::Dispute {
+        let created_at = datetime!(2024-01-10 09:00);
+        storage::Dispute {
+            dispute_id: "dp_1".to_string(),
+            amount: "1000".to_string(),
+            currency: "USD".to_string(),
+            dispute_stage: storage_enums::DisputeStage::Dispute,
+            dispute_status: storage_enums::DisputeStatus::DisputeOpened,
+            payment_id: id_type::PaymentId::try_from(Cow::from("pay_1"))
+                .expect("Invalid payment id"),
+            attempt_id: "pay_1_2".to_string(),
+            merchant_id: id_type::MerchantId::try_from(Cow::from("merchant_1"))
+                .expect("Invalid merchant id"),
+            connector_status: "needs_response".to_string(),
+            connector_dispute_id: "dispute_1".to_string(),
+            connector_reason: Some("fraudulent".to_string()),
+            connector_reason_code: None,
+            challenge_required_by: Some(datetime!(2024-01-20 00:00)),
+            connector_created_at: None,
+            connector_updated_at: None,
+            created_at,
+            modified_at: created_at,
+            connector: "stripe".to_string(),
+            evidence: masking::Secret::new(serde_json::Value::Null),
+            profile_id: None,
+            merchant_connector_id: None,
+            dispute_amount: 1000,
+            organization_id: id_type::OrganizationId::try_from(Cow::from("org_1"))
+                .expect("Invalid organization id"),
+            dispute_currency: None,
+        }
+    }
+
+    fn payment_intent() -> PaymentIntent {
+        let created_at = datetime!(2024-01-01 10:00);
+        PaymentIntent {
+            payment_id: id_type::PaymentId::try_from(Cow::from("pay_1"))
+                .expect("Invalid payment id"),
+            merchant_id: id_type::MerchantId::try_from(Cow::from("merchant_1"))
+                .expect("Invalid merchant id"),
+            status: storage_enums::IntentStatus::Succeeded,
+            amount: MinorUnit::new(1000),
+            currency: Some(storage_enums::Currency::USD),
+            amount_captured: Some(MinorUnit::new(1000)),
+            customer_id: None,
+            description: Some("Winter jacket".to_string()),
+            return_url: None,
+            metadata: None,
+            connector_id: None,
+            shipping_address_id: None,
+            billing_address_id: None,
+            statement_descriptor_name: None,
+            statement_descriptor_suffix: None,
+            created_at,
+            modified_at: created_at,
+            last_synced: None,
+            setup_future_usage: None,
+            fingerprint_id: None,
+            off_session: None,
+            client_secret: None,
+            active_attempt: hyperswitch_domain_models::RemoteStorageObject::ForeignID(
+                "pay_1_2".to_string(),
+            ),
+            business_country: None,
+            business_label: None,
+            order_details: None,
+            allowed_payment_method_types: None,
+            connector_metadata: None,
+            feature_metadata: None,
+            attempt_count: 2,
+            payment_link_id: None,
+            profile_id: None,
+            merchant_decision: None,
+            payment_confirm_source: None,
+            surcharge_applicable: None,
+            updated_by: storage_enums::MerchantStorageScheme::PostgresOnly.to_string(),
+            request_incremental_authorization: None,
+            incremental_authorization_allowed: None,
+            authorization_count: None,
+            session_expiry: None,
+            request_external_three_ds_authentication: None,
+            split_payments: None,
+            frm_metadata: None,
+            customer_details: None,
+            billing_details: None,
+            merchant_order_reference_id: None,
+            shipping_details: None,
+            is_payment_processor_token_flow: None,
+            organization_id: id_type::OrganizationId::try_from(Cow::from("org_1"))
+                .expect("Invalid organization id"),
+            shipping_cost: None,
+            tax_details: None,
+            skip_external_tax_calculation: None,
+            request_extended_authorization: None,
+            psd2_sca_exemption_type: None,
+            processor_merchant_id: id_type::MerchantId::try_from(Cow::from("merchant_1"))
+                .expect("Invalid merchant id"),
+            created_by: None,
+            force_3ds_challenge: None,
+            force_3ds_challenge_trigger: None,
+            is_iframe_redirection_enabled: None,
+        }
+    }
+
+    fn payment_attempt(attempt_id: &str, status: &str, extra: serde_json::Value) -> PaymentAttempt {
+        let mut payment_attempt = serde_json::json!({
+            "payment_id": "pay_1",
+            "merchant_id": "merchant_1",
+            "attempt_id": attempt_id,
+            "status": status,
+            "net_amount": { "order_amount": 1000 },
+            "confirm": true,
+            "created_at": "2024-01-01T10:00:00.000Z",
+            "modified_at": "2024-01-01T10:00:00.000Z",
+            "amount_capturable": 0,
+            "updated_by": "postgres_only",
+            "profile_id": "pro_1",
+            "organization_id": "org_1",
+            "processor_merchant_id": "merchant_1",
+            "connector": "stripe",
+            "payment_method_type": "credit",
+        });
+        if let (Some(payment_attempt), serde_json::Value::Object(extra)) =
+            (payment_attempt.as_object_mut(), extra)
+        {
+            payment_attempt.extend(extra);
+        }
+        serde_json::from_value(payment_attempt).expect("Invalid payment attempt")
+    }
+
+    fn evidence_sources() -> EvidenceSources {
+        let failed_attempt = payment_attempt(
+            "pay_1_1",
+            "failure",
+            serde_json::json!({ "error_message": "Insufficient funds" }),
+        );
+        let disputed_attempt = payment_attempt(
+            "pay_1_2",
+            "charged",
+            serde_json::json!({
+                "authentication_type": "three_ds",
+                "browser_info": { "ip_address": "127.0.0.1" },
+            }),
+        );
+        EvidenceSources {
+            payment_intent: payment_intent(),
+            payment_attempts: vec![failed_attempt, disputed_attempt.clone()],
+            disputed_attempt,
+            customer: None,
+            billing_address: Some(payment_models::Address {
+                address: Some(payment_models::AddressDetails {
+                    line1: Some(masking::Secret::new("1 Main Street".to_string())),
+                    city: Some("San Francisco".to_string()),
+                    country: Some(common_enums::CountryAlpha2::US),
+                    first_name: Some(masking::Secret::new("John".to_string())),
+                    last_name: Some(masking::Secret::new("Doe".to_string())),
+                    ..Default::default()
+                }),
+                phone: None,
+                email: None,
+            }),
+            shipping_address: None,
+            authentication: None,
+            fraud_check: None,
+        }
+    }
+
+    #[test]
+    fn test_render_evidence_summary() {
+        let summary = render_evidence_summary(&dispute(), &evidence_sources());
+        let lines = summary.lines().collect::<Vec<_>>();
+
+        assert_eq!(
+            lines.get(..6),
+            Some(
+                [
+                    "Dispute dp_1",
+                    "Connector: stripe",
+                    "Amount: 1000 USD",
+                    "Stage: dispute, Status: dispute_opened",
+                    "Reason: fraudulent",
+                    "Evidence due by: 2024-01-20 0:00:00.0",
+                ]
+                .as_slice()
+            )
+        );
+        assert!(lines.contains(&"Description: Winter jacket"));
+        assert!(lines.contains(
+            &"2024-01-01 10:00:00.0: attempt pay_1_1 failure through stripe, amount 1000, \
+              payment method type credit, error: Insufficient funds"
+        ));
+        assert!(lines.contains(
+            &"2024-01-01 10:00:00.0: attempt pay_1_2 charged through stripe, amount 1000, \
+              payment method type credit (disputed)"
+        ));
+        assert!(lines.contains(&"Authentication type: three_ds"));
+        assert!(lines.contains(&"Name: John Doe"));
+        assert!(lines.contains(&"Purchase IP address: 127.0.0.1"));
+        assert!(lines.contains(&"Billing address: 1 Main Street, San Francisco, US"));
+        // Sections without any data are left out
+        assert!(!lines
+            .iter()
+            .any(|line| line.starts_with("Shipping address")));
+        assert!(!lines.contains(&"Fraud check"));
+    }
+
+    #[test]
+    fn test_format_address() {
+        let address = payment_models::AddressDetails {
+            line1: Some(masking::Secret::new("1 Main Street".to_string())),
+            line2: Some(masking::Secret::new("Apartment 2".to_string())),
+            city: Some("Berlin".to_string()),
+            zip: Some(masking::Secret::new("10115".to_string())),
+            country: Some(common_enums::CountryAlpha2::DE),
+            first_name: Some(masking::Secret::new("Jane".to_string())),
+            ..Default::default()
+        };
+        assert_eq!(
+            format_address(&address),
+            "1 Main Street, Apartment 2, Berlin, 10115, DE"
+        );
+        assert_eq!(
+            format_address(&payment_models::AddressDetails::default()),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_replaceable_evidence_summary() {
+        let dispute_evidence =
+            |uncategorized_file: Option<&str>, evidence_summary_file: Option<&str>| {
+                api::DisputeEvidence {
+                    uncategorized_file: uncategorized_file.map(str::to_string),
+                    evidence_summary_file: evidence_summary_file.map(str::to_string),
+                    ..Default::default()
+                }
+            };
+
+        assert_eq!(
+            get_replaceable_evidence_summary(&dispute_evidence(None, None)).ok(),
+            Some(None)
+        );
+        // The merchant removed the summary attached earlier
+        assert_eq!(
+            get_replaceable_evidence_summary(&dispute_evidence(None, Some("file_1"))).ok(),
+            Some(None)
+        );
+        assert_eq!(
+            get_replaceable_evidence_summary(&dispute_evidence(Some("file_1"), Some("file_1")))
+                .ok(),
+            Some(Some("file_1".to_string()))
+        );
+        // Files attached by the merchant, whatever their name, are not replaced
+        assert!(get_replaceable_evidence_summary(&dispute_evidence(Some("file_1"), None)).is_err());
+        assert!(get_replaceable_evidence_summary(&dispute_evidence(
+            Some("file_2"),
+            Some("file_1")
+        ))
+        .is_err());
+    }
+}
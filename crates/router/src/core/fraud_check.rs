@@ -2,6 +2,7 @@ use std::fmt::Debug;
 
 use api_models::{self, enums as api_enums};
 use common_enums::CaptureMethod;
+use common_utils::ext_traits::Encode;
 use error_stack::ResultExt;
 use masking::PeekInterface;
 use router_env::{
@@ -842,6 +843,10 @@ pub async fn make_fulfillment_api_call(
         )
         .await
         .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let fulfillment_details = frm_core_types::FulfillmentDetails::from(&req)
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode fulfillment details")?;
     let connector_data = FraudCheckConnectorData::get_connector_by_name(&fraud_check.frm_name)?;
     let connector_integration: services::BoxedFrmConnectorIntegrationInterface<
         Fulfillment,
@@ -867,19 +872,14 @@ pub async fn make_fulfillment_api_call(
     )
     .await
     .to_payment_failed_response()?;
-    let fraud_check_copy = fraud_check.clone();
-    let fraud_check_update = FraudCheckUpdate::ResponseUpdate {
-        frm_status: fraud_check.frm_status,
-        frm_transaction_id: fraud_check.frm_transaction_id,
-        frm_reason: fraud_check.frm_reason,
-        frm_score: fraud_check.frm_score,
-        metadata: fraud_check.metadata,
+    // The fulfillment details are stored to be used as evidence if the payment is disputed
+    let fraud_check_update = FraudCheckUpdate::FulfillmentUpdate {
+        fulfillment_details: Some(fulfillment_details),
         modified_at: common_utils::date_time::now(),
         last_step: FraudCheckLastStep::Fulfillment,
-        payment_capture_method: fraud_check.payment_capture_method,
     };
     let _updated = db
-        .update_fraud_check_response_with_attempt_id(fraud_check_copy, fraud_check_update)
+        .update_fraud_check_response_with_attempt_id(fraud_check, fraud_check_update)
         .await
         .map_err(|error| error.change_context(errors::ApiErrorResponse::PaymentNotFound))?;
     let fulfillment_response =
@@ -162,6 +162,7 @@ impl ConstructFlowSpecificData<frm_api::Checkout, FraudCheckCheckoutData, FraudC
             authentication_id: None,
             psd2_sca_exemption_type: None,
             whole_connector_response: None,
+            merchant_connector_id: None,
         };
 
         Ok(router_data)
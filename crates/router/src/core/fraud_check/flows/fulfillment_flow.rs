@@ -129,6 +129,7 @@ pub async fn construct_fulfillment_router_data<'a>(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: None,
     };
     Ok(router_data)
 }
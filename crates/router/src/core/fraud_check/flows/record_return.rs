@@ -130,6 +130,7 @@ impl ConstructFlowSpecificData<RecordReturn, FraudCheckRecordReturnData, FraudCh
             authentication_id: None,
             psd2_sca_exemption_type: None,
             whole_connector_response: None,
+            merchant_connector_id: None,
         };
 
         Ok(router_data)
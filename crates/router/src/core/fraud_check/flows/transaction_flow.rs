@@ -147,6 +147,7 @@ impl
             authentication_id: None,
             psd2_sca_exemption_type: None,
             whole_connector_response: None,
+            merchant_connector_id: None,
         };
 
         Ok(router_data)
@@ -9,7 +9,8 @@ use common_utils::pii::SecretSerdeValue;
 use hyperswitch_domain_models::payments::{payment_attempt::PaymentAttempt, PaymentIntent};
 pub use hyperswitch_domain_models::{
     router_request_types::fraud_check::{
-        Address, Destination, FrmFulfillmentRequest, FulfillmentStatus, Fulfillments, Product,
+        Address, Destination, FrmFulfillmentRequest, FulfillmentDetails, FulfillmentStatus,
+        Fulfillments, Product,
     },
     types::OrderDetailsWithAmount,
 };
@@ -1,12 +1,12 @@
 use api_models::gsm as gsm_api_types;
 use diesel_models::gsm as storage;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
 
 use crate::{
     core::{
         errors,
-        errors::{RouterResponse, StorageErrorExt},
+        errors::{RouterResponse, RouterResult, StorageErrorExt},
     },
     db::gsm::GsmInterface,
     services,
@@ -19,6 +19,8 @@ pub async fn create_gsm_rule(
     state: SessionState,
     gsm_rule: gsm_api_types::GsmCreateRequest,
 ) -> RouterResponse<gsm_api_types::GsmResponse> {
+    validate_requeue_backoff(gsm_rule.requeue_backoff_in_seconds)?;
+
     let db = state.store.as_ref();
     GsmInterface::add_gsm_rule(db, gsm_rule.foreign_into())
         .await
@@ -69,7 +71,12 @@ pub async fn update_gsm_rule(
         unified_message,
         error_category,
         clear_pan_possible,
+        requeue_backoff_in_seconds,
+        clear_requeue_backoff,
     } = gsm_request;
+    let requeue_backoff_in_seconds =
+        get_requeue_backoff_update(requeue_backoff_in_seconds, clear_requeue_backoff)?;
+
     GsmInterface::update_gsm_rule(
         db,
         connector.to_string(),
@@ -86,6 +93,7 @@ pub async fn update_gsm_rule(
             unified_message,
             error_category,
             clear_pan_possible,
+            requeue_backoff_in_seconds,
         },
     )
     .await
@@ -142,3 +150,68 @@ pub async fn delete_gsm_rule(
         Err(err) => Err(err),
     }
 }
+
+fn validate_requeue_backoff(requeue_backoff_in_seconds: Option<i32>) -> RouterResult<()> {
+    match requeue_backoff_in_seconds {
+        Some(backoff) if backoff.is_negative() => {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`requeue_backoff_in_seconds` must not be negative".to_string(),
+            }))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The requeue delay of a rule is only updated when it is provided, and removed when it is
+/// explicitly cleared
+fn get_requeue_backoff_update(
+    requeue_backoff_in_seconds: Option<i32>,
+    clear_requeue_backoff: Option<bool>,
+) -> RouterResult<Option<Option<i32>>> {
+    validate_requeue_backoff(requeue_backoff_in_seconds)?;
+    match (
+        requeue_backoff_in_seconds,
+        clear_requeue_backoff.unwrap_or(false),
+    ) {
+        (Some(_), true) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message:
+                "`requeue_backoff_in_seconds` cannot be set along with `clear_requeue_backoff`"
+                    .to_string(),
+        })),
+        (None, true) => Ok(Some(None)),
+        (requeue_backoff_in_seconds, false) => Ok(requeue_backoff_in_seconds.map(Some)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_requeue_backoff() {
+        assert!(validate_requeue_backoff(None).is_ok());
+        assert!(validate_requeue_backoff(Some(0)).is_ok());
+        assert!(validate_requeue_backoff(Some(300)).is_ok());
+        assert!(validate_requeue_backoff(Some(-1)).is_err());
+    }
+
+    #[test]
+    fn test_requeue_backoff_partial_update() {
+        // Updating other fields of the rule leaves the requeue delay unchanged
+        assert_eq!(get_requeue_backoff_update(None, None).ok(), Some(None));
+        assert_eq!(
+            get_requeue_backoff_update(None, Some(false)).ok(),
+            Some(None)
+        );
+        assert_eq!(
+            get_requeue_backoff_update(Some(300), None).ok(),
+            Some(Some(Some(300)))
+        );
+        assert_eq!(
+            get_requeue_backoff_update(None, Some(true)).ok(),
+            Some(Some(None))
+        );
+        assert!(get_requeue_backoff_update(Some(300), Some(true)).is_err());
+        assert!(get_requeue_backoff_update(Some(-1), None).is_err());
+    }
+}
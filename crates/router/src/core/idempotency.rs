@@ -0,0 +1,744 @@
+use actix_web::HttpRequest;
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    encryption::Encryption,
+    fp_utils, id_type, type_name,
+    types::keymanager::Identifier,
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, Maskable, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing, types::FlowMetric, Flow};
+
+use super::{
+    api_locking::{LockAction, LockingInput},
+    errors::{self, RouterResult, StorageErrorExt},
+};
+use crate::{
+    headers,
+    routes::{
+        lock_utils::{self, GetApiIdentifier},
+        metrics, SessionState,
+    },
+    services::ApplicationResponse,
+    types::{domain, storage},
+};
+
+pub const IDEMPOTENCY_KEY_PREFIX: &str = "IDEMPOTENCY";
+
+const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+/// Whether the `Idempotency-Key` header of a request is honoured by the API server wrapper
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IdempotencyAction {
+    Apply,
+    // The response of the core function is transformed before it is sent to the client (like in
+    // the compatibility layer), and cannot be replayed as is
+    NotApplicable,
+}
+
+/// Determines whether the responses of a flow are replayed for requests made with an
+/// `Idempotency-Key` header. Flows have to opt in, since their responses are stored until the
+/// idempotency records expire.
+pub trait GetIdempotencyAction {
+    fn get_idempotency_action(&self) -> IdempotencyAction;
+}
+
+impl GetIdempotencyAction for Flow {
+    fn get_idempotency_action(&self) -> IdempotencyAction {
+        match self {
+            Self::PaymentsCreate
+            | Self::PaymentsUpdate
+            | Self::PaymentsConfirm
+            | Self::PaymentsCapture
+            | Self::PaymentsCancel
+            | Self::PaymentsApprove
+            | Self::PaymentsReject
+            | Self::PaymentsCompleteAuthorize
+            | Self::PaymentsIncrementalAuthorization
+            | Self::RefundsCreate
+            | Self::RefundsUpdate
+            | Self::PayoutsCreate
+            | Self::PayoutsConfirm
+            | Self::PayoutsCancel
+            | Self::PayoutsFulfill
+            | Self::CustomersCreate
+            | Self::Relay => IdempotencyAction::Apply,
+            _ => IdempotencyAction::NotApplicable,
+        }
+    }
+}
+
+#[cfg(feature = "olap")]
+impl GetIdempotencyAction for analytics::AnalyticsFlow {
+    fn get_idempotency_action(&self) -> IdempotencyAction {
+        IdempotencyAction::NotApplicable
+    }
+}
+
+#[cfg(feature = "dummy_connector")]
+impl GetIdempotencyAction for crate::routes::dummy_connector::types::Flow {
+    fn get_idempotency_action(&self) -> IdempotencyAction {
+        IdempotencyAction::NotApplicable
+    }
+}
+
+/// Response of an idempotent request, stored encrypted with the key of the merchant since the
+/// response may contain secrets like client secrets
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+struct StoredResponse {
+    body: String,
+    headers: Vec<StoredResponseHeader>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+struct StoredResponseHeader {
+    name: String,
+    value: String,
+    is_masked: bool,
+}
+
+impl StoredResponse {
+    /// Captures the response to be stored. Returns `None` if the response cannot be replayed, and
+    /// `Some(None)` if the response has no body.
+    fn from_response<Q: serde::Serialize>(
+        response: &ApplicationResponse<Q>,
+    ) -> RouterResult<Option<Option<Self>>> {
+        let (body, headers) = match response {
+            ApplicationResponse::Json(body) => (Self::serialize_body(body)?, Vec::new()),
+            ApplicationResponse::JsonWithHeaders((body, headers)) => {
+                (Self::serialize_body(body)?, headers.clone())
+            }
+            ApplicationResponse::IdempotentReplay((body, headers)) => {
+                (body.clone(), headers.clone())
+            }
+            ApplicationResponse::StatusOk => return Ok(Some(None)),
+            // Redirections and rendered pages are not replayed, retries of such requests are
+            // processed again
+            ApplicationResponse::TextPlain(_)
+            | ApplicationResponse::JsonForRedirection(_)
+            | ApplicationResponse::Form(_)
+            | ApplicationResponse::PaymentLinkForm(_)
+            | ApplicationResponse::FileData(_)
+            | ApplicationResponse::GenericLinkForm(_) => return Ok(None),
+        };
+
+        let headers = headers
+            .into_iter()
+            .map(|(name, value)| StoredResponseHeader {
+                name,
+                is_masked: value.is_masked(),
+                value: value.into_inner(),
+            })
+            .collect();
+        Ok(Some(Some(Self { body, headers })))
+    }
+
+    fn serialize_body<Q: serde::Serialize>(body: &Q) -> RouterResult<String> {
+        serde_json::to_string(body)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the response")
+    }
+
+    fn into_response<Q>(self) -> ApplicationResponse<Q> {
+        let headers = self
+            .headers
+            .into_iter()
+            .map(|header| {
+                let value = if header.is_masked {
+                    Maskable::new_masked(header.value.into())
+                } else {
+                    Maskable::new_normal(header.value)
+                };
+                (header.name, value)
+            })
+            .collect();
+        ApplicationResponse::IdempotentReplay((self.body, headers))
+    }
+}
+
+/// Payload of a request as it was sent by the merchant, for flows that fill in generated values
+/// (like the payment ID) before the payload reaches the API server wrapper. Retries of such
+/// requests are fingerprinted with this payload, since the generated values differ across retries.
+#[derive(Clone, Debug)]
+struct OriginalRequestPayload(String);
+
+/// Stores the payload of the request before any values are generated for it, to be used for the
+/// fingerprint of the request if it has an `Idempotency-Key` header.
+pub fn store_original_request_payload<T: serde::Serialize>(
+    request: &HttpRequest,
+    payload: &T,
+) -> RouterResult<()> {
+    if !request.headers().contains_key(headers::IDEMPOTENCY_KEY) {
+        return Ok(());
+    }
+
+    let payload = serialize_request_payload(payload)?;
+    request
+        .extensions_mut()
+        .insert(OriginalRequestPayload(payload));
+    Ok(())
+}
+
+fn serialize_request_payload<T: serde::Serialize>(payload: &T) -> RouterResult<String> {
+    serde_json::to_string(payload)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the request payload")
+}
+
+/// A mutating request made by a merchant with an `Idempotency-Key` header
+#[derive(Clone, Debug)]
+pub struct IdempotentRequest {
+    merchant_id: id_type::MerchantId,
+    idempotency_key: String,
+    request_fingerprint: String,
+    api_identifier: lock_utils::ApiIdentifier,
+}
+
+impl IdempotentRequest {
+    /// Identifies the idempotent request, if the request is a mutating request authenticated as a
+    /// merchant and has an `Idempotency-Key` header.
+    pub fn from_request<T: serde::Serialize>(
+        request: &HttpRequest,
+        merchant_id: Option<&id_type::MerchantId>,
+        flow: &(impl FlowMetric + GetApiIdentifier),
+        payload: &T,
+    ) -> RouterResult<Option<Self>> {
+        let Some(idempotency_key) = request.headers().get(headers::IDEMPOTENCY_KEY) else {
+            return Ok(None);
+        };
+        let (Some(merchant_id), Some(api_identifier)) = (merchant_id, flow.get_api_identifier())
+        else {
+            return Ok(None);
+        };
+        if request.method().is_safe() {
+            return Ok(None);
+        }
+
+        let idempotency_key = idempotency_key
+            .to_str()
+            .ok()
+            .filter(|key| !key.is_empty() && key.len() <= MAX_IDEMPOTENCY_KEY_LENGTH)
+            .ok_or_else(|| errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`{}` header must be between 1 and {MAX_IDEMPOTENCY_KEY_LENGTH} characters long",
+                    headers::IDEMPOTENCY_KEY
+                ),
+            })?
+            .to_owned();
+
+        let original_payload = request
+            .extensions()
+            .get::<OriginalRequestPayload>()
+            .map(|payload| payload.0.clone());
+        let payload = match original_payload {
+            Some(payload) => payload,
+            None => serialize_request_payload(payload)?,
+        };
+        let request_content = format!("{flow}:{}:{}:{payload}", request.method(), request.path());
+        let request_fingerprint = crypto::Sha256
+            .generate_digest(request_content.as_bytes())
+            .map(hex::encode)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to generate the request fingerprint")?;
+
+        Ok(Some(Self {
+            merchant_id: merchant_id.to_owned(),
+            idempotency_key,
+            request_fingerprint,
+            api_identifier,
+        }))
+    }
+
+    /// Returns the stored response if the request has already been processed.
+    ///
+    /// Otherwise, holds the lock on the idempotency key until [`Self::complete`] is called, so
+    /// that concurrent duplicates of the request wait for the request to be processed and replay
+    /// its response.
+    #[instrument(skip_all)]
+    pub async fn begin<Q>(
+        &self,
+        state: &SessionState,
+    ) -> RouterResult<Option<ApplicationResponse<Q>>> {
+        if let Some(record) = self.find_record(state).await? {
+            return self.replay(state, record).await.map(Some);
+        }
+
+        self.get_lock_action()
+            .perform_locking_action(state, self.merchant_id.clone())
+            .await?;
+
+        // A concurrent duplicate of the request may have been processed while waiting for the lock
+        let response = match self.find_record(state).await {
+            Ok(Some(record)) => self.replay(state, record).await.map(Some),
+            other => other.map(|_| None),
+        };
+        if !matches!(response, Ok(None)) {
+            self.free_lock(state).await;
+        }
+        response
+    }
+
+    /// Stores the response of the processed request to be replayed for its retries, and frees the
+    /// lock on the idempotency key.
+    ///
+    /// Failed requests are not stored, so that they can be retried with the same idempotency key.
+    /// Failing to store the response does not fail the request, since it has already been
+    /// processed.
+    #[instrument(skip_all)]
+    pub async fn complete<Q: serde::Serialize>(
+        self,
+        state: &SessionState,
+        response: Option<&ApplicationResponse<Q>>,
+    ) {
+        if let Some(response) = response {
+            self.store_response(state, response)
+                .await
+                .inspect_err(|error| {
+                    logger::error!(?error, "Failed to store the response of idempotent request")
+                })
+                .ok();
+        }
+        self.free_lock(state).await;
+    }
+
+    async fn replay<Q>(
+        &self,
+        state: &SessionState,
+        record: storage::IdempotencyRecord,
+    ) -> RouterResult<ApplicationResponse<Q>> {
+        self.validate_request_fingerprint(&record)?;
+
+        let stored_response = match record.response {
+            Some(response) => Some(self.decrypt_response(state, response).await?),
+            None => None,
+        };
+
+        logger::info!(
+            idempotency_key = %self.idempotency_key,
+            "Replaying the response of idempotent request"
+        );
+        metrics::IDEMPOTENT_REQUESTS_REPLAYED.add(
+            1,
+            router_env::metric_attributes!(("merchant_id", self.merchant_id.clone())),
+        );
+
+        Ok(stored_response.map_or(ApplicationResponse::StatusOk, StoredResponse::into_response))
+    }
+
+    /// Idempotency keys cannot be reused for requests to a different route or with a different
+    /// payload
+    fn validate_request_fingerprint(
+        &self,
+        record: &storage::IdempotencyRecord,
+    ) -> RouterResult<()> {
+        fp_utils::when(
+            record.request_fingerprint != self.request_fingerprint,
+            || {
+                Err(report!(errors::ApiErrorResponse::IdempotencyKeyReused {
+                    idempotency_key: self.idempotency_key.clone(),
+                }))
+            },
+        )
+    }
+
+    async fn get_merchant_key_store(
+        &self,
+        state: &SessionState,
+    ) -> RouterResult<domain::MerchantKeyStore> {
+        state
+            .store
+            .get_merchant_key_store_by_merchant_id(
+                &state.into(),
+                &self.merchant_id,
+                &state.store.get_master_key().to_vec().into(),
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+    }
+
+    async fn encrypt_response(
+        &self,
+        state: &SessionState,
+        response: &StoredResponse,
+    ) -> RouterResult<Encryption> {
+        let key_store = self.get_merchant_key_store(state).await?;
+        let response = serde_json::to_string(response)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the response of idempotent request")?;
+
+        domain::types::crypto_operation::<String, masking::WithType>(
+            &state.into(),
+            type_name!(storage::IdempotencyRecord),
+            domain::types::CryptoOperation::Encrypt(Secret::new(response)),
+            Identifier::Merchant(key_store.merchant_id.clone()),
+            key_store.key.get_inner().peek(),
+        )
+        .await
+        .and_then(|response| response.try_into_operation())
+        .map(Encryption::from)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt the response of idempotent request")
+    }
+
+    async fn decrypt_response(
+        &self,
+        state: &SessionState,
+        response: Encryption,
+    ) -> RouterResult<StoredResponse> {
+        let key_store = self.get_merchant_key_store(state).await?;
+        let response = domain::types::crypto_operation::<String, masking::WithType>(
+            &state.into(),
+            type_name!(storage::IdempotencyRecord),
+            domain::types::CryptoOperation::Decrypt(response),
+            Identifier::Merchant(key_store.merchant_id.clone()),
+            key_store.key.get_inner().peek(),
+        )
+        .await
+        .and_then(|response| response.try_into_operation())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt the response of idempotent request")?;
+
+        serde_json::from_str(&response.into_inner().expose())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to deserialize the response of idempotent request")
+    }
+
+    /// Looks up an unexpired record of the request in Redis, falling back to the database
+    async fn find_record(
+        &self,
+        state: &SessionState,
+    ) -> RouterResult<Option<storage::IdempotencyRecord>> {
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get redis connection")?;
+
+        let cached_record = redis_conn
+            .get_and_deserialize_key::<storage::IdempotencyRecord>(
+                &self.get_redis_key().into(),
+                "IdempotencyRecord",
+            )
+            .await
+            .inspect_err(|error| {
+                if !matches!(
+                    error.current_context(),
+                    redis_interface::errors::RedisError::NotFound
+                ) {
+                    logger::error!(?error, "Failed to get idempotency record from redis");
+                }
+            })
+            .ok();
+        if let Some(record) = cached_record {
+            return Ok(Some(record));
+        }
+
+        let record = match state
+            .store
+            .find_idempotency_record_by_merchant_id_idempotency_key(
+                &self.merchant_id,
+                &self.idempotency_key,
+            )
+            .await
+        {
+            Ok(record) => record,
+            Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+            Err(error) => {
+                return Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to find idempotency record")
+            }
+        };
+
+        if !record.is_expired() {
+            return Ok(Some(record));
+        }
+
+        // Expired records are removed so that the idempotency key can be used again
+        state
+            .store
+            .delete_idempotency_record_by_merchant_id_idempotency_key(
+                &self.merchant_id,
+                &self.idempotency_key,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete expired idempotency record")?;
+        Ok(None)
+    }
+
+    async fn store_response<Q: serde::Serialize>(
+        &self,
+        state: &SessionState,
+        response: &ApplicationResponse<Q>,
+    ) -> RouterResult<()> {
+        let Some(stored_response) = StoredResponse::from_response(response)? else {
+            return Ok(());
+        };
+        let response = match stored_response {
+            Some(stored_response) => Some(self.encrypt_response(state, &stored_response).await?),
+            None => None,
+        };
+
+        let ttl_in_secs = state.conf.idempotency.ttl_in_secs;
+        let created_at = common_utils::date_time::now();
+        let record = state
+            .store
+            .insert_idempotency_record(storage::IdempotencyRecordNew {
+                merchant_id: self.merchant_id.clone(),
+                idempotency_key: self.idempotency_key.clone(),
+                request_fingerprint: self.request_fingerprint.clone(),
+                response,
+                created_at,
+                expires_at: created_at.saturating_add(time::Duration::seconds(ttl_in_secs.into())),
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert idempotency record")?;
+
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get redis connection")?;
+        redis_conn
+            .serialize_and_set_key_with_expiry(
+                &self.get_redis_key().into(),
+                &record,
+                ttl_in_secs.into(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to cache idempotency record in redis")
+    }
+
+    async fn free_lock(&self, state: &SessionState) {
+        self.get_lock_action()
+            .free_lock_action(state, self.merchant_id.clone())
+            .await
+            .inspect_err(|error| logger::error!(?error, "Failed to free idempotency key lock"))
+            .ok();
+    }
+
+    fn get_lock_action(&self) -> LockAction {
+        LockAction::Hold {
+            input: LockingInput {
+                unique_locking_key: format!("{IDEMPOTENCY_KEY_PREFIX}_{}", self.idempotency_key),
+                api_identifier: self.api_identifier.clone(),
+                override_lock_retries: None,
+            },
+        }
+    }
+
+    fn get_redis_key(&self) -> String {
+        format!(
+            "{IDEMPOTENCY_KEY_PREFIX}_{}_{}",
+            self.merchant_id.get_string_repr(),
+            self.idempotency_key
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::borrow::Cow;
+
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    fn idempotent_request(path: &str, payload: serde_json::Value) -> IdempotentRequest {
+        let merchant_id =
+            id_type::MerchantId::try_from(Cow::from("merchant_1")).expect("Invalid merchant id");
+        let request = TestRequest::post()
+            .uri(path)
+            .insert_header((headers::IDEMPOTENCY_KEY, "key_1"))
+            .to_http_request();
+
+        IdempotentRequest::from_request(
+            &request,
+            Some(&merchant_id),
+            &Flow::PaymentsCreate,
+            &payload,
+        )
+        .expect("Failed to identify idempotent request")
+        .expect("Request is not idempotent")
+    }
+
+    fn record(request: &IdempotentRequest) -> storage::IdempotencyRecord {
+        let now = common_utils::date_time::now();
+        storage::IdempotencyRecord {
+            merchant_id: request.merchant_id.clone(),
+            idempotency_key: request.idempotency_key.clone(),
+            request_fingerprint: request.request_fingerprint.clone(),
+            response: None,
+            created_at: now,
+            expires_at: now,
+        }
+    }
+
+    #[test]
+    fn test_flows_opt_in_to_idempotency() {
+        assert_eq!(
+            Flow::PaymentsCreate.get_idempotency_action(),
+            IdempotencyAction::Apply
+        );
+        assert_eq!(
+            Flow::RefundsCreate.get_idempotency_action(),
+            IdempotencyAction::Apply
+        );
+        // The responses of API key flows contain the plaintext API key
+        assert_eq!(
+            Flow::ApiKeyCreate.get_idempotency_action(),
+            IdempotencyAction::NotApplicable
+        );
+        assert_eq!(
+            Flow::UserSignIn.get_idempotency_action(),
+            IdempotencyAction::NotApplicable
+        );
+    }
+
+    #[test]
+    fn test_request_without_idempotency_key_is_not_idempotent() {
+        let merchant_id =
+            id_type::MerchantId::try_from(Cow::from("merchant_1")).expect("Invalid merchant id");
+        let request = TestRequest::post().uri("/payments").to_http_request();
+
+        let idempotent_request = IdempotentRequest::from_request(
+            &request,
+            Some(&merchant_id),
+            &Flow::PaymentsCreate,
+            &serde_json::json!({ "amount": 100 }),
+        )
+        .expect("Failed to identify idempotent request");
+
+        assert!(idempotent_request.is_none());
+    }
+
+    #[test]
+    fn test_same_request_matches_stored_record() {
+        let request = idempotent_request("/payments", serde_json::json!({ "amount": 100 }));
+        let retried_request = idempotent_request("/payments", serde_json::json!({ "amount": 100 }));
+
+        assert!(retried_request
+            .validate_request_fingerprint(&record(&request))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_idempotency_key_reused_with_different_body() {
+        let request = idempotent_request("/payments", serde_json::json!({ "amount": 100 }));
+        let retried_request = idempotent_request("/payments", serde_json::json!({ "amount": 200 }));
+
+        let error = retried_request
+            .validate_request_fingerprint(&record(&request))
+            .err()
+            .expect("Reused idempotency key was accepted");
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::IdempotencyKeyReused { .. }
+        ));
+    }
+
+    #[test]
+    fn test_retry_without_payment_id_matches_stored_record() {
+        let merchant_id =
+            id_type::MerchantId::try_from(Cow::from("merchant_1")).expect("Invalid merchant id");
+        // The payment ID is generated for each retry before the request is fingerprinted
+        let create_request = |payment_id: &str| {
+            let request = TestRequest::post()
+                .uri("/payments")
+                .insert_header((headers::IDEMPOTENCY_KEY, "key_1"))
+                .to_http_request();
+            store_original_request_payload(&request, &serde_json::json!({ "amount": 100 }))
+                .expect("Failed to store the original payload");
+
+            IdempotentRequest::from_request(
+                &request,
+                Some(&merchant_id),
+                &Flow::PaymentsCreate,
+                &serde_json::json!({ "amount": 100, "payment_id": payment_id }),
+            )
+            .expect("Failed to identify idempotent request")
+            .expect("Request is not idempotent")
+        };
+
+        let request = create_request("pay_1");
+        let retried_request = create_request("pay_2");
+
+        assert!(retried_request
+            .validate_request_fingerprint(&record(&request))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_idempotency_key_reused_for_different_route() {
+        let request = idempotent_request("/payments", serde_json::json!({ "amount": 100 }));
+        let retried_request = idempotent_request("/refunds", serde_json::json!({ "amount": 100 }));
+
+        assert!(retried_request
+            .validate_request_fingerprint(&record(&request))
+            .is_err());
+    }
+
+    #[test]
+    fn test_replayed_response_keeps_headers() {
+        let response = ApplicationResponse::JsonWithHeaders((
+            serde_json::json!({ "payment_id": "pay_1" }),
+            vec![
+                (
+                    "x-connector".to_string(),
+                    Maskable::new_normal("stripe".to_string()),
+                ),
+                (
+                    "x-token".to_string(),
+                    Maskable::new_masked("secret".to_string().into()),
+                ),
+            ],
+        ));
+
+        let stored_response = StoredResponse::from_response(&response)
+            .expect("Failed to capture response")
+            .flatten()
+            .expect("Response was not stored");
+        let stored_response = serde_json::from_str::<StoredResponse>(
+            &serde_json::to_string(&stored_response).expect("Failed to serialize response"),
+        )
+        .expect("Failed to deserialize response");
+
+        assert_eq!(
+            stored_response.into_response::<()>(),
+            ApplicationResponse::IdempotentReplay((
+                r#"{"payment_id":"pay_1"}"#.to_string(),
+                vec![
+                    (
+                        "x-connector".to_string(),
+                        Maskable::new_normal("stripe".to_string()),
+                    ),
+                    (
+                        "x-token".to_string(),
+                        Maskable::new_masked("secret".to_string().into()),
+                    ),
+                ],
+            ))
+        );
+    }
+
+    #[test]
+    fn test_responses_without_body_or_not_replayable() {
+        assert_eq!(
+            StoredResponse::from_response(&ApplicationResponse::<()>::StatusOk)
+                .expect("Failed to capture response"),
+            Some(None)
+        );
+        assert_eq!(
+            StoredResponse::from_response(&ApplicationResponse::<()>::TextPlain(
+                "text".to_string()
+            ))
+            .expect("Failed to capture response"),
+            None
+        );
+    }
+}
@@ -86,6 +86,7 @@ pub async fn construct_mandate_revoke_router_data(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: None,
     };
 
     Ok(router_data)
@@ -30,6 +30,8 @@ counter_metric!(
     ATTACH_EVIDENCE_DISPUTE_STATUS_VALIDATION_FAILURE_METRIC,
     GLOBAL_METER
 );
+counter_metric!(DISPUTE_DEADLINE_REMINDER_METRIC, GLOBAL_METER); // No. of reminders sent for disputes whose evidence submission deadline is approaching
+counter_metric!(DISPUTE_AUTO_ACCEPT_METRIC, GLOBAL_METER); // No. of disputes accepted as per the dispute policy of the profile
 
 counter_metric!(INCOMING_PAYOUT_WEBHOOK_METRIC, GLOBAL_METER); // No. of incoming payout webhooks
 counter_metric!(
@@ -44,6 +46,7 @@ counter_metric!(WEBHOOK_OUTGOING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_RECEIVED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_PAYMENT_NOT_FOUND, GLOBAL_METER);
+counter_metric!(WEBHOOK_INCOMING_QUEUED_COUNT, GLOBAL_METER); // No. of incoming webhooks queued as the resource was locked
 counter_metric!(
     WEBHOOK_EVENT_TYPE_IDENTIFICATION_FAILURE_COUNT,
     GLOBAL_METER
@@ -60,6 +63,19 @@ counter_metric!(ROUTING_LINK_CONFIG, GLOBAL_METER);
 counter_metric!(ROUTING_LINK_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
 counter_metric!(ROUTING_RETRIEVE_CONFIG, GLOBAL_METER);
 counter_metric!(ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_SIMULATE_CONFIG, GLOBAL_METER);
+counter_metric!(ROUTING_SIMULATE_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_EXPORT_CONFIG_DSL, GLOBAL_METER);
+counter_metric!(ROUTING_EXPORT_CONFIG_DSL_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_RETRIEVE_ACTIVATION_HISTORY, GLOBAL_METER);
+counter_metric!(
+    ROUTING_RETRIEVE_ACTIVATION_HISTORY_SUCCESS_RESPONSE,
+    GLOBAL_METER
+);
+counter_metric!(ROUTING_DIFF_CONFIG, GLOBAL_METER);
+counter_metric!(ROUTING_DIFF_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_ROLLBACK_CONFIG, GLOBAL_METER);
+counter_metric!(ROUTING_ROLLBACK_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
 counter_metric!(ROUTING_RETRIEVE_DEFAULT_CONFIG, GLOBAL_METER);
 counter_metric!(
     ROUTING_RETRIEVE_DEFAULT_CONFIG_SUCCESS_RESPONSE,
@@ -84,6 +100,9 @@ counter_metric!(
 counter_metric!(DYNAMIC_SUCCESS_BASED_ROUTING, GLOBAL_METER);
 counter_metric!(DYNAMIC_CONTRACT_BASED_ROUTING, GLOBAL_METER);
 
+counter_metric!(CONNECTOR_CIRCUIT_BREAKER_STATE_TRANSITION, GLOBAL_METER);
+counter_metric!(CONNECTOR_CIRCUIT_BREAKER_ROUTING_EXCLUSION, GLOBAL_METER);
+
 #[cfg(feature = "partial-auth")]
 counter_metric!(PARTIAL_AUTH_FAILURE, GLOBAL_METER);
 
@@ -22,6 +22,8 @@ use router_env::{instrument, logger, tracing};
 use serde::{Deserialize, Serialize};
 use storage_impl::redis::cache::{self, SURCHARGE_CACHE};
 
+#[cfg(feature = "v1")]
+use crate::core::card_testing_guard::velocity;
 use crate::{
     core::{
         errors::{self, ConditionalConfigError as ConfigError},
@@ -67,6 +69,34 @@ enum SurchargeSource {
 }
 
 impl SurchargeSource {
+    /// Velocity of the customer of the payment, if the surcharge rules use the velocity keys. The
+    /// card of the payment is not known yet when the surcharge is calculated.
+    #[cfg(feature = "v1")]
+    async fn get_velocity_input(
+        &self,
+        state: &SessionState,
+        payment_intent: &storage::PaymentIntent,
+        currency: common_enums::Currency,
+    ) -> dsl_inputs::VelocityInput {
+        match (self, &payment_intent.profile_id) {
+            (Self::Generate(cached_algo), Some(profile_id))
+                if cached_algo
+                    .cached_algorithm
+                    .uses_key(euclid::types::EuclidKey::is_velocity_key) =>
+            {
+                velocity::get_velocity_input(
+                    state,
+                    profile_id,
+                    payment_intent.customer_id.as_ref(),
+                    None,
+                    currency,
+                )
+                .await
+            }
+            _ => dsl_inputs::VelocityInput::default(),
+        }
+    }
+
     pub fn generate_surcharge_details_and_populate_surcharge_metadata(
         &self,
         backend_input: &backend::BackendInput,
@@ -168,6 +198,9 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
     let mut backend_input =
         make_dsl_input_for_surcharge(payment_attempt, payment_intent, billing_address)
             .change_context(ConfigError::InputConstructionError)?;
+    backend_input.velocity = surcharge_source
+        .get_velocity_input(state, payment_intent, backend_input.payment.currency)
+        .await;
 
     for payment_methods_enabled in response_payment_method_types.iter_mut() {
         for payment_method_type_response in
@@ -268,6 +301,9 @@ pub async fn perform_surcharge_decision_management_for_session_flow(
     let mut backend_input =
         make_dsl_input_for_surcharge(payment_attempt, payment_intent, billing_address)
             .change_context(ConfigError::InputConstructionError)?;
+    backend_input.velocity = surcharge_source
+        .get_velocity_input(state, payment_intent, backend_input.payment.currency)
+        .await;
     for payment_method_type in payment_method_type_list {
         backend_input.payment_method.payment_method_type = Some(*payment_method_type);
         // in case of session flow, payment_method will always be wallet
@@ -326,6 +362,12 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
     logger::debug!(customer_saved_card_list_surcharge_source = surcharge_source_log_message);
     let mut backend_input = make_dsl_input_for_surcharge(payment_attempt, payment_intent, None)
         .change_context(ConfigError::InputConstructionError)?;
+    #[cfg(feature = "v1")]
+    {
+        backend_input.velocity = surcharge_source
+            .get_velocity_input(state, payment_intent, backend_input.payment.currency)
+            .await;
+    }
 
     for customer_payment_method in customer_payment_method_list.iter_mut() {
         let payment_token = customer_payment_method.payment_token.clone();
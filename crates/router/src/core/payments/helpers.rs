@@ -4173,6 +4173,7 @@ pub fn router_data_type_conversion<F1, F2, Req1, Req2, Res1, Res2>(
         authentication_id: router_data.authentication_id,
         psd2_sca_exemption_type: router_data.psd2_sca_exemption_type,
         whole_connector_response: router_data.whole_connector_response,
+        merchant_connector_id: router_data.merchant_connector_id,
     }
 }
 
@@ -32,7 +32,9 @@ use crate::{
     connector::utils::PaymentResponseRouterData,
     consts,
     core::{
-        card_testing_guard::utils as card_testing_guard_utils,
+        card_testing_guard::{
+            utils as card_testing_guard_utils, velocity as card_testing_guard_velocity,
+        },
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate,
         payment_methods::{self, cards::create_encrypted_data},
@@ -2235,6 +2237,25 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         .await;
     }
 
+    if let (Some(card_testing_guard_data), Some(profile_id), Some(currency)) = (
+        &payment_data.card_testing_guard_data,
+        &payment_data.payment_intent.profile_id,
+        payment_data.payment_attempt.currency,
+    ) {
+        card_testing_guard_velocity::record_payment_attempt_velocity(
+            state,
+            card_testing_guard_data,
+            profile_id,
+            payment_data.payment_intent.customer_id.as_ref(),
+            currency,
+            payment_data.payment_attempt.net_amount.get_order_amount(),
+            payment_data.payment_attempt.status,
+        )
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to record payment attempt velocity"))
+        .ok();
+    }
+
     match router_data.integrity_check {
         Ok(()) => Ok(payment_data),
         Err(err) => {
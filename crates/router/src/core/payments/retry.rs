@@ -7,10 +7,12 @@ use router_env::{
     logger,
     tracing::{self, instrument},
 };
+use scheduler::utils as pt_utils;
 
 use crate::{
+    consts,
     core::{
-        errors::{self, RouterResult, StorageErrorExt},
+        errors::{self, CustomResult, RouterResult, StorageErrorExt},
         payments::{
             self,
             flows::{ConstructFlowSpecificData, Feature},
@@ -121,7 +123,7 @@ where
                 None => get_gsm(state, &router_data).await?,
             };
 
-            match get_gsm_decision(gsm) {
+            match get_gsm_decision(gsm.clone()) {
                 api_models::gsm::GsmDecision::Retry => {
                     retries = get_retries(
                         state,
@@ -184,11 +186,56 @@ where
                     retries = retries.map(|i| i - 1);
                 }
                 api_models::gsm::GsmDecision::Requeue => {
-                    Err(report!(errors::ApiErrorResponse::NotImplemented {
-                        message: errors::NotImplementedMessage::Reason(
-                            "Requeue not implemented".to_string(),
-                        ),
-                    }))?
+                    retries = get_retries(
+                        state,
+                        retries,
+                        merchant_context.get_merchant_account().get_id(),
+                        business_profile,
+                    )
+                    .await;
+
+                    // The attempt count is persisted on the payment intent, hence it also accounts
+                    // for the attempts made before the payment was requeued earlier
+                    let is_retry_budget_available = retries.is_some_and(|max_retries| {
+                        i32::from(payment_data.get_payment_intent().attempt_count) <= max_retries
+                    });
+
+                    if !is_retry_budget_available {
+                        metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(1, &[]);
+                        logger::info!("retries exhausted for requeue of auto_retry payment");
+                        break;
+                    }
+
+                    if !is_requeue_possible::<F>(payment_data.get_payment_attempt())? {
+                        logger::info!("requeue is not possible for this payment, proceeding with the default flow");
+                        break;
+                    }
+
+                    let backoff_in_seconds = gsm
+                        .as_ref()
+                        .and_then(|gsm| gsm.requeue_backoff_in_seconds)
+                        .unwrap_or(consts::DEFAULT_GSM_REQUEUE_BACKOFF_IN_SECONDS);
+                    let requeue_schedule_time = common_utils::date_time::now()
+                        .saturating_add(time::Duration::seconds(i64::from(backoff_in_seconds)));
+
+                    add_payment_requeue_task(
+                        &*state.store,
+                        payment_data.get_payment_attempt(),
+                        requeue_schedule_time,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed while adding requeue task to process tracker")?;
+
+                    // Park the attempt in pending state, the requeue task picks it up once the
+                    // backoff has elapsed
+                    router_data.status = storage_enums::AttemptStatus::Pending;
+                    if let Err(error_response) = router_data.response.as_mut() {
+                        error_response.attempt_status = Some(storage_enums::AttemptStatus::Pending);
+                    }
+
+                    metrics::AUTO_RETRY_REQUEUED_PAYMENT_COUNT.add(1, &[]);
+                    break;
                 }
                 api_models::gsm::GsmDecision::DoDefault => break,
             }
@@ -300,6 +347,54 @@ pub fn get_gsm_decision(
     option_gsm_decision.unwrap_or_default()
 }
 
+/// Requeue is supported only for authorize flow, and only when the payment method data can be
+/// fetched again using the payment token when the requeued payment is retried
+#[cfg(feature = "v1")]
+fn is_requeue_possible<F>(payment_attempt: &storage::PaymentAttempt) -> RouterResult<bool> {
+    Ok(get_flow_name::<F>()? == "Authorize" && payment_attempt.payment_token.is_some())
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn add_payment_requeue_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let tracking_data = storage::payment_attempt::PaymentRequeueTrackingData {
+        payment_id: payment_attempt.payment_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+    };
+    let runner = storage::ProcessTrackerRunner::PaymentsRequeueWorkflow;
+    let task = "PAYMENTS_REQUEUE";
+    let tag = ["REQUEUE", "PAYMENT"];
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        payment_attempt.get_id(),
+        &payment_attempt.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "PaymentsRequeue")),
+    );
+    Ok(())
+}
+
 #[inline]
 fn get_flow_name<F>() -> RouterResult<String> {
     Ok(std::any::type_name::<F>()
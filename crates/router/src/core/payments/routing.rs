@@ -26,12 +26,12 @@ use euclid::{
     dssa::graph::{self as euclid_graph, CgraphExt},
     enums as euclid_enums,
     frontend::{ast, dir as euclid_dir},
+    types as euclid_types,
 };
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use external_services::grpc_client::dynamic_routing::{
     contract_routing_client::ContractBasedDynamicRouting,
-    elimination_based_client::{EliminationBasedRouting, EliminationResponse},
-    success_rate_client::{CalSuccessRateResponse, SuccessBasedDynamicRouting},
+    elimination_based_client::EliminationResponse, success_rate_client::CalSuccessRateResponse,
     DynamicRoutingError,
 };
 use hyperswitch_domain_models::address::Address;
@@ -52,6 +52,10 @@ use utils::perform_decision_euclid_routing;
 
 #[cfg(feature = "v2")]
 use crate::core::admin;
+#[cfg(feature = "v1")]
+use crate::core::card_testing_guard::{
+    utils as card_testing_guard_utils, velocity as card_testing_guard_velocity,
+};
 #[cfg(feature = "payouts")]
 use crate::core::payouts;
 #[cfg(feature = "v1")]
@@ -60,7 +64,8 @@ use crate::core::routing::transformers::OpenRouterDecideGatewayRequestExt;
 use crate::headers;
 use crate::{
     core::{
-        errors, errors as oss_errors, payments::routing::utils::DecisionEngineApiHandler, routing,
+        connector_circuit_breaker, errors, errors as oss_errors,
+        payments::routing::utils::DecisionEngineApiHandler, routing,
     },
     logger, services,
     types::{
@@ -79,6 +84,25 @@ pub enum CachedAlgorithm {
     Advanced(backend::VirInterpreterBackend<ConnectorSelection>),
 }
 
+impl CachedAlgorithm {
+    pub fn from_routing_algorithm(
+        algorithm: routing_types::RoutingAlgorithm,
+    ) -> RoutingResult<Self> {
+        Ok(match algorithm {
+            routing_types::RoutingAlgorithm::Single(conn) => Self::Single(conn),
+            routing_types::RoutingAlgorithm::Priority(plist) => Self::Priority(plist),
+            routing_types::RoutingAlgorithm::VolumeSplit(splits) => Self::VolumeSplit(splits),
+            routing_types::RoutingAlgorithm::Advanced(program) => {
+                let interpreter = backend::VirInterpreterBackend::with_program(program)
+                    .change_context(errors::RoutingError::DslBackendInitError)
+                    .attach_printable("Error initializing DSL interpreter backend")?;
+
+                Self::Advanced(interpreter)
+            }
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 pub struct SessionFlowRoutingInput<'a> {
     pub state: &'a SessionState,
@@ -175,6 +199,8 @@ pub fn make_dsl_input_for_payouts(
             .map(api_enums::Country::from_alpha2),
         business_label: payout_data.payout_attempt.business_label.clone(),
         setup_future_usage: None,
+        customer_email: None,
+        created_at: Some(payout_data.payouts.created_at),
     };
     let payment_method = dsl_inputs::PaymentMethodInput {
         payment_method: payout_data
@@ -192,9 +218,21 @@ pub fn make_dsl_input_for_payouts(
         metadata,
         payment,
         payment_method,
+        velocity: dsl_inputs::VelocityInput::default(),
+        issuer_data: None,
+        customer_device_data: None,
+        acquirer_data: None,
     })
 }
 
+/// Returns the email of the billing address in lowercase, so that rules on email domains match
+/// regardless of the case the email was given in
+fn get_customer_email(billing_address: Option<&Address>) -> Option<String> {
+    billing_address
+        .and_then(|billing_address| billing_address.email.as_ref())
+        .map(|email| email.peek().to_lowercase())
+}
+
 #[cfg(feature = "v2")]
 pub fn make_dsl_input(
     payments_dsl_input: &routing::PaymentsDslInput<'_>,
@@ -287,6 +325,8 @@ pub fn make_dsl_input(
             .map(api_enums::Country::from_alpha2),
         business_label: None,
         setup_future_usage: Some(payments_dsl_input.payment_intent.setup_future_usage),
+        customer_email: get_customer_email(payments_dsl_input.address.get_payment_method_billing()),
+        created_at: Some(payments_dsl_input.payment_intent.created_at),
     };
 
     let metadata = payments_dsl_input
@@ -304,6 +344,10 @@ pub fn make_dsl_input(
         payment: payment_input,
         payment_method: payment_method_input,
         mandate: mandate_data,
+        velocity: dsl_inputs::VelocityInput::default(),
+        issuer_data: None,
+        customer_device_data: None,
+        acquirer_data: None,
     })
 }
 
@@ -370,19 +414,30 @@ pub fn make_dsl_input(
                 domain::PaymentMethodData::Card(card) => card.card_network.clone(),
 
                 _ => None,
+            })
+            .or_else(|| {
+                payments_dsl_input
+                    .additional_card_info
+                    .and_then(|card_info| card_info.card_network.clone())
             }),
     };
 
     let payment_input = dsl_inputs::PaymentInput {
         amount: payments_dsl_input.payment_attempt.get_total_amount(),
-        card_bin: payments_dsl_input.payment_method_data.as_ref().and_then(
-            |pm_data| match pm_data {
+        card_bin: payments_dsl_input
+            .payment_method_data
+            .as_ref()
+            .and_then(|pm_data| match pm_data {
                 domain::PaymentMethodData::Card(card) => {
                     Some(card.card_number.peek().chars().take(6).collect())
                 }
                 _ => None,
-            },
-        ),
+            })
+            .or_else(|| {
+                payments_dsl_input
+                    .additional_card_info
+                    .and_then(|card_info| card_info.card_isin.clone())
+            }),
         currency: payments_dsl_input.currency,
         authentication_type: payments_dsl_input.payment_attempt.authentication_type,
         capture_method: payments_dsl_input
@@ -401,6 +456,8 @@ pub fn make_dsl_input(
             .map(api_enums::Country::from_alpha2),
         business_label: payments_dsl_input.payment_intent.business_label.clone(),
         setup_future_usage: payments_dsl_input.payment_intent.setup_future_usage,
+        customer_email: get_customer_email(payments_dsl_input.address.get_payment_method_billing()),
+        created_at: Some(payments_dsl_input.payment_intent.created_at),
     };
 
     let metadata = payments_dsl_input
@@ -415,6 +472,10 @@ pub fn make_dsl_input(
         payment: payment_input,
         payment_method: payment_method_input,
         mandate: mandate_data,
+        velocity: dsl_inputs::VelocityInput::default(),
+        issuer_data: None,
+        customer_device_data: None,
+        acquirer_data: None,
     })
 }
 
@@ -452,7 +513,53 @@ pub async fn perform_static_routing_v1(
     )
     .await?;
 
-    Ok(match cached_algorithm.as_ref() {
+    let CachedAlgorithm::Advanced(interpreter) = cached_algorithm.as_ref() else {
+        return perform_static_routing_with_algorithm(
+            state,
+            business_profile,
+            transaction_data,
+            cached_algorithm.as_ref(),
+        )
+        .await;
+    };
+
+    let backend_input =
+        make_static_routing_dsl_input(state, business_profile, transaction_data, interpreter)
+            .await?;
+
+    let de_euclid_connectors = perform_decision_euclid_routing(
+        state,
+        backend_input.clone(),
+        business_profile.get_id().get_string_repr().to_string(),
+    )
+    .await
+    .map_err(|e|
+        // errors are ignored as this is just for diff checking as of now (optional flow).
+        logger::error!(decision_engine_euclid_evaluate_error=?e, "decision_engine_euclid: error in evaluation of rule")
+    ).unwrap_or_default();
+    let routable_connectors =
+        execute_dsl_with_trace_and_get_connector_v1(backend_input, interpreter)?;
+    let connectors = routable_connectors
+        .iter()
+        .map(|c| c.connector.to_string())
+        .collect::<Vec<String>>();
+    utils::compare_and_log_result(
+        de_euclid_connectors,
+        connectors,
+        "evaluate_routing".to_string(),
+    );
+    Ok(routable_connectors)
+}
+
+/// Returns the connectors chosen by a static routing algorithm for a transaction, without
+/// comparing the result against the decision engine
+pub async fn perform_static_routing_with_algorithm(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    transaction_data: &routing::TransactionData<'_>,
+    algorithm: &CachedAlgorithm,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    Ok(match algorithm {
         CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
 
         CachedAlgorithm::Priority(plist) => plist.clone(),
@@ -461,39 +568,82 @@ pub async fn perform_static_routing_v1(
             .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
 
         CachedAlgorithm::Advanced(interpreter) => {
-            let backend_input = match transaction_data {
-                routing::TransactionData::Payment(payment_data) => make_dsl_input(payment_data)?,
-                #[cfg(feature = "payouts")]
-                routing::TransactionData::Payout(payout_data) => {
-                    make_dsl_input_for_payouts(payout_data)?
-                }
-            };
-
-            let de_euclid_connectors = perform_decision_euclid_routing(
+            let backend_input = make_static_routing_dsl_input(
                 state,
-                backend_input.clone(),
-                business_profile.get_id().get_string_repr().to_string(),
+                business_profile,
+                transaction_data,
+                interpreter,
             )
-            .await
-            .map_err(|e|
-                // errors are ignored as this is just for diff checking as of now (optional flow).
-                logger::error!(decision_engine_euclid_evaluate_error=?e, "decision_engine_euclid: error in evaluation of rule")
-            ).unwrap_or_default();
-            let routable_connectors = execute_dsl_and_get_connector_v1(backend_input, interpreter)?;
-            let connectors = routable_connectors
-                .iter()
-                .map(|c| c.connector.to_string())
-                .collect::<Vec<String>>();
-            utils::compare_and_log_result(
-                de_euclid_connectors,
-                connectors,
-                "evaluate_routing".to_string(),
-            );
-            routable_connectors
+            .await?;
+            execute_dsl_and_get_connector_v1(backend_input, interpreter)?
         }
     })
 }
 
+async fn make_static_routing_dsl_input(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    transaction_data: &routing::TransactionData<'_>,
+    interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+) -> RoutingResult<dsl_inputs::BackendInput> {
+    Ok(match transaction_data {
+        routing::TransactionData::Payment(payment_data) => {
+            let mut backend_input = make_dsl_input(payment_data)?;
+            if interpreter.uses_key(euclid_types::EuclidKey::is_velocity_key) {
+                backend_input.velocity =
+                    get_velocity_input(state, business_profile, payment_data).await;
+            }
+            backend_input
+        }
+        #[cfg(feature = "payouts")]
+        routing::TransactionData::Payout(payout_data) => make_dsl_input_for_payouts(payout_data)?,
+    })
+}
+
+/// Velocity of the customer and the card of the payment, for profiles with the card testing guard
+/// enabled
+#[cfg(feature = "v1")]
+async fn get_velocity_input(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    payments_dsl_input: &routing::PaymentsDslInput<'_>,
+) -> dsl_inputs::VelocityInput {
+    if business_profile.card_testing_guard_config.is_none() {
+        return dsl_inputs::VelocityInput::default();
+    }
+
+    let card_fingerprint = match (
+        payments_dsl_input.payment_method_data,
+        &business_profile.card_testing_secret_key,
+    ) {
+        (Some(domain::PaymentMethodData::Card(card)), Some(card_testing_secret_key)) => {
+            card_testing_guard_utils::generate_card_number_fingerprint(
+                card_testing_secret_key,
+                &card.card_number,
+            )
+        }
+        _ => None,
+    };
+
+    card_testing_guard_velocity::get_velocity_input(
+        state,
+        business_profile.get_id(),
+        payments_dsl_input.payment_intent.customer_id.as_ref(),
+        card_fingerprint.as_ref(),
+        payments_dsl_input.currency,
+    )
+    .await
+}
+
+#[cfg(feature = "v2")]
+async fn get_velocity_input(
+    _state: &SessionState,
+    _business_profile: &domain::Profile,
+    _payments_dsl_input: &routing::PaymentsDslInput<'_>,
+) -> dsl_inputs::VelocityInput {
+    dsl_inputs::VelocityInput::default()
+}
+
 async fn ensure_algorithm_cached_v1(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -573,15 +723,42 @@ pub fn perform_routing_for_single_straight_through_algorithm(
     })
 }
 
-fn execute_dsl_and_get_connector_v1(
+pub fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let routing_output: routing_types::RoutingAlgorithm = interpreter
+    let connector_selection = interpreter
         .execute(backend_input)
-        .map(|out| out.connector_selection.foreign_into())
+        .map(|out| out.connector_selection)
         .change_context(errors::RoutingError::DslExecutionError)?;
 
+    get_connectors_from_connector_selection(connector_selection)
+}
+
+/// Executes the rules like [`execute_dsl_and_get_connector_v1`], and logs the trace of the rules
+/// and comparisons which were evaluated, to explain why the connectors were chosen for a payment
+pub fn execute_dsl_with_trace_and_get_connector_v1(
+    backend_input: dsl_inputs::BackendInput,
+    interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let (output, trace) = interpreter
+        .execute_with_trace(backend_input)
+        .change_context(errors::RoutingError::DslExecutionError)?;
+
+    logger::info!(
+        routing_rule = ?output.rule_name,
+        routing_trace = %serde_json::to_string(&trace).unwrap_or_default(),
+        "Evaluated the routing rules"
+    );
+
+    get_connectors_from_connector_selection(output.connector_selection)
+}
+
+fn get_connectors_from_connector_selection(
+    connector_selection: ConnectorSelection,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let routing_output: routing_types::RoutingAlgorithm = connector_selection.foreign_into();
+
     Ok(match routing_output {
         routing_types::RoutingAlgorithm::Priority(plist) => plist,
 
@@ -612,20 +789,7 @@ pub async fn refresh_routing_cache_v1(
         algorithm
     };
 
-    let cached_algorithm = match algorithm {
-        routing_types::RoutingAlgorithm::Single(conn) => CachedAlgorithm::Single(conn),
-        routing_types::RoutingAlgorithm::Priority(plist) => CachedAlgorithm::Priority(plist),
-        routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
-            CachedAlgorithm::VolumeSplit(splits)
-        }
-        routing_types::RoutingAlgorithm::Advanced(program) => {
-            let interpreter = backend::VirInterpreterBackend::with_program(program)
-                .change_context(errors::RoutingError::DslBackendInitError)
-                .attach_printable("Error initializing DSL interpreter backend")?;
-
-            CachedAlgorithm::Advanced(interpreter)
-        }
-    };
+    let cached_algorithm = CachedAlgorithm::from_routing_algorithm(algorithm)?;
 
     let arc_cached_algorithm = Arc::new(cached_algorithm);
 
@@ -956,6 +1120,9 @@ pub async fn perform_fallback_routing(
     .await
 }
 
+/// Eligibility analysis of the connectors chosen by routing, followed by the fallback connectors.
+/// The connectors whose circuit breaker is open are removed before the fallback connectors are
+/// added, and are not added back by them.
 pub async fn perform_eligibility_analysis_with_fallback(
     state: &SessionState,
     key_store: &domain::MerchantKeyStore,
@@ -964,7 +1131,51 @@ pub async fn perform_eligibility_analysis_with_fallback(
     eligible_connectors: Option<Vec<api_enums::RoutableConnectors>>,
     business_profile: &domain::Profile,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let mut final_selection = perform_eligibility_analysis(
+    perform_eligibility_analysis_and_fallback(
+        state,
+        key_store,
+        chosen,
+        transaction_data,
+        eligible_connectors,
+        business_profile,
+        true,
+    )
+    .await
+}
+
+/// Eligibility analysis of the connectors chosen by routing, followed by the fallback connectors,
+/// regardless of the circuit breakers of the connectors. Used to evaluate routing against past
+/// payments, which the current state of the circuit breakers has no bearing on.
+pub async fn perform_static_eligibility_analysis_with_fallback(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    chosen: Vec<routing_types::RoutableConnectorChoice>,
+    transaction_data: &routing::TransactionData<'_>,
+    eligible_connectors: Option<Vec<api_enums::RoutableConnectors>>,
+    business_profile: &domain::Profile,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    perform_eligibility_analysis_and_fallback(
+        state,
+        key_store,
+        chosen,
+        transaction_data,
+        eligible_connectors,
+        business_profile,
+        false,
+    )
+    .await
+}
+
+async fn perform_eligibility_analysis_and_fallback(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    chosen: Vec<routing_types::RoutableConnectorChoice>,
+    transaction_data: &routing::TransactionData<'_>,
+    eligible_connectors: Option<Vec<api_enums::RoutableConnectors>>,
+    business_profile: &domain::Profile,
+    filter_open_circuit_breakers: bool,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let eligible_selection = perform_eligibility_analysis(
         state,
         key_store,
         chosen,
@@ -974,6 +1185,16 @@ pub async fn perform_eligibility_analysis_with_fallback(
     )
     .await?;
 
+    let mut final_selection = if filter_open_circuit_breakers {
+        connector_circuit_breaker::filter_connectors_with_open_circuit_breaker(
+            state,
+            eligible_selection.clone(),
+        )
+        .await
+    } else {
+        eligible_selection.clone()
+    };
+
     let fallback_selection = perform_fallback_routing(
         state,
         key_store,
@@ -983,12 +1204,13 @@ pub async fn perform_eligibility_analysis_with_fallback(
     )
     .await;
 
+    // Connectors removed by their circuit breaker are not added back by the fallback connectors
     final_selection.append(
         &mut fallback_selection
             .unwrap_or_default()
             .iter()
             .filter(|&routable_connector_choice| {
-                !final_selection.contains(routable_connector_choice)
+                !eligible_selection.contains(routable_connector_choice)
             })
             .cloned()
             .collect::<Vec<_>>(),
@@ -1045,6 +1267,8 @@ pub async fn perform_session_flow_routing<'a>(
         // business_label not available in payment_intent anymore
         business_label: None,
         setup_future_usage: Some(session_input.payment_intent.setup_future_usage),
+        customer_email: None,
+        created_at: Some(session_input.payment_intent.created_at),
     };
 
     let metadata = session_input
@@ -1063,6 +1287,10 @@ pub async fn perform_session_flow_routing<'a>(
             mandate_type: None,
             payment_type: None,
         },
+        velocity: dsl_inputs::VelocityInput::default(),
+        issuer_data: None,
+        customer_device_data: None,
+        acquirer_data: None,
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -1189,6 +1417,8 @@ pub async fn perform_session_flow_routing(
             .map(storage_enums::Country::from_alpha2),
         business_label: session_input.payment_intent.business_label.clone(),
         setup_future_usage: session_input.payment_intent.setup_future_usage,
+        customer_email: None,
+        created_at: Some(session_input.payment_intent.created_at),
     };
 
     let metadata = session_input
@@ -1207,6 +1437,10 @@ pub async fn perform_session_flow_routing(
             mandate_type: None,
             payment_type: None,
         },
+        velocity: dsl_inputs::VelocityInput::default(),
+        issuer_data: None,
+        customer_device_data: None,
+        acquirer_data: None,
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -1486,12 +1720,14 @@ pub fn make_dsl_input_for_surcharge(
         business_country: payment_intent
             .business_country
             .map(api_enums::Country::from_alpha2),
+        customer_email: get_customer_email(billing_address.as_ref()),
         billing_country: billing_address
             .and_then(|bic| bic.address)
             .and_then(|add| add.country)
             .map(api_enums::Country::from_alpha2),
         business_label: payment_intent.business_label.clone(),
         setup_future_usage: payment_intent.setup_future_usage,
+        created_at: Some(payment_intent.created_at),
     };
 
     let metadata = payment_intent
@@ -1509,6 +1745,10 @@ pub fn make_dsl_input_for_surcharge(
         payment: payment_input,
         payment_method: payment_method_input,
         mandate: mandate_data,
+        velocity: dsl_inputs::VelocityInput::default(),
+        issuer_data: None,
+        customer_device_data: None,
+        acquirer_data: None,
     };
     Ok(backend_input)
 }
@@ -713,64 +713,94 @@ impl From<RoutingAlgorithmRecord> for routing_algorithm::RoutingProfileMetadata
     }
 }
 use api_models::routing::{ConnectorSelection, RoutableConnectorChoice};
-impl From<ast::Program<ConnectorSelection>> for Program {
-    fn from(p: ast::Program<ConnectorSelection>) -> Self {
-        Self {
+/// The decision engine only evaluates the equality and number comparisons, so programs using the
+/// string pattern and list comparisons cannot be converted
+impl TryFrom<ast::Program<ConnectorSelection>> for Program {
+    type Error = error_stack::Report<errors::RoutingError>;
+
+    fn try_from(p: ast::Program<ConnectorSelection>) -> Result<Self, Self::Error> {
+        Ok(Self {
             globals: HashMap::new(),
             default_selection: convert_output(p.default_selection),
-            rules: p.rules.into_iter().map(convert_rule).collect(),
+            rules: p
+                .rules
+                .into_iter()
+                .map(convert_rule)
+                .collect::<Result<_, _>>()?,
             metadata: Some(p.metadata),
-        }
+        })
     }
 }
 
-fn convert_rule(rule: ast::Rule<ConnectorSelection>) -> Rule {
+fn convert_rule(rule: ast::Rule<ConnectorSelection>) -> RoutingResult<Rule> {
     let routing_type = match &rule.connector_selection {
         ConnectorSelection::Priority(_) => RoutingType::Priority,
         ConnectorSelection::VolumeSplit(_) => RoutingType::VolumeSplit,
     };
 
-    Rule {
+    Ok(Rule {
         name: rule.name,
         routing_type,
         output: convert_output(rule.connector_selection),
-        statements: rule.statements.into_iter().map(convert_if_stmt).collect(),
-    }
+        statements: rule
+            .statements
+            .into_iter()
+            .map(convert_if_stmt)
+            .collect::<Result<_, _>>()?,
+    })
 }
 
-fn convert_if_stmt(stmt: ast::IfStatement) -> IfStatement {
-    IfStatement {
-        condition: stmt.condition.into_iter().map(convert_comparison).collect(),
+fn convert_if_stmt(stmt: ast::IfStatement) -> RoutingResult<IfStatement> {
+    Ok(IfStatement {
+        condition: stmt
+            .condition
+            .into_iter()
+            .map(convert_comparison)
+            .collect::<Result<_, _>>()?,
         nested: stmt
             .nested
-            .map(|v| v.into_iter().map(convert_if_stmt).collect()),
-    }
+            .map(|v| {
+                v.into_iter()
+                    .map(convert_if_stmt)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?,
+    })
 }
 
-fn convert_comparison(c: ast::Comparison) -> Comparison {
-    Comparison {
+fn convert_comparison(c: ast::Comparison) -> RoutingResult<Comparison> {
+    Ok(Comparison {
         lhs: c.lhs,
-        comparison: convert_comparison_type(c.comparison),
-        value: convert_value(c.value),
+        comparison: convert_comparison_type(c.comparison)?,
+        value: convert_value(c.value)?,
         metadata: c.metadata,
-    }
+    })
 }
 
-fn convert_comparison_type(ct: ast::ComparisonType) -> ComparisonType {
+fn convert_comparison_type(ct: ast::ComparisonType) -> RoutingResult<ComparisonType> {
     match ct {
-        ast::ComparisonType::Equal => ComparisonType::Equal,
-        ast::ComparisonType::NotEqual => ComparisonType::NotEqual,
-        ast::ComparisonType::LessThan => ComparisonType::LessThan,
-        ast::ComparisonType::LessThanEqual => ComparisonType::LessThanEqual,
-        ast::ComparisonType::GreaterThan => ComparisonType::GreaterThan,
-        ast::ComparisonType::GreaterThanEqual => ComparisonType::GreaterThanEqual,
+        ast::ComparisonType::Equal => Ok(ComparisonType::Equal),
+        ast::ComparisonType::NotEqual => Ok(ComparisonType::NotEqual),
+        ast::ComparisonType::LessThan => Ok(ComparisonType::LessThan),
+        ast::ComparisonType::LessThanEqual => Ok(ComparisonType::LessThanEqual),
+        ast::ComparisonType::GreaterThan => Ok(ComparisonType::GreaterThan),
+        ast::ComparisonType::GreaterThanEqual => Ok(ComparisonType::GreaterThanEqual),
+        ast::ComparisonType::InList
+        | ast::ComparisonType::StartsWith
+        | ast::ComparisonType::EndsWith
+        | ast::ComparisonType::Contains
+        | ast::ComparisonType::MatchesRegex => Err(errors::RoutingError::GenericConversionError {
+            from: format!("{ct:?}"),
+            to: "decision engine comparison".to_string(),
+        }
+        .into()),
     }
 }
 
 #[allow(clippy::unimplemented)]
-fn convert_value(v: ast::ValueType) -> ValueType {
+fn convert_value(v: ast::ValueType) -> RoutingResult<ValueType> {
     use ast::ValueType::*;
-    match v {
+    Ok(match v {
         Number(n) => ValueType::Number(n.get_amount_as_i64().try_into().unwrap_or_default()),
         EnumVariant(e) => ValueType::EnumVariant(e),
         MetadataVariant(m) => ValueType::MetadataVariant(MetadataValue {
@@ -778,8 +808,14 @@ fn convert_value(v: ast::ValueType) -> ValueType {
             value: m.value,
         }),
         StrValue(s) => ValueType::StrValue(s),
+        StrValueArray(_) | MetadataVariantArray(_) => {
+            Err(errors::RoutingError::GenericConversionError {
+                from: "string array value".to_string(),
+                to: "decision engine value".to_string(),
+            })?
+        }
         _ => unimplemented!(), // GlobalRef(r) => ValueType::GlobalRef(r),
-    }
+    })
 }
 
 fn convert_output(sel: ConnectorSelection) -> Output {
@@ -170,6 +170,7 @@ where
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
     };
     Ok(router_data)
 }
@@ -390,6 +391,7 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: Some(merchant_connector_account.get_id()),
     };
 
     Ok(router_data)
@@ -553,6 +555,7 @@ pub async fn construct_payment_router_data_for_capture<'a>(
         psd2_sca_exemption_type: None,
         authentication_id: None,
         whole_connector_response: None,
+        merchant_connector_id: Some(merchant_connector_account.get_id()),
     };
 
     Ok(router_data)
@@ -681,6 +684,7 @@ pub async fn construct_router_data_for_psync<'a>(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: Some(merchant_connector_account.get_id()),
     };
 
     Ok(router_data)
@@ -842,6 +846,7 @@ pub async fn construct_payment_router_data_for_sdk_session<'a>(
         psd2_sca_exemption_type: None,
         authentication_id: None,
         whole_connector_response: None,
+        merchant_connector_id: Some(merchant_connector_account.get_id()),
     };
 
     Ok(router_data)
@@ -1050,6 +1055,7 @@ pub async fn construct_payment_router_data_for_setup_mandate<'a>(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: Some(merchant_connector_account.get_id()),
     };
 
     Ok(router_data)
@@ -1249,6 +1255,7 @@ where
         authentication_id: None,
         psd2_sca_exemption_type: payment_data.payment_intent.psd2_sca_exemption_type,
         whole_connector_response: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
     };
 
     Ok(router_data)
@@ -1439,6 +1446,7 @@ pub async fn construct_payment_router_data_for_update_metadata<'a>(
         authentication_id: None,
         psd2_sca_exemption_type: payment_data.payment_intent.psd2_sca_exemption_type,
         whole_connector_response: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
     };
 
     Ok(router_data)
@@ -0,0 +1,1064 @@
+use std::collections::{HashMap, HashSet};
+
+use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
+use actix_web::ResponseError;
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    generate_id, id_type,
+    types::{keymanager::KeyManagerState, MinorUnit},
+};
+use diesel_models::{enums as storage_enums, process_tracker::business_status};
+use error_stack::{report, ResultExt};
+use futures::{
+    future::join_all,
+    stream::{self, StreamExt, TryStreamExt},
+};
+use router_env::{instrument, logger, tracing};
+
+use super::{helpers, validator};
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+        utils as core_utils,
+    },
+    routes::{metrics, SessionState},
+    services,
+    types::{api, domain, storage, transformers::ForeignFrom},
+    utils::{self, OptionExt},
+};
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct PayoutBatchTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    pub batch_id: String,
+}
+
+/// A row of a batch, as received in the request
+#[derive(Debug, serde::Serialize)]
+pub enum PayoutBatchRow {
+    Parsed(Box<api::PayoutCreateRequest>),
+    /// The row of the CSV file could not be parsed, it is reported against its row number
+    /// instead of rejecting the whole file
+    Malformed(String),
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PayoutBatchRows {
+    pub profile_id: Option<id_type::ProfileId>,
+    pub description: Option<String>,
+    pub auto_fulfill: Option<bool>,
+    pub rows: Vec<PayoutBatchRow>,
+}
+
+impl ApiEventMetric for PayoutBatchRows {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PayoutBatch { batch_id: None })
+    }
+}
+
+impl From<api::PayoutBatchCreateRequest> for PayoutBatchRows {
+    fn from(request: api::PayoutBatchCreateRequest) -> Self {
+        Self {
+            profile_id: request.profile_id,
+            description: request.description,
+            auto_fulfill: request.auto_fulfill,
+            rows: request
+                .payouts
+                .into_iter()
+                .map(|payout| PayoutBatchRow::Parsed(Box::new(payout)))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, MultipartForm)]
+pub struct PayoutBatchUploadForm {
+    #[multipart(limit = "1MB")]
+    pub file: Bytes,
+    pub profile_id: Option<Text<id_type::ProfileId>>,
+    pub description: Option<Text<String>>,
+    pub auto_fulfill: Option<Text<bool>>,
+}
+
+pub fn get_payout_batch_upload_rows(form: PayoutBatchUploadForm) -> PayoutBatchRows {
+    PayoutBatchRows {
+        profile_id: form.profile_id.map(|profile_id| profile_id.0),
+        description: form.description.map(|description| description.0),
+        auto_fulfill: form.auto_fulfill.map(|auto_fulfill| auto_fulfill.0),
+        rows: parse_payout_batch_csv(form.file.data.as_ref()),
+    }
+}
+
+fn parse_payout_batch_csv(data: &[u8]) -> Vec<PayoutBatchRow> {
+    csv::Reader::from_reader(data)
+        .deserialize::<api::PayoutBatchUploadRecord>()
+        .map(|record| {
+            record
+                .map_err(|error| error.to_string())
+                .and_then(api::PayoutCreateRequest::try_from)
+                .map_or_else(PayoutBatchRow::Malformed, |payout| {
+                    PayoutBatchRow::Parsed(Box::new(payout))
+                })
+        })
+        .collect()
+}
+
+#[instrument(skip_all)]
+pub async fn payout_batch_create_core(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: PayoutBatchRows,
+) -> RouterResponse<api::PayoutBatchResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let max_rows = usize::try_from(state.conf.payouts.batch.max_rows).unwrap_or(usize::MAX);
+
+    utils::when(req.rows.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "A batch must have at least one payout".to_string(),
+        }))
+    })?;
+    utils::when(req.rows.len() > max_rows, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("A batch can have at most {max_rows} payouts"),
+        }))
+    })?;
+
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        &(&state).into(),
+        None,
+        None,
+        &merchant_context,
+        req.profile_id.as_ref(),
+        db,
+        true,
+    )
+    .await?;
+    let auto_fulfill = req.auto_fulfill.unwrap_or(true);
+
+    let mut provided_payout_ids = HashSet::new();
+    let mut customer_ids = HashSet::new();
+    let mut payout_requests = Vec::with_capacity(req.rows.len());
+    let mut items = Vec::with_capacity(req.rows.len());
+    // Customer of each row which has to exist already, aligned with the items
+    let mut required_customer_ids = Vec::with_capacity(req.rows.len());
+    for (row_number, row) in (1..).zip(req.rows) {
+        let mut payout_request = match row {
+            PayoutBatchRow::Parsed(payout_request) => payout_request,
+            PayoutBatchRow::Malformed(error) => {
+                items.push(storage::PayoutBatchItem {
+                    row_number,
+                    payout_id: None,
+                    customer_id: None,
+                    amount: None,
+                    currency: None,
+                    status: storage_enums::PayoutBatchItemStatus::Failed,
+                    payout_status: None,
+                    validation_errors: vec![error],
+                    error_code: None,
+                    error_message: None,
+                });
+                required_customer_ids.push(None);
+                continue;
+            }
+        };
+
+        let mut validation_errors =
+            validate_payout_batch_row(&payout_request, merchant_id, &profile_id);
+
+        // The payout ids are assigned up front, so that a payout is never created twice when the
+        // scheduler task runs again after a failure
+        let payout_id = match payout_request.payout_id.as_ref() {
+            Some(provided_payout_id) => {
+                if core_utils::validate_uuid(provided_payout_id.to_owned(), "payout_id").is_err() {
+                    validation_errors.push("payout_id must be a valid UUID".to_string());
+                } else if !provided_payout_ids.insert(provided_payout_id.to_owned()) {
+                    validation_errors.push("payout_id is repeated in the batch".to_string());
+                }
+                provided_payout_id.to_owned()
+            }
+            None => utils::generate_uuid(),
+        };
+
+        // The payout creates the customer when the details of the customer are provided
+        let required_customer_id = payout_request
+            .get_customer_id()
+            .filter(|_| !has_customer_details(&payout_request))
+            .cloned();
+        if let Some(customer_id) = required_customer_id.as_ref() {
+            customer_ids.insert(customer_id.to_owned());
+        }
+        required_customer_ids.push(required_customer_id);
+
+        items.push(storage::PayoutBatchItem {
+            row_number,
+            payout_id: Some(payout_id.clone()),
+            customer_id: payout_request.get_customer_id().cloned(),
+            amount: payout_request.amount.map(MinorUnit::from),
+            currency: payout_request.currency,
+            status: storage_enums::PayoutBatchItemStatus::Pending,
+            payout_status: None,
+            validation_errors,
+            error_code: None,
+            error_message: None,
+        });
+
+        payout_request.payout_id = Some(payout_id);
+        payout_request.profile_id = Some(profile_id.clone());
+        payout_request.confirm = Some(true);
+        payout_request.auto_fulfill = Some(auto_fulfill);
+        payout_request.payout_type = payout_request.payout_type.or(payout_request
+            .payout_method_data
+            .as_ref()
+            .map(api_models::enums::PayoutType::foreign_from));
+        payout_requests.push(*payout_request);
+    }
+
+    let (existing_payout_ids, missing_customer_ids) = futures::try_join!(
+        find_existing_payout_ids(&state, &merchant_context, &provided_payout_ids),
+        find_missing_customer_ids(&state, &merchant_context, &customer_ids),
+    )?;
+    for (item, required_customer_id) in items.iter_mut().zip(required_customer_ids) {
+        if item
+            .payout_id
+            .as_ref()
+            .is_some_and(|payout_id| existing_payout_ids.contains(payout_id))
+        {
+            item.validation_errors
+                .push("A payout with this payout_id already exists".to_string());
+        }
+        if required_customer_id
+            .is_some_and(|customer_id| missing_customer_ids.contains(&customer_id))
+        {
+            item.validation_errors
+                .push("customer_id does not belong to an existing customer".to_string());
+        }
+        if !item.validation_errors.is_empty() {
+            item.status = storage_enums::PayoutBatchItemStatus::Failed;
+        }
+    }
+
+    let failed_count = items
+        .iter()
+        .filter(|item| item.status == storage_enums::PayoutBatchItemStatus::Failed)
+        .count();
+    let is_valid = failed_count == 0;
+
+    // The payouts of a batch with invalid rows are never created, the requests are not stored
+    let encrypted_payout_requests = if is_valid {
+        Some(
+            cards::create_encrypted_data(
+                &(&state).into(),
+                merchant_context.get_merchant_key_store(),
+                payout_requests,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to encrypt the payout requests of the batch")?
+            .into(),
+        )
+    } else {
+        None
+    };
+
+    let now = common_utils::date_time::now();
+    let payout_batch = storage::PayoutBatchNew {
+        batch_id: generate_id(consts::ID_LENGTH, "payout_batch"),
+        merchant_id: merchant_id.to_owned(),
+        profile_id,
+        status: if is_valid {
+            storage_enums::PayoutBatchStatus::Scheduled
+        } else {
+            storage_enums::PayoutBatchStatus::ValidationFailed
+        },
+        description: req.description,
+        auto_fulfill,
+        total_count: get_count(items.len()),
+        succeeded_count: 0,
+        failed_count: get_count(failed_count),
+        payout_requests: encrypted_payout_requests,
+        items: storage::PayoutBatchItems(items),
+        created_at: now,
+        last_modified_at: now,
+    };
+    let payout_batch = db
+        .insert_payout_batch(payout_batch)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Payout batch already exists".to_string(),
+        })?;
+
+    if is_valid {
+        add_payout_batch_task(&state, &payout_batch).await?;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        api::PayoutBatchResponse::foreign_from((payout_batch, true)),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payout_batch_retrieve_core(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: api::PayoutBatchRetrieveRequest,
+) -> RouterResponse<api::PayoutBatchResponse> {
+    let payout_batch = state
+        .store
+        .find_payout_batch_by_merchant_id_batch_id(
+            merchant_context.get_merchant_account().get_id(),
+            &req.batch_id,
+        )
+        .await
+        .to_not_found_response(payout_batch_not_found(&req.batch_id))?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::PayoutBatchResponse::foreign_from((payout_batch, true)),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payout_batch_list_core(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    constraints: api::PayoutBatchListConstraints,
+) -> RouterResponse<api::PayoutBatchListResponse> {
+    let payout_batches = state
+        .store
+        .list_payout_batches_by_merchant_id(
+            merchant_context.get_merchant_account().get_id(),
+            Some(i64::from(
+                constraints
+                    .limit
+                    .min(common_utils::consts::PAYOUTS_LIST_MAX_LIMIT_GET),
+            )),
+            constraints.offset.map(i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payout batches")?;
+
+    let data = payout_batches
+        .into_iter()
+        .map(|payout_batch| api::PayoutBatchResponse::foreign_from((payout_batch, false)))
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        api::PayoutBatchListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Creates the payouts of the next pending rows of the batch, at most `chunk_size` of them, and
+/// records their outcome. The batch is left in `processing` status while rows remain pending.
+///
+/// Rows whose payout failed with a server error are left pending and an error is returned after
+/// the outcome of the other rows is recorded, so that the scheduler task retries them later.
+#[instrument(skip_all, fields(batch_id = %payout_batch.batch_id))]
+pub async fn execute_payout_batch_chunk(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_batch: storage::PayoutBatch,
+) -> RouterResult<storage::PayoutBatch> {
+    let config = &state.conf.payouts.batch;
+    let chunk_size = usize::try_from(config.chunk_size).unwrap_or(usize::MAX);
+    let concurrency = usize::try_from(config.concurrency).unwrap_or(usize::MAX);
+
+    let mut payout_requests = cards::decrypt_generic_data::<Vec<api::PayoutCreateRequest>>(
+        state,
+        payout_batch.payout_requests.clone(),
+        merchant_context.get_merchant_key_store(),
+    )
+    .await?
+    .get_required_value("payout_requests")?
+    .into_iter()
+    .filter_map(|payout_request| {
+        payout_request
+            .payout_id
+            .clone()
+            .map(|payout_id| (payout_id, payout_request))
+    })
+    .collect::<HashMap<_, _>>();
+
+    let mut items = payout_batch.items.0;
+    let mut pending_items = items
+        .iter_mut()
+        .filter(|item| item.status == storage_enums::PayoutBatchItemStatus::Pending)
+        .take(chunk_size)
+        .collect::<Vec<_>>();
+
+    let mut transient_failures = 0;
+    for items_chunk in pending_items.chunks_mut(concurrency) {
+        let outcomes = join_all(items_chunk.iter().map(|item| {
+            let payout_request = item
+                .payout_id
+                .as_ref()
+                .and_then(|payout_id| payout_requests.remove(payout_id));
+            create_payout_for_batch_item(
+                state,
+                merchant_context,
+                &payout_batch.profile_id,
+                payout_request,
+            )
+        }))
+        .await;
+
+        for (item, outcome) in items_chunk.iter_mut().zip(outcomes) {
+            match outcome {
+                Ok(payout_response) => {
+                    item.status = if helpers::is_payout_err_state(payout_response.status) {
+                        storage_enums::PayoutBatchItemStatus::Failed
+                    } else {
+                        storage_enums::PayoutBatchItemStatus::Succeeded
+                    };
+                    item.payout_status = Some(payout_response.status);
+                    item.error_code = payout_response.error_code;
+                    item.error_message = payout_response.error_message;
+                }
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        row_number = item.row_number,
+                        "Failed to create the payout of a batch row"
+                    );
+                    if error.current_context().status_code().is_server_error() {
+                        transient_failures += 1;
+                    } else {
+                        item.status = storage_enums::PayoutBatchItemStatus::Failed;
+                    }
+                    item.error_code = Some(error.current_context().error_code());
+                    item.error_message = Some(error.current_context().error_message());
+                }
+            }
+        }
+    }
+
+    let payout_batch = update_payout_batch_items(state, &payout_batch, items).await?;
+
+    utils::when(transient_failures > 0, || {
+        Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable(format!(
+            "Failed to create the payouts of {transient_failures} batch rows, they will be retried"
+        ))
+    })?;
+
+    Ok(payout_batch)
+}
+
+/// Marks the rows of the batch which are still pending as failed, once the scheduler task has
+/// run out of retries
+#[instrument(skip_all, fields(batch_id = %batch_id))]
+pub async fn fail_pending_payout_batch_rows(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    batch_id: &str,
+) -> RouterResult<()> {
+    let payout_batch = state
+        .store
+        .find_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+        .await
+        .to_not_found_response(payout_batch_not_found(batch_id))?;
+    if is_payout_batch_complete(&payout_batch) {
+        return Ok(());
+    }
+
+    let mut items = payout_batch.items.0.clone();
+    for item in items
+        .iter_mut()
+        .filter(|item| item.status == storage_enums::PayoutBatchItemStatus::Pending)
+    {
+        item.status = storage_enums::PayoutBatchItemStatus::Failed;
+        item.error_message
+            .get_or_insert_with(|| "The payout of the row could not be created".to_string());
+    }
+
+    update_payout_batch_items(state, &payout_batch, items)
+        .await
+        .map(|_| ())
+}
+
+async fn update_payout_batch_items(
+    state: &SessionState,
+    payout_batch: &storage::PayoutBatch,
+    items: Vec<storage::PayoutBatchItem>,
+) -> RouterResult<storage::PayoutBatch> {
+    let count_items = |status| items.iter().filter(|item| item.status == status).count();
+    let succeeded_count = count_items(storage_enums::PayoutBatchItemStatus::Succeeded);
+    let failed_count = count_items(storage_enums::PayoutBatchItemStatus::Failed);
+    let status = if count_items(storage_enums::PayoutBatchItemStatus::Pending) > 0 {
+        storage_enums::PayoutBatchStatus::Processing
+    } else if failed_count == 0 {
+        storage_enums::PayoutBatchStatus::Succeeded
+    } else if succeeded_count == 0 {
+        storage_enums::PayoutBatchStatus::Failed
+    } else {
+        storage_enums::PayoutBatchStatus::PartiallySucceeded
+    };
+
+    state
+        .store
+        .update_payout_batch_by_merchant_id_batch_id(
+            &payout_batch.merchant_id,
+            &payout_batch.batch_id,
+            storage::PayoutBatchUpdate::ItemsUpdate {
+                status,
+                succeeded_count: get_count(succeeded_count),
+                failed_count: get_count(failed_count),
+                items: storage::PayoutBatchItems(items),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the outcome of the payout batch rows")
+}
+
+async fn create_payout_for_batch_item(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: &id_type::ProfileId,
+    payout_request: Option<api::PayoutCreateRequest>,
+) -> RouterResult<api::PayoutCreateResponse> {
+    let payout_request = payout_request
+        .get_required_value("payout_request")
+        .attach_printable("Payout request of the batch row not found")?;
+    let payout_id = payout_request
+        .payout_id
+        .clone()
+        .get_required_value("payout_id")?;
+
+    let response = match Box::pin(super::payouts_create_core(
+        state.clone(),
+        merchant_context.clone(),
+        payout_request,
+    ))
+    .await
+    {
+        // The payout was created by an earlier run of the task, which failed before recording
+        // the outcome of the row
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::DuplicatePayout { .. }
+            ) =>
+        {
+            Box::pin(super::payouts_retrieve_core(
+                state.clone(),
+                merchant_context.clone(),
+                Some(profile_id.to_owned()),
+                api::PayoutRetrieveRequest {
+                    payout_id,
+                    force_sync: None,
+                    merchant_id: None,
+                },
+            ))
+            .await?
+        }
+        response => response?,
+    };
+
+    match response {
+        services::ApplicationResponse::Json(payout_response) => Ok(payout_response),
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response while creating the payout of a batch row"),
+    }
+}
+
+fn validate_payout_batch_row(
+    payout_request: &api::PayoutCreateRequest,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+) -> Vec<String> {
+    let mut validation_errors = Vec::new();
+
+    match payout_request.amount.map(MinorUnit::from) {
+        None => validation_errors.push("amount is required".to_string()),
+        Some(amount) if amount.get_amount_as_i64() <= 0 => {
+            validation_errors.push("amount must be greater than 0".to_string())
+        }
+        Some(_) => {}
+    }
+
+    if payout_request.currency.is_none() {
+        validation_errors.push("currency is required".to_string());
+    }
+
+    if payout_request.get_customer_id().is_none() {
+        validation_errors.push("customer_id is required".to_string());
+    }
+
+    let payout_method_sources = [
+        payout_request.payout_method_data.is_some(),
+        payout_request.payout_method_id.is_some(),
+        payout_request.payout_token.is_some(),
+    ]
+    .into_iter()
+    .filter(|is_present| *is_present)
+    .count();
+    if payout_method_sources != 1 {
+        validation_errors.push(
+            "Exactly one of payout_method_data, payout_method_id or payout_token is required"
+                .to_string(),
+        );
+    }
+
+    if let (Some(payout_type), Some(payout_method_data)) = (
+        payout_request.payout_type,
+        payout_request.payout_method_data.as_ref(),
+    ) {
+        let payout_method_type = api_models::enums::PayoutType::foreign_from(payout_method_data);
+        if payout_type != payout_method_type {
+            validation_errors.push(format!(
+                "payout_type `{payout_type}` does not match the payout_method_data of type `{payout_method_type}`"
+            ));
+        }
+    }
+
+    if payout_request
+        .merchant_id
+        .as_ref()
+        .is_some_and(|request_merchant_id| request_merchant_id != merchant_id)
+    {
+        validation_errors.push("merchant_id does not match the merchant of the batch".to_string());
+    }
+
+    if payout_request
+        .profile_id
+        .as_ref()
+        .is_some_and(|request_profile_id| request_profile_id != profile_id)
+    {
+        validation_errors.push("profile_id does not match the profile of the batch".to_string());
+    }
+
+    if payout_request.payout_link == Some(true) {
+        validation_errors.push("Payout links are not supported in a batch".to_string());
+    }
+
+    if payout_request.confirm == Some(false) {
+        validation_errors.push("The payouts of a batch are always confirmed".to_string());
+    }
+
+    validation_errors
+}
+
+fn has_customer_details(payout_request: &api::PayoutCreateRequest) -> bool {
+    payout_request.email.is_some()
+        || payout_request.name.is_some()
+        || payout_request.phone.is_some()
+        || payout_request.phone_country_code.is_some()
+        || payout_request.customer.as_ref().is_some_and(|customer| {
+            customer.email.is_some()
+                || customer.name.is_some()
+                || customer.phone.is_some()
+                || customer.phone_country_code.is_some()
+        })
+}
+
+async fn find_existing_payout_ids(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_ids: &HashSet<String>,
+) -> RouterResult<HashSet<String>> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    stream::iter(payout_ids)
+        .map(|payout_id| async move {
+            validator::validate_uniqueness_of_payout_id_against_merchant_id(
+                db,
+                payout_id,
+                merchant_id,
+                storage_scheme,
+            )
+            .await
+            .map(|payout| payout.map(|_| payout_id.to_owned()))
+        })
+        .buffer_unordered(get_lookup_concurrency(state))
+        .try_filter_map(|payout_id| async move { Ok(payout_id) })
+        .try_collect()
+        .await
+}
+
+async fn find_missing_customer_ids(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_ids: &HashSet<id_type::CustomerId>,
+) -> RouterResult<HashSet<id_type::CustomerId>> {
+    let key_manager_state: &KeyManagerState = &state.into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key_store = merchant_context.get_merchant_key_store();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    stream::iter(customer_ids)
+        .map(|customer_id| async move {
+            state
+                .store
+                .find_customer_optional_by_customer_id_merchant_id(
+                    key_manager_state,
+                    customer_id,
+                    merchant_id,
+                    key_store,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the customer of a payout batch row")
+                .map(|customer| customer.is_none().then(|| customer_id.to_owned()))
+        })
+        .buffer_unordered(get_lookup_concurrency(state))
+        .try_filter_map(|customer_id| async move { Ok(customer_id) })
+        .try_collect()
+        .await
+}
+
+fn get_lookup_concurrency(state: &SessionState) -> usize {
+    usize::try_from(state.conf.payouts.batch.concurrency).unwrap_or(usize::MAX)
+}
+
+async fn add_payout_batch_task(
+    state: &SessionState,
+    payout_batch: &storage::PayoutBatch,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::PayoutBatchWorkflow;
+    let task = "PAYOUT_BATCH";
+    let tag = ["PAYOUTS", "BATCH"];
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        task,
+        &payout_batch.batch_id,
+        &payout_batch.merchant_id,
+    );
+    let tracking_data = PayoutBatchTrackingData {
+        merchant_id: payout_batch.merchant_id.clone(),
+        batch_id: payout_batch.batch_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payout batch process tracker task")?;
+
+    let attributes = router_env::metric_attributes!(("flow", "PayoutBatch"));
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            metrics::TASKS_ADDED_COUNT.add(1, attributes);
+            Ok(())
+        }
+        Err(error) => {
+            metrics::TASK_ADDITION_FAILURES_COUNT.add(1, attributes);
+            Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to add payout batch process tracker task")
+        }
+    }
+}
+
+/// Whether the process tracker task of the batch has nothing left to do
+pub fn is_payout_batch_complete(payout_batch: &storage::PayoutBatch) -> bool {
+    !matches!(
+        payout_batch.status,
+        storage_enums::PayoutBatchStatus::Scheduled | storage_enums::PayoutBatchStatus::Processing
+    )
+}
+
+pub fn get_payout_batch_business_status(payout_batch: &storage::PayoutBatch) -> &'static str {
+    match payout_batch.status {
+        storage_enums::PayoutBatchStatus::Failed => business_status::FAILURE,
+        _ => business_status::COMPLETED_BY_PT,
+    }
+}
+
+fn get_count(count: usize) -> i32 {
+    i32::try_from(count).unwrap_or(i32::MAX)
+}
+
+fn payout_batch_not_found(batch_id: &str) -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Payout batch `{batch_id}` does not exist"),
+    }
+}
+
+impl ForeignFrom<(storage::PayoutBatch, bool)> for api::PayoutBatchResponse {
+    fn foreign_from((payout_batch, include_items): (storage::PayoutBatch, bool)) -> Self {
+        let mut totals: Vec<api::PayoutBatchCurrencyTotal> = Vec::new();
+        for item in payout_batch.items.0.iter() {
+            let (Some(amount), Some(currency)) = (item.amount, item.currency) else {
+                continue;
+            };
+            if !totals.iter().any(|total| total.currency == currency) {
+                totals.push(api::PayoutBatchCurrencyTotal {
+                    currency,
+                    total_amount: MinorUnit::zero(),
+                    succeeded_amount: MinorUnit::zero(),
+                    failed_amount: MinorUnit::zero(),
+                });
+            }
+            let Some(total) = totals.iter_mut().find(|total| total.currency == currency) else {
+                continue;
+            };
+            total.total_amount = total.total_amount + amount;
+            match item.status {
+                storage_enums::PayoutBatchItemStatus::Succeeded => {
+                    total.succeeded_amount = total.succeeded_amount + amount
+                }
+                storage_enums::PayoutBatchItemStatus::Failed => {
+                    total.failed_amount = total.failed_amount + amount
+                }
+                storage_enums::PayoutBatchItemStatus::Pending => {}
+            }
+        }
+
+        Self {
+            batch_id: payout_batch.batch_id,
+            merchant_id: payout_batch.merchant_id,
+            profile_id: payout_batch.profile_id,
+            status: payout_batch.status,
+            description: payout_batch.description,
+            auto_fulfill: payout_batch.auto_fulfill,
+            total_count: payout_batch.total_count,
+            succeeded_count: payout_batch.succeeded_count,
+            failed_count: payout_batch.failed_count,
+            totals,
+            items: include_items.then(|| {
+                payout_batch
+                    .items
+                    .0
+                    .into_iter()
+                    .map(|item| api::PayoutBatchItemResponse {
+                        row_number: item.row_number,
+                        payout_id: item.payout_id,
+                        customer_id: item.customer_id,
+                        amount: item.amount,
+                        currency: item.currency,
+                        status: item.status,
+                        payout_status: item.payout_status,
+                        validation_errors: item.validation_errors,
+                        error_code: item.error_code,
+                        error_message: item.error_message,
+                    })
+                    .collect()
+            }),
+            created: payout_batch.created_at,
+            last_modified: payout_batch.last_modified_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::borrow::Cow;
+
+    use super::*;
+
+    const CSV_HEADER: &str = "payout_id,amount,currency,customer_id,email,name,payout_type,payout_method_id,payout_token,payout_method_data,description";
+
+    fn merchant_id() -> id_type::MerchantId {
+        id_type::MerchantId::try_from(Cow::from("merchant_1")).expect("Invalid merchant id")
+    }
+
+    fn profile_id() -> id_type::ProfileId {
+        id_type::ProfileId::try_from(Cow::from("pro_1")).expect("Invalid profile id")
+    }
+
+    fn valid_payout_request() -> api::PayoutCreateRequest {
+        api::PayoutCreateRequest {
+            amount: Some(api_models::payments::Amount::from(MinorUnit::new(1000))),
+            currency: Some(api_models::enums::Currency::USD),
+            customer_id: Some(
+                id_type::CustomerId::try_from(Cow::from("cus_1")).expect("Invalid customer id"),
+            ),
+            payout_method_id: Some("pm_1".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn parse_rows(rows: &[&str]) -> Vec<PayoutBatchRow> {
+        let csv = std::iter::once(CSV_HEADER)
+            .chain(rows.iter().copied())
+            .collect::<Vec<_>>()
+            .join("\n");
+        parse_payout_batch_csv(csv.as_bytes())
+    }
+
+    #[test]
+    fn test_valid_payout_batch_row() {
+        let validation_errors =
+            validate_payout_batch_row(&valid_payout_request(), &merchant_id(), &profile_id());
+        assert!(validation_errors.is_empty(), "{validation_errors:?}");
+    }
+
+    #[test]
+    fn test_invalid_payout_batch_rows() {
+        let assert_invalid = |payout_request: api::PayoutCreateRequest, error: &str| {
+            let validation_errors =
+                validate_payout_batch_row(&payout_request, &merchant_id(), &profile_id());
+            assert!(
+                validation_errors
+                    .iter()
+                    .any(|validation_error| validation_error.contains(error)),
+                "expected `{error}` in {validation_errors:?}"
+            );
+        };
+
+        assert_invalid(
+            api::PayoutCreateRequest {
+                amount: None,
+                ..valid_payout_request()
+            },
+            "amount is required",
+        );
+        assert_invalid(
+            api::PayoutCreateRequest {
+                amount: Some(api_models::payments::Amount::from(MinorUnit::new(0))),
+                ..valid_payout_request()
+            },
+            "amount must be greater than 0",
+        );
+        assert_invalid(
+            api::PayoutCreateRequest {
+                currency: None,
+                ..valid_payout_request()
+            },
+            "currency is required",
+        );
+        assert_invalid(
+            api::PayoutCreateRequest {
+                customer_id: None,
+                ..valid_payout_request()
+            },
+            "customer_id is required",
+        );
+        assert_invalid(
+            api::PayoutCreateRequest {
+                payout_method_id: None,
+                ..valid_payout_request()
+            },
+            "Exactly one of payout_method_data",
+        );
+        assert_invalid(
+            api::PayoutCreateRequest {
+                payout_token: Some("token_1".to_string()),
+                ..valid_payout_request()
+            },
+            "Exactly one of payout_method_data",
+        );
+        assert_invalid(
+            api::PayoutCreateRequest {
+                payout_method_id: None,
+                payout_type: Some(api_models::enums::PayoutType::Bank),
+                payout_method_data: Some(api_models::payouts::PayoutMethodData::default()),
+                ..valid_payout_request()
+            },
+            "does not match the payout_method_data",
+        );
+        assert_invalid(
+            api::PayoutCreateRequest {
+                merchant_id: Some(
+                    id_type::MerchantId::try_from(Cow::from("merchant_2"))
+                        .expect("Invalid merchant id"),
+                ),
+                ..valid_payout_request()
+            },
+            "merchant_id does not match",
+        );
+        assert_invalid(
+            api::PayoutCreateRequest {
+                profile_id: Some(
+                    id_type::ProfileId::try_from(Cow::from("pro_2")).expect("Invalid profile id"),
+                ),
+                ..valid_payout_request()
+            },
+            "profile_id does not match",
+        );
+        assert_invalid(
+            api::PayoutCreateRequest {
+                payout_link: Some(true),
+                ..valid_payout_request()
+            },
+            "Payout links are not supported",
+        );
+        assert_invalid(
+            api::PayoutCreateRequest {
+                confirm: Some(false),
+                ..valid_payout_request()
+            },
+            "always confirmed",
+        );
+    }
+
+    #[test]
+    fn test_parse_payout_batch_csv() {
+        let rows = parse_rows(&[
+            ",1000,USD,cus_1,,,,pm_1,,,Salary",
+            r#",2000,EUR,cus_2,,,card,,,"{""card"":{""card_number"":""4111111111111111"",""expiry_month"":""10"",""expiry_year"":""30""}}","#,
+        ]);
+
+        let payout_requests = rows
+            .iter()
+            .filter_map(|row| match row {
+                PayoutBatchRow::Parsed(payout_request) => Some(payout_request),
+                PayoutBatchRow::Malformed(_) => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(payout_requests.len(), 2, "{rows:?}");
+
+        let first = payout_requests.first().expect("First row");
+        assert_eq!(first.payout_id, None);
+        assert_eq!(
+            first.amount.map(MinorUnit::from),
+            Some(MinorUnit::new(1000))
+        );
+        assert_eq!(first.currency, Some(api_models::enums::Currency::USD));
+        assert_eq!(first.payout_method_id.as_deref(), Some("pm_1"));
+        assert_eq!(first.description.as_deref(), Some("Salary"));
+        assert!(first.payout_method_data.is_none());
+
+        let second = payout_requests.get(1).expect("Second row");
+        assert_eq!(
+            second.payout_type,
+            Some(api_models::enums::PayoutType::Card)
+        );
+        assert!(matches!(
+            second.payout_method_data,
+            Some(api_models::payouts::PayoutMethodData::Card(_))
+        ));
+        assert!(validate_payout_batch_row(second, &merchant_id(), &profile_id()).is_empty());
+    }
+
+    #[test]
+    fn test_malformed_payout_batch_csv_rows() {
+        let rows = parse_rows(&[
+            ",not_a_number,USD,cus_1,,,,pm_1,,,",
+            ",1000,USD,cus_1,,,card,,,{not json},",
+            ",1000,USD,cus_1,,,,pm_1,,,",
+        ]);
+
+        let malformed_rows = rows
+            .iter()
+            .map(|row| match row {
+                PayoutBatchRow::Parsed(_) => None,
+                PayoutBatchRow::Malformed(error) => Some(error.as_str()),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(malformed_rows.len(), 3);
+        assert!(malformed_rows.first().is_some_and(Option::is_some));
+        assert!(malformed_rows
+            .get(1)
+            .copied()
+            .flatten()
+            .is_some_and(|error| error.starts_with("invalid payout_method_data")));
+        assert_eq!(malformed_rows.get(2), Some(&None));
+    }
+}
@@ -0,0 +1,452 @@
+use common_utils::{fp_utils, id_type};
+use error_stack::{report, ResultExt};
+use redis_interface::RedisKey;
+use router_env::{instrument, logger, tracing};
+
+use super::errors::{self, RouterResult};
+use crate::{
+    configs::settings::{RateLimit, RateLimitAlgorithm, RateLimitSettings},
+    headers,
+    routes::{app::SessionStateInfo, lock_utils, metrics, SessionState},
+    services::authentication::AuthenticationType,
+};
+
+pub const RATE_LIMIT_PREFIX: &str = "RATE_LIMIT";
+
+/// Consumes a token from each of the buckets in `KEYS` if all of them have a token available.
+///
+/// `ARGV` holds the capacity and the refill window (in milliseconds) of each bucket. Returns the
+/// outcome along with the limit, remaining tokens, reset time and retry time (in milliseconds) of
+/// the most restrictive bucket.
+const TOKEN_BUCKET_SCRIPT: &str = r#"
+local time = redis.call("TIME")
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+
+local tokens = {}
+local allowed = 1
+for i = 1, #KEYS do
+    local capacity = tonumber(ARGV[2 * i - 1])
+    local window = tonumber(ARGV[2 * i])
+    local bucket = redis.call("HMGET", KEYS[i], "tokens", "updated_at")
+    local available = tonumber(bucket[1])
+    local updated_at = tonumber(bucket[2])
+    if available == nil or updated_at == nil then
+        available = capacity
+    else
+        available = math.min(capacity, available + math.max(0, now - updated_at) * capacity / window)
+    end
+    if available < 1 then
+        allowed = 0
+    end
+    tokens[i] = available
+end
+
+local result = nil
+for i = 1, #KEYS do
+    local capacity = tonumber(ARGV[2 * i - 1])
+    local window = tonumber(ARGV[2 * i])
+    local available = tokens[i]
+    if allowed == 1 then
+        available = available - 1
+    end
+    redis.call("HSET", KEYS[i], "tokens", tostring(available), "updated_at", now)
+    redis.call("PEXPIRE", KEYS[i], window)
+
+    local remaining = math.floor(available)
+    local reset = math.ceil((capacity - available) * window / capacity)
+    local retry_after = 0
+    if available < 1 then
+        retry_after = math.ceil((1 - available) * window / capacity)
+    end
+    if result == nil
+        or (allowed == 1 and remaining < result[3])
+        or (allowed == 0 and retry_after > result[5]) then
+        result = { allowed, capacity, remaining, reset, retry_after }
+    end
+end
+return result
+"#;
+
+/// Records the request in each of the windows in `KEYS` if none of them has reached its limit.
+///
+/// `ARGV` holds the limit and the length (in milliseconds) of each window, followed by an
+/// identifier of the request. Returns the outcome along with the limit, remaining requests, reset
+/// time and retry time (in milliseconds) of the most restrictive window.
+const SLIDING_WINDOW_SCRIPT: &str = r#"
+local time = redis.call("TIME")
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local member = ARGV[2 * #KEYS + 1]
+
+local counts = {}
+local allowed = 1
+for i = 1, #KEYS do
+    local limit = tonumber(ARGV[2 * i - 1])
+    local window = tonumber(ARGV[2 * i])
+    redis.call("ZREMRANGEBYSCORE", KEYS[i], "-inf", now - window)
+    local count = redis.call("ZCARD", KEYS[i])
+    if count >= limit then
+        allowed = 0
+    end
+    counts[i] = count
+end
+
+local result = nil
+for i = 1, #KEYS do
+    local limit = tonumber(ARGV[2 * i - 1])
+    local window = tonumber(ARGV[2 * i])
+    local count = counts[i]
+    if allowed == 1 then
+        redis.call("ZADD", KEYS[i], now, member)
+        redis.call("PEXPIRE", KEYS[i], window)
+        count = count + 1
+    end
+
+    local remaining = math.max(0, limit - count)
+    local reset = 0
+    local oldest = redis.call("ZRANGE", KEYS[i], 0, 0, "WITHSCORES")
+    if oldest[2] then
+        reset = tonumber(oldest[2]) + window - now
+    end
+    local retry_after = 0
+    if count >= limit then
+        local expiring = redis.call("ZRANGE", KEYS[i], count - limit, count - limit, "WITHSCORES")
+        if expiring[2] then
+            retry_after = tonumber(expiring[2]) + window - now
+        end
+    end
+    if result == nil
+        or (allowed == 1 and remaining < result[3])
+        or (allowed == 0 and retry_after > result[5]) then
+        result = { allowed, limit, remaining, reset, retry_after }
+    end
+end
+return result
+"#;
+
+/// Outcome of applying the rate limits to a request, reported in the `RateLimit-*` headers of the
+/// response
+#[derive(Clone, Debug)]
+pub struct RateLimitStatus {
+    pub limit: u32,
+    pub remaining: u32,
+    pub reset_in_secs: u64,
+    /// Present if the request was rejected for exceeding a limit
+    pub retry_after_in_secs: Option<u64>,
+}
+
+impl RateLimitStatus {
+    pub fn validate(&self) -> RouterResult<()> {
+        match self.retry_after_in_secs {
+            Some(retry_after) => Err(report!(errors::ApiErrorResponse::TooManyRequests {
+                retry_after
+            })),
+            None => Ok(()),
+        }
+    }
+
+    pub fn get_response_headers(&self) -> Vec<(&'static str, String)> {
+        let mut response_headers = vec![
+            (headers::RATE_LIMIT_LIMIT, self.limit.to_string()),
+            (headers::RATE_LIMIT_REMAINING, self.remaining.to_string()),
+            (headers::RATE_LIMIT_RESET, self.reset_in_secs.to_string()),
+        ];
+        if let Some(retry_after) = self.retry_after_in_secs {
+            response_headers.push((headers::RETRY_AFTER, retry_after.to_string()));
+        }
+        response_headers
+    }
+}
+
+/// Applies the rate limits configured for the merchant, the API key and the group of routes
+/// of the request.
+///
+/// Requests are allowed if the limits could not be checked, so that an unavailable Redis does not
+/// result in all the requests being rejected.
+#[instrument(skip_all)]
+pub async fn check_rate_limit(
+    state: &SessionState,
+    auth_type: &AuthenticationType,
+    api_identifier: Option<lock_utils::ApiIdentifier>,
+) -> Option<RateLimitStatus> {
+    let config = &state.conf.rate_limit;
+    if !config.enabled {
+        return None;
+    }
+
+    let (merchant_id, key_id) = match auth_type {
+        AuthenticationType::ApiKey {
+            merchant_id,
+            key_id,
+        } => (merchant_id, Some(key_id)),
+        AuthenticationType::PublishableKey { merchant_id }
+        | AuthenticationType::MerchantId { merchant_id }
+        | AuthenticationType::MerchantJwt { merchant_id, .. }
+        | AuthenticationType::MerchantJwtWithProfileId { merchant_id, .. } => (merchant_id, None),
+        // Requests made with the admin API key and webhooks received from connectors are not
+        // rate limited
+        AuthenticationType::AdminApiKey
+        | AuthenticationType::AdminApiAuthWithMerchantId { .. }
+        | AuthenticationType::WebhookAuth { .. }
+        | AuthenticationType::OrganizationJwt { .. }
+        | AuthenticationType::UserJwt { .. }
+        | AuthenticationType::SinglePurposeJwt { .. }
+        | AuthenticationType::SinglePurposeOrLoginJwt { .. }
+        | AuthenticationType::NoAuth => return None,
+    };
+
+    let rate_limits = get_rate_limits(config, merchant_id, key_id, api_identifier);
+    if rate_limits.is_empty() {
+        return None;
+    }
+
+    let status = evaluate_rate_limits(state, config.algorithm, &rate_limits)
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to check rate limits"))
+        .ok()?;
+
+    if status.retry_after_in_secs.is_some() {
+        logger::info!(?status, "Request rejected for exceeding the rate limit");
+        metrics::REQUESTS_RATE_LIMITED.add(
+            1,
+            router_env::metric_attributes!(("merchant_id", merchant_id.clone())),
+        );
+    }
+
+    Some(status)
+}
+
+/// Keys of the limits applicable to a request, along with the limits. The merchant ID is used as
+/// the hash tag of the keys, so that the limits of a merchant can be evaluated in a single script
+/// on a Redis cluster.
+fn get_rate_limits(
+    config: &RateLimitSettings,
+    merchant_id: &id_type::MerchantId,
+    key_id: Option<&id_type::ApiKeyId>,
+    api_identifier: Option<lock_utils::ApiIdentifier>,
+) -> Vec<(String, RateLimit)> {
+    let key_prefix = format!(
+        "{}_{{{}}}",
+        RATE_LIMIT_PREFIX,
+        merchant_id.get_string_repr()
+    );
+
+    let merchant_limit = config
+        .merchant_overrides
+        .get(merchant_id)
+        .or(config.merchant.as_ref())
+        .map(|limit| (format!("{key_prefix}_merchant"), *limit));
+
+    let api_key_limit = key_id.zip(config.api_key).map(|(key_id, limit)| {
+        (
+            format!("{key_prefix}_api_key_{}", key_id.get_string_repr()),
+            limit,
+        )
+    });
+
+    let route_group_limit = api_identifier.and_then(|api_identifier| {
+        config
+            .route_groups
+            .get(&api_identifier)
+            .map(|limit| (format!("{key_prefix}_{api_identifier}"), *limit))
+    });
+
+    merchant_limit
+        .into_iter()
+        .chain(api_key_limit)
+        .chain(route_group_limit)
+        .collect()
+}
+
+async fn evaluate_rate_limits(
+    state: &SessionState,
+    algorithm: RateLimitAlgorithm,
+    rate_limits: &[(String, RateLimit)],
+) -> RouterResult<RateLimitStatus> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let keys = rate_limits
+        .iter()
+        .map(|(key, _)| RedisKey::from(key).tenant_aware_key(&redis_conn))
+        .collect::<Vec<_>>();
+    let mut values = rate_limits
+        .iter()
+        .flat_map(|(_, limit)| {
+            [
+                limit.requests.to_string(),
+                (u64::from(limit.window_in_secs) * 1000).to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let script = match algorithm {
+        RateLimitAlgorithm::TokenBucket => TOKEN_BUCKET_SCRIPT,
+        RateLimitAlgorithm::SlidingWindow => {
+            values.push(
+                state
+                    .get_request_id()
+                    .unwrap_or_else(|| common_utils::generate_id_with_default_len("req")),
+            );
+            SLIDING_WINDOW_SCRIPT
+        }
+    };
+
+    let result = redis_conn
+        .evaluate_redis_script::<_, Vec<i64>>(script, keys, values)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to evaluate rate limit script")?;
+
+    let &[allowed, limit, remaining, reset_in_millis, retry_after_in_millis] = result.as_slice()
+    else {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable_lazy(|| format!("Unexpected rate limit script result: {result:?}"));
+    };
+
+    fp_utils::when(!matches!(allowed, 0 | 1), || {
+        Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected outcome in rate limit script result")
+    })?;
+
+    Ok(RateLimitStatus {
+        limit: u32::try_from(limit).unwrap_or_default(),
+        remaining: u32::try_from(remaining).unwrap_or_default(),
+        reset_in_secs: millis_to_secs(reset_in_millis),
+        retry_after_in_secs: (allowed == 0).then(|| millis_to_secs(retry_after_in_millis).max(1)),
+    })
+}
+
+fn millis_to_secs(millis: i64) -> u64 {
+    u64::try_from(millis).unwrap_or_default().div_ceil(1000)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::{borrow::Cow, collections::HashMap};
+
+    use super::*;
+
+    fn merchant_id(merchant_id: &'static str) -> id_type::MerchantId {
+        id_type::MerchantId::try_from(Cow::from(merchant_id)).expect("Invalid merchant id")
+    }
+
+    fn limit(requests: u32, window_in_secs: u32) -> RateLimit {
+        RateLimit {
+            requests,
+            window_in_secs,
+        }
+    }
+
+    fn summarize(rate_limits: Vec<(String, RateLimit)>) -> Vec<(String, u32, u32)> {
+        rate_limits
+            .into_iter()
+            .map(|(key, limit)| (key, limit.requests, limit.window_in_secs))
+            .collect()
+    }
+
+    fn config() -> RateLimitSettings {
+        RateLimitSettings {
+            enabled: true,
+            merchant: Some(limit(1000, 60)),
+            api_key: Some(limit(500, 60)),
+            route_groups: HashMap::from([(lock_utils::ApiIdentifier::Refunds, limit(100, 10))]),
+            merchant_overrides: HashMap::from([(merchant_id("merchant_large"), limit(5000, 60))]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_rate_limits_by_default() {
+        let rate_limits = get_rate_limits(
+            &RateLimitSettings::default(),
+            &merchant_id("merchant_1"),
+            None,
+            Some(lock_utils::ApiIdentifier::Payments),
+        );
+
+        assert!(rate_limits.is_empty());
+        assert_eq!(
+            RateLimitSettings::default().algorithm,
+            RateLimitAlgorithm::TokenBucket
+        );
+    }
+
+    #[test]
+    fn test_rate_limits_for_api_key_and_route_group() {
+        let key_id = id_type::ApiKeyId::try_from(Cow::from("key_1")).expect("Invalid API key id");
+
+        let rate_limits = get_rate_limits(
+            &config(),
+            &merchant_id("merchant_1"),
+            Some(&key_id),
+            Some(lock_utils::ApiIdentifier::Refunds),
+        );
+
+        assert_eq!(
+            summarize(rate_limits),
+            vec![
+                ("RATE_LIMIT_{merchant_1}_merchant".to_string(), 1000, 60),
+                ("RATE_LIMIT_{merchant_1}_api_key_key_1".to_string(), 500, 60),
+                ("RATE_LIMIT_{merchant_1}_refunds".to_string(), 100, 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rate_limits_without_api_key_or_configured_route_group() {
+        let rate_limits = get_rate_limits(
+            &config(),
+            &merchant_id("merchant_1"),
+            None,
+            Some(lock_utils::ApiIdentifier::Payments),
+        );
+
+        assert_eq!(
+            summarize(rate_limits),
+            vec![("RATE_LIMIT_{merchant_1}_merchant".to_string(), 1000, 60)]
+        );
+    }
+
+    #[test]
+    fn test_merchant_override_replaces_merchant_limit() {
+        let rate_limits = get_rate_limits(&config(), &merchant_id("merchant_large"), None, None);
+
+        assert_eq!(
+            summarize(rate_limits),
+            vec![("RATE_LIMIT_{merchant_large}_merchant".to_string(), 5000, 60)]
+        );
+    }
+
+    #[test]
+    fn test_merchant_override_applies_without_default_merchant_limit() {
+        let config = RateLimitSettings {
+            merchant: None,
+            ..config()
+        };
+
+        assert!(get_rate_limits(&config, &merchant_id("merchant_1"), None, None).is_empty());
+        assert_eq!(
+            summarize(get_rate_limits(
+                &config,
+                &merchant_id("merchant_large"),
+                None,
+                None
+            )),
+            vec![("RATE_LIMIT_{merchant_large}_merchant".to_string(), 5000, 60)]
+        );
+    }
+
+    #[test]
+    fn test_millis_to_secs_rounds_up() {
+        assert_eq!(millis_to_secs(0), 0);
+        assert_eq!(millis_to_secs(1), 1);
+        assert_eq!(millis_to_secs(1000), 1);
+        assert_eq!(millis_to_secs(1001), 2);
+        assert_eq!(millis_to_secs(-5), 0);
+    }
+}
@@ -144,6 +144,7 @@ pub async fn construct_relay_refund_router_data<F>(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: None,
     };
 
     Ok(router_data)
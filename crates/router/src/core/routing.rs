@@ -1,4 +1,8 @@
 pub mod helpers;
+#[cfg(feature = "v1")]
+pub mod history;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod simulation;
 pub mod transformers;
 use std::collections::HashSet;
 
@@ -14,11 +18,7 @@ use common_utils::ext_traits::AsyncExt;
 use diesel_models::routing_algorithm::RoutingAlgorithm;
 use error_stack::ResultExt;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
-use external_services::grpc_client::dynamic_routing::{
-    contract_routing_client::ContractBasedDynamicRouting,
-    elimination_based_client::EliminationBasedRouting,
-    success_rate_client::SuccessBasedDynamicRouting,
-};
+use external_services::grpc_client::dynamic_routing::contract_routing_client::ContractBasedDynamicRouting;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use helpers::update_decision_engine_dynamic_routing_setup;
 use hyperswitch_domain_models::{mandates, payment_address};
@@ -75,6 +75,9 @@ pub struct PaymentsDslInput<'a> {
     pub address: &'a payment_address::PaymentAddress,
     pub recurring_details: Option<&'a mandates_api::RecurringDetails>,
     pub currency: storage_enums::Currency,
+    /// Card details saved with the payment, used for the card keys when the card itself is not
+    /// available, like for payments replayed by the routing simulation
+    pub additional_card_info: Option<&'a api_models::payments::AdditionalCardInfo>,
 }
 
 impl<'a> PaymentsDslInput<'a> {
@@ -95,8 +98,17 @@ impl<'a> PaymentsDslInput<'a> {
             address,
             recurring_details,
             currency,
+            additional_card_info: None,
         }
     }
+
+    pub fn with_additional_card_info(
+        mut self,
+        additional_card_info: Option<&'a api_models::payments::AdditionalCardInfo>,
+    ) -> Self {
+        self.additional_card_info = additional_card_info;
+        self
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -339,8 +351,17 @@ pub async fn create_routing_algorithm_under_profile(
 
     let mut decision_engine_routing_id: Option<String> = None;
 
-    if let Some(EuclidAlgorithm::Advanced(program)) = request.algorithm.clone() {
-        let internal_program: Program = program.into();
+    // Programs using comparisons that the decision engine does not support are not sent to it
+    let internal_program = match request.algorithm.clone() {
+        Some(EuclidAlgorithm::Advanced(program)) => Program::try_from(program)
+            .map_err(|error| {
+                logger::info!(decision_engine_error=?error, "decision_engine_euclid");
+            })
+            .ok(),
+        _ => None,
+    };
+
+    if let Some(internal_program) = internal_program {
         let routing_rule = RoutingRule {
             name: name.clone(),
             description: Some(description.clone()),
@@ -470,10 +491,12 @@ pub async fn link_routing_config(
     state: SessionState,
     merchant_context: domain::MerchantContext,
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
-    algorithm_id: common_utils::id_type::RoutingId,
+    request: routing_types::RoutingActivationWrapper,
     transaction_type: &enums::TransactionType,
+    actor: Option<String>,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_LINK_CONFIG.add(1, &[]);
+    let algorithm_id = request.algorithm_id;
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
@@ -670,6 +693,15 @@ pub async fn link_routing_config(
                     })
                 },
             )?;
+            let activation = history::new_routing_algorithm_activation(
+                merchant_context.get_merchant_account().get_id(),
+                business_profile.get_id(),
+                transaction_type,
+                routing_ref.algorithm_id.clone(),
+                Some(algorithm_id.clone()),
+                actor,
+                request.reason,
+            );
             routing_ref.update_algorithm_id(algorithm_id);
             helpers::update_profile_active_algorithm_ref(
                 db,
@@ -678,6 +710,7 @@ pub async fn link_routing_config(
                 business_profile.clone(),
                 routing_ref,
                 transaction_type,
+                activation,
             )
             .await?;
         }
@@ -790,6 +823,67 @@ pub async fn retrieve_routing_algorithm_from_algorithm_id(
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+#[cfg(feature = "v1")]
+pub async fn export_routing_algorithm_as_dsl(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<routing_types::RoutingDslExportResponse> {
+    metrics::ROUTING_EXPORT_CONFIG_DSL.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_context.get_merchant_account().get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&routing_algorithm.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let algorithm = routing_algorithm
+        .algorithm_data
+        .parse_value::<routing_types::RoutingAlgorithm>("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to parse routing algorithm")?;
+
+    let routing_types::RoutingAlgorithm::Advanced(program) = algorithm else {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Only advanced routing algorithms can be exported as DSL".to_string(),
+        }
+        .into());
+    };
+
+    let dsl = routing_types::ast::printer::program(&program).map_err(|error| {
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Routing algorithm cannot be exported as DSL: {error}"),
+        }
+    })?;
+
+    metrics::ROUTING_EXPORT_CONFIG_DSL_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingDslExportResponse {
+            algorithm_id: routing_algorithm.algorithm_id,
+            name: routing_algorithm.name,
+            dsl,
+        },
+    ))
+}
+
 #[cfg(feature = "v2")]
 pub async fn unlink_routing_config_under_profile(
     state: SessionState,
@@ -850,6 +944,7 @@ pub async fn unlink_routing_config(
     request: routing_types::RoutingConfigRequest,
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
     transaction_type: &enums::TransactionType,
+    actor: Option<String>,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_UNLINK_CONFIG.add(1, &[]);
 
@@ -910,6 +1005,15 @@ pub async fn unlink_routing_config(
                         .await
                         .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
                     let response = record.foreign_into();
+                    let activation = history::new_routing_algorithm_activation(
+                        merchant_context.get_merchant_account().get_id(),
+                        &profile_id,
+                        transaction_type,
+                        Some(algorithm_id),
+                        None,
+                        actor,
+                        None,
+                    );
                     helpers::update_profile_active_algorithm_ref(
                         db,
                         key_manager_state,
@@ -917,6 +1021,7 @@ pub async fn unlink_routing_config(
                         business_profile,
                         routing_algorithm,
                         transaction_type,
+                        activation,
                     )
                     .await?;
 
@@ -21,11 +21,7 @@ use diesel_models::dynamic_routing_stats::{DynamicRoutingStatsNew, DynamicRoutin
 use diesel_models::routing_algorithm;
 use error_stack::ResultExt;
 #[cfg(all(feature = "dynamic_routing", feature = "v1"))]
-use external_services::grpc_client::dynamic_routing::{
-    contract_routing_client::ContractBasedDynamicRouting,
-    elimination_based_client::EliminationBasedRouting,
-    success_rate_client::SuccessBasedDynamicRouting,
-};
+use external_services::grpc_client::dynamic_routing::contract_routing_client::ContractBasedDynamicRouting;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use hyperswitch_domain_models::api::ApplicationResponse;
 #[cfg(all(feature = "dynamic_routing", feature = "v1"))]
@@ -224,6 +220,8 @@ pub async fn update_merchant_active_algorithm_ref(
     Ok(())
 }
 
+/// Changes the routing algorithm active for the profile and records the activation, in the same
+/// transaction
 #[cfg(feature = "v1")]
 pub async fn update_profile_active_algorithm_ref(
     db: &dyn StorageInterface,
@@ -232,7 +230,8 @@ pub async fn update_profile_active_algorithm_ref(
     current_business_profile: domain::Profile,
     algorithm_id: routing_types::RoutingAlgorithmRef,
     transaction_type: &storage::enums::TransactionType,
-) -> RouterResult<()> {
+    activation: storage::RoutingAlgorithmActivationNew,
+) -> RouterResult<storage::RoutingAlgorithmActivation> {
     let ref_val = algorithm_id
         .encode_to_value()
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -263,21 +262,23 @@ pub async fn update_profile_active_algorithm_ref(
         three_ds_decision_rule_algorithm: None,
     };
 
-    db.update_profile_by_profile_id(
-        key_manager_state,
-        merchant_key_store,
-        current_business_profile,
-        business_profile_update,
-    )
-    .await
-    .change_context(errors::ApiErrorResponse::InternalServerError)
-    .attach_printable("Failed to update routing algorithm ref in business profile")?;
+    let (_, activation) = db
+        .update_profile_and_insert_routing_algorithm_activation(
+            key_manager_state,
+            merchant_key_store,
+            current_business_profile,
+            business_profile_update,
+            activation,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update routing algorithm ref in business profile")?;
 
     cache::redact_from_redis_and_publish(db.get_cache_store().as_ref(), [routing_cache_key])
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to invalidate routing cache")?;
-    Ok(())
+    Ok(activation)
 }
 
 #[cfg(feature = "v1")]
@@ -0,0 +1,775 @@
+//! History of the routing algorithms activated for a profile, comparison of two routing
+//! algorithms and rollback of the latest activation.
+//!
+//! Every activation and deactivation of a static routing algorithm through the API is recorded
+//! as an immutable entry, with the algorithm active before the change, so that the change can be
+//! reviewed and reverted.
+
+use std::collections::HashSet;
+
+use api_models::routing::{self as routing_types, ast};
+use common_utils::{date_time, generate_id_with_default_len, id_type};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use super::helpers;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        metrics,
+        payments::routing::utils::{
+            link_de_euclid_routing_algorithm, ActivateRoutingConfigRequest,
+        },
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services::api as service_api,
+    types::{
+        domain, storage,
+        storage::enums as storage_enums,
+        transformers::{ForeignFrom, ForeignInto},
+    },
+    utils::{self, OptionExt, ValueExt},
+};
+
+const DEFAULT_ACTIVATION_HISTORY_LIMIT: u16 = 20;
+const MAX_ACTIVATION_HISTORY_LIMIT: u16 = 100;
+
+/// Builds the record of the change of the routing algorithm active for the profile and
+/// transaction type, to be stored along with the change
+pub fn new_routing_algorithm_activation(
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    transaction_type: &storage_enums::TransactionType,
+    previous_algorithm_id: Option<id_type::RoutingId>,
+    algorithm_id: Option<id_type::RoutingId>,
+    actor: Option<String>,
+    reason: Option<String>,
+) -> storage::RoutingAlgorithmActivationNew {
+    storage::RoutingAlgorithmActivationNew {
+        activation_id: generate_id_with_default_len(consts::ROUTING_ACTIVATION_ID_PREFIX),
+        merchant_id: merchant_id.to_owned(),
+        profile_id: profile_id.to_owned(),
+        transaction_type: *transaction_type,
+        previous_algorithm_id,
+        algorithm_id,
+        activated_by: actor,
+        reason,
+        created_at: date_time::now(),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_routing_activation_history(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    query: routing_types::RoutingActivationHistoryQuery,
+    transaction_type: &storage_enums::TransactionType,
+) -> RouterResponse<routing_types::RoutingActivationHistoryResponse> {
+    metrics::ROUTING_RETRIEVE_ACTIVATION_HISTORY.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&query.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: query.profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_ACTIVATION_HISTORY_LIMIT)
+        .min(MAX_ACTIVATION_HISTORY_LIMIT);
+
+    let activations = db
+        .list_routing_algorithm_activations_by_profile_id_transaction_type(
+            business_profile.get_id(),
+            transaction_type,
+            i64::from(limit),
+            i64::from(query.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list routing algorithm activations")?;
+
+    metrics::ROUTING_RETRIEVE_ACTIVATION_HISTORY_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingActivationHistoryResponse {
+            activations: activations
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect(),
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn diff_routing_algorithms(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    query: routing_types::RoutingAlgorithmDiffQuery,
+) -> RouterResponse<routing_types::RoutingAlgorithmDiffResponse> {
+    metrics::ROUTING_DIFF_CONFIG.add(1, &[]);
+
+    let base_algorithm = get_static_routing_algorithm(
+        &state,
+        &merchant_context,
+        authentication_profile_id.clone(),
+        &query.base_algorithm_id,
+    )
+    .await?;
+    let algorithm = get_static_routing_algorithm(
+        &state,
+        &merchant_context,
+        authentication_profile_id,
+        &query.algorithm_id,
+    )
+    .await?;
+
+    let changes = get_routing_algorithm_changes(&base_algorithm, &algorithm)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compare routing algorithms")?;
+
+    metrics::ROUTING_DIFF_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingAlgorithmDiffResponse {
+            base_algorithm_id: query.base_algorithm_id,
+            algorithm_id: query.algorithm_id,
+            changes,
+        },
+    ))
+}
+
+/// Reverts the latest activation of the profile for the transaction type, by re-activating the
+/// routing algorithm which was active before it (or deactivating the routing algorithm if none
+/// was). The rollback is itself recorded in the history, so that rolling back twice restores the
+/// routing algorithm which was rolled back.
+#[instrument(skip_all)]
+pub async fn rollback_routing_algorithm(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    request: routing_types::RoutingRollbackRequest,
+    transaction_type: &storage_enums::TransactionType,
+    actor: Option<String>,
+) -> RouterResponse<routing_types::RoutingActivationRecord> {
+    metrics::ROUTING_ROLLBACK_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&request.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: request.profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let latest_activation = db
+        .list_routing_algorithm_activations_by_profile_id_transaction_type(
+            business_profile.get_id(),
+            transaction_type,
+            1,
+            0,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list routing algorithm activations")?
+        .into_iter()
+        .next()
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "No routing algorithm activation to roll back".to_string(),
+        })?;
+
+    let mut routing_ref: routing_types::RoutingAlgorithmRef = match transaction_type {
+        storage_enums::TransactionType::Payment => business_profile.routing_algorithm.clone(),
+        #[cfg(feature = "payouts")]
+        storage_enums::TransactionType::Payout => business_profile.payout_routing_algorithm.clone(),
+    }
+    .map(|val| val.parse_value("RoutingAlgorithmRef"))
+    .transpose()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+    .unwrap_or_default();
+
+    validate_rollback_of_activation(routing_ref.algorithm_id.as_ref(), &latest_activation)?;
+
+    let restored_algorithm = match &latest_activation.previous_algorithm_id {
+        Some(previous_algorithm_id) => Some(
+            db.find_routing_algorithm_by_profile_id_algorithm_id(
+                business_profile.get_id(),
+                previous_algorithm_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?,
+        ),
+        None => None,
+    };
+
+    // The restored algorithm is activated again, so it goes through the checks of the activation
+    if let Some(restored_algorithm) = &restored_algorithm {
+        validate_restored_routing_algorithm(restored_algorithm, transaction_type)?;
+    }
+
+    let activation = new_rollback_activation(
+        merchant_context.get_merchant_account().get_id(),
+        latest_activation,
+        actor,
+        request.reason,
+    );
+    routing_ref.algorithm_id = activation.algorithm_id.clone();
+    routing_ref.timestamp = date_time::now_unix_timestamp();
+
+    let activation = helpers::update_profile_active_algorithm_ref(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        business_profile.clone(),
+        routing_ref,
+        transaction_type,
+        activation,
+    )
+    .await?;
+
+    if let Some(euclid_routing_id) =
+        restored_algorithm.and_then(|algorithm| algorithm.decision_engine_routing_id)
+    {
+        let routing_algo = ActivateRoutingConfigRequest {
+            created_by: business_profile.get_id().get_string_repr().to_string(),
+            routing_algorithm_id: euclid_routing_id,
+        };
+        link_de_euclid_routing_algorithm(&state, routing_algo)
+            .await
+            .inspect_err(|error| {
+                logger::error!(
+                    routing_flow=?"rollback_routing_algorithm",
+                    ?error,
+                    "decision_engine_euclid"
+                )
+            })
+            .ok();
+    }
+
+    metrics::ROUTING_ROLLBACK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingActivationRecord::foreign_from(activation),
+    ))
+}
+
+fn validate_rollback_of_activation(
+    active_algorithm_id: Option<&id_type::RoutingId>,
+    latest_activation: &storage::RoutingAlgorithmActivation,
+) -> RouterResult<()> {
+    // The active algorithm could have been changed without going through the activation API, in
+    // which case the latest activation cannot be reverted
+    utils::when(
+        active_algorithm_id != latest_activation.algorithm_id.as_ref(),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The active routing algorithm does not match the latest activation"
+                    .to_string(),
+            })
+        },
+    )
+}
+
+/// Builds the record of the rollback of the latest activation, which restores the routing
+/// algorithm active before it
+fn new_rollback_activation(
+    merchant_id: &id_type::MerchantId,
+    latest_activation: storage::RoutingAlgorithmActivation,
+    actor: Option<String>,
+    reason: Option<String>,
+) -> storage::RoutingAlgorithmActivationNew {
+    new_routing_algorithm_activation(
+        merchant_id,
+        &latest_activation.profile_id,
+        &latest_activation.transaction_type,
+        latest_activation.algorithm_id,
+        latest_activation.previous_algorithm_id,
+        actor,
+        reason,
+    )
+}
+
+fn validate_restored_routing_algorithm(
+    routing_algorithm: &storage::RoutingAlgorithm,
+    transaction_type: &storage_enums::TransactionType,
+) -> RouterResult<()> {
+    utils::when(
+        routing_algorithm.kind == storage_enums::RoutingAlgorithmKind::Dynamic,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Dynamic routing algorithms cannot be restored by a rollback".to_string(),
+            })
+        },
+    )?;
+
+    utils::when(routing_algorithm.algorithm_for != *transaction_type, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Cannot use {}'s routing algorithm for {} operation",
+                routing_algorithm.algorithm_for, transaction_type
+            ),
+        })
+    })
+}
+
+async fn get_static_routing_algorithm(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    algorithm_id: &id_type::RoutingId,
+) -> RouterResult<routing_types::RoutingAlgorithm> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            algorithm_id,
+            merchant_context.get_merchant_account().get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&routing_algorithm.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    utils::when(
+        routing_algorithm.kind == storage_enums::RoutingAlgorithmKind::Dynamic,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Dynamic routing algorithms cannot be compared".to_string(),
+            })
+        },
+    )?;
+
+    routing_algorithm
+        .algorithm_data
+        .parse_value::<routing_types::RoutingAlgorithm>("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to parse routing algorithm")
+}
+
+/// Changes made to the base routing algorithm, rule by rule for advanced routing algorithms.
+///
+/// Rules are matched by name, a renamed rule shows up as a removed rule and an added rule. Other
+/// routing algorithms, or routing algorithms of different kinds, are compared as a whole.
+fn get_routing_algorithm_changes(
+    base_algorithm: &routing_types::RoutingAlgorithm,
+    algorithm: &routing_types::RoutingAlgorithm,
+) -> Result<Vec<routing_types::RoutingAlgorithmChange>, serde_json::Error> {
+    let mut changes = Vec::new();
+
+    match (base_algorithm, algorithm) {
+        (
+            routing_types::RoutingAlgorithm::Advanced(base_program),
+            routing_types::RoutingAlgorithm::Advanced(program),
+        ) => get_program_changes(base_program, program, &mut changes)?,
+        _ => push_change(
+            &mut changes,
+            "algorithm".to_string(),
+            Some(serde_json::to_value(base_algorithm)?),
+            Some(serde_json::to_value(algorithm)?),
+        ),
+    }
+
+    Ok(changes)
+}
+
+fn get_program_changes(
+    base_program: &ast::Program<routing_types::ConnectorSelection>,
+    program: &ast::Program<routing_types::ConnectorSelection>,
+    changes: &mut Vec<routing_types::RoutingAlgorithmChange>,
+) -> Result<(), serde_json::Error> {
+    // Paths are the field names of the serialized program
+    let base_value = serde_json::to_value(base_program)?;
+    let value = serde_json::to_value(program)?;
+    for field in ["defaultSelection", "metadata", "timezone"] {
+        push_change(
+            changes,
+            field.to_string(),
+            base_value.get(field).cloned(),
+            value.get(field).cloned(),
+        );
+    }
+
+    for rule in &program.rules {
+        let path = format!("rules.{}", rule.name);
+        match base_program
+            .rules
+            .iter()
+            .find(|base_rule| base_rule.name == rule.name)
+        {
+            Some(base_rule) => {
+                push_change(
+                    changes,
+                    format!("{path}.connectorSelection"),
+                    Some(serde_json::to_value(&base_rule.connector_selection)?),
+                    Some(serde_json::to_value(&rule.connector_selection)?),
+                );
+                push_change(
+                    changes,
+                    format!("{path}.statements"),
+                    Some(serde_json::to_value(&base_rule.statements)?),
+                    Some(serde_json::to_value(&rule.statements)?),
+                );
+            }
+            None => push_change(changes, path, None, Some(serde_json::to_value(rule)?)),
+        }
+    }
+
+    for base_rule in &base_program.rules {
+        if !program.rules.iter().any(|rule| rule.name == base_rule.name) {
+            push_change(
+                changes,
+                format!("rules.{}", base_rule.name),
+                Some(serde_json::to_value(base_rule)?),
+                None,
+            );
+        }
+    }
+
+    // Rules are evaluated in order, so moving a rule changes which rule applies to a payment
+    let base_rule_names = base_program
+        .rules
+        .iter()
+        .map(|rule| rule.name.as_str())
+        .collect::<HashSet<_>>();
+    let rule_names = program
+        .rules
+        .iter()
+        .map(|rule| rule.name.as_str())
+        .collect::<HashSet<_>>();
+    let base_rule_order = base_program
+        .rules
+        .iter()
+        .map(|rule| rule.name.as_str())
+        .filter(|name| rule_names.contains(name))
+        .collect::<Vec<_>>();
+    let rule_order = program
+        .rules
+        .iter()
+        .map(|rule| rule.name.as_str())
+        .filter(|name| base_rule_names.contains(name))
+        .collect::<Vec<_>>();
+    push_change(
+        changes,
+        "rules".to_string(),
+        Some(serde_json::to_value(base_rule_order)?),
+        Some(serde_json::to_value(rule_order)?),
+    );
+
+    Ok(())
+}
+
+fn push_change(
+    changes: &mut Vec<routing_types::RoutingAlgorithmChange>,
+    path: String,
+    base_value: Option<serde_json::Value>,
+    value: Option<serde_json::Value>,
+) {
+    let change_type = match (&base_value, &value) {
+        (None, None) => return,
+        (Some(base_value), Some(value)) if base_value == value => return,
+        (None, Some(_)) => routing_types::RoutingAlgorithmChangeType::Added,
+        (Some(_), None) => routing_types::RoutingAlgorithmChangeType::Removed,
+        (Some(_), Some(_)) => routing_types::RoutingAlgorithmChangeType::Modified,
+    };
+
+    changes.push(routing_types::RoutingAlgorithmChange {
+        path,
+        change_type,
+        base_value,
+        value,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn routing_id(id: &'static str) -> id_type::RoutingId {
+        id_type::RoutingId::try_from(Cow::from(id)).unwrap()
+    }
+
+    fn merchant_id() -> id_type::MerchantId {
+        id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap()
+    }
+
+    fn profile_id() -> id_type::ProfileId {
+        id_type::ProfileId::try_from(Cow::from("pro_1")).unwrap()
+    }
+
+    fn activation(
+        previous_algorithm_id: Option<id_type::RoutingId>,
+        algorithm_id: Option<id_type::RoutingId>,
+    ) -> storage::RoutingAlgorithmActivation {
+        storage::RoutingAlgorithmActivation {
+            activation_id: "routing_activation_1".to_string(),
+            merchant_id: merchant_id(),
+            profile_id: profile_id(),
+            transaction_type: storage_enums::TransactionType::Payment,
+            previous_algorithm_id,
+            algorithm_id,
+            activated_by: None,
+            reason: None,
+            created_at: date_time::now(),
+        }
+    }
+
+    fn routing_algorithm(
+        kind: storage_enums::RoutingAlgorithmKind,
+        algorithm_for: storage_enums::TransactionType,
+    ) -> storage::RoutingAlgorithm {
+        storage::RoutingAlgorithm {
+            algorithm_id: routing_id("routing_1"),
+            profile_id: profile_id(),
+            merchant_id: merchant_id(),
+            name: "routing algorithm".to_string(),
+            description: None,
+            kind,
+            algorithm_data: serde_json::json!({}),
+            created_at: date_time::now(),
+            modified_at: date_time::now(),
+            algorithm_for,
+            decision_engine_routing_id: None,
+        }
+    }
+
+    fn parse_program(program: serde_json::Value) -> routing_types::RoutingAlgorithm {
+        serde_json::from_value(serde_json::json!({ "type": "advanced", "data": program })).unwrap()
+    }
+
+    fn rule(name: &str, connector: &str, amount: i64) -> serde_json::Value {
+        serde_json::json!({
+            "name": name,
+            "connectorSelection": {
+                "type": "priority",
+                "data": [{ "connector": connector, "merchant_connector_id": null }]
+            },
+            "statements": [{
+                "condition": [{
+                    "lhs": "amount",
+                    "comparison": "greater_than",
+                    "value": { "type": "number", "value": amount },
+                    "metadata": {}
+                }],
+                "nested": null
+            }]
+        })
+    }
+
+    fn program(rules: Vec<serde_json::Value>) -> serde_json::Value {
+        serde_json::json!({
+            "defaultSelection": {
+                "type": "priority",
+                "data": [{ "connector": "stripe", "merchant_connector_id": null }]
+            },
+            "rules": rules,
+            "metadata": {}
+        })
+    }
+
+    #[test]
+    fn test_identical_algorithms_have_no_changes() {
+        let algorithm = parse_program(program(vec![rule("high_amount", "adyen", 1000)]));
+
+        let changes = get_routing_algorithm_changes(&algorithm, &algorithm).unwrap();
+
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn test_rule_changes() {
+        let base_algorithm = parse_program(program(vec![
+            rule("high_amount", "adyen", 1000),
+            rule("low_amount", "stripe", 10),
+            rule("removed", "checkout", 50),
+        ]));
+        let algorithm = parse_program(program(vec![
+            rule("low_amount", "stripe", 10),
+            rule("high_amount", "adyen", 5000),
+            rule("added", "checkout", 50),
+        ]));
+
+        let changes = get_routing_algorithm_changes(&base_algorithm, &algorithm)
+            .unwrap()
+            .into_iter()
+            .map(|change| (change.path, change.change_type))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            changes,
+            vec![
+                (
+                    "rules.high_amount.statements".to_string(),
+                    routing_types::RoutingAlgorithmChangeType::Modified
+                ),
+                (
+                    "rules.added".to_string(),
+                    routing_types::RoutingAlgorithmChangeType::Added
+                ),
+                (
+                    "rules.removed".to_string(),
+                    routing_types::RoutingAlgorithmChangeType::Removed
+                ),
+                (
+                    "rules".to_string(),
+                    routing_types::RoutingAlgorithmChangeType::Modified
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rollback_requires_the_latest_activation_to_be_active() {
+        let latest_activation =
+            activation(Some(routing_id("routing_1")), Some(routing_id("routing_2")));
+
+        assert!(validate_rollback_of_activation(
+            Some(&routing_id("routing_2")),
+            &latest_activation
+        )
+        .is_ok());
+        // Activated outside of the activation API after the latest activation
+        assert!(validate_rollback_of_activation(
+            Some(&routing_id("routing_3")),
+            &latest_activation
+        )
+        .is_err());
+        // Deactivated outside of the activation API after the latest activation
+        assert!(validate_rollback_of_activation(None, &latest_activation).is_err());
+
+        let latest_deactivation = activation(Some(routing_id("routing_1")), None);
+        assert!(validate_rollback_of_activation(None, &latest_deactivation).is_ok());
+        assert!(validate_rollback_of_activation(
+            Some(&routing_id("routing_1")),
+            &latest_deactivation
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_rollback_restores_the_previous_algorithm() {
+        let rollback = new_rollback_activation(
+            &merchant_id(),
+            activation(Some(routing_id("routing_1")), Some(routing_id("routing_2"))),
+            Some("user_1".to_string()),
+            Some("Revert routing change".to_string()),
+        );
+
+        assert_eq!(
+            rollback.previous_algorithm_id,
+            Some(routing_id("routing_2"))
+        );
+        assert_eq!(rollback.algorithm_id, Some(routing_id("routing_1")));
+        assert_eq!(rollback.profile_id, profile_id());
+        assert_eq!(
+            rollback.transaction_type,
+            storage_enums::TransactionType::Payment
+        );
+        assert_eq!(rollback.activated_by, Some("user_1".to_string()));
+    }
+
+    #[test]
+    fn test_rollback_of_the_first_activation_deactivates_the_algorithm() {
+        let rollback = new_rollback_activation(
+            &merchant_id(),
+            activation(None, Some(routing_id("routing_1"))),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            rollback.previous_algorithm_id,
+            Some(routing_id("routing_1"))
+        );
+        assert_eq!(rollback.algorithm_id, None);
+
+        // Rolling back the rollback activates the algorithm again
+        let rollback = new_rollback_activation(
+            &merchant_id(),
+            activation(rollback.previous_algorithm_id, rollback.algorithm_id),
+            None,
+            None,
+        );
+
+        assert_eq!(rollback.previous_algorithm_id, None);
+        assert_eq!(rollback.algorithm_id, Some(routing_id("routing_1")));
+    }
+
+    #[test]
+    fn test_restored_routing_algorithm_validation() {
+        assert!(validate_restored_routing_algorithm(
+            &routing_algorithm(
+                storage_enums::RoutingAlgorithmKind::Advanced,
+                storage_enums::TransactionType::Payment
+            ),
+            &storage_enums::TransactionType::Payment
+        )
+        .is_ok());
+        assert!(validate_restored_routing_algorithm(
+            &routing_algorithm(
+                storage_enums::RoutingAlgorithmKind::Dynamic,
+                storage_enums::TransactionType::Payment
+            ),
+            &storage_enums::TransactionType::Payment
+        )
+        .is_err());
+    }
+
+    #[cfg(feature = "payouts")]
+    #[test]
+    fn test_restored_routing_algorithm_of_another_transaction_type() {
+        assert!(validate_restored_routing_algorithm(
+            &routing_algorithm(
+                storage_enums::RoutingAlgorithmKind::Advanced,
+                storage_enums::TransactionType::Payout
+            ),
+            &storage_enums::TransactionType::Payment
+        )
+        .is_err());
+        assert!(validate_restored_routing_algorithm(
+            &routing_algorithm(
+                storage_enums::RoutingAlgorithmKind::Priority,
+                storage_enums::TransactionType::Payment
+            ),
+            &storage_enums::TransactionType::Payout
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,768 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    str::FromStr,
+};
+
+use api_models::{
+    analytics as analytics_api,
+    analytics::payments::{PaymentDimensions, PaymentFilters, PaymentMetrics},
+    routing as routing_types,
+};
+use common_utils::{ext_traits::ValueExt, id_type, types::authentication::AuthInfo};
+use error_stack::ResultExt;
+use futures::{StreamExt, TryStreamExt};
+use hyperswitch_domain_models::{
+    address::Address,
+    payment_address::PaymentAddress,
+    payments::payment_intent::{PaymentIntentFetchConstraints, PaymentIntentListParams},
+};
+use masking::ExposeInterface;
+use router_env::{instrument, tracing};
+
+use super::{PaymentsDslInput, TransactionData};
+use crate::{
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        metrics,
+        payments::routing::{self as payments_routing, CachedAlgorithm},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services::api as service_api,
+    types::{domain, storage, storage::enums as storage_enums},
+    utils::{self, OptionExt},
+};
+
+const DEFAULT_SIMULATION_LIMIT: u32 = 1000;
+const MAX_SIMULATION_LIMIT: u32 = 10000;
+/// Number of payments replayed concurrently
+const SIMULATION_CONCURRENCY: usize = 50;
+
+/// Replays a payment routing algorithm against the past payments of its profile, through the
+/// same interpreter and eligibility analysis as live payments, without calling any connector.
+///
+/// The card details and mandate details of past payments are not stored in full, so rules on
+/// them are evaluated with the card network and card BIN saved with the payment, and payments
+/// are treated as non-mandate payments. Volume splits are sampled for every payment, and
+/// velocity keys are evaluated with the current velocity, like for live payments. The success
+/// rates of the connectors are taken from the payments analytics of the profile in the window.
+#[instrument(skip_all)]
+pub async fn simulate_routing_algorithm(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    algorithm_id: id_type::RoutingId,
+    request: routing_types::RoutingSimulationRequest,
+) -> RouterResponse<routing_types::RoutingSimulationResponse> {
+    metrics::ROUTING_SIMULATE_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key_store = merchant_context.get_merchant_key_store();
+
+    let limit = request.limit.unwrap_or(DEFAULT_SIMULATION_LIMIT);
+    utils::when(limit == 0 || limit > MAX_SIMULATION_LIMIT, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`limit` must be between 1 and {MAX_SIMULATION_LIMIT}"),
+        })
+    })?;
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(&algorithm_id, merchant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        key_store,
+        Some(&routing_algorithm.profile_id),
+        merchant_id,
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    utils::when(
+        routing_algorithm.algorithm_for != storage_enums::TransactionType::Payment,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Only payment routing algorithms can be simulated".to_string(),
+            })
+        },
+    )?;
+
+    let algorithm = routing_algorithm
+        .algorithm_data
+        .parse_value::<routing_types::RoutingAlgorithm>("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to parse routing algorithm")?;
+    let algorithm = CachedAlgorithm::from_routing_algorithm(algorithm)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to initialize routing algorithm")?;
+
+    let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+        offset: 0,
+        starting_at: Some(request.time_range.start_time),
+        ending_at: request.time_range.end_time,
+        amount_filter: None,
+        connector: None,
+        currency: None,
+        status: None,
+        payment_method: None,
+        payment_method_type: None,
+        authentication_type: None,
+        merchant_connector_id: None,
+        profile_id: Some(vec![business_profile.get_id().to_owned()]),
+        customer_id: None,
+        starting_after_id: None,
+        ending_before_id: None,
+        limit: Some(limit),
+        order: Default::default(),
+        card_network: None,
+        card_discovery: None,
+        merchant_order_reference_id: None,
+    }));
+    let payments = db
+        .get_filtered_payment_intents_attempt(
+            key_manager_state,
+            merchant_id,
+            &constraints,
+            key_store,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let historical_outcomes = get_historical_outcomes(
+        &state,
+        merchant_context.get_merchant_account(),
+        &business_profile,
+        request.time_range,
+    )
+    .await?;
+
+    let outcomes = futures::stream::iter(payments.iter().filter_map(
+        |(payment_intent, payment_attempt)| {
+            // Payments which were never routed to a connector have no outcome to compare against
+            let historical_connector = payment_attempt.connector.clone()?;
+            let currency = payment_attempt.currency.or(payment_intent.currency)?;
+            Some((
+                payment_intent,
+                payment_attempt,
+                historical_connector,
+                currency,
+            ))
+        },
+    ))
+    .map(
+        |(payment_intent, payment_attempt, historical_connector, currency)| {
+            let state = &state;
+            let business_profile = &business_profile;
+            let algorithm = &algorithm;
+            async move {
+                let simulated_connector = simulate_payment(
+                    state,
+                    key_store,
+                    business_profile,
+                    algorithm,
+                    payment_intent,
+                    payment_attempt,
+                    currency,
+                )
+                .await
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed to simulate routing for payment {:?}",
+                        payment_intent.payment_id
+                    )
+                })?;
+
+                Ok::<_, error_stack::Report<errors::RoutingError>>(PaymentOutcome {
+                    payment_method: payment_attempt.payment_method,
+                    currency,
+                    historical_connector,
+                    simulated_connector,
+                    is_successful: is_successful_attempt(payment_attempt.status),
+                })
+            }
+        },
+    )
+    .buffered(SIMULATION_CONCURRENCY)
+    .try_collect::<Vec<_>>()
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    metrics::ROUTING_SIMULATE_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        SimulationReport::new(&outcomes, &historical_outcomes)
+            .into_response(algorithm_id, request.time_range),
+    ))
+}
+
+/// Returns the connector the payment would have been routed to by the algorithm, if any of the
+/// connectors chosen by the algorithm or the fallback connectors is eligible for the payment.
+async fn simulate_payment(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    algorithm: &CachedAlgorithm,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    currency: storage_enums::Currency,
+) -> errors::CustomResult<Option<String>, errors::RoutingError> {
+    let billing_address = payment_intent
+        .billing_details
+        .clone()
+        .map(|billing_details| {
+            billing_details
+                .into_inner()
+                .expose()
+                .parse_value::<api_models::payments::Address>("Address")
+        })
+        .transpose()
+        .change_context(errors::RoutingError::DeserializationError {
+            from: "billing_details".to_string(),
+            to: "Address".to_string(),
+        })
+        .attach_printable("Unable to parse billing details of payment")?
+        .map(Address::from);
+    let address = PaymentAddress::new(None, billing_address, None, None);
+
+    let additional_card_info = payment_attempt
+        .payment_method_data
+        .clone()
+        .map(|data| {
+            data.parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+        })
+        .transpose()
+        .change_context(errors::RoutingError::DeserializationError {
+            from: "payment_method_data".to_string(),
+            to: "AdditionalPaymentData".to_string(),
+        })
+        .attach_printable("Unable to parse payment method data of payment")?
+        .and_then(|data| match data {
+            api_models::payments::AdditionalPaymentData::Card(card) => Some(card),
+            _ => None,
+        });
+
+    let transaction_data = TransactionData::Payment(
+        PaymentsDslInput::new(
+            None,
+            payment_attempt,
+            payment_intent,
+            None,
+            &address,
+            None,
+            currency,
+        )
+        .with_additional_card_info(additional_card_info.as_deref()),
+    );
+
+    let chosen = payments_routing::perform_static_routing_with_algorithm(
+        state,
+        business_profile,
+        &transaction_data,
+        algorithm,
+    )
+    .await?;
+
+    let eligible_connector = payments_routing::perform_static_eligibility_analysis_with_fallback(
+        state,
+        key_store,
+        chosen,
+        &transaction_data,
+        None,
+        business_profile,
+    )
+    .await?
+    .into_iter()
+    .next();
+
+    Ok(eligible_connector.map(|choice| choice.connector.to_string()))
+}
+
+/// Payment counts of the profile in the simulated window from analytics, per connector, payment
+/// method and currency
+#[instrument(skip_all)]
+async fn get_historical_outcomes(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &domain::Profile,
+    time_range: common_utils::types::TimeRange,
+) -> errors::RouterResult<HistoricalOutcomes> {
+    let auth = AuthInfo::ProfileLevel {
+        org_id: merchant_account.get_org_id().to_owned(),
+        merchant_id: merchant_account.get_id().to_owned(),
+        profile_ids: vec![business_profile.get_id().to_owned()],
+    };
+    let request = analytics_api::GetPaymentMetricRequest {
+        time_series: None,
+        time_range,
+        group_by_names: vec![
+            PaymentDimensions::Connector,
+            PaymentDimensions::PaymentMethod,
+            PaymentDimensions::Currency,
+        ],
+        filters: PaymentFilters {
+            profile_id: vec![business_profile.get_id().to_owned()],
+            ..Default::default()
+        },
+        metrics: HashSet::from([
+            PaymentMetrics::PaymentCount,
+            PaymentMetrics::PaymentSuccessCount,
+        ]),
+        distribution: None,
+        delta: false,
+    };
+
+    let metrics = analytics::payments::get_metrics(&state.pool, &None, &auth, request)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch historical payment outcomes from analytics")?;
+
+    let mut historical_outcomes = HistoricalOutcomes::default();
+    for bucket in metrics.query_data {
+        let Some(connector) = bucket.dimensions.connector else {
+            continue;
+        };
+        let payment_method = bucket
+            .dimensions
+            .payment_method
+            .as_deref()
+            .and_then(|payment_method| storage_enums::PaymentMethod::from_str(payment_method).ok());
+        historical_outcomes.record(
+            &connector,
+            payment_method,
+            bucket.dimensions.currency,
+            bucket.values.payment_count.unwrap_or_default(),
+            bucket.values.payment_success_count.unwrap_or_default(),
+        );
+    }
+
+    Ok(historical_outcomes)
+}
+
+/// Outcome of a past payment, along with the connector it would have been routed to
+struct PaymentOutcome {
+    payment_method: Option<storage_enums::PaymentMethod>,
+    currency: storage_enums::Currency,
+    historical_connector: String,
+    simulated_connector: Option<String>,
+    is_successful: bool,
+}
+
+// Same as the payment success rate in analytics
+fn is_successful_attempt(status: storage_enums::AttemptStatus) -> bool {
+    status == storage_enums::AttemptStatus::Charged
+}
+
+#[derive(Clone, Copy, Default)]
+struct PaymentCounts {
+    payments: u64,
+    successes: u64,
+}
+
+impl PaymentCounts {
+    fn add(&mut self, payments: u64, successes: u64) {
+        self.payments = self.payments.saturating_add(payments);
+        self.successes = self.successes.saturating_add(successes);
+    }
+
+    fn success_rate(&self) -> Option<f64> {
+        get_success_rate(self.successes, self.payments)
+    }
+}
+
+type PaymentSegment = (
+    String,
+    Option<storage_enums::PaymentMethod>,
+    storage_enums::Currency,
+);
+
+/// Payment counts of the connectors in the simulated window, from analytics
+#[derive(Default)]
+struct HistoricalOutcomes {
+    connectors: HashMap<String, PaymentCounts>,
+    payment_methods: HashMap<(String, Option<storage_enums::PaymentMethod>), PaymentCounts>,
+    currencies: HashMap<(String, storage_enums::Currency), PaymentCounts>,
+    segments: HashMap<PaymentSegment, PaymentCounts>,
+}
+
+impl HistoricalOutcomes {
+    fn record(
+        &mut self,
+        connector: &str,
+        payment_method: Option<storage_enums::PaymentMethod>,
+        currency: Option<storage_enums::Currency>,
+        payments: u64,
+        successes: u64,
+    ) {
+        self.connectors
+            .entry(connector.to_owned())
+            .or_default()
+            .add(payments, successes);
+        self.payment_methods
+            .entry((connector.to_owned(), payment_method))
+            .or_default()
+            .add(payments, successes);
+        if let Some(currency) = currency {
+            self.currencies
+                .entry((connector.to_owned(), currency))
+                .or_default()
+                .add(payments, successes);
+            self.segments
+                .entry((connector.to_owned(), payment_method, currency))
+                .or_default()
+                .add(payments, successes);
+        }
+    }
+
+    /// Success rate of the connector for payments of the payment method and currency of the
+    /// payment if the connector has processed such payments, and across all its payments
+    /// otherwise
+    fn estimate_success_rate(&self, outcome: &PaymentOutcome) -> Option<f64> {
+        let connector = outcome.simulated_connector.as_ref()?;
+        self.segments
+            .get(&(connector.clone(), outcome.payment_method, outcome.currency))
+            .and_then(PaymentCounts::success_rate)
+            .or_else(|| {
+                self.connectors
+                    .get(connector)
+                    .and_then(PaymentCounts::success_rate)
+            })
+    }
+}
+
+#[derive(Default)]
+struct ConnectorTraffic {
+    historical_payments: u64,
+    simulated_payments: u64,
+}
+
+#[derive(Default)]
+struct TrafficShift(BTreeMap<String, ConnectorTraffic>);
+
+impl TrafficShift {
+    fn record(&mut self, outcome: &PaymentOutcome) {
+        self.0
+            .entry(outcome.historical_connector.clone())
+            .or_default()
+            .historical_payments += 1;
+        if let Some(simulated_connector) = &outcome.simulated_connector {
+            self.0
+                .entry(simulated_connector.clone())
+                .or_default()
+                .simulated_payments += 1;
+        }
+    }
+
+    fn into_response(
+        self,
+        historical_counts: impl Fn(&str) -> Option<PaymentCounts>,
+    ) -> Vec<routing_types::ConnectorTrafficShift> {
+        self.0
+            .into_iter()
+            .map(
+                |(connector, traffic)| routing_types::ConnectorTrafficShift {
+                    historical_success_rate: historical_counts(&connector)
+                        .and_then(|counts| counts.success_rate()),
+                    connector,
+                    historical_payments: traffic.historical_payments,
+                    simulated_payments: traffic.simulated_payments,
+                },
+            )
+            .collect()
+    }
+}
+
+struct SimulationReport<'a> {
+    historical_outcomes: &'a HistoricalOutcomes,
+    total_payments: u64,
+    rerouted_payments: u64,
+    unroutable_payments: u64,
+    successful_payments: u64,
+    estimated_success_rate: Option<f64>,
+    connectors: TrafficShift,
+    payment_methods: HashMap<Option<storage_enums::PaymentMethod>, TrafficShift>,
+    currencies: HashMap<storage_enums::Currency, TrafficShift>,
+}
+
+impl<'a> SimulationReport<'a> {
+    fn new(outcomes: &[PaymentOutcome], historical_outcomes: &'a HistoricalOutcomes) -> Self {
+        let mut report = Self {
+            historical_outcomes,
+            total_payments: 0,
+            rerouted_payments: 0,
+            unroutable_payments: 0,
+            successful_payments: 0,
+            estimated_success_rate: None,
+            connectors: TrafficShift::default(),
+            payment_methods: HashMap::new(),
+            currencies: HashMap::new(),
+        };
+        for outcome in outcomes {
+            report.total_payments += 1;
+            if outcome.is_successful {
+                report.successful_payments += 1;
+            }
+            match &outcome.simulated_connector {
+                None => report.unroutable_payments += 1,
+                Some(connector) if *connector != outcome.historical_connector => {
+                    report.rerouted_payments += 1
+                }
+                Some(_) => {}
+            }
+            report.connectors.record(outcome);
+            report
+                .payment_methods
+                .entry(outcome.payment_method)
+                .or_default()
+                .record(outcome);
+            report
+                .currencies
+                .entry(outcome.currency)
+                .or_default()
+                .record(outcome);
+        }
+        report.estimated_success_rate = estimate_success_rate(outcomes, historical_outcomes);
+        report
+    }
+
+    fn into_response(
+        self,
+        algorithm_id: id_type::RoutingId,
+        time_range: common_utils::types::TimeRange,
+    ) -> routing_types::RoutingSimulationResponse {
+        let historical_outcomes = self.historical_outcomes;
+
+        let mut payment_methods = self
+            .payment_methods
+            .into_iter()
+            .map(
+                |(payment_method, traffic)| routing_types::PaymentMethodTrafficShift {
+                    payment_method,
+                    connectors: traffic.into_response(|connector| {
+                        historical_outcomes
+                            .payment_methods
+                            .get(&(connector.to_owned(), payment_method))
+                            .copied()
+                    }),
+                },
+            )
+            .collect::<Vec<_>>();
+        payment_methods.sort_by_key(|traffic| traffic.payment_method.map(|pm| pm.to_string()));
+
+        let mut currencies = self
+            .currencies
+            .into_iter()
+            .map(|(currency, traffic)| routing_types::CurrencyTrafficShift {
+                currency,
+                connectors: traffic.into_response(|connector| {
+                    historical_outcomes
+                        .currencies
+                        .get(&(connector.to_owned(), currency))
+                        .copied()
+                }),
+            })
+            .collect::<Vec<_>>();
+        currencies.sort_by_key(|traffic| traffic.currency.to_string());
+
+        routing_types::RoutingSimulationResponse {
+            algorithm_id,
+            time_range,
+            total_payments: self.total_payments,
+            rerouted_payments: self.rerouted_payments,
+            unroutable_payments: self.unroutable_payments,
+            connectors: self
+                .connectors
+                .into_response(|connector| historical_outcomes.connectors.get(connector).copied()),
+            payment_methods,
+            currencies,
+            historical_success_rate: get_success_rate(
+                self.successful_payments,
+                self.total_payments,
+            ),
+            estimated_success_rate: self.estimated_success_rate,
+        }
+    }
+}
+
+/// Estimates the success rate of the routed payments from the historical success rate of the
+/// connector they are routed to. Payments routed to connectors without any historical payments
+/// are not considered.
+fn estimate_success_rate(
+    outcomes: &[PaymentOutcome],
+    historical_outcomes: &HistoricalOutcomes,
+) -> Option<f64> {
+    let rates = outcomes
+        .iter()
+        .filter_map(|outcome| historical_outcomes.estimate_success_rate(outcome))
+        .collect::<Vec<_>>();
+
+    let count = u32::try_from(rates.len()).ok().filter(|count| *count > 0)?;
+    Some(rates.iter().sum::<f64>() / f64::from(count))
+}
+
+fn get_success_rate(successes: u64, total: u64) -> Option<f64> {
+    // Payment counts are well within the range of `u32`
+    let successes = f64::from(u32::try_from(successes).ok()?);
+    let total = f64::from(u32::try_from(total).ok().filter(|total| *total > 0)?);
+    Some(successes * 100.0 / total)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn outcome(
+        payment_method: storage_enums::PaymentMethod,
+        currency: storage_enums::Currency,
+        historical_connector: &str,
+        simulated_connector: Option<&str>,
+        is_successful: bool,
+    ) -> PaymentOutcome {
+        PaymentOutcome {
+            payment_method: Some(payment_method),
+            currency,
+            historical_connector: historical_connector.to_string(),
+            simulated_connector: simulated_connector.map(ToString::to_string),
+            is_successful,
+        }
+    }
+
+    fn historical_outcomes() -> HistoricalOutcomes {
+        let mut historical_outcomes = HistoricalOutcomes::default();
+        historical_outcomes.record(
+            "stripe",
+            Some(storage_enums::PaymentMethod::Card),
+            Some(storage_enums::Currency::USD),
+            10,
+            9,
+        );
+        historical_outcomes.record(
+            "stripe",
+            Some(storage_enums::PaymentMethod::Wallet),
+            Some(storage_enums::Currency::EUR),
+            10,
+            5,
+        );
+        historical_outcomes.record(
+            "adyen",
+            Some(storage_enums::PaymentMethod::Card),
+            Some(storage_enums::Currency::USD),
+            4,
+            2,
+        );
+        historical_outcomes
+    }
+
+    #[test]
+    fn test_report_counts_rerouted_and_unroutable_payments() {
+        let historical_outcomes = historical_outcomes();
+        let outcomes = vec![
+            outcome(
+                storage_enums::PaymentMethod::Card,
+                storage_enums::Currency::USD,
+                "adyen",
+                Some("stripe"),
+                false,
+            ),
+            outcome(
+                storage_enums::PaymentMethod::Card,
+                storage_enums::Currency::USD,
+                "stripe",
+                Some("stripe"),
+                true,
+            ),
+            outcome(
+                storage_enums::PaymentMethod::Wallet,
+                storage_enums::Currency::EUR,
+                "stripe",
+                None,
+                true,
+            ),
+        ];
+
+        let algorithm_id = id_type::RoutingId::try_from(Cow::from("routing_123"))
+            .expect("Failed to create routing id");
+        let time_range = common_utils::types::TimeRange {
+            start_time: common_utils::date_time::now(),
+            end_time: None,
+        };
+        let response = SimulationReport::new(&outcomes, &historical_outcomes)
+            .into_response(algorithm_id, time_range);
+
+        assert_eq!(response.total_payments, 3);
+        assert_eq!(response.rerouted_payments, 1);
+        assert_eq!(response.unroutable_payments, 1);
+
+        let stripe = response
+            .connectors
+            .iter()
+            .find(|traffic| traffic.connector == "stripe")
+            .expect("Missing stripe traffic");
+        assert_eq!(stripe.historical_payments, 2);
+        assert_eq!(stripe.simulated_payments, 2);
+        // From analytics, across all the payments of the connector
+        assert_eq!(stripe.historical_success_rate, Some(70.0));
+
+        let card_stripe = response
+            .payment_methods
+            .iter()
+            .find(|traffic| traffic.payment_method == Some(storage_enums::PaymentMethod::Card))
+            .and_then(|traffic| {
+                traffic
+                    .connectors
+                    .iter()
+                    .find(|traffic| traffic.connector == "stripe")
+            })
+            .expect("Missing stripe card traffic");
+        assert_eq!(card_stripe.historical_success_rate, Some(90.0));
+
+        assert_eq!(response.estimated_success_rate, Some(90.0));
+    }
+
+    #[test]
+    fn test_success_rate_estimate_falls_back_to_connector_rate() {
+        let historical_outcomes = historical_outcomes();
+        // Adyen has not processed any EUR wallet payments
+        let outcomes = vec![
+            outcome(
+                storage_enums::PaymentMethod::Wallet,
+                storage_enums::Currency::EUR,
+                "stripe",
+                Some("adyen"),
+                true,
+            ),
+            outcome(
+                storage_enums::PaymentMethod::Card,
+                storage_enums::Currency::USD,
+                "stripe",
+                Some("checkout"),
+                true,
+            ),
+        ];
+
+        assert_eq!(
+            estimate_success_rate(&outcomes, &historical_outcomes),
+            Some(50.0)
+        );
+    }
+
+    #[test]
+    fn test_success_rate_of_no_payments() {
+        assert_eq!(get_success_rate(0, 0), None);
+        assert_eq!(get_success_rate(1, 4), Some(25.0));
+        assert_eq!(
+            estimate_success_rate(&[], &HistoricalOutcomes::default()),
+            None
+        );
+    }
+}
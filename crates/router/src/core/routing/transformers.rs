@@ -1,6 +1,6 @@
 use api_models::routing::{
-    MerchantRoutingAlgorithm, RoutingAlgorithm as Algorithm, RoutingAlgorithmKind,
-    RoutingDictionaryRecord,
+    MerchantRoutingAlgorithm, RoutingActivationRecord, RoutingAlgorithm as Algorithm,
+    RoutingAlgorithmKind, RoutingDictionaryRecord,
 };
 #[cfg(feature = "v1")]
 use api_models::{
@@ -11,6 +11,7 @@ use common_utils::ext_traits::ValueExt;
 use diesel_models::{
     enums as storage_enums,
     routing_algorithm::{RoutingAlgorithm, RoutingProfileMetadata},
+    routing_algorithm_activation::RoutingAlgorithmActivation,
 };
 #[cfg(feature = "v1")]
 use hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt;
@@ -55,6 +56,21 @@ impl ForeignFrom<RoutingAlgorithm> for RoutingDictionaryRecord {
     }
 }
 
+impl ForeignFrom<RoutingAlgorithmActivation> for RoutingActivationRecord {
+    fn foreign_from(value: RoutingAlgorithmActivation) -> Self {
+        Self {
+            id: value.activation_id,
+            profile_id: value.profile_id,
+            transaction_type: value.transaction_type,
+            previous_algorithm_id: value.previous_algorithm_id,
+            algorithm_id: value.algorithm_id,
+            activated_by: value.activated_by,
+            reason: value.reason,
+            created_at: value.created_at,
+        }
+    }
+}
+
 impl ForeignTryFrom<RoutingAlgorithm> for MerchantRoutingAlgorithm {
     type Error = error_stack::Report<errors::ParsingError>;
 
@@ -1,8 +1,12 @@
-use api_models::surcharge_decision_configs::{
-    SurchargeDecisionConfigReq, SurchargeDecisionManagerRecord, SurchargeDecisionManagerResponse,
+use api_models::{
+    conditional_configs::DecisionManagerAnalysisResponse,
+    surcharge_decision_configs::{
+        SurchargeDecisionConfigReq, SurchargeDecisionManagerRecord,
+        SurchargeDecisionManagerResponse,
+    },
 };
 use common_utils::ext_traits::StringExt;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 
 use crate::{
     core::errors::{self, RouterResponse},
@@ -162,6 +166,31 @@ pub async fn upsert_surcharge_decision_config(
     todo!();
 }
 
+/// Reports the unreachable, shadowed and overlapping rules of the config without saving it
+pub async fn analyze_surcharge_decision_config(
+    request: SurchargeDecisionConfigReq,
+) -> RouterResponse<DecisionManagerAnalysisResponse> {
+    use common_utils::ext_traits::OptionExt;
+
+    let program = request
+        .algorithm
+        .get_required_value("algorithm")
+        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "algorithm",
+        })
+        .attach_printable("Program for config not given")?;
+
+    let findings = euclid::dssa::report::analyze_rules(program).map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.to_string(),
+        })
+    })?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        DecisionManagerAnalysisResponse { findings },
+    ))
+}
+
 #[cfg(feature = "v1")]
 pub async fn delete_surcharge_decision_config(
     state: SessionState,
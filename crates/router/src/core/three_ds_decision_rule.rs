@@ -0,0 +1,245 @@
+use api_models::three_ds_decision_rule::{
+    ThreeDsDecisionRuleExecuteRequest, ThreeDsDecisionRuleExecuteResponse,
+};
+use common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule;
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use error_stack::{report, ResultExt};
+use euclid::{
+    backend::{self, EuclidBackend},
+    frontend::ast,
+};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services::api as service_api,
+    types::{domain, storage},
+};
+
+/// Evaluates a 3DS decision rule program against the parameters of a payment, returning the
+/// decision along with the trace of the rules which were evaluated
+pub async fn execute_three_ds_decision_rule(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: ThreeDsDecisionRuleExecuteRequest,
+) -> RouterResponse<ThreeDsDecisionRuleExecuteResponse> {
+    let program = match ProgramInput::try_from_request(request.program, request.routing_id)? {
+        ProgramInput::Program(program) => program,
+        ProgramInput::RoutingId(routing_id) => {
+            get_stored_three_ds_decision_rule_program(
+                &state,
+                &merchant_context,
+                profile_id,
+                &routing_id,
+            )
+            .await?
+        }
+    };
+
+    let interpreter = backend::VirInterpreterBackend::with_program(program).map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.to_string(),
+        })
+    })?;
+
+    let (output, trace) = interpreter
+        .execute_with_trace(request.parameters)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to execute the 3DS decision rule program")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        ThreeDsDecisionRuleExecuteResponse {
+            decision: output.connector_selection.decision,
+            rule_name: output.rule_name,
+            trace,
+        },
+    ))
+}
+
+/// The program to be evaluated, either provided in the request or stored as a routing algorithm
+#[derive(Debug)]
+enum ProgramInput {
+    Program(ast::Program<ThreeDSDecisionRule>),
+    RoutingId(common_utils::id_type::RoutingId),
+}
+
+impl ProgramInput {
+    fn try_from_request(
+        program: Option<ast::Program<ThreeDSDecisionRule>>,
+        routing_id: Option<common_utils::id_type::RoutingId>,
+    ) -> RouterResult<Self> {
+        match (program, routing_id) {
+            (Some(program), None) => Ok(Self::Program(program)),
+            (None, Some(routing_id)) => Ok(Self::RoutingId(routing_id)),
+            _ => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Exactly one of `program` and `routing_id` has to be provided".to_string(),
+            })),
+        }
+    }
+}
+
+/// 3DS decision rule programs are stored like advanced routing algorithms, with the 3DS decision
+/// as the output of their rules
+#[derive(Debug, serde::Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+enum StoredThreeDsDecisionRuleAlgorithm {
+    Advanced(ast::Program<ThreeDSDecisionRule>),
+}
+
+/// Fetches a stored 3DS decision rule program, which has to belong to the merchant and to the
+/// profile of the user when the user is scoped to a profile
+async fn get_stored_three_ds_decision_rule_program(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    routing_id: &common_utils::id_type::RoutingId,
+) -> RouterResult<ast::Program<ThreeDSDecisionRule>> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(routing_id, merchant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        &state.into(),
+        merchant_context.get_merchant_key_store(),
+        Some(&algorithm.profile_id),
+        merchant_id,
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &business_profile)?;
+
+    parse_three_ds_decision_rule_algorithm(algorithm)
+}
+
+fn parse_three_ds_decision_rule_algorithm(
+    algorithm: storage::RoutingAlgorithm,
+) -> RouterResult<ast::Program<ThreeDSDecisionRule>> {
+    let invalid_algorithm_error = || errors::ApiErrorResponse::InvalidRequestData {
+        message: format!(
+            "The routing algorithm {} is not a 3DS decision rule algorithm",
+            algorithm.algorithm_id.get_string_repr()
+        ),
+    };
+
+    if algorithm.kind != storage::enums::RoutingAlgorithmKind::Advanced
+        || algorithm.algorithm_for != storage::enums::TransactionType::Payment
+    {
+        return Err(report!(invalid_algorithm_error()));
+    }
+
+    algorithm
+        .algorithm_data
+        .parse_value::<StoredThreeDsDecisionRuleAlgorithm>("StoredThreeDsDecisionRuleAlgorithm")
+        .change_context_lazy(invalid_algorithm_error)
+        .map(|StoredThreeDsDecisionRuleAlgorithm::Advanced(program)| program)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::borrow::Cow;
+
+    use common_types::three_ds_decision_rule_engine::ThreeDSDecision;
+
+    use super::*;
+
+    fn program() -> ast::Program<ThreeDSDecisionRule> {
+        serde_json::from_value(serde_json::json!({
+            "defaultSelection": { "decision": "challenge_requested" },
+            "rules": [],
+            "metadata": {}
+        }))
+        .expect("Invalid program")
+    }
+
+    fn routing_id() -> common_utils::id_type::RoutingId {
+        common_utils::id_type::RoutingId::try_from(Cow::from("routing_1"))
+            .expect("Invalid routing id")
+    }
+
+    fn algorithm(
+        kind: storage::enums::RoutingAlgorithmKind,
+        algorithm_data: serde_json::Value,
+    ) -> storage::RoutingAlgorithm {
+        let now = common_utils::date_time::now();
+        storage::RoutingAlgorithm {
+            algorithm_id: routing_id(),
+            profile_id: common_utils::id_type::ProfileId::try_from(Cow::from("pro_1"))
+                .expect("Invalid profile id"),
+            merchant_id: common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1"))
+                .expect("Invalid merchant id"),
+            name: "3DS decision rules".to_string(),
+            description: None,
+            kind,
+            algorithm_data,
+            created_at: now,
+            modified_at: now,
+            algorithm_for: storage::enums::TransactionType::Payment,
+            decision_engine_routing_id: None,
+        }
+    }
+
+    #[test]
+    fn test_program_input_from_request() {
+        assert!(matches!(
+            ProgramInput::try_from_request(Some(program()), None),
+            Ok(ProgramInput::Program(program)) if program == self::program()
+        ));
+        assert!(matches!(
+            ProgramInput::try_from_request(None, Some(routing_id())),
+            Ok(ProgramInput::RoutingId(routing_id)) if routing_id == self::routing_id()
+        ));
+        assert!(ProgramInput::try_from_request(Some(program()), Some(routing_id())).is_err());
+        assert!(ProgramInput::try_from_request(None, None).is_err());
+    }
+
+    #[test]
+    fn test_parse_stored_three_ds_decision_rule_algorithm() {
+        let stored_program = parse_three_ds_decision_rule_algorithm(algorithm(
+            storage::enums::RoutingAlgorithmKind::Advanced,
+            serde_json::json!({ "type": "advanced", "data": program() }),
+        ))
+        .expect("3DS decision rule algorithm was rejected");
+
+        assert_eq!(
+            stored_program.default_selection.decision,
+            ThreeDSDecision::ChallengeRequested
+        );
+    }
+
+    #[test]
+    fn test_reject_stored_routing_algorithms() {
+        // An advanced routing algorithm selects connectors instead of a 3DS decision
+        let connector_selection_program = serde_json::json!({
+            "type": "advanced",
+            "data": {
+                "defaultSelection": { "type": "priority", "data": [] },
+                "rules": [],
+                "metadata": {}
+            }
+        });
+        assert!(parse_three_ds_decision_rule_algorithm(algorithm(
+            storage::enums::RoutingAlgorithmKind::Advanced,
+            connector_selection_program,
+        ))
+        .is_err());
+
+        assert!(parse_three_ds_decision_rule_algorithm(algorithm(
+            storage::enums::RoutingAlgorithmKind::Priority,
+            serde_json::json!({ "type": "advanced", "data": program() }),
+        ))
+        .is_err());
+    }
+}
@@ -123,6 +123,7 @@ pub fn construct_uas_router_data<F: Clone, Req, Res>(
         authentication_id,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: None,
     })
 }
 
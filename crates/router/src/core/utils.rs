@@ -235,6 +235,7 @@ pub async fn construct_payout_router_data<'a, F>(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
     };
 
     Ok(router_data)
@@ -391,6 +392,7 @@ pub async fn construct_refund_router_data<'a, F>(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: Some(merchant_connector_account_id.clone()),
     };
 
     Ok(router_data)
@@ -594,6 +596,7 @@ pub async fn construct_refund_router_data<'a, F>(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
     };
 
     Ok(router_data)
@@ -1035,6 +1038,7 @@ pub async fn construct_accept_dispute_router_data<'a>(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: None,
     };
     Ok(router_data)
 }
@@ -1133,6 +1137,7 @@ pub async fn construct_submit_evidence_router_data<'a>(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: None,
     };
     Ok(router_data)
 }
@@ -1237,6 +1242,7 @@ pub async fn construct_upload_file_router_data<'a>(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: None,
     };
     Ok(router_data)
 }
@@ -1360,6 +1366,7 @@ pub async fn construct_payments_dynamic_tax_calculation_router_data<F: Clone>(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: None,
     };
     Ok(router_data)
 }
@@ -1461,6 +1468,7 @@ pub async fn construct_defend_dispute_router_data<'a>(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: None,
     };
     Ok(router_data)
 }
@@ -1556,6 +1564,7 @@ pub async fn construct_retrieve_file_router_data<'a>(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: None,
     };
     Ok(router_data)
 }
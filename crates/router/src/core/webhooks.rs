@@ -8,17 +8,22 @@ mod outgoing;
 mod outgoing_v2;
 #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
 pub mod recovery_incoming;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod secret_rotation;
 pub mod types;
 pub mod utils;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod webhook_endpoints;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
 
 #[cfg(feature = "v1")]
 pub(crate) use self::{
-    incoming::incoming_webhooks_wrapper,
+    incoming::{incoming_webhooks_wrapper, is_queued_webhook_pending, process_queued_webhook},
     outgoing::{
-        create_event_and_trigger_outgoing_webhook, get_outgoing_webhook_request,
-        trigger_webhook_and_raise_event,
+        create_event_and_trigger_outgoing_webhook,
+        create_event_and_trigger_outgoing_webhook_with_idempotent_event_id,
+        get_outgoing_webhook_request, trigger_webhook_and_raise_event,
     },
 };
 #[cfg(feature = "v2")]
@@ -1,4 +1,6 @@
-use std::{str::FromStr, time::Instant};
+mod queue;
+
+use std::time::Instant;
 
 use actix_web::FromRequest;
 #[cfg(feature = "payouts")]
@@ -21,7 +23,7 @@ use super::{types, utils, MERCHANT_ID};
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
         metrics,
         payments::{self, tokenization},
@@ -53,6 +55,8 @@ use crate::{
 #[cfg(feature = "payouts")]
 use crate::{core::payouts, types::storage::PayoutAttemptUpdate};
 
+pub(crate) use self::queue::{is_queued_webhook_pending, process_queued_webhook};
+
 #[allow(clippy::too_many_arguments)]
 pub async fn incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
     flow: &impl router_env::types::FlowMetric,
@@ -422,24 +426,30 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                     &connector,
                     &request_details,
                     event_type,
+                    false,
                 ))
                 .await
                 .attach_printable("Incoming webhook flow for payments failed"),
 
-                api::WebhookFlow::Refund => Box::pin(refunds_incoming_webhook_flow(
+                api::WebhookFlow::Refund => Box::pin(queue::process_queueable_webhook_flow(
                     state.clone(),
+                    req_state,
                     merchant_context,
                     business_profile,
                     webhook_details,
-                    connector_name.as_str(),
                     source_verified,
+                    &connector,
+                    &request_details,
                     event_type,
+                    types::QueuedWebhookFlow::Refund,
+                    false,
                 ))
                 .await
                 .attach_printable("Incoming webhook flow for refunds failed"),
 
-                api::WebhookFlow::Dispute => Box::pin(disputes_incoming_webhook_flow(
+                api::WebhookFlow::Dispute => Box::pin(queue::process_queueable_webhook_flow(
                     state.clone(),
+                    req_state,
                     merchant_context,
                     business_profile,
                     webhook_details,
@@ -447,6 +457,8 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                     &connector,
                     &request_details,
                     event_type,
+                    types::QueuedWebhookFlow::Dispute,
+                    false,
                 ))
                 .await
                 .attach_printable("Incoming webhook flow for disputes failed"),
@@ -464,13 +476,18 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
 
                 api::WebhookFlow::ReturnResponse => Ok(WebhookResponseTracker::NoEffect),
 
-                api::WebhookFlow::Mandate => Box::pin(mandates_incoming_webhook_flow(
+                api::WebhookFlow::Mandate => Box::pin(queue::process_queueable_webhook_flow(
                     state.clone(),
+                    req_state,
                     merchant_context,
                     business_profile,
                     webhook_details,
                     source_verified,
+                    &connector,
+                    &request_details,
                     event_type,
+                    types::QueuedWebhookFlow::Mandate,
+                    false,
                 ))
                 .await
                 .attach_printable("Incoming webhook flow for mandates failed"),
@@ -604,9 +621,10 @@ async fn payments_incoming_webhook_flow(
     connector: &ConnectorEnum,
     request_details: &IncomingWebhookRequestDetails<'_>,
     event_type: webhooks::IncomingWebhookEvent,
+    is_queued_webhook: bool,
 ) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
     let consume_or_trigger_flow = if source_verified {
-        payments::CallConnectorAction::HandleResponse(webhook_details.resource_object)
+        payments::CallConnectorAction::HandleResponse(webhook_details.resource_object.clone())
     } else {
         payments::CallConnectorAction::Trigger
     };
@@ -620,21 +638,28 @@ async fn payments_incoming_webhook_flow(
             )
             .await?;
 
-            let lock_action = api_locking::LockAction::Hold {
-                input: api_locking::LockingInput {
-                    unique_locking_key: payment_id.get_string_repr().to_owned(),
-                    api_identifier: lock_utils::ApiIdentifier::Payments,
-                    override_lock_retries: None,
-                },
+            let locking_input = api_locking::LockingInput {
+                unique_locking_key: payment_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::Payments,
+                override_lock_retries: None,
+            };
+            let Some(lock_action) = Box::pin(queue::lock_or_queue_webhook(
+                &state,
+                &merchant_context,
+                &business_profile,
+                connector,
+                request_details,
+                event_type,
+                types::QueuedWebhookFlow::Payment,
+                &webhook_details,
+                source_verified,
+                locking_input,
+                is_queued_webhook,
+            ))
+            .await?
+            else {
+                return Ok(WebhookResponseTracker::NoEffect);
             };
-
-            lock_action
-                .clone()
-                .perform_locking_action(
-                    &state,
-                    merchant_context.get_merchant_account().get_id().to_owned(),
-                )
-                .await?;
 
             let response = Box::pin(payments::payments_core::<
                 api::PSync,
@@ -1647,6 +1672,13 @@ async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
+        // The reminders and the dispute policy are not essential to the webhook being processed
+        disputes::deadline::add_dispute_deadline_task(&state, &business_profile, &dispute_object)
+            .await
+            .inspect_err(|error| {
+                logger::error!(?error, "Failed to add dispute deadline task");
+            })
+            .ok();
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
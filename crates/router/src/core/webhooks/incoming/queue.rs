@@ -0,0 +1,664 @@
+use std::str::FromStr;
+
+use api_models::webhooks::{self, WebhookResponseTracker};
+use base64::Engine;
+use common_utils::{
+    encryption::Encryption, ext_traits::Encode, type_name, types::keymanager::Identifier,
+};
+use error_stack::ResultExt;
+use hyperswitch_interfaces::webhooks::IncomingWebhookRequestDetails;
+use masking::{ExposeInterface, PeekInterface, Secret};
+use redis_interface::RedisKey;
+use router_env::{instrument, tracing};
+
+use super::{
+    super::{types, MERCHANT_ID},
+    disputes_incoming_webhook_flow, get_connector_by_connector_name,
+    mandates_incoming_webhook_flow, payments_incoming_webhook_flow, refunds_incoming_webhook_flow,
+};
+use crate::{
+    consts,
+    core::{
+        api_locking,
+        errors::{self, CustomResult, RouterResult},
+        metrics,
+    },
+    logger,
+    routes::{app::SessionStateInfo, lock_utils, SessionState},
+    services::connector_integration_interface::ConnectorEnum,
+    types::{api, domain, storage},
+    utils::generate_id,
+};
+
+const INCOMING_WEBHOOK_QUEUE_PREFIX: &str = "INCOMING_WEBHOOK_QUEUE";
+
+/// Expiry of the ID of the last webhook queued for an object, longer than the time for which a
+/// queued webhook is retried
+const LAST_QUEUED_WEBHOOK_EXPIRY_IN_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Replaces the ID of the last webhook queued for an object with the ID in `ARGV[1]`, returning
+/// the ID it replaced.
+const SET_LAST_QUEUED_WEBHOOK_SCRIPT: &str = r#"
+local previous = redis.call("GET", KEYS[1])
+redis.call("SET", KEYS[1], ARGV[1], "EX", ARGV[2])
+return previous
+"#;
+
+/// Processes the flows of the webhooks that are queued while their object is locked.
+///
+/// The lock on the payment of a payments webhook is acquired by the payments flow, since the
+/// payment ID is only known once the object reference ID of the webhook has been resolved. The
+/// other flows are locked on the object reference ID of the webhook.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub(super) async fn process_queueable_webhook_flow(
+    state: SessionState,
+    req_state: crate::routes::app::ReqState,
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    connector: &ConnectorEnum,
+    request_details: &IncomingWebhookRequestDetails<'_>,
+    event_type: webhooks::IncomingWebhookEvent,
+    flow: types::QueuedWebhookFlow,
+    is_queued_webhook: bool,
+) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
+    let lock_action = match get_locking_input(flow, &webhook_details.object_reference_id) {
+        Some(locking_input) => {
+            let lock_action = Box::pin(lock_or_queue_webhook(
+                &state,
+                &merchant_context,
+                &business_profile,
+                connector,
+                request_details,
+                event_type,
+                flow,
+                &webhook_details,
+                source_verified,
+                locking_input,
+                is_queued_webhook,
+            ))
+            .await?;
+            match lock_action {
+                Some(lock_action) => Some(lock_action),
+                None => return Ok(WebhookResponseTracker::NoEffect),
+            }
+        }
+        None => None,
+    };
+    let merchant_id = merchant_context.get_merchant_account().get_id().to_owned();
+
+    let response = match flow {
+        types::QueuedWebhookFlow::Payment => {
+            Box::pin(payments_incoming_webhook_flow(
+                state.clone(),
+                req_state,
+                merchant_context,
+                business_profile,
+                webhook_details,
+                source_verified,
+                connector,
+                request_details,
+                event_type,
+                is_queued_webhook,
+            ))
+            .await
+        }
+        types::QueuedWebhookFlow::Refund => {
+            Box::pin(refunds_incoming_webhook_flow(
+                state.clone(),
+                merchant_context,
+                business_profile,
+                webhook_details,
+                connector.id(),
+                source_verified,
+                event_type,
+            ))
+            .await
+        }
+        types::QueuedWebhookFlow::Dispute => {
+            Box::pin(disputes_incoming_webhook_flow(
+                state.clone(),
+                merchant_context,
+                business_profile,
+                webhook_details,
+                source_verified,
+                connector,
+                request_details,
+                event_type,
+            ))
+            .await
+        }
+        types::QueuedWebhookFlow::Mandate => {
+            Box::pin(mandates_incoming_webhook_flow(
+                state.clone(),
+                merchant_context,
+                business_profile,
+                webhook_details,
+                source_verified,
+                event_type,
+            ))
+            .await
+        }
+    };
+
+    if let Some(lock_action) = lock_action {
+        lock_action.free_lock_action(&state, merchant_id).await?;
+    }
+    response
+}
+
+/// Lock on the object of a webhook of the flows other than the payments flow
+fn get_locking_input(
+    flow: types::QueuedWebhookFlow,
+    object_reference_id: &webhooks::ObjectReferenceId,
+) -> Option<api_locking::LockingInput> {
+    let (unique_locking_key, api_identifier) = match (flow, object_reference_id) {
+        (
+            types::QueuedWebhookFlow::Refund,
+            webhooks::ObjectReferenceId::RefundId(webhooks::RefundIdType::RefundId(id)),
+        ) => (format!("refund_{id}"), lock_utils::ApiIdentifier::Refunds),
+        (
+            types::QueuedWebhookFlow::Refund,
+            webhooks::ObjectReferenceId::RefundId(webhooks::RefundIdType::ConnectorRefundId(id)),
+        ) => (
+            format!("connector_refund_{id}"),
+            lock_utils::ApiIdentifier::Refunds,
+        ),
+        (
+            types::QueuedWebhookFlow::Mandate,
+            webhooks::ObjectReferenceId::MandateId(webhooks::MandateIdType::MandateId(id)),
+        ) => (format!("mandate_{id}"), lock_utils::ApiIdentifier::Mandates),
+        (
+            types::QueuedWebhookFlow::Mandate,
+            webhooks::ObjectReferenceId::MandateId(webhooks::MandateIdType::ConnectorMandateId(id)),
+        ) => (
+            format!("connector_mandate_{id}"),
+            lock_utils::ApiIdentifier::Mandates,
+        ),
+        // Disputes are referenced by their payment
+        (types::QueuedWebhookFlow::Dispute, webhooks::ObjectReferenceId::PaymentId(payment_id)) => {
+            (
+                format!("dispute_{}", get_payment_id_type_key(payment_id)),
+                lock_utils::ApiIdentifier::Disputes,
+            )
+        }
+        _ => return None,
+    };
+
+    Some(api_locking::LockingInput {
+        unique_locking_key,
+        api_identifier,
+        override_lock_retries: None,
+    })
+}
+
+fn get_payment_id_type_key(payment_id: &api_models::payments::PaymentIdType) -> String {
+    match payment_id {
+        api_models::payments::PaymentIdType::PaymentIntentId(id) => {
+            format!("payment_{}", id.get_string_repr())
+        }
+        api_models::payments::PaymentIdType::ConnectorTransactionId(id) => {
+            format!("connector_transaction_{id}")
+        }
+        api_models::payments::PaymentIdType::PaymentAttemptId(id) => format!("attempt_{id}"),
+        api_models::payments::PaymentIdType::PreprocessingId(id) => {
+            format!("preprocessing_{id}")
+        }
+    }
+}
+
+/// Acquires the lock on the object of the webhook.
+///
+/// The webhook is queued instead if the object is locked, or if webhooks received earlier for
+/// the object are still queued, so that the webhooks of an object are processed in the order
+/// they were received. Returns `None` if the webhook was queued.
+///
+/// Queued webhooks have already been acknowledged to the connector, and wait for the lock instead
+/// of being queued again.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub(super) async fn lock_or_queue_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &domain::Profile,
+    connector: &ConnectorEnum,
+    request_details: &IncomingWebhookRequestDetails<'_>,
+    event_type: webhooks::IncomingWebhookEvent,
+    flow: types::QueuedWebhookFlow,
+    webhook_details: &api::IncomingWebhookDetails,
+    source_verified: bool,
+    locking_input: api_locking::LockingInput,
+    is_queued_webhook: bool,
+) -> RouterResult<Option<api_locking::LockAction>> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    if is_queued_webhook {
+        let lock_action = api_locking::LockAction::Hold {
+            input: locking_input,
+        };
+        lock_action
+            .clone()
+            .perform_locking_action(state, merchant_id.to_owned())
+            .await?;
+        return Ok(Some(lock_action));
+    }
+
+    let queue_key = format!(
+        "{INCOMING_WEBHOOK_QUEUE_PREFIX}_{}_{}",
+        merchant_id.get_string_repr(),
+        locking_input.unique_locking_key
+    );
+
+    if get_pending_queued_webhook(state, &queue_key)
+        .await?
+        .is_none()
+    {
+        let lock_action = api_locking::LockAction::QueueWithOk {
+            input: locking_input,
+        };
+        let lock_status = lock_action
+            .clone()
+            .perform_locking_action(state, merchant_id.to_owned())
+            .await?;
+        if lock_status == api_locking::LockStatus::Acquired {
+            return Ok(Some(lock_action));
+        }
+    }
+
+    add_webhook_to_queue(
+        state,
+        merchant_context,
+        business_profile,
+        connector,
+        request_details,
+        event_type,
+        flow,
+        webhook_details,
+        source_verified,
+        &queue_key,
+    )
+    .await?;
+
+    Ok(None)
+}
+
+/// Returns the process tracker ID of the last webhook queued for an object, if it has not been
+/// processed yet
+async fn get_pending_queued_webhook(
+    state: &SessionState,
+    queue_key: &str,
+) -> RouterResult<Option<String>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let last_queued_process_id = redis_conn
+        .get_key::<Option<String>>(&queue_key.into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the last queued incoming webhook")?;
+
+    match last_queued_process_id {
+        Some(process_id) if is_queued_webhook_pending(state, &process_id).await? => {
+            Ok(Some(process_id))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Checks whether a queued webhook is yet to be processed
+pub(crate) async fn is_queued_webhook_pending(
+    state: &SessionState,
+    process_id: &str,
+) -> RouterResult<bool> {
+    let process = state
+        .store
+        .find_process_by_id(process_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find queued incoming webhook")?;
+
+    Ok(process
+        .is_some_and(|process| process.status != storage::enums::ProcessTrackerStatus::Finish))
+}
+
+/// Queues a webhook received while its object was locked, to be processed by the scheduler once
+/// the lock is released and the webhooks queued before it for the object have been processed
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn add_webhook_to_queue(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &domain::Profile,
+    connector: &ConnectorEnum,
+    request_details: &IncomingWebhookRequestDetails<'_>,
+    event_type: webhooks::IncomingWebhookEvent,
+    flow: types::QueuedWebhookFlow,
+    webhook_details: &api::IncomingWebhookDetails,
+    source_verified: bool,
+    queue_key: &str,
+) -> RouterResult<()> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let object_reference_id = types::QueuedWebhookObjectReferenceId::from_object_reference_id(
+        &webhook_details.object_reference_id,
+    )
+    .ok_or(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unexpected object reference id of queued incoming webhook")?;
+    let queued_webhook_details = types::QueuedWebhookDetails {
+        object_reference_id,
+        resource_object: consts::BASE64_ENGINE.encode(&webhook_details.resource_object),
+        request: types::QueuedWebhookRequestDetails {
+            method: request_details.method.to_string(),
+            uri: request_details.uri.to_string(),
+            headers: request_details
+                .headers
+                .iter()
+                .map(|(name, value)| {
+                    (
+                        name.as_str().to_owned(),
+                        String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                    )
+                })
+                .collect(),
+            body: consts::BASE64_ENGINE.encode(request_details.body),
+            query_params: request_details.query_params.clone(),
+        },
+    };
+    let webhook_details = encrypt_webhook_details(
+        state,
+        merchant_context.get_merchant_key_store(),
+        &queued_webhook_details,
+    )
+    .await?;
+
+    let runner = storage::ProcessTrackerRunner::IncomingWebhookQueueWorkflow;
+    let task = "INCOMING_WEBHOOK_QUEUE";
+    let tag = ["INCOMING_WEBHOOKS"];
+    // Every queued webhook is a separate task, linked to the webhook queued before it for the
+    // same object
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        task,
+        &generate_id(consts::ID_LENGTH, "whq"),
+        merchant_id,
+    );
+    let previous_process_id = set_last_queued_webhook(state, queue_key, &process_tracker_id)
+        .await?
+        .filter(|previous_process_id| previous_process_id != &process_tracker_id);
+
+    let tracking_data = types::IncomingWebhookQueueTrackingData {
+        merchant_id: merchant_id.clone(),
+        business_profile_id: business_profile.get_id().to_owned(),
+        connector_name: connector.id().to_owned(),
+        event_type,
+        flow,
+        previous_process_id,
+        source_verified,
+        webhook_details,
+    };
+    let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+        state
+            .conf
+            .webhooks
+            .incoming_queue
+            .retry_delay_in_secs
+            .into(),
+    ));
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct incoming webhook queue process tracker task")?;
+
+    let attributes = router_env::metric_attributes!(("flow", "IncomingWebhookQueue"));
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => crate::routes::metrics::TASKS_ADDED_COUNT.add(1, attributes),
+        Err(error) => {
+            crate::routes::metrics::TASK_ADDITION_FAILURES_COUNT.add(1, attributes);
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to add incoming webhook to the queue");
+        }
+    }
+
+    logger::info!(?flow, "Queued incoming webhook as its object is locked");
+    metrics::WEBHOOK_INCOMING_QUEUED_COUNT.add(
+        1,
+        router_env::metric_attributes!(
+            (MERCHANT_ID, merchant_id.clone()),
+            ("connector", connector.id().to_owned())
+        ),
+    );
+
+    Ok(())
+}
+
+async fn set_last_queued_webhook(
+    state: &SessionState,
+    queue_key: &str,
+    process_id: &str,
+) -> RouterResult<Option<String>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .evaluate_redis_script::<_, Option<String>>(
+            SET_LAST_QUEUED_WEBHOOK_SCRIPT,
+            vec![RedisKey::from(queue_key).tenant_aware_key(&redis_conn)],
+            vec![
+                process_id.to_owned(),
+                LAST_QUEUED_WEBHOOK_EXPIRY_IN_SECS.to_string(),
+            ],
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set the last queued incoming webhook")
+}
+
+async fn encrypt_webhook_details(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    webhook_details: &types::QueuedWebhookDetails,
+) -> RouterResult<Encryption> {
+    let webhook_details = webhook_details
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize queued incoming webhook")?;
+
+    domain::types::crypto_operation::<String, masking::WithType>(
+        &state.into(),
+        type_name!(storage::ProcessTracker),
+        domain::types::CryptoOperation::Encrypt(Secret::new(webhook_details)),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|webhook_details| webhook_details.try_into_operation())
+    .map(Encryption::from)
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt queued incoming webhook")
+}
+
+async fn decrypt_webhook_details(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    webhook_details: Encryption,
+) -> RouterResult<types::QueuedWebhookDetails> {
+    let webhook_details = domain::types::crypto_operation::<String, masking::WithType>(
+        &state.into(),
+        type_name!(storage::ProcessTracker),
+        domain::types::CryptoOperation::Decrypt(webhook_details),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|webhook_details| webhook_details.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt queued incoming webhook")?;
+
+    serde_json::from_str(&webhook_details.into_inner().expose())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize queued incoming webhook")
+}
+
+/// Processes a webhook queued by [`lock_or_queue_webhook`]
+#[instrument(skip_all)]
+pub(crate) async fn process_queued_webhook(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    tracking_data: types::IncomingWebhookQueueTrackingData,
+) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
+    let (connector, _) =
+        get_connector_by_connector_name(&state, &tracking_data.connector_name, None)?;
+    let queued_webhook_details = decrypt_webhook_details(
+        &state,
+        merchant_context.get_merchant_key_store(),
+        tracking_data.webhook_details,
+    )
+    .await?;
+
+    let request = queued_webhook_details.request;
+    let mut headers = actix_web::http::header::HeaderMap::new();
+    for (name, value) in request.headers {
+        headers.append(
+            actix_web::http::header::HeaderName::from_str(&name)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse header name of queued webhook")?,
+            actix_web::http::header::HeaderValue::from_str(&value)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse header value of queued webhook")?,
+        );
+    }
+    let body = consts::BASE64_ENGINE
+        .decode(request.body)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decode body of queued webhook")?;
+    let request_details = IncomingWebhookRequestDetails {
+        method: actix_web::http::Method::from_str(&request.method)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse method of queued webhook")?,
+        uri: actix_web::http::Uri::from_str(&request.uri)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse uri of queued webhook")?,
+        headers: &headers,
+        body: &body,
+        query_params: request.query_params,
+    };
+
+    let webhook_details = api::IncomingWebhookDetails {
+        object_reference_id: queued_webhook_details
+            .object_reference_id
+            .into_object_reference_id(),
+        resource_object: consts::BASE64_ENGINE
+            .decode(queued_webhook_details.resource_object)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to decode resource object of queued webhook")?,
+    };
+
+    let req_state = state.get_req_state();
+    Box::pin(process_queueable_webhook_flow(
+        state,
+        req_state,
+        merchant_context,
+        business_profile,
+        webhook_details,
+        tracking_data.source_verified,
+        &connector,
+        &request_details,
+        tracking_data.event_type,
+        tracking_data.flow,
+        true,
+    ))
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locking_input_of_refund_and_mandate_webhooks() {
+        let refund = get_locking_input(
+            types::QueuedWebhookFlow::Refund,
+            &webhooks::ObjectReferenceId::RefundId(webhooks::RefundIdType::ConnectorRefundId(
+                "re_123".to_string(),
+            )),
+        )
+        .map(|input| input.unique_locking_key);
+        assert_eq!(refund.as_deref(), Some("connector_refund_re_123"));
+
+        let mandate = get_locking_input(
+            types::QueuedWebhookFlow::Mandate,
+            &webhooks::ObjectReferenceId::MandateId(webhooks::MandateIdType::MandateId(
+                "man_123".to_string(),
+            )),
+        )
+        .map(|input| input.unique_locking_key);
+        assert_eq!(mandate.as_deref(), Some("mandate_man_123"));
+    }
+
+    #[test]
+    fn test_locking_input_of_dispute_webhooks() {
+        let dispute = get_locking_input(
+            types::QueuedWebhookFlow::Dispute,
+            &webhooks::ObjectReferenceId::PaymentId(
+                api_models::payments::PaymentIdType::ConnectorTransactionId("txn_1".to_string()),
+            ),
+        )
+        .map(|input| input.unique_locking_key);
+        assert_eq!(
+            dispute.as_deref(),
+            Some("dispute_connector_transaction_txn_1")
+        );
+    }
+
+    #[test]
+    fn test_no_locking_input_for_payments_or_mismatched_references() {
+        // The payments flow locks the payment once its ID is resolved
+        assert!(get_locking_input(
+            types::QueuedWebhookFlow::Payment,
+            &webhooks::ObjectReferenceId::PaymentId(
+                api_models::payments::PaymentIdType::ConnectorTransactionId("txn_1".to_string()),
+            ),
+        )
+        .is_none());
+        assert!(get_locking_input(
+            types::QueuedWebhookFlow::Refund,
+            &webhooks::ObjectReferenceId::MandateId(webhooks::MandateIdType::MandateId(
+                "man_123".to_string(),
+            )),
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_object_reference_id_round_trip() {
+        let object_reference_id = types::QueuedWebhookObjectReferenceId::from_object_reference_id(
+            &webhooks::ObjectReferenceId::RefundId(webhooks::RefundIdType::RefundId(
+                "ref_1".to_string(),
+            )),
+        );
+
+        assert!(matches!(
+            object_reference_id.map(|id| id.into_object_reference_id()),
+            Some(webhooks::ObjectReferenceId::RefundId(
+                webhooks::RefundIdType::RefundId(id)
+            )) if id == "ref_1"
+        ));
+    }
+}
@@ -32,7 +32,7 @@ use crate::{
     events::outgoing_webhook_logs::{
         OutgoingWebhookEvent, OutgoingWebhookEventContent, OutgoingWebhookEventMetric,
     },
-    logger,
+    headers, logger,
     routes::{app::SessionStateInfo, SessionState},
     services,
     types::{
@@ -60,24 +60,138 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
-    let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
+    let idempotent_event_id = utils::get_idempotent_event_id(
+        &primary_object_id,
+        event_type,
+        enums::WebhookDeliveryAttempt::InitialAttempt,
+    );
 
-    if !state.conf.webhooks.outgoing_enabled
-        || webhook_url_result.is_err()
-        || webhook_url_result.as_ref().is_ok_and(String::is_empty)
-    {
+    Box::pin(
+        create_event_and_trigger_outgoing_webhook_with_idempotent_event_id(
+            state,
+            merchant_context,
+            business_profile,
+            event_type,
+            event_class,
+            primary_object_id,
+            primary_object_type,
+            content,
+            primary_object_created_at,
+            idempotent_event_id,
+        ),
+    )
+    .await
+}
+
+/// Same as [`create_event_and_trigger_outgoing_webhook`], but with the idempotent event ID provided
+/// by the caller, for events which may be raised more than once for the same object and event type.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub(crate) async fn create_event_and_trigger_outgoing_webhook_with_idempotent_event_id(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    idempotent_event_id: String,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    if !state.conf.webhooks.outgoing_enabled {
+        logger::debug!(
+            business_profile_id=?business_profile.get_id(),
+            %idempotent_event_id,
+            "Outgoing webhooks are disabled in application configuration; skipping outgoing \
+             webhooks for event"
+        );
+        return Ok(());
+    }
+
+    // The webhook URL configured in the business profile receives all events, while the webhook
+    // endpoints registered for the profile receive only the events they are subscribed to
+    let is_profile_webhook_url_configured =
+        get_webhook_url_from_business_profile(&business_profile)
+            .is_ok_and(|webhook_url| !webhook_url.is_empty());
+    let webhook_endpoints = get_webhook_endpoints_subscribed_to_event(
+        &state,
+        merchant_context.get_merchant_key_store(),
+        &business_profile,
+        event_type,
+    )
+    .await;
+
+    let delivery_targets = is_profile_webhook_url_configured
+        .then_some(None)
+        .into_iter()
+        .chain(webhook_endpoints.into_iter().map(Some))
+        .collect::<Vec<_>>();
+
+    if delivery_targets.is_empty() {
         logger::debug!(
             business_profile_id=?business_profile.get_id(),
             %idempotent_event_id,
-            "Outgoing webhooks are disabled in application configuration, or merchant webhook URL \
-             could not be obtained; skipping outgoing webhooks for event"
+            "Merchant webhook URL could not be obtained and no webhook endpoints are subscribed to \
+             the event; skipping outgoing webhooks for event"
         );
         return Ok(());
     }
 
+    let mut delivery_results = Vec::with_capacity(delivery_targets.len());
+    for webhook_endpoint in delivery_targets {
+        let webhook_endpoint_id = webhook_endpoint
+            .as_ref()
+            .map(|webhook_endpoint| webhook_endpoint.endpoint_id.clone());
+        let result = Box::pin(create_event_and_trigger_outgoing_webhook_for_endpoint(
+            state.clone(),
+            merchant_context.clone(),
+            business_profile.clone(),
+            webhook_endpoint,
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+            idempotent_event_id.clone(),
+        ))
+        .await
+        .inspect_err(|error| {
+            logger::error!(
+                ?error,
+                ?webhook_endpoint_id,
+                "Failed to create and trigger outgoing webhook"
+            );
+        });
+        delivery_results.push(result);
+    }
+
+    delivery_results.into_iter().collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn create_event_and_trigger_outgoing_webhook_for_endpoint(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    webhook_endpoint: Option<domain::WebhookEndpoint>,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    idempotent_event_id: String,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
+    // Events delivered to webhook endpoints are tracked separately for each endpoint
+    let idempotent_event_id = match &webhook_endpoint {
+        Some(webhook_endpoint) => format!("{idempotent_event_id}_{}", webhook_endpoint.endpoint_id),
+        None => idempotent_event_id,
+    };
+
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
@@ -90,10 +204,14 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         timestamp: now,
     };
 
-    let request_content =
-        get_outgoing_webhook_request(&merchant_context, outgoing_webhook, &business_profile)
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to construct outgoing webhook request content")?;
+    let request_content = get_outgoing_webhook_request(
+        &merchant_context,
+        outgoing_webhook,
+        &business_profile,
+        webhook_endpoint.as_ref(),
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to construct outgoing webhook request content")?;
 
     let event_metadata = storage::EventMetadata::foreign_from(&content);
     let key_manager_state = &(&state).into();
@@ -142,6 +260,9 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
         is_overall_delivery_successful: Some(false),
+        webhook_endpoint_id: webhook_endpoint
+            .as_ref()
+            .map(|webhook_endpoint| webhook_endpoint.endpoint_id.clone()),
     };
 
     let lock_value = utils::perform_redis_lock(
@@ -228,6 +349,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
             Box::pin(trigger_webhook_and_raise_event(
                 state,
                 business_profile,
+                webhook_endpoint,
                 &cloned_key_store,
                 event,
                 request_content,
@@ -248,6 +370,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
 pub(crate) async fn trigger_webhook_and_raise_event(
     state: SessionState,
     business_profile: domain::Profile,
+    webhook_endpoint: Option<domain::WebhookEndpoint>,
     merchant_key_store: &domain::MerchantKeyStore,
     event: domain::Event,
     request_content: OutgoingWebhookRequestContent,
@@ -266,6 +389,7 @@ pub(crate) async fn trigger_webhook_and_raise_event(
     let trigger_webhook_result = trigger_webhook_to_merchant(
         state.clone(),
         business_profile,
+        webhook_endpoint,
         merchant_key_store,
         event.clone(),
         request_content,
@@ -285,9 +409,11 @@ pub(crate) async fn trigger_webhook_and_raise_event(
     .await;
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn trigger_webhook_to_merchant(
     state: SessionState,
     business_profile: domain::Profile,
+    webhook_endpoint: Option<domain::WebhookEndpoint>,
     merchant_key_store: &domain::MerchantKeyStore,
     event: domain::Event,
     request_content: OutgoingWebhookRequestContent,
@@ -295,7 +421,7 @@ async fn trigger_webhook_to_merchant(
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+        get_webhook_url(&business_profile, webhook_endpoint.as_ref()),
         process_tracker.clone(),
     ) {
         (Ok(webhook_url), _) => Ok(webhook_url),
@@ -319,21 +445,68 @@ async fn trigger_webhook_to_merchant(
         (Err(error), None) => Err(error),
     }?;
 
-    let event_id = event.event_id;
-
-    let headers = request_content
+    let mut request_headers = request_content
         .headers
         .into_iter()
         .map(|(name, value)| (name, value.into_masked()))
-        .collect();
+        .collect::<Vec<_>>();
+    let request_body = request_content.body.expose();
+
+    // The timestamped signatures are computed for every delivery attempt, so that the signed
+    // timestamp corresponds to the time at which the webhook was sent
+    let signing_keys =
+        get_outgoing_webhook_signing_keys(&business_profile, webhook_endpoint.as_ref());
+    if !signing_keys.is_empty() {
+        let timestamp = common_utils::date_time::now_unix_timestamp();
+        // The request body is not regenerated for retries, webhooks sent as Stripe events are
+        // identified by the Stripe signature header of the stored request
+        let stripe_signature = request_headers.iter_mut().find(|(name, _)| {
+            name.eq_ignore_ascii_case(headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE)
+        });
+
+        match stripe_signature {
+            // Stripe client libraries reject signatures with a timestamp older than a few minutes
+            Some((_, signature)) => {
+                *signature = types::get_outgoing_webhook_signature_stripe(
+                    &request_body,
+                    timestamp,
+                    &signing_keys,
+                )?
+                .into();
+            }
+            None => {
+                // The request body contains the ID of the initial attempt
+                let webhook_event_id = event
+                    .initial_attempt_id
+                    .clone()
+                    .unwrap_or_else(|| event.event_id.clone());
+                let signature = types::get_outgoing_webhook_signature_v2(
+                    &request_body,
+                    &webhook_event_id,
+                    timestamp,
+                    &signing_keys,
+                )?;
+
+                request_headers.push((
+                    headers::X_WEBHOOK_EVENT_ID.to_string(),
+                    webhook_event_id.into(),
+                ));
+                request_headers.push((
+                    headers::X_WEBHOOK_SIGNATURE_V2.to_string(),
+                    signature.into(),
+                ));
+            }
+        }
+    }
+
+    let event_id = event.event_id;
+
     let request = services::RequestBuilder::new()
         .method(services::Method::Post)
         .url(&webhook_url)
         .attach_default_headers()
-        .headers(headers)
-        .set_body(RequestContent::RawBytes(
-            request_content.body.expose().into_bytes(),
-        ))
+        .headers(request_headers)
+        .set_body(RequestContent::RawBytes(request_body.into_bytes()))
         .build();
 
     let response = state
@@ -603,6 +776,7 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
         primary_object_id: event.primary_object_id.clone(),
         primary_object_type: event.primary_object_type,
         initial_attempt_id: event.initial_attempt_id.clone(),
+        webhook_endpoint_id: event.webhook_endpoint_id.clone(),
     };
 
     let runner = storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow;
@@ -639,6 +813,70 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
     }
 }
 
+async fn get_webhook_endpoints_subscribed_to_event(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    event_type: enums::EventType,
+) -> Vec<domain::WebhookEndpoint> {
+    state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(
+            &state.into(),
+            &business_profile.merchant_id,
+            business_profile.get_id(),
+            merchant_key_store,
+        )
+        .await
+        .inspect_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to fetch webhook endpoints of business profile"
+            );
+        })
+        .map(|webhook_endpoints| {
+            webhook_endpoints
+                .into_iter()
+                .filter(|webhook_endpoint| webhook_endpoint.is_subscribed_to(event_type))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn get_webhook_url(
+    business_profile: &domain::Profile,
+    webhook_endpoint: Option<&domain::WebhookEndpoint>,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    match webhook_endpoint {
+        Some(webhook_endpoint) => Ok(webhook_endpoint.url.clone()),
+        None => get_webhook_url_from_business_profile(business_profile),
+    }
+}
+
+/// Returns the keys used to compute the versioned signature of outgoing webhooks. The previous
+/// `payment_response_hash_key` of the profile is included while a key rotation is in progress.
+fn get_outgoing_webhook_signing_keys(
+    business_profile: &domain::Profile,
+    webhook_endpoint: Option<&domain::WebhookEndpoint>,
+) -> Vec<Secret<String>> {
+    match webhook_endpoint.and_then(|webhook_endpoint| webhook_endpoint.secret.clone()) {
+        Some(secret) => vec![secret.into_inner()],
+        None => business_profile
+            .payment_response_hash_key
+            .clone()
+            .map(Secret::new)
+            .into_iter()
+            .chain(
+                business_profile
+                    .webhook_secret_rotation
+                    .as_ref()
+                    .and_then(|rotation| rotation.get_active_previous_key())
+                    .cloned(),
+            )
+            .collect(),
+    }
+}
+
 fn get_webhook_url_from_business_profile(
     business_profile: &domain::Profile,
 ) -> CustomResult<String, errors::WebhooksFlowError> {
@@ -659,11 +897,13 @@ pub(crate) fn get_outgoing_webhook_request(
     merchant_context: &domain::MerchantContext,
     outgoing_webhook: api::OutgoingWebhook,
     business_profile: &domain::Profile,
+    webhook_endpoint: Option<&domain::WebhookEndpoint>,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
         business_profile: &domain::Profile,
+        webhook_endpoint: Option<&domain::WebhookEndpoint>,
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![
             (
@@ -677,7 +917,10 @@ pub(crate) fn get_outgoing_webhook_request(
         ];
 
         let transformed_outgoing_webhook = WebhookType::from(outgoing_webhook);
-        let payment_response_hash_key = business_profile.payment_response_hash_key.clone();
+        let payment_response_hash_key = webhook_endpoint
+            .and_then(|webhook_endpoint| webhook_endpoint.secret.clone())
+            .map(|secret| secret.into_inner().expose())
+            .or_else(|| business_profile.payment_response_hash_key.clone());
         let custom_headers = business_profile
             .outgoing_webhook_custom_http_headers
             .clone()
@@ -712,17 +955,39 @@ pub(crate) fn get_outgoing_webhook_request(
         })
     }
 
-    match merchant_context
-        .get_merchant_account()
-        .get_compatible_connector()
-    {
+    let webhook_format = business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.webhook_format);
+
+    match (
+        webhook_format,
+        merchant_context
+            .get_merchant_account()
+            .get_compatible_connector(),
+    ) {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(outgoing_webhook, business_profile),
+        (Some(enums::OutgoingWebhookFormat::Stripe), _) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeEventOutgoingWebhook>(
+                outgoing_webhook,
+                business_profile,
+                webhook_endpoint,
+            )
+        }
+        // Merchant accounts with Stripe as their compatible connector keep receiving webhooks in
+        // the shape they received before the webhook format could be configured
+        #[cfg(feature = "stripe")]
+        (None, Some(api_models::enums::Connector::Stripe)) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                business_profile,
+                webhook_endpoint,
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             business_profile,
+            webhook_endpoint,
         ),
     }
 }
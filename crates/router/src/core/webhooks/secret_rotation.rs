@@ -0,0 +1,333 @@
+use common_utils::{type_name, types::keymanager::Identifier};
+use error_stack::ResultExt;
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{
+        api::webhook_endpoints,
+        domain::{
+            self,
+            types::{self as domain_types, AsyncLift},
+        },
+        storage,
+    },
+};
+
+const DEFAULT_ROTATION_WINDOW_IN_SECONDS: u32 = 24 * 60 * 60;
+const MAX_ROTATION_WINDOW_IN_SECONDS: u32 = 7 * 24 * 60 * 60;
+const MIN_PAYMENT_RESPONSE_HASH_KEY_LENGTH: usize = 32;
+const GENERATED_PAYMENT_RESPONSE_HASH_KEY_LENGTH: usize = 64;
+
+#[instrument(skip(state, key_store, request))]
+pub async fn start_webhook_secret_rotation(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+    request: webhook_endpoints::WebhookSecretRotationStartRequest,
+) -> RouterResponse<webhook_endpoints::WebhookSecretRotationResponse> {
+    let rotation_window_in_seconds = request
+        .rotation_window_in_seconds
+        .unwrap_or(DEFAULT_ROTATION_WINDOW_IN_SECONDS);
+    if rotation_window_in_seconds == 0
+        || rotation_window_in_seconds > MAX_ROTATION_WINDOW_IN_SECONDS
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`rotation_window_in_seconds` must be between 1 and {MAX_ROTATION_WINDOW_IN_SECONDS}"
+            ),
+        }
+        .into());
+    }
+
+    let new_payment_response_hash_key = match request.new_payment_response_hash_key {
+        Some(key) if key.peek().len() < MIN_PAYMENT_RESPONSE_HASH_KEY_LENGTH => {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`new_payment_response_hash_key` must be at least {MIN_PAYMENT_RESPONSE_HASH_KEY_LENGTH} characters long"
+                ),
+            }
+            .into());
+        }
+        Some(key) => key.expose(),
+        None => common_utils::crypto::generate_cryptographically_secure_random_string(
+            GENERATED_PAYMENT_RESPONSE_HASH_KEY_LENGTH,
+        ),
+    };
+
+    let business_profile = find_business_profile(&state, &key_store, &profile_id).await?;
+    validate_webhook_secret_rotation_start(business_profile.webhook_secret_rotation.as_ref())?;
+
+    // The previous key is stored encrypted, since it keeps signing outgoing webhooks until the
+    // rotation is completed or expires
+    let key_manager_state = (&state).into();
+    let previous_payment_response_hash_key = business_profile
+        .payment_response_hash_key
+        .clone()
+        .map(Secret::new)
+        .async_lift(|inner| async {
+            domain_types::crypto_operation(
+                &key_manager_state,
+                type_name!(storage::Profile),
+                domain_types::CryptoOperation::EncryptOptional(inner),
+                Identifier::Merchant(key_store.merchant_id.clone()),
+                key_store.key.get_inner().peek(),
+            )
+            .await
+            .and_then(|val| val.try_into_optionaloperation())
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt the previous payment response hash key")?;
+
+    let now = common_utils::date_time::now();
+    let webhook_secret_rotation = domain::WebhookSecretRotation {
+        previous_payment_response_hash_key,
+        started_at: now,
+        expires_at: now + time::Duration::seconds(i64::from(rotation_window_in_seconds)),
+        completed_at: None,
+    };
+
+    let profile_update = domain::ProfileUpdate::WebhookSecretRotationUpdate {
+        payment_response_hash_key: Some(new_payment_response_hash_key.clone()),
+        webhook_secret_rotation,
+    };
+    let business_profile =
+        update_business_profile(&state, &key_store, business_profile, profile_update).await?;
+
+    let mut response = get_webhook_secret_rotation_response(&business_profile);
+    response.payment_response_hash_key = Some(new_payment_response_hash_key.into());
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+#[instrument(skip(state, key_store))]
+pub async fn retrieve_webhook_secret_rotation(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<webhook_endpoints::WebhookSecretRotationResponse> {
+    let business_profile = find_business_profile(&state, &key_store, &profile_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        get_webhook_secret_rotation_response(&business_profile),
+    ))
+}
+
+#[instrument(skip(state, key_store))]
+pub async fn complete_webhook_secret_rotation(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<webhook_endpoints::WebhookSecretRotationResponse> {
+    let business_profile = find_business_profile(&state, &key_store, &profile_id).await?;
+
+    let profile_update = domain::ProfileUpdate::WebhookSecretRotationUpdate {
+        payment_response_hash_key: None,
+        webhook_secret_rotation: get_completed_webhook_secret_rotation(
+            business_profile.webhook_secret_rotation.clone(),
+        )?,
+    };
+    let business_profile =
+        update_business_profile(&state, &key_store, business_profile, profile_update).await?;
+
+    Ok(ApplicationResponse::Json(
+        get_webhook_secret_rotation_response(&business_profile),
+    ))
+}
+
+/// A new rotation can only be started once the previous rotation has been completed or has expired
+fn validate_webhook_secret_rotation_start(
+    webhook_secret_rotation: Option<&domain::WebhookSecretRotation>,
+) -> errors::RouterResult<()> {
+    if get_webhook_secret_rotation_status(webhook_secret_rotation)
+        == webhook_endpoints::WebhookSecretRotationStatus::InProgress
+    {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A webhook secret rotation is already in progress for the profile".to_string(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Completes a rotation which has not been completed yet. The previous key is discarded, so that
+/// it is no longer used to sign outgoing webhooks.
+fn get_completed_webhook_secret_rotation(
+    webhook_secret_rotation: Option<domain::WebhookSecretRotation>,
+) -> errors::RouterResult<domain::WebhookSecretRotation> {
+    match webhook_secret_rotation {
+        Some(rotation) if rotation.completed_at.is_none() => Ok(domain::WebhookSecretRotation {
+            previous_payment_response_hash_key: None,
+            completed_at: Some(common_utils::date_time::now()),
+            ..rotation
+        }),
+        _ => Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "No webhook secret rotation is pending completion for the profile".to_string(),
+        }
+        .into()),
+    }
+}
+
+fn get_webhook_secret_rotation_status(
+    webhook_secret_rotation: Option<&domain::WebhookSecretRotation>,
+) -> webhook_endpoints::WebhookSecretRotationStatus {
+    match webhook_secret_rotation {
+        None => webhook_endpoints::WebhookSecretRotationStatus::NotStarted,
+        Some(rotation) if rotation.completed_at.is_some() => {
+            webhook_endpoints::WebhookSecretRotationStatus::Completed
+        }
+        Some(rotation) if common_utils::date_time::now() >= rotation.expires_at => {
+            webhook_endpoints::WebhookSecretRotationStatus::Expired
+        }
+        Some(_) => webhook_endpoints::WebhookSecretRotationStatus::InProgress,
+    }
+}
+
+fn get_webhook_secret_rotation_response(
+    business_profile: &domain::Profile,
+) -> webhook_endpoints::WebhookSecretRotationResponse {
+    let webhook_secret_rotation = business_profile.webhook_secret_rotation.as_ref();
+
+    webhook_endpoints::WebhookSecretRotationResponse {
+        profile_id: business_profile.get_id().to_owned(),
+        status: get_webhook_secret_rotation_status(webhook_secret_rotation),
+        payment_response_hash_key: None,
+        started_at: webhook_secret_rotation.map(|rotation| rotation.started_at),
+        expires_at: webhook_secret_rotation.map(|rotation| rotation.expires_at),
+        completed_at: webhook_secret_rotation.and_then(|rotation| rotation.completed_at),
+    }
+}
+
+async fn find_business_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> errors::RouterResult<domain::Profile> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            key_store,
+            &key_store.merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+}
+
+async fn update_business_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: domain::Profile,
+    profile_update: domain::ProfileUpdate,
+) -> errors::RouterResult<domain::Profile> {
+    let profile_id = business_profile.get_id().to_owned();
+
+    state
+        .store
+        .update_profile_by_profile_id(&state.into(), key_store, business_profile, profile_update)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use common_utils::crypto::Encryptable;
+
+    use super::*;
+
+    fn rotation(
+        started_seconds_ago: i64,
+        window_in_seconds: i64,
+        completed: bool,
+    ) -> domain::WebhookSecretRotation {
+        let now = common_utils::date_time::now();
+        let started_at = now - time::Duration::seconds(started_seconds_ago);
+        domain::WebhookSecretRotation {
+            previous_payment_response_hash_key: Some(Encryptable::new(
+                Secret::new("previous_key".to_string()),
+                Secret::new(Vec::new()),
+            )),
+            started_at,
+            expires_at: started_at + time::Duration::seconds(window_in_seconds),
+            completed_at: completed.then_some(now),
+        }
+    }
+
+    #[test]
+    fn test_webhook_secret_rotation_status() {
+        assert_eq!(
+            get_webhook_secret_rotation_status(None),
+            webhook_endpoints::WebhookSecretRotationStatus::NotStarted
+        );
+        assert_eq!(
+            get_webhook_secret_rotation_status(Some(&rotation(60, 3600, false))),
+            webhook_endpoints::WebhookSecretRotationStatus::InProgress
+        );
+        assert_eq!(
+            get_webhook_secret_rotation_status(Some(&rotation(60, 3600, true))),
+            webhook_endpoints::WebhookSecretRotationStatus::Completed
+        );
+        assert_eq!(
+            get_webhook_secret_rotation_status(Some(&rotation(7200, 3600, false))),
+            webhook_endpoints::WebhookSecretRotationStatus::Expired
+        );
+    }
+
+    #[test]
+    fn test_start_webhook_secret_rotation_twice() {
+        assert!(validate_webhook_secret_rotation_start(None).is_ok());
+        assert!(validate_webhook_secret_rotation_start(Some(&rotation(60, 3600, false))).is_err());
+        assert!(validate_webhook_secret_rotation_start(Some(&rotation(60, 3600, true))).is_ok());
+        assert!(validate_webhook_secret_rotation_start(Some(&rotation(7200, 3600, false))).is_ok());
+    }
+
+    #[test]
+    fn test_complete_webhook_secret_rotation() {
+        let completed_rotation =
+            get_completed_webhook_secret_rotation(Some(rotation(60, 3600, false)))
+                .expect("Failed to complete the rotation");
+
+        assert!(completed_rotation.completed_at.is_some());
+        assert!(completed_rotation
+            .previous_payment_response_hash_key
+            .is_none());
+        assert_eq!(
+            get_webhook_secret_rotation_status(Some(&completed_rotation)),
+            webhook_endpoints::WebhookSecretRotationStatus::Completed
+        );
+    }
+
+    #[test]
+    fn test_complete_without_webhook_secret_rotation() {
+        assert!(get_completed_webhook_secret_rotation(None).is_err());
+        assert!(get_completed_webhook_secret_rotation(Some(rotation(60, 3600, true))).is_err());
+    }
+
+    #[test]
+    fn test_previous_key_after_grace_period() {
+        let active_rotation = rotation(60, 3600, false);
+        assert_eq!(
+            active_rotation
+                .get_active_previous_key()
+                .map(|key| key.peek().as_str()),
+            Some("previous_key")
+        );
+
+        // The previous key no longer signs outgoing webhooks once the rotation window has passed
+        assert!(rotation(7200, 3600, false)
+            .get_active_previous_key()
+            .is_none());
+        assert!(rotation(60, 3600, true).get_active_previous_key().is_none());
+    }
+}
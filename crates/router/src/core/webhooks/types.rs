@@ -1,7 +1,7 @@
 use api_models::{webhook_events, webhooks};
 use common_utils::{crypto::SignMessage, ext_traits::Encode};
 use error_stack::ResultExt;
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 use serde::Serialize;
 
 use crate::{
@@ -69,6 +69,73 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
     }
 }
 
+/// Computes the value of the versioned signature header of an outgoing webhook, of the form
+/// `t=<timestamp>,v2=<signature>[,v2=<signature>...]`.
+///
+/// The signed payload is `<timestamp>.<event_id>.<body>`, which allows receivers to reject
+/// replayed deliveries. One signature is included for every key, so that receivers can verify
+/// webhooks with either the previous or the new key while a key rotation is in progress.
+pub(crate) fn get_outgoing_webhook_signature_v2(
+    payload: &str,
+    event_id: &str,
+    timestamp: i64,
+    keys: &[Secret<String>],
+) -> errors::CustomResult<String, errors::WebhooksFlowError> {
+    let signed_payload = format!("{timestamp}.{event_id}.{payload}");
+
+    keys.iter()
+        .map(|key| {
+            common_utils::crypto::HmacSha512::sign_message(
+                &common_utils::crypto::HmacSha512,
+                key.peek().as_bytes(),
+                signed_payload.as_bytes(),
+            )
+            .map(|signature| format!("v2={}", hex::encode(signature)))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the message")
+        .map(|signatures| {
+            std::iter::once(format!("t={timestamp}"))
+                .chain(signatures)
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+}
+
+/// Computes the value of the `Stripe-Signature` header of an outgoing webhook sent as a Stripe
+/// event, of the form `t=<timestamp>,v1=<signature>[,v1=<signature>...]`.
+///
+/// The signed payload is `<timestamp>.<body>`, signed with HMAC-SHA256, as expected by the webhook
+/// verification utilities of the Stripe client libraries, which accept the webhook if any of the
+/// `v1` signatures matches.
+pub(crate) fn get_outgoing_webhook_signature_stripe(
+    payload: &str,
+    timestamp: i64,
+    keys: &[Secret<String>],
+) -> errors::CustomResult<String, errors::WebhooksFlowError> {
+    let signed_payload = format!("{timestamp}.{payload}");
+
+    keys.iter()
+        .map(|key| {
+            common_utils::crypto::HmacSha256::sign_message(
+                &common_utils::crypto::HmacSha256,
+                key.peek().as_bytes(),
+                signed_payload.as_bytes(),
+            )
+            .map(|signature| format!("v1={}", hex::encode(signature)))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the message")
+        .map(|signatures| {
+            std::iter::once(format!("t={timestamp}"))
+                .chain(signatures)
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) merchant_id: common_utils::id_type::MerchantId,
@@ -78,6 +145,116 @@ pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) primary_object_id: String,
     pub(crate) primary_object_type: enums::EventObjectType,
     pub(crate) initial_attempt_id: Option<String>,
+    pub(crate) webhook_endpoint_id: Option<String>,
+}
+
+/// Incoming webhook queued for processing, since another webhook or request was being processed
+/// for the object of the webhook when it was received
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct IncomingWebhookQueueTrackingData {
+    pub(crate) merchant_id: common_utils::id_type::MerchantId,
+    pub(crate) business_profile_id: common_utils::id_type::ProfileId,
+    pub(crate) connector_name: String,
+    pub(crate) event_type: webhooks::IncomingWebhookEvent,
+    pub(crate) flow: QueuedWebhookFlow,
+    /// Process tracker ID of the webhook queued before this one for the same object, which has
+    /// to be processed first
+    pub(crate) previous_process_id: Option<String>,
+    pub(crate) source_verified: bool,
+    /// [`QueuedWebhookDetails`] encrypted with the key of the merchant, since the webhook may
+    /// contain personal and payment method data
+    pub(crate) webhook_details: common_utils::encryption::Encryption,
+}
+
+/// Flows of the incoming webhooks that are queued while their object is locked
+#[cfg(feature = "v1")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum QueuedWebhookFlow {
+    Payment,
+    Refund,
+    Dispute,
+    Mandate,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct QueuedWebhookDetails {
+    pub(crate) object_reference_id: QueuedWebhookObjectReferenceId,
+    /// Base64 encoded resource object of the webhook
+    pub(crate) resource_object: String,
+    pub(crate) request: QueuedWebhookRequestDetails,
+}
+
+/// Serializable copy of the object reference IDs of the queued webhook flows
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum QueuedWebhookObjectReferenceId {
+    PaymentId(api_models::payments::PaymentIdType),
+    RefundId(String),
+    ConnectorRefundId(String),
+    MandateId(String),
+    ConnectorMandateId(String),
+}
+
+#[cfg(feature = "v1")]
+impl QueuedWebhookObjectReferenceId {
+    pub(crate) fn from_object_reference_id(
+        object_reference_id: &webhooks::ObjectReferenceId,
+    ) -> Option<Self> {
+        match object_reference_id {
+            webhooks::ObjectReferenceId::PaymentId(payment_id) => {
+                Some(Self::PaymentId(payment_id.clone()))
+            }
+            webhooks::ObjectReferenceId::RefundId(webhooks::RefundIdType::RefundId(id)) => {
+                Some(Self::RefundId(id.clone()))
+            }
+            webhooks::ObjectReferenceId::RefundId(webhooks::RefundIdType::ConnectorRefundId(
+                id,
+            )) => Some(Self::ConnectorRefundId(id.clone())),
+            webhooks::ObjectReferenceId::MandateId(webhooks::MandateIdType::MandateId(id)) => {
+                Some(Self::MandateId(id.clone()))
+            }
+            webhooks::ObjectReferenceId::MandateId(
+                webhooks::MandateIdType::ConnectorMandateId(id),
+            ) => Some(Self::ConnectorMandateId(id.clone())),
+            webhooks::ObjectReferenceId::ExternalAuthenticationID(_) => None,
+            #[cfg(feature = "payouts")]
+            webhooks::ObjectReferenceId::PayoutId(_) => None,
+        }
+    }
+
+    pub(crate) fn into_object_reference_id(self) -> webhooks::ObjectReferenceId {
+        match self {
+            Self::PaymentId(payment_id) => webhooks::ObjectReferenceId::PaymentId(payment_id),
+            Self::RefundId(id) => {
+                webhooks::ObjectReferenceId::RefundId(webhooks::RefundIdType::RefundId(id))
+            }
+            Self::ConnectorRefundId(id) => {
+                webhooks::ObjectReferenceId::RefundId(webhooks::RefundIdType::ConnectorRefundId(id))
+            }
+            Self::MandateId(id) => {
+                webhooks::ObjectReferenceId::MandateId(webhooks::MandateIdType::MandateId(id))
+            }
+            Self::ConnectorMandateId(id) => webhooks::ObjectReferenceId::MandateId(
+                webhooks::MandateIdType::ConnectorMandateId(id),
+            ),
+        }
+    }
+}
+
+/// Owned copy of the details of an incoming webhook request
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct QueuedWebhookRequestDetails {
+    pub(crate) method: String,
+    pub(crate) uri: String,
+    pub(crate) headers: Vec<(String, String)>,
+    /// Base64 encoded (decoded) body of the webhook
+    pub(crate) body: String,
+    pub(crate) query_params: String,
 }
 
 pub struct WebhookResponse {
@@ -126,3 +303,56 @@ impl WebhookResponse {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use common_utils::crypto::VerifySignature;
+
+    use super::*;
+
+    #[test]
+    fn test_outgoing_webhook_signature_v2_with_multiple_keys() {
+        let keys = [
+            Secret::new("new_key".to_string()),
+            Secret::new("old_key".to_string()),
+        ];
+        let header_value =
+            get_outgoing_webhook_signature_v2(r#"{"foo":"bar"}"#, "evt_123", 1717000000, &keys)
+                .unwrap();
+
+        let mut parts = header_value.split(',');
+        assert_eq!(parts.next(), Some("t=1717000000"));
+
+        let signatures = parts
+            .map(|part| hex::decode(part.strip_prefix("v2=").unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(signatures.len(), keys.len());
+
+        for (key, signature) in keys.iter().zip(signatures) {
+            assert!(common_utils::crypto::HmacSha512
+                .verify_signature(
+                    key.peek().as_bytes(),
+                    &signature,
+                    br#"1717000000.evt_123.{"foo":"bar"}"#,
+                )
+                .unwrap());
+        }
+    }
+
+    #[test]
+    fn test_outgoing_webhook_signature_stripe() {
+        let keys = [Secret::new("whsec_test".to_string())];
+        let header_value =
+            get_outgoing_webhook_signature_stripe(r#"{"id":"evt_123"}"#, 1717000000, &keys)
+                .unwrap();
+
+        let (timestamp, signature) = header_value.split_once(',').unwrap();
+        assert_eq!(timestamp, "t=1717000000");
+
+        let signature = hex::decode(signature.strip_prefix("v1=").unwrap()).unwrap();
+        assert!(common_utils::crypto::HmacSha256
+            .verify_signature(b"whsec_test", &signature, br#"1717000000.{"id":"evt_123"}"#)
+            .unwrap());
+    }
+}
@@ -134,6 +134,7 @@ pub async fn construct_webhook_router_data(
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: None,
     };
     Ok(router_data)
 }
@@ -0,0 +1,280 @@
+use common_utils::{
+    crypto::OptionalEncryptableSecretString, type_name, types::keymanager::Identifier,
+};
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{api::webhook_endpoints, domain, storage, transformers::ForeignFrom},
+};
+
+const WEBHOOK_ENDPOINT_ID_PREFIX: &str = "whe";
+const MAX_WEBHOOK_ENDPOINTS_PER_PROFILE: usize = 16;
+
+#[instrument(skip(state, key_store, request))]
+pub async fn create_webhook_endpoint(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+    request: webhook_endpoints::WebhookEndpointCreateRequest,
+) -> RouterResponse<webhook_endpoints::WebhookEndpointResponse> {
+    let store = state.store.as_ref();
+    let merchant_id = key_store.merchant_id.clone();
+
+    validate_profile(&state, &key_store, &profile_id).await?;
+
+    let existing_endpoints_count = store
+        .list_webhook_endpoints_by_merchant_id_profile_id(
+            &(&state).into(),
+            &merchant_id,
+            &profile_id,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints")?
+        .len();
+    if existing_endpoints_count >= MAX_WEBHOOK_ENDPOINTS_PER_PROFILE {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "A profile can have at most {MAX_WEBHOOK_ENDPOINTS_PER_PROFILE} webhook endpoints"
+            ),
+        }
+        .into());
+    }
+
+    let now = common_utils::date_time::now();
+    let webhook_endpoint = domain::WebhookEndpoint {
+        endpoint_id: common_utils::generate_id_with_default_len(WEBHOOK_ENDPOINT_ID_PREFIX),
+        merchant_id,
+        profile_id,
+        url: request.url.to_string(),
+        secret: encrypt_webhook_endpoint_secret(&state, &key_store, request.secret).await?,
+        description: request.description,
+        enabled_events: request
+            .enabled_events
+            .map(|events| events.into_iter().collect())
+            .unwrap_or_default(),
+        is_enabled: request.is_enabled.unwrap_or(true),
+        created_at: now,
+        last_modified_at: now,
+    };
+
+    let webhook_endpoint = store
+        .insert_webhook_endpoint(&(&state).into(), webhook_endpoint, &key_store)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Webhook endpoint with the given identifier already exists".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoints::WebhookEndpointResponse::foreign_from(webhook_endpoint),
+    ))
+}
+
+#[instrument(skip(state, key_store))]
+pub async fn list_webhook_endpoints(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<Vec<webhook_endpoints::WebhookEndpointResponse>> {
+    validate_profile(&state, &key_store, &profile_id).await?;
+
+    let webhook_endpoints = state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(
+            &(&state).into(),
+            &key_store.merchant_id,
+            &profile_id,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints")?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoints
+            .into_iter()
+            .map(webhook_endpoints::WebhookEndpointResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip(state, key_store))]
+pub async fn retrieve_webhook_endpoint(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+    endpoint_id: String,
+) -> RouterResponse<webhook_endpoints::WebhookEndpointResponse> {
+    let webhook_endpoint =
+        find_webhook_endpoint(&state, &key_store, &profile_id, &endpoint_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoints::WebhookEndpointResponse::foreign_from(webhook_endpoint),
+    ))
+}
+
+#[instrument(skip(state, key_store, request))]
+pub async fn update_webhook_endpoint(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+    endpoint_id: String,
+    request: webhook_endpoints::WebhookEndpointUpdateRequest,
+) -> RouterResponse<webhook_endpoints::WebhookEndpointResponse> {
+    find_webhook_endpoint(&state, &key_store, &profile_id, &endpoint_id).await?;
+
+    // An empty `secret` or `description` removes the value configured for the endpoint
+    let secret = match request.secret {
+        Some(secret) => Some(
+            encrypt_webhook_endpoint_secret(
+                &state,
+                &key_store,
+                Some(secret).filter(|secret| !secret.peek().is_empty()),
+            )
+            .await?,
+        ),
+        None => None,
+    };
+    let webhook_endpoint_update = domain::WebhookEndpointUpdate::Update {
+        url: request.url.map(|url| url.to_string()),
+        secret,
+        description: request
+            .description
+            .map(|description| Some(description).filter(|description| !description.is_empty())),
+        enabled_events: request
+            .enabled_events
+            .map(|events| events.into_iter().collect()),
+        is_enabled: request.is_enabled,
+    };
+
+    let webhook_endpoint = state
+        .store
+        .update_webhook_endpoint_by_merchant_id_endpoint_id(
+            &(&state).into(),
+            &key_store.merchant_id,
+            &endpoint_id,
+            webhook_endpoint_update,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(&endpoint_id))?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoints::WebhookEndpointResponse::foreign_from(webhook_endpoint),
+    ))
+}
+
+#[instrument(skip(state, key_store))]
+pub async fn delete_webhook_endpoint(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+    endpoint_id: String,
+) -> RouterResponse<webhook_endpoints::WebhookEndpointDeleteResponse> {
+    find_webhook_endpoint(&state, &key_store, &profile_id, &endpoint_id).await?;
+
+    let deleted = state
+        .store
+        .delete_webhook_endpoint_by_merchant_id_endpoint_id(&key_store.merchant_id, &endpoint_id)
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(&endpoint_id))?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoints::WebhookEndpointDeleteResponse {
+            endpoint_id,
+            deleted,
+        },
+    ))
+}
+
+async fn validate_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> errors::RouterResult<()> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            key_store,
+            &key_store.merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+        .map(|_| ())
+}
+
+async fn find_webhook_endpoint(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &common_utils::id_type::ProfileId,
+    endpoint_id: &str,
+) -> errors::RouterResult<domain::WebhookEndpoint> {
+    state
+        .store
+        .find_webhook_endpoint_by_merchant_id_endpoint_id(
+            &state.into(),
+            &key_store.merchant_id,
+            endpoint_id,
+            key_store,
+        )
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(endpoint_id))
+        .and_then(|webhook_endpoint| {
+            if webhook_endpoint.profile_id == *profile_id {
+                Ok(webhook_endpoint)
+            } else {
+                Err(webhook_endpoint_not_found(endpoint_id).into())
+            }
+        })
+}
+
+async fn encrypt_webhook_endpoint_secret(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    secret: Option<Secret<String>>,
+) -> errors::RouterResult<OptionalEncryptableSecretString> {
+    domain::types::crypto_operation(
+        &state.into(),
+        type_name!(storage::WebhookEndpoint),
+        domain::types::CryptoOperation::EncryptOptional(secret),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_optionaloperation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt webhook endpoint secret")
+}
+
+fn webhook_endpoint_not_found(endpoint_id: &str) -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Webhook endpoint `{endpoint_id}` does not exist"),
+    }
+}
+
+impl ForeignFrom<domain::WebhookEndpoint> for webhook_endpoints::WebhookEndpointResponse {
+    fn foreign_from(item: domain::WebhookEndpoint) -> Self {
+        Self {
+            endpoint_id: item.endpoint_id,
+            merchant_id: item.merchant_id,
+            profile_id: item.profile_id,
+            url: item.url,
+            has_secret: item.secret.is_some(),
+            description: item.description,
+            enabled_events: item.enabled_events,
+            is_enabled: item.is_enabled,
+            created_at: item.created_at,
+            last_modified_at: item.last_modified_at,
+        }
+    }
+}
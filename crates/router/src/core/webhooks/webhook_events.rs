@@ -264,6 +264,23 @@ pub async fn retry_delivery_attempt(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to find business profile")?;
 
+    let webhook_endpoint = match &event_to_retry.webhook_endpoint_id {
+        Some(webhook_endpoint_id) => Some(
+            store
+                .find_webhook_endpoint_by_merchant_id_endpoint_id(
+                    key_manager_state,
+                    &key_store.merchant_id,
+                    webhook_endpoint_id,
+                    &key_store,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: format!("Webhook endpoint `{webhook_endpoint_id}` does not exist"),
+                })?,
+        ),
+        None => None,
+    };
+
     let delivery_attempt = storage::enums::WebhookDeliveryAttempt::ManualRetry;
     let new_event_id = super::utils::generate_event_id();
     let idempotent_event_id = super::utils::get_idempotent_event_id(
@@ -291,6 +308,7 @@ pub async fn retry_delivery_attempt(
         delivery_attempt: Some(delivery_attempt),
         metadata: event_to_retry.metadata,
         is_overall_delivery_successful: Some(false),
+        webhook_endpoint_id: event_to_retry.webhook_endpoint_id,
     };
 
     let event = store
@@ -313,6 +331,7 @@ pub async fn retry_delivery_attempt(
     Box::pin(super::outgoing::trigger_webhook_and_raise_event(
         state.clone(),
         business_profile,
+        webhook_endpoint,
         &key_store,
         event,
         request_content,
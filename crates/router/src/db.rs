@@ -20,6 +20,7 @@ pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
 pub mod health_check;
+pub mod idempotency_record;
 pub mod kafka_store;
 pub mod locker_mock_up;
 pub mod mandate;
@@ -29,6 +30,7 @@ pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method_session;
+pub mod payout_batch;
 pub mod refund;
 pub mod relay;
 pub mod reverse_lookup;
@@ -39,6 +41,7 @@ pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 use ::payment_methods::state::PaymentMethodsStorageInterface;
 use common_utils::id_type;
@@ -142,6 +145,9 @@ pub trait StorageInterface:
     + user::theme::ThemeInterface
     + payment_method_session::PaymentMethodsSessionInterface
     + tokenization::TokenizationInterface
+    + webhook_endpoint::WebhookEndpointInterface
+    + payout_batch::PayoutBatchInterface
+    + idempotency_record::IdempotencyRecordInterface
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
@@ -233,6 +233,9 @@ impl ApiKeyInterface for MockDb {
             created_at: api_key.created_at,
             expires_at: api_key.expires_at,
             last_used: api_key.last_used,
+            scopes: api_key.scopes,
+            allowed_ips: api_key.allowed_ips,
+            profile_id: api_key.profile_id,
         };
         locked_api_keys.push(stored_key.clone());
 
@@ -258,6 +261,9 @@ impl ApiKeyInterface for MockDb {
                 description,
                 expires_at,
                 last_used,
+                scopes,
+                allowed_ips,
+                profile_id,
             } => {
                 if let Some(name) = name {
                     key_to_update.name = name;
@@ -272,6 +278,15 @@ impl ApiKeyInterface for MockDb {
                 if last_used.is_some() {
                     key_to_update.last_used = last_used
                 }
+                if let Some(scopes) = scopes {
+                    key_to_update.scopes = scopes;
+                }
+                if let Some(allowed_ips) = allowed_ips {
+                    key_to_update.allowed_ips = allowed_ips;
+                }
+                if let Some(profile_id) = profile_id {
+                    key_to_update.profile_id = profile_id;
+                }
             }
             storage::ApiKeyUpdate::LastUsedUpdate { last_used } => {
                 key_to_update.last_used = Some(last_used);
@@ -417,6 +432,9 @@ mod tests {
                 created_at: datetime!(2023-02-01 0:00),
                 expires_at: Some(datetime!(2023-03-01 0:00)),
                 last_used: None,
+                scopes: None,
+                allowed_ips: None,
+                profile_id: None,
             })
             .await
             .unwrap();
@@ -432,6 +450,9 @@ mod tests {
                 created_at: datetime!(2023-03-01 0:00),
                 expires_at: None,
                 last_used: None,
+                scopes: None,
+                allowed_ips: None,
+                profile_id: None,
             })
             .await
             .unwrap();
@@ -513,6 +534,9 @@ mod tests {
             created_at: datetime!(2023-06-01 0:00),
             expires_at: None,
             last_used: None,
+            scopes: None,
+            allowed_ips: None,
+            profile_id: None,
         };
 
         let api = db.insert_api_key(api).await.unwrap();
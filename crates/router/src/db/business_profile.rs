@@ -1,3 +1,4 @@
+use async_bb8_diesel::AsyncConnection;
 use common_utils::{ext_traits::AsyncExt, types::keymanager::KeyManagerState};
 use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
@@ -59,6 +60,16 @@ where
         profile_update: domain::ProfileUpdate,
     ) -> CustomResult<domain::Profile, errors::StorageError>;
 
+    /// Updates the profile and records the routing algorithm activation in the same transaction
+    async fn update_profile_and_insert_routing_algorithm_activation(
+        &self,
+        key_manager_state: &KeyManagerState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        current_state: domain::Profile,
+        profile_update: domain::ProfileUpdate,
+        activation: storage::RoutingAlgorithmActivationNew,
+    ) -> CustomResult<(domain::Profile, storage::RoutingAlgorithmActivation), errors::StorageError>;
+
     async fn delete_profile_by_profile_id_merchant_id(
         &self,
         profile_id: &common_utils::id_type::ProfileId,
@@ -184,6 +195,56 @@ impl ProfileInterface for Store {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    #[instrument(skip_all)]
+    async fn update_profile_and_insert_routing_algorithm_activation(
+        &self,
+        key_manager_state: &KeyManagerState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        current_state: domain::Profile,
+        profile_update: domain::ProfileUpdate,
+        activation: storage::RoutingAlgorithmActivationNew,
+    ) -> CustomResult<(domain::Profile, storage::RoutingAlgorithmActivation), errors::StorageError>
+    {
+        let conn = connection::pg_accounts_connection_write(self).await?;
+        let current_state = Conversion::convert(current_state)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        let profile_update = storage::ProfileUpdateInternal::from(profile_update);
+
+        // Returning `DatabaseConnectionError` after logging the actual error, as the transaction
+        // cannot return an `error_stack::Report`
+        let (business_profile, activation) = conn
+            .transaction_async(|conn| async move {
+                let business_profile = current_state
+                    .update_by_profile_id(&conn, profile_update)
+                    .await
+                    .map_err(|error| {
+                        router_env::logger::error!(?error, "Failed to update the profile");
+                        errors::StorageError::DatabaseConnectionError
+                    })?;
+                let activation = activation.insert(&conn).await.map_err(|error| {
+                    router_env::logger::error!(
+                        ?error,
+                        "Failed to insert the routing algorithm activation"
+                    );
+                    errors::StorageError::DatabaseConnectionError
+                })?;
+                Ok::<_, errors::StorageError>((business_profile, activation))
+            })
+            .await?;
+
+        let business_profile = business_profile
+            .convert(
+                key_manager_state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)?;
+
+        Ok((business_profile, activation))
+    }
+
     #[instrument(skip_all)]
     async fn delete_profile_by_profile_id_merchant_id(
         &self,
@@ -363,6 +424,18 @@ impl ProfileInterface for MockDb {
             )
     }
 
+    async fn update_profile_and_insert_routing_algorithm_activation(
+        &self,
+        _key_manager_state: &KeyManagerState,
+        _merchant_key_store: &domain::MerchantKeyStore,
+        _current_state: domain::Profile,
+        _profile_update: domain::ProfileUpdate,
+        _activation: storage::RoutingAlgorithmActivationNew,
+    ) -> CustomResult<(domain::Profile, storage::RoutingAlgorithmActivation), errors::StorageError>
+    {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn delete_profile_by_profile_id_merchant_id(
         &self,
         profile_id: &common_utils::id_type::ProfileId,
@@ -326,6 +326,19 @@ impl DisputeInterface for MockDb {
                                     .end_time
                                     .map_or(true, |end_time| dispute_time <= end_time)
                         })
+                    && dispute_constraints
+                        .challenge_required_by_range
+                        .as_ref()
+                        .map_or(true, |range| {
+                            dispute
+                                .challenge_required_by
+                                .is_some_and(|challenge_required_by| {
+                                    challenge_required_by >= range.start_time
+                                        && range.end_time.map_or(true, |end_time| {
+                                            challenge_required_by <= end_time
+                                        })
+                                })
+                        })
             })
             .skip(offset_usize)
             .take(limit_usize)
@@ -690,6 +703,7 @@ mod tests {
                         dispute_stage: None,
                         reason: None,
                         time_range: None,
+                        challenge_required_by_range: None,
                     },
                 )
                 .await
@@ -0,0 +1,148 @@
+use common_utils::id_type;
+use diesel_models::idempotency_record as storage;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait IdempotencyRecordInterface {
+    async fn insert_idempotency_record(
+        &self,
+        idempotency_record: storage::IdempotencyRecordNew,
+    ) -> CustomResult<storage::IdempotencyRecord, errors::StorageError>;
+
+    async fn find_idempotency_record_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<storage::IdempotencyRecord, errors::StorageError>;
+
+    async fn delete_idempotency_record_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl IdempotencyRecordInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_idempotency_record(
+        &self,
+        idempotency_record: storage::IdempotencyRecordNew,
+    ) -> CustomResult<storage::IdempotencyRecord, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        idempotency_record
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_idempotency_record_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<storage::IdempotencyRecord, errors::StorageError> {
+        // Read from the primary, a record inserted by a concurrent request may not have been
+        // replicated yet
+        let conn = connection::pg_connection_write(self).await?;
+        storage::IdempotencyRecord::find_by_merchant_id_idempotency_key(
+            &conn,
+            merchant_id,
+            idempotency_key,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_idempotency_record_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::IdempotencyRecord::delete_by_merchant_id_idempotency_key(
+            &conn,
+            merchant_id,
+            idempotency_key,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotencyRecordInterface for MockDb {
+    async fn insert_idempotency_record(
+        &self,
+        idempotency_record: storage::IdempotencyRecordNew,
+    ) -> CustomResult<storage::IdempotencyRecord, errors::StorageError> {
+        let mut idempotency_records = self.idempotency_records.lock().await;
+        if idempotency_records.iter().any(|record| {
+            record.merchant_id == idempotency_record.merchant_id
+                && record.idempotency_key == idempotency_record.idempotency_key
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "idempotency_key",
+                key: Some(idempotency_record.idempotency_key.clone()),
+            })?
+        }
+        let idempotency_record = storage::IdempotencyRecord {
+            merchant_id: idempotency_record.merchant_id,
+            idempotency_key: idempotency_record.idempotency_key,
+            request_fingerprint: idempotency_record.request_fingerprint,
+            response: idempotency_record.response,
+            created_at: idempotency_record.created_at,
+            expires_at: idempotency_record.expires_at,
+        };
+        idempotency_records.push(idempotency_record.clone());
+        Ok(idempotency_record)
+    }
+
+    async fn find_idempotency_record_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<storage::IdempotencyRecord, errors::StorageError> {
+        let idempotency_records = self.idempotency_records.lock().await;
+        idempotency_records
+            .iter()
+            .find(|record| {
+                record.merchant_id == *merchant_id && record.idempotency_key == idempotency_key
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No idempotency record available for idempotency_key = {idempotency_key}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn delete_idempotency_record_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut idempotency_records = self.idempotency_records.lock().await;
+        let record_index = idempotency_records
+            .iter()
+            .position(|record| {
+                record.merchant_id == *merchant_id && record.idempotency_key == idempotency_key
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No idempotency record available for idempotency_key = {idempotency_key}"
+            )))?;
+
+        idempotency_records.remove(record_index);
+        Ok(true)
+    }
+}
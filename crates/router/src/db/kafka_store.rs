@@ -71,17 +71,20 @@ use crate::{
         generic_link::GenericLinkInterface,
         gsm::GsmInterface,
         health_check::HealthCheckDbInterface,
+        idempotency_record::IdempotencyRecordInterface,
         locker_mock_up::LockerMockUpInterface,
         mandate::MandateInterface,
         merchant_account::MerchantAccountInterface,
         merchant_connector_account::{ConnectorAccessToken, MerchantConnectorAccountInterface},
         merchant_key_store::MerchantKeyStoreInterface,
         payment_link::PaymentLinkInterface,
+        payout_batch::PayoutBatchInterface,
         refund::RefundInterface,
         reverse_lookup::ReverseLookupInterface,
         routing_algorithm::RoutingAlgorithmInterface,
         tokenization::TokenizationInterface,
         unified_translations::UnifiedTranslationsInterface,
+        webhook_endpoint::WebhookEndpointInterface,
         AccountsStorageInterface, CommonStorageInterface, GlobalStorageInterface,
         MasterKeyInterface, StorageInterface,
     },
@@ -2654,6 +2657,32 @@ impl ProcessTrackerInterface for KafkaStore {
             .find_processes_by_time_status(time_lower_limit, time_upper_limit, status, limit)
             .await
     }
+
+    async fn find_processes_by_runner_status_updated_before(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        status: ProcessTrackerStatus,
+        updated_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .find_processes_by_runner_status_updated_before(runner, status, updated_before, limit)
+            .await
+    }
+
+    async fn archive_processes(
+        &self,
+        processes: Vec<storage::ProcessTracker>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store.archive_processes(processes).await
+    }
+
+    async fn delete_processes_by_ids(
+        &self,
+        ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store.delete_processes_by_ids(ids).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -3042,6 +3071,26 @@ impl ProfileInterface for KafkaStore {
             .await
     }
 
+    async fn update_profile_and_insert_routing_algorithm_activation(
+        &self,
+        key_manager_state: &KeyManagerState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        current_state: domain::Profile,
+        business_profile_update: domain::ProfileUpdate,
+        activation: storage::RoutingAlgorithmActivationNew,
+    ) -> CustomResult<(domain::Profile, storage::RoutingAlgorithmActivation), errors::StorageError>
+    {
+        self.diesel_store
+            .update_profile_and_insert_routing_algorithm_activation(
+                key_manager_state,
+                merchant_key_store,
+                current_state,
+                business_profile_update,
+                activation,
+            )
+            .await
+    }
+
     async fn delete_profile_by_profile_id_merchant_id(
         &self,
         profile_id: &id_type::ProfileId,
@@ -3183,6 +3232,32 @@ impl RoutingAlgorithmInterface for KafkaStore {
             )
             .await
     }
+
+    async fn insert_routing_algorithm_activation(
+        &self,
+        activation: storage::RoutingAlgorithmActivationNew,
+    ) -> CustomResult<storage::RoutingAlgorithmActivation, errors::StorageError> {
+        self.diesel_store
+            .insert_routing_algorithm_activation(activation)
+            .await
+    }
+
+    async fn list_routing_algorithm_activations_by_profile_id_transaction_type(
+        &self,
+        profile_id: &id_type::ProfileId,
+        transaction_type: &enums::TransactionType,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::RoutingAlgorithmActivation>, errors::StorageError> {
+        self.diesel_store
+            .list_routing_algorithm_activations_by_profile_id_transaction_type(
+                profile_id,
+                transaction_type,
+                limit,
+                offset,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -4265,3 +4340,166 @@ impl TokenizationInterface for KafkaStore {
 
 #[cfg(not(all(feature = "v2", feature = "tokenization_v2")))]
 impl TokenizationInterface for KafkaStore {}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_webhook_endpoint(
+        &self,
+        state: &KeyManagerState,
+        webhook_endpoint: domain::WebhookEndpoint,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .insert_webhook_endpoint(state, webhook_endpoint, merchant_key_store)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .find_webhook_endpoint_by_merchant_id_endpoint_id(
+                state,
+                merchant_id,
+                endpoint_id,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_endpoints_by_merchant_id_profile_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::WebhookEndpoint>, errors::StorageError> {
+        self.diesel_store
+            .list_webhook_endpoints_by_merchant_id_profile_id(
+                state,
+                merchant_id,
+                profile_id,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+        webhook_endpoint_update: domain::WebhookEndpointUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .update_webhook_endpoint_by_merchant_id_endpoint_id(
+                state,
+                merchant_id,
+                endpoint_id,
+                webhook_endpoint_update,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_webhook_endpoint_by_merchant_id_endpoint_id(merchant_id, endpoint_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store.insert_payout_batch(payout_batch).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .find_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_batches_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id, payout_batch_update)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotencyRecordInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_idempotency_record(
+        &self,
+        idempotency_record: storage::IdempotencyRecordNew,
+    ) -> CustomResult<storage::IdempotencyRecord, errors::StorageError> {
+        self.diesel_store
+            .insert_idempotency_record(idempotency_record)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_idempotency_record_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<storage::IdempotencyRecord, errors::StorageError> {
+        self.diesel_store
+            .find_idempotency_record_by_merchant_id_idempotency_key(merchant_id, idempotency_key)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_idempotency_record_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_idempotency_record_by_merchant_id_idempotency_key(merchant_id, idempotency_key)
+            .await
+    }
+}
@@ -0,0 +1,213 @@
+use common_utils::id_type;
+use diesel_models::payout_batch as storage;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait PayoutBatchInterface {
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError>;
+
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payout_batch
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatch::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatch::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatch::update_by_merchant_id_batch_id(
+            &conn,
+            merchant_id,
+            batch_id,
+            payout_batch_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for MockDb {
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let mut payout_batches = self.payout_batches.lock().await;
+        if payout_batches
+            .iter()
+            .any(|batch| batch.batch_id == payout_batch.batch_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "batch_id",
+                key: Some(payout_batch.batch_id.clone()),
+            })?
+        }
+        let payout_batch = storage::PayoutBatch {
+            batch_id: payout_batch.batch_id,
+            merchant_id: payout_batch.merchant_id,
+            profile_id: payout_batch.profile_id,
+            status: payout_batch.status,
+            description: payout_batch.description,
+            auto_fulfill: payout_batch.auto_fulfill,
+            total_count: payout_batch.total_count,
+            succeeded_count: payout_batch.succeeded_count,
+            failed_count: payout_batch.failed_count,
+            payout_requests: payout_batch.payout_requests,
+            items: payout_batch.items,
+            created_at: payout_batch.created_at,
+            last_modified_at: payout_batch.last_modified_at,
+        };
+        payout_batches.push(payout_batch.clone());
+        Ok(payout_batch)
+    }
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let payout_batches = self.payout_batches.lock().await;
+        payout_batches
+            .iter()
+            .find(|batch| batch.merchant_id == *merchant_id && batch.batch_id == batch_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No payout batch available for batch_id = {batch_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError> {
+        let payout_batches = self.payout_batches.lock().await;
+        let mut merchant_batches = payout_batches
+            .iter()
+            .filter(|batch| batch.merchant_id == *merchant_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        merchant_batches.sort_by(|batch1, batch2| batch2.created_at.cmp(&batch1.created_at));
+
+        Ok(merchant_batches
+            .into_iter()
+            .skip(
+                offset
+                    .and_then(|offset| usize::try_from(offset).ok())
+                    .unwrap_or(0),
+            )
+            .take(
+                limit
+                    .and_then(|limit| usize::try_from(limit).ok())
+                    .unwrap_or(usize::MAX),
+            )
+            .collect())
+    }
+
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let mut payout_batches = self.payout_batches.lock().await;
+        payout_batches
+            .iter_mut()
+            .find(|batch| batch.merchant_id == *merchant_id && batch.batch_id == batch_id)
+            .map(|batch| {
+                let update = storage::PayoutBatchUpdateInternal::from(payout_batch_update);
+                *batch = storage::PayoutBatch {
+                    status: update.status.unwrap_or(batch.status),
+                    succeeded_count: update.succeeded_count.unwrap_or(batch.succeeded_count),
+                    failed_count: update.failed_count.unwrap_or(batch.failed_count),
+                    items: update.items.unwrap_or(batch.items.to_owned()),
+                    payout_requests: update
+                        .payout_requests
+                        .unwrap_or(batch.payout_requests.to_owned()),
+                    last_modified_at: update.last_modified_at,
+                    ..batch.to_owned()
+                };
+                batch.to_owned()
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No payout batch available for batch_id = {batch_id}"
+                ))
+                .into(),
+            )
+    }
+}
@@ -1,4 +1,6 @@
-use diesel_models::routing_algorithm as routing_storage;
+use diesel_models::{
+    routing_algorithm as routing_storage, routing_algorithm_activation as activation_storage,
+};
 use error_stack::report;
 use router_env::{instrument, tracing};
 use storage_impl::mock_db::MockDb;
@@ -57,6 +59,19 @@ pub trait RoutingAlgorithmInterface {
         limit: i64,
         offset: i64,
     ) -> StorageResult<Vec<routing_storage::RoutingProfileMetadata>>;
+
+    async fn insert_routing_algorithm_activation(
+        &self,
+        activation: activation_storage::RoutingAlgorithmActivationNew,
+    ) -> StorageResult<activation_storage::RoutingAlgorithmActivation>;
+
+    async fn list_routing_algorithm_activations_by_profile_id_transaction_type(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        transaction_type: &common_enums::TransactionType,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<activation_storage::RoutingAlgorithmActivation>>;
 }
 
 #[async_trait::async_trait]
@@ -172,6 +187,41 @@ impl RoutingAlgorithmInterface for Store {
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn insert_routing_algorithm_activation(
+        &self,
+        activation: activation_storage::RoutingAlgorithmActivationNew,
+    ) -> StorageResult<activation_storage::RoutingAlgorithmActivation> {
+        // The activations are stored alongside the profiles, so that they can be recorded in the
+        // same transaction as the change of the routing algorithm of the profile
+        let conn = connection::pg_accounts_connection_write(self).await?;
+        activation
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_routing_algorithm_activations_by_profile_id_transaction_type(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        transaction_type: &common_enums::TransactionType,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<activation_storage::RoutingAlgorithmActivation>> {
+        // Read from the primary, the history is used to roll back the activation made just before
+        let conn = connection::pg_accounts_connection_write(self).await?;
+        activation_storage::RoutingAlgorithmActivation::list_by_profile_id_transaction_type(
+            &conn,
+            profile_id,
+            transaction_type,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -234,4 +284,21 @@ impl RoutingAlgorithmInterface for MockDb {
     ) -> StorageResult<Vec<routing_storage::RoutingProfileMetadata>> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn insert_routing_algorithm_activation(
+        &self,
+        _activation: activation_storage::RoutingAlgorithmActivationNew,
+    ) -> StorageResult<activation_storage::RoutingAlgorithmActivation> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_routing_algorithm_activations_by_profile_id_transaction_type(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _transaction_type: &common_enums::TransactionType,
+        _limit: i64,
+        _offset: i64,
+    ) -> StorageResult<Vec<activation_storage::RoutingAlgorithmActivation>> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
@@ -0,0 +1,339 @@
+use common_utils::{ext_traits::AsyncExt, id_type, types::keymanager::KeyManagerState};
+use diesel_models::webhook_endpoint as storage;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::domain::{
+        self,
+        behaviour::{Conversion, ReverseConversion},
+    },
+};
+
+#[async_trait::async_trait]
+pub trait WebhookEndpointInterface
+where
+    domain::WebhookEndpoint:
+        Conversion<DstType = storage::WebhookEndpoint, NewDstType = storage::WebhookEndpointNew>,
+{
+    async fn insert_webhook_endpoint(
+        &self,
+        state: &KeyManagerState,
+        webhook_endpoint: domain::WebhookEndpoint,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError>;
+
+    async fn find_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError>;
+
+    async fn list_webhook_endpoints_by_merchant_id_profile_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::WebhookEndpoint>, errors::StorageError>;
+
+    async fn update_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+        webhook_endpoint_update: domain::WebhookEndpointUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError>;
+
+    async fn delete_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_webhook_endpoint(
+        &self,
+        state: &KeyManagerState,
+        webhook_endpoint: domain::WebhookEndpoint,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        webhook_endpoint
+            .construct_new()
+            .await
+            .change_context(errors::StorageError::EncryptionError)?
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpoint::find_by_merchant_id_endpoint_id(&conn, merchant_id, endpoint_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_endpoints_by_merchant_id_profile_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::WebhookEndpoint>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpoint::list_by_merchant_id_profile_id(&conn, merchant_id, profile_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+            .async_and_then(|webhook_endpoints| async {
+                let mut domain_webhook_endpoints = Vec::with_capacity(webhook_endpoints.len());
+                for webhook_endpoint in webhook_endpoints.into_iter() {
+                    domain_webhook_endpoints.push(
+                        webhook_endpoint
+                            .convert(
+                                state,
+                                merchant_key_store.key.get_inner(),
+                                merchant_key_store.merchant_id.clone().into(),
+                            )
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)?,
+                    );
+                }
+                Ok(domain_webhook_endpoints)
+            })
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+        webhook_endpoint_update: domain::WebhookEndpointUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::update_by_merchant_id_endpoint_id(
+            &conn,
+            merchant_id,
+            endpoint_id,
+            webhook_endpoint_update.into(),
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?
+        .convert(
+            state,
+            merchant_key_store.key.get_inner(),
+            merchant_key_store.merchant_id.clone().into(),
+        )
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::delete_by_merchant_id_endpoint_id(&conn, merchant_id, endpoint_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for MockDb {
+    async fn insert_webhook_endpoint(
+        &self,
+        state: &KeyManagerState,
+        webhook_endpoint: domain::WebhookEndpoint,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let mut webhook_endpoints = self.webhook_endpoints.lock().await;
+        if webhook_endpoints
+            .iter()
+            .any(|endpoint| endpoint.endpoint_id == webhook_endpoint.endpoint_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "endpoint_id",
+                key: Some(webhook_endpoint.endpoint_id.clone()),
+            })?
+        }
+        let webhook_endpoint = Conversion::convert(webhook_endpoint)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        webhook_endpoints.push(webhook_endpoint.clone());
+        webhook_endpoint
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn find_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let webhook_endpoint = self
+            .webhook_endpoints
+            .lock()
+            .await
+            .iter()
+            .find(|endpoint| {
+                endpoint.merchant_id == *merchant_id && endpoint.endpoint_id == endpoint_id
+            })
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No webhook endpoint available for endpoint_id = {endpoint_id}"
+            )))?;
+        webhook_endpoint
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn list_webhook_endpoints_by_merchant_id_profile_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::WebhookEndpoint>, errors::StorageError> {
+        let webhook_endpoints: Vec<_> = self
+            .webhook_endpoints
+            .lock()
+            .await
+            .iter()
+            .filter(|endpoint| {
+                endpoint.merchant_id == *merchant_id && endpoint.profile_id == *profile_id
+            })
+            .cloned()
+            .collect();
+
+        let mut domain_webhook_endpoints = Vec::with_capacity(webhook_endpoints.len());
+        for webhook_endpoint in webhook_endpoints {
+            domain_webhook_endpoints.push(
+                webhook_endpoint
+                    .convert(
+                        state,
+                        merchant_key_store.key.get_inner(),
+                        merchant_key_store.merchant_id.clone().into(),
+                    )
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            );
+        }
+        Ok(domain_webhook_endpoints)
+    }
+
+    async fn update_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+        webhook_endpoint_update: domain::WebhookEndpointUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let updated_webhook_endpoint = self
+            .webhook_endpoints
+            .lock()
+            .await
+            .iter_mut()
+            .find(|endpoint| {
+                endpoint.merchant_id == *merchant_id && endpoint.endpoint_id == endpoint_id
+            })
+            .map(|endpoint| {
+                let update = storage::WebhookEndpointUpdateInternal::from(webhook_endpoint_update);
+                *endpoint = storage::WebhookEndpoint {
+                    url: update.url.unwrap_or(endpoint.url.to_owned()),
+                    secret: update.secret.unwrap_or(endpoint.secret.to_owned()),
+                    description: update
+                        .description
+                        .unwrap_or(endpoint.description.to_owned()),
+                    enabled_events: update
+                        .enabled_events
+                        .unwrap_or(endpoint.enabled_events.to_owned()),
+                    is_enabled: update.is_enabled.unwrap_or(endpoint.is_enabled),
+                    last_modified_at: update.last_modified_at,
+                    ..endpoint.to_owned()
+                };
+                endpoint.to_owned()
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No webhook endpoint available for endpoint_id = {endpoint_id}"
+            )))?;
+        updated_webhook_endpoint
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn delete_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut webhook_endpoints = self.webhook_endpoints.lock().await;
+        let endpoint_index = webhook_endpoints
+            .iter()
+            .position(|endpoint| {
+                endpoint.merchant_id == *merchant_id && endpoint.endpoint_id == endpoint_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No webhook endpoint available for endpoint_id = {endpoint_id}"
+            )))?;
+
+        webhook_endpoints.remove(endpoint_index);
+        Ok(true)
+    }
+}
@@ -75,6 +75,8 @@ pub mod headers {
     pub const X_ACCEPT_VERSION: &str = "X-Accept-Version";
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
+    pub const X_WEBHOOK_SIGNATURE_V2: &str = "X-Webhook-Signature-V2";
+    pub const X_WEBHOOK_EVENT_ID: &str = "X-Webhook-Event-Id";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
     pub const X_PROFILE_ID: &str = "X-Profile-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
@@ -92,6 +94,10 @@ pub mod headers {
     pub const X_CLIENT_SECRET: &str = "X-Client-Secret";
     pub const X_CUSTOMER_ID: &str = "X-Customer-Id";
     pub const X_CONNECTED_MERCHANT_ID: &str = "x-connected-merchant-id";
+    pub const RATE_LIMIT_LIMIT: &str = "ratelimit-limit";
+    pub const RATE_LIMIT_REMAINING: &str = "ratelimit-remaining";
+    pub const RATE_LIMIT_RESET: &str = "ratelimit-reset";
+    pub const RETRY_AFTER: &str = "retry-after";
 }
 
 pub mod pii {
@@ -385,6 +391,7 @@ pub fn get_application_builder(
         ))
         .wrap(middleware::default_response_headers())
         .wrap(middleware::RequestId)
+        .wrap(middleware::RateLimitHeaders)
         .wrap(cors::cors(cors))
         // this middleware works only for Http1.1 requests
         .wrap(middleware::Http400RequestDetailsLogger)
@@ -5,7 +5,7 @@ use router_env::{
     tracing::{field::Empty, Instrument},
 };
 
-use crate::{headers, routes::metrics};
+use crate::{core::rate_limit, headers, routes::metrics};
 
 /// Middleware to include request ID in response header.
 pub struct RequestId;
@@ -482,3 +482,73 @@ where
         })
     }
 }
+
+/// Middleware for attaching the rate limit headers to the response, if rate limits were applied
+/// to the request.
+pub struct RateLimitHeaders;
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for RateLimitHeaders
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = RateLimitHeadersMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(RateLimitHeadersMiddleware { service }))
+    }
+}
+
+pub struct RateLimitHeadersMiddleware<S> {
+    service: S,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest> for RateLimitHeadersMiddleware<S>
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        let response_fut = self.service.call(req);
+
+        Box::pin(async move {
+            let mut response = response_fut.await?;
+            let rate_limit_status = response
+                .request()
+                .extensions()
+                .get::<rate_limit::RateLimitStatus>()
+                .cloned();
+
+            if let Some(rate_limit_status) = rate_limit_status {
+                for (name, value) in rate_limit_status.get_response_headers() {
+                    response.headers_mut().insert(
+                        http::header::HeaderName::from_static(name),
+                        http::HeaderValue::from_str(&value)?,
+                    );
+                }
+            }
+
+            Ok(response)
+        })
+    }
+}
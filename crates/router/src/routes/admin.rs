@@ -2,6 +2,8 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::core::connector_circuit_breaker;
 use crate::{
     core::{admin::*, api_locking},
     services::{api, authentication as auth, authorization::permissions::Permission},
@@ -496,7 +498,10 @@ pub async fn connector_create(
             create_connector(state, req, merchant_context, auth_data.profile_id)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(
+                merchant_id.clone(),
+                Permission::ProfileConnectorWrite,
+            )),
             &auth::JWTAuthMerchantFromRoute {
                 merchant_id: merchant_id.clone(),
                 required_permission: Permission::ProfileConnectorWrite,
@@ -592,7 +597,10 @@ pub async fn connector_retrieve(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(
+                merchant_id.clone(),
+                Permission::ProfileConnectorWrite,
+            )),
             &auth::JWTAuthMerchantFromRoute {
                 merchant_id,
                 // This should ideally be ProfileConnectorRead, but since this API responds with
@@ -715,7 +723,10 @@ pub async fn connector_list(
         merchant_id.to_owned(),
         |state, _auth, merchant_id, _| list_payment_connectors(state, merchant_id, None),
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(
+                merchant_id.clone(),
+                Permission::MerchantConnectorRead,
+            )),
             &auth::JWTAuthMerchantFromRoute {
                 merchant_id,
                 required_permission: Permission::MerchantConnectorRead,
@@ -768,7 +779,10 @@ pub async fn connector_list_profile(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(
+                merchant_id.clone(),
+                Permission::ProfileConnectorRead,
+            )),
             &auth::JWTAuthMerchantFromRoute {
                 merchant_id,
                 required_permission: Permission::ProfileConnectorRead,
@@ -829,7 +843,10 @@ pub async fn connector_update(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(
+                merchant_id.clone(),
+                Permission::ProfileConnectorWrite,
+            )),
             &auth::JWTAuthMerchantFromRoute {
                 merchant_id: merchant_id.clone(),
                 required_permission: Permission::ProfileConnectorWrite,
@@ -946,6 +963,106 @@ pub async fn connector_delete(
     ))
     .await
 }
+
+/// Merchant Connector - Circuit Breaker Retrieve
+///
+/// Retrieve the state of the circuit breaker of a Merchant Connector
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorCircuitBreakerRetrieve))]
+pub async fn connector_circuit_breaker_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorCircuitBreakerRetrieve;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = web::Json(admin::MerchantConnectorId {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    })
+    .into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            connector_circuit_breaker::retrieve_circuit_breaker(
+                state,
+                req.merchant_id,
+                auth.profile_id,
+                req.merchant_connector_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(
+                merchant_id.clone(),
+                Permission::ProfileConnectorRead,
+            )),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::ProfileConnectorRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Connector - Circuit Breaker Reset
+///
+/// Close the circuit breaker of a Merchant Connector, clearing the recorded connector call outcomes
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorCircuitBreakerReset))]
+pub async fn connector_circuit_breaker_reset(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorCircuitBreakerReset;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = web::Json(admin::MerchantConnectorId {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    })
+    .into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            connector_circuit_breaker::reset_circuit_breaker(
+                state,
+                req.merchant_id,
+                auth.profile_id,
+                req.merchant_connector_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(
+                merchant_id.clone(),
+                Permission::ProfileConnectorWrite,
+            )),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::ProfileConnectorWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// Merchant Connector - Delete
 ///
 /// Delete or Detach a Merchant Connector from Merchant Account
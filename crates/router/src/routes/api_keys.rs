@@ -164,7 +164,9 @@ pub async fn api_key_update(
         state,
         &req,
         payload,
-        |state, _, payload, _| api_keys::update_api_key(state, payload),
+        |state, auth_data, payload, _| {
+            api_keys::update_api_key(state, payload, auth_data.key_store)
+        },
         auth::auth_type(
             &auth::AdminApiAuthWithApiKeyFallbackAndMerchantIdFromRoute(merchant_id.clone()),
             &auth::JWTAuthMerchantFromRoute {
@@ -197,12 +199,14 @@ pub async fn api_key_update(
         payload,
         |state,
          auth::AuthenticationDataWithoutProfile {
-             merchant_account, ..
+             merchant_account,
+             key_store,
+             ..
          },
          mut payload,
          _| {
             payload.merchant_id = merchant_account.get_id().to_owned();
-            api_keys::update_api_key(state, payload)
+            api_keys::update_api_key(state, payload, key_store)
         },
         auth::auth_type(
             &auth::AdminApiAuthWithMerchantIdFromHeader,
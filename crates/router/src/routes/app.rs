@@ -23,7 +23,11 @@ use hyperswitch_interfaces::{
 };
 use router_env::tracing_actix_web::RequestId;
 use scheduler::SchedulerInterface;
-use storage_impl::{config::TenantConfig, redis::RedisStore, MockDb};
+use storage_impl::{
+    config::TenantConfig,
+    redis::{kv_store::RedisConnInterface, RedisStore},
+    MockDb,
+};
 use tokio::sync::oneshot;
 
 use self::settings::Tenant;
@@ -66,7 +70,9 @@ use super::{
     relay, user, user_role,
 };
 #[cfg(feature = "v1")]
-use super::{apple_pay_certificates_migration, blocklist, payment_link, webhook_events};
+use super::{
+    apple_pay_certificates_migration, blocklist, payment_link, webhook_endpoints, webhook_events,
+};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers, payments};
 #[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
@@ -105,6 +111,8 @@ use crate::{
 #[derive(Clone)]
 pub struct ReqState {
     pub event_context: events::EventContext<crate::events::EventType, EventsHandler>,
+    /// Authentication of the request, absent for requests not made to the API
+    pub auth_type: Option<crate::services::authentication::AuthenticationType>,
 }
 
 #[derive(Clone)]
@@ -140,6 +148,7 @@ impl SessionState {
     pub fn get_req_state(&self) -> ReqState {
         ReqState {
             event_context: events::EventContext::new(self.event_handler.clone()),
+            auth_type: None,
         }
     }
     pub fn get_grpc_headers(&self) -> GrpcHeaders {
@@ -405,7 +414,10 @@ impl AppState {
             let theme_storage_client = conf.theme.storage.get_file_storage_client().await;
             let crm_client = conf.crm.get_crm_client().await;
 
-            let grpc_client = conf.grpc_client.get_grpc_client_interface().await;
+            let grpc_client = conf
+                .grpc_client
+                .get_grpc_client_interface(cache_store.get_redis_conn())
+                .await;
 
             Self {
                 flow_name: String::from("default"),
@@ -912,18 +924,46 @@ impl Routing {
                     routing::routing_unlink_config(state, req, payload, &TransactionType::Payment)
                 })),
             )
+            .service(
+                web::resource("/history").route(web::get().to(|state, req, query| {
+                    routing::routing_retrieve_activation_history(
+                        state,
+                        req,
+                        query,
+                        &TransactionType::Payment,
+                    )
+                })),
+            )
+            .service(web::resource("/diff").route(web::get().to(routing::routing_diff_config)))
+            .service(
+                web::resource("/rollback").route(web::post().to(|state, req, payload| {
+                    routing::routing_rollback_config(state, req, payload, &TransactionType::Payment)
+                })),
+            )
             .service(
                 web::resource("/decision")
                     .route(web::put().to(routing::upsert_decision_manager_config))
                     .route(web::get().to(routing::retrieve_decision_manager_config))
                     .route(web::delete().to(routing::delete_decision_manager_config)),
             )
+            .service(
+                web::resource("/decision/analyze")
+                    .route(web::post().to(routing::analyze_decision_manager_config)),
+            )
+            .service(
+                web::resource("/three_ds_decision/execute")
+                    .route(web::post().to(routing::execute_three_ds_decision_rule)),
+            )
             .service(
                 web::resource("/decision/surcharge")
                     .route(web::put().to(routing::upsert_surcharge_decision_manager_config))
                     .route(web::get().to(routing::retrieve_surcharge_decision_manager_config))
                     .route(web::delete().to(routing::delete_surcharge_decision_manager_config)),
             )
+            .service(
+                web::resource("/decision/surcharge/analyze")
+                    .route(web::post().to(routing::analyze_surcharge_decision_manager_config)),
+            )
             .service(
                 web::resource("/default/profile/{profile_id}").route(web::post().to(
                     |state, req, path, payload| {
@@ -1007,8 +1047,14 @@ impl Routing {
                 )
                 .service(
                     web::resource("/payouts/{algorithm_id}/activate").route(web::post().to(
-                        |state, req, path| {
-                            routing::routing_link_config(state, req, path, &TransactionType::Payout)
+                        |state, req, path, payload| {
+                            routing::routing_link_config(
+                                state,
+                                req,
+                                path,
+                                payload,
+                                &TransactionType::Payout,
+                            )
                         },
                     )),
                 )
@@ -1022,6 +1068,26 @@ impl Routing {
                         )
                     },
                 )))
+                .service(web::resource("/payouts/history").route(web::get().to(
+                    |state, req, query| {
+                        routing::routing_retrieve_activation_history(
+                            state,
+                            req,
+                            query,
+                            &TransactionType::Payout,
+                        )
+                    },
+                )))
+                .service(web::resource("/payouts/rollback").route(web::post().to(
+                    |state, req, payload| {
+                        routing::routing_rollback_config(
+                            state,
+                            req,
+                            payload,
+                            &TransactionType::Payout,
+                        )
+                    },
+                )))
                 .service(
                     web::resource("/payouts/default/profile/{profile_id}").route(web::post().to(
                         |state, req, path, payload| {
@@ -1053,10 +1119,24 @@ impl Routing {
             )
             .service(
                 web::resource("/{algorithm_id}/activate").route(web::post().to(
-                    |state, req, path| {
-                        routing::routing_link_config(state, req, path, &TransactionType::Payment)
+                    |state, req, path, payload| {
+                        routing::routing_link_config(
+                            state,
+                            req,
+                            path,
+                            payload,
+                            &TransactionType::Payment,
+                        )
                     },
                 )),
+            )
+            .service(
+                web::resource("/{algorithm_id}/simulate")
+                    .route(web::post().to(routing::routing_simulate_config)),
+            )
+            .service(
+                web::resource("/{algorithm_id}/dsl")
+                    .route(web::get().to(routing::routing_export_config_dsl)),
             );
         route
     }
@@ -1252,6 +1332,17 @@ impl Payouts {
                 );
         }
         route = route
+            .service(web::resource("/batch").route(web::post().to(payouts_batch_create)))
+            .service(web::resource("/batch/upload").route(web::post().to(payouts_batch_upload)));
+        #[cfg(feature = "olap")]
+        {
+            route = route
+                .service(web::resource("/batch/list").route(web::get().to(payouts_batch_list)));
+        }
+        route = route
+            .service(
+                web::resource("/batch/{batch_id}").route(web::get().to(payouts_batch_retrieve)),
+            )
             .service(
                 web::resource("/{payout_id}")
                     .route(web::get().to(payouts_retrieve))
@@ -1640,6 +1731,18 @@ impl MerchantConnectorAccount {
                         .route(web::get().to(connector_retrieve))
                         .route(web::post().to(connector_update))
                         .route(web::delete().to(connector_delete)),
+                )
+                .service(
+                    web::resource(
+                        "/{merchant_id}/connectors/{merchant_connector_id}/circuit_breaker",
+                    )
+                    .route(web::get().to(connector_circuit_breaker_retrieve)),
+                )
+                .service(
+                    web::resource(
+                        "/{merchant_id}/connectors/{merchant_connector_id}/circuit_breaker/reset",
+                    )
+                    .route(web::post().to(connector_circuit_breaker_reset)),
                 );
         }
         #[cfg(feature = "oltp")]
@@ -1892,6 +1995,10 @@ impl Disputes {
                     .route(web::put().to(disputes::attach_dispute_evidence))
                     .route(web::delete().to(disputes::delete_dispute_evidence)),
             )
+            .service(
+                web::resource("/evidence/package")
+                    .route(web::post().to(disputes::build_dispute_evidence_package)),
+            )
             .service(
                 web::resource("/evidence/{dispute_id}")
                     .route(web::get().to(disputes::retrieve_dispute_evidence)),
@@ -2127,6 +2234,39 @@ impl Profile {
                 .service(
                     web::resource("/toggle_connector_agnostic_mit")
                         .route(web::post().to(profiles::toggle_connector_agnostic_mit)),
+                )
+                .service(
+                    web::scope("/webhook_endpoints")
+                        .service(
+                            web::resource("")
+                                .route(web::post().to(webhook_endpoints::create_webhook_endpoint))
+                                .route(web::get().to(webhook_endpoints::list_webhook_endpoints)),
+                        )
+                        .service(
+                            web::resource("/{endpoint_id}")
+                                .route(web::get().to(webhook_endpoints::retrieve_webhook_endpoint))
+                                .route(web::post().to(webhook_endpoints::update_webhook_endpoint))
+                                .route(
+                                    web::delete().to(webhook_endpoints::delete_webhook_endpoint),
+                                ),
+                        ),
+                )
+                .service(
+                    web::scope("/webhook_secret_rotation")
+                        .service(
+                            web::resource("")
+                                .route(
+                                    web::post()
+                                        .to(webhook_endpoints::start_webhook_secret_rotation),
+                                )
+                                .route(
+                                    web::get()
+                                        .to(webhook_endpoints::retrieve_webhook_secret_rotation),
+                                ),
+                        )
+                        .service(web::resource("/complete").route(
+                            web::post().to(webhook_endpoints::complete_webhook_secret_rotation),
+                        )),
                 ),
         );
 
@@ -42,6 +42,7 @@ pub async fn add_entry_to_blocklist(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantAccountWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantAccountWrite,
@@ -86,6 +87,7 @@ pub async fn remove_entry_from_blocklist(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantAccountWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantAccountWrite,
@@ -132,6 +134,7 @@ pub async fn list_blocked_payment_methods(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantAccountRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantAccountRead,
@@ -178,6 +181,7 @@ pub async fn toggle_blocklist_guard(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantAccountWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantAccountWrite,
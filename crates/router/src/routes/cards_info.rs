@@ -6,7 +6,7 @@ use router_env::{instrument, tracing, Flow};
 use super::app::AppState;
 use crate::{
     core::{api_locking, cards_info},
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::domain,
 };
 
@@ -40,7 +40,11 @@ pub async fn card_iin_info(
         card_iin,
     };
 
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Permission::MerchantPaymentRead,
+    };
 
     let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload, api_auth)
     {
@@ -4,7 +4,7 @@ use router_env::Flow;
 use crate::{
     core::{api_locking, currency},
     routes::AppState,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
 };
 
 #[cfg(feature = "v1")]
@@ -20,6 +20,7 @@ pub async fn retrieve_forex(state: web::Data<AppState>, req: HttpRequest) -> Htt
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantPaymentRead,
             }),
             &auth::DashboardNoPermissionAuth,
             req.headers(),
@@ -56,6 +57,7 @@ pub async fn convert_forex(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantPaymentRead,
             }),
             &auth::DashboardNoPermissionAuth,
             req.headers(),
@@ -31,6 +31,7 @@ pub async fn customers_create(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantCustomerWrite,
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantCustomerWrite,
@@ -64,6 +65,7 @@ pub async fn customers_create(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantCustomerWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantCustomerWrite,
@@ -91,7 +93,11 @@ pub async fn customers_retrieve(
             permission: Permission::MerchantCustomerRead,
         })
     } else {
-        let api_auth = auth::ApiKeyAuth::default();
+        let api_auth = auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Permission::MerchantCustomerRead,
+        };
         match auth::is_ephemeral_auth(req.headers(), api_auth) {
             Ok(auth) => auth,
             Err(err) => return api::log_and_return_error_response(err),
@@ -140,6 +146,7 @@ pub async fn customers_retrieve(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantCustomerRead,
             },
             &v2_client_auth,
             req.headers(),
@@ -190,6 +197,7 @@ pub async fn customers_list(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantCustomerRead,
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantCustomerRead,
@@ -228,6 +236,7 @@ pub async fn customers_list(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantCustomerRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantCustomerRead,
@@ -270,6 +279,7 @@ pub async fn customers_update(
             &auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantCustomerWrite,
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantCustomerWrite,
@@ -309,6 +319,7 @@ pub async fn customers_update(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantCustomerWrite,
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantCustomerWrite,
@@ -345,6 +356,7 @@ pub async fn customers_delete(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantCustomerWrite,
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantCustomerWrite,
@@ -381,6 +393,7 @@ pub async fn customers_delete(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantCustomerWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantCustomerWrite,
@@ -417,6 +430,7 @@ pub async fn get_customer_mandates(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantMandateRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantMandateRead,
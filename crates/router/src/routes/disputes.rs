@@ -54,6 +54,7 @@ pub async fn retrieve_dispute(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileDisputeRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeRead,
@@ -111,6 +112,7 @@ pub async fn retrieve_disputes_list(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantDisputeRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantDisputeRead,
@@ -175,6 +177,7 @@ pub async fn retrieve_disputes_list_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileDisputeRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeRead,
@@ -216,6 +219,7 @@ pub async fn get_disputes_filters(state: web::Data<AppState>, req: HttpRequest)
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantDisputeRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantDisputeRead,
@@ -264,6 +268,7 @@ pub async fn get_disputes_filters_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileDisputeRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeRead,
@@ -316,6 +321,7 @@ pub async fn accept_dispute(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileDisputeWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeWrite,
@@ -363,6 +369,7 @@ pub async fn submit_dispute_evidence(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileDisputeWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeWrite,
@@ -417,6 +424,7 @@ pub async fn attach_dispute_evidence(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileDisputeWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeWrite,
@@ -469,6 +477,7 @@ pub async fn retrieve_dispute_evidence(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileDisputeRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeRead,
@@ -480,6 +489,61 @@ pub async fn retrieve_dispute_evidence(
     .await
 }
 
+#[cfg(feature = "v1")]
+/// Disputes - Build Evidence Package
+///
+/// To pre-fill the evidence of a dispute from the payment, customer and fulfillment data held for it
+#[utoipa::path(
+    post,
+    path = "/disputes/evidence/package",
+    request_body=DisputeEvidencePackageRequest,
+    responses(
+        (status = 200, description = "The dispute evidence package was built successfully", body = DisputeEvidencePackageResponse),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Build Dispute Evidence Package",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BuildDisputeEvidencePackage))]
+pub async fn build_dispute_evidence_package(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<dispute_models::DisputeEvidencePackageRequest>,
+) -> HttpResponse {
+    let flow = Flow::BuildDisputeEvidencePackage;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            disputes::evidence::build_evidence_package(
+                state,
+                merchant_context,
+                auth.profile_id,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+                permission: Permission::ProfileDisputeWrite,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileDisputeWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Disputes - Delete Evidence attached to a Dispute
 ///
 /// To delete an evidence file attached to a dispute
@@ -517,6 +581,7 @@ pub async fn delete_dispute_evidence(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileDisputeWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeWrite,
@@ -552,6 +617,7 @@ pub async fn get_disputes_aggregate(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantDisputeRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantDisputeRead,
@@ -593,6 +659,7 @@ pub async fn get_disputes_aggregate_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileDisputeRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeRead,
@@ -6,7 +6,7 @@ use super::AppState;
 use crate::types::domain;
 use crate::{
     core::{api_locking, payments::helpers},
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
 };
 
 #[cfg(all(feature = "v1", not(feature = "customer_v2")))]
@@ -33,6 +33,7 @@ pub async fn ephemeral_key_create(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
         }),
         api_locking::LockAction::NotApplicable,
     )
@@ -57,6 +58,7 @@ pub async fn ephemeral_key_delete(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
         }),
         api_locking::LockAction::NotApplicable,
     )
@@ -91,6 +93,7 @@ pub async fn client_secret_create(
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -115,6 +118,7 @@ pub async fn client_secret_delete(
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -8,7 +8,7 @@ pub mod transformers;
 use super::app::AppState;
 use crate::{
     core::files::*,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::{api::files, domain},
 };
 
@@ -55,6 +55,7 @@ pub async fn files_create(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantDisputeWrite,
             }),
             &auth::DashboardNoPermissionAuth,
             req.headers(),
@@ -107,6 +108,7 @@ pub async fn files_delete(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantDisputeWrite,
             }),
             &auth::DashboardNoPermissionAuth,
             req.headers(),
@@ -159,6 +161,7 @@ pub async fn files_retrieve(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantDisputeRead,
             }),
             &auth::DashboardNoPermissionAuth,
             req.headers(),
@@ -1,6 +1,7 @@
 use router_env::Flow;
 
-#[derive(Clone, Debug, strum::Display)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, strum::Display, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum ApiIdentifier {
     Payments,
@@ -66,6 +67,11 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingLinkConfig
             | Flow::RoutingUnlinkConfig
             | Flow::RoutingRetrieveConfig
+            | Flow::RoutingSimulateConfig
+            | Flow::RoutingExportConfigDsl
+            | Flow::RoutingRetrieveActivationHistory
+            | Flow::RoutingDiffConfig
+            | Flow::RoutingRollbackConfig
             | Flow::RoutingRetrieveActiveConfig
             | Flow::RoutingRetrieveDefaultConfig
             | Flow::RoutingRetrieveDictionary
@@ -74,6 +80,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingDeleteConfig
             | Flow::DecisionManagerDeleteConfig
             | Flow::DecisionManagerRetrieveConfig
+            | Flow::DecisionManagerAnalyzeConfig
+            | Flow::ThreeDsDecisionRuleExecute
             | Flow::ToggleDynamicRouting
             | Flow::UpdateDynamicRoutingConfigs
             | Flow::DecisionManagerUpsertConfig
@@ -90,7 +98,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantConnectorsRetrieve
             | Flow::MerchantConnectorsUpdate
             | Flow::MerchantConnectorsDelete
-            | Flow::MerchantConnectorsList => Self::MerchantConnector,
+            | Flow::MerchantConnectorsList
+            | Flow::ConnectorCircuitBreakerRetrieve
+            | Flow::ConnectorCircuitBreakerReset => Self::MerchantConnector,
 
             Flow::ConfigKeyCreate
             | Flow::ConfigKeyFetch
@@ -169,6 +179,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsFilter
             | Flow::PayoutsAccounts
             | Flow::PayoutsConfirm
+            | Flow::PayoutsBatchCreate
+            | Flow::PayoutsBatchRetrieve
+            | Flow::PayoutsBatchList
             | Flow::PayoutLinkInitiate => Self::Payouts,
 
             Flow::RefundsCreate
@@ -187,6 +200,14 @@ impl From<Flow> for ApiIdentifier {
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
             | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEndpointCreate
+            | Flow::WebhookEndpointList
+            | Flow::WebhookEndpointRetrieve
+            | Flow::WebhookEndpointUpdate
+            | Flow::WebhookEndpointDelete
+            | Flow::WebhookSecretRotationStart
+            | Flow::WebhookSecretRotationRetrieve
+            | Flow::WebhookSecretRotationComplete
             | Flow::RecoveryIncomingWebhookReceive => Self::Webhooks,
 
             Flow::ApiKeyCreate
@@ -201,6 +222,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::DisputesEvidenceSubmit
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
+            | Flow::BuildDisputeEvidencePackage
             | Flow::DisputesAggregate
             | Flow::DeleteDisputeEvidence => Self::Disputes,
 
@@ -348,3 +370,29 @@ impl From<Flow> for ApiIdentifier {
         }
     }
 }
+
+/// Determines the group of routes a flow belongs to, used for applying the rate limits configured
+/// for a group of routes
+pub trait GetApiIdentifier {
+    fn get_api_identifier(&self) -> Option<ApiIdentifier>;
+}
+
+impl GetApiIdentifier for Flow {
+    fn get_api_identifier(&self) -> Option<ApiIdentifier> {
+        Some(ApiIdentifier::from(self.clone()))
+    }
+}
+
+#[cfg(feature = "olap")]
+impl GetApiIdentifier for analytics::AnalyticsFlow {
+    fn get_api_identifier(&self) -> Option<ApiIdentifier> {
+        None
+    }
+}
+
+#[cfg(feature = "dummy_connector")]
+impl GetApiIdentifier for super::dummy_connector::types::Flow {
+    fn get_api_identifier(&self) -> Option<ApiIdentifier> {
+        None
+    }
+}
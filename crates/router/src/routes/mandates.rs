@@ -50,6 +50,7 @@ pub async fn get_mandate(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantMandateRead,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -81,6 +82,7 @@ pub async fn revoke_mandate(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantMandateWrite,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -132,6 +134,7 @@ pub async fn retrieve_mandates_list(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantMandateRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantMandateRead,
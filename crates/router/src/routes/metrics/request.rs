@@ -9,7 +9,8 @@ pub fn track_response_status_code<Q>(response: &ApplicationResponse<Q>) -> i64 {
         | ApplicationResponse::GenericLinkForm(_)
         | ApplicationResponse::PaymentLinkForm(_)
         | ApplicationResponse::FileData(_)
-        | ApplicationResponse::JsonWithHeaders(_) => 200,
+        | ApplicationResponse::JsonWithHeaders(_)
+        | ApplicationResponse::IdempotentReplay(_) => 200,
         ApplicationResponse::JsonForRedirection(_) => 302,
     }
 }
@@ -3,7 +3,7 @@ use router_env::{instrument, tracing, Flow};
 
 use crate::{
     core::{api_locking, payment_link::*},
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::domain,
     AppState,
 };
@@ -35,7 +35,11 @@ pub async fn payment_link_retrieve(
 ) -> impl Responder {
     let flow = Flow::PaymentLinkRetrieve;
     let payload = json_payload.into_inner();
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Permission::MerchantPaymentRead,
+    };
 
     let (auth_type, _) =
         match auth::check_client_secret_and_get_auth(req.headers(), &payload, api_auth) {
@@ -172,6 +176,7 @@ pub async fn payments_link_list(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantPaymentRead,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -70,6 +70,7 @@ pub async fn create_payment_method_api(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -106,6 +107,7 @@ pub async fn create_payment_method_api(
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -140,6 +142,7 @@ pub async fn create_payment_method_intent_api(
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -204,6 +207,7 @@ pub async fn payment_method_update_api(
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -237,6 +241,7 @@ pub async fn payment_method_retrieve_api(
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerRead,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -270,6 +275,7 @@ pub async fn payment_method_delete_api(
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -413,7 +419,11 @@ pub async fn save_payment_method_api(
     let flow = Flow::PaymentMethodSave;
     let payload = json_payload.into_inner();
     let pm_id = path.into_inner();
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Permission::MerchantCustomerWrite,
+    };
 
     let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload, api_auth)
     {
@@ -452,7 +462,11 @@ pub async fn list_payment_method_api(
 ) -> HttpResponse {
     let flow = Flow::PaymentMethodsList;
     let payload = json_payload.into_inner();
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Permission::MerchantCustomerRead,
+    };
 
     let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload, api_auth)
     {
@@ -516,7 +530,11 @@ pub async fn list_customer_payment_method_api(
     let flow = Flow::CustomerPaymentMethodsList;
     let payload = query_payload.into_inner();
     let customer_id = customer_id.into_inner().0;
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Permission::MerchantCustomerRead,
+    };
 
     let ephemeral_auth = match auth::is_ephemeral_auth(req.headers(), api_auth) {
         Ok(auth) => auth,
@@ -583,7 +601,11 @@ pub async fn list_customer_payment_method_api_client(
     let flow = Flow::CustomerPaymentMethodsList;
     let payload = query_payload.into_inner();
     let api_key = auth::get_api_key(req.headers()).ok();
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Permission::MerchantCustomerRead,
+    };
 
     let (auth, _, is_ephemeral_auth) =
         match auth::get_ephemeral_or_other_auth(req.headers(), false, Some(&payload), api_auth)
@@ -638,6 +660,7 @@ pub async fn initiate_pm_collect_link_flow(
         &auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -675,6 +698,7 @@ pub async fn list_customer_payment_method_api(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantCustomerRead,
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantCustomerRead,
@@ -717,6 +741,7 @@ pub async fn get_payment_method_token_data(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantCustomerRead,
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantCustomerRead,
@@ -754,6 +779,7 @@ pub async fn get_total_payment_method_count(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantCustomerRead,
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantCustomerRead,
@@ -831,6 +857,7 @@ pub async fn payment_method_retrieve_api(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerRead,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -851,7 +878,11 @@ pub async fn payment_method_update_api(
     let flow = Flow::PaymentMethodsUpdate;
     let payment_method_id = path.into_inner();
     let payload = json_payload.into_inner();
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Permission::MerchantCustomerWrite,
+    };
 
     let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload, api_auth)
     {
@@ -890,7 +921,11 @@ pub async fn payment_method_delete_api(
     let pm = PaymentMethodId {
         payment_method_id: payment_method_id.into_inner().0,
     };
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Permission::MerchantCustomerWrite,
+    };
 
     let ephemeral_auth = match auth::is_ephemeral_auth(req.headers(), api_auth) {
         Ok(auth) => auth,
@@ -945,6 +980,7 @@ pub async fn list_countries_currencies_for_connector_payment_method(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileConnectorWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileConnectorWrite,
@@ -971,7 +1007,11 @@ pub async fn default_payment_method_set_api(
     let payload = path.into_inner();
     let pc = payload.clone();
     let customer_id = &pc.customer_id;
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Permission::MerchantCustomerWrite,
+    };
 
     let ephemeral_auth = match auth::is_ephemeral_auth(req.headers(), api_auth) {
         Ok(auth) => auth,
@@ -1248,6 +1288,7 @@ pub async fn payment_methods_session_create(
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -1288,6 +1329,7 @@ pub async fn payment_methods_session_update(
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantCustomerWrite,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -1324,6 +1366,7 @@ pub async fn payment_methods_session_retrieve(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantCustomerRead,
             },
             &auth::V2ClientAuth(
                 common_utils::types::authentication::ResourceId::PaymentMethodSession(
@@ -14,6 +14,7 @@ use crate::{
     self as app,
     core::{
         errors::{self, http_not_implemented},
+        idempotency,
         payments::{self, PaymentRedirectFlow},
     },
     routes::lock_utils,
@@ -48,6 +49,10 @@ pub async fn payments_create(
         return http_not_implemented();
     };
 
+    if let Err(err) = idempotency::store_original_request_payload(&req, &payload) {
+        return api::log_and_return_error_response(err);
+    }
+
     if let Err(err) = get_or_generate_payment_id(&mut payload) {
         return api::log_and_return_error_response(err);
     }
@@ -98,11 +103,13 @@ pub async fn payments_create(
             env::Env::Production => &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: true,
+                permission: Permission::ProfilePaymentWrite,
             }),
             _ => auth::auth_type(
                 &auth::HeaderAuth(auth::ApiKeyAuth {
                     is_connected_allowed: false,
                     is_platform_allowed: true,
+                    permission: Permission::ProfilePaymentWrite,
                 }),
                 &auth::JWTAuth {
                     permission: Permission::ProfilePaymentWrite,
@@ -165,11 +172,13 @@ pub async fn payments_create_intent(
             env::Env::Production => &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantPaymentWrite,
             },
             _ => auth::auth_type(
                 &auth::V2ApiKeyAuth {
                     is_connected_allowed: false,
                     is_platform_allowed: false,
+                    permission: Permission::ProfilePaymentWrite,
                 },
                 &auth::JWTAuth {
                     permission: Permission::ProfilePaymentWrite,
@@ -235,6 +244,7 @@ pub async fn payments_get_intent(
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantPaymentRead,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -278,11 +288,13 @@ pub async fn payments_create_and_confirm_intent(
             env::Env::Production => &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantPaymentWrite,
             },
             _ => auth::auth_type(
                 &auth::V2ApiKeyAuth {
                     is_connected_allowed: false,
                     is_platform_allowed: false,
+                    permission: Permission::ProfilePaymentWrite,
                 },
                 &auth::JWTAuth {
                     permission: Permission::ProfilePaymentWrite,
@@ -349,6 +361,7 @@ pub async fn payments_update_intent(
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantPaymentWrite,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -449,6 +462,7 @@ pub async fn payments_retrieve(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: true,
+        permission: Permission::ProfilePaymentRead,
     };
 
     let (auth_type, auth_flow) =
@@ -510,6 +524,7 @@ pub async fn payments_retrieve_with_gateway_creds(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: true,
+        permission: Permission::ProfilePaymentRead,
     };
 
     let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers(), api_auth) {
@@ -600,6 +615,7 @@ pub async fn payments_update(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: true,
+        permission: Permission::ProfilePaymentWrite,
     };
     let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers(), api_auth) {
         Ok(auth) => auth,
@@ -751,6 +767,7 @@ pub async fn payments_update_metadata(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: true,
+            permission: Permission::ProfilePaymentWrite,
         }),
         locking_action,
     ))
@@ -797,6 +814,7 @@ pub async fn payments_confirm(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: true,
+        permission: Permission::ProfilePaymentWrite,
     };
 
     let (auth_type, auth_flow) =
@@ -884,6 +902,7 @@ pub async fn payments_capture(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: true,
+            permission: Permission::ProfilePaymentWrite,
         }),
         locking_action,
     ))
@@ -1312,6 +1331,7 @@ pub async fn payments_complete_authorize(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: true,
+        permission: Permission::ProfilePaymentWrite,
     };
 
     let (auth_type, auth_flow) =
@@ -1405,6 +1425,7 @@ pub async fn payments_cancel(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: true,
+            permission: Permission::ProfilePaymentWrite,
         }),
         locking_action,
     ))
@@ -1435,6 +1456,7 @@ pub async fn payments_list(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: true,
+                permission: Permission::MerchantPaymentRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantPaymentRead,
@@ -1470,6 +1492,7 @@ pub async fn payments_list(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantPaymentRead,
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantPaymentRead,
@@ -1510,6 +1533,7 @@ pub async fn profile_payments_list(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: true,
+                permission: Permission::ProfilePaymentRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfilePaymentRead,
@@ -1778,11 +1802,13 @@ pub async fn payments_approve(
             env::Env::Production => &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: true,
+                permission: Permission::ProfilePaymentWrite,
             }),
             _ => auth::auth_type(
                 &auth::HeaderAuth(auth::ApiKeyAuth {
                     is_connected_allowed: false,
                     is_platform_allowed: true,
+                    permission: Permission::ProfilePaymentWrite,
                 }),
                 &auth::JWTAuth {
                     permission: Permission::ProfilePaymentWrite,
@@ -1850,11 +1876,13 @@ pub async fn payments_reject(
             env::Env::Production => &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: true,
+                permission: Permission::ProfilePaymentWrite,
             }),
             _ => auth::auth_type(
                 &auth::HeaderAuth(auth::ApiKeyAuth {
                     is_connected_allowed: false,
                     is_platform_allowed: true,
+                    permission: Permission::ProfilePaymentWrite,
                 }),
                 &auth::JWTAuth {
                     permission: Permission::ProfilePaymentWrite,
@@ -2030,6 +2058,7 @@ pub async fn payments_incremental_authorization(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: true,
+            permission: Permission::ProfilePaymentWrite,
         }),
         locking_action,
     ))
@@ -2181,6 +2210,7 @@ pub async fn retrieve_extended_card_info(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: true,
+            permission: Permission::MerchantPaymentRead,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -2838,6 +2868,7 @@ pub async fn proxy_confirm_intent(
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantPaymentWrite,
         },
         locking_action,
     ))
@@ -2914,6 +2945,7 @@ pub async fn payment_status(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfilePaymentRead,
             },
             &auth::JWTAuth {
                 permission: Permission::ProfilePaymentRead,
@@ -2966,6 +2998,7 @@ pub async fn payment_get_intent_using_merchant_reference_id(
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantPaymentRead,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -3091,6 +3124,7 @@ pub async fn payments_capture(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileAccountWrite,
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileAccountWrite,
@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+use actix_multipart::form::MultipartForm;
 use actix_web::{
     body::{BoxBody, MessageBody},
     web, HttpRequest, HttpResponse, Responder,
@@ -38,6 +40,7 @@ pub async fn payouts_create(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantPayoutWrite,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -75,6 +78,7 @@ pub async fn payouts_retrieve(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfilePayoutRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfilePayoutRead,
@@ -111,6 +115,7 @@ pub async fn payouts_update(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantPayoutWrite,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -130,7 +135,11 @@ pub async fn payouts_confirm(
     tracing::Span::current().record("payout_id", &payout_id);
     payload.payout_id = Some(payout_id);
     payload.confirm = Some(true);
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Permission::MerchantPayoutWrite,
+    };
 
     let (auth_type, _auth_flow) =
         match auth::check_client_secret_and_get_auth(req.headers(), &payload, api_auth) {
@@ -181,6 +190,7 @@ pub async fn payouts_cancel(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantPayoutWrite,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -212,6 +222,7 @@ pub async fn payouts_fulfill(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantPayoutWrite,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -244,6 +255,7 @@ pub async fn payouts_list(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantPayoutRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantPayoutRead,
@@ -286,6 +298,7 @@ pub async fn payouts_list_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfilePayoutRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfilePayoutRead,
@@ -323,6 +336,7 @@ pub async fn payouts_list_by_filter(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantPayoutRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantPayoutRead,
@@ -365,6 +379,7 @@ pub async fn payouts_list_by_filter_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfilePayoutRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfilePayoutRead,
@@ -402,6 +417,7 @@ pub async fn payouts_list_available_filters_for_merchant(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantPayoutRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantPayoutRead,
@@ -444,6 +460,7 @@ pub async fn payouts_list_available_filters_for_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfilePayoutRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfilePayoutRead,
@@ -455,6 +472,145 @@ pub async fn payouts_list_available_filters_for_profile(
     .await
 }
 
+/// Payouts - Batch Create
+#[cfg(all(feature = "v1", feature = "payouts"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchCreate))]
+pub async fn payouts_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutBatchCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchCreate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        batch::PayoutBatchRows::from(json_payload.into_inner()),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::payout_batch_create_core(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Permission::MerchantPayoutWrite,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch Upload
+#[cfg(all(feature = "v1", feature = "payouts"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchCreate))]
+pub async fn payouts_batch_upload(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<batch::PayoutBatchUploadForm>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchCreate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        batch::get_payout_batch_upload_rows(form),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::payout_batch_create_core(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Permission::MerchantPayoutWrite,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch Retrieve
+#[cfg(all(feature = "v1", feature = "payouts"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchRetrieve))]
+pub async fn payouts_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchRetrieve;
+    let payload = payout_types::PayoutBatchRetrieveRequest {
+        batch_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::payout_batch_retrieve_core(state, merchant_context, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+                permission: Permission::MerchantPayoutRead,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch List
+#[cfg(all(feature = "olap", feature = "payouts", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchList))]
+pub async fn payouts_batch_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_params: web::Query<payout_types::PayoutBatchListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::payout_batch_list_core(state, merchant_context, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+                permission: Permission::MerchantPayoutRead,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
 // #[get("/accounts")]
 pub async fn payouts_accounts() -> impl Responder {
@@ -5,7 +5,7 @@ use router_env::{instrument, tracing, types::Flow};
 use crate::{
     core::api_locking,
     routes::AppState,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::transformers::ForeignTryFrom,
 };
 
@@ -17,7 +17,11 @@ pub async fn link_token_create(
 ) -> impl Responder {
     let payload = json_payload.into_inner();
     let flow = Flow::PmAuthLinkTokenCreate;
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Permission::MerchantPaymentWrite,
+    };
 
     let (auth, _) = match crate::services::authentication::check_client_secret_and_get_auth(
         req.headers(),
@@ -66,7 +70,11 @@ pub async fn exchange_token(
 ) -> impl Responder {
     let payload = json_payload.into_inner();
     let flow = Flow::PmAuthExchangeToken;
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Permission::MerchantPaymentWrite,
+    };
 
     let (auth, _) = match crate::services::authentication::check_client_secret_and_get_auth(
         req.headers(),
@@ -32,7 +32,10 @@ pub async fn profile_create(
             create_profile(state, req, merchant_context)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(
+                merchant_id.clone(),
+                permissions::Permission::MerchantAccountWrite,
+            )),
             &auth::JWTAuthMerchantFromRoute {
                 merchant_id,
                 required_permission: permissions::Permission::MerchantAccountWrite,
@@ -103,7 +106,10 @@ pub async fn profile_retrieve(
         profile_id,
         |state, auth_data, profile_id, _| retrieve_profile(state, profile_id, auth_data.key_store),
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(
+                merchant_id.clone(),
+                permissions::Permission::ProfileAccountRead,
+            )),
             &auth::JWTAuthMerchantFromRoute {
                 merchant_id: merchant_id.clone(),
                 required_permission: permissions::Permission::ProfileAccountRead,
@@ -166,7 +172,10 @@ pub async fn profile_update(
         json_payload.into_inner(),
         |state, auth_data, req, _| update_profile(state, &profile_id, auth_data.key_store, req),
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(
+                merchant_id.clone(),
+                permissions::Permission::ProfileAccountWrite,
+            )),
             &auth::JWTAuthMerchantAndProfileFromRoute {
                 merchant_id: merchant_id.clone(),
                 profile_id: profile_id.clone(),
@@ -251,7 +260,10 @@ pub async fn profiles_list(
         merchant_id.clone(),
         |state, _auth, merchant_id, _| list_profile(state, merchant_id, None),
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(
+                merchant_id.clone(),
+                permissions::Permission::MerchantAccountRead,
+            )),
             &auth::JWTAuthMerchantFromRoute {
                 merchant_id,
                 required_permission: permissions::Permission::MerchantAccountRead,
@@ -317,7 +329,10 @@ pub async fn profiles_list_at_profile_level(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(
+                merchant_id.clone(),
+                permissions::Permission::ProfileAccountRead,
+            )),
             &auth::JWTAuthMerchantFromRoute {
                 merchant_id,
                 required_permission: permissions::Permission::ProfileAccountRead,
@@ -354,6 +369,7 @@ pub async fn toggle_connector_agnostic_mit(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: permissions::Permission::MerchantRoutingWrite,
             }),
             &auth::JWTAuth {
                 permission: permissions::Permission::MerchantRoutingWrite,
@@ -413,7 +429,10 @@ pub async fn payment_connector_list_profile(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(
+                merchant_id.clone(),
+                permissions::Permission::ProfileConnectorRead,
+            )),
             &auth::JWTAuthMerchantFromRoute {
                 merchant_id,
                 required_permission: permissions::Permission::ProfileConnectorRead,
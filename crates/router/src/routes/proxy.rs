@@ -4,7 +4,7 @@ use router_env::{instrument, tracing, Flow};
 use crate::{
     self as app,
     core::{api_locking, proxy},
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::domain,
 };
 
@@ -30,6 +30,7 @@ pub async fn proxy(
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantPaymentWrite,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -84,6 +84,7 @@ pub async fn refunds_create(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRefundWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRefundWrite,
@@ -120,6 +121,7 @@ pub async fn refunds_create(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRefundWrite,
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileRefundWrite,
@@ -190,6 +192,7 @@ pub async fn refunds_retrieve(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRefundRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRefundRead,
@@ -240,6 +243,7 @@ pub async fn refunds_retrieve(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRefundRead,
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileRefundRead,
@@ -300,6 +304,7 @@ pub async fn refunds_retrieve_with_body(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::ProfileRefundRead,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -350,6 +355,7 @@ pub async fn refunds_update(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantRefundWrite,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -389,6 +395,7 @@ pub async fn refunds_metadata_update(
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::MerchantRefundWrite,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -436,6 +443,7 @@ pub async fn refunds_list(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantRefundRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantRefundRead,
@@ -467,6 +475,7 @@ pub async fn refunds_list(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantRefundRead,
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantRefundRead,
@@ -524,6 +533,7 @@ pub async fn refunds_list_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRefundRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRefundRead,
@@ -576,6 +586,7 @@ pub async fn refunds_filter_list(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantRefundRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantRefundRead,
@@ -623,6 +634,7 @@ pub async fn get_refunds_filters(state: web::Data<AppState>, req: HttpRequest) -
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantRefundRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantRefundRead,
@@ -677,6 +689,7 @@ pub async fn get_refunds_filters_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRefundRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRefundRead,
@@ -716,6 +729,7 @@ pub async fn get_refunds_aggregates(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantRefundRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantRefundRead,
@@ -787,6 +801,7 @@ pub async fn get_refunds_aggregate_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRefundRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRefundRead,
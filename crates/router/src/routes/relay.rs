@@ -4,7 +4,7 @@ use router_env::{instrument, tracing, Flow};
 use crate::{
     self as app,
     core::{api_locking, relay},
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
 };
 
 #[instrument(skip_all, fields(flow = ?Flow::Relay))]
@@ -38,6 +38,7 @@ pub async fn relay(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::ProfileRefundWrite,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -79,6 +80,7 @@ pub async fn relay_retrieve(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Permission::ProfileRefundRead,
         }),
         api_locking::LockAction::NotApplicable,
     ))
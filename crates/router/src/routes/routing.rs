@@ -10,6 +10,8 @@ use router_env::{
     Flow,
 };
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::{core::three_ds_decision_rule, routes::lock_utils};
 use crate::{
     core::{api_locking, conditional_config, routing, surcharge_decision_config},
     routes::AppState,
@@ -46,6 +48,7 @@ pub async fn routing_create_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRoutingWrite,
@@ -88,6 +91,7 @@ pub async fn routing_create_config(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingWrite,
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileRoutingWrite,
@@ -103,21 +107,43 @@ pub async fn routing_create_config(
     .await
 }
 
+/// Activations and deactivations of the routing algorithms of a merchant are performed one at a
+/// time, so that every entry of the activation history refers to the algorithm active before it
+#[cfg(all(feature = "olap", feature = "v1"))]
+fn routing_activation_lock_action(
+    flow: &Flow,
+    transaction_type: &enums::TransactionType,
+) -> api_locking::LockAction {
+    api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: format!("routing_activation_{transaction_type}"),
+            api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    }
+}
+
 #[cfg(all(feature = "olap", feature = "v1"))]
 #[instrument(skip_all)]
 pub async fn routing_link_config(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<common_utils::id_type::RoutingId>,
+    json_payload: Option<web::Json<routing_types::RoutingActivateRequest>>,
     transaction_type: &enums::TransactionType,
 ) -> impl Responder {
     let flow = Flow::RoutingLinkConfig;
+    let lock_action = routing_activation_lock_action(&flow, transaction_type);
+    let wrapper = routing_types::RoutingActivationWrapper {
+        algorithm_id: path.into_inner(),
+        reason: json_payload.and_then(|payload| payload.into_inner().reason),
+    };
     Box::pin(oss_api::server_wrap(
         flow,
         state,
         &req,
-        path.into_inner(),
-        |state, auth: auth::AuthenticationData, algorithm, _| {
+        wrapper,
+        |state, auth: auth::AuthenticationData, wrapper, req_state| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
@@ -125,21 +151,26 @@ pub async fn routing_link_config(
                 state,
                 merchant_context,
                 auth.profile_id,
-                algorithm,
+                wrapper,
                 transaction_type,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(auth::AuthenticationType::get_actor_id),
             )
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRoutingWrite,
             },
             req.headers(),
         ),
-        api_locking::LockAction::NotApplicable,
+        lock_action,
     ))
     .await
 }
@@ -181,6 +212,7 @@ pub async fn routing_link_config(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantRoutingWrite,
             },
             &auth::JWTAuthProfileFromRoute {
                 profile_id: wrapper.profile_id,
@@ -227,6 +259,50 @@ pub async fn routing_retrieve_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingRead,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_simulate_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::RoutingId>,
+    json_payload: web::Json<routing_types::RoutingSimulationRequest>,
+) -> impl Responder {
+    let algorithm_id = path.into_inner();
+    let flow = Flow::RoutingSimulateConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::simulation::simulate_routing_algorithm(
+                state,
+                merchant_context,
+                auth.profile_id,
+                algorithm_id.clone(),
+                payload,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+                permission: Permission::ProfileRoutingRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRoutingRead,
@@ -238,6 +314,176 @@ pub async fn routing_retrieve_config(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_export_config_dsl(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::RoutingId>,
+) -> impl Responder {
+    let algorithm_id = path.into_inner();
+    let flow = Flow::RoutingExportConfigDsl;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        algorithm_id,
+        |state, auth: auth::AuthenticationData, algorithm_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::export_routing_algorithm_as_dsl(
+                state,
+                merchant_context,
+                auth.profile_id,
+                algorithm_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+                permission: Permission::ProfileRoutingRead,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_retrieve_activation_history(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<routing_types::RoutingActivationHistoryQuery>,
+    transaction_type: &enums::TransactionType,
+) -> impl Responder {
+    let flow = Flow::RoutingRetrieveActivationHistory;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::history::retrieve_routing_activation_history(
+                state,
+                merchant_context,
+                auth.profile_id,
+                query,
+                transaction_type,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+                permission: Permission::ProfileRoutingRead,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_diff_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<routing_types::RoutingAlgorithmDiffQuery>,
+) -> impl Responder {
+    let flow = Flow::RoutingDiffConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::history::diff_routing_algorithms(
+                state,
+                merchant_context,
+                auth.profile_id,
+                query,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+                permission: Permission::ProfileRoutingRead,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_rollback_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::RoutingRollbackRequest>,
+    transaction_type: &enums::TransactionType,
+) -> impl Responder {
+    let flow = Flow::RoutingRollbackConfig;
+    let lock_action = routing_activation_lock_action(&flow, transaction_type);
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::history::rollback_routing_algorithm(
+                state,
+                merchant_context,
+                auth.profile_id,
+                payload,
+                transaction_type,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(auth::AuthenticationType::get_actor_id),
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+                permission: Permission::ProfileRoutingWrite,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRoutingWrite,
+            },
+            req.headers(),
+        ),
+        lock_action,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "olap", feature = "v2"))]
 #[instrument(skip_all)]
 pub async fn routing_retrieve_config(
@@ -268,6 +514,7 @@ pub async fn routing_retrieve_config(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingRead,
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileRoutingRead,
@@ -313,6 +560,7 @@ pub async fn list_routing_configs(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantRoutingRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantRoutingRead,
@@ -354,6 +602,7 @@ pub async fn list_routing_configs_for_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRoutingRead,
@@ -396,6 +645,7 @@ pub async fn routing_unlink_config(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantRoutingWrite,
             },
             &auth::JWTAuthProfileFromRoute {
                 profile_id: path,
@@ -422,12 +672,13 @@ pub async fn routing_unlink_config(
     transaction_type: &enums::TransactionType,
 ) -> impl Responder {
     let flow = Flow::RoutingUnlinkConfig;
+    let lock_action = routing_activation_lock_action(&flow, transaction_type);
     Box::pin(oss_api::server_wrap(
         flow,
         state,
         &req,
         payload.into_inner(),
-        |state, auth: auth::AuthenticationData, payload_req, _| {
+        |state, auth: auth::AuthenticationData, payload_req, req_state| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
@@ -437,19 +688,24 @@ pub async fn routing_unlink_config(
                 payload_req,
                 auth.profile_id,
                 transaction_type,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(auth::AuthenticationType::get_actor_id),
             )
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRoutingWrite,
             },
             req.headers(),
         ),
-        api_locking::LockAction::NotApplicable,
+        lock_action,
     ))
     .await
 }
@@ -487,6 +743,7 @@ pub async fn routing_update_default_config(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantRoutingWrite,
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantRoutingWrite,
@@ -530,6 +787,7 @@ pub async fn routing_update_default_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantRoutingWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantRoutingWrite,
@@ -569,6 +827,7 @@ pub async fn routing_retrieve_default_config(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantRoutingRead,
             },
             &auth::JWTAuthProfileFromRoute {
                 profile_id: path,
@@ -613,6 +872,7 @@ pub async fn routing_retrieve_default_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRoutingRead,
@@ -652,6 +912,7 @@ pub async fn upsert_surcharge_decision_manager_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantSurchargeDecisionManagerWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantSurchargeDecisionManagerWrite,
@@ -666,6 +927,44 @@ pub async fn upsert_surcharge_decision_manager_config(
     ))
     .await
 }
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn analyze_surcharge_decision_manager_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::surcharge_decision_configs::SurchargeDecisionConfigReq>,
+) -> impl Responder {
+    let flow = Flow::DecisionManagerAnalyzeConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |_, _: auth::AuthenticationData, request, _| {
+            surcharge_decision_config::analyze_surcharge_decision_config(request)
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+                permission: Permission::MerchantSurchargeDecisionManagerRead,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantSurchargeDecisionManagerRead,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::MerchantSurchargeDecisionManagerRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "olap")]
 #[instrument(skip_all)]
 pub async fn delete_surcharge_decision_manager_config(
@@ -689,6 +988,7 @@ pub async fn delete_surcharge_decision_manager_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantSurchargeDecisionManagerWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantSurchargeDecisionManagerWrite,
@@ -727,6 +1027,7 @@ pub async fn retrieve_surcharge_decision_manager_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantSurchargeDecisionManagerRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantSurchargeDecisionManagerRead,
@@ -766,6 +1067,7 @@ pub async fn upsert_decision_manager_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantThreeDsDecisionManagerWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantThreeDsDecisionManagerWrite,
@@ -781,6 +1083,88 @@ pub async fn upsert_decision_manager_config(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn analyze_decision_manager_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::conditional_configs::DecisionManager>,
+) -> impl Responder {
+    let flow = Flow::DecisionManagerAnalyzeConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |_, _: auth::AuthenticationData, request, _| {
+            conditional_config::analyze_conditional_config(request)
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+                permission: Permission::MerchantThreeDsDecisionManagerRead,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantThreeDsDecisionManagerRead,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::MerchantThreeDsDecisionManagerRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn execute_three_ds_decision_rule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest>,
+) -> impl Responder {
+    let flow = Flow::ThreeDsDecisionRuleExecute;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, request, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            three_ds_decision_rule::execute_three_ds_decision_rule(
+                state,
+                merchant_context,
+                auth.profile_id,
+                request,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+                permission: Permission::MerchantThreeDsDecisionManagerRead,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantThreeDsDecisionManagerRead,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::MerchantThreeDsDecisionManagerRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "olap", feature = "v2"))]
 #[instrument(skip_all)]
 pub async fn upsert_decision_manager_config(
@@ -807,6 +1191,7 @@ pub async fn upsert_decision_manager_config(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileThreeDsDecisionManagerWrite,
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileThreeDsDecisionManagerWrite,
@@ -845,6 +1230,7 @@ pub async fn delete_decision_manager_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantThreeDsDecisionManagerWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantThreeDsDecisionManagerWrite,
@@ -881,6 +1267,7 @@ pub async fn retrieve_decision_manager_config(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileThreeDsDecisionManagerWrite,
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileThreeDsDecisionManagerWrite,
@@ -920,6 +1307,7 @@ pub async fn retrieve_decision_manager_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantThreeDsDecisionManagerRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantThreeDsDecisionManagerRead,
@@ -968,6 +1356,7 @@ pub async fn routing_retrieve_linked_config(
                 &auth::HeaderAuth(auth::ApiKeyAuth {
                     is_connected_allowed: false,
                     is_platform_allowed: false,
+                    permission: Permission::ProfileRoutingRead,
                 }),
                 &auth::JWTAuthProfileFromRoute {
                     profile_id,
@@ -1000,6 +1389,7 @@ pub async fn routing_retrieve_linked_config(
                 &auth::HeaderAuth(auth::ApiKeyAuth {
                     is_connected_allowed: false,
                     is_platform_allowed: false,
+                    permission: Permission::ProfileRoutingRead,
                 }),
                 &auth::JWTAuth {
                     permission: Permission::ProfileRoutingRead,
@@ -1049,6 +1439,7 @@ pub async fn routing_retrieve_linked_config(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingRead,
             },
             &auth::JWTAuthProfileFromRoute {
                 profile_id: wrapper.profile_id,
@@ -1093,6 +1484,7 @@ pub async fn routing_retrieve_default_config_for_profiles(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantRoutingRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantRoutingRead,
@@ -1104,6 +1496,7 @@ pub async fn routing_retrieve_default_config_for_profiles(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantRoutingRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantRoutingRead,
@@ -1149,6 +1542,7 @@ pub async fn routing_update_default_config_for_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingWrite,
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: routing_payload_wrapper.profile_id,
@@ -1198,6 +1592,7 @@ pub async fn toggle_success_based_routing(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingWrite,
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: wrapper.profile_id,
@@ -1242,6 +1637,7 @@ pub async fn success_based_routing_update_configs(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingWrite,
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: routing_payload_wrapper.profile_id,
@@ -1286,6 +1682,7 @@ pub async fn elimination_routing_update_configs(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingWrite,
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: routing_payload_wrapper.profile_id,
@@ -1338,6 +1735,7 @@ pub async fn contract_based_routing_setup_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingWrite,
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: routing_payload_wrapper.profile_id,
@@ -1389,6 +1787,7 @@ pub async fn contract_based_routing_update_configs(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingWrite,
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: routing_payload_wrapper.profile_id,
@@ -1438,6 +1837,7 @@ pub async fn toggle_elimination_routing(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingWrite,
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: wrapper.profile_id,
@@ -1491,6 +1891,7 @@ pub async fn set_dynamic_routing_volume_split(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileRoutingWrite,
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: payload.profile_id,
@@ -30,7 +30,9 @@ use crate::{
     },
     headers::X_CUSTOMER_ID,
     routes::{app::StorageInterface, AppState, SessionState},
-    services::{self, api as api_service, authentication as auth},
+    services::{
+        self, api as api_service, authentication as auth, authorization::permissions::Permission,
+    },
     types::{api, domain, payment_methods as pm_types},
 };
 
@@ -62,6 +64,7 @@ pub async fn create_token_vault_api(
             &auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantCustomerWrite,
             },
             &auth::V2ClientAuth(common_utils::types::authentication::ResourceId::Customer(
                 customer_id,
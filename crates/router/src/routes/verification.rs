@@ -35,6 +35,7 @@ pub async fn apple_pay_merchant_registration(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::ProfileAccountWrite,
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileAccountWrite,
@@ -72,6 +73,7 @@ pub async fn retrieve_apple_pay_verified_domains(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Permission::MerchantAccountRead,
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantAccountRead,
@@ -59,9 +59,10 @@ use crate::{
     configs::Settings,
     consts,
     core::{
-        api_locking,
+        api_locking, connector_circuit_breaker,
         errors::{self, CustomResult},
-        payments,
+        idempotency::{self, GetIdempotencyAction},
+        payments, rate_limit,
     },
     events::{
         api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
@@ -70,6 +71,7 @@ use crate::{
     headers, logger,
     routes::{
         app::{AppStateInfo, ReqState, SessionStateInfo},
+        lock_utils::GetApiIdentifier,
         metrics, AppState, SessionState,
     },
     services::{
@@ -245,6 +247,12 @@ where
                         call_connector_api(state, request, "execute_connector_processing_step")
                             .await;
                     let external_latency = current_time.elapsed().as_millis();
+                    connector_circuit_breaker::record_connector_call_outcome(
+                        state,
+                        &req.connector,
+                        req.merchant_connector_id.as_ref(),
+                        &response,
+                    );
                     logger::info!(raw_connector_request=?masked_request_body);
                     let status_code = response
                         .as_ref()
@@ -538,7 +546,7 @@ pub enum AuthFlow {
     fields(merchant_id)
 )]
 pub async fn server_wrap_util<'a, 'b, U, T, Q, F, Fut, E, OErr>(
-    flow: &'a impl router_env::types::FlowMetric,
+    flow: &'a (impl router_env::types::FlowMetric + GetApiIdentifier),
     state: web::Data<AppState>,
     incoming_request_header: &HeaderMap,
     request: &'a HttpRequest,
@@ -546,6 +554,7 @@ pub async fn server_wrap_util<'a, 'b, U, T, Q, F, Fut, E, OErr>(
     func: F,
     api_auth: &dyn AuthenticateAndFetch<U, SessionState>,
     lock_action: api_locking::LockAction,
+    idempotency_action: idempotency::IdempotencyAction,
 ) -> CustomResult<ApplicationResponse<Q>, OErr>
 where
     F: Fn(SessionState, U, T, ReqState) -> Fut,
@@ -631,6 +640,7 @@ where
         .switch()?;
 
     request_state.event_context.record_info(auth_type.clone());
+    request_state.auth_type = Some(auth_type.clone());
 
     let merchant_id = auth_type
         .get_merchant_id()
@@ -641,20 +651,51 @@ where
 
     tracing::Span::current().record("merchant_id", merchant_id.get_string_repr().to_owned());
 
-    let output = {
-        lock_action
-            .clone()
-            .perform_locking_action(&session_state, merchant_id.to_owned())
-            .await
-            .switch()?;
-        let res = func(session_state.clone(), auth_out, payload, request_state)
-            .await
-            .switch();
-        lock_action
-            .free_lock_action(&session_state, merchant_id.to_owned())
-            .await
-            .switch()?;
-        res
+    if let Some(rate_limit_status) =
+        rate_limit::check_rate_limit(&session_state, &auth_type, flow.get_api_identifier()).await
+    {
+        request.extensions_mut().insert(rate_limit_status.clone());
+        rate_limit_status.validate().switch()?;
+    }
+
+    let idempotent_request = match idempotency_action {
+        idempotency::IdempotencyAction::Apply => idempotency::IdempotentRequest::from_request(
+            request,
+            auth_type.get_merchant_id(),
+            flow,
+            &payload,
+        )
+        .switch()?,
+        idempotency::IdempotencyAction::NotApplicable => None,
+    };
+    let replayed_response = match &idempotent_request {
+        Some(idempotent_request) => idempotent_request.begin(&session_state).await.switch()?,
+        None => None,
+    };
+
+    let output = match replayed_response {
+        Some(response) => Ok(response),
+        None => {
+            let res = async {
+                lock_action
+                    .clone()
+                    .perform_locking_action(&session_state, merchant_id.to_owned())
+                    .await?;
+                let res = func(session_state.clone(), auth_out, payload, request_state)
+                    .await
+                    .switch();
+                lock_action
+                    .free_lock_action(&session_state, merchant_id.to_owned())
+                    .await?;
+                Ok::<_, Report<errors::ApiErrorResponse>>(res)
+            }
+            .await;
+            if let Some(idempotent_request) = idempotent_request {
+                let response = res.as_ref().ok().and_then(|res| res.as_ref().ok());
+                idempotent_request.complete(&session_state, response).await;
+            }
+            res.switch()?
+        }
     };
     let request_duration = Instant::now()
         .saturating_duration_since(start_instant)
@@ -727,7 +768,7 @@ where
     fields(request_method, request_url_path, status_code)
 )]
 pub async fn server_wrap<'a, T, U, Q, F, Fut, E>(
-    flow: impl router_env::types::FlowMetric,
+    flow: impl router_env::types::FlowMetric + GetApiIdentifier + GetIdempotencyAction,
     state: web::Data<AppState>,
     request: &'a HttpRequest,
     payload: T,
@@ -781,6 +822,7 @@ where
         func,
         api_auth,
         lock_action,
+        flow.get_idempotency_action(),
     )
     .await
     .map(|response| {
@@ -800,6 +842,9 @@ where
             ),
         },
         Ok(ApplicationResponse::StatusOk) => http_response_ok(),
+        Ok(ApplicationResponse::IdempotentReplay((response, headers))) => {
+            http_response_json_with_headers(response, headers, None)
+        }
         Ok(ApplicationResponse::TextPlain(text)) => http_response_plaintext(text),
         Ok(ApplicationResponse::FileData((file_data, content_type))) => {
             http_response_file_data(file_data, content_type)
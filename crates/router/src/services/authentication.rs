@@ -191,6 +191,25 @@ impl AuthenticationType {
             | Self::NoAuth => None,
         }
     }
+
+    /// Identifier of the user or API key which made the request
+    pub fn get_actor_id(&self) -> Option<String> {
+        match self {
+            Self::ApiKey { key_id, .. } => Some(key_id.get_string_repr().to_owned()),
+            Self::OrganizationJwt { user_id, .. }
+            | Self::MerchantJwtWithProfileId { user_id, .. }
+            | Self::UserJwt { user_id }
+            | Self::SinglePurposeJwt { user_id, .. }
+            | Self::SinglePurposeOrLoginJwt { user_id, .. } => Some(user_id.clone()),
+            Self::MerchantJwt { user_id, .. } => user_id.clone(),
+            Self::AdminApiKey
+            | Self::AdminApiAuthWithMerchantId { .. }
+            | Self::MerchantId { .. }
+            | Self::PublishableKey { .. }
+            | Self::WebhookAuth { .. }
+            | Self::NoAuth => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, serde::Deserialize, strum::Display)]
@@ -349,10 +368,12 @@ where
     ) -> RouterResult<(T, AuthenticationType)>;
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct ApiKeyAuth {
     pub is_connected_allowed: bool,
     pub is_platform_allowed: bool,
+    /// Permission required to access the resource, enforced for API keys restricted to scopes
+    pub permission: Permission,
 }
 
 pub struct NoAuth;
@@ -455,6 +476,14 @@ where
                 .attach_printable("API key has expired");
         }
 
+        check_api_key_restrictions(
+            &stored_api_key,
+            request_headers,
+            state.conf().api_keys.get_inner().trusted_proxy_count,
+            self.permission,
+            Some(&profile_id),
+        )?;
+
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -595,6 +624,17 @@ where
                 })
                 .change_context(errors::ApiErrorResponse::Unauthorized)?;
 
+        check_api_key_restrictions(
+            &stored_api_key,
+            request_headers,
+            state.conf().api_keys.get_inner().trusted_proxy_count,
+            self.permission,
+            profile_id.as_ref(),
+        )?;
+
+        // API keys restricted to a profile can only access the resources of that profile
+        let profile_id = profile_id.or_else(|| stored_api_key.profile_id.clone());
+
         let merchant = state
             .store()
             .find_merchant_account_by_merchant_id(
@@ -650,7 +690,7 @@ where
 }
 
 #[derive(Debug)]
-pub struct ApiKeyAuthWithMerchantIdFromRoute(pub id_type::MerchantId);
+pub struct ApiKeyAuthWithMerchantIdFromRoute(pub id_type::MerchantId, pub Permission);
 
 #[cfg(feature = "partial-auth")]
 impl GetAuthType for ApiKeyAuthWithMerchantIdFromRoute {
@@ -673,6 +713,7 @@ where
         let api_auth = ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: self.1,
         };
         let (auth_data, auth_type) = api_auth
             .authenticate_and_fetch(request_headers, state)
@@ -1377,6 +1418,17 @@ where
                 .attach_printable("API key has expired");
         }
 
+        // API keys restricted to scopes or a profile cannot be used in place of the admin API key
+        if stored_api_key.scopes.is_some() || stored_api_key.profile_id.is_some() {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("API key has restricted access");
+        }
+        check_api_key_allowed_ips(
+            &stored_api_key,
+            request_headers,
+            conf.api_keys.get_inner().trusted_proxy_count,
+        )?;
+
         if fallback_merchant_ids
             .merchant_ids
             .contains(&stored_api_key.merchant_id)
@@ -1487,6 +1539,17 @@ where
                 .attach_printable("API key has expired");
         }
 
+        // API keys restricted to scopes or a profile cannot be used in place of the admin API key
+        if stored_api_key.scopes.is_some() || stored_api_key.profile_id.is_some() {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("API key has restricted access");
+        }
+        check_api_key_allowed_ips(
+            &stored_api_key,
+            request_headers,
+            conf.api_keys.get_inner().trusted_proxy_count,
+        )?;
+
         if fallback_merchant_ids
             .merchant_ids
             .contains(&stored_api_key.merchant_id)
@@ -2052,6 +2115,8 @@ where
 pub struct V2ApiKeyAuth {
     pub is_connected_allowed: bool,
     pub is_platform_allowed: bool,
+    /// Permission required to access the resource, enforced for API keys restricted to scopes
+    pub permission: Permission,
 }
 
 #[cfg(feature = "v2")]
@@ -2108,6 +2173,14 @@ where
                 .attach_printable("API key has expired");
         }
 
+        check_api_key_restrictions(
+            &stored_api_key,
+            request_headers,
+            state.conf().api_keys.get_inner().trusted_proxy_count,
+            self.permission,
+            Some(&profile_id),
+        )?;
+
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -3862,6 +3935,96 @@ pub fn get_api_key(headers: &HeaderMap) -> RouterResult<&str> {
     get_header_value_by_key("api-key".into(), headers)?.get_required_value("api_key")
 }
 
+/// Checks the request against the restrictions configured on the API key, which limit the
+/// resources, the IP addresses and the profile that the API key can be used with.
+fn check_api_key_restrictions(
+    stored_api_key: &storage::ApiKey,
+    headers: &HeaderMap,
+    trusted_proxy_count: usize,
+    required_permission: Permission,
+    profile_id: Option<&id_type::ProfileId>,
+) -> RouterResult<()> {
+    if let Some(scopes) = &stored_api_key.scopes {
+        authorization::check_api_key_scopes(required_permission, scopes)
+            .attach_printable("API key is not permitted to access the resource")?;
+    }
+
+    check_api_key_allowed_ips(stored_api_key, headers, trusted_proxy_count)?;
+
+    match &stored_api_key.profile_id {
+        Some(allowed_profile_id) => {
+            check_api_key_allowed_profile(allowed_profile_id, required_permission, profile_id)
+        }
+        None => Ok(()),
+    }
+}
+
+/// API keys restricted to a profile can only access profile level resources, and only those of
+/// the profile that the API key is restricted to.
+fn check_api_key_allowed_profile(
+    allowed_profile_id: &id_type::ProfileId,
+    required_permission: Permission,
+    profile_id: Option<&id_type::ProfileId>,
+) -> RouterResult<()> {
+    let entity_type = required_permission.entity_type();
+    if entity_type != common_enums::EntityType::Profile {
+        return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: entity_type.to_string(),
+        }))
+        .attach_printable("API key restricted to a profile cannot access non-profile resources");
+    }
+
+    match profile_id {
+        Some(profile_id) if profile_id != allowed_profile_id => {
+            Err(report!(errors::ApiErrorResponse::AccessForbidden {
+                resource: profile_id.get_string_repr().to_owned(),
+            }))
+            .attach_printable("API key is restricted to a different profile")
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Obtains the client IP address from the `X-Forwarded-For` header. Since the leftmost entries
+/// of the header are supplied by the client, the entry appended by the outermost of the trusted
+/// proxies is used, which is the `trusted_proxy_count`-th entry from the right.
+fn get_client_ip_from_forwarded_for(
+    forwarded_for: &str,
+    trusted_proxy_count: usize,
+) -> Option<std::net::IpAddr> {
+    let entries = forwarded_for.split(',').map(str::trim).collect::<Vec<_>>();
+    let index = entries.len().checked_sub(trusted_proxy_count)?;
+
+    entries.get(index)?.parse().ok()
+}
+
+fn check_api_key_allowed_ips(
+    stored_api_key: &storage::ApiKey,
+    headers: &HeaderMap,
+    trusted_proxy_count: usize,
+) -> RouterResult<()> {
+    let Some(allowed_ips) = &stored_api_key.allowed_ips else {
+        return Ok(());
+    };
+
+    let client_ip = get_header_value_by_key(headers::X_FORWARDED_FOR.to_string(), headers)?
+        .and_then(|forwarded_for| {
+            get_client_ip_from_forwarded_for(forwarded_for, trusted_proxy_count)
+        })
+        .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+        .attach_printable("Failed to obtain the client IP address of the request")?;
+
+    let is_allowed_ip = allowed_ips
+        .iter()
+        .filter_map(|allowed_ip| api_keys::parse_allowed_ip(allowed_ip))
+        .any(|allowed_network| allowed_network.contains(&client_ip));
+
+    fp_utils::when(!is_allowed_ip, || {
+        Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("API key is not permitted to be used from the client IP address")
+    })
+}
+
 pub fn get_header_value_by_key(key: String, headers: &HeaderMap) -> RouterResult<Option<&str>> {
     headers
         .get(&key)
@@ -4220,3 +4383,115 @@ impl ExternalToken {
         )?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+
+    fn profile_id(profile_id: &'static str) -> id_type::ProfileId {
+        id_type::ProfileId::try_from(std::borrow::Cow::from(profile_id))
+            .expect("Failed to create profile id")
+    }
+
+    #[test]
+    fn test_client_ip_from_forwarded_for_ignores_client_supplied_entries() {
+        let forwarded_for = "10.0.0.1, 203.0.113.7, 198.51.100.2";
+
+        assert_eq!(
+            get_client_ip_from_forwarded_for(forwarded_for, 1),
+            "198.51.100.2".parse().ok()
+        );
+        assert_eq!(
+            get_client_ip_from_forwarded_for(forwarded_for, 2),
+            "203.0.113.7".parse().ok()
+        );
+    }
+
+    #[test]
+    fn test_client_ip_from_forwarded_for_with_too_few_entries() {
+        assert_eq!(get_client_ip_from_forwarded_for("198.51.100.2", 2), None);
+        assert_eq!(get_client_ip_from_forwarded_for("198.51.100.2", 0), None);
+        assert_eq!(get_client_ip_from_forwarded_for("unknown", 1), None);
+    }
+
+    #[test]
+    fn test_profile_restricted_api_key_on_profile_resources() {
+        let allowed_profile_id = profile_id("pro_allowed");
+
+        assert!(check_api_key_allowed_profile(
+            &allowed_profile_id,
+            Permission::ProfilePaymentRead,
+            None
+        )
+        .is_ok());
+        assert!(check_api_key_allowed_profile(
+            &allowed_profile_id,
+            Permission::ProfilePaymentRead,
+            Some(&allowed_profile_id)
+        )
+        .is_ok());
+        assert!(check_api_key_allowed_profile(
+            &allowed_profile_id,
+            Permission::ProfilePaymentRead,
+            Some(&profile_id("pro_other"))
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_unrestricted_api_key_keeps_full_access() {
+        let now = date_time::now();
+        let api_key = storage::ApiKey {
+            key_id: id_type::ApiKeyId::try_from(std::borrow::Cow::from("key_1"))
+                .expect("Failed to create api key id"),
+            merchant_id: id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .expect("Failed to create merchant id"),
+            name: "API Key".to_string(),
+            description: None,
+            hashed_api_key: "hashed_api_key".to_string().into(),
+            prefix: "snd_abc".to_string(),
+            created_at: now,
+            expires_at: None,
+            last_used: None,
+            scopes: None,
+            allowed_ips: None,
+            profile_id: None,
+        };
+
+        for permission in [
+            Permission::ProfilePaymentWrite,
+            Permission::MerchantConnectorWrite,
+            Permission::MerchantApiKeyWrite,
+            Permission::MerchantAccountWrite,
+        ] {
+            assert!(check_api_key_restrictions(
+                &api_key,
+                &HeaderMap::new(),
+                1,
+                permission,
+                Some(&profile_id("pro_1"))
+            )
+            .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_profile_restricted_api_key_on_merchant_resources() {
+        let allowed_profile_id = profile_id("pro_allowed");
+
+        assert!(check_api_key_allowed_profile(
+            &allowed_profile_id,
+            Permission::MerchantPaymentRead,
+            Some(&allowed_profile_id)
+        )
+        .is_err());
+        assert!(check_api_key_allowed_profile(
+            &allowed_profile_id,
+            Permission::MerchantCustomerRead,
+            None
+        )
+        .is_err());
+    }
+}
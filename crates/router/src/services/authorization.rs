@@ -1,10 +1,12 @@
 use std::sync::Arc;
 
+use common_types::api_keys::ApiKeyScopes;
 use common_utils::id_type;
 use error_stack::ResultExt;
 use redis_interface::RedisConnectionPool;
 use router_env::logger;
 
+use self::permission_groups::PermissionGroupExt;
 use super::authentication::AuthToken;
 use crate::{
     consts,
@@ -127,6 +129,34 @@ pub fn check_permission(
         )
 }
 
+pub fn check_api_key_scopes(
+    required_permission: permissions::Permission,
+    scopes: &ApiKeyScopes,
+) -> RouterResult<()> {
+    let required_resource = required_permission.resource();
+    scopes
+        .0
+        .iter()
+        .any(|scope| {
+            required_permission.scope() <= scope.permission_group.scope()
+                && scope
+                    .permission_group
+                    .resources()
+                    .contains(&required_resource)
+                && scope
+                    .resources
+                    .as_ref()
+                    .map_or(true, |resources| resources.contains(&required_resource))
+        })
+        .then_some(())
+        .ok_or(
+            ApiErrorResponse::AccessForbidden {
+                resource: required_permission.to_string(),
+            }
+            .into(),
+        )
+}
+
 pub fn check_tenant(
     token_tenant_id: Option<id_type::TenantId>,
     header_tenant_id: &id_type::TenantId,
@@ -152,3 +182,80 @@ fn get_redis_connection_for_global_tenant<A: SessionStateInfo>(
         .change_context(ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to get redis connection")
 }
+
+#[cfg(test)]
+mod tests {
+    use common_enums::{PermissionGroup, Resource};
+    use common_types::api_keys::ApiKeyScope;
+
+    use super::*;
+
+    fn scopes(scopes: &[(PermissionGroup, Option<Vec<Resource>>)]) -> ApiKeyScopes {
+        ApiKeyScopes(
+            scopes
+                .iter()
+                .map(|(permission_group, resources)| ApiKeyScope {
+                    permission_group: *permission_group,
+                    resources: resources.clone(),
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_read_scope_rejects_write_permissions() {
+        let scopes = scopes(&[(PermissionGroup::OperationsView, None)]);
+
+        assert!(check_api_key_scopes(permissions::Permission::ProfilePaymentRead, &scopes).is_ok());
+        assert!(check_api_key_scopes(permissions::Permission::MerchantRefundRead, &scopes).is_ok());
+        assert!(
+            check_api_key_scopes(permissions::Permission::ProfilePaymentWrite, &scopes).is_err()
+        );
+    }
+
+    #[test]
+    fn test_write_scope_allows_read_permissions() {
+        let scopes = scopes(&[(PermissionGroup::OperationsManage, None)]);
+
+        assert!(check_api_key_scopes(permissions::Permission::ProfilePaymentRead, &scopes).is_ok());
+        assert!(
+            check_api_key_scopes(permissions::Permission::ProfilePaymentWrite, &scopes).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_scope_restricted_to_resource_subset() {
+        let scopes = scopes(&[(
+            PermissionGroup::OperationsManage,
+            Some(vec![Resource::Payment]),
+        )]);
+
+        assert!(
+            check_api_key_scopes(permissions::Permission::ProfilePaymentWrite, &scopes).is_ok()
+        );
+        assert!(
+            check_api_key_scopes(permissions::Permission::ProfileRefundWrite, &scopes).is_err()
+        );
+        assert!(
+            check_api_key_scopes(permissions::Permission::MerchantCustomerRead, &scopes).is_err()
+        );
+    }
+
+    #[test]
+    fn test_resources_outside_of_scope_groups() {
+        let scopes = scopes(&[
+            (PermissionGroup::OperationsView, None),
+            (PermissionGroup::ConnectorsManage, None),
+        ]);
+
+        assert!(
+            check_api_key_scopes(permissions::Permission::ProfileConnectorWrite, &scopes).is_ok()
+        );
+        assert!(
+            check_api_key_scopes(permissions::Permission::ProfileRoutingRead, &scopes).is_err()
+        );
+        assert!(
+            check_api_key_scopes(permissions::Permission::MerchantApiKeyWrite, &scopes).is_err()
+        );
+    }
+}
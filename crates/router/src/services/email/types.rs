@@ -81,6 +81,20 @@ pub enum EmailBody {
         prefix: String,
     },
     WelcomeToCommunity,
+    ReportDownload {
+        report_name: String,
+        link: String,
+        expires_in: u32,
+    },
+    DisputeDeadlineReminder {
+        dispute_id: String,
+        payment_id: String,
+        connector: String,
+        amount: String,
+        currency: String,
+        challenge_required_by: String,
+        hours_remaining: i64,
+    },
 }
 
 pub mod html {
@@ -242,6 +256,34 @@ Email         : {user_email}
             EmailBody::WelcomeToCommunity => {
                 include_str!("assets/welcome_to_community.html").to_string()
             }
+            EmailBody::ReportDownload {
+                report_name,
+                link,
+                expires_in,
+            } => format!(
+                include_str!("assets/report_download.html"),
+                report_name = report_name,
+                link = link,
+                expires_in = expires_in,
+            ),
+            EmailBody::DisputeDeadlineReminder {
+                dispute_id,
+                payment_id,
+                connector,
+                amount,
+                currency,
+                challenge_required_by,
+                hours_remaining,
+            } => format!(
+                include_str!("assets/dispute_deadline_reminder.html"),
+                dispute_id = dispute_id,
+                payment_id = payment_id,
+                connector = connector,
+                amount = amount,
+                currency = currency,
+                challenge_required_by = challenge_required_by,
+                hours_remaining = hours_remaining,
+            ),
         }
     }
 }
@@ -678,3 +720,63 @@ impl EmailData for WelcomeToCommunity {
         })
     }
 }
+
+pub struct ReportDownload {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub report_name: String,
+    pub link: String,
+    /// Time (in hours) after which the download link expires
+    pub expires_in: u32,
+}
+
+#[async_trait::async_trait]
+impl EmailData for ReportDownload {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let body = html::get_html_body(EmailBody::ReportDownload {
+            report_name: self.report_name.clone(),
+            link: self.link.clone(),
+            expires_in: self.expires_in,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone().into_inner(),
+        })
+    }
+}
+
+pub struct DisputeDeadlineReminder {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub dispute_id: String,
+    pub payment_id: String,
+    pub connector: String,
+    pub amount: String,
+    pub currency: String,
+    pub challenge_required_by: String,
+    /// Time (in hours) left until the evidence submission deadline
+    pub hours_remaining: i64,
+}
+
+#[async_trait::async_trait]
+impl EmailData for DisputeDeadlineReminder {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let body = html::get_html_body(EmailBody::DisputeDeadlineReminder {
+            dispute_id: self.dispute_id.clone(),
+            payment_id: self.payment_id.clone(),
+            connector: self.connector.clone(),
+            amount: self.amount.clone(),
+            currency: self.currency.clone(),
+            challenge_required_by: self.challenge_required_by.clone(),
+            hours_remaining: self.hours_remaining,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone().into_inner(),
+        })
+    }
+}
@@ -977,6 +977,7 @@ impl<F1, F2, T1, T2> ForeignFrom<(&RouterData<F1, T1, PaymentsResponseData>, T2)
             authentication_id: data.authentication_id.clone(),
             psd2_sca_exemption_type: data.psd2_sca_exemption_type,
             whole_connector_response: data.whole_connector_response.clone(),
+            merchant_connector_id: data.merchant_connector_id.clone(),
         }
     }
 }
@@ -1045,6 +1046,7 @@ impl<F1, F2>
             additional_merchant_data: data.additional_merchant_data.clone(),
             connector_mandate_request_reference_id: None,
             whole_connector_response: None,
+            merchant_connector_id: data.merchant_connector_id.clone(),
         }
     }
 }
@@ -15,7 +15,7 @@ pub use hyperswitch_domain_models::router_flow_types::dispute::{Accept, Defend,
 
 pub use super::disputes_v2::{AcceptDisputeV2, DefendDisputeV2, DisputeV2, SubmitEvidenceV2};
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Clone, Default, Debug, Deserialize, Serialize)]
 pub struct DisputeEvidence {
     pub cancellation_policy: Option<String>,
     pub customer_communication: Option<String>,
@@ -27,6 +27,9 @@ pub struct DisputeEvidence {
     pub invoice_showing_distinct_transactions: Option<String>,
     pub recurring_transaction_agreement: Option<String>,
     pub uncategorized_file: Option<String>,
+    /// Evidence summary attached as the uncategorized file by the evidence package, which can be
+    /// replaced by the next evidence package
+    pub evidence_summary_file: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
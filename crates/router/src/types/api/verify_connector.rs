@@ -127,6 +127,7 @@ impl VerifyConnectorData {
             authentication_id: None,
             psd2_sca_exemption_type: None,
             whole_connector_response: None,
+            merchant_connector_id: None,
         }
     }
 }
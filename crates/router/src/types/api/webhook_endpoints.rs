@@ -0,0 +1,5 @@
+pub use api_models::webhook_endpoints::{
+    WebhookEndpointCreateRequest, WebhookEndpointDeleteResponse, WebhookEndpointResponse,
+    WebhookEndpointUpdateRequest, WebhookSecretRotationResponse, WebhookSecretRotationStartRequest,
+    WebhookSecretRotationStatus,
+};
@@ -7,6 +7,8 @@ mod merchant_account {
 }
 
 mod business_profile {
+    #[cfg(feature = "v1")]
+    pub use hyperswitch_domain_models::business_profile::WebhookSecretRotation;
     pub use hyperswitch_domain_models::business_profile::{
         Profile, ProfileGeneralUpdate, ProfileSetter, ProfileUpdate,
     };
@@ -61,6 +63,7 @@ pub mod types;
 #[cfg(feature = "olap")]
 pub mod user;
 pub mod user_key_store;
+mod webhook_endpoint;
 
 pub use address::*;
 pub use business_profile::*;
@@ -78,3 +81,4 @@ pub use user::*;
 pub use user_key_store::*;
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 pub use vault::*;
+pub use webhook_endpoint::*;
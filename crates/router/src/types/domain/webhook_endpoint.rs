@@ -0,0 +1,218 @@
+use common_utils::{
+    crypto::OptionalEncryptableSecretString, id_type, type_name, types::keymanager::KeyManagerState,
+};
+use diesel_models::{enums::EventType, webhook_endpoint::WebhookEndpointUpdateInternal};
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+
+use crate::{
+    errors::{CustomResult, ValidationError},
+    types::domain::types,
+};
+
+#[derive(Clone, Debug)]
+pub struct WebhookEndpoint {
+    /// A string that uniquely identifies the webhook endpoint.
+    pub endpoint_id: String,
+
+    /// Merchant Account identifier to which the webhook endpoint belongs.
+    pub merchant_id: id_type::MerchantId,
+
+    /// Business Profile identifier to which the webhook endpoint belongs.
+    pub profile_id: id_type::ProfileId,
+
+    /// The URL to which webhooks are delivered.
+    pub url: String,
+
+    /// The secret used to sign webhooks delivered to the endpoint, stored encrypted.
+    pub secret: OptionalEncryptableSecretString,
+
+    /// A description of the webhook endpoint.
+    pub description: Option<String>,
+
+    /// Event types the endpoint is subscribed to, an empty list subscribes to all events.
+    pub enabled_events: Vec<EventType>,
+
+    /// Indicates whether webhooks are delivered to the endpoint.
+    pub is_enabled: bool,
+
+    /// The timestamp when the webhook endpoint was created.
+    pub created_at: time::PrimitiveDateTime,
+
+    /// The timestamp when the webhook endpoint was last modified.
+    pub last_modified_at: time::PrimitiveDateTime,
+}
+
+impl WebhookEndpoint {
+    pub fn is_subscribed_to(&self, event_type: EventType) -> bool {
+        self.is_enabled
+            && (self.enabled_events.is_empty() || self.enabled_events.contains(&event_type))
+    }
+}
+
+#[derive(Debug)]
+pub enum WebhookEndpointUpdate {
+    /// `secret` and `description` are cleared when set to `Some(None)`.
+    Update {
+        url: Option<String>,
+        secret: Option<OptionalEncryptableSecretString>,
+        description: Option<Option<String>>,
+        enabled_events: Option<Vec<EventType>>,
+        is_enabled: Option<bool>,
+    },
+}
+
+impl From<WebhookEndpointUpdate> for WebhookEndpointUpdateInternal {
+    fn from(webhook_endpoint_update: WebhookEndpointUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match webhook_endpoint_update {
+            WebhookEndpointUpdate::Update {
+                url,
+                secret,
+                description,
+                enabled_events,
+                is_enabled,
+            } => Self {
+                url,
+                secret: secret.map(|secret| secret.map(Into::into)),
+                description,
+                enabled_events,
+                is_enabled,
+                last_modified_at,
+            },
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl super::behaviour::Conversion for WebhookEndpoint {
+    type DstType = diesel_models::webhook_endpoint::WebhookEndpoint;
+    type NewDstType = diesel_models::webhook_endpoint::WebhookEndpointNew;
+
+    async fn convert(self) -> CustomResult<Self::DstType, ValidationError> {
+        Ok(diesel_models::webhook_endpoint::WebhookEndpoint {
+            endpoint_id: self.endpoint_id,
+            merchant_id: self.merchant_id,
+            profile_id: self.profile_id,
+            url: self.url,
+            secret: self.secret.map(Into::into),
+            description: self.description,
+            enabled_events: self.enabled_events,
+            is_enabled: self.is_enabled,
+            created_at: self.created_at,
+            last_modified_at: self.last_modified_at,
+        })
+    }
+
+    async fn convert_back(
+        state: &KeyManagerState,
+        item: Self::DstType,
+        key: &Secret<Vec<u8>>,
+        key_manager_identifier: common_utils::types::keymanager::Identifier,
+    ) -> CustomResult<Self, ValidationError>
+    where
+        Self: Sized,
+    {
+        let secret = types::crypto_operation::<String, masking::WithType>(
+            state,
+            type_name!(Self::DstType),
+            types::CryptoOperation::DecryptOptional(item.secret),
+            key_manager_identifier,
+            key.peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_optionaloperation())
+        .change_context(ValidationError::InvalidValue {
+            message: "Failed while decrypting webhook endpoint secret".to_string(),
+        })?;
+
+        Ok(Self {
+            endpoint_id: item.endpoint_id,
+            merchant_id: item.merchant_id,
+            profile_id: item.profile_id,
+            url: item.url,
+            secret,
+            description: item.description,
+            enabled_events: item.enabled_events,
+            is_enabled: item.is_enabled,
+            created_at: item.created_at,
+            last_modified_at: item.last_modified_at,
+        })
+    }
+
+    async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError> {
+        Ok(diesel_models::webhook_endpoint::WebhookEndpointNew {
+            endpoint_id: self.endpoint_id,
+            merchant_id: self.merchant_id,
+            profile_id: self.profile_id,
+            url: self.url,
+            secret: self.secret.map(Into::into),
+            description: self.description,
+            enabled_events: self.enabled_events,
+            is_enabled: self.is_enabled,
+            created_at: self.created_at,
+            last_modified_at: self.last_modified_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    fn webhook_endpoint(is_enabled: bool, enabled_events: Vec<EventType>) -> WebhookEndpoint {
+        let now = common_utils::date_time::now();
+        WebhookEndpoint {
+            endpoint_id: "whe_test".to_string(),
+            merchant_id: id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .expect("valid merchant id"),
+            profile_id: id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1"))
+                .expect("valid profile id"),
+            url: "https://www.example.com/webhooks".to_string(),
+            secret: None,
+            description: None,
+            enabled_events,
+            is_enabled,
+            created_at: now,
+            last_modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_is_subscribed_to() {
+        let all_events = webhook_endpoint(true, vec![]);
+        assert!(all_events.is_subscribed_to(EventType::PaymentSucceeded));
+        assert!(all_events.is_subscribed_to(EventType::RefundFailed));
+
+        let refund_events = webhook_endpoint(true, vec![EventType::RefundSucceeded]);
+        assert!(refund_events.is_subscribed_to(EventType::RefundSucceeded));
+        assert!(!refund_events.is_subscribed_to(EventType::PaymentSucceeded));
+
+        let disabled = webhook_endpoint(false, vec![]);
+        assert!(!disabled.is_subscribed_to(EventType::PaymentSucceeded));
+    }
+
+    #[test]
+    fn test_update_clears_optional_fields() {
+        let update = WebhookEndpointUpdateInternal::from(WebhookEndpointUpdate::Update {
+            url: None,
+            secret: Some(None),
+            description: Some(None),
+            enabled_events: None,
+            is_enabled: None,
+        });
+        assert!(matches!(update.secret, Some(None)));
+        assert!(matches!(update.description, Some(None)));
+
+        let update = WebhookEndpointUpdateInternal::from(WebhookEndpointUpdate::Update {
+            url: None,
+            secret: None,
+            description: None,
+            enabled_events: None,
+            is_enabled: Some(false),
+        });
+        assert!(update.secret.is_none());
+        assert!(update.description.is_none());
+    }
+}
@@ -69,6 +69,17 @@ impl DisputeDbExt for Dispute {
             }
         }
 
+        if let Some(challenge_required_by_range) =
+            dispute_list_constraints.challenge_required_by_range
+        {
+            filter = filter
+                .filter(dsl::challenge_required_by.ge(challenge_required_by_range.start_time));
+
+            if let Some(end_time) = challenge_required_by_range.end_time {
+                filter = filter.filter(dsl::challenge_required_by.le(end_time));
+            }
+        }
+
         if let Some(profile_id) = &dispute_list_constraints.profile_id {
             filter = filter.filter(dsl::profile_id.eq_any(profile_id.clone()));
         }
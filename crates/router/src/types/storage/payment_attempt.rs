@@ -8,6 +8,15 @@ pub use hyperswitch_domain_models::payments::payment_attempt::{
 use crate::{
     core::errors, errors::RouterResult, types::transformers::ForeignFrom, utils::OptionExt,
 };
+/// Tracking data of the process tracker task which retries a payment attempt that was
+/// requeued by a GSM rule
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct PaymentRequeueTrackingData {
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub attempt_id: String,
+}
+
 pub trait PaymentAttemptExt {
     fn make_new_capture(
         &self,
@@ -0,0 +1,4 @@
+pub use diesel_models::payout_batch::{
+    PayoutBatch, PayoutBatchItem, PayoutBatchItems, PayoutBatchNew, PayoutBatchUpdate,
+    PayoutBatchUpdateInternal,
+};
@@ -1,3 +1,4 @@
-pub use diesel_models::routing_algorithm::{
-    RoutingAlgorithm, RoutingAlgorithmMetadata, RoutingProfileMetadata,
+pub use diesel_models::{
+    routing_algorithm::{RoutingAlgorithm, RoutingAlgorithmMetadata, RoutingProfileMetadata},
+    routing_algorithm_activation::{RoutingAlgorithmActivation, RoutingAlgorithmActivationNew},
 };
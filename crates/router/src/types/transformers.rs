@@ -888,6 +888,9 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: api_key.scopes.map(|scopes| scopes.0),
+            allowed_ips: api_key.allowed_ips,
+            profile_id: api_key.profile_id,
         }
     }
 }
@@ -902,6 +905,9 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: api_key.scopes.map(|scopes| scopes.0),
+            allowed_ips: api_key.allowed_ips,
+            profile_id: api_key.profile_id,
         }
     }
 }
@@ -915,6 +921,17 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            scopes: api_key.scopes.map(|scopes| {
+                (!scopes.is_empty()).then_some(common_types::api_keys::ApiKeyScopes(scopes))
+            }),
+            allowed_ips: api_key
+                .allowed_ips
+                .map(|allowed_ips| (!allowed_ips.is_empty()).then_some(allowed_ips)),
+            profile_id: match (api_key.profile_id, api_key.remove_profile_restriction) {
+                (Some(profile_id), _) => Some(Some(profile_id)),
+                (None, Some(true)) => Some(None),
+                (None, _) => None,
+            },
         }
     }
 }
@@ -1875,6 +1892,7 @@ impl ForeignFrom<gsm_api_types::GsmCreateRequest> for storage::GatewayStatusMapp
             unified_message: value.unified_message,
             error_category: value.error_category,
             clear_pan_possible: value.clear_pan_possible,
+            requeue_backoff_in_seconds: value.requeue_backoff_in_seconds,
         }
     }
 }
@@ -1895,6 +1913,7 @@ impl ForeignFrom<storage::GatewayStatusMap> for gsm_api_types::GsmResponse {
             unified_message: value.unified_message,
             error_category: value.error_category,
             clear_pan_possible: value.clear_pan_possible,
+            requeue_backoff_in_seconds: value.requeue_backoff_in_seconds,
         }
     }
 }
@@ -1997,6 +2016,7 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
             event_class: item.event_class,
             is_delivery_successful: item.is_overall_delivery_successful,
             initial_attempt_id,
+            webhook_endpoint_id: item.webhook_endpoint_id,
             created: item.created_at,
         })
     }
@@ -2147,6 +2167,7 @@ impl ForeignFrom<api_models::admin::WebhookDetails>
             payment_created_enabled: item.payment_created_enabled,
             payment_succeeded_enabled: item.payment_succeeded_enabled,
             payment_failed_enabled: item.payment_failed_enabled,
+            webhook_format: item.webhook_format,
         }
     }
 }
@@ -2163,6 +2184,7 @@ impl ForeignFrom<diesel_models::business_profile::WebhookDetails>
             payment_created_enabled: item.payment_created_enabled,
             payment_succeeded_enabled: item.payment_succeeded_enabled,
             payment_failed_enabled: item.payment_failed_enabled,
+            webhook_format: item.webhook_format,
         }
     }
 }
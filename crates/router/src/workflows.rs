@@ -2,9 +2,14 @@
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod dispute_deadline;
+pub mod incoming_webhook_queue;
 pub mod outgoing_webhook_retry;
 pub mod payment_method_status_update;
+pub mod payment_requeue;
 pub mod payment_sync;
+#[cfg(feature = "payouts")]
+pub mod payout_batch;
 
 pub mod refund_router;
 
@@ -0,0 +1,151 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use error_stack::ResultExt;
+use router_env::tracing::{self, instrument};
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+#[cfg(feature = "v1")]
+use scheduler::utils as pt_utils;
+
+#[cfg(feature = "v1")]
+use crate::{
+    core::disputes::deadline::{self, DisputeDeadlineTrackingData},
+    db::StorageInterface,
+    logger,
+    types::domain,
+};
+use crate::{errors, routes::SessionState, types::storage};
+
+/// Delays between the attempts of a failed run of the task, in seconds, along with the number of
+/// attempts made with each delay
+#[cfg(feature = "v1")]
+const DISPUTE_DEADLINE_RETRY_FREQUENCIES: [(i32, i32); 2] = [(60, 3), (60 * 10, 3)];
+
+pub struct DisputeDeadlineWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DisputeDeadlineWorkflow {
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: DisputeDeadlineTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeDeadlineTrackingData")?;
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let business_profile = db
+            .find_business_profile_by_profile_id(
+                key_manager_state,
+                &key_store,
+                &tracking_data.profile_id,
+            )
+            .await?;
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let next_schedule_time = Box::pin(deadline::process_dispute_deadline(
+            state,
+            merchant_context,
+            business_profile,
+            &tracking_data,
+        ))
+        .await?;
+
+        match next_schedule_time {
+            Some(schedule_time) => {
+                logger::debug!(
+                    dispute_id = %tracking_data.dispute_id,
+                    %schedule_time,
+                    "Scheduling the next dispute deadline reminder"
+                );
+                db.as_scheduler()
+                    .reset_process(process, schedule_time)
+                    .await?;
+            }
+            None => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    /// Retries the run of the task, the dispute is no longer tracked once the retries run out
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        let schedule_time = pt_utils::get_time_from_delta(pt_utils::get_delay(
+            process.retry_count + 1,
+            &DISPUTE_DEADLINE_RETRY_FREQUENCIES,
+        ));
+
+        match schedule_time {
+            Some(schedule_time) => {
+                logger::error!(
+                    ?error,
+                    "failed to process the dispute deadline, retrying later"
+                );
+                state
+                    .store
+                    .as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await
+                    .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+            }
+            None => {
+                consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+            }
+        }
+    }
+
+    #[cfg(feature = "v2")]
+    #[instrument(skip_all)]
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
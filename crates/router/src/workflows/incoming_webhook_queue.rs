@@ -0,0 +1,213 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+use router_env::tracing::{self, instrument};
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+#[cfg(feature = "v1")]
+use crate::{
+    core::{
+        errors::StorageErrorExt,
+        webhooks::{self as webhooks_core, types::IncomingWebhookQueueTrackingData},
+    },
+    db::StorageInterface,
+    logger,
+    types::domain,
+};
+use crate::{errors, routes::SessionState, types::storage};
+
+pub struct IncomingWebhookQueueWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for IncomingWebhookQueueWorkflow {
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: IncomingWebhookQueueTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("IncomingWebhookQueueTrackingData")?;
+
+        let db = &*state.store;
+        let config = &state.conf.webhooks.incoming_queue;
+
+        // The webhooks queued for an object are processed in the order they were received, so
+        // the webhook waits for the one queued before it without using up its retries
+        if let Some(previous_process_id) = &tracking_data.previous_process_id {
+            if webhooks_core::is_queued_webhook_pending(state, previous_process_id).await? {
+                let schedule_time = common_utils::date_time::now()
+                    .saturating_add(time::Duration::seconds(config.retry_delay_in_secs.into()));
+                db.as_scheduler()
+                    .update_process(
+                        process.clone(),
+                        storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                            status: storage::enums::ProcessTrackerStatus::Pending,
+                            retry_count: process.retry_count,
+                            schedule_time,
+                        },
+                    )
+                    .await?;
+                return Ok(());
+            }
+        }
+
+        match Box::pin(process_queued_webhook(state, tracking_data)).await {
+            Ok(webhook_response_tracker) => {
+                logger::debug!(
+                    ?webhook_response_tracker,
+                    "Processed queued incoming webhook"
+                );
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+            // The webhook has already been acknowledged to the connector, so every failure is
+            // retried until the retry limit is reached
+            Err(error) => {
+                let retry_count = u32::try_from(process.retry_count).unwrap_or(u32::MAX);
+                if retry_count < config.max_retries {
+                    let is_resource_busy = matches!(
+                        error.current_context(),
+                        errors::ApiErrorResponse::ResourceBusy
+                    );
+                    logger::warn!(?error, retry_count, "Retrying queued incoming webhook");
+                    let schedule_time = common_utils::date_time::now().saturating_add(
+                        time::Duration::seconds(get_retry_delay_in_secs(
+                            config.retry_delay_in_secs,
+                            retry_count,
+                            is_resource_busy,
+                        )),
+                    );
+                    db.as_scheduler()
+                        .retry_process(process, schedule_time)
+                        .await?;
+                } else {
+                    logger::error!(
+                        ?error,
+                        "Queued incoming webhook could not be processed within the retry limit"
+                    );
+                    db.as_scheduler()
+                        .finish_process_with_business_status(
+                            process,
+                            business_status::RETRIES_EXCEEDED,
+                        )
+                        .await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    #[instrument(skip_all)]
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn process_queued_webhook(
+    state: &SessionState,
+    tracking_data: IncomingWebhookQueueTrackingData,
+) -> errors::RouterResult<api_models::webhooks::WebhookResponseTracker> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let business_profile = db
+        .find_business_profile_by_profile_id(
+            key_manager_state,
+            &key_store,
+            &tracking_data.business_profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: tracking_data
+                .business_profile_id
+                .get_string_repr()
+                .to_owned(),
+        })?;
+    let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+        merchant_account,
+        key_store,
+    )));
+
+    Box::pin(webhooks_core::process_queued_webhook(
+        state.clone(),
+        merchant_context,
+        business_profile,
+        tracking_data,
+    ))
+    .await
+}
+
+/// Delay before the next retry of a queued webhook. Webhooks waiting for a lock are retried at
+/// the configured delay, other failures are retried with an exponential backoff.
+#[cfg(feature = "v1")]
+fn get_retry_delay_in_secs(
+    retry_delay_in_secs: u32,
+    retry_count: u32,
+    is_resource_busy: bool,
+) -> i64 {
+    const MAX_BACKOFF_EXPONENT: u32 = 6;
+
+    let retry_delay_in_secs = i64::from(retry_delay_in_secs);
+    if is_resource_busy {
+        retry_delay_in_secs
+    } else {
+        retry_delay_in_secs.saturating_mul(1 << retry_count.min(MAX_BACKOFF_EXPONENT))
+    }
+}
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay_of_busy_resource_is_constant() {
+        assert_eq!(get_retry_delay_in_secs(5, 0, true), 5);
+        assert_eq!(get_retry_delay_in_secs(5, 8, true), 5);
+    }
+
+    #[test]
+    fn test_retry_delay_backs_off_exponentially_up_to_cap() {
+        assert_eq!(get_retry_delay_in_secs(5, 0, false), 5);
+        assert_eq!(get_retry_delay_in_secs(5, 1, false), 10);
+        assert_eq!(get_retry_delay_in_secs(5, 3, false), 40);
+        assert_eq!(get_retry_delay_in_secs(5, 6, false), 320);
+        assert_eq!(get_retry_delay_in_secs(5, 20, false), 320);
+    }
+}
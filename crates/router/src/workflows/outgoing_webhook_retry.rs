@@ -66,6 +66,40 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             )
             .await?;
 
+        let webhook_endpoint = match &tracking_data.webhook_endpoint_id {
+            Some(webhook_endpoint_id) => {
+                let webhook_endpoint = match db
+                    .find_webhook_endpoint_by_merchant_id_endpoint_id(
+                        key_manager_state,
+                        &tracking_data.merchant_id,
+                        webhook_endpoint_id,
+                        &key_store,
+                    )
+                    .await
+                {
+                    Ok(webhook_endpoint) => Some(webhook_endpoint)
+                        .filter(|webhook_endpoint| webhook_endpoint.is_enabled),
+                    Err(error) if error.current_context().is_db_not_found() => None,
+                    Err(error) => return Err(error.into()),
+                };
+
+                // The webhook endpoint was deleted or disabled after the task was created
+                if webhook_endpoint.is_none() {
+                    logger::debug!(
+                        %webhook_endpoint_id,
+                        "Webhook endpoint is unavailable or disabled, finishing task"
+                    );
+                    db.as_scheduler()
+                        .finish_process_with_business_status(process, business_status::REVOKED)
+                        .await?;
+                    return Ok(());
+                }
+
+                webhook_endpoint
+            }
+            None => None,
+        };
+
         let event_id = webhooks_core::utils::generate_event_id();
         let idempotent_event_id = webhooks_core::utils::get_idempotent_event_id(
             &tracking_data.primary_object_id,
@@ -119,6 +153,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             delivery_attempt: Some(delivery_attempt),
             metadata: initial_event.metadata,
             is_overall_delivery_successful: Some(false),
+            webhook_endpoint_id: initial_event.webhook_endpoint_id,
         };
 
         let event = db
@@ -138,6 +173,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                 Box::pin(webhooks_core::trigger_webhook_and_raise_event(
                     state.clone(),
                     business_profile,
+                    webhook_endpoint,
                     &key_store,
                     event,
                     request_content,
@@ -187,6 +223,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             &merchant_context,
                             outgoing_webhook,
                             &business_profile,
+                            webhook_endpoint.as_ref(),
                         )
                         .map_err(|error| {
                             logger::error!(
@@ -199,6 +236,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                         Box::pin(webhooks_core::trigger_webhook_and_raise_event(
                             state.clone(),
                             business_profile,
+                            webhook_endpoint,
                             &key_store,
                             event,
                             request_content,
@@ -432,7 +470,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                 | ApplicationResponse::Form(_)
                 | ApplicationResponse::GenericLinkForm(_)
                 | ApplicationResponse::PaymentLinkForm(_)
-                | ApplicationResponse::FileData(_) => {
+                | ApplicationResponse::FileData(_)
+                | ApplicationResponse::IdempotentReplay(_) => {
                     Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                         resource_name: tracking_data.primary_object_id.clone(),
                     })
@@ -488,14 +527,22 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::IdempotentReplay(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
                     }
                 }
                 .map(Box::new)?;
-            let event_type = Some(EventType::foreign_from(dispute_response.dispute_status));
+            // Deadline reminders are raised for disputes which are still open
+            let event_type = match (tracking_data.event_type, dispute_response.dispute_status) {
+                (
+                    EventType::DisputeDeadlineApproaching,
+                    api_models::enums::DisputeStatus::DisputeOpened,
+                ) => Some(EventType::DisputeDeadlineApproaching),
+                _ => Some(EventType::foreign_from(dispute_response.dispute_status)),
+            };
             logger::debug!(current_resource_status=%dispute_response.dispute_status);
 
             Ok((
@@ -520,7 +567,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::IdempotentReplay(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
@@ -0,0 +1,481 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors, utils as pt_utils,
+};
+
+use crate::{
+    core::{
+        errors::StorageErrorExt,
+        payments::{self as payment_flows, operations},
+    },
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+pub struct PaymentsRequeueWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentsRequeueWorkflow {
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::payment_attempt::PaymentRequeueTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentRequeueTrackingData")?;
+        let key_manager_state = &state.into();
+        let requeued_payment = RequeuedPayment::fetch(state, &tracking_data).await?;
+
+        // The payment might have been synced, cancelled or retried by other means after it was
+        // requeued, in which case the requeued attempt should not be retried
+        if !requeued_payment.is_attempt_still_parked(&tracking_data.attempt_id) {
+            logger::info!(
+                payment_id = ?tracking_data.payment_id,
+                "payment is no longer in requeued state, skipping the retry"
+            );
+            return Ok(db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await?);
+        }
+
+        let RequeuedPayment {
+            merchant_account,
+            key_store,
+            payment_intent,
+            payment_attempt,
+        } = requeued_payment;
+        let storage_scheme = merchant_account.storage_scheme;
+
+        // A payment can only be retried with a new attempt once its intent and active attempt
+        // have failed, both are restored if the payment could not be retried
+        let payment_token = payment_attempt.payment_token.clone();
+        let payment_method = payment_attempt.payment_method;
+        let payment_method_type = payment_attempt.payment_method_type;
+
+        let payment_attempt = db
+            .update_payment_attempt_with_attempt_id(
+                payment_attempt,
+                storage::PaymentAttemptUpdate::StatusUpdate {
+                    status: enums::AttemptStatus::Failure,
+                    updated_by: storage_scheme.to_string(),
+                },
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let payment_intent = db
+            .update_payment_intent(
+                key_manager_state,
+                payment_intent,
+                storage::PaymentIntentUpdate::PGStatusUpdate {
+                    status: enums::IntentStatus::Failed,
+                    updated_by: storage_scheme.to_string(),
+                    incremental_authorization_allowed: Some(false),
+                },
+                &key_store,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store.clone(),
+        )));
+
+        // Connector selection is performed again for the new attempt, so the payment is either
+        // retried through the same connector or the next one returned by routing
+        let request = api::PaymentsRequest {
+            payment_id: Some(api::PaymentIdType::PaymentIntentId(
+                tracking_data.payment_id.clone(),
+            )),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            payment_token,
+            payment_method,
+            payment_method_type,
+            retry_action: Some(api_models::enums::RetryAction::ManualRetry),
+            ..Default::default()
+        };
+
+        // TODO: Add support for ReqState in PT flows
+        let response = Box::pin(payment_flows::payments_core::<
+            api::Authorize,
+            api::PaymentsResponse,
+            _,
+            _,
+            _,
+            payment_flows::PaymentData<api::Authorize>,
+        >(
+            state.clone(),
+            state.get_req_state(),
+            merchant_context,
+            None,
+            operations::PaymentConfirm,
+            request,
+            services::AuthFlow::Merchant,
+            payment_flows::CallConnectorAction::Trigger,
+            None,
+            hyperswitch_domain_models::payments::HeaderPayload::default(),
+        ))
+        .await;
+
+        if let Err(error) = response {
+            logger::error!(
+                ?error,
+                payment_id = ?tracking_data.payment_id,
+                "failed to retry the requeued payment"
+            );
+
+            // The outcome of the payment is tracked by the new attempt once it has been created,
+            // otherwise the requeued attempt is parked again until the retry is attempted again
+            let is_new_attempt_created = db
+                .find_payment_intent_by_payment_id_merchant_id(
+                    key_manager_state,
+                    &tracking_data.payment_id,
+                    &tracking_data.merchant_id,
+                    &key_store,
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?
+                .active_attempt
+                .get_id()
+                != tracking_data.attempt_id;
+
+            if !is_new_attempt_created {
+                park_requeued_attempt(
+                    state,
+                    &key_store,
+                    storage_scheme,
+                    payment_intent,
+                    payment_attempt,
+                )
+                .await?;
+            }
+
+            return Err(error.into());
+        }
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v1")]
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        let schedule_time = pt_utils::get_time_from_delta(pt_utils::get_delay(
+            process.retry_count + 1,
+            &REQUEUE_RETRY_FREQUENCIES,
+        ));
+
+        match schedule_time {
+            Some(schedule_time) => {
+                logger::error!(
+                    ?error,
+                    "failed to retry the requeued payment, retrying later"
+                );
+                state
+                    .store
+                    .as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await
+                    .change_context(sch_errors::ProcessTrackerError::ProcessUpdateFailed)
+            }
+            None => {
+                logger::error!(
+                    ?error,
+                    "retries exhausted for the requeued payment, marking it as failed"
+                );
+                match fail_requeued_payment(state, &process).await {
+                    Ok(()) => state
+                        .store
+                        .as_scheduler()
+                        .finish_process_with_business_status(
+                            process,
+                            business_status::RETRIES_EXCEEDED,
+                        )
+                        .await
+                        .change_context(sch_errors::ProcessTrackerError::ProcessUpdateFailed),
+                    Err(error) => {
+                        consumer::consumer_error_handler(state.store.as_scheduler(), process, error)
+                            .await
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "v2")]
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Intervals (in seconds) at which the retry of a requeued payment is attempted again when it
+/// fails, and the number of times each interval is used, after which the payment is marked as
+/// failed
+#[cfg(feature = "v1")]
+const REQUEUE_RETRY_FREQUENCIES: [(i32, i32); 2] = [(60, 3), (60 * 5, 2)];
+
+#[cfg(feature = "v1")]
+struct RequeuedPayment {
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_intent: storage::PaymentIntent,
+    payment_attempt: storage::PaymentAttempt,
+}
+
+#[cfg(feature = "v1")]
+impl RequeuedPayment {
+    async fn fetch(
+        state: &SessionState,
+        tracking_data: &storage::payment_attempt::PaymentRequeueTrackingData,
+    ) -> Result<Self, sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let storage_scheme = merchant_account.storage_scheme;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                key_manager_state,
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &key_store,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &tracking_data.attempt_id,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        Ok(Self {
+            merchant_account,
+            key_store,
+            payment_intent,
+            payment_attempt,
+        })
+    }
+
+    fn is_attempt_still_parked(&self, requeued_attempt_id: &str) -> bool {
+        is_attempt_parked(
+            self.payment_intent.status,
+            &self.payment_intent.active_attempt.get_id(),
+            self.payment_attempt.status,
+            requeued_attempt_id,
+        )
+    }
+}
+
+/// A requeued attempt is parked in pending state, as the active attempt of a processing payment
+#[cfg(feature = "v1")]
+fn is_attempt_parked(
+    intent_status: enums::IntentStatus,
+    active_attempt_id: &str,
+    attempt_status: enums::AttemptStatus,
+    requeued_attempt_id: &str,
+) -> bool {
+    intent_status == enums::IntentStatus::Processing
+        && active_attempt_id == requeued_attempt_id
+        && attempt_status == enums::AttemptStatus::Pending
+}
+
+#[cfg(feature = "v1")]
+async fn park_requeued_attempt(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
+    payment_intent: storage::PaymentIntent,
+    payment_attempt: storage::PaymentAttempt,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let db: &dyn StorageInterface = &*state.store;
+
+    db.update_payment_attempt_with_attempt_id(
+        payment_attempt,
+        storage::PaymentAttemptUpdate::StatusUpdate {
+            status: enums::AttemptStatus::Pending,
+            updated_by: storage_scheme.to_string(),
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    db.update_payment_intent(
+        &state.into(),
+        payment_intent,
+        storage::PaymentIntentUpdate::PGStatusUpdate {
+            status: enums::IntentStatus::Processing,
+            updated_by: storage_scheme.to_string(),
+            incremental_authorization_allowed: None,
+        },
+        key_store,
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(())
+}
+
+/// Marks the requeued attempt and the payment as failed, if the attempt is still parked
+#[cfg(feature = "v1")]
+async fn fail_requeued_payment(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let db: &dyn StorageInterface = &*state.store;
+    let tracking_data: storage::payment_attempt::PaymentRequeueTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("PaymentRequeueTrackingData")?;
+    let requeued_payment = RequeuedPayment::fetch(state, &tracking_data).await?;
+
+    if !requeued_payment.is_attempt_still_parked(&tracking_data.attempt_id) {
+        return Ok(());
+    }
+
+    let RequeuedPayment {
+        merchant_account,
+        key_store,
+        payment_intent,
+        payment_attempt,
+    } = requeued_payment;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    db.update_payment_attempt_with_attempt_id(
+        payment_attempt,
+        storage::PaymentAttemptUpdate::StatusUpdate {
+            status: enums::AttemptStatus::Failure,
+            updated_by: storage_scheme.to_string(),
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    db.update_payment_intent(
+        &state.into(),
+        payment_intent,
+        storage::PaymentIntentUpdate::PGStatusUpdate {
+            status: enums::IntentStatus::Failed,
+            updated_by: storage_scheme.to_string(),
+            incremental_authorization_allowed: Some(false),
+        },
+        &key_store,
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_attempt_parked() {
+        assert!(is_attempt_parked(
+            enums::IntentStatus::Processing,
+            "attempt_1",
+            enums::AttemptStatus::Pending,
+            "attempt_1",
+        ));
+        // The payment was retried with a new attempt
+        assert!(!is_attempt_parked(
+            enums::IntentStatus::Processing,
+            "attempt_2",
+            enums::AttemptStatus::Pending,
+            "attempt_1",
+        ));
+        // The payment was synced after it was requeued
+        assert!(!is_attempt_parked(
+            enums::IntentStatus::Succeeded,
+            "attempt_1",
+            enums::AttemptStatus::Charged,
+            "attempt_1",
+        ));
+        // The requeued attempt was already closed
+        assert!(!is_attempt_parked(
+            enums::IntentStatus::Processing,
+            "attempt_1",
+            enums::AttemptStatus::Failure,
+            "attempt_1",
+        ));
+    }
+
+    #[test]
+    fn test_requeue_retry_schedule() {
+        let delays = (1..=6)
+            .map(|retry_count| pt_utils::get_delay(retry_count, &REQUEUE_RETRY_FREQUENCIES))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            delays,
+            vec![Some(60), Some(60), Some(60), Some(300), Some(300), None]
+        );
+    }
+}
@@ -0,0 +1,184 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use error_stack::ResultExt;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use router_env::tracing::{self, instrument};
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+#[cfg(feature = "v1")]
+use scheduler::utils as pt_utils;
+
+#[cfg(feature = "v1")]
+use crate::{core::payouts::batch, types::domain};
+use crate::{errors, routes::SessionState, types::storage};
+
+/// Delays between the runs of the task after a failed run, in seconds, along with the number of
+/// runs retried with each delay. The count is reset whenever a run completes without errors.
+#[cfg(feature = "v1")]
+const PAYOUT_BATCH_RETRY_FREQUENCIES: [(i32, i32); 2] = [(60, 3), (60 * 5, 2)];
+
+pub struct PayoutBatchWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayoutBatchWorkflow {
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: batch::PayoutBatchTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutBatchTrackingData")?;
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let mut payout_batch = db
+            .find_payout_batch_by_merchant_id_batch_id(
+                &tracking_data.merchant_id,
+                &tracking_data.batch_id,
+            )
+            .await?;
+        if !batch::is_payout_batch_complete(&payout_batch) {
+            payout_batch =
+                batch::execute_payout_batch_chunk(state, &merchant_context, payout_batch).await?;
+        }
+
+        // The next chunk of rows is picked up right away, every run of the task handles at most
+        // `chunk_size` rows
+        if batch::is_payout_batch_complete(&payout_batch) {
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    batch::get_payout_batch_business_status(&payout_batch),
+                )
+                .await?;
+        } else {
+            db.as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                        status: storage::enums::ProcessTrackerStatus::Pending,
+                        retry_count: 0,
+                        schedule_time: common_utils::date_time::now(),
+                    },
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    /// Retries the task while retries remain, since the rows which are still pending can be
+    /// created by a later run. The pending rows are marked as failed once the retries run out.
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        let schedule_time = pt_utils::get_time_from_delta(pt_utils::get_delay(
+            process.retry_count + 1,
+            &PAYOUT_BATCH_RETRY_FREQUENCIES,
+        ));
+
+        match schedule_time {
+            Some(schedule_time) => {
+                logger::error!(?error, "failed to process the payout batch, retrying later");
+                state
+                    .store
+                    .as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await
+                    .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+            }
+            None => {
+                logger::error!(
+                    ?error,
+                    "retries exhausted for the payout batch, marking the pending rows as failed"
+                );
+                match fail_payout_batch(state, &process).await {
+                    Ok(()) => state
+                        .store
+                        .as_scheduler()
+                        .finish_process_with_business_status(
+                            process,
+                            business_status::RETRIES_EXCEEDED,
+                        )
+                        .await
+                        .change_context(errors::ProcessTrackerError::ProcessUpdateFailed),
+                    Err(error) => {
+                        consumer::consumer_error_handler(state.store.as_scheduler(), process, error)
+                            .await
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "v2")]
+    #[instrument(skip_all)]
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn fail_payout_batch(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data: batch::PayoutBatchTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("PayoutBatchTrackingData")?;
+
+    batch::fail_pending_payout_batch_rows(
+        state,
+        &tracking_data.merchant_id,
+        &tracking_data.batch_id,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -134,6 +134,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: None,
     }
 }
 
@@ -207,6 +208,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         authentication_id: None,
         psd2_sca_exemption_type: None,
         whole_connector_response: None,
+        merchant_connector_id: None,
     }
 }
 
@@ -82,6 +82,10 @@ pub enum Flow {
     MerchantConnectorsDelete,
     /// Merchant Connectors list flow.
     MerchantConnectorsList,
+    /// Merchant Connector circuit breaker retrieve flow.
+    ConnectorCircuitBreakerRetrieve,
+    /// Merchant Connector circuit breaker reset flow.
+    ConnectorCircuitBreakerReset,
     /// Merchant Transfer Keys
     MerchantTransferKey,
     /// ConfigKey create flow.
@@ -205,6 +209,15 @@ pub enum Flow {
     PayoutsFilter,
     /// Payouts accounts flow.
     PayoutsAccounts,
+    #[cfg(feature = "payouts")]
+    /// Payouts batch create flow, from a JSON list or a CSV file of payouts
+    PayoutsBatchCreate,
+    #[cfg(feature = "payouts")]
+    /// Payouts batch retrieve flow
+    PayoutsBatchRetrieve,
+    #[cfg(feature = "payouts")]
+    /// Payouts batch list flow
+    PayoutsBatchList,
     /// Payout link initiate flow
     PayoutLinkInitiate,
     /// Payments Redirect flow
@@ -243,6 +256,16 @@ pub enum Flow {
     RoutingUnlinkConfig,
     /// Routing retrieve config
     RoutingRetrieveConfig,
+    /// Routing simulate config against past payments
+    RoutingSimulateConfig,
+    /// Routing export config as DSL
+    RoutingExportConfigDsl,
+    /// Routing retrieve activation history
+    RoutingRetrieveActivationHistory,
+    /// Routing diff configs
+    RoutingDiffConfig,
+    /// Routing rollback active config
+    RoutingRollbackConfig,
     /// Routing retrieve active config
     RoutingRetrieveActiveConfig,
     /// Routing retrieve default config
@@ -309,6 +332,8 @@ pub enum Flow {
     DisputesAggregate,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
+    /// Build Dispute Evidence Package flow
+    BuildDisputeEvidencePackage,
     /// Invalidate cache flow
     CacheInvalidate,
     /// Payment Link Retrieve flow
@@ -361,6 +386,10 @@ pub enum Flow {
     DecisionManagerDeleteConfig,
     /// Retrieve Decision Manager Config
     DecisionManagerRetrieveConfig,
+    /// Analyze Decision Manager Config
+    DecisionManagerAnalyzeConfig,
+    /// Execute a 3DS decision rule program against a sample input
+    ThreeDsDecisionRuleExecute,
     /// Manual payment fulfillment acknowledgement
     FrmFulfillment,
     /// Get connectors feature matrix
@@ -523,6 +552,22 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Create a webhook endpoint for a profile
+    WebhookEndpointCreate,
+    /// List webhook endpoints of a profile
+    WebhookEndpointList,
+    /// Retrieve a webhook endpoint
+    WebhookEndpointRetrieve,
+    /// Update a webhook endpoint
+    WebhookEndpointUpdate,
+    /// Delete a webhook endpoint
+    WebhookEndpointDelete,
+    /// Start the rotation of the webhook signing key of a profile
+    WebhookSecretRotationStart,
+    /// Retrieve the status of the webhook signing key rotation of a profile
+    WebhookSecretRotationRetrieve,
+    /// Complete the rotation of the webhook signing key of a profile
+    WebhookSecretRotationComplete,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
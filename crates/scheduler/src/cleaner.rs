@@ -0,0 +1,410 @@
+use std::sync::Arc;
+
+use common_utils::{errors::CustomResult, id_type};
+use diesel_models::enums::ProcessTrackerStatus;
+use error_stack::{report, ResultExt};
+use router_env::{
+    instrument,
+    tracing::{self, Instrument},
+};
+use strum::IntoEnumIterator;
+use time::Duration;
+use tokio::sync::mpsc;
+
+use super::{
+    env::logger::{self, debug, error},
+    metrics,
+};
+use crate::{
+    configs::settings::{CleanerPolicy, CleanupAction, SchedulerSettings},
+    db::process_tracker::storage,
+    errors,
+    flow::SchedulerFlow,
+    scheduler::SchedulerInterface,
+    utils::*,
+    SchedulerAppState, SchedulerSessionState,
+};
+
+#[instrument(skip_all)]
+pub async fn start_cleaner<T, U, F>(
+    state: &T,
+    scheduler_settings: Arc<SchedulerSettings>,
+    (tx, mut rx): (mpsc::Sender<()>, mpsc::Receiver<()>),
+    app_state_to_session_state: F,
+) -> CustomResult<(), errors::ProcessTrackerError>
+where
+    F: Fn(&T, &id_type::TenantId) -> CustomResult<U, errors::ProcessTrackerError>,
+    T: SchedulerAppState,
+    U: SchedulerSessionState,
+{
+    use std::time::Duration;
+
+    let mut interval =
+        tokio::time::interval(Duration::from_millis(scheduler_settings.loop_interval));
+
+    let mut shutdown_interval = tokio::time::interval(Duration::from_millis(
+        scheduler_settings.graceful_shutdown_interval,
+    ));
+
+    let signal = common_utils::signals::get_allowed_signals()
+        .map_err(|error| {
+            logger::error!("Signal Handler Error: {:?}", error);
+            errors::ProcessTrackerError::ConfigurationError
+        })
+        .attach_printable("Failed while creating a signals handler")?;
+    let handle = signal.handle();
+    let task_handle =
+        tokio::spawn(common_utils::signals::signal_handler(signal, tx).in_current_span());
+
+    loop {
+        match rx.try_recv() {
+            Err(mpsc::error::TryRecvError::Empty) => {
+                interval.tick().await;
+                let tenants = state.get_tenants();
+                for tenant in tenants {
+                    let session_state = app_state_to_session_state(state, &tenant)?;
+                    match run_cleaner_flow(&session_state, &scheduler_settings).await {
+                        Ok(_) => (),
+                        Err(error) => {
+                            // Intentionally not propagating error to caller.
+                            // Any errors that occur in the cleaner flow must be handled here only, as
+                            // this is the topmost level function which is concerned with the cleaner flow.
+                            error!(?error);
+                        }
+                    }
+                }
+            }
+            Ok(()) | Err(mpsc::error::TryRecvError::Disconnected) => {
+                logger::debug!("Awaiting shutdown!");
+                rx.close();
+                shutdown_interval.tick().await;
+                logger::info!("Terminating cleaner");
+                break;
+            }
+        }
+    }
+    handle.close();
+    task_handle
+        .await
+        .change_context(errors::ProcessTrackerError::UnexpectedFlow)?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn run_cleaner_flow<T>(
+    state: &T,
+    settings: &SchedulerSettings,
+) -> CustomResult<(), errors::ProcessTrackerError>
+where
+    T: SchedulerSessionState,
+{
+    acquire_lock_and_run(
+        state.get_db().as_scheduler(),
+        "CLEANER_LOCK",
+        &settings.cleaner.lock_key,
+        settings.cleaner.lock_ttl,
+        move || async {
+            for runner in storage::ProcessTrackerRunner::iter() {
+                let policy = settings.cleaner.get_policy(runner);
+
+                // Errors for a runner are logged and do not prevent the remaining runners from
+                // being cleaned up.
+                if let Err(error) =
+                    reset_stuck_tasks(state.get_db().as_scheduler(), settings, runner, policy).await
+                {
+                    error!(?error, %runner, "Failed to reset stuck tasks");
+                }
+
+                if let Err(error) =
+                    clean_up_tasks(state.get_db().as_scheduler(), settings, runner, policy).await
+                {
+                    error!(?error, %runner, "Failed to clean up tasks");
+                }
+            }
+
+            Ok(())
+        },
+    )
+    .await
+}
+
+/// Resets tasks which have been in `PROCESS_STARTED` for longer than the stuck task threshold,
+/// most likely because the consumer processing them was terminated, and adds them back to the
+/// stream so that they are picked up again.
+#[instrument(skip_all, fields(%runner))]
+pub async fn reset_stuck_tasks(
+    db: &dyn SchedulerInterface,
+    settings: &SchedulerSettings,
+    runner: storage::ProcessTrackerRunner,
+    policy: &CleanerPolicy,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let stuck_before = get_time_before(policy.stuck_task_threshold)?;
+
+    let tasks = db
+        .find_processes_by_runner_status_updated_before(
+            runner,
+            ProcessTrackerStatus::ProcessStarted,
+            stuck_before,
+            settings.cleaner.batch_size,
+        )
+        .await
+        .change_context(errors::ProcessTrackerError::ProcessFetchingFailed)?;
+
+    if tasks.is_empty() {
+        return Ok(());
+    }
+
+    let count = tasks.len();
+    debug!("Resetting {count} stuck tasks");
+    divide_and_append_tasks(db, SchedulerFlow::Cleaner, tasks, settings).await?;
+
+    // Safety: Assuming we won't deal with more than `u64::MAX` tasks at once
+    #[allow(clippy::as_conversions)]
+    metrics::TASKS_RESET_COUNT.add(
+        count as u64,
+        router_env::metric_attributes!(("runner", runner.to_string())),
+    );
+
+    Ok(())
+}
+
+/// Archives or deletes `FINISH`ed tasks, and tasks in `REVIEW` if configured, which have not been
+/// updated within their retention period.
+#[instrument(skip_all, fields(%runner))]
+pub async fn clean_up_tasks(
+    db: &dyn SchedulerInterface,
+    settings: &SchedulerSettings,
+    runner: storage::ProcessTrackerRunner,
+    policy: &CleanerPolicy,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    for (status, retention) in get_retention_periods(policy) {
+        let tasks = db
+            .find_processes_by_runner_status_updated_before(
+                runner,
+                status,
+                get_time_before(retention)?,
+                settings.cleaner.batch_size,
+            )
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessFetchingFailed)?;
+
+        if tasks.is_empty() {
+            continue;
+        }
+
+        let count = match policy.action {
+            CleanupAction::Archive => {
+                let count = db
+                    .archive_processes(tasks)
+                    .await
+                    .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+                // Safety: Assuming we won't deal with more than `u64::MAX` tasks at once
+                #[allow(clippy::as_conversions)]
+                metrics::TASKS_ARCHIVED_COUNT.add(
+                    count as u64,
+                    router_env::metric_attributes!(("runner", runner.to_string())),
+                );
+                count
+            }
+            CleanupAction::Delete => {
+                let ids = tasks.into_iter().map(|task| task.id).collect();
+                let count = db
+                    .delete_processes_by_ids(ids)
+                    .await
+                    .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+                // Safety: Assuming we won't deal with more than `u64::MAX` tasks at once
+                #[allow(clippy::as_conversions)]
+                metrics::TASKS_DELETED_COUNT.add(
+                    count as u64,
+                    router_env::metric_attributes!(("runner", runner.to_string())),
+                );
+                count
+            }
+        };
+        debug!("Cleaned up {count} tasks with status {status:?}");
+    }
+
+    Ok(())
+}
+
+/// Returns the statuses of the tasks which are cleaned up along with their retention periods.
+/// `FINISH`ed tasks are always cleaned up, while tasks in `REVIEW` are only cleaned up if a
+/// retention period is configured for them.
+fn get_retention_periods(policy: &CleanerPolicy) -> Vec<(ProcessTrackerStatus, i64)> {
+    std::iter::once((ProcessTrackerStatus::Finish, policy.finished_task_retention))
+        .chain(
+            policy
+                .review_task_retention
+                .map(|retention| (ProcessTrackerStatus::Review, retention)),
+        )
+        .collect()
+}
+
+fn get_time_before(
+    seconds: i64,
+) -> CustomResult<time::PrimitiveDateTime, errors::ProcessTrackerError> {
+    common_utils::date_time::now()
+        .checked_sub(Duration::seconds(seconds))
+        .ok_or_else(|| {
+            report!(errors::ProcessTrackerError::ConfigurationError)
+                .attach_printable("Error obtaining time limit for cleaner tasks")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_utils::date_time;
+    use storage_impl::mock_db::MockDb;
+
+    use super::*;
+    use crate::db::process_tracker::ProcessTrackerInterface;
+
+    const DAY_IN_SECONDS: i64 = 86400;
+
+    async fn insert_task(
+        db: &MockDb,
+        id: &str,
+        runner: storage::ProcessTrackerRunner,
+        status: ProcessTrackerStatus,
+        updated_seconds_ago: i64,
+    ) {
+        let mut task = storage::ProcessTrackerNew::new(
+            id,
+            "TASK",
+            runner,
+            ["TAG"],
+            serde_json::json!({}),
+            None,
+            date_time::now(),
+            common_types::consts::API_VERSION,
+        )
+        .unwrap();
+        task.status = status;
+        task.updated_at = date_time::now() - Duration::seconds(updated_seconds_ago);
+        db.insert_process(task).await.unwrap();
+    }
+
+    async fn remaining_task_ids(db: &MockDb) -> Vec<String> {
+        db.processes
+            .lock()
+            .await
+            .iter()
+            .map(|task| task.id.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_retention_periods() {
+        let policy = CleanerPolicy {
+            finished_task_retention: DAY_IN_SECONDS,
+            review_task_retention: None,
+            ..CleanerPolicy::default()
+        };
+        assert_eq!(
+            get_retention_periods(&policy),
+            vec![(ProcessTrackerStatus::Finish, DAY_IN_SECONDS)]
+        );
+
+        let policy = CleanerPolicy {
+            review_task_retention: Some(7 * DAY_IN_SECONDS),
+            ..policy
+        };
+        assert_eq!(
+            get_retention_periods(&policy),
+            vec![
+                (ProcessTrackerStatus::Finish, DAY_IN_SECONDS),
+                (ProcessTrackerStatus::Review, 7 * DAY_IN_SECONDS)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_clean_up_tasks_past_retention() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let runner = storage::ProcessTrackerRunner::PaymentsSyncWorkflow;
+
+        insert_task(
+            &db,
+            "finished_old",
+            runner,
+            ProcessTrackerStatus::Finish,
+            2 * DAY_IN_SECONDS,
+        )
+        .await;
+        insert_task(
+            &db,
+            "finished_recent",
+            runner,
+            ProcessTrackerStatus::Finish,
+            60,
+        )
+        .await;
+        insert_task(
+            &db,
+            "review_old",
+            runner,
+            ProcessTrackerStatus::Review,
+            2 * DAY_IN_SECONDS,
+        )
+        .await;
+        insert_task(
+            &db,
+            "started_old",
+            runner,
+            ProcessTrackerStatus::ProcessStarted,
+            2 * DAY_IN_SECONDS,
+        )
+        .await;
+        insert_task(
+            &db,
+            "other_runner_finished_old",
+            storage::ProcessTrackerRunner::RefundWorkflowRouter,
+            ProcessTrackerStatus::Finish,
+            2 * DAY_IN_SECONDS,
+        )
+        .await;
+
+        let policy = CleanerPolicy {
+            action: CleanupAction::Delete,
+            finished_task_retention: DAY_IN_SECONDS,
+            review_task_retention: None,
+            ..CleanerPolicy::default()
+        };
+        clean_up_tasks(&db, &SchedulerSettings::default(), runner, &policy)
+            .await
+            .unwrap();
+
+        // Tasks in `REVIEW` are retained when no retention period is configured for them
+        assert_eq!(
+            remaining_task_ids(&db).await,
+            vec![
+                "finished_recent",
+                "review_old",
+                "started_old",
+                "other_runner_finished_old"
+            ]
+        );
+
+        let policy = CleanerPolicy {
+            review_task_retention: Some(DAY_IN_SECONDS),
+            ..policy
+        };
+        clean_up_tasks(&db, &SchedulerSettings::default(), runner, &policy)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            remaining_task_ids(&db).await,
+            vec![
+                "finished_recent",
+                "started_old",
+                "other_runner_finished_old"
+            ]
+        );
+    }
+}
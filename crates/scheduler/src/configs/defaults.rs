@@ -4,6 +4,7 @@ impl Default for super::settings::SchedulerSettings {
             stream: "SCHEDULER_STREAM".into(),
             producer: super::settings::ProducerSettings::default(),
             consumer: super::settings::ConsumerSettings::default(),
+            cleaner: super::settings::CleanerSettings::default(),
             graceful_shutdown_interval: 60000,
             loop_interval: 5000,
             server: super::settings::Server::default(),
@@ -32,6 +33,29 @@ impl Default for super::settings::ConsumerSettings {
     }
 }
 
+impl Default for super::settings::CleanerSettings {
+    fn default() -> Self {
+        Self {
+            lock_key: "CLEANER_LOCKING_KEY".into(),
+            lock_ttl: 160,
+            batch_size: 1000,
+            default_policy: super::settings::CleanerPolicy::default(),
+            runner_policies: Vec::new(),
+        }
+    }
+}
+
+impl Default for super::settings::CleanerPolicy {
+    fn default() -> Self {
+        Self {
+            action: super::settings::CleanupAction::default(),
+            finished_task_retention: 2592000, // 30 days
+            review_task_retention: None,
+            stuck_task_threshold: 3600,
+        }
+    }
+}
+
 impl Default for super::settings::Server {
     fn default() -> Self {
         Self {
@@ -1,3 +1,4 @@
+use diesel_models::process_tracker::ProcessTrackerRunner;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use serde::Deserialize;
 
@@ -7,6 +8,7 @@ pub struct SchedulerSettings {
     pub stream: String,
     pub producer: ProducerSettings,
     pub consumer: ConsumerSettings,
+    pub cleaner: CleanerSettings,
     pub loop_interval: u64,
     pub graceful_shutdown_interval: u64,
     pub server: Server,
@@ -37,3 +39,98 @@ pub struct ConsumerSettings {
     pub disabled: bool,
     pub consumer_group: String,
 }
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CleanerSettings {
+    pub lock_key: String,
+    pub lock_ttl: i64,
+    /// Maximum number of tasks fetched per runner and status in a single cleaner run
+    pub batch_size: i64,
+    /// Policy applied to runners which do not have an entry in `runner_policies`
+    pub default_policy: CleanerPolicy,
+    pub runner_policies: Vec<RunnerCleanerPolicy>,
+}
+
+impl CleanerSettings {
+    pub fn get_policy(&self, runner: ProcessTrackerRunner) -> &CleanerPolicy {
+        self.runner_policies
+            .iter()
+            .find(|runner_policy| runner_policy.runner == runner)
+            .map(|runner_policy| &runner_policy.policy)
+            .unwrap_or(&self.default_policy)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RunnerCleanerPolicy {
+    pub runner: ProcessTrackerRunner,
+    #[serde(flatten)]
+    pub policy: CleanerPolicy,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CleanerPolicy {
+    /// Whether tasks past their retention are moved to `process_tracker_archive` or deleted
+    pub action: CleanupAction,
+    /// Time in seconds for which `FINISH`ed tasks are retained
+    pub finished_task_retention: i64,
+    /// Time in seconds for which tasks in `REVIEW` are retained, they are never cleaned up if unset
+    pub review_task_retention: Option<i64>,
+    /// Time in seconds after which a task in `PROCESS_STARTED` is considered stuck and is reset
+    pub stuck_task_threshold: i64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CleanupAction {
+    #[default]
+    Archive,
+    Delete,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cleaner_runner_policy() {
+        let settings = CleanerSettings {
+            runner_policies: vec![RunnerCleanerPolicy {
+                runner: ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+                policy: CleanerPolicy {
+                    action: CleanupAction::Delete,
+                    finished_task_retention: 604800,
+                    ..CleanerPolicy::default()
+                },
+            }],
+            ..CleanerSettings::default()
+        };
+
+        let runner_policy = settings.get_policy(ProcessTrackerRunner::OutgoingWebhookRetryWorkflow);
+        assert_eq!(runner_policy.action, CleanupAction::Delete);
+        assert_eq!(runner_policy.finished_task_retention, 604800);
+    }
+
+    #[test]
+    fn test_cleaner_default_policy() {
+        let settings = CleanerSettings {
+            runner_policies: vec![RunnerCleanerPolicy {
+                runner: ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+                policy: CleanerPolicy {
+                    action: CleanupAction::Delete,
+                    ..CleanerPolicy::default()
+                },
+            }],
+            ..CleanerSettings::default()
+        };
+
+        let default_policy = settings.get_policy(ProcessTrackerRunner::PaymentsSyncWorkflow);
+        assert_eq!(default_policy.action, CleanupAction::Archive);
+        assert_eq!(
+            default_policy.finished_task_retention,
+            CleanerPolicy::default().finished_task_retention
+        );
+    }
+}
@@ -19,6 +19,8 @@ impl super::settings::SchedulerSettings {
 
         self.producer.validate()?;
 
+        self.cleaner.validate()?;
+
         self.server.validate()?;
 
         Ok(())
@@ -35,6 +37,52 @@ impl super::settings::ProducerSettings {
     }
 }
 
+impl super::settings::CleanerSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.lock_key.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "cleaner lock key must not be empty".into(),
+            ))
+        })?;
+
+        when(self.batch_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "cleaner batch size must be greater than zero".into(),
+            ))
+        })?;
+
+        std::iter::once(&self.default_policy)
+            .chain(
+                self.runner_policies
+                    .iter()
+                    .map(|runner_policy| &runner_policy.policy),
+            )
+            .try_for_each(super::settings::CleanerPolicy::validate)
+    }
+}
+
+impl super::settings::CleanerPolicy {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        // A zero retention period would clean up tasks as soon as they are finished
+        when(
+            self.finished_task_retention <= 0
+                || self.stuck_task_threshold <= 0
+                || self
+                    .review_task_retention
+                    .is_some_and(|retention| retention <= 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "cleaner retention periods and stuck task threshold must be positive".into(),
+                ))
+            },
+        )
+    }
+}
+
 impl super::settings::Server {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         common_utils::fp_utils::when(self.host.is_default_or_empty(), || {
@@ -44,3 +92,71 @@ impl super::settings::Server {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use diesel_models::process_tracker::ProcessTrackerRunner;
+
+    use crate::configs::settings::{CleanerPolicy, CleanerSettings, RunnerCleanerPolicy};
+
+    #[test]
+    fn test_cleaner_policy_validation() {
+        assert!(CleanerPolicy::default().validate().is_ok());
+        assert!(CleanerPolicy {
+            review_task_retention: Some(86400),
+            ..CleanerPolicy::default()
+        }
+        .validate()
+        .is_ok());
+
+        assert!(CleanerPolicy {
+            finished_task_retention: 0,
+            ..CleanerPolicy::default()
+        }
+        .validate()
+        .is_err());
+        assert!(CleanerPolicy {
+            review_task_retention: Some(0),
+            ..CleanerPolicy::default()
+        }
+        .validate()
+        .is_err());
+        assert!(CleanerPolicy {
+            stuck_task_threshold: 0,
+            ..CleanerPolicy::default()
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_cleaner_settings_validation() {
+        // Runners without their own policy fall back to the default policy
+        assert!(CleanerSettings::default().validate().is_ok());
+
+        assert!(CleanerSettings {
+            runner_policies: vec![RunnerCleanerPolicy {
+                runner: ProcessTrackerRunner::PaymentsSyncWorkflow,
+                policy: CleanerPolicy {
+                    finished_task_retention: 0,
+                    ..CleanerPolicy::default()
+                },
+            }],
+            ..CleanerSettings::default()
+        }
+        .validate()
+        .is_err());
+        assert!(CleanerSettings {
+            batch_size: 0,
+            ..CleanerSettings::default()
+        }
+        .validate()
+        .is_err());
+        assert!(CleanerSettings {
+            lock_key: String::new(),
+            ..CleanerSettings::default()
+        }
+        .validate()
+        .is_err());
+    }
+}
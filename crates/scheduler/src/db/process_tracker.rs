@@ -62,6 +62,24 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         status: storage_enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_processes_by_runner_status_updated_before(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        status: storage_enums::ProcessTrackerStatus,
+        updated_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn archive_processes(
+        &self,
+        processes: Vec<storage::ProcessTracker>,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    async fn delete_processes_by_ids(
+        &self,
+        ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -192,6 +210,61 @@ impl ProcessTrackerInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    async fn find_processes_by_runner_status_updated_before(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        status: storage_enums::ProcessTrackerStatus,
+        updated_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::find_processes_by_runner_status_updated_before(
+            &conn,
+            &runner.to_string(),
+            status,
+            updated_before,
+            limit,
+            common_types::consts::API_VERSION,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn archive_processes(
+        &self,
+        processes: Vec<storage::ProcessTracker>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let archived_at = common_utils::date_time::now();
+        let ids = processes
+            .iter()
+            .map(|process| process.id.clone())
+            .collect::<Vec<_>>();
+        let archived_processes = processes
+            .into_iter()
+            .map(|process| storage::ProcessTrackerArchiveNew::new(process, archived_at))
+            .collect();
+
+        // Inserting into the archive ignores conflicts, so a failure after this point leaves the
+        // processes in both tables and the next run retries deleting them.
+        storage::ProcessTrackerArchiveNew::insert_batch(&conn, archived_processes)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+        storage::ProcessTracker::delete_processes_by_ids(&conn, ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn delete_processes_by_ids(
+        &self,
+        ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ProcessTracker::delete_processes_by_ids(&conn, ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -299,4 +372,48 @@ impl ProcessTrackerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_processes_by_runner_status_updated_before(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        status: storage_enums::ProcessTrackerStatus,
+        updated_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let runner = runner.to_string();
+        let mut processes = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                process.runner.as_ref() == Some(&runner)
+                    && process.status == status
+                    && process.updated_at < updated_before
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        processes.sort_by_key(|process| process.updated_at);
+        processes.truncate(usize::try_from(limit).unwrap_or_default());
+        Ok(processes)
+    }
+
+    async fn archive_processes(
+        &self,
+        _processes: Vec<storage::ProcessTracker>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_processes_by_ids(
+        &self,
+        ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut processes = self.processes.lock().await;
+        let initial_count = processes.len();
+        processes.retain(|process| !ids.contains(&process.id));
+        Ok(initial_count - processes.len())
+    }
 }
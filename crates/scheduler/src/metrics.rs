@@ -12,3 +12,6 @@ counter_metric!(TASK_CONSUMED, PT_METER); // Tasks consumed by consumer
 counter_metric!(TASK_PROCESSED, PT_METER); // Tasks completed processing
 counter_metric!(TASK_FINISHED, PT_METER); // Tasks finished
 counter_metric!(TASK_RETRIED, PT_METER); // Tasks added for retries
+counter_metric!(TASKS_RESET_COUNT, PT_METER); // Stuck tasks reset by cleaner
+counter_metric!(TASKS_ARCHIVED_COUNT, PT_METER); // Tasks archived by cleaner
+counter_metric!(TASKS_DELETED_COUNT, PT_METER); // Tasks deleted by cleaner
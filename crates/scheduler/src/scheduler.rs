@@ -8,8 +8,8 @@ use storage_impl::mock_db::MockDb;
 use storage_impl::RouterStore;
 use tokio::sync::mpsc;
 
-use super::env::logger::error;
 pub use crate::{
+    cleaner,
     configs::settings::SchedulerSettings,
     consumer::{self, workflows},
     db::{process_tracker::ProcessTrackerInterface, queue::QueueInterface},
@@ -94,7 +94,13 @@ where
             .await?
         }
         SchedulerFlow::Cleaner => {
-            error!("This flow has not been implemented yet!");
+            cleaner::start_cleaner(
+                state,
+                scheduler_settings,
+                channel,
+                app_state_to_session_state,
+            )
+            .await?
         }
     }
     Ok(())
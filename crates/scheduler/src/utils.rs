@@ -400,10 +400,29 @@ where
     T: SchedulerInterface + Send + Sync + ?Sized,
     Fut: futures::Future<Output = CustomResult<(), errors::ProcessTrackerError>>,
 {
-    let tag = "PRODUCER_LOCK";
-    let lock_key = &settings.producer.lock_key;
+    acquire_lock_and_run(
+        state,
+        "PRODUCER_LOCK",
+        &settings.producer.lock_key,
+        settings.producer.lock_ttl,
+        callback,
+    )
+    .await
+}
+
+pub(crate) async fn acquire_lock_and_run<T, F, Fut>(
+    state: &T,
+    tag: &str,
+    lock_key: &str,
+    ttl: i64,
+    callback: F,
+) -> CustomResult<(), errors::ProcessTrackerError>
+where
+    F: Fn() -> Fut,
+    T: SchedulerInterface + Send + Sync + ?Sized,
+    Fut: futures::Future<Output = CustomResult<(), errors::ProcessTrackerError>>,
+{
     let lock_val = "LOCKED";
-    let ttl = settings.producer.lock_ttl;
 
     if state
         .acquire_pt_lock(tag, lock_key, lock_val, ttl)
@@ -65,6 +65,9 @@ pub struct MockDb {
     pub user_authentication_methods:
         Arc<Mutex<Vec<store::user_authentication_method::UserAuthenticationMethod>>>,
     pub themes: Arc<Mutex<Vec<store::user::theme::Theme>>>,
+    pub webhook_endpoints: Arc<Mutex<Vec<store::webhook_endpoint::WebhookEndpoint>>>,
+    pub payout_batches: Arc<Mutex<Vec<store::payout_batch::PayoutBatch>>>,
+    pub idempotency_records: Arc<Mutex<Vec<store::idempotency_record::IdempotencyRecord>>>,
 }
 
 impl MockDb {
@@ -113,6 +116,9 @@ impl MockDb {
             user_key_store: Default::default(),
             user_authentication_methods: Default::default(),
             themes: Default::default(),
+            webhook_endpoints: Default::default(),
+            payout_batches: Default::default(),
+            idempotency_records: Default::default(),
         })
     }
 
@@ -0,0 +1,22 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS process_tracker_archive (
+    id VARCHAR(127) PRIMARY KEY,
+    name VARCHAR(64),
+    tag TEXT [] NOT NULL DEFAULT '{}'::TEXT [],
+    runner VARCHAR(64),
+    retry_count INTEGER NOT NULL,
+    schedule_time TIMESTAMP,
+    rule VARCHAR(255) NOT NULL,
+    tracking_data JSON NOT NULL,
+    business_status VARCHAR(255) NOT NULL,
+    status "ProcessTrackerStatus" NOT NULL,
+    event TEXT [] NOT NULL DEFAULT '{}'::TEXT [],
+    created_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL,
+    version "ApiVersion" NOT NULL DEFAULT 'v1',
+    archived_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS process_tracker_archive_archived_at_index ON process_tracker_archive (archived_at);
+
+CREATE INDEX IF NOT EXISTS process_tracker_runner_status_updated_at_index ON process_tracker (runner, status, updated_at);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE api_keys
+DROP COLUMN IF EXISTS scopes,
+DROP COLUMN IF EXISTS allowed_ips,
+DROP COLUMN IF EXISTS profile_id;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS idempotency_record_expires_at_index;
+
+DROP TABLE IF EXISTS idempotency_record;
@@ -0,0 +1,12 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS idempotency_record (
+    merchant_id VARCHAR(64) NOT NULL,
+    idempotency_key VARCHAR(255) NOT NULL,
+    request_fingerprint VARCHAR(64) NOT NULL,
+    response BYTEA,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    expires_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (merchant_id, idempotency_key)
+);
+
+CREATE INDEX IF NOT EXISTS idempotency_record_expires_at_index ON idempotency_record (expires_at);
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS routing_algorithm_activation (
+    activation_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    transaction_type "TransactionType" NOT NULL,
+    previous_algorithm_id VARCHAR(64),
+    algorithm_id VARCHAR(64),
+    activated_by VARCHAR(255),
+    reason VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS routing_algorithm_activation_profile_id_index ON routing_algorithm_activation (profile_id, transaction_type, created_at);
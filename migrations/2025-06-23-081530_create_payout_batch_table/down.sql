@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payout_batch_merchant_id_created_at_index;
+
+DROP TABLE IF EXISTS payout_batch;
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE business_profile DROP COLUMN IF EXISTS dispute_policy;
+
+DELETE FROM pg_enum
+WHERE enumlabel = 'dispute_deadline_approaching'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventType'
+);
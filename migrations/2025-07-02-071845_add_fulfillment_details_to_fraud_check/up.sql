@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE fraud_check ADD COLUMN IF NOT EXISTS fulfillment_details JSONB;